@@ -0,0 +1,59 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use profile::prelude::*;
+
+const NUMBER_OF_ACCOUNTS: HDPathValue = 10;
+
+fn derive_account_factor_instances(c: &mut Criterion) {
+    let private_factor_source =
+        PrivateHierarchicalDeterministicFactorSource::placeholder();
+    let mut group = c.benchmark_group("derive 10 account factor instances");
+
+    group.bench_function("seed derived once per instance", |b| {
+        b.iter(|| {
+            (0..NUMBER_OF_ACCOUNTS)
+                .map(|index| {
+                    private_factor_source
+                        .mnemonic_with_passphrase
+                        .derive_private_key(AccountPath::new(
+                            black_box(NetworkID::Mainnet),
+                            CAP26KeyKind::TransactionSigning,
+                            index,
+                        ))
+                })
+                .collect::<Vec<_>>()
+        })
+    });
+
+    group.bench_function("seed cached, one instance at a time", |b| {
+        b.iter(|| {
+            let seed_cache = SeedCache::default();
+            (0..NUMBER_OF_ACCOUNTS)
+                .map(|index| {
+                    private_factor_source
+                        .derive_entity_creation_factor_instance_using::<
+                            AccountPath,
+                        >(
+                            &seed_cache,
+                            black_box(NetworkID::Mainnet),
+                            index,
+                        )
+                })
+                .collect::<Vec<_>>()
+        })
+    });
+
+    group.bench_function("seed derived once, all instances at once", |b| {
+        b.iter(|| {
+            private_factor_source
+                .derive_entity_creation_factor_instances::<AccountPath, _>(
+                    black_box(NetworkID::Mainnet),
+                    0..NUMBER_OF_ACCOUNTS,
+                )
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, derive_account_factor_instances);
+criterion_main!(benches);
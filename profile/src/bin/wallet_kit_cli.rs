@@ -0,0 +1,156 @@
+//! Command line tool for QA and support engineers, generating placeholder
+//! Profiles, migrating and validating Profile snapshots and deriving
+//! addresses from a mnemonic.
+//!
+//! Build with `cargo run --features cli --bin wallet_kit_cli -- <COMMAND>`.
+
+use clap::{Parser, Subcommand};
+use profile::prelude::*;
+use std::{fs, path::PathBuf, process::ExitCode};
+
+#[derive(Parser)]
+#[command(name = "wallet_kit_cli", about = "Radix Wallet Kit tooling")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Prints the JSON of a placeholder Profile.
+    Placeholder {
+        /// Prints the other placeholder Profile.
+        #[arg(long)]
+        other: bool,
+    },
+
+    /// Migrates the Profile snapshot at `input` to the current snapshot
+    /// version, repairing its content hint, and writes it to `output` or
+    /// prints it if omitted.
+    Migrate {
+        input: PathBuf,
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+    },
+
+    /// Validates the Profile snapshot at `input`, printing an anonymized
+    /// summary of it if valid.
+    Validate { input: PathBuf },
+
+    /// Prints the addresses of the Accounts at `count` consecutive indices,
+    /// starting at `start`, of the mnemonic.
+    DeriveAddresses {
+        /// The BIP39 mnemonic phrase, words separated by spaces.
+        #[arg(long)]
+        mnemonic: String,
+
+        /// The BIP39 passphrase, empty if omitted.
+        #[arg(long, default_value = "")]
+        passphrase: String,
+
+        /// The logical name of the network, e.g. `mainnet` or `stokenet`.
+        #[arg(long, default_value = "mainnet")]
+        network: String,
+
+        #[arg(long, default_value_t = 0)]
+        start: HDPathValue,
+
+        #[arg(long, default_value_t = 5)]
+        count: HDPathValue,
+    },
+}
+
+fn read_profile(input: &PathBuf) -> std::result::Result<Profile, String> {
+    let json = fs::read(input)
+        .map_err(|e| format!("Failed to read {}: {}", input.display(), e))?;
+    serde_json::from_slice::<Profile>(&json)
+        .map_err(|e| format!("Invalid Profile snapshot: {}", e))
+}
+
+fn to_json(profile: &Profile) -> String {
+    profile.to_pretty_json_stable()
+}
+
+fn network_with_logical_name(
+    name: &str,
+) -> std::result::Result<NetworkID, String> {
+    enum_iterator::all::<NetworkID>()
+        .find(|n| n.logical_name() == name)
+        .ok_or(format!("Unknown network '{}'", name))
+}
+
+fn run(command: Command) -> std::result::Result<(), String> {
+    match command {
+        Command::Placeholder { other } => {
+            let profile = if other {
+                Profile::placeholder_other()
+            } else {
+                Profile::placeholder()
+            };
+            print!("{}", to_json(&profile));
+        }
+        Command::Migrate { input, output } => {
+            let mut profile = read_profile(&input)?;
+            profile.header.snapshot_version =
+                ProfileSnapshotVersion::default();
+            profile.update_snapshot_version();
+            if profile.repair_content_hint() {
+                eprintln!("Repaired content hint.");
+            }
+            let json = to_json(&profile);
+            match output {
+                Some(output) => fs::write(&output, json).map_err(|e| {
+                    format!("Failed to write {}: {}", output.display(), e)
+                })?,
+                None => print!("{}", json),
+            }
+        }
+        Command::Validate { input } => {
+            let profile = read_profile(&input)?;
+            println!("Valid Profile snapshot.");
+            println!("{:#?}", profile.anonymized_summary());
+        }
+        Command::DeriveAddresses {
+            mnemonic,
+            passphrase,
+            network,
+            start,
+            count,
+        } => {
+            let network_id = network_with_logical_name(&network)?;
+            let mnemonic = Mnemonic::from_phrase(&mnemonic)
+                .map_err(|e| format!("Invalid mnemonic: {}", e))?;
+            let mnemonic_with_passphrase =
+                MnemonicWithPassphrase::with_passphrase(
+                    mnemonic,
+                    BIP39Passphrase::new(passphrase),
+                );
+            let paths = (start..start + count).map(|index| {
+                AccountPath::new(
+                    network_id,
+                    CAP26KeyKind::TransactionSigning,
+                    index,
+                )
+            });
+            for key in mnemonic_with_passphrase.derive_private_keys(paths) {
+                let public_key = key.public_key();
+                println!(
+                    "{}\t{}",
+                    public_key.derivation_path,
+                    AccountAddress::new(public_key.public_key, network_id)
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse().command) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,362 @@
+use crate::prelude::*;
+use std::sync::RwLock;
+
+/// Verifies the two-way link between a website and a dApp definition
+/// before the wallet handles a request from the website, so that a
+/// phishing website cannot impersonate a dApp.
+///
+/// The `.well-known/radix.json` file of the website and the metadata of the
+/// dApp definition are fetched using the [`NetworkingDriver`], successful
+/// verifications are cached for `cache_ttl_seconds`.
+#[derive(Debug, uniffi::Object)]
+pub struct DappVerifier {
+    networking_driver: Arc<dyn NetworkingDriver>,
+    gateway_client: GatewayClient,
+    cache_ttl_seconds: u64,
+    cache: RwLock<HashMap<(String, AccountAddress), VerifiedDapp>>,
+    offline_mode: Option<Arc<OfflineMode>>,
+}
+
+/// The result of a successful verification by the [`DappVerifier`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct VerifiedDapp {
+    pub origin: Url,
+    pub dapp_definition_address: AccountAddress,
+
+    /// The metadata of the dApp definition, e.g. its name and icon.
+    pub metadata: EntityMetadataCollection,
+
+    pub verified_at: Timestamp,
+}
+
+#[uniffi::export]
+impl DappVerifier {
+    #[uniffi::constructor]
+    pub fn new(
+        networking_driver: Arc<dyn NetworkingDriver>,
+        gateway: Gateway,
+        cache_ttl_seconds: u64,
+    ) -> Self {
+        Self {
+            gateway_client: GatewayClient::new(
+                networking_driver.clone(),
+                gateway,
+            ),
+            networking_driver,
+            cache_ttl_seconds,
+            cache: RwLock::new(HashMap::new()),
+            offline_mode: None,
+        }
+    }
+
+    /// Verifies that the website at `origin` claims the dApp definition
+    /// with `dapp_definition_address` and vice versa, using a cached
+    /// result if one younger than `cache_ttl_seconds` exists.
+    pub fn verify(
+        &self,
+        origin: Url,
+        dapp_definition_address: AccountAddress,
+    ) -> Result<VerifiedDapp> {
+        let key = (
+            origin.origin().ascii_serialization(),
+            dapp_definition_address.clone(),
+        );
+        if let Some(cached) = self.cached(&key) {
+            return Ok(cached);
+        }
+        if let Some(offline_mode) = &self.offline_mode {
+            offline_mode
+                .ensure_available(WalletCapability::DappVerification)?;
+        }
+        let verified =
+            self.fetch_and_verify(origin, dapp_definition_address)?;
+        self.cache
+            .write()
+            .expect(
+                "Should be able to acquire write lock for DappVerifier cache",
+            )
+            .insert(key, verified.clone());
+        Ok(verified)
+    }
+
+    /// Forgets all cached verifications.
+    pub fn clear_cache(&self) {
+        self.cache
+            .write()
+            .expect(
+                "Should be able to acquire write lock for DappVerifier cache",
+            )
+            .clear();
+    }
+}
+
+impl DappVerifier {
+    /// Sets the offline mode checked before fetching, failing verifications
+    /// not cached with [`CommonError::FeatureUnavailableOffline`] if enabled.
+    pub fn with_offline_mode(self, offline_mode: Arc<OfflineMode>) -> Self {
+        Self {
+            gateway_client: self
+                .gateway_client
+                .with_offline_mode(offline_mode.clone()),
+            offline_mode: Some(offline_mode),
+            ..self
+        }
+    }
+
+    /// The path, relative to the origin of a website, of the file listing
+    /// the dApp definitions the website claims.
+    pub const WELL_KNOWN_FILE_PATH: &'static str = ".well-known/radix.json";
+
+    fn cached(&self, key: &(String, AccountAddress)) -> Option<VerifiedDapp> {
+        let cache = self.cache.read().expect(
+            "Should be able to acquire read lock for DappVerifier cache",
+        );
+        let cached = cache.get(key)?;
+        let age = Timestamp::now().seconds_since(&cached.verified_at);
+        (0..self.cache_ttl_seconds as i64)
+            .contains(&age)
+            .then(|| cached.clone())
+    }
+
+    fn fetch_and_verify(
+        &self,
+        origin: Url,
+        dapp_definition_address: AccountAddress,
+    ) -> Result<VerifiedDapp> {
+        ensure_https(&origin)?;
+
+        let well_known_file = self.fetch_well_known_file(&origin)?;
+        well_known_file.verify_website_claims_dapp(&dapp_definition_address)?;
+
+        let metadata = self.fetch_metadata(&dapp_definition_address)?;
+        metadata.verify_dapp_claims_website(&origin)?;
+
+        Ok(VerifiedDapp {
+            origin,
+            dapp_definition_address,
+            metadata,
+            verified_at: Timestamp::now(),
+        })
+    }
+
+    fn fetch_well_known_file(
+        &self,
+        origin: &Url,
+    ) -> Result<RadixWellKnownFile> {
+        let url = Url::parse(&origin.origin().ascii_serialization())
+            .and_then(|o| o.join(Self::WELL_KNOWN_FILE_PATH))
+            .map_err(|_| CommonError::InvalidURL(origin.to_string()))?;
+        self.networking_driver
+            .execute_network_request(NetworkRequest::new_get(url))?
+            .decode_json()
+    }
+
+    fn fetch_metadata(
+        &self,
+        address: &AccountAddress,
+    ) -> Result<EntityMetadataCollection> {
+        self.gateway_client
+            .fetch_all(EntityMetadataPageRequest::new(address))
+            .map(EntityMetadataCollection::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn dashboard() -> Url {
+        Url::parse("https://dashboard.radixdlt.com/network-staking").unwrap()
+    }
+
+    fn well_known_url() -> Url {
+        Url::parse("https://dashboard.radixdlt.com/.well-known/radix.json")
+            .unwrap()
+    }
+
+    fn metadata_url() -> Url {
+        Url::parse("https://mainnet.radixdlt.com/state/entity/metadata/page")
+            .unwrap()
+    }
+
+    fn json_response<T: Serialize>(value: &T) -> NetworkResponse {
+        NetworkResponse::new(200, serde_json::to_vec(value).unwrap())
+    }
+
+    fn make_sut(
+        responses: impl IntoIterator<Item = (Url, NetworkResponse)>,
+        cache_ttl_seconds: u64,
+    ) -> (DappVerifier, Arc<MockNetworkingDriver>) {
+        let driver = Arc::new(MockNetworkingDriver::new(responses));
+        let sut = DappVerifier::new(
+            driver.clone(),
+            Gateway::mainnet(),
+            cache_ttl_seconds,
+        );
+        (sut, driver)
+    }
+
+    fn valid_responses() -> Vec<(Url, NetworkResponse)> {
+        vec![
+            (
+                well_known_url(),
+                json_response(&RadixWellKnownFile::placeholder()),
+            ),
+            (
+                metadata_url(),
+                json_response(&EntityMetadataCollection::placeholder()),
+            ),
+        ]
+    }
+
+    #[test]
+    fn verify() {
+        let (sut, driver) = make_sut(valid_responses(), 60);
+        let verified = with_time_provider(
+            MockTimeProvider(Timestamp::placeholder()),
+            || sut.verify(dashboard(), AccountAddress::placeholder()),
+        )
+        .unwrap();
+        assert_eq!(verified.metadata, EntityMetadataCollection::placeholder());
+        assert_eq!(verified.verified_at, Timestamp::placeholder());
+        assert_eq!(
+            driver.requests().into_iter().map(|r| r.url).collect_vec(),
+            vec![well_known_url(), metadata_url()]
+        );
+    }
+
+    #[test]
+    fn metadata_request_body() {
+        let (sut, driver) = make_sut(valid_responses(), 60);
+        sut.verify(dashboard(), AccountAddress::placeholder())
+            .unwrap();
+        let request = driver.requests()[1].clone();
+        assert_eq!(request.method, NetworkMethod::Post);
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(&request.body).unwrap(),
+            json!({ "address": AccountAddress::placeholder().to_string() })
+        );
+    }
+
+    #[test]
+    fn uses_cache_within_ttl() {
+        let (sut, driver) = make_sut(valid_responses(), 60);
+        let address = AccountAddress::placeholder();
+        let first = with_time_provider(
+            MockTimeProvider(Timestamp::parse("2023-09-11T16:05:00Z").unwrap()),
+            || sut.verify(dashboard(), address.clone()),
+        )
+        .unwrap();
+        let second = with_time_provider(
+            MockTimeProvider(Timestamp::parse("2023-09-11T16:05:59Z").unwrap()),
+            || sut.verify(dashboard(), address.clone()),
+        )
+        .unwrap();
+        assert_eq!(first, second);
+        assert_eq!(driver.requests().len(), 2);
+    }
+
+    #[test]
+    fn refetches_after_ttl() {
+        let (sut, driver) = make_sut(valid_responses(), 60);
+        let address = AccountAddress::placeholder();
+        with_time_provider(
+            MockTimeProvider(Timestamp::parse("2023-09-11T16:05:00Z").unwrap()),
+            || sut.verify(dashboard(), address.clone()),
+        )
+        .unwrap();
+        with_time_provider(
+            MockTimeProvider(Timestamp::parse("2023-09-11T16:06:00Z").unwrap()),
+            || sut.verify(dashboard(), address.clone()),
+        )
+        .unwrap();
+        assert_eq!(driver.requests().len(), 4);
+    }
+
+    #[test]
+    fn clear_cache() {
+        let (sut, driver) = make_sut(valid_responses(), 60);
+        sut.verify(dashboard(), AccountAddress::placeholder())
+            .unwrap();
+        sut.clear_cache();
+        sut.verify(dashboard(), AccountAddress::placeholder())
+            .unwrap();
+        assert_eq!(driver.requests().len(), 4);
+    }
+
+    #[test]
+    fn failures_are_not_cached() {
+        let (sut, driver) = make_sut([], 60);
+        assert!(sut
+            .verify(dashboard(), AccountAddress::placeholder())
+            .is_err());
+        assert!(sut
+            .verify(dashboard(), AccountAddress::placeholder())
+            .is_err());
+        assert_eq!(driver.requests().len(), 2);
+    }
+
+    #[test]
+    fn missing_well_known_file() {
+        let (sut, _) = make_sut([], 60);
+        assert_eq!(
+            sut.verify(dashboard(), AccountAddress::placeholder()),
+            Err(CommonError::NetworkResponseBadCode { code: 404 })
+        );
+    }
+
+    #[test]
+    fn website_does_not_claim_dapp() {
+        let (sut, driver) = make_sut(valid_responses(), 60);
+        assert_eq!(
+            sut.verify(dashboard(), AccountAddress::placeholder_other()),
+            Err(CommonError::WebsiteDoesNotClaimDappDefinition {
+                dapp_definition_address: AccountAddress::placeholder_other()
+                    .to_string()
+            })
+        );
+        assert_eq!(driver.requests().len(), 1);
+    }
+
+    #[test]
+    fn dapp_does_not_claim_website() {
+        let (sut, _) = make_sut(
+            [
+                (
+                    well_known_url(),
+                    json_response(&RadixWellKnownFile::placeholder()),
+                ),
+                (
+                    metadata_url(),
+                    json_response(&EntityMetadataCollection::new([
+                        EntityMetadataItem::new(
+                            MetadataKey::AccountType,
+                            MetadataTypedValue::StringValue {
+                                value: DAPP_DEFINITION_ACCOUNT_TYPE.to_owned(),
+                            },
+                            true,
+                        ),
+                    ])),
+                ),
+            ],
+            60,
+        );
+        assert_eq!(
+            sut.verify(dashboard(), AccountAddress::placeholder()),
+            Err(CommonError::DappDefinitionDoesNotClaimWebsite {
+                origin: "https://dashboard.radixdlt.com".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_non_https_origin() {
+        let (sut, driver) = make_sut(valid_responses(), 60);
+        let origin = Url::parse("http://dashboard.radixdlt.com").unwrap();
+        assert_eq!(
+            sut.verify(origin.clone(), AccountAddress::placeholder()),
+            Err(CommonError::UrlNotHttps(origin.to_string()))
+        );
+        assert!(driver.requests().is_empty());
+    }
+}
@@ -0,0 +1,538 @@
+use crate::prelude::*;
+use std::collections::VecDeque;
+
+/// Executes requests against the API of a [`Gateway`] using the
+/// [`NetworkingDriver`] of the Wallet Client, transparently following the
+/// cursors of paginated endpoints and retrying transient failures as per
+/// its [`RetryPolicy`].
+#[derive(Clone, Debug)]
+pub struct GatewayClient {
+    networking_driver: Arc<dyn NetworkingDriver>,
+    gateway: Gateway,
+    pagination_options: PaginationOptions,
+    pub(crate) retry_policy: RetryPolicy,
+    failure_observer: Option<Arc<dyn NetworkRequestFailureObserver>>,
+    offline_mode: Option<Arc<OfflineMode>>,
+}
+
+impl GatewayClient {
+    pub fn new(
+        networking_driver: Arc<dyn NetworkingDriver>,
+        gateway: Gateway,
+    ) -> Self {
+        Self {
+            networking_driver,
+            gateway,
+            pagination_options: PaginationOptions::default(),
+            retry_policy: RetryPolicy::default(),
+            failure_observer: None,
+            offline_mode: None,
+        }
+    }
+
+    pub fn with_pagination_options(
+        self,
+        pagination_options: PaginationOptions,
+    ) -> Self {
+        Self {
+            pagination_options,
+            ..self
+        }
+    }
+
+    /// Sets the policy used for all requests, unless overridden per call.
+    pub fn with_retry_policy(self, retry_policy: RetryPolicy) -> Self {
+        Self {
+            retry_policy,
+            ..self
+        }
+    }
+
+    /// Sets the observer notified of every failed attempt of a request.
+    pub fn with_failure_observer(
+        self,
+        failure_observer: Arc<dyn NetworkRequestFailureObserver>,
+    ) -> Self {
+        Self {
+            failure_observer: Some(failure_observer),
+            ..self
+        }
+    }
+
+    /// Sets the offline mode checked before every request, failing it with
+    /// [`CommonError::FeatureUnavailableOffline`] if enabled.
+    pub fn with_offline_mode(self, offline_mode: Arc<OfflineMode>) -> Self {
+        Self {
+            offline_mode: Some(offline_mode),
+            ..self
+        }
+    }
+
+    pub fn gateway(&self) -> &Gateway {
+        &self.gateway
+    }
+
+    /// Returns an iterator over all items of the paginated collection
+    /// requested by `request`, fetching the next page - at most
+    /// `max_page_count` pages - only once all items of the previous page
+    /// have been consumed. The iterator ends after the first error.
+    pub fn stream_all<R>(&self, request: R) -> GatewayPagedItems<'_, R>
+    where
+        R: GatewayPagedRequest,
+    {
+        self.stream_all_with_retry_policy(request, self.retry_policy.clone())
+    }
+
+    /// Like [`GatewayClient::stream_all`] but fetches each page using
+    /// `retry_policy` instead of the policy of the client.
+    pub fn stream_all_with_retry_policy<R>(
+        &self,
+        request: R,
+        retry_policy: RetryPolicy,
+    ) -> GatewayPagedItems<'_, R>
+    where
+        R: GatewayPagedRequest,
+    {
+        GatewayPagedItems {
+            client: self,
+            request,
+            retry_policy,
+            buffered: VecDeque::new(),
+            next_cursor: None,
+            fetched_page_count: 0,
+            is_done: false,
+        }
+    }
+
+    /// Fetches all items of the paginated collection requested by `request`,
+    /// see [`GatewayClient::stream_all`].
+    pub fn fetch_all<R>(&self, request: R) -> Result<Vec<R::Item>>
+    where
+        R: GatewayPagedRequest,
+    {
+        self.stream_all(request).collect()
+    }
+
+    /// Like [`GatewayClient::fetch_all`] but fetches each page using
+    /// `retry_policy` instead of the policy of the client.
+    pub fn fetch_all_with_retry_policy<R>(
+        &self,
+        request: R,
+        retry_policy: RetryPolicy,
+    ) -> Result<Vec<R::Item>>
+    where
+        R: GatewayPagedRequest,
+    {
+        self.stream_all_with_retry_policy(request, retry_policy)
+            .collect()
+    }
+
+    fn url_of(&self, path: &str) -> Result<Url> {
+        self.gateway
+            .url
+            .join(path)
+            .map_err(|_| CommonError::InvalidURL(self.gateway.to_string()))
+    }
+
+    /// The body of `request` with the pagination fields added.
+    fn page_body<R: GatewayPagedRequest>(
+        &self,
+        request: &R,
+        cursor: Option<&PageCursor>,
+    ) -> serde_json::Value {
+        let mut body = serde_json::to_value(request)
+            .expect("Should always be able to JSON serialize a request body.");
+        let fields = body
+            .as_object_mut()
+            .expect("Gateway paged requests should serialize to JSON objects.");
+        if let Some(cursor) = cursor {
+            fields.insert("cursor".to_owned(), json!(cursor));
+        }
+        if let Some(limit) = self.pagination_options.limit_per_page {
+            fields.insert("limit_per_page".to_owned(), json!(limit));
+        }
+        body
+    }
+
+    fn fetch_page<R: GatewayPagedRequest>(
+        &self,
+        request: &R,
+        cursor: Option<&PageCursor>,
+        retry_policy: &RetryPolicy,
+    ) -> Result<PagedResponse<R::Item>> {
+        self.post(R::PATH, &self.page_body(request, cursor), retry_policy)
+    }
+
+    /// POSTs `body` as JSON to the endpoint at `path` using `retry_policy`
+    /// and decodes the JSON response.
+    pub(crate) fn post<B, T>(
+        &self,
+        path: &str,
+        body: &B,
+        retry_policy: &RetryPolicy,
+    ) -> Result<T>
+    where
+        B: Serialize,
+        T: for<'de> Deserialize<'de>,
+    {
+        if let Some(offline_mode) = &self.offline_mode {
+            offline_mode.ensure_available(WalletCapability::GatewayQueries)?;
+        }
+        let request = NetworkRequest::new_post_json(self.url_of(path)?, body);
+        measure(MeasuredOperation::GatewayCall, path, || {
+            retry_policy.execute(
+                self.networking_driver.as_ref(),
+                request,
+                self.failure_observer.as_deref(),
+            )
+        })?
+        .decode_json()
+    }
+}
+
+/// Iterator over all items of a paginated Gateway collection, created by
+/// [`GatewayClient::stream_all`].
+pub struct GatewayPagedItems<'a, R: GatewayPagedRequest> {
+    client: &'a GatewayClient,
+    request: R,
+    retry_policy: RetryPolicy,
+    buffered: VecDeque<R::Item>,
+    next_cursor: Option<PageCursor>,
+    fetched_page_count: u32,
+    is_done: bool,
+}
+
+impl<'a, R: GatewayPagedRequest> GatewayPagedItems<'a, R> {
+    fn fetch_next_page(&mut self) -> Result<()> {
+        let max_page_count = self.client.pagination_options.max_page_count;
+        if self.fetched_page_count >= max_page_count {
+            return Err(CommonError::GatewayPageLimitExceeded {
+                max_page_count,
+            });
+        }
+        let page = self.client.fetch_page(
+            &self.request,
+            self.next_cursor.as_ref(),
+            &self.retry_policy,
+        )?;
+        self.fetched_page_count += 1;
+        self.next_cursor = page.next_cursor;
+        self.buffered.extend(page.items);
+        Ok(())
+    }
+}
+
+impl<'a, R: GatewayPagedRequest> Iterator for GatewayPagedItems<'a, R> {
+    type Item = Result<R::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.buffered.pop_front() {
+                return Some(Ok(item));
+            }
+            let has_more_pages =
+                self.fetched_page_count == 0 || self.next_cursor.is_some();
+            if self.is_done || !has_more_pages {
+                return None;
+            }
+            if let Err(error) = self.fetch_next_page() {
+                self.is_done = true;
+                return Some(Err(error));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use std::sync::RwLock;
+
+    #[derive(Serialize)]
+    struct NumbersRequest {
+        address: String,
+    }
+
+    impl GatewayPagedRequest for NumbersRequest {
+        type Item = u8;
+        const PATH: &'static str = "numbers/page";
+    }
+
+    fn request() -> NumbersRequest {
+        NumbersRequest {
+            address: "abc".to_owned(),
+        }
+    }
+
+    /// Responds with `responses` in order, regardless of the request.
+    #[derive(Debug)]
+    struct SequenceNetworkingDriver {
+        responses: RwLock<Vec<Result<NetworkResponse>>>,
+        requests: RwLock<Vec<NetworkRequest>>,
+    }
+
+    impl SequenceNetworkingDriver {
+        fn new(responses: Vec<Result<NetworkResponse>>) -> Arc<Self> {
+            Arc::new(Self {
+                responses: RwLock::new(responses.into_iter().rev().collect()),
+                requests: Default::default(),
+            })
+        }
+
+        fn request_bodies(&self) -> Vec<serde_json::Value> {
+            self.requests
+                .read()
+                .unwrap()
+                .iter()
+                .map(|r| serde_json::from_slice(&r.body).unwrap())
+                .collect()
+        }
+    }
+
+    impl NetworkingDriver for SequenceNetworkingDriver {
+        fn execute_network_request(
+            &self,
+            request: NetworkRequest,
+        ) -> Result<NetworkResponse> {
+            self.requests.write().unwrap().push(request);
+            self.responses
+                .write()
+                .unwrap()
+                .pop()
+                .unwrap_or(Ok(NetworkResponse::new(404, Vec::new())))
+        }
+    }
+
+    fn page(
+        items: &[u8],
+        next_cursor: Option<&str>,
+    ) -> Result<NetworkResponse> {
+        Ok(NetworkResponse::new(
+            200,
+            serde_json::to_vec(
+                &json!({ "items": items, "next_cursor": next_cursor }),
+            )
+            .unwrap(),
+        ))
+    }
+
+    fn make_sut(
+        driver: Arc<SequenceNetworkingDriver>,
+        max_page_count: u32,
+        limit_per_page: impl Into<Option<u32>>,
+    ) -> GatewayClient {
+        GatewayClient::new(driver, Gateway::mainnet())
+            .with_pagination_options(PaginationOptions::new(
+                max_page_count,
+                limit_per_page,
+            ))
+            .with_retry_policy(RetryPolicy::new(3, 0, 0, 0, [429, 503]))
+    }
+
+    #[test]
+    fn fetch_all_follows_cursors() {
+        let driver = SequenceNetworkingDriver::new(vec![
+            page(&[1, 2], Some("c1")),
+            page(&[3], Some("c2")),
+            page(&[4], None),
+        ]);
+        let sut = make_sut(driver.clone(), 10, None);
+        assert_eq!(sut.fetch_all(request()), Ok(vec![1, 2, 3, 4]));
+        assert_eq!(
+            driver.request_bodies(),
+            vec![
+                json!({ "address": "abc" }),
+                json!({ "address": "abc", "cursor": "c1" }),
+                json!({ "address": "abc", "cursor": "c2" }),
+            ]
+        );
+    }
+
+    #[test]
+    fn request_url() {
+        let driver = SequenceNetworkingDriver::new(vec![page(&[], None)]);
+        let sut = make_sut(driver.clone(), 10, None);
+        sut.fetch_all(request()).unwrap();
+        assert_eq!(
+            driver.requests.read().unwrap()[0].url,
+            Url::parse("https://mainnet.radixdlt.com/numbers/page").unwrap()
+        );
+    }
+
+    #[test]
+    fn limit_per_page_is_sent() {
+        let driver = SequenceNetworkingDriver::new(vec![page(&[1], None)]);
+        let sut = make_sut(driver.clone(), 10, 50);
+        sut.fetch_all(request()).unwrap();
+        assert_eq!(
+            driver.request_bodies(),
+            vec![json!({ "address": "abc", "limit_per_page": 50 })]
+        );
+    }
+
+    #[test]
+    fn stream_is_lazy() {
+        let driver = SequenceNetworkingDriver::new(vec![
+            page(&[1, 2], Some("c1")),
+            page(&[3], None),
+        ]);
+        let sut = make_sut(driver.clone(), 10, None);
+        let first_two = sut.stream_all(request()).take(2).collect_vec();
+        assert_eq!(first_two, vec![Ok(1), Ok(2)]);
+        assert_eq!(driver.request_bodies().len(), 1);
+    }
+
+    #[test]
+    fn page_limit_exceeded() {
+        let driver = SequenceNetworkingDriver::new(vec![
+            page(&[1], Some("c1")),
+            page(&[2], Some("c2")),
+        ]);
+        let sut = make_sut(driver.clone(), 2, None);
+        assert_eq!(
+            sut.stream_all(request()).collect_vec(),
+            vec![
+                Ok(1),
+                Ok(2),
+                Err(CommonError::GatewayPageLimitExceeded {
+                    max_page_count: 2
+                })
+            ]
+        );
+        assert_eq!(driver.request_bodies().len(), 2);
+    }
+
+    #[test]
+    fn transient_failures_are_retried() {
+        let driver = SequenceNetworkingDriver::new(vec![
+            page(&[1], Some("c1")),
+            Ok(NetworkResponse::new(503, Vec::new())),
+            Err(CommonError::Unknown),
+            page(&[2], None),
+        ]);
+        let sut = make_sut(driver.clone(), 10, None);
+        assert_eq!(sut.fetch_all(request()), Ok(vec![1, 2]));
+        assert_eq!(driver.request_bodies().len(), 4);
+    }
+
+    #[test]
+    fn gives_up_after_max_retries() {
+        let driver = SequenceNetworkingDriver::new(vec![
+            Ok(NetworkResponse::new(429, Vec::new())),
+            Ok(NetworkResponse::new(429, Vec::new())),
+            Ok(NetworkResponse::new(429, Vec::new())),
+            page(&[1], None),
+        ]);
+        let sut = make_sut(driver.clone(), 10, None);
+        assert_eq!(
+            sut.fetch_all(request()),
+            Err(CommonError::NetworkResponseBadCode { code: 429 })
+        );
+        assert_eq!(driver.request_bodies().len(), 3);
+    }
+
+    #[test]
+    fn client_errors_are_not_retried() {
+        let driver = SequenceNetworkingDriver::new(vec![Ok(
+            NetworkResponse::new(400, Vec::new()),
+        )]);
+        let sut = make_sut(driver.clone(), 10, None);
+        assert_eq!(
+            sut.fetch_all(request()),
+            Err(CommonError::NetworkResponseBadCode { code: 400 })
+        );
+        assert_eq!(driver.request_bodies().len(), 1);
+    }
+
+    #[test]
+    fn stream_ends_after_error() {
+        let driver = SequenceNetworkingDriver::new(vec![
+            page(&[1], Some("c1")),
+            Ok(NetworkResponse::new(400, Vec::new())),
+            page(&[2], None),
+        ]);
+        let sut = make_sut(driver.clone(), 10, None);
+        assert_eq!(
+            sut.stream_all(request()).collect_vec(),
+            vec![
+                Ok(1),
+                Err(CommonError::NetworkResponseBadCode { code: 400 })
+            ]
+        );
+    }
+
+    #[test]
+    fn per_call_retry_policy_override() {
+        let driver = SequenceNetworkingDriver::new(vec![
+            Ok(NetworkResponse::new(503, Vec::new())),
+            page(&[1], None),
+        ]);
+        let sut = make_sut(driver.clone(), 10, None);
+        assert_eq!(
+            sut.fetch_all_with_retry_policy(
+                request(),
+                RetryPolicy::no_retries()
+            ),
+            Err(CommonError::NetworkResponseBadCode { code: 503 })
+        );
+        assert_eq!(driver.request_bodies().len(), 1);
+    }
+
+    #[test]
+    fn failures_are_observed() {
+        let driver = SequenceNetworkingDriver::new(vec![
+            Ok(NetworkResponse::new(503, Vec::new())),
+            page(&[1], None),
+        ]);
+        let observer = Arc::new(RecordingFailureObserver::default());
+        let sut =
+            make_sut(driver, 10, None).with_failure_observer(observer.clone());
+        assert_eq!(sut.fetch_all(request()), Ok(vec![1]));
+        assert_eq!(
+            observer
+                .failures()
+                .into_iter()
+                .map(|f| (f.url.path().to_owned(), f.status_code, f.will_retry))
+                .collect_vec(),
+            vec![("/numbers/page".to_owned(), Some(503), true)]
+        );
+    }
+
+    #[test]
+    fn offline_mode_fails_without_request() {
+        let driver = SequenceNetworkingDriver::new(vec![page(&[1], None)]);
+        let offline_mode = Arc::new(OfflineMode::default());
+        let sut = make_sut(driver.clone(), 10, None)
+            .with_offline_mode(offline_mode.clone());
+        offline_mode.set_enabled(true);
+        assert_eq!(
+            sut.fetch_all(request()),
+            Err(CommonError::FeatureUnavailableOffline(
+                WalletCapability::GatewayQueries
+            ))
+        );
+        assert!(driver.request_bodies().is_empty());
+
+        offline_mode.set_enabled(false);
+        assert_eq!(sut.fetch_all(request()), Ok(vec![1]));
+    }
+
+    #[test]
+    fn gateway_calls_are_measured() {
+        let driver = SequenceNetworkingDriver::new(vec![
+            page(&[1], Some("c1")),
+            page(&[2], None),
+        ]);
+        let sut = make_sut(driver, 10, None);
+        let metrics = Arc::new(RecordingMetricsDriver::default());
+        with_metrics_driver(metrics.clone(), || sut.fetch_all(request()))
+            .unwrap();
+        assert_eq!(
+            metrics.recorded(),
+            vec![
+                (MeasuredOperation::GatewayCall, "numbers/page".to_owned()),
+                (MeasuredOperation::GatewayCall, "numbers/page".to_owned())
+            ]
+        );
+    }
+}
@@ -0,0 +1,352 @@
+use crate::prelude::*;
+
+/// Everything an Account holds, aggregated from the vaults of the Account
+/// as returned by the Gateway, so that portfolio logic - e.g. totals and the
+/// XRD balance of fee payers - is shared by all Wallet Clients.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct AccountHoldings {
+    pub account_address: AccountAddress,
+
+    /// One entry per fungible resource with a non zero amount, in the order
+    /// returned by the Gateway.
+    pub fungibles: Vec<FungibleResourceHolding>,
+
+    /// One entry per non-fungible resource with at least one non-fungible,
+    /// in the order returned by the Gateway.
+    pub non_fungibles: Vec<NonFungibleResourceHolding>,
+}
+
+impl AccountHoldings {
+    pub fn new(
+        account_address: AccountAddress,
+        fungibles: impl IntoIterator<Item = FungibleResourceHolding>,
+        non_fungibles: impl IntoIterator<Item = NonFungibleResourceHolding>,
+    ) -> Self {
+        Self {
+            account_address,
+            fungibles: fungibles.into_iter().collect(),
+            non_fungibles: non_fungibles.into_iter().collect(),
+        }
+    }
+
+    /// Aggregates the vaults of each resource of `details`, omitting
+    /// resources of which the Account holds nothing.
+    pub fn from_gateway(details: GatewayAccountDetails) -> Self {
+        let fungibles = details
+            .fungible_resources
+            .items
+            .into_iter()
+            .map(FungibleResourceHolding::from_gateway)
+            .filter(|h| !h.amount.is_zero());
+        let non_fungibles = details
+            .non_fungible_resources
+            .items
+            .into_iter()
+            .map(NonFungibleResourceHolding::from_gateway)
+            .filter(|h| h.total_count > 0);
+        Self::new(details.address, fungibles, non_fungibles)
+    }
+
+    /// Parses the JSON of a `state/entity/details` Gateway response item.
+    pub fn from_gateway_json(json: impl AsRef<str>) -> Result<Self> {
+        let json = json.as_ref();
+        serde_json::from_str::<GatewayAccountDetails>(json)
+            .map(Self::from_gateway)
+            .map_err(|_| CommonError::FailedToDeserializeJSONToValue {
+                json_byte_count: json.len(),
+                type_name: "GatewayAccountDetails".to_string(),
+            })
+    }
+
+    /// The amount of the fungible resource with `resource_address` the
+    /// Account holds, zero if none.
+    pub fn amount_of(&self, resource_address: &ResourceAddress) -> Decimal {
+        self.fungibles
+            .iter()
+            .find(|h| &h.resource_address == resource_address)
+            .map(|h| h.amount.clone())
+            .unwrap_or_else(Decimal::zero)
+    }
+
+    /// The amount of XRD the Account holds, zero if none.
+    pub fn xrd_balance(&self) -> Decimal {
+        self.amount_of(&ResourceAddress::xrd_on_network(
+            self.account_address.network_id,
+        ))
+    }
+
+    /// `true` if the Account holds at least `fee` XRD.
+    pub fn can_pay_fee(&self, fee: &Decimal) -> bool {
+        &self.xrd_balance() >= fee
+    }
+
+    pub fn liquid_stake_units(&self) -> Vec<FungibleResourceHolding> {
+        self.fungibles
+            .iter()
+            .filter(|h| {
+                matches!(h.kind, FungibleResourceKind::LiquidStakeUnit { .. })
+            })
+            .cloned()
+            .collect()
+    }
+
+    pub fn pool_units(&self) -> Vec<FungibleResourceHolding> {
+        self.fungibles
+            .iter()
+            .filter(|h| matches!(h.kind, FungibleResourceKind::PoolUnit { .. }))
+            .cloned()
+            .collect()
+    }
+
+    pub fn stake_claims(&self) -> Vec<NonFungibleResourceHolding> {
+        self.non_fungibles
+            .iter()
+            .filter(|h| {
+                matches!(h.kind, NonFungibleResourceKind::StakeClaim { .. })
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// The total amount of each fungible resource held by `holdings`, in
+    /// the order the resources are first found.
+    pub fn portfolio_fungible_totals(
+        holdings: &[Self],
+    ) -> Vec<FungibleResourceHolding> {
+        let mut totals = Vec::<FungibleResourceHolding>::new();
+        for holding in holdings.iter().flat_map(|h| h.fungibles.iter()) {
+            match totals
+                .iter_mut()
+                .find(|t| t.resource_address == holding.resource_address)
+            {
+                Some(total) => {
+                    total.amount = total.amount.clone() + holding.amount.clone()
+                }
+                None => totals.push(holding.clone()),
+            }
+        }
+        totals
+    }
+
+    /// The Accounts of `holdings` which can pay `fee`, with the largest XRD
+    /// balance first.
+    pub fn fee_payer_candidates(
+        holdings: &[Self],
+        fee: &Decimal,
+    ) -> Vec<AccountAddress> {
+        holdings
+            .iter()
+            .filter(|h| h.can_pay_fee(fee))
+            .sorted_by(|a, b| b.xrd_balance().cmp(&a.xrd_balance()))
+            .map(|h| h.account_address.clone())
+            .collect()
+    }
+}
+
+#[uniffi::export]
+pub fn new_account_holdings_from_gateway_json(
+    json: String,
+) -> Result<AccountHoldings> {
+    AccountHoldings::from_gateway_json(json)
+}
+
+#[uniffi::export]
+pub fn account_holdings_xrd_balance(holdings: &AccountHoldings) -> Decimal {
+    holdings.xrd_balance()
+}
+
+#[uniffi::export]
+pub fn account_holdings_portfolio_fungible_totals(
+    holdings: Vec<AccountHoldings>,
+) -> Vec<FungibleResourceHolding> {
+    AccountHoldings::portfolio_fungible_totals(&holdings)
+}
+
+#[uniffi::export]
+pub fn account_holdings_fee_payer_candidates(
+    holdings: Vec<AccountHoldings>,
+    fee: Decimal,
+) -> Vec<AccountAddress> {
+    AccountHoldings::fee_payer_candidates(&holdings, &fee)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn xrd() -> ResourceAddress {
+        ResourceAddress::xrd_on_network(NetworkID::Mainnet)
+    }
+
+    fn lsu() -> ResourceAddress {
+        "resource_rdx1tkk83magp3gjyxrpskfsqwkg4g949rmcjee4tu2xmw93ltw2cz94sq"
+            .parse()
+            .unwrap()
+    }
+
+    fn dec(s: &str) -> Decimal {
+        Decimal::try_from_str(s).unwrap()
+    }
+
+    fn gateway_json(
+        address: &AccountAddress,
+        xrd_vault_amounts: &[&str],
+    ) -> String {
+        json!({
+            "address": address.to_string(),
+            "fungible_resources": {
+                "items": [
+                    {
+                        "resource_address": xrd().to_string(),
+                        "vaults": {
+                            "items": xrd_vault_amounts
+                                .iter()
+                                .map(|a| json!({ "amount": a }))
+                                .collect_vec()
+                        }
+                    },
+                    {
+                        "resource_address": lsu().to_string(),
+                        "explicit_metadata": {
+                            "items": [{
+                                "key": "validator",
+                                "value": {
+                                    "typed": {
+                                        "type": "GlobalAddress",
+                                        "value": "validator_rdx1abc"
+                                    }
+                                },
+                                "is_locked": true
+                            }]
+                        },
+                        "vaults": { "items": [{ "amount": "10" }] }
+                    }
+                ]
+            },
+            "non_fungible_resources": {
+                "items": [{
+                    "resource_address": "resource_rdx1n2ekdd2m0jsxjt9wasmu3p49twy2yfalpaa6wf08md46sk8dfmldnd",
+                    "vaults": { "items": [{ "total_count": 0, "items": [] }] }
+                }]
+            }
+        })
+        .to_string()
+    }
+
+    fn make_sut(
+        address: AccountAddress,
+        xrd_vault_amounts: &[&str],
+    ) -> AccountHoldings {
+        new_account_holdings_from_gateway_json(gateway_json(
+            &address,
+            xrd_vault_amounts,
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn from_gateway_json() {
+        let sut = make_sut(AccountAddress::placeholder_mainnet(), &["1", "2"]);
+        assert_eq!(sut.account_address, AccountAddress::placeholder_mainnet());
+        assert_eq!(
+            sut.fungibles,
+            vec![
+                FungibleResourceHolding::new(
+                    xrd(),
+                    dec("3"),
+                    FungibleResourceKind::Regular
+                ),
+                FungibleResourceHolding::new(
+                    lsu(),
+                    dec("10"),
+                    FungibleResourceKind::LiquidStakeUnit {
+                        validator: "validator_rdx1abc".to_owned()
+                    }
+                )
+            ]
+        );
+        assert!(sut.non_fungibles.is_empty());
+    }
+
+    #[test]
+    fn zero_amounts_are_omitted() {
+        let sut = make_sut(AccountAddress::placeholder_mainnet(), &["0"]);
+        assert_eq!(sut.fungibles.len(), 1);
+        assert_eq!(sut.fungibles[0].resource_address, lsu());
+    }
+
+    #[test]
+    fn from_invalid_json() {
+        assert_eq!(
+            new_account_holdings_from_gateway_json("{}".to_owned()),
+            Err(CommonError::FailedToDeserializeJSONToValue {
+                json_byte_count: 2,
+                type_name: "GatewayAccountDetails".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn xrd_balance() {
+        let sut = make_sut(AccountAddress::placeholder_mainnet(), &["1.5"]);
+        assert_eq!(account_holdings_xrd_balance(&sut), dec("1.5"));
+        assert!(sut.can_pay_fee(&dec("1.5")));
+        assert!(!sut.can_pay_fee(&dec("1.6")));
+    }
+
+    #[test]
+    fn xrd_balance_is_zero_without_xrd() {
+        let sut =
+            AccountHoldings::new(AccountAddress::placeholder_mainnet(), [], []);
+        assert_eq!(sut.xrd_balance(), Decimal::zero());
+    }
+
+    #[test]
+    fn units_and_claims() {
+        let sut = make_sut(AccountAddress::placeholder_mainnet(), &["1"]);
+        assert_eq!(
+            sut.liquid_stake_units()
+                .into_iter()
+                .map(|h| h.resource_address)
+                .collect_vec(),
+            vec![lsu()]
+        );
+        assert!(sut.pool_units().is_empty());
+        assert!(sut.stake_claims().is_empty());
+    }
+
+    #[test]
+    fn portfolio_fungible_totals() {
+        let holdings = vec![
+            make_sut(AccountAddress::placeholder_mainnet(), &["1"]),
+            make_sut(AccountAddress::placeholder_mainnet_other(), &["2.5"]),
+        ];
+        let totals = account_holdings_portfolio_fungible_totals(holdings);
+        assert_eq!(
+            totals
+                .into_iter()
+                .map(|t| (t.resource_address, t.amount))
+                .collect_vec(),
+            vec![(xrd(), dec("3.5")), (lsu(), dec("20"))]
+        );
+    }
+
+    #[test]
+    fn fee_payer_candidates() {
+        let holdings = vec![
+            make_sut(AccountAddress::placeholder_mainnet(), &["1"]),
+            make_sut(AccountAddress::placeholder_mainnet_other(), &["5"]),
+        ];
+        assert_eq!(
+            account_holdings_fee_payer_candidates(holdings.clone(), dec("0.5")),
+            vec![
+                AccountAddress::placeholder_mainnet_other(),
+                AccountAddress::placeholder_mainnet()
+            ]
+        );
+        assert_eq!(
+            account_holdings_fee_payer_candidates(holdings, dec("2")),
+            vec![AccountAddress::placeholder_mainnet_other()]
+        );
+    }
+}
@@ -0,0 +1,149 @@
+use crate::prelude::*;
+
+/// What a fungible resource represents, as claimed by its metadata.
+///
+/// N.B. the claims are not verified against the validator or pool, which
+/// requires their metadata to point back to the resource.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Enum)]
+pub enum FungibleResourceKind {
+    /// A regular token, e.g. XRD.
+    Regular,
+
+    /// A liquid stake unit, received when staking XRD to `validator`.
+    LiquidStakeUnit { validator: String },
+
+    /// A pool unit, received when contributing liquidity to `pool`.
+    PoolUnit { pool: String },
+}
+
+impl FungibleResourceKind {
+    /// The kind claimed by `metadata`, metadata of unexpected type is
+    /// ignored since any resource is free to set any metadata.
+    pub fn from_metadata(metadata: &EntityMetadataCollection) -> Self {
+        if let Some(validator) = metadata.validator().ok().flatten() {
+            Self::LiquidStakeUnit { validator }
+        } else if let Some(pool) = metadata.pool().ok().flatten() {
+            Self::PoolUnit { pool }
+        } else {
+            Self::Regular
+        }
+    }
+}
+
+/// The total amount of a fungible resource held by an Account, across all
+/// vaults of the Account.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct FungibleResourceHolding {
+    pub resource_address: ResourceAddress,
+    pub amount: Decimal,
+    pub kind: FungibleResourceKind,
+}
+
+impl FungibleResourceHolding {
+    pub fn new(
+        resource_address: ResourceAddress,
+        amount: Decimal,
+        kind: FungibleResourceKind,
+    ) -> Self {
+        Self {
+            resource_address,
+            amount,
+            kind,
+        }
+    }
+
+    /// Sums the amounts of all vaults of `resource`.
+    pub fn from_gateway(resource: GatewayFungibleResource) -> Self {
+        let kind = resource
+            .explicit_metadata
+            .as_ref()
+            .map(FungibleResourceKind::from_metadata)
+            .unwrap_or(FungibleResourceKind::Regular);
+        let amount = resource.vaults.items.into_iter().map(|v| v.amount).sum();
+        Self::new(resource.resource_address, amount, kind)
+    }
+
+    pub fn is_xrd(&self) -> bool {
+        self.resource_address.is_xrd()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn metadata(key: MetadataKey, address: &str) -> EntityMetadataCollection {
+        EntityMetadataCollection::new([EntityMetadataItem::new(
+            key,
+            MetadataTypedValue::GlobalAddressValue {
+                value: address.to_owned(),
+            },
+            true,
+        )])
+    }
+
+    #[test]
+    fn kind_regular() {
+        assert_eq!(
+            FungibleResourceKind::from_metadata(
+                &EntityMetadataCollection::placeholder_other()
+            ),
+            FungibleResourceKind::Regular
+        );
+    }
+
+    #[test]
+    fn kind_liquid_stake_unit() {
+        assert_eq!(
+            FungibleResourceKind::from_metadata(&metadata(
+                MetadataKey::Validator,
+                "validator_rdx1abc"
+            )),
+            FungibleResourceKind::LiquidStakeUnit {
+                validator: "validator_rdx1abc".to_owned()
+            }
+        );
+    }
+
+    #[test]
+    fn kind_pool_unit() {
+        assert_eq!(
+            FungibleResourceKind::from_metadata(&metadata(
+                MetadataKey::Pool,
+                "pool_rdx1abc"
+            )),
+            FungibleResourceKind::PoolUnit {
+                pool: "pool_rdx1abc".to_owned()
+            }
+        );
+    }
+
+    #[test]
+    fn kind_ignores_unexpected_metadata_type() {
+        let metadata =
+            EntityMetadataCollection::new([EntityMetadataItem::new(
+                MetadataKey::Validator,
+                MetadataTypedValue::StringValue {
+                    value: "validator_rdx1abc".to_owned(),
+                },
+                false,
+            )]);
+        assert_eq!(
+            FungibleResourceKind::from_metadata(&metadata),
+            FungibleResourceKind::Regular
+        );
+    }
+
+    #[test]
+    fn from_gateway_sums_vaults() {
+        let resource = serde_json::from_value::<GatewayFungibleResource>(json!({
+            "resource_address": "resource_rdx1tknxxxxxxxxxradxrdxxxxxxxxx009923554798xxxxxxxxxradxrd",
+            "vaults": { "items": [{ "amount": "1.5" }, { "amount": "2" }] }
+        }))
+        .unwrap();
+        let sut = FungibleResourceHolding::from_gateway(resource);
+        assert_eq!(sut.amount, Decimal::try_from_str("3.5").unwrap());
+        assert_eq!(sut.kind, FungibleResourceKind::Regular);
+        assert!(sut.is_xrd());
+    }
+}
@@ -0,0 +1,131 @@
+use crate::prelude::*;
+
+/// The amount of a fungible resource in one vault of an entity, as returned
+/// by the Gateway.
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct GatewayFungibleVault {
+    pub amount: Decimal,
+}
+
+/// A fungible resource held by an entity, as returned by the Gateway when
+/// details are requested with `"aggregation_level": "Vault"`.
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct GatewayFungibleResource {
+    pub resource_address: ResourceAddress,
+
+    /// Only present if metadata keys were requested as `explicit_metadata`.
+    #[serde(default)]
+    pub explicit_metadata: Option<EntityMetadataCollection>,
+
+    pub vaults: PagedResponse<GatewayFungibleVault>,
+}
+
+/// The non-fungibles of a resource in one vault of an entity, as returned
+/// by the Gateway, `items` is only the first page of ids of the vault.
+#[serde_as]
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct GatewayNonFungibleVault {
+    pub total_count: u64,
+
+    /// Only present if ids were requested with `non_fungible_include_nfids`.
+    #[serde_as(as = "Vec<DisplayFromStr>")]
+    #[serde(default)]
+    pub items: Vec<NonFungibleLocalId>,
+}
+
+/// A non-fungible resource held by an entity, as returned by the Gateway
+/// when details are requested with `"aggregation_level": "Vault"`.
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct GatewayNonFungibleResource {
+    pub resource_address: ResourceAddress,
+
+    /// Only present if metadata keys were requested as `explicit_metadata`.
+    #[serde(default)]
+    pub explicit_metadata: Option<EntityMetadataCollection>,
+
+    pub vaults: PagedResponse<GatewayNonFungibleVault>,
+}
+
+/// The details of an Account as returned by the `state/entity/details`
+/// endpoint of the Gateway, limited to the resources it holds.
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct GatewayAccountDetails {
+    pub address: AccountAddress,
+
+    pub fungible_resources: PagedResponse<GatewayFungibleResource>,
+
+    pub non_fungible_resources: PagedResponse<GatewayNonFungibleResource>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn decode() {
+        let json = json!({
+            "address": "account_rdx16xlfcpp0vf7e3gqnswv8j9k58n6rjccu58vvspmdva22kf3aplease",
+            "fungible_resources": {
+                "total_count": 1,
+                "items": [{
+                    "aggregation_level": "Vault",
+                    "resource_address": "resource_rdx1tknxxxxxxxxxradxrdxxxxxxxxx009923554798xxxxxxxxxradxrd",
+                    "vaults": {
+                        "total_count": 1,
+                        "items": [{
+                            "vault_address": "internal_vault_rdx1tz474x29nxxd4k2p2reete9xyz4apawv63dphxkr00qt23vyju49fq",
+                            "amount": "1337.5",
+                            "last_updated_at_state_version": 1
+                        }]
+                    }
+                }]
+            },
+            "non_fungible_resources": {
+                "items": [{
+                    "aggregation_level": "Vault",
+                    "resource_address": "resource_rdx1n2ekdd2m0jsxjt9wasmu3p49twy2yfalpaa6wf08md46sk8dfmldnd",
+                    "vaults": {
+                        "items": [{
+                            "vault_address": "internal_vault_rdx1nz474x29nxxd4k2p2reete9xyz4apawv63dphxkr00qt23vyju49fq",
+                            "total_count": 2,
+                            "items": ["#1#", "<foo>"]
+                        }]
+                    }
+                }]
+            }
+        });
+        let sut =
+            serde_json::from_value::<GatewayAccountDetails>(json).unwrap();
+        assert_eq!(sut.address, AccountAddress::placeholder_mainnet());
+        assert_eq!(
+            sut.fungible_resources.items[0].vaults.items[0].amount,
+            Decimal::try_from_str("1337.5").unwrap()
+        );
+        assert_eq!(sut.fungible_resources.items[0].explicit_metadata, None);
+        assert_eq!(
+            sut.non_fungible_resources.items[0].vaults.items[0].items,
+            vec![
+                NonFungibleLocalId::integer(1),
+                NonFungibleLocalId::string("foo").unwrap()
+            ]
+        );
+    }
+
+    #[test]
+    fn decode_vault_without_ids() {
+        let sut = serde_json::from_value::<GatewayNonFungibleVault>(
+            json!({ "total_count": 3 }),
+        )
+        .unwrap();
+        assert_eq!(sut.total_count, 3);
+        assert!(sut.items.is_empty());
+    }
+
+    #[test]
+    fn decode_invalid_local_id_fails() {
+        assert!(serde_json::from_value::<GatewayNonFungibleVault>(
+            json!({ "total_count": 1, "items": ["not an id"] }),
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,9 @@
+mod account_holdings;
+mod fungible_resource_holding;
+mod gateway_entity_details;
+mod non_fungible_resource_holding;
+
+pub use account_holdings::*;
+pub use fungible_resource_holding::*;
+pub use gateway_entity_details::*;
+pub use non_fungible_resource_holding::*;
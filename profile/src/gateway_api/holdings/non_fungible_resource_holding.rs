@@ -0,0 +1,148 @@
+use crate::prelude::*;
+
+/// What a non-fungible resource represents, as claimed by its metadata.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Enum)]
+pub enum NonFungibleResourceKind {
+    /// A regular collection of NFTs.
+    Regular,
+
+    /// Claims for XRD unstaked from `validator`, redeemable once the
+    /// unstaking delay has passed.
+    StakeClaim { validator: String },
+}
+
+impl NonFungibleResourceKind {
+    /// The kind claimed by `metadata`, metadata of unexpected type is
+    /// ignored since any resource is free to set any metadata.
+    pub fn from_metadata(metadata: &EntityMetadataCollection) -> Self {
+        match metadata.validator().ok().flatten() {
+            Some(validator) => Self::StakeClaim { validator },
+            None => Self::Regular,
+        }
+    }
+}
+
+/// The non-fungibles of a collection held by an Account, across all vaults
+/// of the Account.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct NonFungibleResourceHolding {
+    pub resource_address: ResourceAddress,
+
+    /// Total number of non-fungibles of the collection the Account holds,
+    /// which might be more than the number of loaded `ids`.
+    pub total_count: u64,
+
+    /// The ids of the non-fungibles loaded so far, in the order of the
+    /// vaults they are in.
+    pub ids: Vec<NonFungibleLocalId>,
+
+    pub kind: NonFungibleResourceKind,
+}
+
+impl NonFungibleResourceHolding {
+    pub fn new(
+        resource_address: ResourceAddress,
+        total_count: u64,
+        ids: impl IntoIterator<Item = NonFungibleLocalId>,
+        kind: NonFungibleResourceKind,
+    ) -> Self {
+        Self {
+            resource_address,
+            total_count,
+            ids: ids.into_iter().collect(),
+            kind,
+        }
+    }
+
+    /// Sums the counts and collects the ids of all vaults of `resource`.
+    pub fn from_gateway(resource: GatewayNonFungibleResource) -> Self {
+        let kind = resource
+            .explicit_metadata
+            .as_ref()
+            .map(NonFungibleResourceKind::from_metadata)
+            .unwrap_or(NonFungibleResourceKind::Regular);
+        let total_count =
+            resource.vaults.items.iter().map(|v| v.total_count).sum();
+        let ids = resource
+            .vaults
+            .items
+            .into_iter()
+            .flat_map(|v| v.items)
+            .unique()
+            .collect_vec();
+        Self::new(resource.resource_address, total_count, ids, kind)
+    }
+
+    /// `true` if not all ids have been loaded.
+    pub fn has_more_ids(&self) -> bool {
+        (self.ids.len() as u64) < self.total_count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn resource(vaults: serde_json::Value) -> GatewayNonFungibleResource {
+        serde_json::from_value(json!({
+            "resource_address": "resource_rdx1n2ekdd2m0jsxjt9wasmu3p49twy2yfalpaa6wf08md46sk8dfmldnd",
+            "vaults": { "items": vaults }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn kind_stake_claim() {
+        let metadata =
+            EntityMetadataCollection::new([EntityMetadataItem::new(
+                MetadataKey::Validator,
+                MetadataTypedValue::GlobalAddressValue {
+                    value: "validator_rdx1abc".to_owned(),
+                },
+                true,
+            )]);
+        assert_eq!(
+            NonFungibleResourceKind::from_metadata(&metadata),
+            NonFungibleResourceKind::StakeClaim {
+                validator: "validator_rdx1abc".to_owned()
+            }
+        );
+    }
+
+    #[test]
+    fn kind_regular() {
+        assert_eq!(
+            NonFungibleResourceKind::from_metadata(
+                &EntityMetadataCollection::default()
+            ),
+            NonFungibleResourceKind::Regular
+        );
+    }
+
+    #[test]
+    fn from_gateway_merges_vaults() {
+        let sut = NonFungibleResourceHolding::from_gateway(resource(json!([
+            { "total_count": 2, "items": ["#1#", "#2#"] },
+            { "total_count": 1, "items": ["#3#"] }
+        ])));
+        assert_eq!(sut.total_count, 3);
+        assert_eq!(
+            sut.ids,
+            vec![
+                NonFungibleLocalId::integer(1),
+                NonFungibleLocalId::integer(2),
+                NonFungibleLocalId::integer(3)
+            ]
+        );
+        assert!(!sut.has_more_ids());
+        assert_eq!(sut.kind, NonFungibleResourceKind::Regular);
+    }
+
+    #[test]
+    fn has_more_ids() {
+        let sut = NonFungibleResourceHolding::from_gateway(resource(json!([
+            { "total_count": 5, "items": ["#1#"] }
+        ])));
+        assert!(sut.has_more_ids());
+    }
+}
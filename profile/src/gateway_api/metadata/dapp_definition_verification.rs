@@ -0,0 +1,303 @@
+use crate::prelude::*;
+
+/// The `account_type` metadata value of a dApp definition account.
+pub const DAPP_DEFINITION_ACCOUNT_TYPE: &str = "dapp definition";
+
+impl EntityMetadataCollection {
+    /// `true` if this is the metadata of a dApp definition account, i.e.
+    /// its `account_type` is `"dapp definition"`.
+    pub fn is_dapp_definition(&self) -> bool {
+        self.verify_is_dapp_definition().is_ok()
+    }
+
+    /// Verifies that this is the metadata of a dApp definition account.
+    pub fn verify_is_dapp_definition(&self) -> Result<()> {
+        let account_type = self.account_type()?;
+        if account_type.as_deref() == Some(DAPP_DEFINITION_ACCOUNT_TYPE) {
+            Ok(())
+        } else {
+            Err(CommonError::DappDefinitionAccountTypeMismatch {
+                found: account_type,
+            })
+        }
+    }
+
+    /// Verifies the first half of the two-way link between a dApp definition
+    /// and a website: that this dApp definition claims the website with
+    /// `origin` in its `claimed_websites`.
+    ///
+    /// The other half is verified by
+    /// [`RadixWellKnownFile::verify_website_claims_dapp`].
+    pub fn verify_dapp_claims_website(&self, origin: &Url) -> Result<()> {
+        self.verify_is_dapp_definition()?;
+        if self
+            .claimed_websites()?
+            .iter()
+            .any(|w| w.origin() == origin.origin())
+        {
+            Ok(())
+        } else {
+            Err(CommonError::DappDefinitionDoesNotClaimWebsite {
+                origin: origin.origin().ascii_serialization(),
+            })
+        }
+    }
+}
+
+/// A dApp listed in the `.well-known/radix.json` file of a website.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+pub struct RadixWellKnownDapp {
+    #[serde(rename = "dAppDefinitionAddress")]
+    pub dapp_definition_address: AccountAddress,
+}
+
+/// The contents of the `.well-known/radix.json` file hosted by a website,
+/// listing the dApp definitions the website claims.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+pub struct RadixWellKnownFile {
+    #[serde(rename = "dApps")]
+    pub dapps: Vec<RadixWellKnownDapp>,
+}
+
+impl RadixWellKnownFile {
+    /// Parses the contents of a `.well-known/radix.json` file.
+    pub fn from_json(json: impl AsRef<str>) -> Result<Self> {
+        let json = json.as_ref();
+        serde_json::from_str(json).map_err(|_| {
+            CommonError::FailedToDeserializeJSONToValue {
+                json_byte_count: json.len(),
+                type_name: "RadixWellKnownFile".to_string(),
+            }
+        })
+    }
+
+    /// Verifies the second half of the two-way link between a dApp
+    /// definition and a website: that the website hosting this file claims
+    /// the dApp definition with `dapp_definition_address`.
+    ///
+    /// The other half is verified by
+    /// [`EntityMetadataCollection::verify_dapp_claims_website`].
+    pub fn verify_website_claims_dapp(
+        &self,
+        dapp_definition_address: &AccountAddress,
+    ) -> Result<()> {
+        if self
+            .dapps
+            .iter()
+            .any(|d| d.dapp_definition_address == *dapp_definition_address)
+        {
+            Ok(())
+        } else {
+            Err(CommonError::WebsiteDoesNotClaimDappDefinition {
+                dapp_definition_address: dapp_definition_address.to_string(),
+            })
+        }
+    }
+}
+
+impl HasPlaceholder for RadixWellKnownFile {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self {
+            dapps: vec![RadixWellKnownDapp {
+                dapp_definition_address: AccountAddress::placeholder(),
+            }],
+        }
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self {
+            dapps: vec![RadixWellKnownDapp {
+                dapp_definition_address: AccountAddress::placeholder_other(),
+            }],
+        }
+    }
+}
+
+#[uniffi::export]
+pub fn entity_metadata_collection_verify_dapp_claims_website(
+    metadata: &EntityMetadataCollection,
+    origin: Url,
+) -> Result<()> {
+    metadata.verify_dapp_claims_website(&origin)
+}
+
+#[uniffi::export]
+pub fn new_radix_well_known_file_from_json(
+    json: String,
+) -> Result<RadixWellKnownFile> {
+    RadixWellKnownFile::from_json(json)
+}
+
+#[uniffi::export]
+pub fn radix_well_known_file_verify_website_claims_dapp(
+    well_known_file: &RadixWellKnownFile,
+    dapp_definition_address: &AccountAddress,
+) -> Result<()> {
+    well_known_file.verify_website_claims_dapp(dapp_definition_address)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn dashboard() -> Url {
+        Url::parse("https://dashboard.radixdlt.com").unwrap()
+    }
+
+    #[test]
+    fn is_dapp_definition() {
+        assert!(EntityMetadataCollection::placeholder().is_dapp_definition());
+        assert!(
+            !EntityMetadataCollection::placeholder_other().is_dapp_definition()
+        );
+    }
+
+    #[test]
+    fn verify_is_dapp_definition_missing_account_type() {
+        assert_eq!(
+            EntityMetadataCollection::placeholder_other()
+                .verify_is_dapp_definition(),
+            Err(CommonError::DappDefinitionAccountTypeMismatch { found: None })
+        );
+    }
+
+    #[test]
+    fn verify_is_dapp_definition_wrong_account_type() {
+        let sut = EntityMetadataCollection::new([EntityMetadataItem::new(
+            MetadataKey::AccountType,
+            MetadataTypedValue::StringValue {
+                value: "savings".to_owned(),
+            },
+            true,
+        )]);
+        assert_eq!(
+            sut.verify_is_dapp_definition(),
+            Err(CommonError::DappDefinitionAccountTypeMismatch {
+                found: Some("savings".to_owned())
+            })
+        );
+    }
+
+    #[test]
+    fn dapp_claims_website() {
+        assert_eq!(
+            EntityMetadataCollection::placeholder()
+                .verify_dapp_claims_website(&dashboard()),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn dapp_claims_website_ignores_path() {
+        assert_eq!(
+            EntityMetadataCollection::placeholder().verify_dapp_claims_website(
+                &Url::parse("https://dashboard.radixdlt.com/network-staking")
+                    .unwrap()
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn dapp_does_not_claim_website() {
+        assert_eq!(
+            EntityMetadataCollection::placeholder().verify_dapp_claims_website(
+                &Url::parse("https://evil.com").unwrap()
+            ),
+            Err(CommonError::DappDefinitionDoesNotClaimWebsite {
+                origin: "https://evil.com".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn not_a_dapp_definition_does_not_claim_website() {
+        assert_eq!(
+            EntityMetadataCollection::placeholder_other()
+                .verify_dapp_claims_website(&dashboard()),
+            Err(CommonError::DappDefinitionAccountTypeMismatch { found: None })
+        );
+    }
+
+    #[test]
+    fn well_known_file_json() {
+        let sut = RadixWellKnownFile::from_json(
+            r#"
+            {
+                "dApps": [
+                    {
+                        "dAppDefinitionAddress": "account_rdx16xlfcpp0vf7e3gqnswv8j9k58n6rjccu58vvspmdva22kf3aplease"
+                    }
+                ]
+            }
+            "#,
+        )
+        .unwrap();
+        assert_eq!(sut, RadixWellKnownFile::placeholder());
+    }
+
+    #[test]
+    fn well_known_file_invalid_json() {
+        assert_eq!(
+            RadixWellKnownFile::from_json("{}"),
+            Err(CommonError::FailedToDeserializeJSONToValue {
+                json_byte_count: 2,
+                type_name: "RadixWellKnownFile".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn website_claims_dapp() {
+        assert_eq!(
+            RadixWellKnownFile::placeholder()
+                .verify_website_claims_dapp(&AccountAddress::placeholder()),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn website_does_not_claim_dapp() {
+        assert_eq!(
+            RadixWellKnownFile::placeholder_other()
+                .verify_website_claims_dapp(&AccountAddress::placeholder()),
+            Err(CommonError::WebsiteDoesNotClaimDappDefinition {
+                dapp_definition_address: AccountAddress::placeholder()
+                    .to_string()
+            })
+        );
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn verify_dapp_claims_website() {
+        assert!(entity_metadata_collection_verify_dapp_claims_website(
+            &EntityMetadataCollection::placeholder(),
+            Url::parse("https://dashboard.radixdlt.com").unwrap()
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn verify_website_claims_dapp() {
+        let sut = new_radix_well_known_file_from_json(
+            serde_json::to_string(&RadixWellKnownFile::placeholder()).unwrap(),
+        )
+        .unwrap();
+        assert!(radix_well_known_file_verify_website_claims_dapp(
+            &sut,
+            &AccountAddress::placeholder()
+        )
+        .is_ok());
+    }
+}
@@ -0,0 +1,530 @@
+use crate::prelude::*;
+
+/// The metadata of an entity, e.g. a resource or a dApp definition account,
+/// as returned by the Gateway, with typed and validated accessors for the
+/// well known [`MetadataKey`]s.
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    uniffi::Record,
+)]
+pub struct EntityMetadataCollection {
+    pub items: Vec<EntityMetadataItem>,
+}
+
+impl EntityMetadataCollection {
+    pub fn new<I>(items: I) -> Self
+    where
+        I: IntoIterator<Item = EntityMetadataItem>,
+    {
+        Self {
+            items: items.into_iter().collect_vec(),
+        }
+    }
+
+    /// Parses the metadata from the JSON of a Gateway response.
+    pub fn from_json(json: impl AsRef<str>) -> Result<Self> {
+        let json = json.as_ref();
+        serde_json::from_str(json).map_err(|_| {
+            CommonError::FailedToDeserializeJSONToValue {
+                json_byte_count: json.len(),
+                type_name: "EntityMetadataCollection".to_string(),
+            }
+        })
+    }
+
+    fn item(&self, key: MetadataKey) -> Option<&EntityMetadataItem> {
+        let key = key.to_string();
+        self.items.iter().find(|i| i.key == key)
+    }
+
+    /// `true` if there is an item for `key` and it has been locked.
+    pub fn is_locked(&self, key: MetadataKey) -> bool {
+        self.item(key).map(|i| i.is_locked).unwrap_or(false)
+    }
+
+    fn unexpected_type(
+        key: MetadataKey,
+        expected: &str,
+        found: &MetadataTypedValue,
+    ) -> CommonError {
+        CommonError::GatewayMetadataUnexpectedValueType {
+            key: key.to_string(),
+            expected: expected.to_owned(),
+            found: found.type_name().to_owned(),
+        }
+    }
+
+    fn string(&self, key: MetadataKey) -> Result<Option<String>> {
+        let Some(item) = self.item(key) else {
+            return Ok(None);
+        };
+        match &item.value.typed {
+            MetadataTypedValue::StringValue { value } => {
+                Ok(Some(value.clone()))
+            }
+            other => Err(Self::unexpected_type(key, "String", other)),
+        }
+    }
+
+    fn strings(&self, key: MetadataKey) -> Result<Vec<String>> {
+        let Some(item) = self.item(key) else {
+            return Ok(Vec::new());
+        };
+        match &item.value.typed {
+            MetadataTypedValue::StringArrayValue { values } => {
+                Ok(values.clone())
+            }
+            other => Err(Self::unexpected_type(key, "StringArray", other)),
+        }
+    }
+
+    fn parse_url(key: MetadataKey, value: &str) -> Result<Url> {
+        parse_web_url(value).map_err(|_| {
+            CommonError::GatewayMetadataInvalidUrl {
+                key: key.to_string(),
+                value: value.to_owned(),
+            }
+        })
+    }
+
+    fn parse_origin(key: MetadataKey, value: &str) -> Result<Url> {
+        parse_origin(value).map_err(|_| {
+            CommonError::GatewayMetadataInvalidUrl {
+                key: key.to_string(),
+                value: value.to_owned(),
+            }
+        })
+    }
+
+    fn global_address(&self, key: MetadataKey) -> Result<Option<String>> {
+        let Some(item) = self.item(key) else {
+            return Ok(None);
+        };
+        match &item.value.typed {
+            MetadataTypedValue::GlobalAddressValue { value } => {
+                Ok(Some(value.clone()))
+            }
+            other => Err(Self::unexpected_type(key, "GlobalAddress", other)),
+        }
+    }
+
+    fn url(&self, key: MetadataKey) -> Result<Option<Url>> {
+        let Some(item) = self.item(key) else {
+            return Ok(None);
+        };
+        match &item.value.typed {
+            MetadataTypedValue::UrlValue { value } => {
+                Self::parse_url(key, value).map(Some)
+            }
+            other => Err(Self::unexpected_type(key, "Url", other)),
+        }
+    }
+
+    fn origins(&self, key: MetadataKey) -> Result<Vec<Url>> {
+        let Some(item) = self.item(key) else {
+            return Ok(Vec::new());
+        };
+        match &item.value.typed {
+            MetadataTypedValue::OriginArrayValue { values } => {
+                values.iter().map(|v| Self::parse_origin(key, v)).collect()
+            }
+            other => Err(Self::unexpected_type(key, "OriginArray", other)),
+        }
+    }
+
+    /// The `name` of the entity, if any.
+    pub fn name(&self) -> Result<Option<String>> {
+        self.string(MetadataKey::Name)
+    }
+
+    /// The `symbol` of the resource, if any.
+    pub fn symbol(&self) -> Result<Option<String>> {
+        self.string(MetadataKey::Symbol)
+    }
+
+    /// The `description` of the entity, if any.
+    pub fn description(&self) -> Result<Option<String>> {
+        self.string(MetadataKey::Description)
+    }
+
+    /// The `icon_url` of the entity, if any, failing if it is not a
+    /// valid URL.
+    pub fn icon_url(&self) -> Result<Option<Url>> {
+        self.url(MetadataKey::IconUrl)
+    }
+
+    /// The `tags` of the entity, empty if none.
+    pub fn tags(&self) -> Result<Vec<String>> {
+        self.strings(MetadataKey::Tags)
+    }
+
+    /// The `account_type` of the entity, if any.
+    pub fn account_type(&self) -> Result<Option<String>> {
+        self.string(MetadataKey::AccountType)
+    }
+
+    /// The `claimed_websites` of a dApp definition, empty if none, failing
+    /// if any of them is not a valid origin.
+    pub fn claimed_websites(&self) -> Result<Vec<Url>> {
+        self.origins(MetadataKey::ClaimedWebsites)
+    }
+
+    /// The `validator` of a liquid stake unit or stake claim resource, if
+    /// any.
+    pub fn validator(&self) -> Result<Option<String>> {
+        self.global_address(MetadataKey::Validator)
+    }
+
+    /// The `pool` of a pool unit resource, if any.
+    pub fn pool(&self) -> Result<Option<String>> {
+        self.global_address(MetadataKey::Pool)
+    }
+}
+
+/// Request for the pages of the metadata of the entity with `address`.
+#[derive(Serialize, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct EntityMetadataPageRequest {
+    pub address: String,
+}
+
+impl EntityMetadataPageRequest {
+    pub fn new(address: impl std::fmt::Display) -> Self {
+        Self {
+            address: address.to_string(),
+        }
+    }
+}
+
+impl GatewayPagedRequest for EntityMetadataPageRequest {
+    type Item = EntityMetadataItem;
+    const PATH: &'static str = "state/entity/metadata/page";
+}
+
+impl HasPlaceholder for EntityMetadataCollection {
+    /// A placeholder used to facilitate unit tests, the metadata of a
+    /// dApp definition.
+    fn placeholder() -> Self {
+        Self::new([
+            EntityMetadataItem::new(
+                MetadataKey::AccountType,
+                MetadataTypedValue::StringValue {
+                    value: DAPP_DEFINITION_ACCOUNT_TYPE.to_owned(),
+                },
+                true,
+            ),
+            EntityMetadataItem::new(
+                MetadataKey::Name,
+                MetadataTypedValue::StringValue {
+                    value: "Radix Dashboard".to_owned(),
+                },
+                false,
+            ),
+            EntityMetadataItem::new(
+                MetadataKey::ClaimedWebsites,
+                MetadataTypedValue::OriginArrayValue {
+                    values: vec!["https://dashboard.radixdlt.com".to_owned()],
+                },
+                false,
+            ),
+        ])
+    }
+
+    /// A placeholder used to facilitate unit tests, the metadata of a
+    /// fungible resource.
+    fn placeholder_other() -> Self {
+        Self::new([
+            EntityMetadataItem::new(
+                MetadataKey::Name,
+                MetadataTypedValue::StringValue {
+                    value: "Radix".to_owned(),
+                },
+                true,
+            ),
+            EntityMetadataItem::new(
+                MetadataKey::Symbol,
+                MetadataTypedValue::StringValue {
+                    value: "XRD".to_owned(),
+                },
+                true,
+            ),
+            EntityMetadataItem::new(
+                MetadataKey::IconUrl,
+                MetadataTypedValue::UrlValue {
+                    value:
+                        "https://assets.radixdlt.com/icons/icon-xrd-32x32.png"
+                            .to_owned(),
+                },
+                true,
+            ),
+            EntityMetadataItem::new(
+                MetadataKey::Tags,
+                MetadataTypedValue::StringArrayValue {
+                    values: vec!["official".to_owned(), "radix".to_owned()],
+                },
+                true,
+            ),
+        ])
+    }
+}
+
+#[uniffi::export]
+pub fn new_entity_metadata_collection_from_json(
+    json: String,
+) -> Result<EntityMetadataCollection> {
+    EntityMetadataCollection::from_json(json)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn page_request_json() {
+        assert_eq!(
+            serde_json::to_value(EntityMetadataPageRequest::new(
+                AccountAddress::placeholder()
+            ))
+            .unwrap(),
+            json!({ "address": AccountAddress::placeholder().to_string() })
+        );
+    }
+
+    #[test]
+    fn equality() {
+        assert_eq!(
+            EntityMetadataCollection::placeholder(),
+            EntityMetadataCollection::placeholder()
+        );
+        assert_eq!(
+            EntityMetadataCollection::placeholder_other(),
+            EntityMetadataCollection::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            EntityMetadataCollection::placeholder(),
+            EntityMetadataCollection::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn resource_accessors() {
+        let sut = EntityMetadataCollection::placeholder_other();
+        assert_eq!(sut.name(), Ok(Some("Radix".to_owned())));
+        assert_eq!(sut.symbol(), Ok(Some("XRD".to_owned())));
+        assert_eq!(sut.description(), Ok(None));
+        assert_eq!(
+            sut.icon_url(),
+            Ok(Some(
+                Url::parse(
+                    "https://assets.radixdlt.com/icons/icon-xrd-32x32.png"
+                )
+                .unwrap()
+            ))
+        );
+        assert_eq!(
+            sut.tags(),
+            Ok(vec!["official".to_owned(), "radix".to_owned()])
+        );
+    }
+
+    #[test]
+    fn is_locked() {
+        let sut = EntityMetadataCollection::placeholder();
+        assert!(sut.is_locked(MetadataKey::AccountType));
+        assert!(!sut.is_locked(MetadataKey::Name));
+        assert!(!sut.is_locked(MetadataKey::Symbol));
+    }
+
+    #[test]
+    fn missing_arrays_are_empty() {
+        let sut = EntityMetadataCollection::default();
+        assert_eq!(sut.tags(), Ok(Vec::new()));
+        assert_eq!(sut.claimed_websites(), Ok(Vec::new()));
+    }
+
+    #[test]
+    fn validator_and_pool() {
+        let validator = "validator_rdx1sd5368vqdmjk0y2w7ymdts02cz9c52858gpyny56xdvzuheepdeyy0";
+        let sut = EntityMetadataCollection::new([EntityMetadataItem::new(
+            MetadataKey::Validator,
+            MetadataTypedValue::GlobalAddressValue {
+                value: validator.to_owned(),
+            },
+            true,
+        )]);
+        assert_eq!(sut.validator(), Ok(Some(validator.to_owned())));
+        assert_eq!(sut.pool(), Ok(None));
+    }
+
+    #[test]
+    fn pool_of_unexpected_type() {
+        let sut = EntityMetadataCollection::new([EntityMetadataItem::new(
+            MetadataKey::Pool,
+            MetadataTypedValue::StringValue {
+                value: "pool".to_owned(),
+            },
+            true,
+        )]);
+        assert_eq!(
+            sut.pool(),
+            Err(CommonError::GatewayMetadataUnexpectedValueType {
+                key: "pool".to_owned(),
+                expected: "GlobalAddress".to_owned(),
+                found: "String".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn unexpected_value_type() {
+        let sut = EntityMetadataCollection::new([EntityMetadataItem::new(
+            MetadataKey::Name,
+            MetadataTypedValue::UrlValue {
+                value: "https://radixdlt.com".to_owned(),
+            },
+            false,
+        )]);
+        assert_eq!(
+            sut.name(),
+            Err(CommonError::GatewayMetadataUnexpectedValueType {
+                key: "name".to_owned(),
+                expected: "String".to_owned(),
+                found: "Url".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_icon_url() {
+        let sut = EntityMetadataCollection::new([EntityMetadataItem::new(
+            MetadataKey::IconUrl,
+            MetadataTypedValue::UrlValue {
+                value: "not a url".to_owned(),
+            },
+            false,
+        )]);
+        assert_eq!(
+            sut.icon_url(),
+            Err(CommonError::GatewayMetadataInvalidUrl {
+                key: "icon_url".to_owned(),
+                value: "not a url".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn claimed_website_with_path_is_invalid() {
+        let sut = EntityMetadataCollection::new([EntityMetadataItem::new(
+            MetadataKey::ClaimedWebsites,
+            MetadataTypedValue::OriginArrayValue {
+                values: vec!["https://radixdlt.com/dashboard".to_owned()],
+            },
+            false,
+        )]);
+        assert_eq!(
+            sut.claimed_websites(),
+            Err(CommonError::GatewayMetadataInvalidUrl {
+                key: "claimed_websites".to_owned(),
+                value: "https://radixdlt.com/dashboard".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn claimed_website_without_https_is_invalid() {
+        let sut = EntityMetadataCollection::new([EntityMetadataItem::new(
+            MetadataKey::ClaimedWebsites,
+            MetadataTypedValue::OriginArrayValue {
+                values: vec!["http://radixdlt.com".to_owned()],
+            },
+            false,
+        )]);
+        assert_eq!(
+            sut.claimed_websites(),
+            Err(CommonError::GatewayMetadataInvalidUrl {
+                key: "claimed_websites".to_owned(),
+                value: "http://radixdlt.com".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn icon_url_with_non_web_scheme_is_invalid() {
+        let sut = EntityMetadataCollection::new([EntityMetadataItem::new(
+            MetadataKey::IconUrl,
+            MetadataTypedValue::UrlValue {
+                value: "javascript:alert(1)".to_owned(),
+            },
+            false,
+        )]);
+        assert!(sut.icon_url().is_err());
+    }
+
+    #[test]
+    fn from_gateway_json() {
+        let json = r#"
+        {
+            "items": [
+                {
+                    "key": "symbol",
+                    "value": {
+                        "raw_hex": "5c2200010c03585244",
+                        "typed": { "type": "String", "value": "XRD" }
+                    },
+                    "is_locked": true,
+                    "last_updated_at_state_version": 2
+                },
+                {
+                    "key": "supply",
+                    "value": {
+                        "raw_hex": "5c2200",
+                        "typed": { "type": "Decimal", "value": "1" }
+                    },
+                    "is_locked": false,
+                    "last_updated_at_state_version": 2
+                }
+            ]
+        }
+        "#;
+        let sut = EntityMetadataCollection::from_json(json).unwrap();
+        assert_eq!(sut.symbol(), Ok(Some("XRD".to_owned())));
+        assert_eq!(sut.items[1].value.typed, MetadataTypedValue::Unsupported);
+    }
+
+    #[test]
+    fn from_invalid_json() {
+        assert_eq!(
+            EntityMetadataCollection::from_json("[]"),
+            Err(CommonError::FailedToDeserializeJSONToValue {
+                json_byte_count: 2,
+                type_name: "EntityMetadataCollection".to_string(),
+            })
+        );
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn from_json() {
+        let sut = EntityMetadataCollection::placeholder();
+        assert_eq!(
+            new_entity_metadata_collection_from_json(
+                serde_json::to_string(&sut).unwrap()
+            ),
+            Ok(sut)
+        );
+    }
+}
@@ -0,0 +1,68 @@
+use crate::prelude::*;
+
+/// The value of an [`EntityMetadataItem`], the Gateway also returns the
+/// raw SBOR encoded value which the wallet does not use.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+pub struct EntityMetadataItemValue {
+    pub typed: MetadataTypedValue,
+}
+
+/// A single metadata item of an entity as returned by the Gateway.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+pub struct EntityMetadataItem {
+    /// The key of the item, e.g. `"name"`, see [`MetadataKey`].
+    pub key: String,
+
+    pub value: EntityMetadataItemValue,
+
+    /// `true` if the item has been locked by its owner and can no longer
+    /// be changed.
+    pub is_locked: bool,
+}
+
+impl EntityMetadataItem {
+    pub fn new(
+        key: MetadataKey,
+        typed: MetadataTypedValue,
+        is_locked: bool,
+    ) -> Self {
+        Self {
+            key: key.to_string(),
+            value: EntityMetadataItemValue { typed },
+            is_locked,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn json_from_gateway() {
+        let sut: EntityMetadataItem = serde_json::from_value(json!({
+            "key": "symbol",
+            "value": {
+                "raw_hex": "5c2200010c0358524",
+                "typed": { "type": "String", "value": "XRD" }
+            },
+            "is_locked": true,
+            "last_updated_at_state_version": 2
+        }))
+        .unwrap();
+        assert_eq!(
+            sut,
+            EntityMetadataItem::new(
+                MetadataKey::Symbol,
+                MetadataTypedValue::StringValue {
+                    value: "XRD".to_owned()
+                },
+                true
+            )
+        );
+    }
+}
@@ -0,0 +1,68 @@
+use crate::prelude::*;
+
+/// The well known keys of entity metadata on Radix ledger, as per the
+/// metadata standard, which the wallet reads from the Gateway.
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Hash, strum_macros::Display, uniffi::Enum,
+)]
+#[strum(serialize_all = "snake_case")]
+pub enum MetadataKey {
+    /// The name of a resource or dApp, e.g. `"Radix"`.
+    Name,
+
+    /// The symbol of a fungible resource, e.g. `"XRD"`.
+    Symbol,
+
+    /// A longer description of a resource or dApp.
+    Description,
+
+    /// The URL of an icon of a resource or dApp.
+    IconUrl,
+
+    /// Tags of a resource or dApp, e.g. `["official", "defi"]`.
+    Tags,
+
+    /// The type of an account, `"dapp definition"` for dApp definitions.
+    AccountType,
+
+    /// The origins of the websites a dApp definition claims.
+    ClaimedWebsites,
+
+    /// The addresses of the entities a dApp definition claims.
+    ClaimedEntities,
+
+    /// The address of the dApp definition of a component or resource.
+    DappDefinition,
+
+    /// The addresses of the dApp definitions of a component or resource.
+    DappDefinitions,
+
+    /// The address of the validator a liquid stake unit or stake claim
+    /// resource belongs to.
+    Validator,
+
+    /// The address of the pool a pool unit resource belongs to.
+    Pool,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn display() {
+        assert_eq!(format!("{}", MetadataKey::Name), "name");
+        assert_eq!(format!("{}", MetadataKey::IconUrl), "icon_url");
+        assert_eq!(format!("{}", MetadataKey::AccountType), "account_type");
+        assert_eq!(
+            format!("{}", MetadataKey::ClaimedWebsites),
+            "claimed_websites"
+        );
+        assert_eq!(
+            format!("{}", MetadataKey::DappDefinitions),
+            "dapp_definitions"
+        );
+        assert_eq!(format!("{}", MetadataKey::Validator), "validator");
+        assert_eq!(format!("{}", MetadataKey::Pool), "pool");
+    }
+}
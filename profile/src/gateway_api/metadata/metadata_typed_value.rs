@@ -0,0 +1,112 @@
+use crate::prelude::*;
+
+/// The typed value of an entity metadata item as returned by the Gateway,
+/// only the types used by the wallet are modelled, all others are decoded
+/// as `Unsupported`.
+///
+/// URLs and origins are kept as strings and are validated when read through
+/// the accessors of [`EntityMetadataCollection`], so that one malformed item
+/// does not prevent decoding the rest of the metadata.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Enum,
+)]
+#[serde(tag = "type")]
+pub enum MetadataTypedValue {
+    #[serde(rename = "String")]
+    StringValue { value: String },
+
+    #[serde(rename = "Url")]
+    UrlValue { value: String },
+
+    #[serde(rename = "Origin")]
+    OriginValue { value: String },
+
+    #[serde(rename = "GlobalAddress")]
+    GlobalAddressValue { value: String },
+
+    #[serde(rename = "StringArray")]
+    StringArrayValue { values: Vec<String> },
+
+    #[serde(rename = "UrlArray")]
+    UrlArrayValue { values: Vec<String> },
+
+    #[serde(rename = "OriginArray")]
+    OriginArrayValue { values: Vec<String> },
+
+    #[serde(rename = "GlobalAddressArray")]
+    GlobalAddressArrayValue { values: Vec<String> },
+
+    /// Any metadata type not used by the wallet, e.g. `"Decimal"`.
+    #[serde(other)]
+    Unsupported,
+}
+
+impl MetadataTypedValue {
+    /// The name of the type as used by the Gateway, e.g. `"String"`.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Self::StringValue { .. } => "String",
+            Self::UrlValue { .. } => "Url",
+            Self::OriginValue { .. } => "Origin",
+            Self::GlobalAddressValue { .. } => "GlobalAddress",
+            Self::StringArrayValue { .. } => "StringArray",
+            Self::UrlArrayValue { .. } => "UrlArray",
+            Self::OriginArrayValue { .. } => "OriginArray",
+            Self::GlobalAddressArrayValue { .. } => "GlobalAddressArray",
+            Self::Unsupported => "Unsupported",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn json_string() {
+        assert_json_value_eq_after_roundtrip(
+            &MetadataTypedValue::StringValue {
+                value: "XRD".to_owned(),
+            },
+            json!({ "type": "String", "value": "XRD" }),
+        );
+    }
+
+    #[test]
+    fn json_origin_array() {
+        assert_json_value_eq_after_roundtrip(
+            &MetadataTypedValue::OriginArrayValue {
+                values: vec!["https://dashboard.radixdlt.com".to_owned()],
+            },
+            json!({
+                "type": "OriginArray",
+                "values": ["https://dashboard.radixdlt.com"]
+            }),
+        );
+    }
+
+    #[test]
+    fn json_unsupported() {
+        let sut: MetadataTypedValue =
+            serde_json::from_value(json!({ "type": "Decimal", "value": "1" }))
+                .unwrap();
+        assert_eq!(sut, MetadataTypedValue::Unsupported);
+    }
+
+    #[test]
+    fn type_name() {
+        assert_eq!(
+            MetadataTypedValue::UrlValue {
+                value: "https://radixdlt.com".to_owned()
+            }
+            .type_name(),
+            "Url"
+        );
+        assert_eq!(
+            MetadataTypedValue::GlobalAddressArrayValue { values: Vec::new() }
+                .type_name(),
+            "GlobalAddressArray"
+        );
+        assert_eq!(MetadataTypedValue::Unsupported.type_name(), "Unsupported");
+    }
+}
@@ -0,0 +1,11 @@
+mod dapp_definition_verification;
+mod entity_metadata_collection;
+mod entity_metadata_item;
+mod metadata_key;
+mod metadata_typed_value;
+
+pub use dapp_definition_verification::*;
+pub use entity_metadata_collection::*;
+pub use entity_metadata_item::*;
+pub use metadata_key::*;
+pub use metadata_typed_value::*;
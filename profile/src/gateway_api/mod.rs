@@ -0,0 +1,13 @@
+mod dapp_verifier;
+mod gateway_client;
+mod holdings;
+mod metadata;
+mod network_status;
+mod pagination;
+
+pub use dapp_verifier::*;
+pub use gateway_client::*;
+pub use holdings::*;
+pub use metadata::*;
+pub use network_status::*;
+pub use pagination::*;
@@ -0,0 +1,278 @@
+use crate::prelude::*;
+
+/// The state of the ledger as last seen by a Gateway, returned by
+/// [`GatewayClient::network_status`].
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct NetworkStatus {
+    /// The logical name of the network, e.g. `"mainnet"`.
+    #[serde(rename = "network")]
+    pub network_name: String,
+
+    /// The epoch the ledger is in, used as the start epoch of transactions.
+    #[serde(rename = "epoch")]
+    pub current_epoch: Epoch,
+
+    /// The number of transactions committed to the ledger so far.
+    #[serde(rename = "state_version")]
+    pub ledger_state_version: u64,
+
+    /// The consensus round within `current_epoch`.
+    pub round: u64,
+
+    /// When the round producing `ledger_state_version` was proposed.
+    pub proposer_round_timestamp: Timestamp,
+}
+
+impl NetworkStatus {
+    /// Estimates the [`NetworkCongestion`] from the transactions committed
+    /// between `earlier` and this status, [`NetworkCongestion::Normal`] if
+    /// `earlier` is not in fact earlier.
+    pub fn congestion_since(
+        &self,
+        earlier: &NetworkStatus,
+    ) -> NetworkCongestion {
+        let elapsed_seconds = self
+            .proposer_round_timestamp
+            .seconds_since(&earlier.proposer_round_timestamp);
+        if elapsed_seconds <= 0 {
+            return NetworkCongestion::Normal;
+        }
+        NetworkCongestion::estimate(
+            self.ledger_state_version
+                .saturating_sub(earlier.ledger_state_version),
+            elapsed_seconds as u64,
+        )
+    }
+
+    /// The [`TipSuggestion`] for the congestion since `earlier`, see
+    /// [`NetworkStatus::congestion_since`].
+    pub fn tip_suggestion_since(
+        &self,
+        earlier: &NetworkStatus,
+    ) -> TipSuggestion {
+        TipSuggestion::new(self.congestion_since(earlier))
+    }
+}
+
+/// The response of the `status/gateway-status` endpoint, of which only the
+/// ledger state is used.
+#[derive(Deserialize)]
+pub(crate) struct GatewayStatusResponse {
+    pub(crate) ledger_state: NetworkStatus,
+}
+
+impl GatewayClient {
+    /// The path of the endpoint returning the [`NetworkStatus`].
+    pub const NETWORK_STATUS_PATH: &'static str = "status/gateway-status";
+
+    /// Fetches the current [`NetworkStatus`] of the Gateway.
+    pub fn network_status(&self) -> Result<NetworkStatus> {
+        self.post::<_, GatewayStatusResponse>(
+            Self::NETWORK_STATUS_PATH,
+            &json!({}),
+            &self.retry_policy,
+        )
+        .map(|r| r.ledger_state)
+    }
+}
+
+impl HasPlaceholder for NetworkStatus {
+    fn placeholder() -> Self {
+        Self {
+            network_name: "mainnet".to_owned(),
+            current_epoch: Epoch::placeholder(),
+            ledger_state_version: 100_000,
+            round: 42,
+            proposer_round_timestamp: Timestamp::parse(
+                "2024-01-31T14:23:45.000Z",
+            )
+            .unwrap(),
+        }
+    }
+
+    /// A placeholder one minute after [`NetworkStatus::placeholder`], with
+    /// 3000 more committed transactions.
+    fn placeholder_other() -> Self {
+        Self {
+            ledger_state_version: 103_000,
+            round: 1042,
+            proposer_round_timestamp: Timestamp::parse(
+                "2024-01-31T14:24:45.000Z",
+            )
+            .unwrap(),
+            ..Self::placeholder()
+        }
+    }
+}
+
+#[uniffi::export]
+pub fn new_network_status_placeholder() -> NetworkStatus {
+    NetworkStatus::placeholder()
+}
+
+#[uniffi::export]
+pub fn new_network_status_placeholder_other() -> NetworkStatus {
+    NetworkStatus::placeholder_other()
+}
+
+#[uniffi::export]
+pub fn network_status_congestion_since(
+    status: &NetworkStatus,
+    earlier: &NetworkStatus,
+) -> NetworkCongestion {
+    status.congestion_since(earlier)
+}
+
+#[uniffi::export]
+pub fn network_status_tip_suggestion_since(
+    status: &NetworkStatus,
+    earlier: &NetworkStatus,
+) -> TipSuggestion {
+    status.tip_suggestion_since(earlier)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = NetworkStatus;
+
+    /// Responds with `response` to any request, recording the requests.
+    #[derive(Debug)]
+    struct FixedNetworkingDriver {
+        response: NetworkResponse,
+        requests: std::sync::RwLock<Vec<NetworkRequest>>,
+    }
+
+    impl NetworkingDriver for FixedNetworkingDriver {
+        fn execute_network_request(
+            &self,
+            request: NetworkRequest,
+        ) -> Result<NetworkResponse> {
+            self.requests.write().unwrap().push(request);
+            Ok(self.response.clone())
+        }
+    }
+
+    fn make_sut(
+        status_code: u16,
+        body: &str,
+    ) -> (GatewayClient, Arc<FixedNetworkingDriver>) {
+        let driver = Arc::new(FixedNetworkingDriver {
+            response: NetworkResponse::new(
+                status_code,
+                body.as_bytes().to_vec(),
+            ),
+            requests: Default::default(),
+        });
+        let client = GatewayClient::new(driver.clone(), Gateway::mainnet())
+            .with_retry_policy(RetryPolicy::no_retries());
+        (client, driver)
+    }
+
+    const RESPONSE: &str = r#"
+    {
+        "ledger_state": {
+            "network": "mainnet",
+            "state_version": 100000,
+            "proposer_round_timestamp": "2024-01-31T14:23:45.000Z",
+            "epoch": 1337,
+            "round": 42
+        },
+        "release_info": {
+            "release_version": "v1.4.0",
+            "open_api_schema_version": "v1.4.0",
+            "image_tag": "v1.4.0"
+        }
+    }
+    "#;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::placeholder(), SUT::placeholder());
+        assert_eq!(SUT::placeholder_other(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::placeholder(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn network_status() {
+        let (client, driver) = make_sut(200, RESPONSE);
+        assert_eq!(client.network_status(), Ok(SUT::placeholder()));
+        let requests = driver.requests.read().unwrap();
+        assert_eq!(
+            requests[0].url,
+            Url::parse("https://mainnet.radixdlt.com/status/gateway-status")
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn network_status_bad_code() {
+        let (client, _) = make_sut(500, "");
+        assert_eq!(
+            client.network_status(),
+            Err(CommonError::NetworkResponseBadCode { code: 500 })
+        );
+    }
+
+    #[test]
+    fn congestion_since() {
+        assert_eq!(
+            SUT::placeholder_other().congestion_since(&SUT::placeholder()),
+            NetworkCongestion::High
+        );
+    }
+
+    #[test]
+    fn congestion_since_later_status_is_normal() {
+        assert_eq!(
+            SUT::placeholder().congestion_since(&SUT::placeholder_other()),
+            NetworkCongestion::Normal
+        );
+    }
+
+    #[test]
+    fn tip_suggestion_since() {
+        assert_eq!(
+            SUT::placeholder_other().tip_suggestion_since(&SUT::placeholder()),
+            TipSuggestion::new(NetworkCongestion::High)
+        );
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn placeholders() {
+        assert_eq!(
+            new_network_status_placeholder(),
+            NetworkStatus::placeholder()
+        );
+        assert_eq!(
+            new_network_status_placeholder_other(),
+            NetworkStatus::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn congestion_and_tip_since() {
+        let earlier = NetworkStatus::placeholder();
+        let status = NetworkStatus::placeholder_other();
+        assert_eq!(
+            network_status_congestion_since(&status, &earlier),
+            NetworkCongestion::High
+        );
+        assert_eq!(
+            network_status_tip_suggestion_since(&status, &earlier)
+                .tip_percentage,
+            TipSuggestion::CONGESTED_TIP_PERCENTAGE
+        );
+    }
+}
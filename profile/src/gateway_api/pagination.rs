@@ -0,0 +1,119 @@
+use crate::prelude::*;
+
+/// An opaque cursor returned by the Gateway pointing to the next page of a
+/// paginated collection, passed back as-is to fetch that page.
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    derive_more::Display,
+)]
+#[serde(transparent)]
+pub struct PageCursor(pub String);
+
+/// One page of a cursor paginated Gateway collection, e.g. the metadata of
+/// an entity or the non-fungible ids of a vault.
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PagedResponse<T> {
+    /// Total number of items in the collection, if returned by the Gateway.
+    #[serde(default)]
+    pub total_count: Option<u64>,
+
+    /// Cursor of the next page, `None` if this is the last page.
+    #[serde(default)]
+    pub next_cursor: Option<PageCursor>,
+
+    pub items: Vec<T>,
+}
+
+/// A request to a cursor paginated Gateway endpoint, the Gateway expects the
+/// `cursor` and `limit_per_page` of a request next to the other fields of
+/// the JSON body, which [`GatewayClient`] adds.
+pub trait GatewayPagedRequest: Serialize {
+    /// The type of the items of each [`PagedResponse`].
+    type Item: for<'de> Deserialize<'de>;
+
+    /// The path of the endpoint relative to the URL of the Gateway, e.g.
+    /// `"state/entity/metadata/page"`.
+    const PATH: &'static str;
+}
+
+/// Limits for how [`GatewayClient::stream_all`] follows cursors.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PaginationOptions {
+    /// Fetching stops with an error if the collection has more pages than
+    /// this, protecting against unbounded fetching.
+    pub max_page_count: u32,
+
+    /// Number of items requested per page, `None` lets the Gateway decide.
+    pub limit_per_page: Option<u32>,
+}
+
+impl PaginationOptions {
+    pub fn new(
+        max_page_count: u32,
+        limit_per_page: impl Into<Option<u32>>,
+    ) -> Self {
+        Self {
+            max_page_count,
+            limit_per_page: limit_per_page.into(),
+        }
+    }
+}
+
+impl Default for PaginationOptions {
+    fn default() -> Self {
+        Self::new(100, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn decode_page() {
+        let json = r#"
+        {
+            "ledger_state": { "epoch": 1 },
+            "total_count": 3,
+            "next_cursor": "abc",
+            "items": [1, 2]
+        }
+        "#;
+        assert_eq!(
+            serde_json::from_str::<PagedResponse<u8>>(json).unwrap(),
+            PagedResponse {
+                total_count: Some(3),
+                next_cursor: Some(PageCursor("abc".to_owned())),
+                items: vec![1, 2]
+            }
+        );
+    }
+
+    #[test]
+    fn decode_last_page() {
+        let sut =
+            serde_json::from_str::<PagedResponse<u8>>(r#"{ "items": [] }"#)
+                .unwrap();
+        assert_eq!(sut.next_cursor, None);
+        assert_eq!(sut.total_count, None);
+    }
+
+    #[test]
+    fn cursor_display() {
+        assert_eq!(PageCursor("abc".to_owned()).to_string(), "abc");
+    }
+
+    #[test]
+    fn default_options() {
+        assert_eq!(
+            PaginationOptions::default(),
+            PaginationOptions::new(100, None)
+        );
+    }
+}
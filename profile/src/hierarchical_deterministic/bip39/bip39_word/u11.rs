@@ -25,6 +25,50 @@ impl U11 {
         }
         Ok(Self { inner })
     }
+
+    /// The largest value, `2047`, the index of the last word of a BIP39
+    /// word list.
+    pub const MAX: u16 = 2047;
+
+    /// `self + other`, or `None` if the sum exceeds [`U11::MAX`].
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        Self::new(self.inner + other.inner).ok()
+    }
+
+    /// `self - other`, or `None` if `other` is greater than `self`.
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        self.inner
+            .checked_sub(other.inner)
+            .map(|inner| Self { inner })
+    }
+
+    /// `self + other` modulo 2048.
+    pub fn wrapping_add(&self, other: &Self) -> Self {
+        Self {
+            inner: (self.inner + other.inner) % 2048,
+        }
+    }
+
+    /// `self - other` modulo 2048.
+    pub fn wrapping_sub(&self, other: &Self) -> Self {
+        Self {
+            inner: (self.inner + 2048 - other.inner) % 2048,
+        }
+    }
+}
+
+impl TryFrom<u16> for U11 {
+    type Error = CommonError;
+
+    fn try_from(value: u16) -> Result<Self> {
+        Self::new(value)
+    }
+}
+
+impl From<U11> for u16 {
+    fn from(value: U11) -> Self {
+        value.inner
+    }
 }
 
 #[cfg(test)]
@@ -52,6 +96,45 @@ mod tests {
         assert_eq!(U11::new(1024).unwrap().inner, 1024);
     }
 
+    fn u11(value: u16) -> U11 {
+        U11::new(value).unwrap()
+    }
+
+    #[test]
+    fn checked_add() {
+        assert_eq!(u11(2000).checked_add(&u11(47)), Some(u11(2047)));
+        assert_eq!(u11(2000).checked_add(&u11(48)), None);
+    }
+
+    #[test]
+    fn checked_sub() {
+        assert_eq!(u11(5).checked_sub(&u11(5)), Some(u11(0)));
+        assert_eq!(u11(5).checked_sub(&u11(6)), None);
+    }
+
+    #[test]
+    fn wrapping_add() {
+        assert_eq!(u11(2047).wrapping_add(&u11(1)), u11(0));
+        assert_eq!(u11(2047).wrapping_add(&u11(2047)), u11(2046));
+        assert_eq!(u11(1).wrapping_add(&u11(2)), u11(3));
+    }
+
+    #[test]
+    fn wrapping_sub() {
+        assert_eq!(u11(0).wrapping_sub(&u11(1)), u11(2047));
+        assert_eq!(u11(3).wrapping_sub(&u11(2)), u11(1));
+    }
+
+    #[test]
+    fn u16_roundtrip() {
+        assert_eq!(u16::from(u11(U11::MAX)), 2047);
+        assert_eq!(U11::try_from(2047u16), Ok(u11(2047)));
+        assert_eq!(
+            U11::try_from(2048u16),
+            Err(CommonError::InvalidBIP39Index(2048))
+        );
+    }
+
     #[test]
     fn ord() {
         assert!(U11::new(0).unwrap() < U11::new(1).unwrap());
@@ -11,7 +11,7 @@ use crate::prelude::*;
     uniffi::Record,
 )]
 #[display("{}", self.to_obfuscated_string())]
-#[debug("{:?}", self.partially_obfuscated_string())]
+#[debug("{}", self.redacted_debug())]
 pub struct Mnemonic {
     pub words: Vec<BIP39Word>,
     pub word_count: BIP39WordCount,
@@ -36,6 +36,17 @@ impl Mnemonic {
         )
     }
 }
+impl SafeDebug for Mnemonic {
+    fn redacted_debug(&self) -> String {
+        format!("<REDACTED {}>", self.word_count)
+    }
+
+    #[cfg(feature = "danger-fully-reveal-debug")]
+    fn danger_fully_reveal_debug(&self) -> String {
+        self.phrase()
+    }
+}
+
 impl SafeToLog for Mnemonic {
     /// Logs the word count and FactorSourceID o
     fn non_sensitive(&self) -> impl std::fmt::Debug {
@@ -49,6 +60,19 @@ pub fn mnemonic_phrase(from: &Mnemonic) -> String {
     from.phrase()
 }
 
+/// Creates a mnemonic from the indices of its words in the English BIP39
+/// word list, recomputing the checksum, see [`Mnemonic::from_word_indices`].
+#[uniffi::export]
+pub fn new_mnemonic_from_word_indices(indices: Vec<U11>) -> Result<Mnemonic> {
+    Mnemonic::from_word_indices(indices)
+}
+
+/// The indices of the words of `mnemonic` in its BIP39 word list.
+#[uniffi::export]
+pub fn mnemonic_to_word_indices(mnemonic: &Mnemonic) -> Vec<U11> {
+    mnemonic.to_word_indices()
+}
+
 impl Mnemonic {
     pub fn to_obfuscated_string(&self) -> String {
         format!("Mnemonic in {} obfuscated.", self.language)
@@ -102,6 +126,31 @@ impl Mnemonic {
             .map(Self::from_internal)
     }
 
+    /// Creates a mnemonic from the indices of its words in the English
+    /// BIP39 word list, recomputing the checksum - the last bits of the last
+    /// word - so that any indices, e.g. ones encoding security question
+    /// answers, form a valid mnemonic.
+    ///
+    /// Fails if the number of indices is not a BIP39 word count.
+    pub fn from_word_indices(indices: Vec<U11>) -> Result<Self> {
+        BIP39WordCount::from_count(indices.len())?;
+        let checksum_bit_count = indices.len() / 3;
+        let entropy_bit_count = indices.len() * 11 - checksum_bit_count;
+        let mut entropy = vec![0u8; entropy_bit_count / 8];
+        for bit in 0..entropy_bit_count {
+            let index = indices[bit / 11].inner;
+            if (index >> (10 - bit % 11)) & 1 == 1 {
+                entropy[bit / 8] |= 1 << (7 - bit % 8);
+            }
+        }
+        Ok(Self::from_entropy(&entropy))
+    }
+
+    /// The indices of the words of this mnemonic in its BIP39 word list.
+    pub fn to_word_indices(&self) -> Vec<U11> {
+        self.words.iter().map(|w| w.index.clone()).collect()
+    }
+
     pub fn to_seed(&self, passphrase: &str) -> Seed {
         self.internal().to_seed(passphrase)
     }
@@ -129,6 +178,26 @@ impl HasPlaceholder for Mnemonic {
     }
 }
 
+impl Mnemonic {
+    /// A placeholder used to facilitate unit tests, the mnemonic of an
+    /// Olympia device factor source, distinct from all other placeholders.
+    pub fn placeholder_olympia() -> Self {
+        Self::from_phrase("legal winner thank year wave sausage worth useful legal winner thank year wave sausage worth useful legal winner thank year wave sausage worth title").expect("Valid mnemonic")
+    }
+
+    /// A placeholder used to facilitate unit tests, the mnemonic secured by
+    /// a Ledger hardware wallet, distinct from all other placeholders.
+    pub fn placeholder_ledger() -> Self {
+        Self::from_phrase("letter advice cage absurd amount doctor acoustic avoid letter advice cage absurd amount doctor acoustic avoid letter advice cage absurd amount doctor acoustic bless").expect("Valid mnemonic")
+    }
+
+    /// A placeholder used to facilitate unit tests, the mnemonic of a device
+    /// factor source used on Stokenet, distinct from all other placeholders.
+    pub fn placeholder_stokenet() -> Self {
+        Self::from_phrase("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon art").expect("Valid mnemonic")
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -149,14 +218,67 @@ mod tests {
     }
 
     #[test]
-    fn debug() {
-        let mnemonic = Mnemonic::placeholder();
+    fn placeholders_are_distinct() {
+        let placeholders = HashSet::<Mnemonic>::from_iter([
+            Mnemonic::placeholder(),
+            Mnemonic::placeholder_other(),
+            Mnemonic::placeholder_olympia(),
+            Mnemonic::placeholder_ledger(),
+            Mnemonic::placeholder_stokenet(),
+        ]);
+        assert_eq!(placeholders.len(), 5);
+    }
+
+    fn indices(values: &[u16]) -> Vec<U11> {
+        values.iter().map(|v| U11::new(*v).unwrap()).collect()
+    }
+
+    #[test]
+    fn word_indices_roundtrip() {
+        let sut = Mnemonic::placeholder();
+        assert_eq!(Mnemonic::from_word_indices(sut.to_word_indices()), Ok(sut));
+    }
+
+    #[test]
+    fn to_word_indices() {
+        let mut expected = indices(&[2047; 12]);
+        expected[11] = BIP39Word::english("wrong").unwrap().index;
+        assert_eq!(Mnemonic::placeholder_other().to_word_indices(), expected);
+    }
+
+    #[test]
+    fn from_word_indices_recomputes_checksum() {
         assert_eq!(
-            format!("{:?}", mnemonic),
-            format!("{:?}", "24 words (bright...mandate)")
+            Mnemonic::from_word_indices(indices(&[2047; 12])),
+            Ok(Mnemonic::placeholder_other())
+        );
+        assert_eq!(
+            Mnemonic::from_word_indices(indices(&[0; 24])).unwrap().phrase(),
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon art"
+        );
+    }
+
+    #[test]
+    fn from_word_indices_invalid_count() {
+        assert_eq!(
+            Mnemonic::from_word_indices(indices(&[0; 13])),
+            Err(CommonError::InvalidBIP39WordCount(13))
         );
     }
 
+    #[test]
+    fn debug() {
+        let mnemonic = Mnemonic::placeholder();
+        assert_eq!(format!("{:?}", mnemonic), "<REDACTED 24 words>");
+    }
+
+    #[cfg(feature = "danger-fully-reveal-debug")]
+    #[test]
+    fn danger_fully_reveal_debug() {
+        let mnemonic = Mnemonic::placeholder();
+        assert_eq!(mnemonic.danger_fully_reveal_debug(), mnemonic.phrase());
+    }
+
     #[test]
     fn display() {
         let mnemonic = Mnemonic::placeholder();
@@ -284,4 +406,14 @@ mod uniffi_tests {
         let sut: Mnemonic = str.parse().unwrap();
         assert_eq!(mnemonic_phrase(&sut), str);
     }
+
+    #[test]
+    fn word_indices() {
+        let sut = Mnemonic::placeholder();
+        assert_eq!(mnemonic_to_word_indices(&sut), sut.to_word_indices());
+        assert_eq!(
+            new_mnemonic_from_word_indices(sut.to_word_indices()),
+            Ok(sut)
+        );
+    }
 }
@@ -11,7 +11,6 @@ use crate::prelude::*;
     Ord,
     derive_more::Display,
     derive_more::Debug,
-    uniffi::Enum,
 )]
 pub enum DerivationPath {
     #[debug("{}", self.bip32_string())]
@@ -31,6 +30,32 @@ impl TryFrom<&HDPath> for DerivationPath {
     }
 }
 
+impl FromStr for DerivationPath {
+    type Err = CommonError;
+
+    /// Parses a BIP32 string, e.g. `"m/44H/1022H/1H/525H/1460H/0H"`, into
+    /// a CAP26 or BIP44Like path.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<HDPath>().and_then(|p| Self::try_from(&p))
+    }
+}
+
+uniffi::custom_type!(DerivationPath, String);
+/// Crosses the FFI boundary as its BIP32 string, validated when lifted.
+impl crate::UniffiCustomTypeConverter for DerivationPath {
+    type Builtin = String;
+
+    #[cfg(not(tarpaulin_include))] // false negative | tested in bindgen tests
+    fn into_custom(val: Self::Builtin) -> uniffi::Result<Self> {
+        val.parse::<Self>().map_err(|e| e.into())
+    }
+
+    #[cfg(not(tarpaulin_include))] // false negative | tested in bindgen tests
+    fn from_custom(obj: Self) -> Self::Builtin {
+        obj.bip32_string()
+    }
+}
+
 impl<'de> serde::Deserialize<'de> for DerivationPath {
     /// Tries to deserializes a JSON string as a bech32 address into an `AccountAddress`.
     #[cfg(not(tarpaulin_include))] // false negative
@@ -105,6 +130,23 @@ impl Derivation for DerivationPath {
 }
 
 impl DerivationPath {
+    /// The network of an Account or Identity path, `None` for paths not
+    /// bound to any network, i.e. GetID and BIP44Like (Olympia) paths.
+    pub fn network_id(&self) -> Option<NetworkID> {
+        match self {
+            DerivationPath::CAP26 {
+                value: CAP26Path::Account { value },
+            } => Some(value.network_id()),
+            DerivationPath::CAP26 {
+                value: CAP26Path::Identity { value },
+            } => Some(value.network_id()),
+            DerivationPath::CAP26 {
+                value: CAP26Path::GetID { .. },
+            }
+            | DerivationPath::BIP44Like { .. } => None,
+        }
+    }
+
     pub fn placeholder_cap26() -> Self {
         DerivationPath::CAP26 {
             value: CAP26Path::placeholder_account(),
@@ -173,6 +215,72 @@ mod tests {
         );
     }
 
+    #[test]
+    fn network_id_of_entity_paths() {
+        assert_eq!(
+            DerivationPath::placeholder().network_id(),
+            Some(NetworkID::Mainnet)
+        );
+        assert_eq!(
+            DerivationPath::from(IdentityPath::new(
+                NetworkID::Stokenet,
+                CAP26KeyKind::TransactionSigning,
+                0
+            ))
+            .network_id(),
+            Some(NetworkID::Stokenet)
+        );
+    }
+
+    #[test]
+    fn network_id_of_unbound_paths() {
+        assert_eq!(
+            DerivationPath::from(GetIDPath::default()).network_id(),
+            None
+        );
+        assert_eq!(
+            DerivationPath::from(BIP44LikePath::placeholder()).network_id(),
+            None
+        );
+    }
+
+    #[test]
+    fn from_str_cap26() {
+        assert_eq!(
+            "m/44H/1022H/1H/525H/1460H/0H".parse::<DerivationPath>(),
+            Ok(AccountPath::placeholder().into())
+        );
+    }
+
+    #[test]
+    fn from_str_bip44like() {
+        assert_eq!(
+            "m/44H/1022H/0H/0/0H".parse::<DerivationPath>(),
+            Ok(BIP44LikePath::placeholder().into())
+        );
+    }
+
+    #[test]
+    fn from_str_invalid() {
+        assert!("m/44H/1022H/99H".parse::<DerivationPath>().is_err());
+        assert!("foobar".parse::<DerivationPath>().is_err());
+    }
+
+    #[test]
+    fn custom_type_roundtrip() {
+        use crate::UniffiCustomTypeConverter;
+        for sut in [
+            DerivationPath::placeholder(),
+            DerivationPath::placeholder_other(),
+            BIP44LikePath::placeholder().into(),
+        ] {
+            let builtin = DerivationPath::from_custom(sut.clone());
+            assert_eq!(builtin, sut.bip32_string());
+            assert_eq!(DerivationPath::into_custom(builtin).unwrap(), sut);
+        }
+        assert!(DerivationPath::into_custom("invalid".to_owned()).is_err());
+    }
+
     #[test]
     fn cap26_scheme() {
         assert_eq!(
@@ -0,0 +1,46 @@
+use crate::prelude::*;
+
+/// The reason keys are being derived, passed along with derivation requests
+/// to the Wallet Client so that prompts - e.g. on a Ledger or for a device
+/// biometrics check - can explain to the user *why* keys are derived.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    enum_iterator::Sequence,
+    uniffi::Enum,
+)]
+pub enum DerivationPurpose {
+    /// Deriving the public key of a new Account.
+    CreatingNewAccount,
+
+    /// Deriving many public keys to find Accounts controlled by a factor
+    /// source, e.g. when recovering a wallet from a mnemonic.
+    RecoveringAccounts,
+
+    /// Deriving public keys to use as factors in a multi-factor setup, i.e.
+    /// when securifying an entity.
+    SettingUpMultiFactor,
+
+    /// Deriving the authentication signing key of an entity, used for ROLA.
+    CreatingAuthenticationSigningKey,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn all_distinct() {
+        assert_eq!(
+            HashSet::<DerivationPurpose>::from_iter(enum_iterator::all::<
+                DerivationPurpose,
+            >())
+            .len(),
+            4
+        );
+    }
+}
@@ -15,7 +15,7 @@ use crate::prelude::*;
 )]
 #[serde(rename_all = "camelCase")]
 #[display("<OBFUSCATED>")]
-#[debug("{:?}", self.partially_obfuscated_string())]
+#[debug("{}", self.redacted_debug())]
 pub struct MnemonicWithPassphrase {
     pub mnemonic: Mnemonic,
     pub passphrase: BIP39Passphrase,
@@ -31,6 +31,25 @@ impl MnemonicWithPassphrase {
         )
     }
 }
+impl SafeDebug for MnemonicWithPassphrase {
+    fn redacted_debug(&self) -> String {
+        format!(
+            "{} + {}",
+            self.mnemonic.redacted_debug(),
+            self.passphrase.partially_obfuscated_string()
+        )
+    }
+
+    #[cfg(feature = "danger-fully-reveal-debug")]
+    fn danger_fully_reveal_debug(&self) -> String {
+        format!(
+            "{} + '{}'",
+            self.mnemonic.danger_fully_reveal_debug(),
+            self.passphrase.0
+        )
+    }
+}
+
 impl SafeToLog for MnemonicWithPassphrase {
     /// Logs the word count and FactorSourceID o
     fn non_sensitive(&self) -> impl std::fmt::Debug {
@@ -68,6 +87,24 @@ impl MnemonicWithPassphrase {
     }
 }
 
+impl MnemonicWithPassphrase {
+    /// A short human-friendly identifier used to tell mnemonics apart in
+    /// the UI without revealing them, see
+    /// [`FactorSourceIDFromHash::fingerprint`].
+    pub fn fingerprint(&self) -> String {
+        FactorSourceIDFromHash::new_for_device(self.clone()).fingerprint()
+    }
+}
+
+/// A short human-friendly identifier of `mnemonic_with_passphrase`, see
+/// [`MnemonicWithPassphrase::fingerprint`].
+#[uniffi::export]
+pub fn mnemonic_with_passphrase_fingerprint(
+    mnemonic_with_passphrase: &MnemonicWithPassphrase,
+) -> String {
+    mnemonic_with_passphrase.fingerprint()
+}
+
 impl HasPlaceholder for MnemonicWithPassphrase {
     /// A placeholder used to facilitate unit tests.
     fn placeholder() -> Self {
@@ -82,6 +119,23 @@ impl HasPlaceholder for MnemonicWithPassphrase {
     }
 }
 
+impl MnemonicWithPassphrase {
+    /// A placeholder used to facilitate unit tests.
+    pub fn placeholder_olympia() -> Self {
+        Self::new(Mnemonic::placeholder_olympia())
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    pub fn placeholder_ledger() -> Self {
+        Self::new(Mnemonic::placeholder_ledger())
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    pub fn placeholder_stokenet() -> Self {
+        Self::new(Mnemonic::placeholder_stokenet())
+    }
+}
+
 pub type PrivateKeyBytes = [u8; 32];
 
 impl MnemonicWithPassphrase {
@@ -125,7 +179,6 @@ impl MnemonicWithPassphrase {
             .expect("Valid Secp256k1PrivateKey bytes")
     }
 
-    #[cfg(not(tarpaulin_include))] // false negative
     pub fn derive_private_key<D>(
         &self,
         derivation: D,
@@ -133,7 +186,50 @@ impl MnemonicWithPassphrase {
     where
         D: Derivation,
     {
-        let seed = self.to_seed();
+        measure(MeasuredOperation::Derivation, "derive_private_key", || {
+            Self::derive_private_key_from_seed(&self.to_seed(), derivation)
+        })
+    }
+
+    /// Derives a private key for each of the `derivations`, computing the
+    /// seed - which is expensive since it runs PBKDF2 - only once.
+    pub fn derive_private_keys<D, I>(
+        &self,
+        derivations: I,
+    ) -> Vec<HierarchicalDeterministicPrivateKey>
+    where
+        D: Derivation,
+        I: IntoIterator<Item = D>,
+    {
+        measure(MeasuredOperation::Derivation, "derive_private_keys", || {
+            Self::derive_private_keys_from_seed(&self.to_seed(), derivations)
+        })
+    }
+
+    /// Derives a private key for each of the `derivations` from `seed`, e.g.
+    /// as memoized by a [`SeedCache`].
+    pub fn derive_private_keys_from_seed<D, I>(
+        seed: &Seed,
+        derivations: I,
+    ) -> Vec<HierarchicalDeterministicPrivateKey>
+    where
+        D: Derivation,
+        I: IntoIterator<Item = D>,
+    {
+        derivations
+            .into_iter()
+            .map(|d| Self::derive_private_key_from_seed(seed, d))
+            .collect_vec()
+    }
+
+    #[cfg(not(tarpaulin_include))] // false negative
+    fn derive_private_key_from_seed<D>(
+        seed: &Seed,
+        derivation: D,
+    ) -> HierarchicalDeterministicPrivateKey
+    where
+        D: Derivation,
+    {
         let path = derivation.derivation_path();
         match derivation.scheme() {
             DerivationPathScheme::Cap26 => {
@@ -142,13 +238,13 @@ impl MnemonicWithPassphrase {
                     SLIP10Curve::Curve25519
                 );
                 let key =
-                    Self::derive_ed25519_private_key(&seed, path.hd_path());
+                    Self::derive_ed25519_private_key(seed, path.hd_path());
                 HierarchicalDeterministicPrivateKey::new(key.into(), path)
             }
             DerivationPathScheme::Bip44Olympia => {
                 assert_eq!(derivation.scheme().curve(), SLIP10Curve::Secp256k1);
                 let key =
-                    Self::derive_secp256k1_private_key(&seed, path.hd_path());
+                    Self::derive_secp256k1_private_key(seed, path.hd_path());
                 HierarchicalDeterministicPrivateKey::new(key.into(), path)
             }
         }
@@ -160,6 +256,27 @@ mod tests {
 
     use crate::prelude::*;
 
+    #[test]
+    fn fingerprint() {
+        let sut = MnemonicWithPassphrase::placeholder();
+        assert_eq!(
+            sut.fingerprint(),
+            FactorSourceIDFromHash::placeholder_device().fingerprint()
+        );
+        assert_eq!(
+            mnemonic_with_passphrase_fingerprint(&sut),
+            sut.fingerprint()
+        );
+    }
+
+    #[test]
+    fn fingerprint_depends_on_passphrase() {
+        assert_ne!(
+            MnemonicWithPassphrase::placeholder().fingerprint(),
+            MnemonicWithPassphrase::new(Mnemonic::placeholder()).fingerprint()
+        );
+    }
+
     #[test]
     fn equality() {
         assert_eq!(
@@ -192,11 +309,21 @@ mod tests {
     fn debug() {
         assert_eq!(
             format!("{:?}", MnemonicWithPassphrase::placeholder()),
-            format!("{:?}", "24 words (bright...mandate) + <NOT EMPTY>")
+            "<REDACTED 24 words> + <NOT EMPTY>"
         );
         assert_eq!(
             format!("{:?}", MnemonicWithPassphrase::placeholder_other()),
-            format!("{:?}", "12 words (zoo...wrong) + <EMPTY>")
+            "<REDACTED 12 words> + <EMPTY>"
+        );
+    }
+
+    #[cfg(feature = "danger-fully-reveal-debug")]
+    #[test]
+    fn danger_fully_reveal_debug() {
+        assert_eq!(
+            MnemonicWithPassphrase::placeholder_other()
+                .danger_fully_reveal_debug(),
+            "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo wrong + ''"
         );
     }
 
@@ -341,4 +468,22 @@ mod tests {
             .collect::<HashSet<_>>();
         assert_eq!(set.len(), n);
     }
+
+    #[test]
+    fn derivation_is_measured() {
+        let driver = Arc::new(RecordingMetricsDriver::default());
+        with_metrics_driver(driver.clone(), || {
+            MnemonicWithPassphrase::placeholder().derive_private_keys([
+                AccountPath::placeholder(),
+                AccountPath::placeholder(),
+            ])
+        });
+        assert_eq!(
+            driver.recorded(),
+            vec![(
+                MeasuredOperation::Derivation,
+                "derive_private_keys".to_owned()
+            )]
+        );
+    }
 }
@@ -1,13 +1,17 @@
 mod derivation;
 mod derivation_path;
 mod derivation_path_scheme;
+mod derivation_purpose;
 mod hierarchical_deterministic_private_key;
 mod hierarchical_deterministic_public_key;
 mod mnemonic_with_passphrase;
+mod seed_cache;
 
 pub use derivation::*;
 pub use derivation_path::*;
 pub use derivation_path_scheme::*;
+pub use derivation_purpose::*;
 pub use hierarchical_deterministic_private_key::*;
 pub use hierarchical_deterministic_public_key::*;
 pub use mnemonic_with_passphrase::*;
+pub use seed_cache::*;
@@ -0,0 +1,172 @@
+use crate::prelude::*;
+use std::sync::RwLock;
+use zeroize::Zeroizing;
+
+/// Memoizes the BIP39 seeds of device factor sources, keyed by their
+/// [`FactorSourceIDFromHash`], so that deriving keys one at a time does not
+/// re-run PBKDF2 for each of them, e.g. when scanning for accounts during
+/// recovery.
+///
+/// Never exported to hosts, the [`Wallet`] owns one and clears it when it is
+/// locked or deleted. Seeds are zeroized when removed or when the cache is
+/// dropped.
+#[derive(Default)]
+pub struct SeedCache {
+    cached: RwLock<HashMap<FactorSourceIDFromHash, CachedSeed>>,
+}
+
+struct CachedSeed {
+    mnemonic_with_passphrase: MnemonicWithPassphrase,
+    seed: Zeroizing<Seed>,
+}
+
+impl SeedCache {
+    /// Calls `f` with the seed of the `mnemonic_with_passphrase` of the
+    /// factor source with `id`, computing it only if this cache does not
+    /// already hold it.
+    ///
+    /// A cached seed of another mnemonic for the same `id` is replaced, so
+    /// a mismatching mnemonic is never derived from a stale seed.
+    pub fn with_seed<F, R>(
+        &self,
+        id: &FactorSourceIDFromHash,
+        mnemonic_with_passphrase: &MnemonicWithPassphrase,
+        f: F,
+    ) -> R
+    where
+        F: FnOnce(&Seed) -> R,
+    {
+        {
+            let cached = self
+                .cached
+                .read()
+                .expect("Should be able to acquire read lock for seed cache");
+            if let Some(cached) = cached.get(id).filter(|c| {
+                c.mnemonic_with_passphrase == *mnemonic_with_passphrase
+            }) {
+                return f(&cached.seed);
+            }
+        }
+
+        let mut cached = self
+            .cached
+            .write()
+            .expect("Should be able to acquire write lock for seed cache");
+        cached.insert(
+            id.clone(),
+            CachedSeed {
+                mnemonic_with_passphrase: mnemonic_with_passphrase.clone(),
+                seed: Zeroizing::new(mnemonic_with_passphrase.to_seed()),
+            },
+        );
+        f(&cached[id].seed)
+    }
+
+    /// `true` if the seed of the factor source with `id` has been computed.
+    pub fn contains_seed_of(&self, id: &FactorSourceIDFromHash) -> bool {
+        self.cached
+            .read()
+            .expect("Should be able to acquire read lock for seed cache")
+            .contains_key(id)
+    }
+
+    /// Zeroizes and forgets the seed of the factor source with `id`, if any.
+    pub fn remove(&self, id: &FactorSourceIDFromHash) {
+        self.cached
+            .write()
+            .expect("Should be able to acquire write lock for seed cache")
+            .remove(id);
+    }
+
+    /// Zeroizes and forgets all seeds.
+    pub fn clear(&self) {
+        self.cached
+            .write()
+            .expect("Should be able to acquire write lock for seed cache")
+            .clear();
+    }
+}
+
+impl std::fmt::Debug for SeedCache {
+    /// Never reveals the seeds.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SeedCache")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn seed_is_computed_on_first_use() {
+        let mwp = MnemonicWithPassphrase::placeholder();
+        let id = FactorSourceIDFromHash::placeholder_device();
+        let sut = SeedCache::default();
+        assert!(!sut.contains_seed_of(&id));
+        assert_eq!(sut.with_seed(&id, &mwp, |s| *s), mwp.to_seed());
+        assert!(sut.contains_seed_of(&id));
+    }
+
+    #[test]
+    fn seeds_of_many_factor_sources_are_cached() {
+        let sut = SeedCache::default();
+        let sources = [
+            PrivateHierarchicalDeterministicFactorSource::placeholder(),
+            PrivateHierarchicalDeterministicFactorSource::placeholder_other(),
+        ];
+        for source in sources.iter() {
+            sut.with_seed(
+                &source.factor_source.id,
+                &source.mnemonic_with_passphrase,
+                |_| (),
+            );
+        }
+        assert!(sources
+            .iter()
+            .all(|s| sut.contains_seed_of(&s.factor_source.id)));
+    }
+
+    #[test]
+    fn other_mnemonic_for_same_id_is_not_derived_from_stale_seed() {
+        let id = FactorSourceIDFromHash::placeholder_device();
+        let other = MnemonicWithPassphrase::placeholder_other();
+        let sut = SeedCache::default();
+        sut.with_seed(&id, &MnemonicWithPassphrase::placeholder(), |_| ());
+        assert_eq!(sut.with_seed(&id, &other, |s| *s), other.to_seed());
+    }
+
+    #[test]
+    fn remove() {
+        let id = FactorSourceIDFromHash::placeholder_device();
+        let sut = SeedCache::default();
+        sut.with_seed(&id, &MnemonicWithPassphrase::placeholder(), |_| ());
+        sut.remove(&id);
+        assert!(!sut.contains_seed_of(&id));
+    }
+
+    #[test]
+    fn clear() {
+        let sut = SeedCache::default();
+        let source =
+            PrivateHierarchicalDeterministicFactorSource::placeholder_other();
+        sut.with_seed(
+            &source.factor_source.id,
+            &source.mnemonic_with_passphrase,
+            |_| (),
+        );
+        sut.clear();
+        assert!(!sut.contains_seed_of(&source.factor_source.id));
+    }
+
+    #[test]
+    fn debug_does_not_reveal_seed() {
+        let sut = SeedCache::default();
+        sut.with_seed(
+            &FactorSourceIDFromHash::placeholder_device(),
+            &MnemonicWithPassphrase::placeholder(),
+            |_| (),
+        );
+        assert_eq!(format!("{:?}", sut), "SeedCache");
+    }
+}
@@ -0,0 +1,658 @@
+use crate::prelude::*;
+use std::{
+    fmt::{Debug, Display, Formatter},
+    hash::Hasher,
+    ops::Index,
+};
+use uniffi::{
+    check_remaining,
+    deps::bytes::{Buf, BufMut},
+    metadata, Lift, Lower, LowerReturn, MetadataBuffer, RustBuffer,
+};
+
+/// An ordered collection of unique [`Identifiable`] elements, i.e. an
+/// ordered set where uniqueness is decided by the `id` of the elements
+/// rather than by the elements themselves.
+///
+/// Serialized as a JSON array and bridged over FFI as a list.
+pub struct IdentifiedVecOf<V: Identifiable> {
+    /// The IDs of all elements, in order.
+    order: Vec<V::ID>,
+    elements: HashMap<V::ID, V>,
+}
+
+impl<V> IdentifiedVecOf<V>
+where
+    V: Identifiable,
+    V::ID: Eq + std::hash::Hash + Clone + Debug,
+{
+    /// Instantiates a new empty collection.
+    pub fn new() -> Self {
+        Self {
+            order: Vec::new(),
+            elements: HashMap::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+
+    /// The IDs of all elements, in order.
+    pub fn ids(&self) -> Vec<&V::ID> {
+        self.order.iter().collect()
+    }
+
+    /// Iterates over all elements, in order.
+    pub fn iter(&self) -> impl Iterator<Item = &V> {
+        self.order.iter().map(|id| &self.elements[id])
+    }
+
+    /// References to all elements, in order.
+    pub fn elements(&self) -> Vec<&V> {
+        self.iter().collect()
+    }
+
+    /// Clones of all elements, in order.
+    pub fn items(&self) -> Vec<V>
+    where
+        V: Clone,
+    {
+        self.iter().cloned().collect()
+    }
+
+    pub fn first(&self) -> Option<&V> {
+        self.get_at_index(0)
+    }
+
+    pub fn last(&self) -> Option<&V> {
+        self.order.last().map(|id| &self.elements[id])
+    }
+
+    pub fn contains_id(&self, id: &V::ID) -> bool {
+        self.elements.contains_key(id)
+    }
+
+    /// The element with `id`, if any.
+    pub fn get_id(&self, id: &V::ID) -> Option<&V> {
+        self.elements.get(id)
+    }
+
+    /// The element at `index`, if `index` is within bounds.
+    pub fn get_at_index(&self, index: usize) -> Option<&V> {
+        self.order.get(index).map(|id| &self.elements[id])
+    }
+
+    /// The index of the element with `id`, if any.
+    pub fn index_of_id(&self, id: &V::ID) -> Option<usize> {
+        self.order.iter().position(|i| i == id)
+    }
+
+    /// Appends `element` unless an element with the same ID already exists,
+    /// returning `true` if it was appended.
+    pub fn append(&mut self, element: V) -> bool {
+        self.try_insert_unique(element).is_ok()
+    }
+
+    /// Appends `element`, failing with `IdentifiableItemAlreadyPresent` if
+    /// an element with the same ID already exists, in which case the
+    /// collection is left unchanged.
+    pub fn try_insert_unique(&mut self, element: V) -> Result<()> {
+        let id = element.id();
+        if self.contains_id(&id) {
+            return Err(CommonError::IdentifiableItemAlreadyPresent(format!(
+                "{:?}",
+                id
+            )));
+        }
+        self.order.push(id.clone());
+        self.elements.insert(id, element);
+        Ok(())
+    }
+
+    /// Mutates the element with `id` in place, returning `true` if it
+    /// exists.
+    ///
+    /// # Panics
+    /// Panics if `mutate` changes the ID of the element.
+    pub fn update_with<F>(&mut self, id: &V::ID, mutate: F) -> bool
+    where
+        F: FnOnce(&mut V),
+    {
+        let Some(element) = self.elements.get_mut(id) else {
+            return false;
+        };
+        mutate(element);
+        assert_eq!(
+            &element.id(),
+            id,
+            "Mutating an element must not change its ID."
+        );
+        true
+    }
+
+    /// Replaces the element with `id` by the result of `mutate`, if it
+    /// succeeds, returning `Ok(true)` if the element exists. If `mutate`
+    /// fails the collection is left unchanged.
+    pub fn try_update_with<F>(&mut self, id: &V::ID, mutate: F) -> Result<bool>
+    where
+        F: FnOnce(&V) -> Result<V>,
+    {
+        let Some(element) = self.elements.get(id) else {
+            return Ok(false);
+        };
+        let updated = mutate(element)?;
+        if &updated.id() != id {
+            return Err(CommonError::IdentifiableItemIDChanged(format!(
+                "{:?}",
+                id
+            )));
+        }
+        self.elements.insert(id.clone(), updated);
+        Ok(true)
+    }
+
+    /// Removes and returns the element with `id`, if any.
+    pub fn remove_by_id(&mut self, id: &V::ID) -> Option<V> {
+        let element = self.elements.remove(id)?;
+        self.order.retain(|i| i != id);
+        Some(element)
+    }
+}
+
+impl<V> FromIterator<V> for IdentifiedVecOf<V>
+where
+    V: Identifiable,
+    V::ID: Eq + std::hash::Hash + Clone + Debug,
+{
+    /// Collects `iter` keeping only the first element of each ID.
+    fn from_iter<I: IntoIterator<Item = V>>(iter: I) -> Self {
+        let mut collection = Self::new();
+        for element in iter {
+            collection.append(element);
+        }
+        collection
+    }
+}
+
+impl<V> IntoIterator for IdentifiedVecOf<V>
+where
+    V: Identifiable,
+    V::ID: Eq + std::hash::Hash + Clone + Debug,
+{
+    type Item = V;
+    type IntoIter = std::vec::IntoIter<V>;
+
+    fn into_iter(mut self) -> Self::IntoIter {
+        self.order
+            .iter()
+            .map(|id| self.elements.remove(id).expect("Element for every ID"))
+            .collect_vec()
+            .into_iter()
+    }
+}
+
+impl<V> Index<usize> for IdentifiedVecOf<V>
+where
+    V: Identifiable,
+    V::ID: Eq + std::hash::Hash + Clone + Debug,
+{
+    type Output = V;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.get_at_index(index)
+            .unwrap_or_else(|| panic!("Element at index: {index}"))
+    }
+}
+
+impl<V> Clone for IdentifiedVecOf<V>
+where
+    V: Identifiable + Clone,
+    V::ID: Eq + std::hash::Hash + Clone + Debug,
+{
+    fn clone(&self) -> Self {
+        Self {
+            order: self.order.clone(),
+            elements: self.elements.clone(),
+        }
+    }
+}
+
+impl<V> PartialEq for IdentifiedVecOf<V>
+where
+    V: Identifiable + PartialEq,
+    V::ID: Eq + std::hash::Hash + Clone + Debug,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<V> Eq for IdentifiedVecOf<V>
+where
+    V: Identifiable + Eq,
+    V::ID: Eq + std::hash::Hash + Clone + Debug,
+{
+}
+
+impl<V> std::hash::Hash for IdentifiedVecOf<V>
+where
+    V: Identifiable + std::hash::Hash,
+    V::ID: Eq + std::hash::Hash + Clone + Debug,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len().hash(state);
+        self.iter().for_each(|e| e.hash(state));
+    }
+}
+
+impl<V> Debug for IdentifiedVecOf<V>
+where
+    V: Identifiable + Debug,
+    V::ID: Eq + std::hash::Hash + Clone + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<V> Display for IdentifiedVecOf<V>
+where
+    V: Identifiable + Debug,
+    V::ID: Eq + std::hash::Hash + Clone + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+impl<V> Serialize for IdentifiedVecOf<V>
+where
+    V: Identifiable + Serialize,
+    V::ID: Eq + std::hash::Hash + Clone + Debug,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl<'de, V> Deserialize<'de> for IdentifiedVecOf<V>
+where
+    V: Identifiable + Deserialize<'de>,
+    V::ID: Eq + std::hash::Hash + Clone + Debug,
+{
+    /// Fails if the array contains multiple elements with the same ID.
+    #[cfg(not(tarpaulin_include))] // false negative
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let elements = Vec::<V>::deserialize(deserializer)?;
+        let mut collection = Self::new();
+        for element in elements {
+            collection
+                .try_insert_unique(element)
+                .map_err(de::Error::custom)?;
+        }
+        Ok(collection)
+    }
+}
+
+#[cfg(not(tarpaulin_include))] // Tested in binding tests (e.g. test*.swift files)
+unsafe impl<UT, V> Lower<UT> for IdentifiedVecOf<V>
+where
+    V: Identifiable + Lower<UT>,
+    V::ID: Eq + std::hash::Hash + Clone + Debug,
+{
+    type FfiType = RustBuffer;
+
+    fn write(obj: IdentifiedVecOf<V>, buf: &mut Vec<u8>) {
+        let len = i32::try_from(obj.len()).unwrap();
+        buf.put_i32(len); // We limit arrays to i32::MAX items
+        for item in obj {
+            <V as Lower<UT>>::write(item, buf);
+        }
+    }
+
+    fn lower(obj: IdentifiedVecOf<V>) -> RustBuffer {
+        Self::lower_into_rust_buffer(obj)
+    }
+
+    const TYPE_ID_META: MetadataBuffer =
+        MetadataBuffer::from_code(metadata::codes::TYPE_VEC)
+            .concat(V::TYPE_ID_META);
+}
+
+#[cfg(not(tarpaulin_include))] // Tested in binding tests (e.g. test*.swift files)
+unsafe impl<UT, V> LowerReturn<UT> for IdentifiedVecOf<V>
+where
+    V: Identifiable + Lower<UT>,
+    V::ID: Eq + std::hash::Hash + Clone + Debug,
+{
+    type ReturnType = <Self as Lower<UT>>::FfiType;
+
+    fn lower_return(obj: Self) -> uniffi::Result<Self::ReturnType, RustBuffer> {
+        Ok(<Self as Lower<UT>>::lower(obj))
+    }
+
+    const TYPE_ID_META: MetadataBuffer = <Self as Lower<UT>>::TYPE_ID_META;
+}
+
+#[cfg(not(tarpaulin_include))] // Tested in binding tests (e.g. test*.swift files)
+unsafe impl<UT, V> Lift<UT> for IdentifiedVecOf<V>
+where
+    V: Identifiable + Lift<UT>,
+    V::ID: Eq + std::hash::Hash + Clone + Debug,
+{
+    type FfiType = RustBuffer;
+
+    /// Elements with an ID already read are skipped, the first element
+    /// with each ID is kept.
+    fn try_read(buf: &mut &[u8]) -> uniffi::Result<IdentifiedVecOf<V>> {
+        check_remaining(buf, 4)?;
+        let len = usize::try_from(buf.get_i32())?;
+        let mut vec = Vec::with_capacity(len);
+        for _ in 0..len {
+            vec.push(<V as Lift<UT>>::try_read(buf)?)
+        }
+        Ok(IdentifiedVecOf::from_iter(vec))
+    }
+
+    fn try_lift(buf: RustBuffer) -> uniffi::Result<IdentifiedVecOf<V>> {
+        Self::try_lift_from_rust_buffer(buf)
+    }
+
+    const TYPE_ID_META: MetadataBuffer =
+        MetadataBuffer::from_code(metadata::codes::TYPE_VEC)
+            .concat(V::TYPE_ID_META);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    /// An element identified by its first field only.
+    #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+    struct User {
+        id: u8,
+        name: String,
+    }
+
+    impl User {
+        fn new(id: u8, name: &str) -> Self {
+            Self {
+                id,
+                name: name.to_owned(),
+            }
+        }
+    }
+
+    impl Identifiable for User {
+        type ID = u8;
+
+        fn id(&self) -> Self::ID {
+            self.id
+        }
+    }
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = IdentifiedVecOf<User>;
+
+    fn sut() -> SUT {
+        SUT::from_iter([
+            User::new(1, "Alice"),
+            User::new(2, "Bob"),
+            User::new(3, "Carol"),
+        ])
+    }
+
+    #[test]
+    fn from_iter_keeps_first_of_duplicates() {
+        let sut = SUT::from_iter([User::new(1, "Alice"), User::new(1, "Eve")]);
+        assert_eq!(sut.items(), [User::new(1, "Alice")]);
+    }
+
+    #[test]
+    fn len_and_is_empty() {
+        assert_eq!(sut().len(), 3);
+        assert!(!sut().is_empty());
+        assert!(SUT::new().is_empty());
+    }
+
+    #[test]
+    fn try_insert_unique() {
+        let mut sut = sut();
+        assert_eq!(sut.try_insert_unique(User::new(4, "Dave")), Ok(()));
+        assert_eq!(
+            sut.try_insert_unique(User::new(2, "Eve")),
+            Err(CommonError::IdentifiableItemAlreadyPresent("2".to_owned()))
+        );
+        assert_eq!(sut.ids(), [&1, &2, &3, &4]);
+        assert_eq!(sut.get_id(&2), Some(&User::new(2, "Bob")));
+    }
+
+    #[test]
+    fn append() {
+        let mut sut = sut();
+        assert!(!sut.append(User::new(1, "Eve")));
+        assert!(sut.append(User::new(4, "Dave")));
+        assert_eq!(sut.last(), Some(&User::new(4, "Dave")));
+    }
+
+    #[test]
+    fn append_duplicate_retains_first_added() {
+        let mut sut = sut();
+        assert!(!sut.append(User::new(2, "Eve")));
+        assert_eq!(sut.len(), 3);
+        assert_eq!(sut.index_of_id(&2), Some(1));
+        assert_eq!(sut.get_id(&2), Some(&User::new(2, "Bob")));
+        assert_eq!(sut, self::sut());
+    }
+
+    #[test]
+    fn get_id() {
+        assert_eq!(sut().get_id(&3), Some(&User::new(3, "Carol")));
+        assert_eq!(sut().get_id(&4), None);
+    }
+
+    #[test]
+    fn index_access() {
+        let sut = sut();
+        assert_eq!(sut[1], User::new(2, "Bob"));
+        assert_eq!(sut.get_at_index(3), None);
+        assert_eq!(sut.first(), Some(&User::new(1, "Alice")));
+        assert_eq!(sut.index_of_id(&3), Some(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "Element at index: 3")]
+    fn index_out_of_bounds() {
+        _ = &sut()[3];
+    }
+
+    #[test]
+    fn update_with() {
+        let mut sut = sut();
+        assert!(sut.update_with(&2, |u| u.name = "Robert".to_owned()));
+        assert!(!sut.update_with(&4, |u| u.name = "Dave".to_owned()));
+        assert_eq!(sut[1], User::new(2, "Robert"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Mutating an element must not change its ID.")]
+    fn update_with_changing_id_panics() {
+        sut().update_with(&2, |u| u.id = 5);
+    }
+
+    #[test]
+    fn try_update_with() {
+        let mut sut = sut();
+        assert_eq!(
+            sut.try_update_with(&2, |u| Ok(User::new(u.id, "Robert"))),
+            Ok(true)
+        );
+        assert_eq!(
+            sut.try_update_with(&4, |u| Ok(User::new(u.id, "Dave"))),
+            Ok(false)
+        );
+        assert_eq!(
+            sut.try_update_with(&3, |_| Err(CommonError::Unknown)),
+            Err(CommonError::Unknown)
+        );
+        assert_eq!(
+            sut.try_update_with(&3, |_| Ok(User::new(5, "Eve"))),
+            Err(CommonError::IdentifiableItemIDChanged("3".to_owned()))
+        );
+        assert_eq!(
+            sut.items(),
+            [
+                User::new(1, "Alice"),
+                User::new(2, "Robert"),
+                User::new(3, "Carol")
+            ]
+        );
+    }
+
+    #[test]
+    fn remove_by_id() {
+        let mut sut = sut();
+        assert_eq!(sut.remove_by_id(&2), Some(User::new(2, "Bob")));
+        assert_eq!(sut.remove_by_id(&2), None);
+        assert_eq!(sut.ids(), [&1, &3]);
+    }
+
+    #[test]
+    fn equality_depends_on_order() {
+        assert_eq!(sut(), sut());
+        let reversed = SUT::from_iter(sut().into_iter().rev());
+        assert_ne!(sut(), reversed);
+    }
+
+    #[test]
+    fn hash() {
+        assert_eq!(HashSet::<SUT>::from_iter([sut(), sut()]).len(), 1);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            format!("{}", SUT::from_iter([User::new(1, "Alice")])),
+            "[User { id: 1, name: \"Alice\" }]"
+        );
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        let json = serde_json::to_value(sut()).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!([
+                { "id": 1, "name": "Alice" },
+                { "id": 2, "name": "Bob" },
+                { "id": 3, "name": "Carol" }
+            ])
+        );
+        assert_eq!(serde_json::from_value::<SUT>(json).unwrap(), sut());
+    }
+
+    #[test]
+    fn json_with_duplicates_fails() {
+        assert!(serde_json::from_value::<SUT>(serde_json::json!([
+            { "id": 1, "name": "Alice" },
+            { "id": 1, "name": "Eve" }
+        ]))
+        .is_err());
+    }
+}
+
+#[cfg(test)]
+mod proptests {
+    use super::IdentifiedVecOf;
+    use crate::prelude::*;
+    use proptest::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Element {
+        id: u8,
+        value: u16,
+    }
+
+    impl Identifiable for Element {
+        type ID = u8;
+
+        fn id(&self) -> Self::ID {
+            self.id
+        }
+    }
+
+    fn elements() -> impl Strategy<Value = Vec<Element>> {
+        prop::collection::vec(
+            (0u8..32, any::<u16>())
+                .prop_map(|(id, value)| Element { id, value }),
+            0..64,
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn ids_are_unique_and_first_occurrence_is_kept(elements in elements()) {
+            let sut = IdentifiedVecOf::from_iter(elements.clone());
+            let expected = elements
+                .into_iter()
+                .unique_by(|e| e.id)
+                .collect_vec();
+            prop_assert_eq!(sut.items(), expected);
+        }
+
+        #[test]
+        fn try_insert_unique_fails_iff_id_is_present(
+            elements in elements(),
+            element in (0u8..32, any::<u16>())
+                .prop_map(|(id, value)| Element { id, value })
+        ) {
+            let mut sut = IdentifiedVecOf::from_iter(elements);
+            let was_present = sut.contains_id(&element.id);
+            let before = sut.clone();
+            let result = sut.try_insert_unique(element.clone());
+            prop_assert_eq!(result.is_err(), was_present);
+            if was_present {
+                prop_assert_eq!(sut, before);
+            } else {
+                prop_assert_eq!(sut.last(), Some(&element));
+                prop_assert_eq!(sut.len(), before.len() + 1);
+            }
+        }
+
+        #[test]
+        fn remove_by_id_keeps_order_of_others(
+            elements in elements(),
+            id in 0u8..32
+        ) {
+            let mut sut = IdentifiedVecOf::from_iter(elements);
+            let expected = sut
+                .items()
+                .into_iter()
+                .filter(|e| e.id != id)
+                .collect_vec();
+            sut.remove_by_id(&id);
+            prop_assert!(!sut.contains_id(&id));
+            prop_assert_eq!(sut.items(), expected);
+        }
+
+        #[test]
+        fn index_access_agrees_with_iteration(elements in elements()) {
+            let sut = IdentifiedVecOf::from_iter(elements);
+            for (index, element) in sut.iter().enumerate() {
+                prop_assert_eq!(&sut[index], element);
+                prop_assert_eq!(sut.get_id(&element.id), Some(element));
+                prop_assert_eq!(sut.index_of_id(&element.id), Some(index));
+            }
+        }
+    }
+}
@@ -1,33 +1,49 @@
 #![allow(unused_imports)]
 
+#[cfg(feature = "gateway")]
+mod gateway_api;
 mod hierarchical_deterministic;
+mod identified_vec_of;
 mod identified_vec_via;
 mod logic;
+#[cfg(feature = "gateway")]
+mod networking;
+mod olympia_import;
 mod profilesnapshot_version;
+mod transaction_models;
 mod v100;
 mod wallet;
+#[cfg(feature = "radix-connect")]
+mod wallet_interaction;
 mod wallet_kit_common;
 
 pub mod prelude {
 
+    #[cfg(feature = "gateway")]
+    pub use crate::gateway_api::*;
     pub use crate::hierarchical_deterministic::*;
+    pub use crate::identified_vec_of::*;
     pub use crate::identified_vec_via::*;
     pub use crate::logic::*;
+    #[cfg(feature = "gateway")]
+    pub use crate::networking::*;
+    pub use crate::olympia_import::*;
     pub use crate::profilesnapshot_version::*;
+    pub use crate::transaction_models::*;
     pub use crate::v100::*;
     pub use crate::wallet::*;
+    #[cfg(feature = "radix-connect")]
+    pub use crate::wallet_interaction::*;
     pub use crate::wallet_kit_common::*;
 
     pub(crate) use std::collections::{BTreeSet, HashMap, HashSet};
 
     pub(crate) use ::identified_vec::{
-        Identifiable, IdentifiedVec, IdentifiedVecOf, IsIdentifiedVec,
-        ItemsCloned,
+        Identifiable, IdentifiedVec, IsIdentifiedVec, ItemsCloned,
     };
 
     pub(crate) use ::hex::decode as hex_decode;
     pub(crate) use ::hex::encode as hex_encode;
-    pub(crate) use iso8601_timestamp::Timestamp;
     pub(crate) use itertools::Itertools;
     pub(crate) use log::{debug, error, info, trace, warn};
     pub(crate) use serde::{
@@ -68,9 +84,7 @@ impl UniffiCustomTypeConverter for Timestamp {
     type Builtin = String;
 
     fn into_custom(val: Self::Builtin) -> uniffi::Result<Self> {
-        Timestamp::parse(val.as_str())
-            .ok_or(CommonError::InvalidISO8601String(val))
-            .map_err(|e| e.into())
+        Timestamp::parse(val).map_err(|e| e.into())
     }
 
     fn from_custom(obj: Self) -> Self::Builtin {
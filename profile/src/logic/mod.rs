@@ -1,5 +1,33 @@
 #![allow(unused_imports)]
 
+mod profile_account_order;
+mod profile_anonymized_summary;
+mod profile_content_hint;
+mod profile_derivation_indices;
+mod profile_entities_controlled_by_key;
+mod profile_entities_linked_to_factor_source;
+mod profile_import_validation;
+mod profile_manifest_warnings;
+mod profile_network_management;
 mod profile_next_derivation;
+mod profile_securify_account_plan;
+mod profile_signing_plan;
+mod profile_signing_policy;
+mod profile_snapshot_version;
+mod profile_support_dump;
 
+pub use profile_account_order::*;
+pub use profile_anonymized_summary::*;
+pub use profile_content_hint::*;
+pub use profile_derivation_indices::*;
+pub use profile_entities_controlled_by_key::*;
+pub use profile_entities_linked_to_factor_source::*;
+pub use profile_import_validation::*;
+pub use profile_manifest_warnings::*;
+pub use profile_network_management::*;
 pub use profile_next_derivation::*;
+pub use profile_securify_account_plan::*;
+pub use profile_signing_plan::*;
+pub use profile_signing_policy::*;
+pub use profile_snapshot_version::*;
+pub use profile_support_dump::*;
@@ -0,0 +1,142 @@
+use crate::prelude::*;
+
+impl Accounts {
+    /// Returns the accounts in the order of `ordered_addresses`, followed by
+    /// the accounts missing from `ordered_addresses` - e.g. hidden ones or
+    /// ones created since the order was read - in their current relative
+    /// order.
+    ///
+    /// Throws an error if any address is unknown or listed more than once.
+    pub fn reordered(
+        &self,
+        ordered_addresses: &[AccountAddress],
+    ) -> Result<Self> {
+        let mut listed = HashSet::new();
+        let mut accounts = ordered_addresses
+            .iter()
+            .map(|address| {
+                if !listed.insert(address.clone()) {
+                    return Err(CommonError::AccountListedMoreThanOnce(
+                        address.clone(),
+                    ));
+                }
+                self.get_account_by_address(address)
+                    .cloned()
+                    .ok_or(CommonError::UnknownAccount)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        accounts.extend(
+            self.items()
+                .into_iter()
+                .filter(|a| !listed.contains(&a.address)),
+        );
+        Ok(Self::with_accounts(accounts))
+    }
+}
+
+impl Profile {
+    /// Reorders the Accounts on `network_id` as `ordered_addresses`, see
+    /// [`Accounts::reordered`], returns `true` if the order changed.
+    ///
+    /// The order of the Accounts is persisted - and synced - as part of the
+    /// Profile, replacing the default order, which is by creation.
+    pub fn reorder_accounts(
+        &mut self,
+        network_id: NetworkID,
+        ordered_addresses: &[AccountAddress],
+    ) -> Result<bool> {
+        let accounts = self
+            .networks
+            .get_id(&network_id)
+            .map(|n| n.accounts.clone())
+            .ok_or(CommonError::UnknownNetworkInProfile(network_id))?;
+        let reordered = accounts.reordered(ordered_addresses)?;
+        if reordered == accounts {
+            return Ok(false);
+        }
+        self.networks
+            .update_with(&network_id, |n| n.accounts = reordered.clone());
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn reordered() {
+        let sut = Accounts::placeholder_mainnet();
+        let alice = Account::placeholder_mainnet();
+        let bob = Account::placeholder_mainnet_other();
+        assert_eq!(
+            sut.reordered(&[bob.address.clone(), alice.address.clone()]),
+            Ok(Accounts::with_accounts([bob, alice]))
+        );
+    }
+
+    #[test]
+    fn reordered_appends_missing_accounts() {
+        let carol = Account::placeholder_stokenet_carol();
+        let diana = Account::placeholder_stokenet_diana();
+        let sut = Accounts::placeholder_stokenet();
+        assert_eq!(
+            sut.reordered(&[diana.address.clone()]),
+            Ok(Accounts::with_accounts([diana, carol]))
+        );
+    }
+
+    #[test]
+    fn reordered_empty_keeps_order() {
+        let sut = Accounts::placeholder_mainnet();
+        assert_eq!(sut.reordered(&[]), Ok(sut));
+    }
+
+    #[test]
+    fn reordered_unknown_account_fails() {
+        let sut = Accounts::placeholder_mainnet();
+        assert_eq!(
+            sut.reordered(&[Account::placeholder_stokenet_carol().address]),
+            Err(CommonError::UnknownAccount)
+        );
+    }
+
+    #[test]
+    fn reordered_duplicate_fails() {
+        let sut = Accounts::placeholder_mainnet();
+        let address = Account::placeholder_mainnet().address;
+        assert_eq!(
+            sut.reordered(&[address.clone(), address.clone()]),
+            Err(CommonError::AccountListedMoreThanOnce(address))
+        );
+    }
+
+    #[test]
+    fn profile_reorder_accounts() {
+        let mut sut = Profile::placeholder();
+        let alice = Account::placeholder_mainnet();
+        let bob = Account::placeholder_mainnet_other();
+
+        assert_eq!(
+            sut.reorder_accounts(NetworkID::Mainnet, &[bob.address.clone()]),
+            Ok(true)
+        );
+        assert_eq!(
+            sut.networks.get_id(&NetworkID::Mainnet).unwrap().accounts,
+            Accounts::with_accounts([bob.clone(), alice])
+        );
+        assert_eq!(
+            sut.reorder_accounts(NetworkID::Mainnet, &[bob.address]),
+            Ok(false)
+        );
+    }
+
+    #[test]
+    fn profile_reorder_accounts_unknown_network_fails() {
+        let mut sut = Profile::placeholder();
+        assert_eq!(
+            sut.reorder_accounts(NetworkID::Kisharnet, &[]),
+            Err(CommonError::UnknownNetworkInProfile(NetworkID::Kisharnet))
+        );
+    }
+}
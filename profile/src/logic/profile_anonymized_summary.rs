@@ -0,0 +1,187 @@
+use crate::prelude::*;
+
+/// The number of factor sources of one kind in a Profile.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct FactorSourceKindCount {
+    pub kind: FactorSourceKind,
+    pub count: u32,
+}
+
+/// Non-identifying aggregates of the contents of a Profile on one network.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct AnonymizedNetworkSummary {
+    pub network_id: NetworkID,
+    pub number_of_accounts: u32,
+    /// Number of accounts flagged as deleted by the user.
+    pub number_of_hidden_accounts: u32,
+    pub number_of_personas: u32,
+    /// Number of personas flagged as deleted by the user.
+    pub number_of_hidden_personas: u32,
+    pub number_of_authorized_dapps: u32,
+}
+
+/// A summary of a Profile containing only non-identifying aggregates, i.e.
+/// no addresses, keys, names, IDs or dates, which hosts can use for opt-in
+/// diagnostics without risking leaking personally identifiable information.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct AnonymizedProfileSummary {
+    pub snapshot_version: ProfileSnapshotVersion,
+    /// Sorted by kind, only kinds present in the Profile are included.
+    pub factor_source_kinds: Vec<FactorSourceKindCount>,
+    /// In the same order as the networks of the Profile.
+    pub networks: Vec<AnonymizedNetworkSummary>,
+    pub number_of_p2p_links: u32,
+    pub number_of_gateways: u32,
+}
+
+fn count<I>(items: I) -> u32
+where
+    I: IntoIterator,
+{
+    items.into_iter().count() as u32
+}
+
+impl AnonymizedNetworkSummary {
+    fn new(value: &ProfileNetwork) -> Self {
+        let is_hidden =
+            |flags: &EntityFlags| flags.contains_id(&EntityFlag::DeletedByUser);
+        Self {
+            network_id: value.id,
+            number_of_accounts: value.accounts.len() as u32,
+            number_of_hidden_accounts: count(
+                value.accounts.iter().filter(|a| is_hidden(&a.flags)),
+            ),
+            number_of_personas: value.personas.len() as u32,
+            number_of_hidden_personas: count(
+                value.personas.iter().filter(|p| is_hidden(&p.flags)),
+            ),
+            number_of_authorized_dapps: value.authorized_dapps.len() as u32,
+        }
+    }
+}
+
+impl Profile {
+    /// Returns a summary of this Profile containing only non-identifying
+    /// aggregates, e.g. the number of accounts per network, safe to use
+    /// for opt-in diagnostics.
+    pub fn anonymized_summary(&self) -> AnonymizedProfileSummary {
+        let factor_source_kinds = self
+            .factor_sources
+            .iter()
+            .map(|f| f.factor_source_kind())
+            .counts()
+            .into_iter()
+            .map(|(kind, count)| FactorSourceKindCount {
+                kind,
+                count: count as u32,
+            })
+            .sorted_by_key(|c| c.kind)
+            .collect_vec();
+
+        AnonymizedProfileSummary {
+            snapshot_version: self.header.snapshot_version,
+            factor_source_kinds,
+            networks: self
+                .networks
+                .iter()
+                .map(|n| AnonymizedNetworkSummary::new(&n))
+                .collect_vec(),
+            number_of_p2p_links: self.app_preferences.p2p_links.len() as u32,
+            number_of_gateways: self.app_preferences.gateways.len() as u32,
+        }
+    }
+}
+
+#[uniffi::export]
+pub fn profile_anonymized_summary(
+    profile: &Profile,
+) -> AnonymizedProfileSummary {
+    profile.anonymized_summary()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn placeholder() {
+        let sut = Profile::placeholder().anonymized_summary();
+        assert_eq!(
+            sut,
+            AnonymizedProfileSummary {
+                snapshot_version: ProfileSnapshotVersion::V100,
+                factor_source_kinds: vec![
+                    FactorSourceKindCount {
+                        kind: FactorSourceKind::Device,
+                        count: 1
+                    },
+                    FactorSourceKindCount {
+                        kind: FactorSourceKind::LedgerHQHardwareWallet,
+                        count: 1
+                    },
+                ],
+                networks: vec![
+                    AnonymizedNetworkSummary {
+                        network_id: NetworkID::Mainnet,
+                        number_of_accounts: 2,
+                        number_of_hidden_accounts: 1,
+                        number_of_personas: 2,
+                        number_of_hidden_personas: 1,
+                        number_of_authorized_dapps: 2,
+                    },
+                    AnonymizedNetworkSummary {
+                        network_id: NetworkID::Stokenet,
+                        number_of_accounts: 2,
+                        number_of_hidden_accounts: 1,
+                        number_of_personas: 2,
+                        number_of_hidden_personas: 1,
+                        number_of_authorized_dapps: 2,
+                    },
+                ],
+                number_of_p2p_links: 2,
+                number_of_gateways: Profile::placeholder()
+                    .app_preferences
+                    .gateways
+                    .len() as u32,
+            }
+        );
+    }
+
+    #[test]
+    fn does_not_contain_identifying_information() {
+        let profile = Profile::placeholder();
+        let summary = format!("{:?}", profile.anonymized_summary());
+        let account = Account::placeholder();
+        assert!(!summary.contains(&account.address.address));
+        assert!(!summary.contains(&account.display_name.value));
+        assert!(!summary.contains(&profile.id().to_string()));
+        assert!(!summary.contains(&profile.bdfs().id.to_string()));
+    }
+
+    #[test]
+    fn empty_profile() {
+        let sut = ProfileBuilder::new().build().anonymized_summary();
+        assert!(sut.networks.is_empty());
+        assert_eq!(
+            sut.factor_source_kinds,
+            vec![FactorSourceKindCount {
+                kind: FactorSourceKind::Device,
+                count: 1
+            }]
+        );
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn anonymized_summary() {
+        let profile = Profile::placeholder();
+        assert_eq!(
+            profile_anonymized_summary(&profile),
+            profile.anonymized_summary()
+        );
+    }
+}
@@ -0,0 +1,83 @@
+use crate::prelude::*;
+
+impl Profile {
+    /// `true` if the `content_hint` of the header agrees with the actual
+    /// number of accounts, personas and networks of this Profile.
+    pub fn is_content_hint_consistent(&self) -> bool {
+        self.header.content_hint == self.networks.content_hint()
+    }
+
+    /// Sets the `content_hint` of the header to the actual number of
+    /// accounts, personas and networks of this Profile, returns `true` if
+    /// the hint disagreed, e.g. for an imported snapshot produced by a
+    /// buggy client.
+    pub fn repair_content_hint(&mut self) -> bool {
+        if self.is_content_hint_consistent() {
+            return false;
+        }
+        log::warn!(
+            "Repairing content hint of Profile with ID {}, was: {}",
+            self.id(),
+            self.header.content_hint
+        );
+        self.update_content_hint();
+        true
+    }
+
+    /// Sets the `content_hint` of the header to the actual number of
+    /// accounts, personas and networks of this Profile, done after every
+    /// mutation of the Profile.
+    pub(crate) fn update_content_hint(&mut self) {
+        self.header.content_hint = self.networks.content_hint();
+    }
+}
+
+/// Returns `profile` with a `content_hint` agreeing with its actual
+/// content, see [`Profile::repair_content_hint`].
+#[uniffi::export]
+pub fn profile_with_repaired_content_hint(profile: Profile) -> Profile {
+    let mut profile = profile;
+    profile.repair_content_hint();
+    profile
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn placeholder_is_consistent() {
+        let mut sut = Profile::placeholder();
+        assert!(sut.is_content_hint_consistent());
+        assert!(!sut.repair_content_hint());
+        assert_eq!(sut, Profile::placeholder());
+    }
+
+    #[test]
+    fn repair_inconsistent() {
+        let mut sut = Profile::placeholder();
+        sut.header.content_hint = ContentHint::with_counters(1, 0, 3);
+        assert!(!sut.is_content_hint_consistent());
+        assert!(sut.repair_content_hint());
+        assert_eq!(
+            sut.header.content_hint,
+            ContentHint::with_counters(4, 4, 2)
+        );
+        assert!(sut.is_content_hint_consistent());
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn repaired_content_hint() {
+        let mut profile = Profile::placeholder();
+        profile.header.content_hint = ContentHint::new();
+        assert_eq!(
+            profile_with_repaired_content_hint(profile),
+            Profile::placeholder()
+        );
+    }
+}
@@ -0,0 +1,618 @@
+use crate::prelude::*;
+
+/// An inclusive range of derivation indices, `start..=end`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct DerivationIndexRange {
+    /// The first index in the range.
+    pub start: HDPathValue,
+
+    /// The last index in the range, inclusive.
+    pub end: HDPathValue,
+}
+
+impl DerivationIndexRange {
+    /// The range `start..=end`, failing if `start` is greater than `end`.
+    pub fn new(start: HDPathValue, end: HDPathValue) -> Result<Self> {
+        if start > end {
+            return Err(CommonError::InvalidDerivationIndexRange {
+                start,
+                end,
+            });
+        }
+        Ok(Self { start, end })
+    }
+}
+
+#[uniffi::export]
+pub fn new_derivation_index_range(
+    start: HDPathValue,
+    end: HDPathValue,
+) -> Result<DerivationIndexRange> {
+    DerivationIndexRange::new(start, end)
+}
+
+/// A report of the derivation indices used by the entities of one kind on
+/// one network controlled by one factor source, as produced by
+/// [`Profile::derivation_indices_report`].
+///
+/// Imported profiles might contain non-contiguous indices (`gaps`) or
+/// even several entities claiming the same index (`collisions`).
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct DerivationIndicesReport {
+    /// Ranges of indices below the highest used index which no entity
+    /// uses, sorted. Ranges rather than single indices, since an imported
+    /// profile might use an index close to the largest possible one.
+    pub gaps: Vec<DerivationIndexRange>,
+
+    /// Indices used by more than one entity, sorted.
+    pub collisions: Vec<HDPathValue>,
+
+    /// The index to use for the next entity, one past the highest used.
+    pub next: HDPathValue,
+}
+
+impl DerivationIndicesReport {
+    /// `true` if there are neither gaps nor collisions.
+    pub fn is_contiguous(&self) -> bool {
+        self.gaps.is_empty() && self.collisions.is_empty()
+    }
+}
+
+impl Profile {
+    /// The derivation indices, in order of appearance and including
+    /// duplicates, of the `transaction_signing` factor instances of all
+    /// entities of `entity_kind` on `network_id` which are controlled by
+    /// the factor source with id `factor_source_id`.
+    fn used_derivation_indices(
+        &self,
+        factor_source_id: &FactorSourceIDFromHash,
+        network_id: NetworkID,
+        entity_kind: EntityKind,
+    ) -> Vec<HDPathValue> {
+        let Some(network) = self.networks.get_id(&network_id) else {
+            return Vec::new();
+        };
+        let security_states = match entity_kind {
+            EntityKind::Accounts => network
+                .accounts
+                .iter()
+                .map(|a| a.security_state.clone())
+                .collect_vec(),
+            EntityKind::Persona => network
+                .personas
+                .iter()
+                .map(|p| p.security_state.clone())
+                .collect_vec(),
+        };
+        security_states
+            .into_iter()
+            .filter_map(|s| {
+                let instance = s.transaction_signing_factor_instance()?;
+                (instance.factor_source_id == *factor_source_id).then(|| {
+                    instance.derivation_path().last_component().index()
+                })
+            })
+            .collect_vec()
+    }
+
+    /// Returns the highest derivation index used by any entity of
+    /// `entity_kind` on `network_id` controlled by the factor source with id
+    /// `factor_source_id`, or `None` if no such entity exists.
+    pub fn highest_used_derivation_index(
+        &self,
+        factor_source_id: &FactorSourceIDFromHash,
+        network_id: NetworkID,
+        entity_kind: EntityKind,
+    ) -> Option<HDPathValue> {
+        self.used_derivation_indices(factor_source_id, network_id, entity_kind)
+            .into_iter()
+            .max()
+    }
+
+    /// Inspects the derivation indices used by entities of `entity_kind` on
+    /// `network_id` controlled by the factor source with id
+    /// `factor_source_id`, reporting gaps and collisions together with the
+    /// index to use for the next entity, which never re-derives an existing
+    /// address.
+    pub fn derivation_indices_report(
+        &self,
+        factor_source_id: &FactorSourceIDFromHash,
+        network_id: NetworkID,
+        entity_kind: EntityKind,
+    ) -> DerivationIndicesReport {
+        let used = self.used_derivation_indices(
+            factor_source_id,
+            network_id,
+            entity_kind,
+        );
+        let counts = used.iter().counts();
+        let next = used.iter().max().map(|i| i + 1).unwrap_or(0);
+
+        let mut gaps = Vec::new();
+        let mut expected: HDPathValue = 0;
+        for index in counts.keys().copied().copied().sorted() {
+            if index > expected {
+                gaps.push(DerivationIndexRange {
+                    start: expected,
+                    end: index - 1,
+                });
+            }
+            expected = index + 1;
+        }
+
+        let collisions = counts
+            .into_iter()
+            .filter(|(_, n)| *n > 1)
+            .map(|(i, _)| *i)
+            .sorted()
+            .collect_vec();
+
+        DerivationIndicesReport {
+            gaps,
+            collisions,
+            next,
+        }
+    }
+
+    /// Repairs the derivation indices used by entities of `entity_kind` on
+    /// `network_id` controlled by the factor source with id
+    /// `factor_source_id`, by raising the next derivation index persisted in
+    /// the factor source to one past the highest used index, should it have
+    /// been at or below it, e.g. in an imported profile. If no indices are
+    /// persisted for `network_id` yet, they are appended. Returns the report
+    /// of the indices as inspected before the repair.
+    ///
+    /// Does nothing but report if the factor source is not in Profile.
+    ///
+    /// Gaps are deliberately left unused, since the addresses at those
+    /// indices might belong to deleted entities, and collisions cannot be
+    /// repaired without changing the address of an entity, so both are only
+    /// reported.
+    pub fn repair_derivation_indices(
+        &mut self,
+        factor_source_id: &FactorSourceIDFromHash,
+        network_id: NetworkID,
+        entity_kind: EntityKind,
+    ) -> DerivationIndicesReport {
+        let report = self.derivation_indices_report(
+            factor_source_id,
+            network_id,
+            entity_kind.clone(),
+        );
+        _ = self.factor_sources.update_with(
+            &factor_source_id.clone().into(),
+            |f| {
+                let indices =
+                    &mut f.common_mut().next_derivation_indices_per_network;
+                if !indices.update_with(&network_id, |i| {
+                    i.raise_index_for(entity_kind.clone(), report.next)
+                }) {
+                    let mut unused = NextDerivationIndices::unused(network_id);
+                    unused.raise_index_for(entity_kind.clone(), report.next);
+                    indices.append(unused);
+                }
+            },
+        );
+        report
+    }
+
+    /// Repairs the persisted next derivation indices of every factor source,
+    /// for both Accounts and Personas on every network they are persisted
+    /// for, see [`Profile::repair_derivation_indices`]. Run when a Profile is
+    /// imported. Returns the reports which are not contiguous.
+    ///
+    /// Networks without persisted indices are left as they are, the next
+    /// index on those is inferred from the entities in Profile anyway.
+    pub fn repair_all_derivation_indices(
+        &mut self,
+    ) -> Vec<DerivationIndicesReport> {
+        let persisted = self
+            .factor_sources
+            .iter()
+            .filter_map(|f| {
+                let id = f.factor_source_id().as_hash().cloned()?;
+                let network_ids = f
+                    .common()
+                    .next_derivation_indices_per_network
+                    .ids()
+                    .into_iter()
+                    .cloned()
+                    .collect_vec();
+                Some((id, network_ids))
+            })
+            .collect_vec();
+
+        let mut reports = Vec::new();
+        for (factor_source_id, network_ids) in persisted {
+            for network_id in network_ids {
+                for kind in [EntityKind::Accounts, EntityKind::Persona] {
+                    let report = self.repair_derivation_indices(
+                        &factor_source_id,
+                        network_id,
+                        kind,
+                    );
+                    if !report.is_contiguous() {
+                        reports.push(report);
+                    }
+                }
+            }
+        }
+        reports
+    }
+}
+
+/// Inspects the derivation indices used by entities of `entity_kind` on
+/// `network_id` controlled by the factor source with id `factor_source_id`,
+/// see [`Profile::derivation_indices_report`].
+#[uniffi::export]
+pub fn profile_derivation_indices_report(
+    profile: &Profile,
+    factor_source_id: &FactorSourceIDFromHash,
+    network_id: NetworkID,
+    entity_kind: EntityKind,
+) -> DerivationIndicesReport {
+    profile.derivation_indices_report(factor_source_id, network_id, entity_kind)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn profile_with_mainnet_accounts<I>(accounts: I) -> Profile
+    where
+        I: IntoIterator<Item = Account>,
+    {
+        let mut profile = Profile::placeholder();
+        profile.networks = ProfileNetworks::with_network(ProfileNetwork::new(
+            NetworkID::Mainnet,
+            Accounts::from_iter(accounts),
+            Personas::new(),
+            AuthorizedDapps::new(),
+        ));
+        profile
+    }
+
+    fn bdfs_id() -> FactorSourceIDFromHash {
+        DeviceFactorSource::placeholder_babylon().id
+    }
+
+    #[test]
+    fn highest_used_derivation_index_accounts() {
+        let profile = Profile::placeholder();
+        assert_eq!(
+            profile.highest_used_derivation_index(
+                &bdfs_id(),
+                NetworkID::Mainnet,
+                EntityKind::Accounts
+            ),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn highest_used_derivation_index_personas() {
+        let profile = Profile::placeholder();
+        assert_eq!(
+            profile.highest_used_derivation_index(
+                &bdfs_id(),
+                NetworkID::Stokenet,
+                EntityKind::Persona
+            ),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn highest_used_derivation_index_unknown_network() {
+        let profile = Profile::placeholder();
+        assert_eq!(
+            profile.highest_used_derivation_index(
+                &bdfs_id(),
+                NetworkID::Nebunet,
+                EntityKind::Accounts
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn highest_used_derivation_index_other_factor_source() {
+        let profile = Profile::placeholder();
+        assert_eq!(
+            profile.highest_used_derivation_index(
+                &DeviceFactorSource::placeholder_olympia().id,
+                NetworkID::Mainnet,
+                EntityKind::Accounts
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn report_contiguous() {
+        let profile = Profile::placeholder();
+        let report = profile.derivation_indices_report(
+            &bdfs_id(),
+            NetworkID::Mainnet,
+            EntityKind::Accounts,
+        );
+        assert!(report.is_contiguous());
+        assert_eq!(report.next, 2);
+    }
+
+    #[test]
+    fn report_empty() {
+        let profile = profile_with_mainnet_accounts([]);
+        assert_eq!(
+            profile.derivation_indices_report(
+                &bdfs_id(),
+                NetworkID::Mainnet,
+                EntityKind::Accounts,
+            ),
+            DerivationIndicesReport {
+                gaps: Vec::new(),
+                collisions: Vec::new(),
+                next: 0
+            }
+        );
+    }
+
+    #[test]
+    fn report_gap() {
+        let profile = profile_with_mainnet_accounts([
+            Account::placeholder_mainnet_alice(),
+            Account::placeholder_mainnet_carol(),
+        ]);
+        assert_eq!(
+            profile.derivation_indices_report(
+                &bdfs_id(),
+                NetworkID::Mainnet,
+                EntityKind::Accounts,
+            ),
+            DerivationIndicesReport {
+                gaps: vec![DerivationIndexRange::new(1, 1).unwrap()],
+                collisions: Vec::new(),
+                next: 3
+            }
+        );
+    }
+
+    #[test]
+    fn report_collision() {
+        let mut bob = Account::placeholder_mainnet_bob();
+        bob.security_state =
+            Account::placeholder_mainnet_alice().security_state;
+        let profile = profile_with_mainnet_accounts([
+            Account::placeholder_mainnet_alice(),
+            bob,
+        ]);
+        let report = profile.derivation_indices_report(
+            &bdfs_id(),
+            NetworkID::Mainnet,
+            EntityKind::Accounts,
+        );
+        assert_eq!(report.collisions, vec![0]);
+        assert!(!report.is_contiguous());
+    }
+
+    #[test]
+    fn report_gap_near_max_index_is_a_single_range() {
+        let high: HDPathValue = (1 << 31) - 2;
+        let mut carol = Account::placeholder_mainnet_carol();
+        let instance =
+            HierarchicalDeterministicFactorInstance::with_key_and_path(
+                bdfs_id(),
+                carol
+                    .security_state
+                    .transaction_signing_factor_instance()
+                    .unwrap()
+                    .public_key
+                    .public_key
+                    .clone(),
+                AccountPath::new_mainnet_transaction_signing(high).into(),
+            );
+        carol.security_state = EntitySecurityState::Unsecured {
+            value: UnsecuredEntityControl::new(instance, None).unwrap(),
+        };
+        let profile = profile_with_mainnet_accounts([
+            Account::placeholder_mainnet_alice(),
+            carol,
+        ]);
+        let report = profile.derivation_indices_report(
+            &bdfs_id(),
+            NetworkID::Mainnet,
+            EntityKind::Accounts,
+        );
+        assert_eq!(
+            report.gaps,
+            vec![DerivationIndexRange::new(1, high - 1).unwrap()]
+        );
+        assert_eq!(report.next, high + 1);
+    }
+
+    #[test]
+    fn repair_raises_persisted_next_index_past_highest_used() {
+        let mut profile = Profile::placeholder();
+        let bdfs_id = bdfs_id();
+        _ = profile
+            .factor_sources
+            .update_with(&bdfs_id.clone().into(), |f| {
+                f.common_mut().next_derivation_indices_per_network =
+                    NextDerivationIndicesPerNetwork::from_iter([
+                        NextDerivationIndices::new(NetworkID::Mainnet, 1, 2),
+                    ]);
+            });
+        assert!(profile.validate_next_derivation_indices().is_err());
+
+        let report = profile.repair_derivation_indices(
+            &bdfs_id,
+            NetworkID::Mainnet,
+            EntityKind::Accounts,
+        );
+
+        assert!(report.is_contiguous());
+        assert_eq!(
+            profile.persisted_next_derivation_indices(
+                &bdfs_id,
+                NetworkID::Mainnet
+            ),
+            Some(NextDerivationIndices::new(NetworkID::Mainnet, 2, 2))
+        );
+        assert_eq!(profile.validate_next_derivation_indices(), Ok(()));
+    }
+
+    #[test]
+    fn repair_appends_missing_persisted_next_index() {
+        let mut profile = Profile::placeholder();
+        let bdfs_id = bdfs_id();
+        assert_eq!(
+            profile.persisted_next_derivation_indices(
+                &bdfs_id,
+                NetworkID::Mainnet
+            ),
+            None
+        );
+
+        profile.repair_derivation_indices(
+            &bdfs_id,
+            NetworkID::Mainnet,
+            EntityKind::Accounts,
+        );
+
+        assert_eq!(
+            profile.persisted_next_derivation_indices(
+                &bdfs_id,
+                NetworkID::Mainnet
+            ),
+            Some(NextDerivationIndices::new(NetworkID::Mainnet, 2, 0))
+        );
+    }
+
+    #[test]
+    fn repair_all_only_touches_persisted_networks() {
+        let mut profile = Profile::placeholder();
+        let bdfs_id = bdfs_id();
+        _ = profile
+            .factor_sources
+            .update_with(&bdfs_id.clone().into(), |f| {
+                f.common_mut().next_derivation_indices_per_network =
+                    NextDerivationIndicesPerNetwork::from_iter([
+                        NextDerivationIndices::new(NetworkID::Mainnet, 0, 0),
+                    ]);
+            });
+
+        assert!(profile.repair_all_derivation_indices().is_empty());
+
+        assert_eq!(
+            profile.persisted_next_derivation_indices(
+                &bdfs_id,
+                NetworkID::Mainnet
+            ),
+            Some(NextDerivationIndices::new(NetworkID::Mainnet, 2, 2))
+        );
+        assert_eq!(
+            profile.persisted_next_derivation_indices(
+                &bdfs_id,
+                NetworkID::Stokenet
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn range_start_after_end_is_invalid() {
+        assert_eq!(
+            DerivationIndexRange::new(2, 1),
+            Err(CommonError::InvalidDerivationIndexRange { start: 2, end: 1 })
+        );
+        assert_eq!(
+            DerivationIndexRange::new(1, 1),
+            Ok(DerivationIndexRange { start: 1, end: 1 })
+        );
+    }
+
+    #[test]
+    fn repair_never_lowers_persisted_next_index() {
+        let mut profile = Profile::placeholder();
+        let bdfs_id = bdfs_id();
+        _ = profile
+            .factor_sources
+            .update_with(&bdfs_id.clone().into(), |f| {
+                f.common_mut().next_derivation_indices_per_network =
+                    NextDerivationIndicesPerNetwork::from_iter([
+                        NextDerivationIndices::new(NetworkID::Mainnet, 7, 2),
+                    ]);
+            });
+        profile.repair_derivation_indices(
+            &bdfs_id,
+            NetworkID::Mainnet,
+            EntityKind::Accounts,
+        );
+        assert_eq!(
+            profile
+                .persisted_next_derivation_indices(&bdfs_id, NetworkID::Mainnet)
+                .unwrap()
+                .for_account,
+            7
+        );
+    }
+
+    #[test]
+    fn next_derivation_index_skips_gap() {
+        let profile = profile_with_mainnet_accounts([
+            Account::placeholder_mainnet_alice(),
+            Account::placeholder_mainnet_carol(),
+        ]);
+        assert_eq!(
+            profile.next_derivation_index_for_entity(
+                EntityKind::Accounts,
+                NetworkID::Mainnet
+            ),
+            3
+        );
+    }
+
+    #[test]
+    fn next_derivation_index_personas() {
+        let profile = Profile::placeholder();
+        assert_eq!(
+            profile.next_derivation_index_for_entity(
+                EntityKind::Persona,
+                NetworkID::Mainnet
+            ),
+            2
+        );
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn new_range() {
+        assert_eq!(
+            new_derivation_index_range(0, 4),
+            DerivationIndexRange::new(0, 4)
+        );
+    }
+
+    #[test]
+    fn derivation_indices_report() {
+        let sut = Profile::placeholder();
+        let id = DeviceFactorSource::placeholder_babylon().id;
+        assert_eq!(
+            profile_derivation_indices_report(
+                &sut,
+                &id,
+                NetworkID::Mainnet,
+                EntityKind::Accounts
+            ),
+            sut.derivation_indices_report(
+                &id,
+                NetworkID::Mainnet,
+                EntityKind::Accounts
+            )
+        );
+    }
+}
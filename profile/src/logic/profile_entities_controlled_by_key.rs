@@ -0,0 +1,201 @@
+use crate::prelude::*;
+
+/// The public keys of all factor instances controlling an entity, i.e. the
+/// transaction signing and - if any - the authentication signing keys, none
+/// for a watch-only entity.
+fn controlling_public_keys(
+    security_state: &EntitySecurityState,
+) -> Vec<PublicKey> {
+    match security_state {
+        EntitySecurityState::Unsecured { value } => {
+            std::iter::once(&value.transaction_signing)
+                .chain(value.authentication_signing.iter())
+                .map(|instance| instance.public_key.public_key.clone())
+                .collect_vec()
+        }
+        EntitySecurityState::Unowned => Vec::new(),
+    }
+}
+
+impl Profile {
+    /// Returns an index from the hash of the public key of every factor
+    /// instance controlling an Account or Persona on any network, to the
+    /// addresses of the entities controlled by it - in order of appearance,
+    /// more than one if the key is reused.
+    pub fn entities_by_public_key_hash(
+        &self,
+    ) -> HashMap<PublicKeyHash, Vec<AddressOfAccountOrPersona>> {
+        let accounts = self.networks.iter().flat_map(|n| {
+            n.accounts.iter().map(|a| {
+                (
+                    AddressOfAccountOrPersona::from(a.address.clone()),
+                    controlling_public_keys(&a.security_state),
+                )
+            })
+        });
+        let personas = self.networks.iter().flat_map(|n| {
+            n.personas.iter().map(|p| {
+                (
+                    AddressOfAccountOrPersona::from(p.address.clone()),
+                    controlling_public_keys(&p.security_state),
+                )
+            })
+        });
+
+        let mut index =
+            HashMap::<PublicKeyHash, Vec<AddressOfAccountOrPersona>>::new();
+        for (address, public_keys) in accounts.chain(personas) {
+            for public_key in public_keys {
+                let addresses = index
+                    .entry(PublicKeyHash::hash_of_public_key(&public_key))
+                    .or_default();
+                if !addresses.contains(&address) {
+                    addresses.push(address.clone());
+                }
+            }
+        }
+        index
+    }
+
+    /// Returns the addresses of all Accounts and Personas controlled by the
+    /// public key with hash `public_key_hash`, e.g. to resolve the keys
+    /// referenced in a Dapp's proof of ownership request. Empty if no entity
+    /// is controlled by it.
+    pub fn entities_controlled_by_public_key_hash(
+        &self,
+        public_key_hash: &PublicKeyHash,
+    ) -> Vec<AddressOfAccountOrPersona> {
+        self.entities_by_public_key_hash()
+            .remove(public_key_hash)
+            .unwrap_or_default()
+    }
+
+    /// Returns the hashes of all public keys controlling more than one
+    /// entity, which should never happen, sorted.
+    pub fn reused_public_key_hashes(&self) -> Vec<PublicKeyHash> {
+        self.entities_by_public_key_hash()
+            .into_iter()
+            .filter(|(_, addresses)| addresses.len() > 1)
+            .map(|(hash, _)| hash)
+            .sorted()
+            .collect_vec()
+    }
+}
+
+#[uniffi::export]
+pub fn profile_entities_controlled_by_public_key_hash(
+    profile: &Profile,
+    public_key_hash: PublicKeyHash,
+) -> Vec<AddressOfAccountOrPersona> {
+    profile.entities_controlled_by_public_key_hash(&public_key_hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn transaction_signing_key_hash(
+        security_state: &EntitySecurityState,
+    ) -> PublicKeyHash {
+        PublicKeyHash::hash_of_public_key(
+            &security_state
+                .transaction_signing_factor_instance()
+                .unwrap()
+                .public_key
+                .public_key,
+        )
+    }
+
+    #[test]
+    fn account() {
+        let sut = Profile::placeholder();
+        let account = Account::placeholder();
+        assert_eq!(
+            sut.entities_controlled_by_public_key_hash(
+                &transaction_signing_key_hash(&account.security_state)
+            ),
+            vec![AddressOfAccountOrPersona::from(account.address)]
+        );
+    }
+
+    #[test]
+    fn persona() {
+        let sut = Profile::placeholder();
+        let persona = Persona::placeholder();
+        assert_eq!(
+            sut.entities_controlled_by_public_key_hash(
+                &transaction_signing_key_hash(&persona.security_state)
+            ),
+            vec![AddressOfAccountOrPersona::from(persona.address)]
+        );
+    }
+
+    #[test]
+    fn unknown_key() {
+        let sut = Profile::placeholder();
+        assert!(sut
+            .entities_controlled_by_public_key_hash(&PublicKeyHash::hash_of(
+                "unknown"
+            ))
+            .is_empty());
+    }
+
+    #[test]
+    fn every_entity_is_indexed() {
+        let sut = Profile::placeholder();
+        let number_of_entities = sut
+            .networks
+            .iter()
+            .map(|n| n.accounts.len() + n.personas.len())
+            .sum::<usize>();
+        let indexed = sut
+            .entities_by_public_key_hash()
+            .into_values()
+            .flatten()
+            .collect::<HashSet<_>>();
+        assert_eq!(indexed.len(), number_of_entities);
+    }
+
+    #[test]
+    fn no_reused_keys() {
+        assert!(Profile::placeholder().reused_public_key_hashes().is_empty());
+    }
+
+    #[test]
+    fn reused_key() {
+        let mut sut = Profile::placeholder();
+        let first = Account::placeholder_mainnet_alice();
+        let second = Account::placeholder_mainnet_bob();
+        let reused_security_state = first.security_state.clone();
+        sut.networks.update_account(&second.address, |a| {
+            a.security_state = reused_security_state.clone()
+        });
+        let hash = transaction_signing_key_hash(&first.security_state);
+        assert_eq!(sut.reused_public_key_hashes(), vec![hash.clone()]);
+        assert_eq!(
+            sut.entities_controlled_by_public_key_hash(&hash),
+            vec![
+                AddressOfAccountOrPersona::from(first.address),
+                AddressOfAccountOrPersona::from(second.address)
+            ]
+        );
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn entities_controlled_by_public_key_hash() {
+        let profile = Profile::placeholder();
+        let hash = PublicKeyHash::hash_of("unknown");
+        assert_eq!(
+            profile_entities_controlled_by_public_key_hash(
+                &profile,
+                hash.clone()
+            ),
+            profile.entities_controlled_by_public_key_hash(&hash)
+        );
+    }
+}
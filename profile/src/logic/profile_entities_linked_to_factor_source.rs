@@ -0,0 +1,151 @@
+use crate::prelude::*;
+
+/// The Accounts and Personas on a network linked to some factor source, i.e.
+/// having a factor instance - for transaction or authentication signing -
+/// created by it. Includes hidden entities, see their `flags`.
+#[derive(Clone, Debug, PartialEq, Eq, uniffi::Record)]
+pub struct EntitiesLinkedToFactorSource {
+    pub network_id: NetworkID,
+    pub accounts: Accounts,
+    pub personas: Personas,
+}
+
+impl EntitiesLinkedToFactorSource {
+    /// `true` if neither an Account nor a Persona is linked.
+    pub fn is_empty(&self) -> bool {
+        self.accounts.is_empty() && self.personas.is_empty()
+    }
+}
+
+/// `true` if any factor instance of `security_state` was created by the
+/// factor source with id `factor_source_id`, never for watch-only entities.
+fn is_linked_to(
+    security_state: &EntitySecurityState,
+    factor_source_id: &FactorSourceID,
+) -> bool {
+    match security_state {
+        EntitySecurityState::Unsecured { value } => {
+            std::iter::once(&value.transaction_signing)
+                .chain(value.authentication_signing.iter())
+                .any(|instance| {
+                    FactorSourceID::from(instance.factor_source_id.clone())
+                        == *factor_source_id
+                })
+        }
+        EntitySecurityState::Unowned => false,
+    }
+}
+
+impl Profile {
+    /// Returns - per network, in order, omitting networks without any - all
+    /// Accounts and Personas, including hidden ones, linked to the factor
+    /// source with id `factor_source_id`, e.g. to warn the user about what
+    /// they lose if they delete it.
+    pub fn entities_linked_to_factor_source(
+        &self,
+        factor_source_id: &FactorSourceID,
+    ) -> Vec<EntitiesLinkedToFactorSource> {
+        self.networks
+            .iter()
+            .map(|n| EntitiesLinkedToFactorSource {
+                network_id: n.id,
+                accounts: Accounts::from_iter(
+                    n.accounts
+                        .iter()
+                        .filter(|a| {
+                            is_linked_to(&a.security_state, factor_source_id)
+                        })
+                        .cloned(),
+                ),
+                personas: Personas::from_iter(
+                    n.personas
+                        .iter()
+                        .filter(|p| {
+                            is_linked_to(&p.security_state, factor_source_id)
+                        })
+                        .cloned(),
+                ),
+            })
+            .filter(|linked| !linked.is_empty())
+            .collect_vec()
+    }
+}
+
+#[uniffi::export]
+pub fn profile_entities_linked_to_factor_source(
+    profile: &Profile,
+    factor_source_id: FactorSourceID,
+) -> Vec<EntitiesLinkedToFactorSource> {
+    profile.entities_linked_to_factor_source(&factor_source_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn all_placeholder_entities_linked_to_device() {
+        let sut = Profile::placeholder();
+        let linked = sut.entities_linked_to_factor_source(
+            &FactorSource::placeholder_device().factor_source_id(),
+        );
+        assert_eq!(
+            linked.iter().map(|l| l.network_id).collect_vec(),
+            sut.networks.iter().map(|n| n.id).collect_vec()
+        );
+        for (linked, network) in linked.iter().zip(sut.networks.iter()) {
+            assert_eq!(linked.accounts, network.accounts);
+            assert_eq!(linked.personas, network.personas);
+        }
+    }
+
+    #[test]
+    fn unused_factor_source_links_nothing() {
+        assert!(Profile::placeholder()
+            .entities_linked_to_factor_source(
+                &FactorSource::placeholder_ledger().factor_source_id()
+            )
+            .is_empty());
+    }
+
+    #[test]
+    fn includes_hidden() {
+        let mut sut = Profile::placeholder();
+        let account = Account::placeholder_mainnet_alice();
+        sut.networks.update_account(&account.address, |a| {
+            a.flags.insert_flag(EntityFlag::DeletedByUser);
+        });
+        let linked = sut.entities_linked_to_factor_source(
+            &FactorSource::placeholder_device().factor_source_id(),
+        );
+        assert!(linked[0].accounts.contains_id(&account.address));
+    }
+
+    #[test]
+    fn excludes_watch_only() {
+        let mut sut = Profile::placeholder();
+        let account = Account::placeholder_mainnet_alice();
+        sut.networks.update_account(&account.address, |a| {
+            a.security_state = EntitySecurityState::Unowned;
+        });
+        let linked = sut.entities_linked_to_factor_source(
+            &FactorSource::placeholder_device().factor_source_id(),
+        );
+        assert!(!linked[0].accounts.contains_id(&account.address));
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn entities_linked_to_factor_source() {
+        let profile = Profile::placeholder();
+        let id = FactorSource::placeholder_device().factor_source_id();
+        assert_eq!(
+            profile_entities_linked_to_factor_source(&profile, id.clone()),
+            profile.entities_linked_to_factor_source(&id)
+        );
+    }
+}
@@ -0,0 +1,63 @@
+use crate::prelude::*;
+
+impl Profile {
+    /// Checks the consistency of an imported snapshot, failing for snapshots
+    /// which are corrupted or have been tampered with:
+    /// * the address of every account must be derived from its transaction
+    ///   signing key, see [`Account::validate_address_matches_factor_instance`].
+    /// * no persisted next derivation index may be used already, see
+    ///   [`Profile::validate_next_derivation_indices`].
+    pub fn validate_for_import(&self) -> Result<()> {
+        self.networks
+            .iter()
+            .flat_map(|n| n.accounts.iter())
+            .try_for_each(|a| a.validate_address_matches_factor_instance())?;
+        self.validate_next_derivation_indices()
+    }
+}
+
+/// Checks the consistency of an imported `profile`, see
+/// [`Profile::validate_for_import`].
+#[uniffi::export]
+pub fn profile_validate_for_import(profile: &Profile) -> Result<()> {
+    profile.validate_for_import()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn placeholders_are_valid() {
+        assert_eq!(Profile::placeholder().validate_for_import(), Ok(()));
+        assert_eq!(Profile::placeholder_other().validate_for_import(), Ok(()));
+    }
+
+    #[test]
+    fn tampered_account_address_is_invalid() {
+        let mut sut = Profile::placeholder();
+        let mut network = sut.networks.first().unwrap().clone();
+        let mut accounts = network.accounts.items();
+        accounts[0].address = Account::placeholder_mainnet_carol().address;
+        network.accounts = Accounts::with_accounts(accounts);
+        sut.networks = ProfileNetworks::with_network(network);
+        assert!(matches!(
+            sut.validate_for_import(),
+            Err(CommonError::AccountAddressDoesNotMatchFactorInstance { .. })
+        ));
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn validate_for_import() {
+        let sut = Profile::placeholder();
+        assert_eq!(
+            profile_validate_for_import(&sut),
+            sut.validate_for_import()
+        );
+    }
+}
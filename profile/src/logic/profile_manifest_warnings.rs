@@ -0,0 +1,120 @@
+use crate::prelude::*;
+
+/// A consequence of a transaction on an Account or Persona of the user,
+/// which the transaction review should prominently warn about.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Enum)]
+pub enum ManifestWarning {
+    /// The transaction changes the access rules of the entity, i.e. it may
+    /// no longer be controlled by the user afterwards.
+    ChangesControlOfEntity { address: AddressOfAccountOrPersona },
+
+    /// The transaction sets, updates or removes metadata of the entity.
+    UpdatesMetadataOfEntity { address: AddressOfAccountOrPersona },
+}
+
+impl Profile {
+    /// `true` if the Account or Persona with `address` is in the Profile.
+    fn contains_entity(&self, address: &AddressOfAccountOrPersona) -> bool {
+        match address {
+            AddressOfAccountOrPersona::Account { value } => {
+                self.networks.get_account(value).is_some()
+            }
+            AddressOfAccountOrPersona::Persona { value } => {
+                self.networks.get_persona(value).is_some()
+            }
+        }
+    }
+
+    /// The [`ManifestWarning`]s of a transaction with `summary`, only about
+    /// entities in this Profile - control changes first, then metadata
+    /// updates, each in the order of the summary.
+    pub fn manifest_warnings(
+        &self,
+        summary: &ManifestSummary,
+    ) -> Vec<ManifestWarning> {
+        let control_changes = summary
+            .entities_with_access_rule_changes
+            .iter()
+            .filter(|a| self.contains_entity(a))
+            .unique()
+            .map(|a| ManifestWarning::ChangesControlOfEntity {
+                address: a.clone(),
+            });
+        let metadata_updates = summary
+            .entities_with_metadata_updates
+            .iter()
+            .filter(|a| self.contains_entity(a))
+            .unique()
+            .map(|a| ManifestWarning::UpdatesMetadataOfEntity {
+                address: a.clone(),
+            });
+        control_changes.chain(metadata_updates).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn alice() -> AddressOfAccountOrPersona {
+        Account::placeholder_mainnet_alice().address.into()
+    }
+
+    fn satoshi() -> AddressOfAccountOrPersona {
+        Persona::placeholder_mainnet_satoshi().address.into()
+    }
+
+    fn summary() -> ManifestSummary {
+        ManifestSummary::new(ManifestClass::General, [], [], [], [])
+    }
+
+    #[test]
+    fn no_changes_no_warnings() {
+        assert!(Profile::placeholder()
+            .manifest_warnings(&ManifestSummary::placeholder())
+            .is_empty());
+    }
+
+    #[test]
+    fn changes_control_of_account() {
+        let summary = summary().with_access_rule_changes([alice()]);
+        assert_eq!(
+            Profile::placeholder().manifest_warnings(&summary),
+            vec![ManifestWarning::ChangesControlOfEntity { address: alice() }]
+        );
+    }
+
+    #[test]
+    fn control_changes_before_metadata_updates() {
+        let summary = summary()
+            .with_metadata_updates([alice()])
+            .with_access_rule_changes([satoshi()]);
+        assert_eq!(
+            Profile::placeholder().manifest_warnings(&summary),
+            vec![
+                ManifestWarning::ChangesControlOfEntity { address: satoshi() },
+                ManifestWarning::UpdatesMetadataOfEntity { address: alice() },
+            ]
+        );
+    }
+
+    #[test]
+    fn entities_not_in_profile_are_ignored() {
+        let summary = summary()
+            .with_access_rule_changes([
+                AccountAddress::placeholder_mainnet().into()
+            ])
+            .with_metadata_updates([
+                IdentityAddress::placeholder_mainnet().into()
+            ]);
+        assert!(Profile::placeholder()
+            .manifest_warnings(&summary)
+            .is_empty());
+    }
+
+    #[test]
+    fn duplicates_are_removed() {
+        let summary = summary().with_access_rule_changes([alice(), alice()]);
+        assert_eq!(Profile::placeholder().manifest_warnings(&summary).len(), 1);
+    }
+}
@@ -0,0 +1,163 @@
+use crate::prelude::*;
+
+impl ProfileNetwork {
+    /// `true` if any Account or Persona, hidden or not, exists on this
+    /// network.
+    pub fn has_entities(&self) -> bool {
+        !self.accounts.is_empty() || !self.personas.is_empty()
+    }
+}
+
+impl Profile {
+    /// The ID of the network of the current Gateway.
+    pub fn current_network_id(&self) -> NetworkID {
+        self.app_preferences.gateways.current.network.id
+    }
+
+    /// Adds an empty network with `network_id` unless the Profile already
+    /// contains it, returns `true` if it was added.
+    pub fn add_network_if_needed(&mut self, network_id: NetworkID) -> bool {
+        if self.networks.contains_id(&network_id) {
+            return false;
+        }
+        self.networks.append(ProfileNetwork::new(
+            network_id,
+            Accounts::default(),
+            Personas::default(),
+            AuthorizedDapps::default(),
+        ));
+        true
+    }
+
+    /// Removes the network with `network_id`, which is only allowed if it
+    /// has no Accounts or Personas and is not the network of the current
+    /// Gateway.
+    pub fn remove_network(
+        &mut self,
+        network_id: NetworkID,
+    ) -> Result<ProfileNetwork> {
+        let network = self
+            .networks
+            .get_id(&network_id)
+            .cloned()
+            .ok_or(CommonError::UnknownNetworkInProfile(network_id))?;
+        if network.has_entities() {
+            return Err(CommonError::CannotRemoveNetworkWithEntities(
+                network_id,
+            ));
+        }
+        if self.current_network_id() == network_id {
+            return Err(CommonError::CannotRemoveNetworkOfCurrentGateway(
+                network_id,
+            ));
+        }
+        self.networks.remove_by_id(&network_id);
+        Ok(network)
+    }
+
+    /// Changes the current Gateway to `to`, adding its network to the
+    /// Profile if needed, so that the network of the current Gateway always
+    /// exists in the Profile. Returns whether the network was added.
+    pub fn change_current_gateway(&mut self, to: Gateway) -> Result<bool> {
+        let network_id = to.network.id;
+        self.app_preferences.gateways.change_current(to)?;
+        Ok(self.add_network_if_needed(network_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn has_entities() {
+        assert!(ProfileNetwork::placeholder_mainnet().has_entities());
+        assert!(!ProfileNetwork::new(
+            NetworkID::Mainnet,
+            Accounts::default(),
+            Personas::default(),
+            AuthorizedDapps::default(),
+        )
+        .has_entities());
+    }
+
+    #[test]
+    fn current_network_id() {
+        let sut = Profile::placeholder();
+        assert_eq!(
+            sut.current_network_id(),
+            sut.app_preferences.gateways.current.network.id
+        );
+    }
+
+    #[test]
+    fn add_network_if_needed() {
+        let mut sut = Profile::placeholder();
+        assert!(sut.add_network_if_needed(NetworkID::Nebunet));
+        assert!(sut.networks.get_id(&NetworkID::Nebunet).is_some());
+        assert!(!sut.add_network_if_needed(NetworkID::Nebunet));
+    }
+
+    #[test]
+    fn add_network_if_needed_keeps_existing() {
+        let mut sut = Profile::placeholder();
+        let before = sut.networks.get_id(&NetworkID::Mainnet).cloned();
+        assert!(!sut.add_network_if_needed(NetworkID::Mainnet));
+        assert_eq!(sut.networks.get_id(&NetworkID::Mainnet).cloned(), before);
+    }
+
+    #[test]
+    fn remove_empty_network() {
+        let mut sut = Profile::placeholder();
+        sut.add_network_if_needed(NetworkID::Nebunet);
+        let removed = sut.remove_network(NetworkID::Nebunet).unwrap();
+        assert_eq!(removed.id, NetworkID::Nebunet);
+        assert!(sut.networks.get_id(&NetworkID::Nebunet).is_none());
+    }
+
+    #[test]
+    fn remove_unknown_network() {
+        let mut sut = Profile::placeholder();
+        assert_eq!(
+            sut.remove_network(NetworkID::Nebunet),
+            Err(CommonError::UnknownNetworkInProfile(NetworkID::Nebunet))
+        );
+    }
+
+    #[test]
+    fn remove_network_with_entities() {
+        let mut sut = Profile::placeholder();
+        assert_eq!(
+            sut.remove_network(NetworkID::Mainnet),
+            Err(CommonError::CannotRemoveNetworkWithEntities(
+                NetworkID::Mainnet
+            ))
+        );
+    }
+
+    #[test]
+    fn remove_network_of_current_gateway() {
+        let mut sut = Profile::placeholder();
+        let current = sut.current_network_id();
+        sut.add_network_if_needed(current);
+        assert_eq!(
+            sut.remove_network(current),
+            Err(CommonError::CannotRemoveNetworkOfCurrentGateway(current))
+        );
+    }
+
+    #[test]
+    fn change_current_gateway_adds_network() {
+        let mut sut = Profile::placeholder();
+        assert_eq!(sut.change_current_gateway(Gateway::nebunet()), Ok(true));
+        assert_eq!(sut.app_preferences.gateways.current, Gateway::nebunet());
+        assert!(sut.networks.get_id(&NetworkID::Nebunet).is_some());
+    }
+
+    #[test]
+    fn change_current_gateway_to_existing_network() {
+        let mut sut = Profile::placeholder();
+        assert_eq!(sut.change_current_gateway(Gateway::mainnet()), Ok(false));
+        assert_eq!(sut.current_network_id(), NetworkID::Mainnet);
+    }
+}
@@ -7,7 +7,7 @@ impl Profile {
         F: IsFactorSource,
     {
         self.factor_sources
-            .get(id)
+            .get_id(id)
             .ok_or(CommonError::ProfileDoesNotContainFactorSourceWithID(
                 id.clone(),
             ))
@@ -57,35 +57,175 @@ impl Profile {
         explicit_main.unwrap_or(implicit_main).clone()
     }
 
-    fn next_derivation_index_for_entity_for_factor_source(
+    /// Returns the next derivation index to use for a new entity of `kind`
+    /// on `network_id` controlled by `factor_source_id`, which is the index
+    /// persisted in the factor source, but at least one past the highest
+    /// index in use, so that we never re-derive an existing address, not
+    /// even one of a deleted entity or if the indices in use are
+    /// non-contiguous.
+    pub(crate) fn next_derivation_index_for_entity_for_factor_source(
         &self,
         kind: EntityKind,
         network_id: NetworkID,
         factor_source_id: FactorSourceIDFromHash,
     ) -> HDPathValue {
-        match kind {
-            EntityKind::Persona => panic!("Personas are not supported yet"),
-            EntityKind::Accounts => {}
-        };
-        let index = self
-            .networks
-            .get(&network_id)
-            .map(|n| {
-                n.accounts
-                    .items()
-                    .into_iter()
-                    .filter(|a| match &a.security_state {
-                        EntitySecurityState::Unsecured { value } => {
-                            value.transaction_signing.factor_source_id
-                                == factor_source_id
-                        }
-                    })
-                    .collect_vec()
-                    .len()
-            })
+        let inferred = self
+            .highest_used_derivation_index(
+                &factor_source_id,
+                network_id,
+                kind.clone(),
+            )
+            .map(|i| i + 1)
             .unwrap_or(0);
+        self.persisted_next_derivation_indices(&factor_source_id, network_id)
+            .map(|i| i.index_for(kind))
+            .unwrap_or(0)
+            .max(inferred)
+    }
+
+    /// The next derivation indices on `network_id` persisted in the factor
+    /// source with id `factor_source_id`, if any.
+    pub fn persisted_next_derivation_indices(
+        &self,
+        factor_source_id: &FactorSourceIDFromHash,
+        network_id: NetworkID,
+    ) -> Option<NextDerivationIndices> {
+        self.factor_sources
+            .get_id(&factor_source_id.clone().into())?
+            .common()
+            .next_derivation_indices_per_network
+            .get_id(&network_id)
+            .cloned()
+    }
+
+    /// Persists the next derivation indices on `network_id` of the factor
+    /// source with id `factor_source_id`, as inferred from the entities in
+    /// Profile if not persisted before, does nothing if the factor source is
+    /// not in Profile.
+    fn persist_next_derivation_indices(
+        &mut self,
+        factor_source_id: &FactorSourceIDFromHash,
+        network_id: NetworkID,
+    ) {
+        let next = NextDerivationIndices::new(
+            network_id,
+            self.next_derivation_index_for_entity_for_factor_source(
+                EntityKind::Accounts,
+                network_id,
+                factor_source_id.clone(),
+            ),
+            self.next_derivation_index_for_entity_for_factor_source(
+                EntityKind::Persona,
+                network_id,
+                factor_source_id.clone(),
+            ),
+        );
+        _ = self.factor_sources.update_with(
+            &factor_source_id.clone().into(),
+            |f| {
+                let indices =
+                    &mut f.common_mut().next_derivation_indices_per_network;
+                if !indices.update_with(&network_id, |i| *i = next) {
+                    indices.append(next);
+                }
+            },
+        );
+    }
 
-        index as u32
+    /// Records that a new entity of `kind` with `security_state` has been
+    /// added to `network_id`, raising the persisted next derivation index of
+    /// its factor source past the index it used.
+    ///
+    /// The first time this happens for a factor source on a network, the
+    /// indices are migrated from being inferred from the entities in Profile
+    /// to being persisted.
+    ///
+    /// Does nothing for a watch-only entity, which used no derivation index.
+    pub fn record_used_derivation_index(
+        &mut self,
+        kind: EntityKind,
+        network_id: NetworkID,
+        security_state: &EntitySecurityState,
+    ) {
+        let Some(instance) =
+            security_state.transaction_signing_factor_instance()
+        else {
+            return;
+        };
+        let factor_source_id = &instance.factor_source_id;
+        let used = instance.derivation_path().last_component().index();
+        self.persist_next_derivation_indices(factor_source_id, network_id);
+        _ = self.factor_sources.update_with(
+            &factor_source_id.clone().into(),
+            |f| {
+                f.common_mut()
+                    .next_derivation_indices_per_network
+                    .update_with(&network_id, |i| {
+                        i.raise_index_for(kind, used + 1)
+                    });
+            },
+        );
+    }
+
+    /// Migrates all factor sources from inferring their next derivation
+    /// indices from the entities in Profile to persisting them, for every
+    /// network in Profile.
+    pub fn migrate_to_persisted_next_derivation_indices(&mut self) {
+        let factor_source_ids = self
+            .factor_sources
+            .iter()
+            .filter_map(|f| f.factor_source_id().as_hash().cloned())
+            .collect_vec();
+        let network_ids =
+            self.networks.ids().into_iter().cloned().collect_vec();
+        for factor_source_id in factor_source_ids.iter() {
+            for network_id in network_ids.iter() {
+                self.persist_next_derivation_indices(
+                    factor_source_id,
+                    *network_id,
+                );
+            }
+        }
+    }
+
+    /// Checks that no persisted next derivation index is an index already
+    /// used by an entity, which would re-derive an existing address.
+    pub fn validate_next_derivation_indices(&self) -> Result<()> {
+        for factor_source in self.factor_sources.iter() {
+            let Some(factor_source_id) =
+                factor_source.factor_source_id().as_hash().cloned()
+            else {
+                continue;
+            };
+            for next in factor_source
+                .common()
+                .next_derivation_indices_per_network
+                .iter()
+            {
+                for kind in [EntityKind::Accounts, EntityKind::Persona] {
+                    let Some(highest_used) = self
+                        .highest_used_derivation_index(
+                            &factor_source_id,
+                            next.network_id,
+                            kind.clone(),
+                        )
+                    else {
+                        continue;
+                    };
+                    if next.index_for(kind) <= highest_used {
+                        return Err(
+                            CommonError::NextDerivationIndexAlreadyUsed {
+                                factor_source_id,
+                                network_id: next.network_id,
+                                next: next.index_for(kind),
+                                highest_used,
+                            },
+                        );
+                    }
+                }
+            }
+        }
+        Ok(())
     }
 
     pub fn next_derivation_index_for_entity(
@@ -290,6 +430,137 @@ mod tests {
         );
     }
 
+    #[test]
+    fn next_derivation_index_persisted_survives_deletion() {
+        let mut profile = Profile::placeholder();
+        let bob = Account::placeholder_mainnet_bob();
+        profile.record_used_derivation_index(
+            EntityKind::Accounts,
+            NetworkID::Mainnet,
+            &bob.security_state,
+        );
+        profile.networks.update_with(&NetworkID::Mainnet, |n| {
+            _ = n.accounts.remove_by_id(&bob.address);
+        });
+        assert_eq!(
+            profile.next_derivation_index_for_entity(
+                EntityKind::Accounts,
+                NetworkID::Mainnet
+            ),
+            2
+        );
+    }
+
+    #[test]
+    fn next_derivation_index_inferred_without_persisted() {
+        let mut profile = Profile::placeholder();
+        profile.networks.update_with(&NetworkID::Mainnet, |n| {
+            _ = n
+                .accounts
+                .remove_by_id(&Account::placeholder_mainnet_bob().address);
+        });
+        assert_eq!(
+            profile.next_derivation_index_for_entity(
+                EntityKind::Accounts,
+                NetworkID::Mainnet
+            ),
+            1
+        );
+    }
+
+    #[test]
+    fn record_used_derivation_index_migrates_both_kinds() {
+        let mut profile = Profile::placeholder();
+        let bdfs_id = profile.bdfs().id;
+        assert_eq!(
+            profile.persisted_next_derivation_indices(
+                &bdfs_id,
+                NetworkID::Mainnet
+            ),
+            None
+        );
+        profile.record_used_derivation_index(
+            EntityKind::Accounts,
+            NetworkID::Mainnet,
+            &Account::placeholder_mainnet_alice().security_state,
+        );
+        assert_eq!(
+            profile.persisted_next_derivation_indices(
+                &bdfs_id,
+                NetworkID::Mainnet
+            ),
+            Some(NextDerivationIndices::new(NetworkID::Mainnet, 2, 2))
+        );
+        assert_eq!(profile.validate_next_derivation_indices(), Ok(()));
+    }
+
+    #[test]
+    fn record_used_derivation_index_raises_past_used() {
+        let mut profile = Profile::placeholder();
+        // Carol is not in the placeholder Profile, but uses index 2.
+        let carol = Account::placeholder_mainnet_carol();
+        profile.record_used_derivation_index(
+            EntityKind::Accounts,
+            NetworkID::Mainnet,
+            &carol.security_state,
+        );
+        assert_eq!(
+            profile
+                .persisted_next_derivation_indices(
+                    &profile.bdfs().id,
+                    NetworkID::Mainnet
+                )
+                .unwrap()
+                .for_account,
+            3
+        );
+    }
+
+    #[test]
+    fn migrate_to_persisted_next_derivation_indices() {
+        let mut profile = Profile::placeholder();
+        profile.migrate_to_persisted_next_derivation_indices();
+        let bdfs_id = profile.bdfs().id;
+        assert_eq!(
+            profile.persisted_next_derivation_indices(
+                &bdfs_id,
+                NetworkID::Mainnet
+            ),
+            Some(NextDerivationIndices::new(NetworkID::Mainnet, 2, 2))
+        );
+        assert_eq!(
+            profile.persisted_next_derivation_indices(
+                &LedgerHardwareWalletFactorSource::placeholder().id,
+                NetworkID::Stokenet
+            ),
+            Some(NextDerivationIndices::unused(NetworkID::Stokenet))
+        );
+        assert_eq!(profile.validate_next_derivation_indices(), Ok(()));
+    }
+
+    #[test]
+    fn validate_next_derivation_indices_fails_for_used_index() {
+        let mut profile = Profile::placeholder();
+        let bdfs_id = profile.bdfs().id;
+        _ = profile
+            .factor_sources
+            .update_with(&bdfs_id.clone().into(), |f| {
+                f.common_mut().next_derivation_indices_per_network =
+                    NextDerivationIndicesPerNetwork::from_iter([
+                        NextDerivationIndices::new(NetworkID::Mainnet, 1, 2),
+                    ]);
+            });
+        assert_eq!(
+            profile.validate_next_derivation_indices(),
+            Err(CommonError::NextDerivationIndexAlreadyUsed {
+                factor_source_id: bdfs_id,
+                network_id: NetworkID::Mainnet,
+                next: 1,
+                highest_used: 1
+            })
+        );
+    }
+
     #[test]
     fn next_derivation_index_for_entity_account_olympia_dfs_mainnet() {
         let profile = Profile::placeholder();
@@ -0,0 +1,271 @@
+use crate::prelude::*;
+
+/// A factor instance to derive in order to securify an entity, i.e. the
+/// public key at `derivation_path` of the factor source with id
+/// `factor_source_id`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct FactorInstanceToDerive {
+    pub factor_source_id: FactorSourceIDFromHash,
+    pub derivation_path: DerivationPath,
+}
+
+/// What securifying an Account with the factor sources of the security
+/// structure chosen by the user requires, planned before deriving any key.
+///
+/// The access controller creation manifest and its XRD cost are not part
+/// of the plan: both need the rule set of the roles of the security
+/// structure, which the Profile does not model yet, see
+/// [`SecurityStructureConfigurationReference`].
+#[derive(Clone, Debug, PartialEq, Eq, uniffi::Record)]
+pub struct SecurifyAccountPlan {
+    pub account_address: AccountAddress,
+
+    /// The factor instance controlling the Account until it is securified.
+    pub current_factor_instance: HierarchicalDeterministicFactorInstance,
+
+    /// One factor instance per chosen factor source, at the next free index
+    /// of Accounts on the network of the Account, in the order chosen.
+    pub factor_instances_to_derive: Vec<FactorInstanceToDerive>,
+
+    /// The other entities linked to the factor source currently controlling
+    /// the Account, which stay controlled by it.
+    pub entities_still_linked_to_current_factor_source:
+        Vec<EntitiesLinkedToFactorSource>,
+}
+
+impl Profile {
+    /// Plans securifying the Account with `account_address` with the factor
+    /// sources with ids `factor_source_ids`, duplicates are ignored.
+    ///
+    /// Fails if the Account is unknown or watch-only, if no factor source
+    /// is given or if any of them is unknown.
+    pub fn securify_account_plan(
+        &self,
+        account_address: &AccountAddress,
+        factor_source_ids: Vec<FactorSourceIDFromHash>,
+    ) -> Result<SecurifyAccountPlan> {
+        let account = self
+            .networks
+            .get_account(account_address)
+            .ok_or(CommonError::UnknownAccount)?;
+        let current_factor_instance =
+            account.transaction_signing_factor_instance()?;
+        let factor_source_ids =
+            factor_source_ids.into_iter().unique().collect_vec();
+        if factor_source_ids.is_empty() {
+            return Err(CommonError::NoFactorSourcesToSecurifyWith);
+        }
+
+        let network_id = account.network_id;
+        let factor_instances_to_derive = factor_source_ids
+            .into_iter()
+            .map(|factor_source_id| {
+                let id = FactorSourceID::from(factor_source_id.clone());
+                if !self.factor_sources.contains_id(&id) {
+                    return Err(
+                        CommonError::ProfileDoesNotContainFactorSourceWithID(
+                            id,
+                        ),
+                    );
+                }
+                let index = self
+                    .next_derivation_index_for_entity_for_factor_source(
+                        EntityKind::Accounts,
+                        network_id,
+                        factor_source_id.clone(),
+                    );
+                Ok(FactorInstanceToDerive {
+                    factor_source_id,
+                    derivation_path: AccountPath::new(
+                        network_id,
+                        CAP26KeyKind::TransactionSigning,
+                        index,
+                    )
+                    .into(),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let entities_still_linked_to_current_factor_source = self
+            .entities_linked_to_factor_source(&FactorSourceID::from(
+                current_factor_instance.factor_source_id.clone(),
+            ))
+            .into_iter()
+            .map(|linked| EntitiesLinkedToFactorSource {
+                accounts: Accounts::from_iter(
+                    linked
+                        .accounts
+                        .items()
+                        .into_iter()
+                        .filter(|a| a.address != *account_address),
+                ),
+                ..linked
+            })
+            .filter(|linked| !linked.is_empty())
+            .collect_vec();
+
+        Ok(SecurifyAccountPlan {
+            account_address: account_address.clone(),
+            current_factor_instance,
+            factor_instances_to_derive,
+            entities_still_linked_to_current_factor_source,
+        })
+    }
+}
+
+#[uniffi::export]
+pub fn profile_securify_account_plan(
+    profile: &Profile,
+    account_address: AccountAddress,
+    factor_source_ids: Vec<FactorSourceIDFromHash>,
+) -> Result<SecurifyAccountPlan> {
+    profile.securify_account_plan(&account_address, factor_source_ids)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn alice() -> Account {
+        Account::placeholder_mainnet_alice()
+    }
+
+    fn ledger_id() -> FactorSourceIDFromHash {
+        FactorSourceIDFromHash::placeholder_ledger()
+    }
+
+    #[test]
+    fn plan_with_ledger() {
+        let profile = Profile::placeholder();
+        let sut = profile
+            .securify_account_plan(&alice().address, vec![ledger_id()])
+            .unwrap();
+        assert_eq!(sut.account_address, alice().address);
+        assert_eq!(
+            sut.current_factor_instance,
+            alice().transaction_signing_factor_instance().unwrap()
+        );
+        assert_eq!(
+            sut.factor_instances_to_derive,
+            vec![FactorInstanceToDerive {
+                factor_source_id: ledger_id(),
+                derivation_path: AccountPath::new(
+                    NetworkID::Mainnet,
+                    CAP26KeyKind::TransactionSigning,
+                    0
+                )
+                .into()
+            }]
+        );
+    }
+
+    #[test]
+    fn derives_at_next_free_index() {
+        let profile = Profile::placeholder();
+        let bdfs_id = profile.bdfs().id;
+        let sut = profile
+            .securify_account_plan(&alice().address, vec![bdfs_id.clone()])
+            .unwrap();
+        assert_eq!(
+            sut.factor_instances_to_derive[0].derivation_path,
+            AccountPath::new(
+                NetworkID::Mainnet,
+                CAP26KeyKind::TransactionSigning,
+                profile.next_derivation_index_for_entity(
+                    EntityKind::Accounts,
+                    NetworkID::Mainnet
+                )
+            )
+            .into()
+        );
+    }
+
+    #[test]
+    fn duplicate_factor_sources_are_ignored() {
+        let sut = Profile::placeholder()
+            .securify_account_plan(
+                &alice().address,
+                vec![ledger_id(), ledger_id()],
+            )
+            .unwrap();
+        assert_eq!(sut.factor_instances_to_derive.len(), 1);
+    }
+
+    #[test]
+    fn other_entities_stay_linked_to_current_factor_source() {
+        let profile = Profile::placeholder();
+        let sut = profile
+            .securify_account_plan(&alice().address, vec![ledger_id()])
+            .unwrap();
+        let mainnet = &sut.entities_still_linked_to_current_factor_source[0];
+        assert!(!mainnet.accounts.contains_id(&alice().address));
+        assert!(mainnet
+            .accounts
+            .contains_id(&Account::placeholder_mainnet_bob().address));
+        assert!(!mainnet.personas.is_empty());
+    }
+
+    #[test]
+    fn unknown_account_fails() {
+        assert_eq!(
+            Profile::placeholder().securify_account_plan(
+                &AccountAddress::placeholder_mainnet(),
+                vec![ledger_id()]
+            ),
+            Err(CommonError::UnknownAccount)
+        );
+    }
+
+    #[test]
+    fn watch_only_account_fails() {
+        let mut profile = Profile::placeholder();
+        profile.networks.update_account(&alice().address, |a| {
+            a.security_state = EntitySecurityState::Unowned;
+        });
+        assert_eq!(
+            profile.securify_account_plan(&alice().address, vec![ledger_id()]),
+            Err(CommonError::EntityIsWatchOnly(alice().address.to_string()))
+        );
+    }
+
+    #[test]
+    fn without_factor_sources_fails() {
+        assert_eq!(
+            Profile::placeholder()
+                .securify_account_plan(&alice().address, vec![]),
+            Err(CommonError::NoFactorSourcesToSecurifyWith)
+        );
+    }
+
+    #[test]
+    fn unknown_factor_source_fails() {
+        let unknown = FactorSourceIDFromHash::placeholder_ledger_other();
+        assert_eq!(
+            Profile::placeholder()
+                .securify_account_plan(&alice().address, vec![unknown.clone()]),
+            Err(CommonError::ProfileDoesNotContainFactorSourceWithID(
+                unknown.into()
+            ))
+        );
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn securify_account_plan() {
+        let profile = Profile::placeholder();
+        let address = Account::placeholder_mainnet_alice().address;
+        let ids = vec![FactorSourceIDFromHash::placeholder_ledger()];
+        assert_eq!(
+            profile_securify_account_plan(
+                &profile,
+                address.clone(),
+                ids.clone()
+            ),
+            profile.securify_account_plan(&address, ids)
+        );
+    }
+}
@@ -0,0 +1,305 @@
+use crate::prelude::*;
+
+/// A transaction to sign, identified by the hash of its intent, together
+/// with the [`SigningPolicy`] resolved for it.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct TransactionToSign {
+    pub intent_hash: TransactionIntentHash,
+    pub policy: SigningPolicy,
+}
+
+impl TransactionToSign {
+    pub fn new(
+        intent_hash: TransactionIntentHash,
+        policy: SigningPolicy,
+    ) -> Self {
+        Self {
+            intent_hash,
+            policy,
+        }
+    }
+
+    /// The factor instances signing or notarizing this transaction.
+    fn factor_instances(
+        &self,
+    ) -> impl Iterator<Item = &HierarchicalDeterministicFactorInstance> {
+        self.policy
+            .notary
+            .iter()
+            .chain(self.policy.signers.iter().map(|s| &s.factor_instance))
+    }
+}
+
+/// The transactions the user is about to sign in one go, e.g. a batch of
+/// dApp requests, in the order they will be signed.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct SigningPlan {
+    pub transactions: Vec<TransactionToSign>,
+}
+
+/// What a factor source will sign as part of a [`SigningPlan`], so that
+/// hosts can show the user the plan before signing starts.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct FactorSourceSigningDescription {
+    pub factor_source_id: FactorSourceIDFromHash,
+    pub factor_source_kind: FactorSourceKind,
+
+    /// The Accounts and Personas signing with this factor source, in the
+    /// order of the Profile.
+    pub entities: Vec<AddressOfAccountOrPersona>,
+
+    /// The transactions this factor source signs or notarizes, in the order
+    /// of the plan.
+    pub transactions: Vec<TransactionIntentHash>,
+
+    /// How many times the user will have to confirm on a Ledger, which
+    /// signs a transaction with all its keys in one interaction, always
+    /// zero for other kinds of factor sources.
+    pub estimated_ledger_interactions: u32,
+}
+
+impl SigningPlan {
+    pub fn new(
+        transactions: impl IntoIterator<Item = TransactionToSign>,
+    ) -> Self {
+        Self {
+            transactions: transactions.into_iter().collect(),
+        }
+    }
+
+    /// Describes - per factor source, in the order of the factor sources
+    /// in `profile` - which entities sign which transactions. Factor
+    /// sources signing nothing are omitted.
+    ///
+    /// The entities are those linked to the factor source, see
+    /// [`Profile::entities_linked_to_factor_source`], whose transaction
+    /// signing factor instance is used in the plan.
+    ///
+    /// Fails if a factor instance was created by a factor source unknown to
+    /// `profile`.
+    pub fn describe(
+        &self,
+        profile: &Profile,
+    ) -> Result<Vec<FactorSourceSigningDescription>> {
+        let instances = self
+            .transactions
+            .iter()
+            .flat_map(|t| t.factor_instances())
+            .collect_vec();
+        if let Some(unknown) = instances
+            .iter()
+            .map(|i| FactorSourceID::from(i.factor_source_id.clone()))
+            .find(|id| !profile.factor_sources.contains_id(id))
+        {
+            return Err(CommonError::ProfileDoesNotContainFactorSourceWithID(
+                unknown,
+            ));
+        }
+
+        let is_signing = |security_state: &EntitySecurityState| {
+            security_state
+                .transaction_signing_factor_instance()
+                .is_some_and(|i| instances.contains(&i))
+        };
+
+        Ok(profile
+            .factor_sources
+            .iter()
+            .filter_map(|factor_source| {
+                let id = factor_source.factor_source_id();
+                let created_by_factor_source =
+                    |i: &&HierarchicalDeterministicFactorInstance| {
+                        FactorSourceID::from(i.factor_source_id.clone()) == id
+                    };
+                let factor_source_id = instances
+                    .iter()
+                    .find(|i| created_by_factor_source(i))?
+                    .factor_source_id
+                    .clone();
+                let transactions = self
+                    .transactions
+                    .iter()
+                    .filter(|t| {
+                        t.factor_instances()
+                            .any(|i| created_by_factor_source(&i))
+                    })
+                    .map(|t| t.intent_hash.clone())
+                    .collect_vec();
+
+                let entities = profile
+                    .entities_linked_to_factor_source(&id)
+                    .into_iter()
+                    .flat_map(|linked| {
+                        let accounts = linked
+                            .accounts
+                            .items()
+                            .into_iter()
+                            .filter(|a| is_signing(&a.security_state))
+                            .map(|a| {
+                                AddressOfAccountOrPersona::from(a.address)
+                            });
+                        let personas = linked
+                            .personas
+                            .items()
+                            .into_iter()
+                            .filter(|p| is_signing(&p.security_state))
+                            .map(|p| {
+                                AddressOfAccountOrPersona::from(p.address)
+                            });
+                        accounts.chain(personas).collect_vec()
+                    })
+                    .collect_vec();
+
+                let factor_source_kind = factor_source.factor_source_kind();
+                let estimated_ledger_interactions = match factor_source_kind {
+                    FactorSourceKind::LedgerHQHardwareWallet => {
+                        transactions.len() as u32
+                    }
+                    _ => 0,
+                };
+
+                Some(FactorSourceSigningDescription {
+                    factor_source_id,
+                    factor_source_kind,
+                    entities,
+                    transactions,
+                    estimated_ledger_interactions,
+                })
+            })
+            .collect_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn alice() -> Account {
+        Account::placeholder_mainnet_alice()
+    }
+
+    fn ledger_account() -> Account {
+        ledger_controlled_account(NetworkID::Mainnet, 7)
+    }
+
+    fn profile() -> Profile {
+        let mut profile = Profile::placeholder();
+        profile.networks.update_with(&NetworkID::Mainnet, |n| {
+            n.accounts.append(ledger_account());
+        });
+        profile
+    }
+
+    fn transaction(
+        profile: &Profile,
+        hash: Hex32Bytes,
+        class: ManifestClass,
+        accounts: impl IntoIterator<Item = AccountAddress>,
+    ) -> TransactionToSign {
+        let summary = ManifestSummary::new(
+            class,
+            accounts,
+            [AccountAddress::placeholder_mainnet()],
+            [],
+            [],
+        );
+        TransactionToSign::new(
+            TransactionIntentHash::new(NetworkID::Mainnet, hash),
+            profile.signing_policy(&summary).unwrap(),
+        )
+    }
+
+    #[test]
+    fn describe_groups_per_factor_source() {
+        let profile = profile();
+        let transfer = transaction(
+            &profile,
+            Hex32Bytes::placeholder_aced(),
+            ManifestClass::Transfer,
+            [alice().address],
+        );
+        let general = transaction(
+            &profile,
+            Hex32Bytes::placeholder_babe(),
+            ManifestClass::General,
+            [alice().address, ledger_account().address],
+        );
+        let sut = SigningPlan::new([transfer.clone(), general.clone()]);
+        assert_eq!(
+            sut.describe(&profile),
+            Ok(vec![
+                FactorSourceSigningDescription {
+                    factor_source_id: alice()
+                        .transaction_signing_factor_instance()
+                        .unwrap()
+                        .factor_source_id,
+                    factor_source_kind: FactorSourceKind::Device,
+                    entities: vec![alice().address.into()],
+                    transactions: vec![
+                        transfer.intent_hash,
+                        general.intent_hash.clone()
+                    ],
+                    estimated_ledger_interactions: 0,
+                },
+                FactorSourceSigningDescription {
+                    factor_source_id:
+                        FactorSourceIDFromHash::placeholder_ledger(),
+                    factor_source_kind:
+                        FactorSourceKind::LedgerHQHardwareWallet,
+                    entities: vec![ledger_account().address.into()],
+                    transactions: vec![general.intent_hash],
+                    estimated_ledger_interactions: 1,
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn ledger_interactions_one_per_transaction() {
+        let profile = profile();
+        let sut = SigningPlan::new(
+            [
+                Hex32Bytes::placeholder_aced(),
+                Hex32Bytes::placeholder_babe(),
+            ]
+            .into_iter()
+            .map(|hash| {
+                transaction(
+                    &profile,
+                    hash,
+                    ManifestClass::Transfer,
+                    [ledger_account().address],
+                )
+            }),
+        );
+        let description = sut.describe(&profile).unwrap();
+        assert_eq!(description.len(), 1);
+        assert_eq!(description[0].transactions.len(), 2);
+        assert_eq!(description[0].estimated_ledger_interactions, 2);
+    }
+
+    #[test]
+    fn empty_plan_describes_nothing() {
+        assert_eq!(SigningPlan::default().describe(&profile()), Ok(vec![]));
+    }
+
+    #[test]
+    fn unknown_factor_source_fails() {
+        let profile = profile();
+        let sut = SigningPlan::new([transaction(
+            &profile,
+            Hex32Bytes::placeholder_aced(),
+            ManifestClass::Transfer,
+            [ledger_account().address],
+        )]);
+        let mut without_ledger = profile.clone();
+        without_ledger.factor_sources =
+            FactorSources::from_iter([FactorSource::placeholder_device()]);
+        assert_eq!(
+            sut.describe(&without_ledger),
+            Err(CommonError::ProfileDoesNotContainFactorSourceWithID(
+                FactorSource::placeholder_ledger().factor_source_id()
+            ))
+        );
+    }
+}
@@ -0,0 +1,320 @@
+use crate::prelude::*;
+
+/// A factor instance which must sign the intent of a transaction, together
+/// with all entities requiring auth in the transaction it controls - more
+/// than one if the same key controls several entities.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct TransactionSigner {
+    pub factor_instance: HierarchicalDeterministicFactorInstance,
+
+    /// The entities controlled by `factor_instance`, in the order they
+    /// appear in the [`ManifestSummary`].
+    pub owners: Vec<AddressOfAccountOrPersona>,
+}
+
+/// How a transaction must be signed, resolved from its [`ManifestSummary`]
+/// and the Profile, so that all Wallet Clients request the minimal set of
+/// signatures.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct SigningPolicy {
+    /// The factor instance to notarize the transaction with, which then
+    /// also counts as a signatory, `None` if an ephemeral notary key should
+    /// be used.
+    pub notary: Option<HierarchicalDeterministicFactorInstance>,
+
+    /// The factor instances which must sign the intent, each exactly once,
+    /// empty if the notary is the only signatory.
+    pub signers: Vec<TransactionSigner>,
+}
+
+impl SigningPolicy {
+    /// `true` if the notary counts as a signatory of the transaction, i.e.
+    /// [`TransactionHeader::notary_is_signatory`] should be set.
+    pub fn notary_is_signatory(&self) -> bool {
+        self.notary.is_some()
+    }
+}
+
+impl Profile {
+    /// The factor instance signing transactions of the Account or Persona
+    /// with `address`, failing if it is unknown or watch-only.
+    fn transaction_signing_factor_instance_of(
+        &self,
+        address: &AddressOfAccountOrPersona,
+    ) -> Result<HierarchicalDeterministicFactorInstance> {
+        match address {
+            AddressOfAccountOrPersona::Account { value } => self
+                .networks
+                .get_account(value)
+                .ok_or(CommonError::UnknownAccount)?
+                .transaction_signing_factor_instance(),
+            AddressOfAccountOrPersona::Persona { value } => self
+                .networks
+                .get_persona(value)
+                .ok_or(CommonError::UnknownPersona)?
+                .security_state
+                .transaction_signing_factor_instance()
+                .cloned()
+                .ok_or_else(|| {
+                    CommonError::EntityIsWatchOnly(value.to_string())
+                }),
+        }
+    }
+
+    /// Resolves the [`SigningPolicy`] of a transaction with `summary`.
+    ///
+    /// The notary is a signatory only for a transfer from a single
+    /// unsecurified Account, in which case no further signature is needed.
+    /// Otherwise every entity requiring auth needs a signature, but only
+    /// once per factor instance.
+    ///
+    /// Fails if an entity requiring auth is unknown or watch-only.
+    pub fn signing_policy(
+        &self,
+        summary: &ManifestSummary,
+    ) -> Result<SigningPolicy> {
+        let mut signers = Vec::<TransactionSigner>::new();
+        for owner in summary.entities_requiring_auth() {
+            let factor_instance =
+                self.transaction_signing_factor_instance_of(&owner)?;
+            match signers
+                .iter_mut()
+                .find(|s| s.factor_instance == factor_instance)
+            {
+                Some(signer) => signer.owners.push(owner),
+                None => signers.push(TransactionSigner {
+                    factor_instance,
+                    owners: vec![owner],
+                }),
+            }
+        }
+
+        let is_single_account_transfer = summary.classification
+            == ManifestClass::Transfer
+            && summary.identities_requiring_auth.is_empty()
+            && signers.len() == 1
+            && signers[0].owners.len() == 1;
+
+        if is_single_account_transfer {
+            let signer = signers.remove(0);
+            return Ok(SigningPolicy {
+                notary: Some(signer.factor_instance),
+                signers,
+            });
+        }
+
+        Ok(SigningPolicy {
+            notary: None,
+            signers,
+        })
+    }
+}
+
+#[uniffi::export]
+pub fn signing_policy_notary_is_signatory(policy: &SigningPolicy) -> bool {
+    policy.notary_is_signatory()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn alice() -> Account {
+        Account::placeholder_mainnet_alice()
+    }
+
+    fn bob() -> Account {
+        Account::placeholder_mainnet_bob()
+    }
+
+    fn satoshi() -> Persona {
+        Persona::placeholder_mainnet_satoshi()
+    }
+
+    fn instance_of(
+        account: &Account,
+    ) -> HierarchicalDeterministicFactorInstance {
+        account.transaction_signing_factor_instance().unwrap()
+    }
+
+    fn transfer_from(
+        accounts: impl IntoIterator<Item = AccountAddress>,
+    ) -> ManifestSummary {
+        ManifestSummary::new(
+            ManifestClass::Transfer,
+            accounts,
+            [AccountAddress::placeholder_mainnet()],
+            [],
+            [],
+        )
+    }
+
+    #[test]
+    fn transfer_from_single_account_notary_is_signatory() {
+        let sut = Profile::placeholder()
+            .signing_policy(&transfer_from([alice().address]))
+            .unwrap();
+        assert!(sut.notary_is_signatory());
+        assert_eq!(sut.notary, Some(instance_of(&alice())));
+        assert!(sut.signers.is_empty());
+    }
+
+    #[test]
+    fn transfer_from_two_accounts_uses_ephemeral_notary() {
+        let sut = Profile::placeholder()
+            .signing_policy(&transfer_from([alice().address, bob().address]))
+            .unwrap();
+        assert!(!sut.notary_is_signatory());
+        assert_eq!(
+            sut.signers,
+            vec![
+                TransactionSigner {
+                    factor_instance: instance_of(&alice()),
+                    owners: vec![alice().address.into()]
+                },
+                TransactionSigner {
+                    factor_instance: instance_of(&bob()),
+                    owners: vec![bob().address.into()]
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn general_manifest_uses_ephemeral_notary() {
+        let summary = ManifestSummary::new(
+            ManifestClass::General,
+            [alice().address],
+            [],
+            [],
+            [],
+        );
+        let sut = Profile::placeholder().signing_policy(&summary).unwrap();
+        assert!(!sut.notary_is_signatory());
+        assert_eq!(sut.signers.len(), 1);
+    }
+
+    #[test]
+    fn persona_requiring_auth_is_signer() {
+        let summary = ManifestSummary::new(
+            ManifestClass::Transfer,
+            [alice().address],
+            [],
+            [],
+            [satoshi().address],
+        );
+        let sut = Profile::placeholder().signing_policy(&summary).unwrap();
+        assert!(!sut.notary_is_signatory());
+        assert_eq!(
+            sut.signers
+                .iter()
+                .flat_map(|s| s.owners.clone())
+                .collect_vec(),
+            vec![alice().address.into(), satoshi().address.into()]
+        );
+    }
+
+    #[test]
+    fn entities_controlled_by_same_factor_instance_sign_once() {
+        let mut shared_key_account = alice();
+        shared_key_account.address = AccountAddress::placeholder_mainnet();
+        let mut profile = Profile::placeholder();
+        profile.networks.update_with(&NetworkID::Mainnet, |n| {
+            n.accounts.append(shared_key_account.clone());
+        });
+        let summary = ManifestSummary::new(
+            ManifestClass::Transfer,
+            [alice().address, shared_key_account.address.clone()],
+            [],
+            [],
+            [],
+        );
+        let sut = profile.signing_policy(&summary).unwrap();
+        assert!(!sut.notary_is_signatory());
+        assert_eq!(
+            sut.signers,
+            vec![TransactionSigner {
+                factor_instance: instance_of(&alice()),
+                owners: vec![
+                    alice().address.into(),
+                    shared_key_account.address.into()
+                ]
+            }]
+        );
+    }
+
+    #[test]
+    fn no_entities_requiring_auth() {
+        let sut = Profile::placeholder()
+            .signing_policy(&transfer_from([]))
+            .unwrap();
+        assert_eq!(
+            sut,
+            SigningPolicy {
+                notary: None,
+                signers: Vec::new()
+            }
+        );
+    }
+
+    #[test]
+    fn unknown_account_fails() {
+        assert_eq!(
+            Profile::placeholder().signing_policy(&transfer_from([
+                AccountAddress::placeholder_mainnet()
+            ])),
+            Err(CommonError::UnknownAccount)
+        );
+    }
+
+    #[test]
+    fn unknown_persona_fails() {
+        let summary = ManifestSummary::new(
+            ManifestClass::General,
+            [],
+            [],
+            [],
+            [IdentityAddress::placeholder_mainnet()],
+        );
+        assert_eq!(
+            Profile::placeholder().signing_policy(&summary),
+            Err(CommonError::UnknownPersona)
+        );
+    }
+
+    #[test]
+    fn watch_only_account_fails() {
+        let address = AccountAddress::placeholder_mainnet();
+        let mut profile = Profile::placeholder();
+        profile.networks.update_with(&NetworkID::Mainnet, |n| {
+            n.accounts.append(Account::new_watch_only(
+                address.clone(),
+                DisplayName::new("Watched").unwrap(),
+                AppearanceID::default(),
+            ));
+        });
+        assert_eq!(
+            profile.signing_policy(&transfer_from([address.clone()])),
+            Err(CommonError::EntityIsWatchOnly(address.to_string()))
+        );
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn notary_is_signatory() {
+        let policy = Profile::placeholder()
+            .signing_policy(&ManifestSummary::new(
+                ManifestClass::Transfer,
+                [Account::placeholder_mainnet_alice().address],
+                [],
+                [],
+                [],
+            ))
+            .unwrap();
+        assert!(signing_policy_notary_is_signatory(&policy));
+    }
+}
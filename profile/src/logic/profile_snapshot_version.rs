@@ -0,0 +1,124 @@
+use crate::prelude::*;
+
+impl Profile {
+    /// The oldest snapshot version able to represent this Profile, i.e.
+    /// `V101` if it contains a watch-only entity, else `V100`.
+    pub fn required_snapshot_version(&self) -> ProfileSnapshotVersion {
+        let is_unowned =
+            |s: &EntitySecurityState| matches!(s, EntitySecurityState::Unowned);
+        let has_unowned_entity = self.networks.iter().any(|n| {
+            n.accounts.iter().any(|a| is_unowned(&a.security_state))
+                || n.personas.iter().any(|p| is_unowned(&p.security_state))
+        });
+        if has_unowned_entity {
+            ProfileSnapshotVersion::V101
+        } else {
+            ProfileSnapshotVersion::V100
+        }
+    }
+
+    /// Raises the `snapshot_version` of the header to the
+    /// [`Profile::required_snapshot_version`], so that readers of older
+    /// versions refuse the snapshot instead of failing on content they do
+    /// not know. Never lowers it, done after every mutation of the Profile.
+    pub fn update_snapshot_version(&mut self) {
+        self.header.snapshot_version = self
+            .header
+            .snapshot_version
+            .max(self.required_snapshot_version());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn watch_only_account() -> Account {
+        Account::new_watch_only(
+            "account_rdx129qdd2yp9vs8jkkn2uwn6sw0ejwmcwr3r4c3usr2hp0nau67m2kzdm"
+                .parse()
+                .unwrap(),
+            DisplayName::new("Watched").unwrap(),
+            AppearanceID::default(),
+        )
+    }
+
+    fn profile_with_watch_only_account() -> Profile {
+        let mut sut = Profile::placeholder();
+        sut.networks.update_with(&NetworkID::Mainnet, |n| {
+            n.accounts.append(watch_only_account());
+        });
+        sut.update_snapshot_version();
+        sut
+    }
+
+    /// How a reader of V100 snapshots, knowing no later version, decodes the
+    /// version of a snapshot before anything else.
+    #[derive(Deserialize_repr, Debug, PartialEq)]
+    #[repr(u16)]
+    enum V100ReaderSnapshotVersion {
+        V100 = 100,
+    }
+
+    fn read_as_v100_reader(
+        profile: &Profile,
+    ) -> serde_json::Result<V100ReaderSnapshotVersion> {
+        let json = serde_json::to_value(profile).unwrap();
+        serde_json::from_value(json["header"]["snapshotVersion"].clone())
+    }
+
+    #[test]
+    fn placeholder_requires_v100() {
+        let mut sut = Profile::placeholder();
+        assert_eq!(
+            sut.required_snapshot_version(),
+            ProfileSnapshotVersion::V100
+        );
+        sut.update_snapshot_version();
+        assert_eq!(sut, Profile::placeholder());
+    }
+
+    #[test]
+    fn watch_only_account_requires_v101() {
+        let sut = profile_with_watch_only_account();
+        assert_eq!(
+            sut.required_snapshot_version(),
+            ProfileSnapshotVersion::V101
+        );
+        assert_eq!(sut.header.snapshot_version, ProfileSnapshotVersion::V101);
+    }
+
+    #[test]
+    fn never_lowered() {
+        let mut sut = profile_with_watch_only_account();
+        sut.networks.update_with(&NetworkID::Mainnet, |n| {
+            n.accounts.remove_by_id(&watch_only_account().address);
+        });
+        sut.update_snapshot_version();
+        assert_eq!(sut.header.snapshot_version, ProfileSnapshotVersion::V101);
+    }
+
+    #[test]
+    fn v100_reader_reads_profile_without_watch_only_accounts() {
+        let sut = Profile::placeholder();
+        assert_eq!(
+            read_as_v100_reader(&sut).unwrap(),
+            V100ReaderSnapshotVersion::V100
+        );
+        assert!(!serde_json::to_string(&sut).unwrap().contains("unowned"));
+    }
+
+    #[test]
+    fn v100_reader_refuses_profile_with_watch_only_account() {
+        let sut = profile_with_watch_only_account();
+        assert!(read_as_v100_reader(&sut).is_err());
+    }
+
+    #[test]
+    fn json_roundtrip_v101() {
+        let sut = profile_with_watch_only_account();
+        let json = serde_json::to_value(&sut).unwrap();
+        assert_eq!(json["header"]["snapshotVersion"], json!(101));
+        assert_eq!(serde_json::from_value::<Profile>(json).unwrap(), sut);
+    }
+}
@@ -0,0 +1,211 @@
+use crate::prelude::*;
+use serde_json::Value;
+
+/// The value of redacted fields in a support dump.
+const REDACTED: &str = "<redacted>";
+
+/// JSON keys of fields containing names or personal data the user entered,
+/// which are replaced by a short hash so that equal values can still be
+/// told apart.
+const HASHED_KEYS: [&str; 7] = [
+    "displayName",
+    "givenNames",
+    "familyName",
+    "nickname",
+    "name",
+    "description",
+    "value",
+];
+
+/// JSON keys of fields containing keys or secrets, which are redacted.
+const REDACTED_KEYS: [&str; 2] = ["compressedData", "connectionPassword"];
+
+/// The kinds of addresses, i.e. the part of the HRP before `_`, which are
+/// truncated.
+const ADDRESS_PREFIXES: [&str; 8] = [
+    "account",
+    "identity",
+    "resource",
+    "component",
+    "package",
+    "pool",
+    "validator",
+    "accesscontroller",
+];
+
+/// `"<hash:...>"` of the first 8 bytes of the hash of `value`.
+fn hashed(value: &str) -> String {
+    format!("<hash:{}>", hex_encode(&hash(value).0[..8]))
+}
+
+/// Truncates the data part of a bech32 encoded address - keeping the HRP,
+/// the first 4 and the last 6 characters - or `None` if `value` does not
+/// look like an address. Any suffix after `:` - e.g. the local ID of a
+/// non fungible global ID - is kept.
+fn truncated_address(value: &str) -> Option<String> {
+    let (address, suffix) = match value.split_once(':') {
+        Some((address, local_id)) => (address, format!(":{}", local_id)),
+        None => (value, String::new()),
+    };
+    let (kind, _) = address.split_once('_')?;
+    if !ADDRESS_PREFIXES.contains(&kind) {
+        return None;
+    }
+    // The bech32 charset does not contain `1`, so the last `1` is always the
+    // separator between the HRP and the data.
+    let (hrp, data) = address.rsplit_once('1')?;
+    if data.len() <= 10 {
+        return None;
+    }
+    Some(format!(
+        "{}1{}...{}{}",
+        hrp,
+        &data[..4],
+        &data[data.len() - 6..],
+        suffix
+    ))
+}
+
+fn redact(value: Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .map(|(key, value)| {
+                    let value = match value {
+                        Value::String(_)
+                            if REDACTED_KEYS.contains(&key.as_str()) =>
+                        {
+                            Value::String(REDACTED.to_owned())
+                        }
+                        Value::String(s)
+                            if HASHED_KEYS.contains(&key.as_str()) =>
+                        {
+                            Value::String(hashed(&s))
+                        }
+                        other => redact(other),
+                    };
+                    (key, value)
+                })
+                .collect(),
+        ),
+        Value::Array(values) => {
+            Value::Array(values.into_iter().map(redact).collect_vec())
+        }
+        Value::String(s) => Value::String(truncated_address(&s).unwrap_or(s)),
+        other => other,
+    }
+}
+
+impl Profile {
+    /// Returns a redacted JSON representation of this Profile, which users
+    /// can attach to bug reports. It has the same structure as the Profile
+    /// JSON, but addresses are truncated, names and personal data are
+    /// replaced by hashes and keys and secrets are removed.
+    pub fn support_dump(&self) -> String {
+        let json = serde_json::to_value(self)
+            .expect("Should always be able to JSON encode Profile.");
+        serde_json::to_string_pretty(&redact(json))
+            .expect("Should always be able to JSON encode redacted Profile.")
+    }
+}
+
+#[uniffi::export]
+pub fn profile_support_dump(profile: &Profile) -> String {
+    profile.support_dump()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use serde_json::Value;
+
+    /// All keys of all objects in `value`, recursively.
+    fn keys(value: &Value) -> Vec<String> {
+        match value {
+            Value::Object(map) => map
+                .iter()
+                .flat_map(|(k, v)| {
+                    std::iter::once(k.clone()).chain(keys(v).into_iter())
+                })
+                .collect_vec(),
+            Value::Array(values) => values.iter().flat_map(keys).collect_vec(),
+            _ => Vec::new(),
+        }
+    }
+
+    #[test]
+    fn structurally_complete() {
+        let profile = Profile::placeholder();
+        let dump: Value =
+            serde_json::from_str(&profile.support_dump()).unwrap();
+        assert_eq!(keys(&dump), keys(&serde_json::to_value(&profile).unwrap()));
+    }
+
+    #[test]
+    fn does_not_contain_user_data() {
+        let profile = Profile::placeholder();
+        let dump = profile.support_dump();
+        let account = Account::placeholder();
+        assert!(!dump.contains(&account.address.address));
+        assert!(!dump.contains(&account.display_name.value));
+        let public_key = account
+            .transaction_signing_factor_instance()
+            .unwrap()
+            .public_key
+            .public_key;
+        assert!(!dump.contains(&public_key.to_hex()));
+        for link in profile.app_preferences.p2p_links.iter() {
+            assert!(!dump.contains(&link.connection_password.value.to_hex()));
+        }
+    }
+
+    #[test]
+    fn addresses_are_truncated() {
+        let address = Account::placeholder().address.address;
+        let dump = Profile::placeholder().support_dump();
+        let (hrp, data) = address.rsplit_once('1').unwrap();
+        assert!(dump.contains(&format!(
+            "{}1{}...{}",
+            hrp,
+            &data[..4],
+            &data[data.len() - 6..]
+        )));
+    }
+
+    #[test]
+    fn names_are_hashed_deterministically() {
+        let profile = Profile::placeholder();
+        assert_eq!(profile.support_dump(), profile.support_dump());
+        assert_ne!(
+            profile.support_dump(),
+            Profile::placeholder_other().support_dump()
+        );
+    }
+
+    #[test]
+    fn non_fungible_global_id_keeps_local_id() {
+        assert_eq!(
+            super::truncated_address(
+                "resource_rdx1nfyg2f68jw7hfdlg5hzvd8ylsa7e0kjl68t5t62v3ttamtejc9wlxa:<Member_237>"
+            ),
+            Some("resource_rdx1nfyg...c9wlxa:<Member_237>".to_owned())
+        );
+    }
+
+    #[test]
+    fn not_an_address() {
+        assert_eq!(super::truncated_address("https://radixdlt.com"), None);
+        assert_eq!(super::truncated_address("account_"), None);
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn support_dump() {
+        let profile = Profile::placeholder();
+        assert_eq!(profile_support_dump(&profile), profile.support_dump());
+    }
+}
@@ -0,0 +1,11 @@
+mod network_request;
+mod network_request_failure;
+mod network_response;
+mod networking_driver;
+mod retry_policy;
+
+pub use network_request::*;
+pub use network_request_failure::*;
+pub use network_response::*;
+pub use networking_driver::*;
+pub use retry_policy::*;
@@ -0,0 +1,90 @@
+use crate::prelude::*;
+
+/// The HTTP method of a [`NetworkRequest`].
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    derive_more::Display,
+    uniffi::Enum,
+)]
+pub enum NetworkMethod {
+    #[display("GET")]
+    Get,
+    #[display("POST")]
+    Post,
+}
+
+/// A request executed by the Wallet Client on behalf of the wallet kit,
+/// using its [`NetworkingDriver`].
+#[derive(Clone, Debug, PartialEq, Eq, uniffi::Record)]
+pub struct NetworkRequest {
+    pub url: Url,
+    pub method: NetworkMethod,
+    pub headers: HashMap<String, String>,
+    pub body: BagOfBytes,
+}
+
+impl NetworkRequest {
+    pub fn new_get(url: Url) -> Self {
+        Self {
+            url,
+            method: NetworkMethod::Get,
+            headers: HashMap::new(),
+            body: BagOfBytes::default(),
+        }
+    }
+
+    /// A POST request to `url` with `body` encoded as JSON.
+    pub fn new_post_json<T: Serialize>(url: Url, body: &T) -> Self {
+        let body = serde_json::to_vec(body)
+            .expect("Should always be able to JSON serialize a request body.");
+        Self {
+            url,
+            method: NetworkMethod::Post,
+            headers: HashMap::from([(
+                "Content-Type".to_owned(),
+                "application/json".to_owned(),
+            )]),
+            body: body.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn method_display() {
+        assert_eq!(NetworkMethod::Get.to_string(), "GET");
+        assert_eq!(NetworkMethod::Post.to_string(), "POST");
+    }
+
+    #[test]
+    fn get() {
+        let url = Url::parse("https://example.com").unwrap();
+        let sut = NetworkRequest::new_get(url.clone());
+        assert_eq!(sut.url, url);
+        assert_eq!(sut.method, NetworkMethod::Get);
+        assert!(sut.headers.is_empty());
+        assert!(sut.body.is_empty());
+    }
+
+    #[test]
+    fn post_json() {
+        let url = Url::parse("https://example.com").unwrap();
+        let sut = NetworkRequest::new_post_json(url, &json!({ "a": 1 }));
+        assert_eq!(sut.method, NetworkMethod::Post);
+        assert_eq!(
+            sut.headers.get("Content-Type").map(String::as_str),
+            Some("application/json")
+        );
+        assert_eq!(sut.body.to_vec(), br#"{"a":1}"#.to_vec());
+    }
+}
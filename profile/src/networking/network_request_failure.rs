@@ -0,0 +1,107 @@
+use crate::prelude::*;
+
+/// A failed attempt to execute a [`NetworkRequest`], reported to the
+/// [`NetworkRequestFailureObserver`] e.g. so that Wallet Clients can log
+/// failures or show connectivity issues.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct NetworkRequestFailure {
+    pub url: Url,
+
+    /// The attempt which failed, starting at `1`.
+    pub attempt: u32,
+
+    /// The status code of the response, `None` if the [`NetworkingDriver`]
+    /// failed to execute the request.
+    pub status_code: Option<u16>,
+
+    /// Description of the error of the [`NetworkingDriver`], if it failed.
+    pub error: Option<String>,
+
+    /// `true` if the request will be retried as per the [`RetryPolicy`].
+    pub will_retry: bool,
+}
+
+impl NetworkRequestFailure {
+    pub fn new(
+        request: &NetworkRequest,
+        result: &Result<NetworkResponse>,
+        attempt: u32,
+        will_retry: bool,
+    ) -> Self {
+        let (status_code, error) = match result {
+            Ok(response) => (Some(response.status_code), None),
+            Err(error) => (None, Some(error.to_string())),
+        };
+        Self {
+            url: request.url.clone(),
+            attempt,
+            status_code,
+            error,
+            will_retry,
+        }
+    }
+}
+
+/// Implemented by the Wallet Client to be notified of every failed attempt
+/// to execute a network request on its behalf.
+#[uniffi::export]
+pub trait NetworkRequestFailureObserver: Send + Sync + std::fmt::Debug {
+    fn handle_failure(&self, failure: NetworkRequestFailure);
+}
+
+/// Records all failures it is notified of.
+#[cfg(test)]
+#[derive(Debug, Default)]
+pub(crate) struct RecordingFailureObserver {
+    failures: std::sync::RwLock<Vec<NetworkRequestFailure>>,
+}
+
+#[cfg(test)]
+impl RecordingFailureObserver {
+    pub(crate) fn failures(&self) -> Vec<NetworkRequestFailure> {
+        self.failures.read().unwrap().clone()
+    }
+}
+
+#[cfg(test)]
+impl NetworkRequestFailureObserver for RecordingFailureObserver {
+    fn handle_failure(&self, failure: NetworkRequestFailure) {
+        self.failures.write().unwrap().push(failure);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn request() -> NetworkRequest {
+        NetworkRequest::new_get(Url::parse("https://example.com").unwrap())
+    }
+
+    #[test]
+    fn from_bad_status() {
+        let sut = NetworkRequestFailure::new(
+            &request(),
+            &Ok(NetworkResponse::new(503, Vec::new())),
+            2,
+            true,
+        );
+        assert_eq!(sut.url, request().url);
+        assert_eq!(sut.attempt, 2);
+        assert_eq!(sut.status_code, Some(503));
+        assert_eq!(sut.error, None);
+        assert!(sut.will_retry);
+    }
+
+    #[test]
+    fn from_driver_error() {
+        let sut = NetworkRequestFailure::new(
+            &request(),
+            &Err(CommonError::Unknown),
+            1,
+            false,
+        );
+        assert_eq!(sut.status_code, None);
+        assert_eq!(sut.error, Some(CommonError::Unknown.to_string()));
+    }
+}
@@ -0,0 +1,78 @@
+use crate::prelude::*;
+
+/// The response to a [`NetworkRequest`], returned by the
+/// [`NetworkingDriver`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct NetworkResponse {
+    pub status_code: u16,
+    pub body: BagOfBytes,
+}
+
+impl NetworkResponse {
+    pub fn new(status_code: u16, body: impl Into<BagOfBytes>) -> Self {
+        Self {
+            status_code,
+            body: body.into(),
+        }
+    }
+
+    /// Returns the body if the status code is `2xx`, else an error.
+    pub fn successful_body(&self) -> Result<&[u8]> {
+        if (200..300).contains(&self.status_code) {
+            Ok(self.body.as_slice())
+        } else {
+            Err(CommonError::NetworkResponseBadCode {
+                code: self.status_code,
+            })
+        }
+    }
+
+    /// Decodes the body as JSON if the status code is `2xx`.
+    pub fn decode_json<T: for<'de> Deserialize<'de>>(&self) -> Result<T> {
+        let body = self.successful_body()?;
+        serde_json::from_slice(body).map_err(|_| {
+            CommonError::FailedToDeserializeJSONToValue {
+                json_byte_count: body.len(),
+                type_name: std::any::type_name::<T>().to_string(),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn successful_body() {
+        let sut = NetworkResponse::new(204, b"abc".to_vec());
+        assert_eq!(sut.successful_body(), Ok(b"abc".as_slice()));
+    }
+
+    #[test]
+    fn bad_code() {
+        let sut = NetworkResponse::new(404, Vec::new());
+        assert_eq!(
+            sut.successful_body(),
+            Err(CommonError::NetworkResponseBadCode { code: 404 })
+        );
+    }
+
+    #[test]
+    fn decode_json() {
+        let sut = NetworkResponse::new(200, br#"{"dApps":[]}"#.to_vec());
+        assert_eq!(
+            sut.decode_json::<RadixWellKnownFile>(),
+            Ok(RadixWellKnownFile { dapps: Vec::new() })
+        );
+    }
+
+    #[test]
+    fn decode_json_invalid() {
+        let sut = NetworkResponse::new(200, b"oops".to_vec());
+        assert!(matches!(
+            sut.decode_json::<RadixWellKnownFile>(),
+            Err(CommonError::FailedToDeserializeJSONToValue { .. })
+        ));
+    }
+}
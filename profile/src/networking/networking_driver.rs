@@ -0,0 +1,53 @@
+use crate::prelude::*;
+
+/// Implemented by the Wallet Client to execute network requests on behalf
+/// of the wallet kit, e.g. using `URLSession` or `OkHttp`.
+#[uniffi::export]
+pub trait NetworkingDriver: Send + Sync + std::fmt::Debug {
+    fn execute_network_request(
+        &self,
+        request: NetworkRequest,
+    ) -> Result<NetworkResponse>;
+}
+
+/// A [`NetworkingDriver`] which responds with canned responses keyed by
+/// URL, used to facilitate unit tests.
+#[cfg(test)]
+#[derive(Debug, Default)]
+pub(crate) struct MockNetworkingDriver {
+    responses: HashMap<Url, NetworkResponse>,
+    requests: std::sync::RwLock<Vec<NetworkRequest>>,
+}
+
+#[cfg(test)]
+impl MockNetworkingDriver {
+    pub(crate) fn new<I>(responses: I) -> Self
+    where
+        I: IntoIterator<Item = (Url, NetworkResponse)>,
+    {
+        Self {
+            responses: responses.into_iter().collect(),
+            requests: Default::default(),
+        }
+    }
+
+    /// All requests executed so far, in order.
+    pub(crate) fn requests(&self) -> Vec<NetworkRequest> {
+        self.requests.read().unwrap().clone()
+    }
+}
+
+#[cfg(test)]
+impl NetworkingDriver for MockNetworkingDriver {
+    fn execute_network_request(
+        &self,
+        request: NetworkRequest,
+    ) -> Result<NetworkResponse> {
+        self.requests.write().unwrap().push(request.clone());
+        Ok(self
+            .responses
+            .get(&request.url)
+            .cloned()
+            .unwrap_or(NetworkResponse::new(404, Vec::new())))
+    }
+}
@@ -0,0 +1,302 @@
+use crate::prelude::*;
+use std::time::Duration;
+
+/// How network requests which failed transiently - i.e. the
+/// [`NetworkingDriver`] failed or the response has one of the
+/// `retryable_status_codes` - are retried, with exponential backoff.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct RetryPolicy {
+    /// Total number of times a request is executed, including the first
+    /// attempt, `0` is treated as `1`.
+    pub max_attempts: u32,
+
+    /// Delay before the first retry, doubled for every following retry.
+    pub initial_backoff_milliseconds: u64,
+
+    /// Upper bound of the delay before any retry.
+    pub max_backoff_milliseconds: u64,
+
+    /// Up to this percentage of each delay is randomly subtracted from it,
+    /// so that many clients failing at once do not retry in lockstep,
+    /// values above `100` are treated as `100`.
+    pub jitter_percent: u8,
+
+    /// Response status codes considered transient, e.g. `503`.
+    pub retryable_status_codes: Vec<u16>,
+}
+
+impl RetryPolicy {
+    pub fn new(
+        max_attempts: u32,
+        initial_backoff_milliseconds: u64,
+        max_backoff_milliseconds: u64,
+        jitter_percent: u8,
+        retryable_status_codes: impl IntoIterator<Item = u16>,
+    ) -> Self {
+        Self {
+            max_attempts,
+            initial_backoff_milliseconds,
+            max_backoff_milliseconds,
+            jitter_percent,
+            retryable_status_codes: retryable_status_codes
+                .into_iter()
+                .collect(),
+        }
+    }
+
+    /// A policy which never retries.
+    pub fn no_retries() -> Self {
+        Self::new(1, 0, 0, 0, [])
+    }
+
+    /// `true` if `result` of executing a request is a transient failure.
+    pub fn is_retryable(&self, result: &Result<NetworkResponse>) -> bool {
+        match result {
+            Ok(response) => {
+                self.retryable_status_codes.contains(&response.status_code)
+            }
+            Err(_) => true,
+        }
+    }
+
+    /// The delay before retry number `retry` (zero based), without jitter.
+    pub fn backoff_without_jitter(&self, retry: u32) -> Duration {
+        Duration::from_millis(
+            self.initial_backoff_milliseconds
+                .saturating_mul(2u64.saturating_pow(retry))
+                .min(self.max_backoff_milliseconds),
+        )
+    }
+
+    /// The delay before retry number `retry` (zero based), with a random
+    /// jitter subtracted.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        self.backoff_with_jitter_fraction(retry, rand::random::<f64>())
+    }
+
+    /// `fraction` in `[0, 1)` of the maximum jitter is subtracted.
+    fn backoff_with_jitter_fraction(
+        &self,
+        retry: u32,
+        fraction: f64,
+    ) -> Duration {
+        let jitter = f64::from(self.jitter_percent.min(100)) / 100.0;
+        let milliseconds =
+            self.backoff_without_jitter(retry).as_millis() as f64;
+        Duration::from_millis(
+            (milliseconds * (1.0 - jitter * fraction)).round() as u64,
+        )
+    }
+
+    /// Executes `request` using `driver`, retrying transient failures as
+    /// per this policy and notifying `observer` of every failed attempt.
+    ///
+    /// Returns the last response if all attempts failed with a retryable
+    /// status code, so that callers handle it like any other bad status.
+    pub fn execute(
+        &self,
+        driver: &dyn NetworkingDriver,
+        request: NetworkRequest,
+        observer: Option<&dyn NetworkRequestFailureObserver>,
+    ) -> Result<NetworkResponse> {
+        let max_attempts = self.max_attempts.max(1);
+        let mut attempt = 1;
+        loop {
+            let result = driver.execute_network_request(request.clone());
+            let is_failure = match &result {
+                Ok(response) => response.successful_body().is_err(),
+                Err(_) => true,
+            };
+            if !is_failure {
+                return result;
+            }
+            let will_retry =
+                self.is_retryable(&result) && attempt < max_attempts;
+            if let Some(observer) = observer {
+                observer.handle_failure(NetworkRequestFailure::new(
+                    &request, &result, attempt, will_retry,
+                ));
+            }
+            if !will_retry {
+                return result;
+            }
+            std::thread::sleep(self.backoff(attempt - 1));
+            attempt += 1;
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new(3, 250, 5_000, 20, [408, 429, 500, 502, 503, 504])
+    }
+}
+
+#[uniffi::export]
+pub fn new_retry_policy_default() -> RetryPolicy {
+    RetryPolicy::default()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use std::sync::RwLock;
+
+    /// Responds with `responses` in order, regardless of the request.
+    #[derive(Debug)]
+    struct SequenceNetworkingDriver {
+        responses: RwLock<Vec<Result<NetworkResponse>>>,
+    }
+
+    impl SequenceNetworkingDriver {
+        fn new(responses: Vec<Result<NetworkResponse>>) -> Self {
+            Self {
+                responses: RwLock::new(responses.into_iter().rev().collect()),
+            }
+        }
+    }
+
+    impl NetworkingDriver for SequenceNetworkingDriver {
+        fn execute_network_request(
+            &self,
+            _request: NetworkRequest,
+        ) -> Result<NetworkResponse> {
+            self.responses.write().unwrap().pop().unwrap()
+        }
+    }
+
+    fn request() -> NetworkRequest {
+        NetworkRequest::new_get(Url::parse("https://example.com").unwrap())
+    }
+
+    fn status(code: u16) -> Result<NetworkResponse> {
+        Ok(NetworkResponse::new(code, Vec::new()))
+    }
+
+    fn policy(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy::new(max_attempts, 0, 0, 0, [503])
+    }
+
+    #[test]
+    fn default_policy() {
+        let sut = new_retry_policy_default();
+        assert_eq!(sut.max_attempts, 3);
+        assert!(sut.is_retryable(&status(429)));
+        assert!(sut.is_retryable(&status(503)));
+        assert!(!sut.is_retryable(&status(404)));
+        assert!(sut.is_retryable(&Err(CommonError::Unknown)));
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_max() {
+        let sut = RetryPolicy::new(5, 100, 300, 0, []);
+        assert_eq!(sut.backoff_without_jitter(0).as_millis(), 100);
+        assert_eq!(sut.backoff_without_jitter(1).as_millis(), 200);
+        assert_eq!(sut.backoff_without_jitter(2).as_millis(), 300);
+        assert_eq!(sut.backoff_without_jitter(40).as_millis(), 300);
+    }
+
+    #[test]
+    fn backoff_without_jitter_percent_is_exact() {
+        let sut = RetryPolicy::new(5, 100, 1_000, 0, []);
+        assert_eq!(sut.backoff(1).as_millis(), 200);
+    }
+
+    #[test]
+    fn jitter_subtracts_fraction() {
+        let sut = RetryPolicy::new(5, 100, 1_000, 50, []);
+        assert_eq!(sut.backoff_with_jitter_fraction(0, 0.0).as_millis(), 100);
+        assert_eq!(sut.backoff_with_jitter_fraction(0, 0.5).as_millis(), 75);
+        assert!((50..=100).contains(&sut.backoff(0).as_millis()));
+    }
+
+    #[test]
+    fn jitter_percent_is_capped() {
+        let sut = RetryPolicy::new(5, 100, 1_000, 255, []);
+        assert_eq!(sut.backoff_with_jitter_fraction(0, 0.5).as_millis(), 50);
+    }
+
+    #[test]
+    fn success_is_not_retried() {
+        let driver = SequenceNetworkingDriver::new(vec![status(200)]);
+        let observer = RecordingFailureObserver::default();
+        assert_eq!(
+            policy(3).execute(&driver, request(), Some(&observer)),
+            status(200)
+        );
+        assert!(observer.failures().is_empty());
+    }
+
+    #[test]
+    fn retries_until_success() {
+        let driver = SequenceNetworkingDriver::new(vec![
+            status(503),
+            Err(CommonError::Unknown),
+            status(200),
+        ]);
+        let observer = RecordingFailureObserver::default();
+        assert_eq!(
+            policy(3).execute(&driver, request(), Some(&observer)),
+            status(200)
+        );
+        assert_eq!(
+            observer
+                .failures()
+                .into_iter()
+                .map(|f| (f.attempt, f.status_code, f.will_retry))
+                .collect_vec(),
+            vec![(1, Some(503), true), (2, None, true)]
+        );
+    }
+
+    #[test]
+    fn gives_up_after_max_attempts() {
+        let driver = SequenceNetworkingDriver::new(vec![
+            status(503),
+            status(503),
+            status(200),
+        ]);
+        let observer = RecordingFailureObserver::default();
+        assert_eq!(
+            policy(2).execute(&driver, request(), Some(&observer)),
+            status(503)
+        );
+        assert_eq!(
+            observer
+                .failures()
+                .into_iter()
+                .map(|f| f.will_retry)
+                .collect_vec(),
+            vec![true, false]
+        );
+    }
+
+    #[test]
+    fn zero_max_attempts_executes_once() {
+        let driver = SequenceNetworkingDriver::new(vec![status(503)]);
+        assert_eq!(policy(0).execute(&driver, request(), None), status(503));
+    }
+
+    #[test]
+    fn non_retryable_status_is_not_retried() {
+        let driver =
+            SequenceNetworkingDriver::new(vec![status(404), status(200)]);
+        let observer = RecordingFailureObserver::default();
+        assert_eq!(
+            policy(3).execute(&driver, request(), Some(&observer)),
+            status(404)
+        );
+        assert_eq!(observer.failures().len(), 1);
+        assert!(!observer.failures()[0].will_retry);
+    }
+
+    #[test]
+    fn no_retries() {
+        let driver =
+            SequenceNetworkingDriver::new(vec![status(503), status(200)]);
+        assert_eq!(
+            RetryPolicy::no_retries().execute(&driver, request(), None),
+            status(503)
+        );
+    }
+}
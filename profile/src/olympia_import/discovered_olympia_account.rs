@@ -0,0 +1,70 @@
+use crate::prelude::*;
+
+/// An Olympia Account discovered by scanning the BIP44 paths of a factor
+/// source, together with the address of its Babylon counterpart.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct DiscoveredOlympiaAccount {
+    pub account: OlympiaAccountToMigrate,
+
+    /// The address of the Babylon Account controlled by the same key as
+    /// `account`.
+    pub babylon_address: AccountAddress,
+}
+
+impl DiscoveredOlympiaAccount {
+    pub fn new(
+        account: OlympiaAccountToMigrate,
+        network_id: NetworkID,
+    ) -> Self {
+        let babylon_address = account.babylon_address(network_id);
+        Self {
+            account,
+            babylon_address,
+        }
+    }
+}
+
+impl HasPlaceholder for DiscoveredOlympiaAccount {
+    fn placeholder() -> Self {
+        Self::new(OlympiaAccountToMigrate::placeholder(), NetworkID::Mainnet)
+    }
+
+    fn placeholder_other() -> Self {
+        Self::new(
+            OlympiaAccountToMigrate::placeholder_other(),
+            NetworkID::Mainnet,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = DiscoveredOlympiaAccount;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::placeholder(), SUT::placeholder());
+        assert_eq!(SUT::placeholder_other(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::placeholder(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn babylon_address_on_network() {
+        let sut = SUT::new(
+            OlympiaAccountToMigrate::placeholder(),
+            NetworkID::Stokenet,
+        );
+        assert_eq!(sut.babylon_address.network_id, NetworkID::Stokenet);
+        assert_eq!(
+            sut.babylon_address,
+            sut.account.babylon_address(NetworkID::Stokenet)
+        );
+    }
+}
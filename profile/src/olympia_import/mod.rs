@@ -0,0 +1,11 @@
+mod discovered_olympia_account;
+mod olympia_account_to_migrate;
+mod olympia_account_type;
+mod olympia_wallet_export;
+mod olympia_wallet_export_payload;
+
+pub use discovered_olympia_account::*;
+pub use olympia_account_to_migrate::*;
+pub use olympia_account_type::*;
+pub use olympia_wallet_export::*;
+pub use olympia_wallet_export_payload::*;
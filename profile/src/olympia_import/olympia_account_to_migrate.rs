@@ -0,0 +1,186 @@
+use crate::prelude::*;
+use base64::Engine;
+
+/// An Account of the Olympia wallet to migrate to Babylon, as read from the
+/// Olympia wallet export, see [`OlympiaWalletExport`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct OlympiaAccountToMigrate {
+    pub account_type: OlympiaAccountType,
+
+    /// The public key of the Account, derived at `derivation_path`.
+    pub public_key: Secp256k1PublicKey,
+
+    pub derivation_path: BIP44LikePath,
+
+    /// The name of the Account in the Olympia wallet, possibly empty.
+    pub display_name: String,
+}
+
+impl OlympiaAccountToMigrate {
+    pub fn new(
+        account_type: OlympiaAccountType,
+        public_key: Secp256k1PublicKey,
+        derivation_path: BIP44LikePath,
+        display_name: impl AsRef<str>,
+    ) -> Self {
+        Self {
+            account_type,
+            public_key,
+            derivation_path,
+            display_name: display_name.as_ref().to_owned(),
+        }
+    }
+}
+
+#[uniffi::export]
+pub fn olympia_account_to_migrate_babylon_address(
+    account: &OlympiaAccountToMigrate,
+    network_id: NetworkID,
+) -> AccountAddress {
+    account.babylon_address(network_id)
+}
+
+impl OlympiaAccountToMigrate {
+    /// The address of the Babylon Account on `network_id` controlled by the
+    /// same key as this Olympia Account.
+    pub fn babylon_address(&self, network_id: NetworkID) -> AccountAddress {
+        AccountAddress::new(self.public_key.into(), network_id)
+    }
+}
+
+impl OlympiaAccountToMigrate {
+    /// Separates the fields of an account record.
+    pub const FIELD_SEPARATOR: char = '^';
+
+    /// Ends the name, the last field of an account record.
+    pub const NAME_END: char = '}';
+}
+
+/// Parses an account record of the Olympia wallet export, formatted as
+/// `<type>^<public key>^<address index>^<name>}`, where the type is `S` or
+/// `H` and the public key is the compressed secp256k1 key in base64.
+impl FromStr for OlympiaAccountToMigrate {
+    type Err = CommonError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = || CommonError::InvalidOlympiaExportAccount(s.to_owned());
+        let record = s.strip_suffix(Self::NAME_END).unwrap_or(s);
+        let [account_type, public_key, index, display_name] = record
+            .splitn(4, Self::FIELD_SEPARATOR)
+            .collect_vec()
+            .try_into()
+            .map_err(|_| invalid())?;
+
+        let account_type = account_type.parse()?;
+        let public_key = base64::engine::general_purpose::STANDARD
+            .decode(public_key)
+            .map_err(|_| invalid())
+            .and_then(|b| {
+                Secp256k1PublicKey::try_from(b.as_slice())
+                    .map_err(|_| invalid())
+            })?;
+        let index = index
+            .parse::<HDPathValue>()
+            .ok()
+            .filter(|i| !HDPathComponent::from(*i).is_hardened())
+            .ok_or_else(invalid)?;
+
+        Ok(Self::new(
+            account_type,
+            public_key,
+            BIP44LikePath::new(index),
+            display_name,
+        ))
+    }
+}
+
+impl HasPlaceholder for OlympiaAccountToMigrate {
+    fn placeholder() -> Self {
+        "S^AvZppDAk2Q/eaTUczFMCLC+GcI2bPEJpNkBzPFd4I12l^0^Savings}"
+            .parse()
+            .unwrap()
+    }
+
+    fn placeholder_other() -> Self {
+        "H^AlF7iJFufzFbtoL5kmsUvGeg5CRvikGbmGJp4afmH/+n^1^Ledger}"
+            .parse()
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn parse_software() {
+        let sut = OlympiaAccountToMigrate::placeholder();
+        assert_eq!(sut.account_type, OlympiaAccountType::Software);
+        assert_eq!(
+            sut.public_key.to_hex(),
+            "02f669a43024d90fde69351ccc53022c2f86708d9b3c42693640733c5778235da5"
+        );
+        assert_eq!(sut.derivation_path, BIP44LikePath::new(0));
+        assert_eq!(sut.display_name, "Savings");
+    }
+
+    #[test]
+    fn parse_hardware() {
+        let sut = OlympiaAccountToMigrate::placeholder_other();
+        assert_eq!(sut.account_type, OlympiaAccountType::Hardware);
+        assert_eq!(sut.derivation_path, BIP44LikePath::new(1));
+        assert_eq!(sut.display_name, "Ledger");
+    }
+
+    #[test]
+    fn parse_empty_name() {
+        let sut: OlympiaAccountToMigrate =
+            "S^AvZppDAk2Q/eaTUczFMCLC+GcI2bPEJpNkBzPFd4I12l^7^}"
+                .parse()
+                .unwrap();
+        assert_eq!(sut.display_name, "");
+        assert_eq!(sut.derivation_path, BIP44LikePath::new(7));
+    }
+
+    #[test]
+    fn parse_name_with_separator() {
+        let sut: OlympiaAccountToMigrate =
+            "S^AvZppDAk2Q/eaTUczFMCLC+GcI2bPEJpNkBzPFd4I12l^0^a^b}"
+                .parse()
+                .unwrap();
+        assert_eq!(sut.display_name, "a^b");
+    }
+
+    #[test]
+    fn babylon_address() {
+        assert_eq!(
+            OlympiaAccountToMigrate::placeholder()
+                .babylon_address(NetworkID::Mainnet),
+            AccountAddress::new(
+                OlympiaAccountToMigrate::placeholder().public_key.into(),
+                NetworkID::Mainnet
+            )
+        );
+        assert_ne!(
+            OlympiaAccountToMigrate::placeholder()
+                .babylon_address(NetworkID::Mainnet),
+            OlympiaAccountToMigrate::placeholder()
+                .babylon_address(NetworkID::Stokenet)
+        );
+    }
+
+    #[test]
+    fn parse_invalid() {
+        for s in [
+            "",
+            "S^AvZppDAk2Q/eaTUczFMCLC+GcI2bPEJpNkBzPFd4I12l^0",
+            "X^AvZppDAk2Q/eaTUczFMCLC+GcI2bPEJpNkBzPFd4I12l^0^Name}",
+            "S^not base64^0^Name}",
+            "S^AAAA^0^Name}",
+            "S^AvZppDAk2Q/eaTUczFMCLC+GcI2bPEJpNkBzPFd4I12l^-1^Name}",
+            "S^AvZppDAk2Q/eaTUczFMCLC+GcI2bPEJpNkBzPFd4I12l^2147483648^Name}",
+        ] {
+            assert!(s.parse::<OlympiaAccountToMigrate>().is_err(), "{}", s);
+        }
+    }
+}
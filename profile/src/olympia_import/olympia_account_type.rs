@@ -0,0 +1,54 @@
+use crate::prelude::*;
+
+/// The kind of factor controlling an Account of the Olympia wallet.
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Hash, derive_more::Display, uniffi::Enum,
+)]
+pub enum OlympiaAccountType {
+    /// Controlled by a key derived from the mnemonic of the Olympia wallet,
+    /// `"S"` in the export.
+    #[display("S")]
+    Software,
+
+    /// Controlled by a Ledger hardware wallet, `"H"` in the export.
+    #[display("H")]
+    Hardware,
+}
+
+impl FromStr for OlympiaAccountType {
+    type Err = CommonError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "S" => Ok(Self::Software),
+            "H" => Ok(Self::Hardware),
+            _ => Err(CommonError::InvalidOlympiaExportAccount(s.to_owned())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn from_str() {
+        assert_eq!("S".parse(), Ok(OlympiaAccountType::Software));
+        assert_eq!("H".parse(), Ok(OlympiaAccountType::Hardware));
+    }
+
+    #[test]
+    fn from_str_invalid() {
+        assert_eq!(
+            "X".parse::<OlympiaAccountType>(),
+            Err(CommonError::InvalidOlympiaExportAccount("X".to_owned()))
+        );
+    }
+
+    #[test]
+    fn display_roundtrip() {
+        for t in [OlympiaAccountType::Software, OlympiaAccountType::Hardware] {
+            assert_eq!(t.to_string().parse(), Ok(t));
+        }
+    }
+}
@@ -0,0 +1,213 @@
+use crate::prelude::*;
+
+/// The Accounts exported by the Olympia wallet, in a multi-part QR payload,
+/// to be migrated to Babylon.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct OlympiaWalletExport {
+    /// The number of words of the mnemonic of the Olympia wallet, which the
+    /// user must enter to migrate the `Software` Accounts.
+    pub mnemonic_word_count: BIP39WordCount,
+
+    /// The Accounts, in the order of the export.
+    pub accounts: Vec<OlympiaAccountToMigrate>,
+}
+
+/// Parses the Olympia wallet export from all of its scanned `payloads`, in
+/// any order, see [`OlympiaWalletExport::from_payloads`].
+#[uniffi::export]
+pub fn new_olympia_wallet_export_from_payloads(
+    payloads: Vec<String>,
+) -> Result<OlympiaWalletExport> {
+    OlympiaWalletExport::from_payloads(payloads)
+}
+
+impl OlympiaWalletExport {
+    /// Separates the account records in the contents of the payloads.
+    pub const ACCOUNT_SEPARATOR: char = '~';
+
+    /// Parses the Olympia wallet export from all of its `payloads`, in any
+    /// order, each being a [`OlympiaWalletExportPayload`].
+    ///
+    /// Fails if the payloads are not all parts of the same export, if any
+    /// part is missing or if an account record is invalid.
+    pub fn from_payloads<S>(
+        payloads: impl IntoIterator<Item = S>,
+    ) -> Result<Self>
+    where
+        S: AsRef<str>,
+    {
+        let payloads = payloads
+            .into_iter()
+            .map(|p| p.as_ref().parse::<OlympiaWalletExportPayload>())
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .sorted_by_key(|p| p.payload_index)
+            .collect_vec();
+
+        let first = payloads.first().ok_or(
+            CommonError::OlympiaExportPayloadsMissing {
+                expected: 1,
+                found: 0,
+            },
+        )?;
+        let (payload_count, mnemonic_word_count) =
+            (first.payload_count, first.mnemonic_word_count);
+        if payloads.iter().any(|p| {
+            p.payload_count != payload_count
+                || p.mnemonic_word_count != mnemonic_word_count
+        }) {
+            return Err(CommonError::OlympiaExportPayloadsMismatch);
+        }
+        if payloads.iter().map(|p| p.payload_index).dedup().count()
+            != payloads.len()
+        {
+            return Err(CommonError::OlympiaExportPayloadsMismatch);
+        }
+        if payloads.len() as u32 != payload_count {
+            return Err(CommonError::OlympiaExportPayloadsMissing {
+                expected: payload_count,
+                found: payloads.len() as u32,
+            });
+        }
+
+        let accounts = payloads
+            .iter()
+            .map(|p| p.content.as_str())
+            .collect::<String>()
+            .split(Self::ACCOUNT_SEPARATOR)
+            .filter(|record| !record.is_empty())
+            .map(|record| record.parse::<OlympiaAccountToMigrate>())
+            .collect::<Result<Vec<_>>>()?;
+        if accounts.is_empty() {
+            return Err(CommonError::OlympiaExportContainsNoAccounts);
+        }
+
+        Ok(Self {
+            mnemonic_word_count,
+            accounts,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    const SAVINGS: &str =
+        "S^AvZppDAk2Q/eaTUczFMCLC+GcI2bPEJpNkBzPFd4I12l^0^Savings}";
+    const LEDGER: &str =
+        "H^AlF7iJFufzFbtoL5kmsUvGeg5CRvikGbmGJp4afmH/+n^1^Ledger}";
+
+    #[test]
+    fn single_payload() {
+        let sut = OlympiaWalletExport::from_payloads([format!(
+            "1^0^12]{}~{}",
+            SAVINGS, LEDGER
+        )])
+        .unwrap();
+        assert_eq!(sut.mnemonic_word_count, BIP39WordCount::Twelve);
+        assert_eq!(
+            sut.accounts,
+            vec![
+                OlympiaAccountToMigrate::placeholder(),
+                OlympiaAccountToMigrate::placeholder_other()
+            ]
+        );
+    }
+
+    #[test]
+    fn record_split_across_payloads_in_any_order() {
+        let (head, tail) = LEDGER.split_at(20);
+        let sut = OlympiaWalletExport::from_payloads([
+            format!("3^2^12]{}", tail),
+            format!("3^0^12]{}~", SAVINGS),
+            format!("3^1^12]{}", head),
+        ])
+        .unwrap();
+        assert_eq!(
+            sut.accounts,
+            vec![
+                OlympiaAccountToMigrate::placeholder(),
+                OlympiaAccountToMigrate::placeholder_other()
+            ]
+        );
+    }
+
+    #[test]
+    fn missing_payload() {
+        assert_eq!(
+            OlympiaWalletExport::from_payloads([format!("2^0^12]{}", SAVINGS)]),
+            Err(CommonError::OlympiaExportPayloadsMissing {
+                expected: 2,
+                found: 1
+            })
+        );
+    }
+
+    #[test]
+    fn no_payloads() {
+        assert_eq!(
+            OlympiaWalletExport::from_payloads(Vec::<String>::new()),
+            Err(CommonError::OlympiaExportPayloadsMissing {
+                expected: 1,
+                found: 0
+            })
+        );
+    }
+
+    #[test]
+    fn duplicate_payload() {
+        let payload = format!("2^0^12]{}", SAVINGS);
+        assert_eq!(
+            OlympiaWalletExport::from_payloads([payload.clone(), payload]),
+            Err(CommonError::OlympiaExportPayloadsMismatch)
+        );
+    }
+
+    #[test]
+    fn payloads_of_different_exports() {
+        assert_eq!(
+            OlympiaWalletExport::from_payloads([
+                format!("2^0^12]{}~", SAVINGS),
+                format!("2^1^24]{}", LEDGER),
+            ]),
+            Err(CommonError::OlympiaExportPayloadsMismatch)
+        );
+    }
+
+    #[test]
+    fn no_accounts() {
+        assert_eq!(
+            OlympiaWalletExport::from_payloads(["1^0^12]"]),
+            Err(CommonError::OlympiaExportContainsNoAccounts)
+        );
+    }
+
+    #[test]
+    fn invalid_account() {
+        assert_eq!(
+            OlympiaWalletExport::from_payloads(["1^0^12]S^x^0^Bad}"]),
+            Err(CommonError::InvalidOlympiaExportAccount(
+                "S^x^0^Bad}".to_owned()
+            ))
+        );
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn new_from_payloads() {
+        assert_eq!(
+            new_olympia_wallet_export_from_payloads(vec![
+                "1^0^12]S^AvZppDAk2Q/eaTUczFMCLC+GcI2bPEJpNkBzPFd4I12l^0^Savings}"
+                    .to_owned()
+            ])
+            .unwrap()
+            .accounts,
+            vec![OlympiaAccountToMigrate::placeholder()]
+        );
+    }
+}
@@ -0,0 +1,114 @@
+use crate::prelude::*;
+
+/// One of the payloads - each shown as a QR code - the Olympia wallet
+/// exports its Accounts in, formatted as
+/// `<payload count>^<payload index>^<mnemonic word count>]<content>`.
+///
+/// The contents of all payloads, concatenated in the order of their index,
+/// form the account records of the export.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OlympiaWalletExportPayload {
+    pub payload_count: u32,
+    pub payload_index: u32,
+    pub mnemonic_word_count: BIP39WordCount,
+    pub content: String,
+}
+
+impl OlympiaWalletExportPayload {
+    /// Separates the header from the content.
+    pub const HEADER_END: char = ']';
+
+    /// Separates the fields of the header.
+    pub const HEADER_FIELD_SEPARATOR: char = '^';
+}
+
+impl FromStr for OlympiaWalletExportPayload {
+    type Err = CommonError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (header, content) = s.split_once(Self::HEADER_END).ok_or(
+            CommonError::InvalidOlympiaExportPayloadHeader(s.to_owned()),
+        )?;
+        let invalid_header = || {
+            CommonError::InvalidOlympiaExportPayloadHeader(header.to_owned())
+        };
+
+        let [payload_count, payload_index, mnemonic_word_count] = header
+            .split(Self::HEADER_FIELD_SEPARATOR)
+            .map(|n| n.parse::<u32>().map_err(|_| invalid_header()))
+            .collect::<Result<Vec<_>>>()?
+            .try_into()
+            .map_err(|_| invalid_header())?;
+
+        if payload_index >= payload_count {
+            return Err(invalid_header());
+        }
+        let mnemonic_word_count =
+            BIP39WordCount::from_count(mnemonic_word_count as usize)
+                .map_err(|_| invalid_header())?;
+
+        Ok(Self {
+            payload_count,
+            payload_index,
+            mnemonic_word_count,
+            content: content.to_owned(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            "2^1^12]~H^abc".parse::<OlympiaWalletExportPayload>(),
+            Ok(OlympiaWalletExportPayload {
+                payload_count: 2,
+                payload_index: 1,
+                mnemonic_word_count: BIP39WordCount::Twelve,
+                content: "~H^abc".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn parse_content_containing_header_end() {
+        assert_eq!(
+            "1^0^24]S^a^0^[x]}"
+                .parse::<OlympiaWalletExportPayload>()
+                .unwrap()
+                .content,
+            "S^a^0^[x]}"
+        );
+    }
+
+    #[test]
+    fn parse_without_header() {
+        assert_eq!(
+            "S^a^0^Name}".parse::<OlympiaWalletExportPayload>(),
+            Err(CommonError::InvalidOlympiaExportPayloadHeader(
+                "S^a^0^Name}".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_invalid_header() {
+        for (payload, header) in [
+            ("1^0]", "1^0"),
+            ("1^0^12^3]", "1^0^12^3"),
+            ("a^0^12]", "a^0^12"),
+            ("1^1^12]", "1^1^12"),
+            ("1^0^13]", "1^0^13"),
+        ] {
+            assert_eq!(
+                payload.parse::<OlympiaWalletExportPayload>(),
+                Err(CommonError::InvalidOlympiaExportPayloadHeader(
+                    header.to_owned()
+                ))
+            );
+        }
+    }
+}
@@ -23,6 +23,12 @@ pub enum ProfileSnapshotVersion {
     /// The version we went live with on Babylon mainnet 2023-09-28,
     /// shipped with iOS 1.0.0 (7) and Android v 1.0.0.
     V100 = 100,
+
+    /// Adds watch-only Accounts, whose security state has the discriminator
+    /// `"unowned"`, unknown to readers of V100. Only used by Profiles
+    /// containing such an entity, see [`Profile::required_snapshot_version`],
+    /// so that all others remain readable by V100 readers.
+    V101 = 101,
 }
 
 impl Default for ProfileSnapshotVersion {
@@ -65,6 +71,19 @@ mod tests {
         assert_json_value_fails::<ProfileSnapshotVersion>(json!("V100"));
     }
 
+    #[test]
+    fn json_v101() {
+        assert_json_value_eq_after_roundtrip(
+            &ProfileSnapshotVersion::V101,
+            json!(101),
+        );
+    }
+
+    #[test]
+    fn ordering() {
+        assert!(ProfileSnapshotVersion::V100 < ProfileSnapshotVersion::V101);
+    }
+
     #[test]
     fn from_repr() {
         assert_eq!(
@@ -0,0 +1,136 @@
+use crate::prelude::*;
+use std::ops::{Add, Sub};
+
+/// An epoch of the Radix ledger, a transaction is only valid for a window
+/// of epochs specified in its [`TransactionHeader`].
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    derive_more::Display,
+)]
+#[serde(transparent)]
+pub struct Epoch(pub u64);
+uniffi::custom_newtype!(Epoch, u64);
+
+impl Epoch {
+    pub fn new(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<u64> for Epoch {
+    fn from(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Epoch> for u64 {
+    fn from(value: Epoch) -> Self {
+        value.0
+    }
+}
+
+impl Add<u64> for Epoch {
+    type Output = Self;
+
+    fn add(self, rhs: u64) -> Self::Output {
+        Self(self.0.saturating_add(rhs))
+    }
+}
+
+impl Sub<u64> for Epoch {
+    type Output = Self;
+
+    fn sub(self, rhs: u64) -> Self::Output {
+        Self(self.0.saturating_sub(rhs))
+    }
+}
+
+/// The number of epochs between `rhs` and `self`, zero if `rhs` is later.
+impl Sub<Epoch> for Epoch {
+    type Output = u64;
+
+    fn sub(self, rhs: Epoch) -> Self::Output {
+        self.0.saturating_sub(rhs.0)
+    }
+}
+
+impl HasPlaceholder for Epoch {
+    fn placeholder() -> Self {
+        Self(1337)
+    }
+
+    fn placeholder_other() -> Self {
+        Self(4242)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Epoch::placeholder(), Epoch::placeholder());
+        assert_eq!(Epoch::placeholder_other(), Epoch::placeholder_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Epoch::placeholder(), Epoch::placeholder_other());
+    }
+
+    #[test]
+    fn add() {
+        assert_eq!(Epoch::new(10) + 5, Epoch::new(15));
+    }
+
+    #[test]
+    fn add_saturates() {
+        assert_eq!(Epoch::new(u64::MAX) + 1, Epoch::new(u64::MAX));
+    }
+
+    #[test]
+    fn sub() {
+        assert_eq!(Epoch::new(10) - 5, Epoch::new(5));
+        assert_eq!(Epoch::new(3) - 5, Epoch::new(0));
+    }
+
+    #[test]
+    fn distance() {
+        assert_eq!(Epoch::new(15) - Epoch::new(10), 5);
+        assert_eq!(Epoch::new(10) - Epoch::new(15), 0);
+    }
+
+    #[test]
+    fn ord() {
+        assert!(Epoch::new(1) < Epoch::new(2));
+    }
+
+    #[test]
+    fn from_u64_roundtrip() {
+        assert_eq!(u64::from(Epoch::from(42)), 42);
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        assert_json_value_eq_after_roundtrip(
+            &Epoch::placeholder(),
+            json!(1337),
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(format!("{}", Epoch::placeholder()), "1337");
+    }
+}
@@ -0,0 +1,5 @@
+mod network_congestion;
+mod tip_suggestion;
+
+pub use network_congestion::*;
+pub use tip_suggestion::*;
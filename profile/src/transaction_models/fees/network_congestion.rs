@@ -0,0 +1,112 @@
+use crate::prelude::*;
+
+/// A coarse estimate of how busy the network is, derived from the rate at
+/// which transactions are committed to the ledger, used to suggest a tip.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    derive_more::Display,
+    uniffi::Enum,
+)]
+pub enum NetworkCongestion {
+    /// Fewer than [`NetworkCongestion::NORMAL_TRANSACTIONS_PER_SECOND`]
+    /// transactions per second are committed.
+    Low,
+
+    /// The rate of committed transactions is unremarkable, also used when
+    /// the rate cannot be measured.
+    #[default]
+    Normal,
+
+    /// At least [`NetworkCongestion::HIGH_TRANSACTIONS_PER_SECOND`]
+    /// transactions per second are committed.
+    High,
+}
+
+impl NetworkCongestion {
+    /// The committed transactions per second from which the network is no
+    /// longer considered idle.
+    pub const NORMAL_TRANSACTIONS_PER_SECOND: u64 = 10;
+
+    /// The committed transactions per second from which the network is
+    /// considered congested.
+    pub const HIGH_TRANSACTIONS_PER_SECOND: u64 = 50;
+
+    /// Estimates the congestion from `committed_transactions` having been
+    /// committed during `elapsed_seconds`, [`NetworkCongestion::Normal`] if
+    /// no time has elapsed.
+    pub fn estimate(committed_transactions: u64, elapsed_seconds: u64) -> Self {
+        if elapsed_seconds == 0 {
+            return Self::Normal;
+        }
+        let per_second = committed_transactions / elapsed_seconds;
+        if per_second >= Self::HIGH_TRANSACTIONS_PER_SECOND {
+            Self::High
+        } else if per_second >= Self::NORMAL_TRANSACTIONS_PER_SECOND {
+            Self::Normal
+        } else {
+            Self::Low
+        }
+    }
+}
+
+#[uniffi::export]
+pub fn new_network_congestion_estimate(
+    committed_transactions: u64,
+    elapsed_seconds: u64,
+) -> NetworkCongestion {
+    NetworkCongestion::estimate(committed_transactions, elapsed_seconds)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = NetworkCongestion;
+
+    #[test]
+    fn default_is_normal() {
+        assert_eq!(SUT::default(), SUT::Normal);
+    }
+
+    #[test]
+    fn ordering() {
+        assert!(SUT::Low < SUT::Normal);
+        assert!(SUT::Normal < SUT::High);
+    }
+
+    #[test]
+    fn estimate_without_elapsed_time_is_normal() {
+        assert_eq!(SUT::estimate(1_000, 0), SUT::Normal);
+    }
+
+    #[test]
+    fn estimate_thresholds() {
+        assert_eq!(SUT::estimate(0, 60), SUT::Low);
+        assert_eq!(SUT::estimate(599, 60), SUT::Low);
+        assert_eq!(SUT::estimate(600, 60), SUT::Normal);
+        assert_eq!(SUT::estimate(2_999, 60), SUT::Normal);
+        assert_eq!(SUT::estimate(3_000, 60), SUT::High);
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn estimate() {
+        assert_eq!(
+            new_network_congestion_estimate(100, 1),
+            NetworkCongestion::High
+        );
+    }
+}
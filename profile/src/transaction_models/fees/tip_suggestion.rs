@@ -0,0 +1,117 @@
+use crate::prelude::*;
+
+/// The tip, as a percentage of the fee, pre-filled on the advanced fee
+/// screen, together with the [`NetworkCongestion`] it was suggested for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct TipSuggestion {
+    pub congestion: NetworkCongestion,
+    pub tip_percentage: u16,
+}
+
+impl TipSuggestion {
+    /// The tip suggested when the network is idle or busy as usual, i.e.
+    /// no tip, since validators process transactions in the order received.
+    pub const DEFAULT_TIP_PERCENTAGE: u16 = 0;
+
+    /// The tip suggested when the network is congested, so that the
+    /// transaction is prioritized by validators.
+    pub const CONGESTED_TIP_PERCENTAGE: u16 = 5;
+
+    pub fn new(congestion: NetworkCongestion) -> Self {
+        let tip_percentage = match congestion {
+            NetworkCongestion::Low | NetworkCongestion::Normal => {
+                Self::DEFAULT_TIP_PERCENTAGE
+            }
+            NetworkCongestion::High => Self::CONGESTED_TIP_PERCENTAGE,
+        };
+        Self {
+            congestion,
+            tip_percentage,
+        }
+    }
+}
+
+impl Default for TipSuggestion {
+    fn default() -> Self {
+        Self::new(NetworkCongestion::default())
+    }
+}
+
+impl TransactionHeader {
+    /// Returns a copy of this header with the tip of `suggestion`.
+    pub fn with_tip_suggestion(self, suggestion: TipSuggestion) -> Self {
+        Self {
+            tip_percentage: suggestion.tip_percentage,
+            ..self
+        }
+    }
+}
+
+#[uniffi::export]
+pub fn new_tip_suggestion(congestion: NetworkCongestion) -> TipSuggestion {
+    TipSuggestion::new(congestion)
+}
+
+#[uniffi::export]
+pub fn transaction_header_with_tip_suggestion(
+    header: &TransactionHeader,
+    suggestion: TipSuggestion,
+) -> TransactionHeader {
+    header.clone().with_tip_suggestion(suggestion)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = TipSuggestion;
+
+    #[test]
+    fn no_tip_unless_congested() {
+        assert_eq!(SUT::new(NetworkCongestion::Low).tip_percentage, 0);
+        assert_eq!(SUT::new(NetworkCongestion::Normal).tip_percentage, 0);
+        assert_eq!(SUT::new(NetworkCongestion::High).tip_percentage, 5);
+    }
+
+    #[test]
+    fn default_is_for_normal_congestion() {
+        assert_eq!(SUT::default(), SUT::new(NetworkCongestion::Normal));
+    }
+
+    #[test]
+    fn header_with_tip_suggestion() {
+        let header = TransactionHeader::placeholder()
+            .with_tip_suggestion(SUT::new(NetworkCongestion::High));
+        assert_eq!(header.tip_percentage, 5);
+        assert_eq!(
+            header.start_epoch_inclusive,
+            TransactionHeader::placeholder().start_epoch_inclusive
+        );
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn new() {
+        assert_eq!(
+            new_tip_suggestion(NetworkCongestion::High),
+            TipSuggestion::new(NetworkCongestion::High)
+        );
+    }
+
+    #[test]
+    fn header_with_tip_suggestion() {
+        assert_eq!(
+            transaction_header_with_tip_suggestion(
+                &TransactionHeader::placeholder_other(),
+                TipSuggestion::new(NetworkCongestion::Low)
+            )
+            .tip_percentage,
+            0
+        );
+    }
+}
@@ -0,0 +1,403 @@
+use crate::prelude::*;
+
+use radix_engine_common::crypto::Hash;
+use transaction::model::{
+    HasIntentHash, HasSignedIntentHash, IntentHash as EngineIntentHash,
+    IsTransactionHash, PreparedIntentV1, PreparedSignedIntentV1,
+    SignedIntentHash as EngineSignedIntentHash, TransactionHashBech32Decoder,
+    TransactionHashBech32Encoder, TransactionPayloadPreparable,
+};
+
+/// The hash of a transaction intent, used as the ID of the transaction,
+/// displayed and looked up in the dashboard and Gateway as the bech32m
+/// encoded `"txid_rdx1..."`.
+#[derive(
+    SerializeDisplay,
+    DeserializeFromStr,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    derive_more::Display,
+    uniffi::Record,
+)]
+#[display("{}", self.to_bech32())]
+pub struct TransactionIntentHash {
+    /// The network of the transaction, determines the bech32m HRP.
+    pub network_id: NetworkID,
+
+    /// The Blake2b hash of the intent.
+    pub hash: Hex32Bytes,
+}
+
+/// The hash of a signed transaction intent, i.e. the intent and its
+/// signatures, encoded as the bech32m `"signedintent_rdx1..."`.
+#[derive(
+    SerializeDisplay,
+    DeserializeFromStr,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    derive_more::Display,
+    uniffi::Record,
+)]
+#[display("{}", self.to_bech32())]
+pub struct SignedIntentHash {
+    /// The network of the transaction, determines the bech32m HRP.
+    pub network_id: NetworkID,
+
+    /// The Blake2b hash of the signed intent.
+    pub hash: Hex32Bytes,
+}
+
+fn bech32_encode<T: IsTransactionHash>(
+    network_id: &NetworkID,
+    hash: T,
+) -> String {
+    TransactionHashBech32Encoder::new(&network_id.network_definition())
+        .encode(&hash)
+        .expect("Should always be able to bech32 encode a transaction hash.")
+}
+
+/// Decodes `s` trying the HRP of every supported network, returning the
+/// network on which it succeeded along with the hash.
+fn bech32_decode<T: IsTransactionHash>(s: &str) -> Result<(NetworkID, T)> {
+    enum_iterator::all::<NetworkID>()
+        .find_map(|network_id| {
+            TransactionHashBech32Decoder::new(&network_id.network_definition())
+                .validate_and_decode::<T>(s)
+                .ok()
+                .map(|hash| (network_id, hash))
+        })
+        .ok_or(CommonError::InvalidTransactionHashBech32(s.to_owned()))
+}
+
+impl TransactionIntentHash {
+    pub fn new(network_id: NetworkID, hash: Hex32Bytes) -> Self {
+        Self { network_id, hash }
+    }
+
+    /// Computes the intent hash of `compiled_intent`, the SBOR encoded
+    /// intent as produced by the Radix Engine Toolkit.
+    pub fn from_compiled_intent(
+        network_id: NetworkID,
+        compiled_intent: &[u8],
+    ) -> Result<Self> {
+        let prepared = PreparedIntentV1::prepare_from_payload(compiled_intent)
+            .map_err(|_| {
+                CommonError::InvalidCompiledTransactionPayload(
+                    "Intent".to_owned(),
+                )
+            })?;
+        Ok(Self::new(network_id, prepared.intent_hash().0.into()))
+    }
+
+    /// Parses a bech32m encoded intent hash, e.g. `"txid_rdx1..."`, the
+    /// network is derived from the HRP.
+    pub fn from_bech32(s: &str) -> Result<Self> {
+        bech32_decode::<EngineIntentHash>(s)
+            .map(|(network_id, hash)| Self::new(network_id, hash.0.into()))
+    }
+
+    /// The bech32m encoding of this hash, e.g. `"txid_rdx1..."`.
+    pub fn to_bech32(&self) -> String {
+        bech32_encode(
+            &self.network_id,
+            EngineIntentHash(Hash(self.hash.bytes())),
+        )
+    }
+}
+
+impl FromStr for TransactionIntentHash {
+    type Err = CommonError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::from_bech32(s)
+    }
+}
+
+impl SignedIntentHash {
+    pub fn new(network_id: NetworkID, hash: Hex32Bytes) -> Self {
+        Self { network_id, hash }
+    }
+
+    /// Computes the signed intent hash of `compiled_signed_intent`, the SBOR
+    /// encoded signed intent as produced by the Radix Engine Toolkit.
+    pub fn from_compiled_signed_intent(
+        network_id: NetworkID,
+        compiled_signed_intent: &[u8],
+    ) -> Result<Self> {
+        let prepared = PreparedSignedIntentV1::prepare_from_payload(
+            compiled_signed_intent,
+        )
+        .map_err(|_| {
+            CommonError::InvalidCompiledTransactionPayload(
+                "SignedIntent".to_owned(),
+            )
+        })?;
+        Ok(Self::new(
+            network_id,
+            prepared.signed_intent_hash().0.into(),
+        ))
+    }
+
+    /// Parses a bech32m encoded signed intent hash, e.g.
+    /// `"signedintent_rdx1..."`, the network is derived from the HRP.
+    pub fn from_bech32(s: &str) -> Result<Self> {
+        bech32_decode::<EngineSignedIntentHash>(s)
+            .map(|(network_id, hash)| Self::new(network_id, hash.0.into()))
+    }
+
+    /// The bech32m encoding of this hash, e.g. `"signedintent_rdx1..."`.
+    pub fn to_bech32(&self) -> String {
+        bech32_encode(
+            &self.network_id,
+            EngineSignedIntentHash(Hash(self.hash.bytes())),
+        )
+    }
+}
+
+impl FromStr for SignedIntentHash {
+    type Err = CommonError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::from_bech32(s)
+    }
+}
+
+impl HasPlaceholder for TransactionIntentHash {
+    fn placeholder() -> Self {
+        Self::new(NetworkID::Mainnet, Hex32Bytes::placeholder())
+    }
+
+    fn placeholder_other() -> Self {
+        Self::new(NetworkID::Stokenet, Hex32Bytes::placeholder_other())
+    }
+}
+
+impl HasPlaceholder for SignedIntentHash {
+    fn placeholder() -> Self {
+        Self::new(NetworkID::Mainnet, Hex32Bytes::placeholder())
+    }
+
+    fn placeholder_other() -> Self {
+        Self::new(NetworkID::Stokenet, Hex32Bytes::placeholder_other())
+    }
+}
+
+#[uniffi::export]
+pub fn new_transaction_intent_hash_from_compiled_intent(
+    network_id: NetworkID,
+    compiled_intent: BagOfBytes,
+) -> Result<TransactionIntentHash> {
+    TransactionIntentHash::from_compiled_intent(network_id, &compiled_intent)
+}
+
+#[uniffi::export]
+pub fn new_transaction_intent_hash_from_string(
+    string: String,
+) -> Result<TransactionIntentHash> {
+    TransactionIntentHash::from_bech32(&string)
+}
+
+/// The bech32m encoding of the intent hash, e.g. `"txid_rdx1..."`.
+#[uniffi::export]
+pub fn transaction_intent_hash_to_string(
+    intent_hash: &TransactionIntentHash,
+) -> String {
+    intent_hash.to_bech32()
+}
+
+#[uniffi::export]
+pub fn new_signed_intent_hash_from_compiled_signed_intent(
+    network_id: NetworkID,
+    compiled_signed_intent: BagOfBytes,
+) -> Result<SignedIntentHash> {
+    SignedIntentHash::from_compiled_signed_intent(
+        network_id,
+        &compiled_signed_intent,
+    )
+}
+
+#[uniffi::export]
+pub fn new_signed_intent_hash_from_string(
+    string: String,
+) -> Result<SignedIntentHash> {
+    SignedIntentHash::from_bech32(&string)
+}
+
+/// The bech32m encoding of the signed intent hash, e.g.
+/// `"signedintent_rdx1..."`.
+#[uniffi::export]
+pub fn signed_intent_hash_to_string(
+    signed_intent_hash: &SignedIntentHash,
+) -> String {
+    signed_intent_hash.to_bech32()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality() {
+        assert_eq!(
+            TransactionIntentHash::placeholder(),
+            TransactionIntentHash::placeholder()
+        );
+        assert_eq!(
+            SignedIntentHash::placeholder_other(),
+            SignedIntentHash::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            TransactionIntentHash::placeholder(),
+            TransactionIntentHash::placeholder_other()
+        );
+        assert_ne!(
+            SignedIntentHash::placeholder(),
+            SignedIntentHash::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn intent_hash_hrp() {
+        assert!(TransactionIntentHash::placeholder()
+            .to_bech32()
+            .starts_with("txid_rdx1"));
+        assert!(TransactionIntentHash::placeholder_other()
+            .to_bech32()
+            .starts_with("txid_tdx_2_1"));
+    }
+
+    #[test]
+    fn signed_intent_hash_hrp() {
+        assert!(SignedIntentHash::placeholder()
+            .to_bech32()
+            .starts_with("signedintent_rdx1"));
+    }
+
+    #[test]
+    fn intent_hash_bech32_roundtrip() {
+        for sut in [
+            TransactionIntentHash::placeholder(),
+            TransactionIntentHash::placeholder_other(),
+        ] {
+            assert_eq!(
+                TransactionIntentHash::from_bech32(&sut.to_bech32()),
+                Ok(sut)
+            );
+        }
+    }
+
+    #[test]
+    fn signed_intent_hash_bech32_roundtrip() {
+        for sut in [
+            SignedIntentHash::placeholder(),
+            SignedIntentHash::placeholder_other(),
+        ] {
+            assert_eq!(SignedIntentHash::from_str(&sut.to_string()), Ok(sut));
+        }
+    }
+
+    #[test]
+    fn intent_hash_is_not_signed_intent_hash() {
+        let s = TransactionIntentHash::placeholder().to_bech32();
+        assert_eq!(
+            SignedIntentHash::from_bech32(&s),
+            Err(CommonError::InvalidTransactionHashBech32(s))
+        );
+    }
+
+    #[test]
+    fn from_invalid_string() {
+        assert_eq!(
+            TransactionIntentHash::from_str("txid_rdx1invalid"),
+            Err(CommonError::InvalidTransactionHashBech32(
+                "txid_rdx1invalid".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    fn from_invalid_compiled_intent() {
+        assert_eq!(
+            TransactionIntentHash::from_compiled_intent(
+                NetworkID::Mainnet,
+                &[0xde, 0xad]
+            ),
+            Err(CommonError::InvalidCompiledTransactionPayload(
+                "Intent".to_owned()
+            ))
+        );
+        assert_eq!(
+            SignedIntentHash::from_compiled_signed_intent(
+                NetworkID::Mainnet,
+                &[0xde, 0xad]
+            ),
+            Err(CommonError::InvalidCompiledTransactionPayload(
+                "SignedIntent".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    fn json_is_bech32_string() {
+        let sut = TransactionIntentHash::placeholder();
+        assert_eq!(serde_json::to_value(&sut).unwrap(), json!(sut.to_bech32()));
+        assert_eq!(
+            serde_json::from_value::<TransactionIntentHash>(json!(
+                sut.to_bech32()
+            ))
+            .unwrap(),
+            sut
+        );
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn intent_hash_string_roundtrip() {
+        let sut = TransactionIntentHash::placeholder_other();
+        assert_eq!(
+            new_transaction_intent_hash_from_string(
+                transaction_intent_hash_to_string(&sut)
+            ),
+            Ok(sut)
+        );
+    }
+
+    #[test]
+    fn signed_intent_hash_string_roundtrip() {
+        let sut = SignedIntentHash::placeholder_other();
+        assert_eq!(
+            new_signed_intent_hash_from_string(signed_intent_hash_to_string(
+                &sut
+            )),
+            Ok(sut)
+        );
+    }
+
+    #[test]
+    fn from_invalid_compiled_payloads() {
+        assert!(new_transaction_intent_hash_from_compiled_intent(
+            NetworkID::Mainnet,
+            BagOfBytes::placeholder()
+        )
+        .is_err());
+        assert!(new_signed_intent_hash_from_compiled_signed_intent(
+            NetworkID::Mainnet,
+            BagOfBytes::placeholder()
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,615 @@
+use crate::prelude::*;
+
+use radix_engine_toolkit::functions::manifest::summary as engine_summary;
+use radix_engine_toolkit::transaction_types::ManifestClass as EngineManifestClass;
+use transaction::prelude::{
+    ComponentAddress as EngineComponentAddress, DynamicGlobalAddress,
+    InstructionV1,
+};
+
+const ROLE_ASSIGNMENT_GET_METHOD: &str = "get";
+const METADATA_GET_METHOD: &str = "get";
+
+/// The kind of a transaction manifest, as classified by the Radix Engine
+/// Toolkit, only distinguishing what the wallet treats differently.
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Hash, derive_more::Display, uniffi::Enum,
+)]
+pub enum ManifestClass {
+    /// Only withdraws resources from Accounts and deposits them into
+    /// Accounts.
+    Transfer,
+
+    /// Contributes resources withdrawn from Accounts to pools, depositing
+    /// the pool units received into Accounts.
+    PoolContribution,
+
+    /// Redeems pool units withdrawn from Accounts at pools, depositing the
+    /// resources received into Accounts.
+    PoolRedemption,
+
+    /// Any other manifest, e.g. one calling a dApp component.
+    General,
+}
+
+/// The entities a transaction manifest interacts with, as summarized by
+/// the static analysis of the Radix Engine Toolkit, used to resolve a
+/// [`SigningPolicy`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct ManifestSummary {
+    pub classification: ManifestClass,
+
+    /// The Accounts resources are withdrawn from, which requires their auth.
+    pub accounts_withdrawn_from: Vec<AccountAddress>,
+
+    pub accounts_deposited_into: Vec<AccountAddress>,
+
+    /// The Accounts whose auth is required for anything but withdrawals,
+    /// e.g. changing their third party deposit settings.
+    pub accounts_requiring_auth: Vec<AccountAddress>,
+
+    pub identities_requiring_auth: Vec<IdentityAddress>,
+
+    /// The resources of which proofs are created, e.g. to present a badge
+    /// to a dApp component.
+    pub proofs_created: Vec<ResourceAddress>,
+
+    /// The Accounts and Personas whose access rules - including their owner
+    /// role - the manifest changes, i.e. which may no longer be controlled
+    /// by the same factors afterwards.
+    pub entities_with_access_rule_changes: Vec<AddressOfAccountOrPersona>,
+
+    /// The Accounts and Personas whose metadata the manifest sets, updates
+    /// or removes.
+    pub entities_with_metadata_updates: Vec<AddressOfAccountOrPersona>,
+
+    /// The pools resources are contributed to, in exchange for pool units.
+    pub pools_contributed_to: Vec<PoolAddress>,
+
+    /// The pools pool units are redeemed at.
+    pub pools_redeemed_from: Vec<PoolAddress>,
+}
+
+impl ManifestSummary {
+    pub fn new(
+        classification: ManifestClass,
+        accounts_withdrawn_from: impl IntoIterator<Item = AccountAddress>,
+        accounts_deposited_into: impl IntoIterator<Item = AccountAddress>,
+        accounts_requiring_auth: impl IntoIterator<Item = AccountAddress>,
+        identities_requiring_auth: impl IntoIterator<Item = IdentityAddress>,
+    ) -> Self {
+        Self {
+            classification,
+            accounts_withdrawn_from: accounts_withdrawn_from
+                .into_iter()
+                .collect(),
+            accounts_deposited_into: accounts_deposited_into
+                .into_iter()
+                .collect(),
+            accounts_requiring_auth: accounts_requiring_auth
+                .into_iter()
+                .collect(),
+            identities_requiring_auth: identities_requiring_auth
+                .into_iter()
+                .collect(),
+            proofs_created: Vec::new(),
+            entities_with_access_rule_changes: Vec::new(),
+            entities_with_metadata_updates: Vec::new(),
+            pools_contributed_to: Vec::new(),
+            pools_redeemed_from: Vec::new(),
+        }
+    }
+
+    /// Statically analyzes `manifest` using the Radix Engine Toolkit.
+    ///
+    /// Only virtual Accounts and Identities - the kinds the Profile holds -
+    /// are included, any other component is ignored.
+    pub fn from_manifest(manifest: &TransactionManifest) -> Result<Self> {
+        let engine_manifest = manifest.engine_manifest()?;
+        let engine = engine_summary(&engine_manifest);
+        let instructions = engine_manifest.instructions;
+        let network_id = manifest.network_id;
+        let classification = if engine
+            .classification
+            .contains(&EngineManifestClass::PoolContribution)
+        {
+            ManifestClass::PoolContribution
+        } else if engine
+            .classification
+            .contains(&EngineManifestClass::PoolRedemption)
+        {
+            ManifestClass::PoolRedemption
+        } else if engine
+            .classification
+            .contains(&EngineManifestClass::Transfer)
+        {
+            ManifestClass::Transfer
+        } else {
+            ManifestClass::General
+        };
+        Ok(Self::new(
+            classification,
+            addresses_of(&engine.accounts_withdrawn_from, network_id),
+            addresses_of(&engine.accounts_deposited_into, network_id),
+            addresses_of(&engine.accounts_requiring_auth, network_id),
+            addresses_of(&engine.identities_requiring_auth, network_id),
+        )
+        .with_proofs_created(engine.presented_proofs.iter().filter_map(|r| {
+            bech32_address_of(r.as_node_id(), network_id)
+                .parse::<ResourceAddress>()
+                .ok()
+        }))
+        .with_access_rule_changes(entities_called_by(
+            &instructions,
+            network_id,
+            changes_access_rules,
+        ))
+        .with_metadata_updates(entities_called_by(
+            &instructions,
+            network_id,
+            updates_metadata,
+        ))
+        .with_pools_contributed_to(pools_called_with(
+            &instructions,
+            network_id,
+            POOL_CONTRIBUTE_METHOD,
+        ))
+        .with_pools_redeemed_from(pools_called_with(
+            &instructions,
+            network_id,
+            POOL_REDEEM_METHOD,
+        )))
+    }
+
+    /// Returns a copy of this summary with `proofs_created`.
+    pub fn with_proofs_created(
+        self,
+        proofs_created: impl IntoIterator<Item = ResourceAddress>,
+    ) -> Self {
+        Self {
+            proofs_created: proofs_created.into_iter().collect(),
+            ..self
+        }
+    }
+
+    /// Returns a copy of this summary with
+    /// `entities_with_access_rule_changes`.
+    pub fn with_access_rule_changes(
+        self,
+        entities: impl IntoIterator<Item = AddressOfAccountOrPersona>,
+    ) -> Self {
+        Self {
+            entities_with_access_rule_changes: entities.into_iter().collect(),
+            ..self
+        }
+    }
+
+    /// Returns a copy of this summary with `entities_with_metadata_updates`.
+    pub fn with_metadata_updates(
+        self,
+        entities: impl IntoIterator<Item = AddressOfAccountOrPersona>,
+    ) -> Self {
+        Self {
+            entities_with_metadata_updates: entities.into_iter().collect(),
+            ..self
+        }
+    }
+
+    /// Returns a copy of this summary with `pools_contributed_to`.
+    pub fn with_pools_contributed_to(
+        self,
+        pools: impl IntoIterator<Item = PoolAddress>,
+    ) -> Self {
+        Self {
+            pools_contributed_to: pools.into_iter().collect(),
+            ..self
+        }
+    }
+
+    /// Returns a copy of this summary with `pools_redeemed_from`.
+    pub fn with_pools_redeemed_from(
+        self,
+        pools: impl IntoIterator<Item = PoolAddress>,
+    ) -> Self {
+        Self {
+            pools_redeemed_from: pools.into_iter().collect(),
+            ..self
+        }
+    }
+
+    /// `true` if the manifest changes the access rules of the entity with
+    /// `address`, i.e. who controls it.
+    pub fn changes_control_of(
+        &self,
+        address: &AddressOfAccountOrPersona,
+    ) -> bool {
+        self.entities_with_access_rule_changes.contains(address)
+    }
+
+    /// The addresses of all Accounts and Personas whose auth the manifest
+    /// requires, without duplicates, in order of appearance - Accounts
+    /// withdrawn from first.
+    pub fn entities_requiring_auth(&self) -> Vec<AddressOfAccountOrPersona> {
+        self.accounts_withdrawn_from
+            .iter()
+            .chain(self.accounts_requiring_auth.iter())
+            .cloned()
+            .map(AddressOfAccountOrPersona::from)
+            .chain(
+                self.identities_requiring_auth
+                    .iter()
+                    .cloned()
+                    .map(AddressOfAccountOrPersona::from),
+            )
+            .unique()
+            .collect()
+    }
+}
+
+/// The `components` which are addresses of type `A`, in order.
+fn addresses_of<'a, A: EntityAddress>(
+    components: impl IntoIterator<Item = &'a EngineComponentAddress>,
+    network_id: NetworkID,
+) -> Vec<A> {
+    components
+        .into_iter()
+        .filter_map(|c| {
+            A::try_from_bech32(&bech32_address_of(c.as_node_id(), network_id))
+                .ok()
+        })
+        .collect()
+}
+
+/// `true` if `instruction` changes the roles of the entity it calls, which
+/// securifying an Account or Identity does too.
+fn changes_access_rules(instruction: &InstructionV1) -> bool {
+    match instruction {
+        InstructionV1::CallRoleAssignmentMethod { method_name, .. } => {
+            method_name != ROLE_ASSIGNMENT_GET_METHOD
+        }
+        InstructionV1::CallMethod { method_name, .. } => {
+            method_name == SECURIFY_METHOD
+        }
+        _ => false,
+    }
+}
+
+/// `true` if `instruction` sets, removes or locks metadata of the entity it
+/// calls.
+fn updates_metadata(instruction: &InstructionV1) -> bool {
+    match instruction {
+        InstructionV1::CallMetadataMethod { method_name, .. } => {
+            method_name != METADATA_GET_METHOD
+        }
+        _ => false,
+    }
+}
+
+/// The Accounts and Identities called by any of the `instructions` matching
+/// `predicate`, without duplicates, in order of appearance.
+fn entities_called_by(
+    instructions: &[InstructionV1],
+    network_id: NetworkID,
+    predicate: fn(&InstructionV1) -> bool,
+) -> Vec<AddressOfAccountOrPersona> {
+    instructions
+        .iter()
+        .filter(|i| predicate(i))
+        .filter_map(|i| match i {
+            InstructionV1::CallMethod {
+                address: DynamicGlobalAddress::Static(address),
+                ..
+            }
+            | InstructionV1::CallRoleAssignmentMethod {
+                address: DynamicGlobalAddress::Static(address),
+                ..
+            }
+            | InstructionV1::CallMetadataMethod {
+                address: DynamicGlobalAddress::Static(address),
+                ..
+            } => Some(address.as_node_id()),
+            _ => None,
+        })
+        .filter_map(|node_id| {
+            let entity_type = node_id.entity_type()?;
+            let bech32 = bech32_address_of(node_id, network_id);
+            if is_account_entity_type(entity_type) {
+                AccountAddress::try_from_bech32(&bech32)
+                    .ok()
+                    .map(Into::into)
+            } else if is_identity_entity_type(entity_type) {
+                IdentityAddress::try_from_bech32(&bech32)
+                    .ok()
+                    .map(Into::into)
+            } else {
+                None
+            }
+        })
+        .unique()
+        .collect()
+}
+
+/// The pools whose `method_name` any of the `instructions` calls, without
+/// duplicates, in order of appearance.
+fn pools_called_with(
+    instructions: &[InstructionV1],
+    network_id: NetworkID,
+    method_name: &str,
+) -> Vec<PoolAddress> {
+    instructions
+        .iter()
+        .filter_map(|i| match i {
+            InstructionV1::CallMethod {
+                address: DynamicGlobalAddress::Static(address),
+                method_name: called,
+                ..
+            } if called == method_name => PoolAddress::try_from_bech32(
+                &bech32_address_of(address.as_node_id(), network_id),
+            )
+            .ok(),
+            _ => None,
+        })
+        .unique()
+        .collect()
+}
+
+/// Statically analyzes `manifest`, see [`ManifestSummary::from_manifest`].
+#[uniffi::export]
+pub fn transaction_manifest_summary(
+    manifest: &TransactionManifest,
+) -> Result<ManifestSummary> {
+    ManifestSummary::from_manifest(manifest)
+}
+
+impl HasPlaceholder for ManifestSummary {
+    /// A placeholder used to facilitate unit tests, a transfer from one
+    /// Account to another.
+    fn placeholder() -> Self {
+        Self::new(
+            ManifestClass::Transfer,
+            [AccountAddress::placeholder_mainnet()],
+            [AccountAddress::placeholder_mainnet_other()],
+            [],
+            [],
+        )
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::new(
+            ManifestClass::General,
+            [AccountAddress::placeholder_mainnet()],
+            [],
+            [AccountAddress::placeholder_mainnet_other()],
+            [IdentityAddress::placeholder_mainnet()],
+        )
+        .with_proofs_created([ResourceAddress::xrd_on_network(
+            NetworkID::Mainnet,
+        )])
+        .with_access_rule_changes([
+            AccountAddress::placeholder_mainnet_other().into()
+        ])
+        .with_metadata_updates([IdentityAddress::placeholder_mainnet().into()])
+    }
+}
+
+#[uniffi::export]
+pub fn new_manifest_summary_placeholder() -> ManifestSummary {
+    ManifestSummary::placeholder()
+}
+
+#[uniffi::export]
+pub fn new_manifest_summary_placeholder_other() -> ManifestSummary {
+    ManifestSummary::placeholder_other()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = ManifestSummary;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::placeholder(), SUT::placeholder());
+        assert_eq!(SUT::placeholder_other(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::placeholder(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn entities_requiring_auth() {
+        assert_eq!(
+            SUT::placeholder_other().entities_requiring_auth(),
+            vec![
+                AccountAddress::placeholder_mainnet().into(),
+                AccountAddress::placeholder_mainnet_other().into(),
+                IdentityAddress::placeholder_mainnet().into(),
+            ]
+        );
+    }
+
+    #[test]
+    fn entities_requiring_auth_excludes_deposits() {
+        assert_eq!(
+            SUT::placeholder().entities_requiring_auth(),
+            vec![AccountAddress::placeholder_mainnet().into()]
+        );
+    }
+
+    #[test]
+    fn from_transfer_manifest() {
+        assert_eq!(
+            SUT::from_manifest(&TransactionManifest::placeholder()),
+            Ok(SUT::placeholder())
+        );
+    }
+
+    #[test]
+    fn new_has_no_proofs_or_changes() {
+        let sut = SUT::placeholder();
+        assert!(sut.proofs_created.is_empty());
+        assert!(sut.entities_with_access_rule_changes.is_empty());
+        assert!(sut.entities_with_metadata_updates.is_empty());
+    }
+
+    #[test]
+    fn with_proofs_created() {
+        assert_eq!(
+            SUT::placeholder_other().proofs_created,
+            vec![ResourceAddress::xrd_on_network(NetworkID::Mainnet)]
+        );
+    }
+
+    #[test]
+    fn from_general_manifest() {
+        let account = AccountAddress::placeholder_mainnet();
+        let identity = IdentityAddress::placeholder_mainnet();
+        let manifest = TransactionManifest::new(
+            format!(
+                r#"
+CALL_METHOD
+    Address("{account}")
+    "create_proof_of_amount"
+    Address("{}")
+    Decimal("1")
+;
+SET_METADATA
+    Address("{identity}")
+    "name"
+    Enum<Metadata::String>("Satoshi")
+;
+"#,
+                ResourceAddress::xrd_on_network(NetworkID::Mainnet),
+            ),
+            NetworkID::Mainnet,
+            [],
+        )
+        .unwrap();
+        assert_eq!(
+            SUT::from_manifest(&manifest),
+            Ok(SUT::new(
+                ManifestClass::General,
+                [],
+                [],
+                [account.clone()],
+                [identity.clone()]
+            )
+            .with_proofs_created([ResourceAddress::xrd_on_network(
+                NetworkID::Mainnet
+            )])
+            .with_metadata_updates([identity.into()]))
+        );
+    }
+
+    #[test]
+    fn from_manifest_changing_owner_role() {
+        let account = AccountAddress::placeholder_mainnet();
+        let identity = IdentityAddress::placeholder_mainnet();
+        let manifest = TransactionManifest::new(
+            format!(
+                r#"
+SET_OWNER_ROLE
+    Address("{account}")
+    Enum<0u8>()
+;
+CALL_METHOD
+    Address("{identity}")
+    "securify"
+;
+"#
+            ),
+            NetworkID::Mainnet,
+            [],
+        )
+        .unwrap();
+        let sut = SUT::from_manifest(&manifest).unwrap();
+        assert_eq!(
+            sut.entities_with_access_rule_changes,
+            vec![account.into(), identity.into()]
+        );
+        assert!(sut.entities_with_metadata_updates.is_empty());
+    }
+
+    #[test]
+    fn from_manifest_removing_metadata_without_duplicates() {
+        let account = AccountAddress::placeholder_mainnet();
+        let manifest = TransactionManifest::new(
+            format!(
+                r#"
+REMOVE_METADATA
+    Address("{account}")
+    "name"
+;
+LOCK_METADATA
+    Address("{account}")
+    "name"
+;
+"#
+            ),
+            NetworkID::Mainnet,
+            [],
+        )
+        .unwrap();
+        let sut = SUT::from_manifest(&manifest).unwrap();
+        assert_eq!(sut.entities_with_metadata_updates, vec![account.into()]);
+        assert!(sut.entities_with_access_rule_changes.is_empty());
+    }
+
+    #[test]
+    fn changes_control_of() {
+        let sut = SUT::placeholder_other();
+        assert!(sut.changes_control_of(
+            &AccountAddress::placeholder_mainnet_other().into()
+        ));
+        assert!(!sut
+            .changes_control_of(&AccountAddress::placeholder_mainnet().into()));
+        assert!(!sut.changes_control_of(
+            &IdentityAddress::placeholder_mainnet().into()
+        ));
+    }
+
+    #[test]
+    fn entities_requiring_auth_without_duplicates() {
+        let sut = SUT::new(
+            ManifestClass::General,
+            [AccountAddress::placeholder_mainnet()],
+            [],
+            [AccountAddress::placeholder_mainnet()],
+            [],
+        );
+        assert_eq!(
+            sut.entities_requiring_auth(),
+            vec![AccountAddress::placeholder_mainnet().into()]
+        );
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn summary() {
+        assert_eq!(
+            transaction_manifest_summary(&TransactionManifest::placeholder()),
+            Ok(ManifestSummary::placeholder())
+        );
+    }
+
+    #[test]
+    fn placeholders() {
+        assert_eq!(
+            new_manifest_summary_placeholder(),
+            ManifestSummary::placeholder()
+        );
+        assert_eq!(
+            new_manifest_summary_placeholder_other(),
+            ManifestSummary::placeholder_other()
+        );
+    }
+}
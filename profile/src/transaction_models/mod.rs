@@ -0,0 +1,17 @@
+mod epoch;
+mod fees;
+mod intent_hash;
+mod manifest_summary;
+mod nonce;
+mod transaction_header;
+mod transaction_manifest;
+mod transaction_manifest_pools;
+
+pub use epoch::*;
+pub use fees::*;
+pub use intent_hash::*;
+pub use manifest_summary::*;
+pub use nonce::*;
+pub use transaction_header::*;
+pub use transaction_manifest::*;
+pub use transaction_manifest_pools::*;
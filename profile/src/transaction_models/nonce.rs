@@ -0,0 +1,113 @@
+use crate::prelude::*;
+
+/// A random number included in the [`TransactionHeader`] so that
+/// otherwise identical transactions get distinct intent hashes.
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    derive_more::Display,
+)]
+#[serde(transparent)]
+pub struct Nonce(pub u32);
+uniffi::custom_newtype!(Nonce, u32);
+
+impl Nonce {
+    /// Generates a new random [`Nonce`] using the current
+    /// [`EntropyProviderDriver`].
+    pub fn random() -> Self {
+        let bytes = entropy_provider_generate_bytes(4);
+        Self(u32::from_be_bytes(
+            bytes.try_into().expect("Requested exactly 4 bytes"),
+        ))
+    }
+}
+
+impl From<u32> for Nonce {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Nonce> for u32 {
+    fn from(value: Nonce) -> Self {
+        value.0
+    }
+}
+
+impl HasPlaceholder for Nonce {
+    fn placeholder() -> Self {
+        Self(0xdeadbeef)
+    }
+
+    fn placeholder_other() -> Self {
+        Self(0xcafebabe)
+    }
+}
+
+#[uniffi::export]
+pub fn new_nonce_random() -> Nonce {
+    Nonce::random()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Nonce::placeholder(), Nonce::placeholder());
+        assert_eq!(Nonce::placeholder_other(), Nonce::placeholder_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Nonce::placeholder(), Nonce::placeholder_other());
+    }
+
+    #[test]
+    fn random_uses_entropy_provider() {
+        assert_eq!(
+            with_entropy_provider(MockEntropyProvider(0xff), Nonce::random),
+            Nonce(u32::MAX)
+        );
+    }
+
+    #[test]
+    fn random_is_random() {
+        let n = 100;
+        let set = (0..n).map(|_| Nonce::random()).collect::<HashSet<_>>();
+        assert!(set.len() > 95);
+    }
+
+    #[test]
+    fn from_u32_roundtrip() {
+        assert_eq!(u32::from(Nonce::from(42)), 42);
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        assert_json_value_eq_after_roundtrip(
+            &Nonce::placeholder(),
+            json!(0xdeadbeef_u32),
+        );
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn random() {
+        assert_eq!(
+            with_entropy_provider(MockEntropyProvider(0), new_nonce_random),
+            Nonce(0)
+        );
+    }
+}
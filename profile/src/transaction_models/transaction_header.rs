@@ -0,0 +1,227 @@
+use crate::prelude::*;
+
+/// The header of a transaction intent, specifying on which network and
+/// during which epochs the transaction is valid, and who notarizes it.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct TransactionHeader {
+    pub network_id: NetworkID,
+
+    /// The first epoch, inclusive, in which the transaction can be committed.
+    pub start_epoch_inclusive: Epoch,
+
+    /// The epoch, exclusive, from which the transaction can no longer be
+    /// committed.
+    pub end_epoch_exclusive: Epoch,
+
+    pub nonce: Nonce,
+
+    pub notary_public_key: PublicKey,
+
+    /// If the notary also counts as a signatory of the transaction, avoiding
+    /// the need for a separate intent signature by the same key.
+    pub notary_is_signatory: bool,
+
+    pub tip_percentage: u16,
+}
+
+impl TransactionHeader {
+    /// The number of epochs a transaction built by the wallet is valid for,
+    /// roughly 50 minutes.
+    pub const DEFAULT_EPOCH_WINDOW: u64 = 10;
+
+    /// A header valid from `current_epoch` for [`Self::DEFAULT_EPOCH_WINDOW`]
+    /// epochs, with a random [`Nonce`] and no tip, matching how the wallet
+    /// builds transactions.
+    pub fn new(
+        network_id: NetworkID,
+        current_epoch: Epoch,
+        notary_public_key: PublicKey,
+    ) -> Self {
+        Self {
+            network_id,
+            start_epoch_inclusive: current_epoch,
+            end_epoch_exclusive: current_epoch + Self::DEFAULT_EPOCH_WINDOW,
+            nonce: Nonce::random(),
+            notary_public_key,
+            notary_is_signatory: false,
+            tip_percentage: 0,
+        }
+    }
+
+    /// Returns a copy of this header valid from `current_epoch` (inclusive)
+    /// until `current_epoch + window` (exclusive).
+    pub fn with_epoch_window(self, current_epoch: Epoch, window: u64) -> Self {
+        Self {
+            start_epoch_inclusive: current_epoch,
+            end_epoch_exclusive: current_epoch + window,
+            ..self
+        }
+    }
+
+    /// `true` if a transaction with this header can be committed in `epoch`.
+    pub fn is_valid_in_epoch(&self, epoch: Epoch) -> bool {
+        self.start_epoch_inclusive <= epoch && epoch < self.end_epoch_exclusive
+    }
+}
+
+impl HasPlaceholder for TransactionHeader {
+    fn placeholder() -> Self {
+        Self {
+            network_id: NetworkID::Mainnet,
+            start_epoch_inclusive: Epoch::placeholder(),
+            end_epoch_exclusive: Epoch::placeholder()
+                + Self::DEFAULT_EPOCH_WINDOW,
+            nonce: Nonce::placeholder(),
+            notary_public_key: PublicKey::placeholder(),
+            notary_is_signatory: false,
+            tip_percentage: 0,
+        }
+    }
+
+    fn placeholder_other() -> Self {
+        Self {
+            network_id: NetworkID::Simulator,
+            start_epoch_inclusive: Epoch::placeholder_other(),
+            end_epoch_exclusive: Epoch::placeholder_other() + 5,
+            nonce: Nonce::placeholder_other(),
+            notary_public_key: PublicKey::placeholder_other(),
+            notary_is_signatory: true,
+            tip_percentage: 1,
+        }
+    }
+}
+
+#[uniffi::export]
+pub fn new_transaction_header(
+    network_id: NetworkID,
+    current_epoch: Epoch,
+    notary_public_key: PublicKey,
+) -> TransactionHeader {
+    TransactionHeader::new(network_id, current_epoch, notary_public_key)
+}
+
+#[uniffi::export]
+pub fn transaction_header_with_epoch_window(
+    header: &TransactionHeader,
+    current_epoch: Epoch,
+    window: u64,
+) -> TransactionHeader {
+    header.clone().with_epoch_window(current_epoch, window)
+}
+
+#[uniffi::export]
+pub fn new_transaction_header_placeholder() -> TransactionHeader {
+    TransactionHeader::placeholder()
+}
+
+#[uniffi::export]
+pub fn new_transaction_header_placeholder_other() -> TransactionHeader {
+    TransactionHeader::placeholder_other()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality() {
+        assert_eq!(
+            TransactionHeader::placeholder(),
+            TransactionHeader::placeholder()
+        );
+        assert_eq!(
+            TransactionHeader::placeholder_other(),
+            TransactionHeader::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            TransactionHeader::placeholder(),
+            TransactionHeader::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn new_uses_default_epoch_window() {
+        let sut = TransactionHeader::new(
+            NetworkID::Mainnet,
+            Epoch::new(100),
+            PublicKey::placeholder(),
+        );
+        assert_eq!(sut.start_epoch_inclusive, Epoch::new(100));
+        assert_eq!(sut.end_epoch_exclusive, Epoch::new(110));
+        assert!(!sut.notary_is_signatory);
+        assert_eq!(sut.tip_percentage, 0);
+    }
+
+    #[test]
+    fn new_uses_random_nonce() {
+        let sut = with_entropy_provider(MockEntropyProvider(0x01), || {
+            TransactionHeader::new(
+                NetworkID::Mainnet,
+                Epoch::placeholder(),
+                PublicKey::placeholder(),
+            )
+        });
+        assert_eq!(sut.nonce, Nonce(0x01010101));
+    }
+
+    #[test]
+    fn with_epoch_window() {
+        let sut = TransactionHeader::placeholder()
+            .with_epoch_window(Epoch::new(50), 2);
+        assert_eq!(sut.start_epoch_inclusive, Epoch::new(50));
+        assert_eq!(sut.end_epoch_exclusive, Epoch::new(52));
+        assert_eq!(sut.nonce, Nonce::placeholder());
+    }
+
+    #[test]
+    fn is_valid_in_epoch() {
+        let sut = TransactionHeader::placeholder()
+            .with_epoch_window(Epoch::new(10), 10);
+        assert!(!sut.is_valid_in_epoch(Epoch::new(9)));
+        assert!(sut.is_valid_in_epoch(Epoch::new(10)));
+        assert!(sut.is_valid_in_epoch(Epoch::new(19)));
+        assert!(!sut.is_valid_in_epoch(Epoch::new(20)));
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn new() {
+        let sut = new_transaction_header(
+            NetworkID::Stokenet,
+            Epoch::new(7),
+            PublicKey::placeholder_other(),
+        );
+        assert_eq!(sut.network_id, NetworkID::Stokenet);
+        assert_eq!(sut.end_epoch_exclusive, Epoch::new(17));
+    }
+
+    #[test]
+    fn with_epoch_window() {
+        let sut = transaction_header_with_epoch_window(
+            &TransactionHeader::placeholder(),
+            Epoch::new(1),
+            3,
+        );
+        assert_eq!(sut.end_epoch_exclusive, Epoch::new(4));
+    }
+
+    #[test]
+    fn placeholders() {
+        assert_eq!(
+            new_transaction_header_placeholder(),
+            TransactionHeader::placeholder()
+        );
+        assert_eq!(
+            new_transaction_header_placeholder_other(),
+            TransactionHeader::placeholder_other()
+        );
+    }
+}
@@ -0,0 +1,540 @@
+use crate::prelude::*;
+
+use radix_engine_common::constants::{
+    ACCOUNT_OWNER_BADGE, ED25519_SIGNATURE_VIRTUAL_BADGE, IDENTITY_OWNER_BADGE,
+    SECP256K1_SIGNATURE_VIRTUAL_BADGE,
+};
+use radix_engine_common::types::{EntityType as EngineEntityType, NodeId};
+use radix_engine_toolkit_json::models::scrypto::node_id::SerializableNodeIdInternal;
+use transaction::manifest::{compile, decompile, BlobProvider};
+use transaction::prelude::{
+    DynamicGlobalAddress, InstructionV1,
+    ResourceAddress as EngineResourceAddress, TransactionManifestV1,
+};
+
+/// A transaction manifest - the instructions of a transaction - in the
+/// textual Radix Transaction Manifest format, e.g. as sent by a dApp,
+/// together with the blobs it references and the network of its addresses.
+///
+/// Always compiles, which is validated when created.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct TransactionManifest {
+    pub instructions: String,
+    pub network_id: NetworkID,
+    pub blobs: Vec<BagOfBytes>,
+}
+
+/// An instruction of a transaction manifest which only the wallet itself
+/// may use, e.g. in a transaction requested by a dApp it must be rejected
+/// or prominently flagged by the transaction review.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Enum)]
+pub enum ManifestSigningViolation {
+    /// Locks a fee from an Account, the wallet locks the fee itself, from
+    /// an Account the user picks.
+    LocksFee {
+        instruction_index: u32,
+        address: String,
+    },
+
+    /// Securifies an Account or Identity, i.e. changes which factors
+    /// control it.
+    SecurifiesEntity {
+        instruction_index: u32,
+        address: String,
+    },
+
+    /// Calls a method of an access controller, e.g. to initiate recovery
+    /// of a securified entity.
+    CallsAccessController {
+        instruction_index: u32,
+        address: String,
+    },
+
+    /// Creates a proof from the auth zone of a signature badge or owner
+    /// badge, which are only there thanks to the signatures of the user,
+    /// i.e. directly accesses the auth of the Accounts and Personas of the
+    /// user.
+    CreatesProofOfUserAuth {
+        instruction_index: u32,
+        resource_address: ResourceAddress,
+    },
+}
+
+/// The methods of Accounts locking fees.
+const ACCOUNT_LOCK_FEE_METHODS: [&str; 4] = [
+    "lock_fee",
+    "lock_contingent_fee",
+    "lock_fee_and_withdraw",
+    "lock_fee_and_withdraw_non_fungibles",
+];
+
+/// The method of Accounts and Identities securifying them.
+pub(crate) const SECURIFY_METHOD: &str = "securify";
+
+/// The badges put in the auth zone by the signatures of the user.
+const USER_AUTH_BADGES: [EngineResourceAddress; 4] = [
+    SECP256K1_SIGNATURE_VIRTUAL_BADGE,
+    ED25519_SIGNATURE_VIRTUAL_BADGE,
+    ACCOUNT_OWNER_BADGE,
+    IDENTITY_OWNER_BADGE,
+];
+
+/// The bech32 address of `node_id` on `network_id`.
+pub(crate) fn bech32_address_of(
+    node_id: &NodeId,
+    network_id: NetworkID,
+) -> String {
+    SerializableNodeIdInternal {
+        network_id: network_id.discriminant(),
+        node_id: *node_id,
+    }
+    .to_string()
+}
+
+pub(crate) fn is_account_entity_type(entity_type: EngineEntityType) -> bool {
+    matches!(
+        entity_type,
+        EngineEntityType::GlobalAccount
+            | EngineEntityType::GlobalVirtualSecp256k1Account
+            | EngineEntityType::GlobalVirtualEd25519Account
+    )
+}
+
+pub(crate) fn is_identity_entity_type(entity_type: EngineEntityType) -> bool {
+    matches!(
+        entity_type,
+        EngineEntityType::GlobalIdentity
+            | EngineEntityType::GlobalVirtualSecp256k1Identity
+            | EngineEntityType::GlobalVirtualEd25519Identity
+    )
+}
+
+impl TransactionManifest {
+    /// Instantiates a new manifest from its textual `instructions`, failing
+    /// with [`CommonError::InvalidTransactionManifest`] if they do not
+    /// compile for `network_id` with `blobs`.
+    pub fn new(
+        instructions: impl AsRef<str>,
+        network_id: NetworkID,
+        blobs: impl IntoIterator<Item = BagOfBytes>,
+    ) -> Result<Self> {
+        let manifest = Self {
+            instructions: instructions.as_ref().to_owned(),
+            network_id,
+            blobs: blobs.into_iter().collect(),
+        };
+        manifest.engine_manifest()?;
+        Ok(manifest)
+    }
+
+    /// Decompiles a manifest built by the Radix Engine Toolkit, e.g. by
+    /// its `ManifestBuilder`.
+    pub(crate) fn from_engine(
+        manifest: &TransactionManifestV1,
+        network_id: NetworkID,
+    ) -> Result<Self> {
+        let instructions =
+            decompile(&manifest.instructions, &network_id.network_definition())
+                .map_err(|e| {
+                    CommonError::InvalidTransactionManifest(format!("{:?}", e))
+                })?;
+        Ok(Self {
+            instructions,
+            network_id,
+            blobs: manifest
+                .blobs
+                .values()
+                .cloned()
+                .map(BagOfBytes::from)
+                .collect(),
+        })
+    }
+
+    /// Compiles this manifest into the model of the Radix Engine, which is
+    /// what is analyzed and signed.
+    pub(crate) fn engine_manifest(&self) -> Result<TransactionManifestV1> {
+        compile(
+            &self.instructions,
+            &self.network_id.network_definition(),
+            BlobProvider::new_with_blobs(
+                self.blobs.iter().map(|b| b.to_vec()).collect(),
+            ),
+        )
+        .map_err(|e| {
+            CommonError::InvalidTransactionManifest(format!("{:?}", e))
+        })
+    }
+}
+
+impl TransactionManifest {
+    /// Every instruction of this manifest which only the wallet itself may
+    /// use, in order, empty if a dApp may request this manifest to be
+    /// signed.
+    pub fn validate_for_wallet_signing(
+        &self,
+    ) -> Result<Vec<ManifestSigningViolation>> {
+        let manifest = self.engine_manifest()?;
+        Ok(manifest
+            .instructions
+            .iter()
+            .enumerate()
+            .filter_map(|(index, instruction)| {
+                self.signing_violation_of(index as u32, instruction)
+            })
+            .collect())
+    }
+
+    fn signing_violation_of(
+        &self,
+        instruction_index: u32,
+        instruction: &InstructionV1,
+    ) -> Option<ManifestSigningViolation> {
+        use ManifestSigningViolation::*;
+        match instruction {
+            InstructionV1::CallMethod {
+                address: DynamicGlobalAddress::Static(address),
+                method_name,
+                ..
+            } => {
+                let node_id = address.as_node_id();
+                let entity_type = node_id.entity_type()?;
+                let address = bech32_address_of(node_id, self.network_id);
+                if entity_type == EngineEntityType::GlobalAccessController {
+                    Some(CallsAccessController {
+                        instruction_index,
+                        address,
+                    })
+                } else if is_account_entity_type(entity_type)
+                    && ACCOUNT_LOCK_FEE_METHODS.contains(&method_name.as_str())
+                {
+                    Some(LocksFee {
+                        instruction_index,
+                        address,
+                    })
+                } else if (is_account_entity_type(entity_type)
+                    || is_identity_entity_type(entity_type))
+                    && method_name == SECURIFY_METHOD
+                {
+                    Some(SecurifiesEntity {
+                        instruction_index,
+                        address,
+                    })
+                } else {
+                    None
+                }
+            }
+            InstructionV1::CreateProofFromAuthZoneOfAmount {
+                resource_address,
+                ..
+            }
+            | InstructionV1::CreateProofFromAuthZoneOfNonFungibles {
+                resource_address,
+                ..
+            }
+            | InstructionV1::CreateProofFromAuthZoneOfAll {
+                resource_address,
+            } if USER_AUTH_BADGES.contains(resource_address) => {
+                let resource_address = bech32_address_of(
+                    resource_address.as_node_id(),
+                    self.network_id,
+                )
+                .parse::<ResourceAddress>()
+                .ok()?;
+                Some(CreatesProofOfUserAuth {
+                    instruction_index,
+                    resource_address,
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+impl HasPlaceholder for TransactionManifest {
+    /// A placeholder used to facilitate unit tests, a transfer of XRD from
+    /// one Account to another.
+    fn placeholder() -> Self {
+        Self::placeholder_transfer(
+            &AccountAddress::placeholder_mainnet(),
+            &AccountAddress::placeholder_mainnet_other(),
+        )
+    }
+
+    /// A placeholder used to facilitate unit tests, a transfer of XRD in
+    /// the opposite direction of `placeholder`.
+    fn placeholder_other() -> Self {
+        Self::placeholder_transfer(
+            &AccountAddress::placeholder_mainnet_other(),
+            &AccountAddress::placeholder_mainnet(),
+        )
+    }
+}
+
+impl TransactionManifest {
+    /// A placeholder used to facilitate unit tests, a transfer of 1337 XRD
+    /// from `from` to `to`.
+    pub fn placeholder_transfer(
+        from: &AccountAddress,
+        to: &AccountAddress,
+    ) -> Self {
+        let xrd = ResourceAddress::xrd_on_network(from.network_id);
+        Self::new(
+            format!(
+                r#"
+CALL_METHOD
+    Address("{from}")
+    "withdraw"
+    Address("{xrd}")
+    Decimal("1337")
+;
+TAKE_FROM_WORKTOP
+    Address("{xrd}")
+    Decimal("1337")
+    Bucket("bucket1")
+;
+CALL_METHOD
+    Address("{to}")
+    "try_deposit_or_abort"
+    Bucket("bucket1")
+    Enum<0u8>()
+;
+"#
+            ),
+            from.network_id,
+            [],
+        )
+        .expect("Valid placeholder.")
+    }
+}
+
+#[uniffi::export]
+pub fn new_transaction_manifest_from_instructions_string_and_blobs(
+    instructions_string: String,
+    network_id: NetworkID,
+    blobs: Vec<BagOfBytes>,
+) -> Result<TransactionManifest> {
+    TransactionManifest::new(instructions_string, network_id, blobs)
+}
+
+/// Every instruction of `manifest` which only the wallet itself may use,
+/// see [`TransactionManifest::validate_for_wallet_signing`].
+#[uniffi::export]
+pub fn transaction_manifest_validate_for_wallet_signing(
+    manifest: &TransactionManifest,
+) -> Result<Vec<ManifestSigningViolation>> {
+    manifest.validate_for_wallet_signing()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use radix_engine_common::constants::{
+        ACCOUNT_OWNER_BADGE, ED25519_SIGNATURE_VIRTUAL_BADGE,
+    };
+    use radix_engine_common::types::{EntityType as EngineEntityType, NodeId};
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = TransactionManifest;
+
+    fn account() -> AccountAddress {
+        AccountAddress::placeholder_mainnet()
+    }
+
+    fn manifest(instructions: String) -> SUT {
+        SUT::new(instructions, NetworkID::Mainnet, []).unwrap()
+    }
+
+    fn resource_address(
+        engine: radix_engine_common::types::ResourceAddress,
+    ) -> ResourceAddress {
+        bech32_address_of(engine.as_node_id(), NetworkID::Mainnet)
+            .parse()
+            .unwrap()
+    }
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::placeholder(), SUT::placeholder());
+        assert_eq!(SUT::placeholder_other(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::placeholder(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn invalid_instructions_fail() {
+        assert!(matches!(
+            SUT::new("CALL_METHOD;", NetworkID::Mainnet, []),
+            Err(CommonError::InvalidTransactionManifest(_))
+        ));
+    }
+
+    #[test]
+    fn address_on_other_network_fails() {
+        let instructions = SUT::placeholder().instructions;
+        assert!(matches!(
+            SUT::new(instructions, NetworkID::Stokenet, []),
+            Err(CommonError::InvalidTransactionManifest(_))
+        ));
+    }
+
+    #[test]
+    fn from_engine_roundtrip() {
+        let sut = SUT::placeholder();
+        let engine = sut.engine_manifest().unwrap();
+        let decompiled = SUT::from_engine(&engine, NetworkID::Mainnet).unwrap();
+        assert_eq!(decompiled.engine_manifest().unwrap(), engine);
+    }
+
+    #[test]
+    fn transfer_has_no_violations() {
+        assert_eq!(
+            SUT::placeholder().validate_for_wallet_signing(),
+            Ok(vec![])
+        );
+    }
+
+    #[test]
+    fn lock_fee_is_violation() {
+        let sut = manifest(format!(
+            r#"
+CALL_METHOD
+    Address("{}")
+    "lock_fee"
+    Decimal("10")
+;
+"#,
+            account()
+        ));
+        assert_eq!(
+            sut.validate_for_wallet_signing(),
+            Ok(vec![ManifestSigningViolation::LocksFee {
+                instruction_index: 0,
+                address: account().to_string()
+            }])
+        );
+    }
+
+    #[test]
+    fn securify_is_violation() {
+        let identity = IdentityAddress::placeholder_mainnet();
+        let sut = manifest(format!(
+            r#"
+CALL_METHOD
+    Address("{}")
+    "withdraw"
+    Address("{}")
+    Decimal("1")
+;
+CALL_METHOD
+    Address("{}")
+    "securify"
+;
+"#,
+            account(),
+            ResourceAddress::xrd_on_network(NetworkID::Mainnet),
+            identity
+        ));
+        assert_eq!(
+            sut.validate_for_wallet_signing(),
+            Ok(vec![ManifestSigningViolation::SecurifiesEntity {
+                instruction_index: 1,
+                address: identity.to_string()
+            }])
+        );
+    }
+
+    #[test]
+    fn access_controller_method_is_violation() {
+        let access_controller = bech32_address_of(
+            &NodeId::new(
+                EngineEntityType::GlobalAccessController as u8,
+                &[0xab; NodeId::RID_LENGTH],
+            ),
+            NetworkID::Mainnet,
+        );
+        let sut = manifest(format!(
+            r#"
+CALL_METHOD
+    Address("{}")
+    "initiate_recovery_as_primary"
+;
+"#,
+            access_controller
+        ));
+        assert_eq!(
+            sut.validate_for_wallet_signing(),
+            Ok(vec![ManifestSigningViolation::CallsAccessController {
+                instruction_index: 0,
+                address: access_controller
+            }])
+        );
+    }
+
+    #[test]
+    fn proof_of_user_auth_is_violation() {
+        let signature_badge = resource_address(ED25519_SIGNATURE_VIRTUAL_BADGE);
+        let owner_badge = resource_address(ACCOUNT_OWNER_BADGE);
+        let sut = manifest(format!(
+            r#"
+CREATE_PROOF_FROM_AUTH_ZONE_OF_ALL
+    Address("{signature_badge}")
+    Proof("proof1")
+;
+CREATE_PROOF_FROM_AUTH_ZONE_OF_AMOUNT
+    Address("{}")
+    Decimal("1")
+    Proof("proof2")
+;
+CREATE_PROOF_FROM_AUTH_ZONE_OF_ALL
+    Address("{owner_badge}")
+    Proof("proof3")
+;
+"#,
+            ResourceAddress::xrd_on_network(NetworkID::Mainnet)
+        ));
+        assert_eq!(
+            sut.validate_for_wallet_signing(),
+            Ok(vec![
+                ManifestSigningViolation::CreatesProofOfUserAuth {
+                    instruction_index: 0,
+                    resource_address: signature_badge
+                },
+                ManifestSigningViolation::CreatesProofOfUserAuth {
+                    instruction_index: 2,
+                    resource_address: owner_badge
+                },
+            ])
+        );
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn new_from_instructions_string() {
+        let sut = TransactionManifest::placeholder();
+        assert_eq!(
+            new_transaction_manifest_from_instructions_string_and_blobs(
+                sut.instructions.clone(),
+                sut.network_id,
+                vec![]
+            ),
+            Ok(sut)
+        );
+    }
+
+    #[test]
+    fn validate_for_wallet_signing() {
+        assert_eq!(
+            transaction_manifest_validate_for_wallet_signing(
+                &TransactionManifest::placeholder()
+            ),
+            Ok(vec![])
+        );
+    }
+}
@@ -0,0 +1,328 @@
+use crate::prelude::*;
+
+use radix_engine_common::types::{
+    ComponentAddress as EngineComponentAddress,
+    ResourceAddress as EngineResourceAddress,
+};
+use transaction::builder::ManifestBuilder;
+use transaction::prelude::ManifestBucket;
+
+/// The method of all kinds of pools taking resources in exchange for pool
+/// units.
+pub(crate) const POOL_CONTRIBUTE_METHOD: &str = "contribute";
+
+/// The method of all kinds of pools taking pool units in exchange for the
+/// resources they represent.
+pub(crate) const POOL_REDEEM_METHOD: &str = "redeem";
+
+/// An amount of a resource to contribute to a pool.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct PoolContribution {
+    pub resource_address: ResourceAddress,
+    pub amount: Decimal,
+}
+
+impl PoolContribution {
+    pub fn new(resource_address: ResourceAddress, amount: Decimal) -> Self {
+        Self {
+            resource_address,
+            amount,
+        }
+    }
+}
+
+impl TransactionManifest {
+    /// A manifest contributing `contributions` withdrawn from `account` to
+    /// `pool`, depositing the pool units received - and any change the pool
+    /// returns - back into `account`.
+    ///
+    /// Fails if `pool` is on another network than `account`, or if it is
+    /// not of a kind taking as many resources as `contributions`.
+    pub fn contribute_to_pool(
+        account: &AccountAddress,
+        pool: &PoolAddress,
+        contributions: Vec<PoolContribution>,
+    ) -> Result<Self> {
+        Self::validate_pool_network(account, pool)?;
+        let kind = pool.kind();
+        let count_is_valid = match kind {
+            PoolKind::OneResource => contributions.len() == 1,
+            PoolKind::TwoResources => contributions.len() == 2,
+            PoolKind::MultiResources => !contributions.is_empty(),
+        };
+        if !count_is_valid {
+            return Err(CommonError::InvalidNumberOfPoolContributions {
+                kind,
+                found: contributions.len() as u64,
+            });
+        }
+
+        let engine_account = EngineComponentAddress::from(account.clone());
+        let bucket_names = (0..contributions.len())
+            .map(|i| format!("contribution_{i}"))
+            .collect_vec();
+        let builder = contributions.into_iter().zip(bucket_names.iter()).fold(
+            ManifestBuilder::new(),
+            |builder, (contribution, bucket_name)| {
+                let resource =
+                    EngineResourceAddress::from(contribution.resource_address);
+                builder
+                    .withdraw_from_account(
+                        engine_account,
+                        resource,
+                        contribution.amount.native(),
+                    )
+                    .take_all_from_worktop(resource, bucket_name.as_str())
+            },
+        );
+        let engine_pool = EngineComponentAddress::from(pool.clone());
+        let builder = match kind {
+            PoolKind::OneResource => builder.call_method_with_name_lookup(
+                engine_pool,
+                POOL_CONTRIBUTE_METHOD,
+                |lookup| (lookup.bucket(bucket_names[0].as_str()),),
+            ),
+            PoolKind::TwoResources => builder.call_method_with_name_lookup(
+                engine_pool,
+                POOL_CONTRIBUTE_METHOD,
+                |lookup| {
+                    ((
+                        lookup.bucket(bucket_names[0].as_str()),
+                        lookup.bucket(bucket_names[1].as_str()),
+                    ),)
+                },
+            ),
+            PoolKind::MultiResources => builder.call_method_with_name_lookup(
+                engine_pool,
+                POOL_CONTRIBUTE_METHOD,
+                |lookup| {
+                    (bucket_names
+                        .iter()
+                        .map(|n| lookup.bucket(n.as_str()))
+                        .collect::<Vec<ManifestBucket>>(),)
+                },
+            ),
+        };
+        Self::from_engine(
+            &builder
+                .try_deposit_entire_worktop_or_abort(engine_account, None)
+                .build(),
+            account.network_id,
+        )
+    }
+
+    /// A manifest redeeming `amount` of the `pool_unit` resource withdrawn
+    /// from `account` at `pool`, depositing the resources received back
+    /// into `account`.
+    ///
+    /// Fails if `pool` is on another network than `account`.
+    pub fn redeem_from_pool(
+        account: &AccountAddress,
+        pool: &PoolAddress,
+        pool_unit: &ResourceAddress,
+        amount: Decimal,
+    ) -> Result<Self> {
+        Self::validate_pool_network(account, pool)?;
+        let engine_account = EngineComponentAddress::from(account.clone());
+        let pool_unit = EngineResourceAddress::from(pool_unit.clone());
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(engine_account, pool_unit, amount.native())
+            .take_all_from_worktop(pool_unit, "pool_units")
+            .call_method_with_name_lookup(
+                EngineComponentAddress::from(pool.clone()),
+                POOL_REDEEM_METHOD,
+                |lookup| (lookup.bucket("pool_units"),),
+            )
+            .try_deposit_entire_worktop_or_abort(engine_account, None)
+            .build();
+        Self::from_engine(&manifest, account.network_id)
+    }
+
+    fn validate_pool_network(
+        account: &AccountAddress,
+        pool: &PoolAddress,
+    ) -> Result<()> {
+        if pool.network_id != account.network_id {
+            return Err(CommonError::PoolOnWrongNetwork {
+                expected: account.network_id,
+                found: pool.network_id,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// A manifest contributing `contributions` withdrawn from `account` to
+/// `pool`, see [`TransactionManifest::contribute_to_pool`].
+#[uniffi::export]
+pub fn manifest_contribute_to_pool(
+    account: &AccountAddress,
+    pool: &PoolAddress,
+    contributions: Vec<PoolContribution>,
+) -> Result<TransactionManifest> {
+    TransactionManifest::contribute_to_pool(account, pool, contributions)
+}
+
+/// A manifest redeeming `amount` of `pool_unit` withdrawn from `account` at
+/// `pool`, see [`TransactionManifest::redeem_from_pool`].
+#[uniffi::export]
+pub fn manifest_redeem_from_pool(
+    account: &AccountAddress,
+    pool: &PoolAddress,
+    pool_unit: &ResourceAddress,
+    amount: Decimal,
+) -> Result<TransactionManifest> {
+    TransactionManifest::redeem_from_pool(account, pool, pool_unit, amount)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn xrd() -> ResourceAddress {
+        ResourceAddress::xrd_on_network(NetworkID::Mainnet)
+    }
+
+    fn other_resource() -> ResourceAddress {
+        "resource_rdx1tkk83magp3gjyxrpskfsqwkg4g949rmcjee4tu2xmw93ltw2cz94sq"
+            .parse()
+            .unwrap()
+    }
+
+    fn contribution(resource: ResourceAddress) -> PoolContribution {
+        PoolContribution::new(resource, Decimal::one())
+    }
+
+    #[test]
+    fn contribute_to_two_resource_pool() {
+        let account = AccountAddress::placeholder_mainnet();
+        let pool = PoolAddress::placeholder();
+        let sut = TransactionManifest::contribute_to_pool(
+            &account,
+            &pool,
+            vec![contribution(xrd()), contribution(other_resource())],
+        )
+        .unwrap();
+        assert_eq!(sut.network_id, NetworkID::Mainnet);
+        assert!(sut.instructions.contains(&pool.to_string()));
+        assert!(sut.instructions.contains("\"contribute\""));
+        let summary = ManifestSummary::from_manifest(&sut).unwrap();
+        assert_eq!(summary.classification, ManifestClass::PoolContribution);
+        assert_eq!(summary.pools_contributed_to, vec![pool]);
+        assert_eq!(summary.accounts_withdrawn_from, vec![account.clone()]);
+        assert_eq!(summary.accounts_deposited_into, vec![account]);
+    }
+
+    #[test]
+    fn contribute_to_multi_resource_pool() {
+        let sut = TransactionManifest::contribute_to_pool(
+            &AccountAddress::placeholder_mainnet(),
+            &PoolAddress::placeholder_of_kind(PoolKind::MultiResources),
+            vec![contribution(xrd())],
+        );
+        assert!(sut.is_ok());
+    }
+
+    #[test]
+    fn contribute_wrong_number_of_resources_fails() {
+        assert_eq!(
+            TransactionManifest::contribute_to_pool(
+                &AccountAddress::placeholder_mainnet(),
+                &PoolAddress::placeholder_other(),
+                vec![contribution(xrd()), contribution(other_resource())],
+            ),
+            Err(CommonError::InvalidNumberOfPoolContributions {
+                kind: PoolKind::OneResource,
+                found: 2
+            })
+        );
+        assert_eq!(
+            TransactionManifest::contribute_to_pool(
+                &AccountAddress::placeholder_mainnet(),
+                &PoolAddress::placeholder_of_kind(PoolKind::MultiResources),
+                vec![],
+            ),
+            Err(CommonError::InvalidNumberOfPoolContributions {
+                kind: PoolKind::MultiResources,
+                found: 0
+            })
+        );
+    }
+
+    #[test]
+    fn contribute_to_pool_on_other_network_fails() {
+        assert_eq!(
+            TransactionManifest::contribute_to_pool(
+                &AccountAddress::placeholder_stokenet(),
+                &PoolAddress::placeholder(),
+                vec![contribution(xrd()), contribution(other_resource())],
+            ),
+            Err(CommonError::PoolOnWrongNetwork {
+                expected: NetworkID::Stokenet,
+                found: NetworkID::Mainnet
+            })
+        );
+    }
+
+    #[test]
+    fn redeem_from_pool() {
+        let account = AccountAddress::placeholder_mainnet();
+        let pool = PoolAddress::placeholder();
+        let sut = TransactionManifest::redeem_from_pool(
+            &account,
+            &pool,
+            &other_resource(),
+            Decimal::one(),
+        )
+        .unwrap();
+        assert!(sut.instructions.contains("\"redeem\""));
+        let summary = ManifestSummary::from_manifest(&sut).unwrap();
+        assert_eq!(summary.classification, ManifestClass::PoolRedemption);
+        assert_eq!(summary.pools_redeemed_from, vec![pool]);
+        assert_eq!(summary.accounts_withdrawn_from, vec![account]);
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn contribute() {
+        let account = AccountAddress::placeholder_mainnet();
+        let pool = PoolAddress::placeholder_other();
+        let contributions = vec![PoolContribution::new(
+            ResourceAddress::xrd_on_network(NetworkID::Mainnet),
+            Decimal::one(),
+        )];
+        assert_eq!(
+            manifest_contribute_to_pool(&account, &pool, contributions.clone()),
+            TransactionManifest::contribute_to_pool(
+                &account,
+                &pool,
+                contributions
+            )
+        );
+    }
+
+    #[test]
+    fn redeem() {
+        let account = AccountAddress::placeholder_mainnet();
+        let pool = PoolAddress::placeholder_other();
+        let pool_unit = ResourceAddress::xrd_on_network(NetworkID::Mainnet);
+        assert_eq!(
+            manifest_redeem_from_pool(
+                &account,
+                &pool,
+                &pool_unit,
+                Decimal::one()
+            ),
+            TransactionManifest::redeem_from_pool(
+                &account,
+                &pool,
+                &pool_unit,
+                Decimal::one()
+            )
+        );
+    }
+}
@@ -1,4 +1,6 @@
 use crate::prelude::*;
+#[cfg(feature = "developer")]
+use radix_engine_common::types::NodeId;
 
 /// The address of an Account, a bech32 encoding of a public key hash
 /// that starts with the prefix `"account_"`, dependent on NetworkID, meaning the same
@@ -14,7 +16,6 @@ use crate::prelude::*;
     SerializeDisplay,
     DeserializeFromStr,
     derive_more::Display,
-    uniffi::Record,
 )]
 #[display("{address}")]
 pub struct AccountAddress {
@@ -90,6 +91,79 @@ impl AccountAddress {
     }
 }
 
+#[cfg(feature = "developer")]
+impl AccountAddress {
+    /// Returns the address of the same account on `network_id`, i.e. the
+    /// same public key hash bech32 encoded with the HRP of `network_id`.
+    ///
+    /// Only meant for developer tooling, e.g. replaying Mainnet scenarios
+    /// on Stokenet, no funds can be accessed on the other network without
+    /// the private key.
+    pub fn map_to_network(&self, network_id: NetworkID) -> Self {
+        if network_id == self.network_id {
+            return self.clone();
+        }
+        let address = Self::address_from_node_id(
+            NodeId(self.node_id_bytes()),
+            network_id.discriminant(),
+        );
+        Self::__with_address_and_network_id(&address, network_id)
+    }
+
+    /// Returns `true` if `self` and `other` are addresses of the same
+    /// account, possibly on different networks, i.e. if
+    /// `self.map_to_network(other.network_id) == other`.
+    pub fn is_same_entity_as(&self, other: &Self) -> bool {
+        self.node_id_bytes() == other.node_id_bytes()
+    }
+
+    fn node_id_bytes(&self) -> [u8; 30] {
+        let (_, _, _, node_id) = decode_address(&self.address)
+            .expect("Should always be able to decode a valid AccountAddress");
+        node_id
+    }
+}
+
+#[cfg(feature = "developer")]
+#[uniffi::export]
+pub fn account_address_map_to_network(
+    address: &AccountAddress,
+    network_id: NetworkID,
+) -> AccountAddress {
+    address.map_to_network(network_id)
+}
+
+#[cfg(feature = "developer")]
+#[uniffi::export]
+pub fn account_address_is_same_entity_as(
+    address: &AccountAddress,
+    other: &AccountAddress,
+) -> bool {
+    address.is_same_entity_as(other)
+}
+
+uniffi::custom_type!(AccountAddress, String);
+/// Crosses the FFI boundary as its bech32 string, validated when lifted.
+impl crate::UniffiCustomTypeConverter for AccountAddress {
+    type Builtin = String;
+
+    #[cfg(not(tarpaulin_include))] // false negative | tested in bindgen tests
+    fn into_custom(val: Self::Builtin) -> uniffi::Result<Self> {
+        val.parse::<Self>().map_err(|e| e.into())
+    }
+
+    #[cfg(not(tarpaulin_include))] // false negative | tested in bindgen tests
+    fn from_custom(obj: Self) -> Self::Builtin {
+        obj.to_string()
+    }
+}
+
+/// The network the AccountAddress is tied to.
+#[uniffi::export]
+pub fn account_address_network_id(address: &AccountAddress) -> NetworkID {
+    address.network_id
+}
+
 impl FromStr for AccountAddress {
     type Err = CommonError;
 
@@ -98,6 +172,18 @@ impl FromStr for AccountAddress {
     }
 }
 
+impl From<AccountAddress> for radix_engine_common::types::ComponentAddress {
+    fn from(value: AccountAddress) -> Self {
+        radix_engine_common::types::ComponentAddress::try_from_bech32(
+            &radix_engine_common::address::AddressBech32Decoder::new(
+                &value.network_id.network_definition(),
+            ),
+            value.address.as_str(),
+        )
+        .unwrap()
+    }
+}
+
 impl EntityAddress for AccountAddress {
     /// Identifies that AccountAddress uses the `EntityType::Account`, which are used
     /// to validate the HRP (`"account_"`) and is also used when forming HD derivation
@@ -288,7 +374,9 @@ mod tests {
     fn invalid() {
         assert_eq!(
             AccountAddress::try_from_bech32("x"),
-            Err(CommonError::FailedToDecodeAddressFromBech32("x".to_owned()))
+            Err(CommonError::AddressMissingSeparator {
+                input: "x".to_owned()
+            })
         )
     }
 
@@ -297,7 +385,10 @@ mod tests {
         let s = "account_rdx16xlfcpp0vf7e3gqnswv8j9k58n6rjccu58vvspmdva22kf3apleasx";
         assert_eq!(
             AccountAddress::try_from_bech32(s),
-            Err(CommonError::FailedToDecodeAddressFromBech32(s.to_owned()))
+            Err(CommonError::InvalidAddressChecksumAtPosition {
+                position: 65,
+                input: s.to_owned()
+            })
         )
     }
 
@@ -306,7 +397,9 @@ mod tests {
         let s = "identity_rdx16xlfcpp0vf7e3gqnswv8j9k58n6rjccu58vvspmdva22kf3aplease";
         assert_eq!(
             AccountAddress::try_from_bech32(s),
-            Err(CommonError::FailedToDecodeAddressFromBech32(s.to_owned()))
+            Err(CommonError::InvalidAddressChecksum {
+                input: s.to_owned()
+            })
         )
     }
 
@@ -338,6 +431,32 @@ mod tests {
         );
         assert_json_value_fails::<AccountAddress>(json!("super invalid"));
     }
+
+    #[cfg(feature = "developer")]
+    #[test]
+    fn map_to_network() {
+        let public_key = PublicKey::placeholder();
+        let mainnet =
+            AccountAddress::new(public_key.clone(), NetworkID::Mainnet);
+        let stokenet = AccountAddress::new(public_key, NetworkID::Stokenet);
+        assert_eq!(mainnet.map_to_network(NetworkID::Stokenet), stokenet);
+        assert_eq!(stokenet.map_to_network(NetworkID::Mainnet), mainnet);
+        assert_eq!(mainnet.map_to_network(NetworkID::Mainnet), mainnet);
+    }
+
+    #[cfg(feature = "developer")]
+    #[test]
+    fn is_same_entity_as() {
+        let public_key = PublicKey::placeholder();
+        let mainnet =
+            AccountAddress::new(public_key.clone(), NetworkID::Mainnet);
+        let stokenet = AccountAddress::new(public_key, NetworkID::Stokenet);
+        assert!(mainnet.is_same_entity_as(&stokenet));
+        assert!(stokenet.is_same_entity_as(&mainnet));
+        assert!(
+            !mainnet.is_same_entity_as(&AccountAddress::placeholder_other())
+        );
+    }
 }
 
 #[cfg(test)]
@@ -345,13 +464,19 @@ mod uniffi_tests {
     use std::str::FromStr;
 
     use crate::{
-        account_address_to_short, new_account_address,
-        new_account_address_from, Ed25519PublicKey, EntityAddress, NetworkID,
-        PublicKey,
+        account_address_network_id, account_address_to_short,
+        new_account_address, new_account_address_from, Ed25519PublicKey,
+        EntityAddress, NetworkID, PublicKey,
     };
 
     use super::AccountAddress;
 
+    #[cfg(feature = "developer")]
+    use crate::{
+        account_address_is_same_entity_as, account_address_map_to_network,
+        HasPlaceholder,
+    };
+
     #[test]
     fn short() {
         let sut: AccountAddress = AccountAddress::try_from_bech32(
@@ -361,6 +486,28 @@ mod uniffi_tests {
         assert_eq!(account_address_to_short(&sut), "acco...please");
     }
 
+    #[test]
+    fn network_id() {
+        let sut: AccountAddress = AccountAddress::try_from_bech32(
+            "account_rdx16xlfcpp0vf7e3gqnswv8j9k58n6rjccu58vvspmdva22kf3aplease",
+        )
+        .unwrap();
+        assert_eq!(account_address_network_id(&sut), NetworkID::Mainnet);
+    }
+
+    #[test]
+    fn custom_type_roundtrip() {
+        use crate::UniffiCustomTypeConverter;
+        let s = "account_rdx16xlfcpp0vf7e3gqnswv8j9k58n6rjccu58vvspmdva22kf3aplease";
+        let sut = AccountAddress::into_custom(s.to_owned()).unwrap();
+        assert_eq!(sut, AccountAddress::try_from_bech32(s).unwrap());
+        assert_eq!(AccountAddress::from_custom(sut), s);
+        assert!(
+            AccountAddress::into_custom("account_rdx1invalid".to_owned())
+                .is_err()
+        );
+    }
+
     #[test]
     fn new() {
         let public_key: PublicKey = Ed25519PublicKey::from_str(
@@ -381,4 +528,14 @@ mod uniffi_tests {
         );
         assert_eq!(from_bech32.address, bech32)
     }
+
+    #[cfg(feature = "developer")]
+    #[test]
+    fn map_to_network() {
+        let sut = AccountAddress::placeholder();
+        let stokenet =
+            account_address_map_to_network(&sut, NetworkID::Stokenet);
+        assert_eq!(stokenet.network_id, NetworkID::Stokenet);
+        assert!(account_address_is_same_entity_as(&sut, &stokenet));
+    }
 }
@@ -0,0 +1,335 @@
+use radix_engine_common::address::HrpSet;
+use radix_engine_common::types::EntityType as EngineEntityType;
+
+use crate::prelude::*;
+
+/// The bech32 charset, the index of a character is its 5 bit value.
+const CHARSET: &str = "qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// The constant a valid Bech32m checksum polymod evaluates to.
+const BECH32M_CONST: u32 = 0x2bc830a3;
+
+/// Length of the checksum at the end of the data part.
+const CHECKSUM_LENGTH: usize = 6;
+
+/// Number of bytes of data of every address, the entity type byte followed
+/// by 29 bytes of the node id.
+const ADDRESS_DATA_LENGTH: usize = 30;
+
+/// Inputs longer than this are truncated in errors, since the input might be
+/// anything pasted by the user.
+const MAX_INPUT_LENGTH_IN_ERROR: usize = 80;
+
+/// The part of the HRP before the network suffix of all kinds of addresses.
+const ENTITY_HRP_PREFIXES: [&str; 13] = [
+    "package",
+    "resource",
+    "component",
+    "account",
+    "identity",
+    "consensusmanager",
+    "validator",
+    "accesscontroller",
+    "pool",
+    "transactiontracker",
+    "internal_vault",
+    "internal_component",
+    "internal_keyvaluestore",
+];
+
+fn truncated_input(s: &str) -> String {
+    if s.chars().count() <= MAX_INPUT_LENGTH_IN_ERROR {
+        s.to_owned()
+    } else {
+        format!(
+            "{}…",
+            s.chars()
+                .take(MAX_INPUT_LENGTH_IN_ERROR)
+                .collect::<String>()
+        )
+    }
+}
+
+/// The network with an HRP suffix `hrp` ends with, if `hrp` is one of the
+/// known entity prefixes followed by that suffix.
+fn network_of_hrp(hrp: &str) -> Option<NetworkID> {
+    enum_iterator::all::<NetworkID>().find(|n| {
+        let suffix = n.network_definition().hrp_suffix;
+        ENTITY_HRP_PREFIXES
+            .iter()
+            .any(|prefix| hrp == format!("{}_{}", prefix, suffix))
+    })
+}
+
+fn polymod(values: impl IntoIterator<Item = u8>) -> u32 {
+    const GENERATOR: [u32; 5] =
+        [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+    values.into_iter().fold(1, |chk, v| {
+        let top = chk >> 25;
+        let chk = ((chk & 0x1ffffff) << 5) ^ (v as u32);
+        GENERATOR
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| (top >> i) & 1 == 1)
+            .fold(chk, |chk, (_, g)| chk ^ g)
+    })
+}
+
+fn is_valid_checksum(hrp: &str, data: &[u8]) -> bool {
+    let hrp_expanded = hrp
+        .bytes()
+        .map(|b| b >> 5)
+        .chain(std::iter::once(0))
+        .chain(hrp.bytes().map(|b| b & 31));
+    polymod(hrp_expanded.chain(data.iter().cloned())) == BECH32M_CONST
+}
+
+/// The index into `data` of the single character which - if substituted -
+/// makes the checksum valid, `None` if there is no such character or more
+/// than one.
+fn position_of_single_typo(hrp: &str, data: &[u8]) -> Option<usize> {
+    let fixes = (0..data.len())
+        .filter(|&i| {
+            (0..CHARSET.len() as u8).filter(|&v| v != data[i]).any(|v| {
+                let mut candidate = data.to_vec();
+                candidate[i] = v;
+                is_valid_checksum(hrp, &candidate)
+            })
+        })
+        .collect_vec();
+    match fixes.as_slice() {
+        [position] => Some(*position),
+        _ => None,
+    }
+}
+
+/// Regroups 5 bit values into bytes, dropping the padding.
+fn bytes_from_5_bit_values(values: &[u8]) -> Vec<u8> {
+    let mut acc: u32 = 0;
+    let mut bits = 0;
+    let mut bytes = Vec::new();
+    for v in values {
+        acc = (acc << 5) | (*v as u32);
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((acc >> bits) as u8);
+        }
+    }
+    bytes
+}
+
+/// `true` if an address starting with `byte` should have HRP `hrp`.
+fn entity_type_byte_matches_hrp(
+    byte: u8,
+    hrp: &str,
+    network: NetworkID,
+) -> bool {
+    EngineEntityType::from_repr(byte)
+        .map(|entity_type| {
+            HrpSet::from(&network.network_definition())
+                .get_entity_hrp(&entity_type)
+                == hrp
+        })
+        .unwrap_or(false)
+}
+
+/// Finds out why `s` is not a valid address, called when the Radix Engine
+/// fails to decode it - which it does without telling why - so that Wallet
+/// Clients can tell the user e.g. which character of a pasted address is
+/// wrong.
+pub(crate) fn diagnose_address_decoding_failure(s: &str) -> CommonError {
+    let input = truncated_input(s);
+
+    // The bech32 charset does not contain `1`, so the last `1` is always the
+    // separator between the HRP and the data.
+    let Some((hrp, data)) = s.rsplit_once('1') else {
+        return CommonError::AddressMissingSeparator { input };
+    };
+
+    let Some(network) = network_of_hrp(hrp) else {
+        return CommonError::UnknownAddressHRP {
+            hrp: hrp.to_owned(),
+            input,
+        };
+    };
+
+    let data_offset = hrp.chars().count() + 1;
+    let mut values = Vec::with_capacity(data.len());
+    for (i, character) in data.chars().enumerate() {
+        let Some(value) = CHARSET.find(character) else {
+            return CommonError::InvalidAddressCharacter {
+                character,
+                position: data_offset + i,
+                input,
+            };
+        };
+        values.push(value as u8);
+    }
+
+    if values.len() < CHECKSUM_LENGTH {
+        return CommonError::AddressDataTruncated {
+            expected: ADDRESS_DATA_LENGTH,
+            found: 0,
+            input,
+        };
+    }
+
+    if !is_valid_checksum(hrp, &values) {
+        return match position_of_single_typo(hrp, &values) {
+            Some(i) => CommonError::InvalidAddressChecksumAtPosition {
+                position: data_offset + i,
+                input,
+            },
+            None => CommonError::InvalidAddressChecksum { input },
+        };
+    }
+
+    let bytes =
+        bytes_from_5_bit_values(&values[..values.len() - CHECKSUM_LENGTH]);
+    if bytes.len() < ADDRESS_DATA_LENGTH {
+        return CommonError::AddressDataTruncated {
+            expected: ADDRESS_DATA_LENGTH,
+            found: bytes.len(),
+            input,
+        };
+    }
+
+    if !entity_type_byte_matches_hrp(bytes[0], hrp, network) {
+        return CommonError::WrongAddressEntityTypeByte {
+            byte: bytes[0],
+            hrp: hrp.to_owned(),
+            input,
+        };
+    }
+
+    CommonError::FailedToDecodeAddressFromBech32(s.to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::diagnose_address_decoding_failure as sut;
+    use crate::prelude::*;
+
+    #[test]
+    fn missing_separator() {
+        assert_eq!(
+            sut("account_rdx"),
+            CommonError::AddressMissingSeparator {
+                input: "account_rdx".to_owned()
+            }
+        );
+    }
+
+    #[test]
+    fn unknown_hrp_entity() {
+        let s =
+            "acount_rdx16xlfcpp0vf7e3gqnswv8j9k58n6rjccu58vvspmdva22kf3a9mm2yd";
+        assert_eq!(
+            sut(s),
+            CommonError::UnknownAddressHRP {
+                hrp: "acount_rdx".to_owned(),
+                input: s.to_owned()
+            }
+        );
+    }
+
+    #[test]
+    fn unknown_hrp_network() {
+        let s = "account_xyz16xlfcpp0vf7e3gqnswv8j9k58n6rjccu58vvspmdva22kf3ah5c6l9";
+        assert_eq!(
+            sut(s),
+            CommonError::UnknownAddressHRP {
+                hrp: "account_xyz".to_owned(),
+                input: s.to_owned()
+            }
+        );
+    }
+
+    #[test]
+    fn invalid_character() {
+        let s = "account_rdx16xlfcpp0vf7e3gqnswv8j9k58n6rjccu58vvspmdva22kf3aplebse";
+        assert_eq!(
+            sut(s),
+            CommonError::InvalidAddressCharacter {
+                character: 'b',
+                position: 63,
+                input: s.to_owned()
+            }
+        );
+    }
+
+    #[test]
+    fn invalid_checksum_at_position() {
+        let s = "account_rdx16xlfcpp0vf7e3gqnswv8j9k58n6rjccu58vvspmdva22kf3apleasx";
+        assert_eq!(
+            sut(s),
+            CommonError::InvalidAddressChecksumAtPosition {
+                position: 65,
+                input: s.to_owned()
+            }
+        );
+    }
+
+    #[test]
+    fn invalid_checksum() {
+        let s = "identity_rdx16xlfcpp0vf7e3gqnswv8j9k58n6rjccu58vvspmdva22kf3aplease";
+        assert_eq!(
+            sut(s),
+            CommonError::InvalidAddressChecksum {
+                input: s.to_owned()
+            }
+        );
+    }
+
+    #[test]
+    fn truncated_data() {
+        let s = "account_rdx16xlfcpp0vf7e3gqnswv8j9k58n6rjccucjaytf";
+        assert_eq!(
+            sut(s),
+            CommonError::AddressDataTruncated {
+                expected: 30,
+                found: 20,
+                input: s.to_owned()
+            }
+        );
+    }
+
+    #[test]
+    fn truncated_checksum() {
+        let s = "account_rdx1qqq";
+        assert_eq!(
+            sut(s),
+            CommonError::AddressDataTruncated {
+                expected: 30,
+                found: 0,
+                input: s.to_owned()
+            }
+        );
+    }
+
+    #[test]
+    fn wrong_entity_type_byte() {
+        // Data of an identity address, encoded with HRP of accounts.
+        let s = "account_rdx12tgzjrz9u0xz4l28vf04hz87eguclmfaq4d2p8f8lv7zg9ssxnantx";
+        assert_eq!(
+            sut(s),
+            CommonError::WrongAddressEntityTypeByte {
+                byte: 0x52,
+                hrp: "account_rdx".to_owned(),
+                input: s.to_owned()
+            }
+        );
+    }
+
+    #[test]
+    fn long_input_is_truncated() {
+        let s = "x".repeat(200);
+        assert_eq!(
+            sut(&s),
+            CommonError::AddressMissingSeparator {
+                input: format!("{}…", "x".repeat(80))
+            }
+        );
+    }
+}
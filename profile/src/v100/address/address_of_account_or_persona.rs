@@ -0,0 +1,110 @@
+use crate::prelude::*;
+
+/// The address of either an Account or a Persona, used by operations
+/// which apply to both kinds of entities, e.g. renaming.
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    EnumAsInner,
+    derive_more::Display,
+    uniffi::Enum,
+)]
+pub enum AddressOfAccountOrPersona {
+    Account {
+        #[display("{}", value)]
+        value: AccountAddress,
+    },
+    Persona {
+        #[display("{}", value)]
+        value: IdentityAddress,
+    },
+}
+
+impl AddressOfAccountOrPersona {
+    /// The network the address is tied to.
+    pub fn network_id(&self) -> NetworkID {
+        match self {
+            Self::Account { value } => value.network_id,
+            Self::Persona { value } => value.network_id,
+        }
+    }
+}
+
+impl From<AccountAddress> for AddressOfAccountOrPersona {
+    fn from(value: AccountAddress) -> Self {
+        Self::Account { value }
+    }
+}
+
+impl From<IdentityAddress> for AddressOfAccountOrPersona {
+    fn from(value: IdentityAddress) -> Self {
+        Self::Persona { value }
+    }
+}
+
+impl HasPlaceholder for AddressOfAccountOrPersona {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        AccountAddress::placeholder().into()
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        IdentityAddress::placeholder().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality() {
+        assert_eq!(
+            AddressOfAccountOrPersona::placeholder(),
+            AddressOfAccountOrPersona::placeholder()
+        );
+        assert_eq!(
+            AddressOfAccountOrPersona::placeholder_other(),
+            AddressOfAccountOrPersona::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            AddressOfAccountOrPersona::placeholder(),
+            AddressOfAccountOrPersona::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            format!("{}", AddressOfAccountOrPersona::placeholder()),
+            format!("{}", AccountAddress::placeholder())
+        );
+        assert_eq!(
+            format!("{}", AddressOfAccountOrPersona::placeholder_other()),
+            format!("{}", IdentityAddress::placeholder())
+        );
+    }
+
+    #[test]
+    fn network_id() {
+        assert_eq!(
+            AddressOfAccountOrPersona::placeholder().network_id(),
+            NetworkID::Mainnet
+        );
+        assert_eq!(
+            AddressOfAccountOrPersona::from(
+                IdentityAddress::placeholder_stokenet()
+            )
+            .network_id(),
+            NetworkID::Stokenet
+        );
+    }
+}
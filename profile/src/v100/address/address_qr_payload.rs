@@ -0,0 +1,201 @@
+use crate::prelude::*;
+
+/// The URI scheme used when encoding addresses in QR codes, shared with
+/// other tools in the Radix ecosystem.
+pub const ADDRESS_QR_PAYLOAD_SCHEME: &str = "radix";
+
+/// Query parameter holding the discriminant of the NetworkID of the address.
+const ADDRESS_QR_PAYLOAD_NETWORK_PARAM: &str = "network";
+
+impl AccountAddress {
+    /// The canonical QR payload of this address, a URI on the form:
+    ///
+    /// `radix:account_rdx16xlfcpp0vf7e3gqnswv8j9k58n6rjccu58vvspmdva22kf3aplease?network=1`
+    ///
+    /// The kind of address is carried by the HRP of the bech32 address itself.
+    pub fn qr_payload(&self) -> String {
+        format!(
+            "{}:{}?{}={}",
+            ADDRESS_QR_PAYLOAD_SCHEME,
+            self.address,
+            ADDRESS_QR_PAYLOAD_NETWORK_PARAM,
+            self.network_id.discriminant()
+        )
+    }
+
+    /// Parses a scanned QR payload, accepting both a bare bech32 address and
+    /// the URI form produced by `qr_payload`. If the URI contains a `network`
+    /// parameter it MUST match the network of the address, unknown
+    /// parameters are ignored.
+    pub fn from_qr_payload(payload: impl AsRef<str>) -> Result<Self> {
+        let payload = payload.as_ref().trim();
+        let invalid =
+            || CommonError::InvalidAddressQRPayload(payload.to_owned());
+
+        let Some(uri) = payload
+            .strip_prefix(ADDRESS_QR_PAYLOAD_SCHEME)
+            .and_then(|s| s.strip_prefix(':'))
+        else {
+            return Self::try_from_bech32(payload);
+        };
+
+        let (bech32, query) = match uri.split_once('?') {
+            Some((bech32, query)) => (bech32, Some(query)),
+            None => (uri, None),
+        };
+        let address = Self::try_from_bech32(bech32)?;
+
+        for pair in query.into_iter().flat_map(|q| q.split('&')) {
+            let Some((key, value)) = pair.split_once('=') else {
+                continue;
+            };
+            if key != ADDRESS_QR_PAYLOAD_NETWORK_PARAM {
+                continue;
+            }
+            let network_id = value
+                .parse::<u8>()
+                .map_err(|_| invalid())
+                .and_then(NetworkID::try_from)?;
+            if network_id != address.network_id {
+                return Err(invalid());
+            }
+        }
+
+        Ok(address)
+    }
+}
+
+/// The canonical QR payload of `address`, e.g. `radix:account_rdx...?network=1`.
+#[uniffi::export]
+pub fn account_address_qr_payload(address: &AccountAddress) -> String {
+    address.qr_payload()
+}
+
+/// Parses a scanned QR payload, either a bare address or the URI form.
+#[uniffi::export]
+pub fn new_account_address_from_qr_payload(
+    payload: String,
+) -> Result<AccountAddress> {
+    AccountAddress::from_qr_payload(payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = AccountAddress;
+
+    #[test]
+    fn qr_payload_mainnet() {
+        assert_eq!(
+            SUT::placeholder_mainnet().qr_payload(),
+            "radix:account_rdx16xlfcpp0vf7e3gqnswv8j9k58n6rjccu58vvspmdva22kf3aplease?network=1"
+        );
+    }
+
+    #[test]
+    fn qr_payload_stokenet() {
+        assert!(SUT::placeholder_stokenet()
+            .qr_payload()
+            .ends_with("?network=2"));
+    }
+
+    #[test]
+    fn qr_payload_roundtrip() {
+        for sut in [
+            SUT::placeholder_mainnet(),
+            SUT::placeholder_mainnet_other(),
+            SUT::placeholder_stokenet(),
+            SUT::placeholder_stokenet_other(),
+        ] {
+            assert_eq!(SUT::from_qr_payload(sut.qr_payload()).unwrap(), sut);
+        }
+    }
+
+    #[test]
+    fn from_bare_address() {
+        let sut = SUT::placeholder_mainnet();
+        assert_eq!(SUT::from_qr_payload(&sut.address).unwrap(), sut);
+    }
+
+    #[test]
+    fn from_uri_without_query() {
+        let sut = SUT::placeholder_mainnet();
+        assert_eq!(
+            SUT::from_qr_payload(format!("radix:{}", sut.address)).unwrap(),
+            sut
+        );
+    }
+
+    #[test]
+    fn from_uri_ignores_unknown_params() {
+        let sut = SUT::placeholder_stokenet();
+        assert_eq!(
+            SUT::from_qr_payload(format!(
+                "radix:{}?label=savings&network=2",
+                sut.address
+            ))
+            .unwrap(),
+            sut
+        );
+    }
+
+    #[test]
+    fn from_uri_network_mismatch_is_err() {
+        let payload =
+            format!("radix:{}?network=2", SUT::placeholder_mainnet().address);
+        assert_eq!(
+            SUT::from_qr_payload(&payload),
+            Err(CommonError::InvalidAddressQRPayload(payload))
+        );
+    }
+
+    #[test]
+    fn from_uri_invalid_network_is_err() {
+        let payload = format!(
+            "radix:{}?network=mainnet",
+            SUT::placeholder_mainnet().address
+        );
+        assert_eq!(
+            SUT::from_qr_payload(&payload),
+            Err(CommonError::InvalidAddressQRPayload(payload))
+        );
+    }
+
+    #[test]
+    fn from_uri_unknown_network_is_err() {
+        let payload =
+            format!("radix:{}?network=255", SUT::placeholder_mainnet().address);
+        assert_eq!(
+            SUT::from_qr_payload(payload),
+            Err(CommonError::UnknownNetworkID(255))
+        );
+    }
+
+    #[test]
+    fn from_invalid_address_is_err() {
+        assert!(SUT::from_qr_payload("radix:not_an_address").is_err());
+        assert!(SUT::from_qr_payload("").is_err());
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = AccountAddress;
+
+    #[test]
+    fn qr_payload_roundtrip() {
+        let sut = SUT::placeholder_mainnet();
+        assert_eq!(
+            new_account_address_from_qr_payload(account_address_qr_payload(
+                &sut
+            ))
+            .unwrap(),
+            sut
+        );
+    }
+}
@@ -8,7 +8,7 @@ pub type DecodeAddressOutput =
 
 fn engine_decode_address(s: &str) -> Result<EngineDecodeAddressOutput> {
     let Some(tuple) = decode(s) else {
-        return Err(CommonError::FailedToDecodeAddressFromBech32(s.to_owned()));
+        return Err(diagnose_address_decoding_failure(s));
     };
     Ok(tuple)
 }
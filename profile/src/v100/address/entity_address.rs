@@ -44,6 +44,7 @@ pub trait EntityAddress: Sized {
                 virtual_identity_address_from_public_key(&public_key)
             }
             AbstractEntityType::Resource => panic!("resource"),
+            AbstractEntityType::Pool => panic!("pool"),
         };
 
         let address = Self::address_from_node_id(
@@ -15,7 +15,6 @@ use crate::prelude::*;
     SerializeDisplay,
     DeserializeFromStr,
     derive_more::Display,
-    uniffi::Record,
 )]
 #[display("{address}")]
 pub struct IdentityAddress {
@@ -98,6 +97,28 @@ impl HasPlaceholder for IdentityAddress {
     }
 }
 
+uniffi::custom_type!(IdentityAddress, String);
+/// Crosses the FFI boundary as its bech32 string, validated when lifted.
+impl crate::UniffiCustomTypeConverter for IdentityAddress {
+    type Builtin = String;
+
+    #[cfg(not(tarpaulin_include))] // false negative | tested in bindgen tests
+    fn into_custom(val: Self::Builtin) -> uniffi::Result<Self> {
+        val.parse::<Self>().map_err(|e| e.into())
+    }
+
+    #[cfg(not(tarpaulin_include))] // false negative | tested in bindgen tests
+    fn from_custom(obj: Self) -> Self::Builtin {
+        obj.to_string()
+    }
+}
+
+/// The network the IdentityAddress is tied to.
+#[uniffi::export]
+pub fn identity_address_network_id(address: &IdentityAddress) -> NetworkID {
+    address.network_id
+}
+
 impl FromStr for IdentityAddress {
     type Err = CommonError;
 
@@ -192,7 +213,9 @@ mod tests {
     fn invalid() {
         assert_eq!(
             IdentityAddress::try_from_bech32("x"),
-            Err(CommonError::FailedToDecodeAddressFromBech32("x".to_owned()))
+            Err(CommonError::AddressMissingSeparator {
+                input: "x".to_owned()
+            })
         )
     }
 
@@ -201,7 +224,10 @@ mod tests {
         let s = "identity_rdx12tgzjrz9u0xz4l28vf04hz87eguclmfaq4d2p8f8lv7zg9ssnzku8x";
         assert_eq!(
             IdentityAddress::try_from_bech32(s),
-            Err(CommonError::FailedToDecodeAddressFromBech32(s.to_owned()))
+            Err(CommonError::InvalidAddressChecksumAtPosition {
+                position: 66,
+                input: s.to_owned()
+            })
         )
     }
 
@@ -244,3 +270,18 @@ mod tests {
         assert_json_value_fails::<IdentityAddress>(json!("super invalid"));
     }
 }
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn network_id() {
+        assert_eq!(
+            identity_address_network_id(
+                &IdentityAddress::placeholder_stokenet()
+            ),
+            NetworkID::Stokenet
+        );
+    }
+}
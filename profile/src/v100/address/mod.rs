@@ -1,15 +1,23 @@
 mod account_address;
+mod address_decoding_diagnostics;
+mod address_of_account_or_persona;
+mod address_qr_payload;
 mod decode_address_helper;
 mod entity_address;
 mod identity_address;
 mod non_fungible_global_id;
 mod non_fungible_local_id;
+mod pool_address;
 mod resource_address;
 
 pub use account_address::*;
+pub(crate) use address_decoding_diagnostics::*;
+pub use address_of_account_or_persona::*;
+pub use address_qr_payload::*;
 pub use decode_address_helper::*;
 pub use entity_address::*;
 pub use identity_address::*;
 pub use non_fungible_global_id::*;
 pub use non_fungible_local_id::*;
+pub use pool_address::*;
 pub use resource_address::*;
@@ -24,6 +24,28 @@ pub struct NonFungibleGlobalId {
     pub non_fungible_local_id: NonFungibleLocalId,
 }
 
+#[uniffi::export]
+pub fn new_non_fungible_global_id(
+    resource_address: ResourceAddress,
+    local_id: NonFungibleLocalId,
+) -> Result<NonFungibleGlobalId> {
+    NonFungibleGlobalId::new(resource_address, local_id)
+}
+
+#[uniffi::export]
+pub fn new_non_fungible_global_id_from_string(
+    string: String,
+) -> Result<NonFungibleGlobalId> {
+    NonFungibleGlobalId::from_str(string.as_str())
+}
+
+#[uniffi::export]
+pub fn non_fungible_global_id_to_string(
+    global_id: &NonFungibleGlobalId,
+) -> String {
+    global_id.to_canonical_string()
+}
+
 impl From<ResourceAddress> for radix_engine_common::types::ResourceAddress {
     fn from(value: ResourceAddress) -> Self {
         radix_engine_common::types::ResourceAddress::try_from_bech32(
@@ -35,6 +57,20 @@ impl From<ResourceAddress> for radix_engine_common::types::ResourceAddress {
 }
 
 impl NonFungibleGlobalId {
+    /// Instantiates a new `NonFungibleGlobalId`, returns `Err` if the
+    /// `non_fungible_local_id` is invalid.
+    pub fn new(
+        resource_address: ResourceAddress,
+        non_fungible_local_id: NonFungibleLocalId,
+    ) -> Result<Self> {
+        non_fungible_local_id
+            .validated()
+            .map(|non_fungible_local_id| Self {
+                resource_address,
+                non_fungible_local_id,
+            })
+    }
+
     fn from_internal_engine(
         internal: EngineSerializableNonFungibleGlobalIdInternal,
     ) -> Self {
@@ -236,3 +272,59 @@ mod tests {
         assert_eq!(set.len(), 2);
     }
 }
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn new_with_valid_local_id() {
+        let resource_address: ResourceAddress =
+            "resource_rdx1n2ekdd2m0jsxjt9wasmu3p49twy2yfalpaa6wf08md46sk8dfmldnd"
+                .parse()
+                .unwrap();
+        let sut = new_non_fungible_global_id(
+            resource_address,
+            NonFungibleLocalId::integer(2244),
+        )
+        .unwrap();
+        assert_eq!(
+            non_fungible_global_id_to_string(&sut),
+            "resource_rdx1n2ekdd2m0jsxjt9wasmu3p49twy2yfalpaa6wf08md46sk8dfmldnd:#2244#"
+        );
+    }
+
+    #[test]
+    fn new_with_invalid_local_id() {
+        let resource_address: ResourceAddress =
+            "resource_rdx1n2ekdd2m0jsxjt9wasmu3p49twy2yfalpaa6wf08md46sk8dfmldnd"
+                .parse()
+                .unwrap();
+        assert_eq!(
+            new_non_fungible_global_id(
+                resource_address,
+                NonFungibleLocalId::Str {
+                    value: "invalid string".to_owned()
+                },
+            ),
+            Err(CommonError::InvalidNonFungibleLocalIDString)
+        );
+    }
+
+    #[test]
+    fn from_string() {
+        let str = "resource_rdx1n2ekdd2m0jsxjt9wasmu3p49twy2yfalpaa6wf08md46sk8dfmldnd:<foobar>";
+        assert_eq!(
+            new_non_fungible_global_id_from_string(str.to_owned())
+                .unwrap()
+                .to_string(),
+            str
+        );
+        assert_eq!(
+            new_non_fungible_global_id_from_string("super invalid".to_owned()),
+            Err(CommonError::InvalidNonFungibleGlobalID(
+                "super invalid".to_owned()
+            ))
+        );
+    }
+}
@@ -15,7 +15,84 @@ pub fn non_fungible_local_id_to_string(id: NonFungibleLocalId) -> String {
     id.to_string()
 }
 
+#[uniffi::export]
+pub fn new_non_fungible_local_id_from_string(
+    local_id: String,
+) -> Result<NonFungibleLocalId> {
+    NonFungibleLocalId::from_str(local_id.as_str())
+}
+
+#[uniffi::export]
+pub fn new_non_fungible_local_id_int(value: u64) -> NonFungibleLocalId {
+    NonFungibleLocalId::integer(value)
+}
+
+#[uniffi::export]
+pub fn new_non_fungible_local_id_string(
+    string: String,
+) -> Result<NonFungibleLocalId> {
+    NonFungibleLocalId::string(string)
+}
+
+#[uniffi::export]
+pub fn new_non_fungible_local_id_bytes(
+    bytes: BagOfBytes,
+) -> Result<NonFungibleLocalId> {
+    NonFungibleLocalId::bytes(bytes.to_vec())
+}
+
+#[uniffi::export]
+pub fn new_non_fungible_local_id_ruid(
+    bytes: BagOfBytes,
+) -> Result<NonFungibleLocalId> {
+    NonFungibleLocalId::ruid(bytes.to_vec())
+}
+
+#[uniffi::export]
+pub fn non_fungible_local_id_is_valid(id: NonFungibleLocalId) -> bool {
+    id.validated().is_ok()
+}
+
 impl NonFungibleLocalId {
+    /// Creates a new `Integer` local id, any `u64` is valid, formatted as
+    /// `#123#`.
+    pub fn integer(value: u64) -> Self {
+        Self::Integer { value }
+    }
+
+    /// Creates a new `Str` local id, formatted as `<value>`, valid strings
+    /// are 1-64 chars long and only contain `[_0-9a-zA-Z]`.
+    pub fn string(value: impl AsRef<str>) -> Result<Self> {
+        Self::Str {
+            value: value.as_ref().to_owned(),
+        }
+        .validated()
+    }
+
+    /// Creates a new `Bytes` local id, formatted as hex `[dead]`, valid
+    /// byte counts are 1-64.
+    pub fn bytes(value: impl AsRef<[u8]>) -> Result<Self> {
+        Self::Bytes {
+            value: value.as_ref().into(),
+        }
+        .validated()
+    }
+
+    /// Creates a new `Ruid` local id, formatted as four dash separated
+    /// groups of hex `{deadbeef12345678-...}`, must be exactly 32 bytes.
+    pub fn ruid(value: impl AsRef<[u8]>) -> Result<Self> {
+        Self::Ruid {
+            value: value.as_ref().into(),
+        }
+        .validated()
+    }
+
+    /// Returns `self` if it is a valid local id according to the rules of
+    /// the Radix Engine, else an error describing why it is invalid.
+    pub fn validated(self) -> Result<Self> {
+        NativeNonFungibleLocalId::try_from(self.clone()).map(|_| self)
+    }
+
     fn native(&self) -> NativeNonFungibleLocalId {
         NativeNonFungibleLocalId::try_from(self.clone()).unwrap()
     }
@@ -70,10 +147,16 @@ impl TryFrom<NonFungibleLocalId> for NativeNonFungibleLocalId {
 }
 
 impl std::str::FromStr for NonFungibleLocalId {
-    type Err = <NativeNonFungibleLocalId as std::str::FromStr>::Err;
+    type Err = CommonError;
 
-    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-        NativeNonFungibleLocalId::from_str(s).map(Into::into)
+    /// Parses a formatted local id, i.e. one of `#123#`, `<value>`,
+    /// `[dead]` or `{deadbeef12345678-...}`.
+    fn from_str(s: &str) -> Result<Self> {
+        NativeNonFungibleLocalId::from_str(s)
+            .map(Into::into)
+            .map_err(|_| {
+                CommonError::InvalidNonFungibleLocalIDFormat(s.to_owned())
+            })
     }
 }
 
@@ -146,7 +229,70 @@ mod tests {
 
     #[test]
     fn from_str_err() {
-        assert!(NonFungibleLocalId::from_str("no_angle_brackets").is_err());
+        assert_eq!(
+            NonFungibleLocalId::from_str("no_angle_brackets"),
+            Err(CommonError::InvalidNonFungibleLocalIDFormat(
+                "no_angle_brackets".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn from_str_integer_out_of_range() {
+        assert!(NonFungibleLocalId::from_str("#18446744073709551616#").is_err());
+        assert_eq!(
+            NonFungibleLocalId::from_str("#18446744073709551615#"),
+            Ok(NonFungibleLocalId::integer(u64::MAX))
+        );
+    }
+
+    #[test]
+    fn string_valid() {
+        assert_eq!(
+            NonFungibleLocalId::string("foo_Bar_123"),
+            Ok(NonFungibleLocalId::Str {
+                value: "foo_Bar_123".to_owned()
+            })
+        );
+        assert!(NonFungibleLocalId::string("a".repeat(64)).is_ok());
+    }
+
+    #[test]
+    fn string_invalid() {
+        assert_eq!(
+            NonFungibleLocalId::string("has space"),
+            Err(CommonError::InvalidNonFungibleLocalIDString)
+        );
+        assert_eq!(
+            NonFungibleLocalId::string("emoji🚀"),
+            Err(CommonError::InvalidNonFungibleLocalIDString)
+        );
+        assert_eq!(
+            NonFungibleLocalId::string("a".repeat(65)),
+            Err(CommonError::InvalidNonFungibleLocalIDString)
+        );
+    }
+
+    #[test]
+    fn bytes_valid_and_invalid() {
+        assert!(NonFungibleLocalId::bytes([0xab; 64]).is_ok());
+        assert_eq!(
+            NonFungibleLocalId::bytes([0xab; 65]),
+            Err(CommonError::InvalidNonFungibleLocalIDBytes)
+        );
+    }
+
+    #[test]
+    fn ruid_valid_and_invalid() {
+        assert!(NonFungibleLocalId::ruid([0xab; 32]).is_ok());
+        assert_eq!(
+            NonFungibleLocalId::ruid([0xab; 31]),
+            Err(CommonError::InvalidLength {
+                expected: 32,
+                found: 31,
+                data: vec![0xab; 31]
+            })
+        );
     }
 
     #[test]
@@ -261,6 +407,63 @@ mod tests {
         );
     }
 
+    #[test]
+    fn from_string() {
+        assert_eq!(
+            new_non_fungible_local_id_from_string("#1234#".to_owned()),
+            Ok(NonFungibleLocalId::Integer { value: 1234 })
+        );
+        assert!(
+            new_non_fungible_local_id_from_string("1234".to_owned()).is_err()
+        );
+    }
+
+    #[test]
+    fn new_int() {
+        assert_eq!(new_non_fungible_local_id_int(1234).to_string(), "#1234#");
+    }
+
+    #[test]
+    fn new_string() {
+        assert_eq!(
+            new_non_fungible_local_id_string("foo".to_owned())
+                .unwrap()
+                .to_string(),
+            "<foo>"
+        );
+        assert!(new_non_fungible_local_id_string("".to_owned()).is_err());
+    }
+
+    #[test]
+    fn new_bytes() {
+        assert_eq!(
+            new_non_fungible_local_id_bytes(vec![0xde, 0xad].into())
+                .unwrap()
+                .to_string(),
+            "[dead]"
+        );
+        assert!(new_non_fungible_local_id_bytes(BagOfBytes::new()).is_err());
+    }
+
+    #[test]
+    fn new_ruid() {
+        assert!(
+            new_non_fungible_local_id_ruid(BagOfBytes::placeholder_dead())
+                .is_ok()
+        );
+        assert!(new_non_fungible_local_id_ruid(BagOfBytes::new()).is_err());
+    }
+
+    #[test]
+    fn is_valid() {
+        assert!(non_fungible_local_id_is_valid(NonFungibleLocalId::integer(
+            1
+        )));
+        assert!(!non_fungible_local_id_is_valid(NonFungibleLocalId::Str {
+            value: "no spaces allowed".to_owned()
+        }));
+    }
+
     #[test]
     fn display_rui() {
         assert_eq!(
@@ -16,7 +16,6 @@ use crate::prelude::*;
     SerializeDisplay,
     DeserializeFromStr,
     derive_more::Display,
-    uniffi::Record,
 )]
 #[display("{address}")]
 pub struct ResourceAddress {
@@ -49,6 +48,65 @@ impl EntityAddress for ResourceAddress {
     }
 }
 
+uniffi::custom_type!(ResourceAddress, String);
+/// Crosses the FFI boundary as its bech32 string, validated when lifted.
+impl crate::UniffiCustomTypeConverter for ResourceAddress {
+    type Builtin = String;
+
+    #[cfg(not(tarpaulin_include))] // false negative | tested in bindgen tests
+    fn into_custom(val: Self::Builtin) -> uniffi::Result<Self> {
+        val.parse::<Self>().map_err(|e| e.into())
+    }
+
+    #[cfg(not(tarpaulin_include))] // false negative | tested in bindgen tests
+    fn from_custom(obj: Self) -> Self::Builtin {
+        obj.to_string()
+    }
+}
+
+/// The network the ResourceAddress is tied to.
+#[uniffi::export]
+pub fn resource_address_network_id(address: &ResourceAddress) -> NetworkID {
+    address.network_id
+}
+
+impl ResourceAddress {
+    /// The address of XRD, the native token of Radix, on `network_id`.
+    pub fn xrd_on_network(network_id: NetworkID) -> Self {
+        let address = Self::address_from_node_id(
+            radix_engine_common::constants::XRD.into_node_id(),
+            network_id.discriminant(),
+        );
+        Self::__with_address_and_network_id(&address, network_id)
+    }
+
+    /// `true` if this is the address of XRD on its network.
+    pub fn is_xrd(&self) -> bool {
+        self == &Self::xrd_on_network(self.network_id)
+    }
+
+    /// `true` if this is the address of a fungible resource, `false` if it
+    /// is the address of a non-fungible resource.
+    pub fn is_fungible(&self) -> bool {
+        radix_engine_common::types::ResourceAddress::from(self.clone())
+            .is_fungible()
+    }
+}
+
+/// `true` if `address` is the address of a fungible resource.
+#[uniffi::export]
+pub fn resource_address_is_fungible(address: &ResourceAddress) -> bool {
+    address.is_fungible()
+}
+
+/// The address of XRD on `network_id`.
+#[uniffi::export]
+pub fn new_resource_address_xrd_on_network(
+    network_id: NetworkID,
+) -> ResourceAddress {
+    ResourceAddress::xrd_on_network(network_id)
+}
+
 impl FromStr for ResourceAddress {
     type Err = CommonError;
 
@@ -106,6 +164,43 @@ mod tests {
         assert_eq!(a.network_id, NetworkID::Stokenet);
     }
 
+    #[test]
+    fn xrd_on_mainnet() {
+        assert_eq!(
+            ResourceAddress::xrd_on_network(NetworkID::Mainnet).to_string(),
+            "resource_rdx1tknxxxxxxxxxradxrdxxxxxxxxx009923554798xxxxxxxxxradxrd"
+        );
+    }
+
+    #[test]
+    fn xrd_on_stokenet() {
+        let sut = new_resource_address_xrd_on_network(NetworkID::Stokenet);
+        assert_eq!(sut.network_id, NetworkID::Stokenet);
+        assert_eq!(sut.to_string().parse::<ResourceAddress>(), Ok(sut));
+    }
+
+    #[test]
+    fn is_xrd() {
+        assert!(ResourceAddress::xrd_on_network(NetworkID::Stokenet).is_xrd());
+        let other: ResourceAddress =
+            "resource_tdx_2_1tkckx9fynl9f7756z8wxphq7wce6vk874nuq4f2nnxgh3nzrwhjdlp"
+                .parse()
+                .unwrap();
+        assert!(!other.is_xrd());
+    }
+
+    #[test]
+    fn is_fungible() {
+        assert!(
+            ResourceAddress::xrd_on_network(NetworkID::Mainnet).is_fungible()
+        );
+        let non_fungible: ResourceAddress =
+            "resource_sim1ngktvyeenvvqetnqwysevcx5fyvl6hqe36y3rkhdfdn6uzvt5366ha"
+                .parse()
+                .unwrap();
+        assert!(!non_fungible.is_fungible());
+    }
+
     #[test]
     fn network_id_mainnet() {
         let a: ResourceAddress =
@@ -118,7 +213,10 @@ mod tests {
 
 #[cfg(test)]
 mod uniffi_tests {
-    use crate::{new_resource_address, EntityAddress};
+    use crate::{
+        new_resource_address, resource_address_is_fungible,
+        resource_address_network_id, EntityAddress, NetworkID,
+    };
 
     use super::ResourceAddress;
 
@@ -130,4 +228,24 @@ mod uniffi_tests {
         assert_eq!(b.address, s);
         assert_eq!(a, b);
     }
+
+    #[test]
+    fn network_id() {
+        let sut = new_resource_address(
+            "resource_rdx1tknxxxxxxxxxradxrdxxxxxxxxx009923554798xxxxxxxxxradxrd"
+                .to_string(),
+        )
+        .unwrap();
+        assert_eq!(resource_address_network_id(&sut), NetworkID::Mainnet);
+    }
+
+    #[test]
+    fn is_fungible() {
+        let sut = new_resource_address(
+            "resource_rdx1tknxxxxxxxxxradxrdxxxxxxxxx009923554798xxxxxxxxxradxrd"
+                .to_string(),
+        )
+        .unwrap();
+        assert!(resource_address_is_fungible(&sut));
+    }
 }
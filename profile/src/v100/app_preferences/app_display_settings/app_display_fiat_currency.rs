@@ -12,12 +12,29 @@ use crate::prelude::*;
     Hash,
     PartialOrd,
     Ord,
+    enum_iterator::Sequence,
     uniffi::Enum,
 )]
 pub enum FiatCurrency {
     /// American dollars.
     #[serde(rename = "usd")]
     USD,
+
+    /// Euros.
+    #[serde(rename = "eur")]
+    EUR,
+
+    /// British pounds.
+    #[serde(rename = "gbp")]
+    GBP,
+
+    /// Swedish kronor.
+    #[serde(rename = "sek")]
+    SEK,
+
+    /// Japanese yen.
+    #[serde(rename = "jpy")]
+    JPY,
 }
 
 impl Default for FiatCurrency {
@@ -27,6 +44,34 @@ impl Default for FiatCurrency {
     }
 }
 
+impl FiatCurrency {
+    /// The symbol of the currency, e.g. `"$"` for `USD`.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Self::USD => "$",
+            Self::EUR => "€",
+            Self::GBP => "£",
+            Self::SEK => "kr",
+            Self::JPY => "¥",
+        }
+    }
+
+    /// `true` if the symbol is put before the amount, e.g. `"$1.00"`, else
+    /// it is put after it separated by a space, e.g. `"1.00 kr"`.
+    pub fn is_symbol_prefix(&self) -> bool {
+        !matches!(self, Self::SEK)
+    }
+
+    /// The number of fractional digits amounts in this currency are
+    /// displayed with, e.g. `2` for `USD` and `0` for `JPY`.
+    pub fn fraction_digits(&self) -> usize {
+        match self {
+            Self::JPY => 0,
+            _ => 2,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
@@ -38,4 +83,30 @@ mod tests {
             FiatCurrency::USD
         );
     }
+
+    #[test]
+    fn json_roundtrip() {
+        assert_json_value_eq_after_roundtrip(&FiatCurrency::USD, json!("usd"));
+        assert_json_value_eq_after_roundtrip(&FiatCurrency::EUR, json!("eur"));
+        assert_json_value_eq_after_roundtrip(&FiatCurrency::GBP, json!("gbp"));
+        assert_json_value_eq_after_roundtrip(&FiatCurrency::SEK, json!("sek"));
+        assert_json_value_eq_after_roundtrip(&FiatCurrency::JPY, json!("jpy"));
+    }
+
+    #[test]
+    fn json_fails_for_unknown() {
+        assert_json_value_fails::<FiatCurrency>(json!("USD"));
+        assert_json_value_fails::<FiatCurrency>(json!("btc"));
+    }
+
+    #[test]
+    fn symbols_are_unique() {
+        assert_eq!(
+            enum_iterator::all::<FiatCurrency>()
+                .map(|c| c.symbol())
+                .collect::<HashSet<_>>()
+                .len(),
+            enum_iterator::cardinality::<FiatCurrency>()
+        );
+    }
 }
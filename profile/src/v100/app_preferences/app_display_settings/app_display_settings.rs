@@ -38,7 +38,7 @@ impl HasPlaceholder for AppDisplay {
     fn placeholder_other() -> Self {
         Self {
             is_currency_amount_visible: false,
-            fiat_currency_price_target: FiatCurrency::default(),
+            fiat_currency_price_target: FiatCurrency::EUR,
         }
     }
 }
@@ -78,4 +78,18 @@ mod tests {
             "#,
         )
     }
+
+    #[test]
+    fn json_roundtrip_other() {
+        let sut = AppDisplay::placeholder_other();
+        assert_eq_after_json_roundtrip(
+            &sut,
+            r#"
+            {
+                "fiatCurrencyPriceTarget": "eur",
+                "isCurrencyAmountVisible": false
+            }
+            "#,
+        )
+    }
 }
@@ -0,0 +1,231 @@
+use crate::prelude::*;
+
+/// The separators to use when formatting numbers, provided by the Wallet
+/// Client based on the locale of the user.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct NumberFormattingLocale {
+    /// Separates the integer part from the fractional part, e.g. `"."`.
+    pub decimal_separator: String,
+
+    /// Separates groups of thousands in the integer part, e.g. `","`.
+    pub grouping_separator: String,
+}
+
+impl NumberFormattingLocale {
+    pub fn new(
+        decimal_separator: impl AsRef<str>,
+        grouping_separator: impl AsRef<str>,
+    ) -> Self {
+        Self {
+            decimal_separator: decimal_separator.as_ref().to_owned(),
+            grouping_separator: grouping_separator.as_ref().to_owned(),
+        }
+    }
+
+    /// The separators used in the `en_US` locale, e.g. `"1,234.56"`.
+    pub fn en_us() -> Self {
+        Self::new(".", ",")
+    }
+
+    /// The separators used in the `sv_SE` locale, e.g. `"1 234,56"`.
+    pub fn sv_se() -> Self {
+        Self::new(",", " ")
+    }
+}
+
+impl Default for NumberFormattingLocale {
+    fn default() -> Self {
+        Self::en_us()
+    }
+}
+
+impl HasPlaceholder for NumberFormattingLocale {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::en_us()
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::sv_se()
+    }
+}
+
+/// Rounds the non negative base10 `digits` (without sign) to
+/// `fraction_digits` fractional digits, rounding half away from zero,
+/// returning the integer and fractional digits.
+fn round_half_away_from_zero(
+    digits: &str,
+    fraction_digits: usize,
+) -> (String, String) {
+    let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+    let mut fraction = fraction.to_owned();
+    while fraction.len() <= fraction_digits {
+        fraction.push('0');
+    }
+    let round_up = fraction.as_bytes()[fraction_digits] >= b'5';
+
+    let mut kept =
+        format!("{}{}", integer, &fraction[..fraction_digits]).into_bytes();
+    if round_up {
+        let mut carry = true;
+        for digit in kept.iter_mut().rev() {
+            if *digit == b'9' {
+                *digit = b'0';
+            } else {
+                *digit += 1;
+                carry = false;
+                break;
+            }
+        }
+        if carry {
+            kept.insert(0, b'1');
+        }
+    }
+    let kept = String::from_utf8(kept).expect("Digits are always UTF-8");
+    let split = kept.len() - fraction_digits;
+    (kept[..split].to_owned(), kept[split..].to_owned())
+}
+
+/// Inserts `separator` between each group of three digits, from the right.
+fn group_thousands(integer: &str, separator: &str) -> String {
+    integer
+        .as_bytes()
+        .rchunks(3)
+        .rev()
+        .map(|c| std::str::from_utf8(c).expect("Digits are always UTF-8"))
+        .join(separator)
+}
+
+/// Formats `amount` in `currency`, rounded to the number of fraction digits
+/// of the currency and using the separators of `locale`, e.g.
+/// `"$1,234.56"`, `"-€0.50"`, `"1 234,56 kr"` or `"¥1,235"`.
+#[uniffi::export]
+pub fn format_fiat(
+    amount: Decimal,
+    currency: FiatCurrency,
+    locale: NumberFormattingLocale,
+) -> String {
+    let base10 = amount.to_string();
+    let (is_negative, digits) = match base10.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, base10.as_str()),
+    };
+    let (integer, fraction) =
+        round_half_away_from_zero(digits, currency.fraction_digits());
+
+    let mut number =
+        group_thousands(&integer, locale.grouping_separator.as_str());
+    if !fraction.is_empty() {
+        number.push_str(&locale.decimal_separator);
+        number.push_str(&fraction);
+    }
+
+    let is_zero = integer.chars().chain(fraction.chars()).all(|c| c == '0');
+    let sign = if is_negative && !is_zero { "-" } else { "" };
+
+    if currency.is_symbol_prefix() {
+        format!("{}{}{}", sign, currency.symbol(), number)
+    } else {
+        format!("{}{} {}", sign, number, currency.symbol())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn format(amount: &str, currency: FiatCurrency) -> String {
+        format_fiat(
+            Decimal::try_from_str(amount).unwrap(),
+            currency,
+            NumberFormattingLocale::en_us(),
+        )
+    }
+
+    #[test]
+    fn usd() {
+        assert_eq!(format("1234.56", FiatCurrency::USD), "$1,234.56");
+    }
+
+    #[test]
+    fn pads_fraction() {
+        assert_eq!(format("1", FiatCurrency::EUR), "€1.00");
+        assert_eq!(format("0.5", FiatCurrency::GBP), "£0.50");
+    }
+
+    #[test]
+    fn rounds_half_away_from_zero() {
+        assert_eq!(format("0.005", FiatCurrency::USD), "$0.01");
+        assert_eq!(format("0.0049", FiatCurrency::USD), "$0.00");
+        assert_eq!(format("-0.005", FiatCurrency::USD), "-$0.01");
+    }
+
+    #[test]
+    fn rounding_carries() {
+        assert_eq!(format("999.995", FiatCurrency::USD), "$1,000.00");
+        assert_eq!(format("9.5", FiatCurrency::JPY), "¥10");
+    }
+
+    #[test]
+    fn negative() {
+        assert_eq!(format("-1234.5", FiatCurrency::USD), "-$1,234.50");
+    }
+
+    #[test]
+    fn negative_rounded_to_zero_has_no_sign() {
+        assert_eq!(format("-0.001", FiatCurrency::USD), "$0.00");
+    }
+
+    #[test]
+    fn jpy_has_no_fraction() {
+        assert_eq!(format("1234567.4", FiatCurrency::JPY), "¥1,234,567");
+    }
+
+    #[test]
+    fn sek_suffix_symbol_and_locale() {
+        assert_eq!(
+            format_fiat(
+                Decimal::try_from_str("1234567.891").unwrap(),
+                FiatCurrency::SEK,
+                NumberFormattingLocale::sv_se()
+            ),
+            "1 234 567,89 kr"
+        );
+    }
+
+    #[test]
+    fn large_amount() {
+        assert_eq!(
+            format(
+                "3138550867693340381917894711603833208051.177722232017256447",
+                FiatCurrency::USD
+            ),
+            "$3,138,550,867,693,340,381,917,894,711,603,833,208,051.18"
+        );
+    }
+
+    #[test]
+    fn sv_se_locale() {
+        assert_eq!(
+            format_fiat(
+                Decimal::one(),
+                FiatCurrency::EUR,
+                NumberFormattingLocale::sv_se()
+            ),
+            "€1,00"
+        );
+    }
+
+    #[test]
+    fn default_locale_is_en_us() {
+        assert_eq!(
+            NumberFormattingLocale::default(),
+            NumberFormattingLocale::placeholder()
+        );
+        assert_ne!(
+            NumberFormattingLocale::placeholder(),
+            NumberFormattingLocale::placeholder_other()
+        );
+    }
+}
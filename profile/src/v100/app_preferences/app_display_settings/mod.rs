@@ -1,5 +1,7 @@
 mod app_display_fiat_currency;
 mod app_display_settings;
+mod fiat_formatting;
 
 pub use app_display_fiat_currency::*;
 pub use app_display_settings::*;
+pub use fiat_formatting::*;
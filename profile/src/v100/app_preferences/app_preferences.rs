@@ -34,6 +34,20 @@ pub struct AppPreferences {
 
     /// Default config related to making of transactions
     pub transaction: TransactionPreferences,
+
+    /// Host side feature flags and experiment settings which roam with the
+    /// Profile, omitted if there are none.
+    #[serde(
+        default,
+        skip_serializing_if = "UserFlags::is_empty",
+        deserialize_with = "deserialize_validated_user_flags"
+    )]
+    pub user_flags: UserFlags,
+
+    /// Fields unknown to this version, written by a newer version of the
+    /// Profile format, re-emitted as-is when encoded.
+    #[serde(flatten)]
+    pub extra: UnknownFields,
 }
 
 #[uniffi::export]
@@ -60,6 +74,8 @@ impl AppPreferences {
             p2p_links,
             security,
             transaction,
+            user_flags: UserFlags::default(),
+            extra: UnknownFields::default(),
         }
     }
 }
@@ -152,6 +168,38 @@ mod tests {
         )
     }
 
+    #[test]
+    fn user_flags_are_empty_by_default() {
+        assert!(AppPreferences::placeholder().user_flags.is_empty());
+        assert!(serde_json::to_value(AppPreferences::placeholder())
+            .unwrap()
+            .get("userFlags")
+            .is_none());
+    }
+
+    #[test]
+    fn json_roundtrip_with_user_flags() {
+        let mut sut = AppPreferences::placeholder();
+        sut.user_flags = UserFlags::placeholder();
+        let json = serde_json::to_value(&sut).unwrap();
+        assert_eq!(
+            json["userFlags"],
+            json!([
+                { "key": "home.show_new_card_layout", "value": true },
+                { "key": "onboarding.variant", "value": "b" }
+            ])
+        );
+        assert_json_roundtrip(&sut);
+    }
+
+    #[test]
+    fn json_invalid_user_flags_fails() {
+        let mut json =
+            serde_json::to_value(AppPreferences::placeholder()).unwrap();
+        json["userFlags"] = json!([{ "key": "INVALID", "value": true }]);
+        assert_json_value_fails::<AppPreferences>(json);
+    }
+
     #[test]
     fn json_roundtrip() {
         let sut = AppPreferences::placeholder();
@@ -45,8 +45,7 @@ impl Gateway {
         url: String,
         id: NetworkID,
     ) -> Result<Arc<Self>, crate::CommonError> {
-        let url = Url::try_from(url.as_str())
-            .map_err(|_| CommonError::InvalidURL(url))?;
+        let url = url_with_trailing_slash(parse_web_url(url)?);
         let network = NetworkDefinition::lookup_by_id(id)?;
         Ok(Self { url, network }.into())
     }
@@ -197,6 +196,24 @@ mod tests {
         assert_eq!(Gateway::mainnet().id(), Gateway::mainnet().url);
     }
 
+    #[test]
+    fn new_normalizes_trailing_slash() {
+        let sut = Gateway::new(
+            "https://example.com/api".to_owned(),
+            NetworkID::Mainnet,
+        )
+        .unwrap();
+        assert_eq!(sut.url.as_str(), "https://example.com/api/");
+    }
+
+    #[test]
+    fn new_rejects_non_web_url() {
+        assert_eq!(
+            Gateway::new("ftp://example.com".to_owned(), NetworkID::Mainnet),
+            Err(CommonError::InvalidURL("ftp://example.com".to_owned()))
+        );
+    }
+
     #[test]
     fn mainnet_is_default() {
         assert_eq!(Gateway::default(), Gateway::mainnet());
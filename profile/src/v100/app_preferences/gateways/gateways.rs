@@ -89,7 +89,7 @@ impl<'de> Deserialize<'de> for Gateways {
 
         let mut other = wrapped.saved.clone();
 
-        other.remove(&current);
+        other.remove_by_id(&current.id());
 
         Gateways::new_with_other(current, other.items())
             .map_err(de::Error::custom)
@@ -4,6 +4,7 @@ mod gateways;
 mod p2p_links;
 mod security;
 mod transaction_preferences;
+mod user_flags;
 
 pub use app_display_settings::*;
 pub use app_preferences::*;
@@ -11,3 +12,4 @@ pub use gateways::*;
 pub use p2p_links::*;
 pub use security::*;
 pub use transaction_preferences::*;
+pub use user_flags::*;
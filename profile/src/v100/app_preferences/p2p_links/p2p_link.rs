@@ -20,7 +20,7 @@ use radix_engine_common::crypto::Hash;
 )]
 #[serde(rename_all = "camelCase")]
 #[debug(
-    "P2PLink {{ display_name: '{display_name}', connection_password: '{connection_password}' }}"
+    "P2PLink {{ display_name: '{display_name}', connection_password: {connection_password:?} }}"
 )]
 #[display("{}", self.to_obfuscated_string())]
 pub struct P2PLink {
@@ -172,7 +172,7 @@ mod tests {
 
     #[test]
     fn debug() {
-        assert_eq!(format!("{:?}", P2PLink::placeholder()), "P2PLink { display_name: 'Chrome on Macbook', connection_password: 'cafecafecafecafecafecafecafecafecafecafecafecafecafecafecafecafe' }");
+        assert_eq!(format!("{:?}", P2PLink::placeholder()), "P2PLink { display_name: 'Chrome on Macbook', connection_password: <REDACTED 32 bytes> }");
     }
 
     #[test]
@@ -18,7 +18,7 @@ use radix_engine_common::crypto::Hash;
     uniffi::Record,
 )]
 #[serde(transparent)]
-#[debug("{value}")]
+#[debug("{}", self.redacted_debug())]
 pub struct RadixConnectPassword {
     pub value: Hex32Bytes,
 }
@@ -48,6 +48,17 @@ impl RadixConnectPassword {
     }
 }
 
+impl SafeDebug for RadixConnectPassword {
+    fn redacted_debug(&self) -> String {
+        format!("<REDACTED {} bytes>", self.value.bytes().len())
+    }
+
+    #[cfg(feature = "danger-fully-reveal-debug")]
+    fn danger_fully_reveal_debug(&self) -> String {
+        self.value.to_string()
+    }
+}
+
 impl HasPlaceholder for RadixConnectPassword {
     /// A placeholder used to facilitate unit tests.
     fn placeholder() -> Self {
@@ -119,6 +130,15 @@ mod tests {
     fn debug() {
         assert_eq!(
             format!("{:?}", RadixConnectPassword::placeholder()),
+            "<REDACTED 32 bytes>"
+        );
+    }
+
+    #[cfg(feature = "danger-fully-reveal-debug")]
+    #[test]
+    fn danger_fully_reveal_debug() {
+        assert_eq!(
+            RadixConnectPassword::placeholder().danger_fully_reveal_debug(),
             "deaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddead"
         );
     }
@@ -0,0 +1,9 @@
+mod user_flag;
+mod user_flag_key;
+mod user_flag_value;
+mod user_flags;
+
+pub use user_flag::*;
+pub use user_flag_key::*;
+pub use user_flag_value::*;
+pub use user_flags::*;
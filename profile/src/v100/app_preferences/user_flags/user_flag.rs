@@ -0,0 +1,75 @@
+use crate::prelude::*;
+
+/// A host side feature flag or experiment setting which roams with the
+/// Profile, see [`UserFlags`].
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+pub struct UserFlag {
+    pub key: UserFlagKey,
+    pub value: UserFlagValue,
+}
+
+impl UserFlag {
+    pub fn new(key: UserFlagKey, value: impl Into<UserFlagValue>) -> Self {
+        Self {
+            key,
+            value: value.into(),
+        }
+    }
+}
+
+impl Identifiable for UserFlag {
+    type ID = UserFlagKey;
+
+    fn id(&self) -> Self::ID {
+        self.key.clone()
+    }
+}
+
+impl HasPlaceholder for UserFlag {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::new(UserFlagKey::placeholder(), UserFlagValue::placeholder())
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::new(
+            UserFlagKey::placeholder_other(),
+            UserFlagValue::placeholder_other(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = UserFlag;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::placeholder(), SUT::placeholder());
+        assert_eq!(SUT::placeholder_other(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::placeholder(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        assert_eq_after_json_roundtrip(
+            &SUT::placeholder(),
+            r#"
+            {
+                "key": "home.show_new_card_layout",
+                "value": true
+            }
+            "#,
+        );
+    }
+}
@@ -0,0 +1,177 @@
+use crate::prelude::*;
+
+/// The key of a [`UserFlag`], a `name` within the `namespace` of the host
+/// feature or experiment owning it, formatted as `"namespace.name"`.
+///
+/// Both parts are non empty and consist of at most [`UserFlagKey::MAX_LEN`]
+/// lowercase ASCII letters, digits, `_` or `-`.
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    SerializeDisplay,
+    DeserializeFromStr,
+    derive_more::Display,
+    uniffi::Record,
+)]
+#[display("{namespace}.{name}")]
+pub struct UserFlagKey {
+    pub namespace: String,
+    pub name: String,
+}
+
+impl UserFlagKey {
+    /// The maximum length of the `namespace` and of the `name`.
+    pub const MAX_LEN: usize = 32;
+
+    pub fn new(
+        namespace: impl AsRef<str>,
+        name: impl AsRef<str>,
+    ) -> Result<Self> {
+        let namespace = namespace.as_ref();
+        let name = name.as_ref();
+        if !Self::is_valid_part(namespace) || !Self::is_valid_part(name) {
+            return Err(CommonError::InvalidUserFlagKey(format!(
+                "{namespace}.{name}"
+            )));
+        }
+        Ok(Self {
+            namespace: namespace.to_owned(),
+            name: name.to_owned(),
+        })
+    }
+
+    fn is_valid_part(part: &str) -> bool {
+        (1..=Self::MAX_LEN).contains(&part.len())
+            && part.chars().all(|c| {
+                c.is_ascii_lowercase()
+                    || c.is_ascii_digit()
+                    || c == '_'
+                    || c == '-'
+            })
+    }
+}
+
+impl FromStr for UserFlagKey {
+    type Err = CommonError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (namespace, name) = s
+            .split_once('.')
+            .ok_or_else(|| CommonError::InvalidUserFlagKey(s.to_owned()))?;
+        Self::new(namespace, name)
+    }
+}
+
+impl HasPlaceholder for UserFlagKey {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::new("home", "show_new_card_layout").unwrap()
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::new("onboarding", "variant").unwrap()
+    }
+}
+
+#[uniffi::export]
+pub fn new_user_flag_key(
+    namespace: String,
+    name: String,
+) -> Result<UserFlagKey> {
+    UserFlagKey::new(namespace, name)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = UserFlagKey;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::placeholder(), SUT::placeholder());
+        assert_eq!(SUT::placeholder_other(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::placeholder(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(SUT::placeholder().to_string(), "home.show_new_card_layout");
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!(
+            "home.show_new_card_layout".parse::<SUT>(),
+            Ok(SUT::placeholder())
+        );
+    }
+
+    #[test]
+    fn from_str_without_namespace_fails() {
+        assert_eq!(
+            "variant".parse::<SUT>(),
+            Err(CommonError::InvalidUserFlagKey("variant".to_owned()))
+        );
+    }
+
+    #[test]
+    fn invalid_characters_fail() {
+        assert!(SUT::new("Home", "x").is_err());
+        assert!(SUT::new("home", "a.b").is_err());
+        assert!(SUT::new("home", "a b").is_err());
+    }
+
+    #[test]
+    fn empty_parts_fail() {
+        assert!(SUT::new("", "x").is_err());
+        assert!(SUT::new("home", "").is_err());
+    }
+
+    #[test]
+    fn max_len() {
+        let max = "a".repeat(SUT::MAX_LEN);
+        assert!(SUT::new(&max, &max).is_ok());
+        assert!(SUT::new(&max, format!("{max}a")).is_err());
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        assert_json_value_eq_after_roundtrip(
+            &SUT::placeholder(),
+            json!("home.show_new_card_layout"),
+        );
+    }
+
+    #[test]
+    fn json_invalid_fails() {
+        assert_json_value_fails::<SUT>(json!("Home.x"));
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn new() {
+        assert_eq!(
+            new_user_flag_key(
+                "home".to_owned(),
+                "show_new_card_layout".to_owned()
+            ),
+            Ok(UserFlagKey::placeholder())
+        );
+    }
+}
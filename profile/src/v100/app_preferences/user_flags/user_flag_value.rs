@@ -0,0 +1,165 @@
+use crate::prelude::*;
+
+/// The small typed value of a [`UserFlag`], encoded in JSON as a plain
+/// boolean, integer or string.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, EnumAsInner, uniffi::Enum)]
+pub enum UserFlagValue {
+    Bool {
+        value: bool,
+    },
+    Integer {
+        value: i64,
+    },
+
+    /// At most [`UserFlagValue::MAX_TEXT_LEN`] bytes of UTF-8.
+    Text {
+        value: String,
+    },
+}
+
+impl UserFlagValue {
+    /// The maximum length in bytes of a [`UserFlagValue::Text`], keeping
+    /// flags from being abused as arbitrary storage.
+    pub const MAX_TEXT_LEN: usize = 64;
+
+    /// Returns `self` if valid, else fails if it is a too long text.
+    pub fn validated(self) -> Result<Self> {
+        match &self {
+            Self::Text { value } if value.len() > Self::MAX_TEXT_LEN => {
+                Err(CommonError::UserFlagValueTooLong {
+                    expected: Self::MAX_TEXT_LEN,
+                    found: value.len(),
+                })
+            }
+            _ => Ok(self),
+        }
+    }
+}
+
+impl From<bool> for UserFlagValue {
+    fn from(value: bool) -> Self {
+        Self::Bool { value }
+    }
+}
+
+impl From<i64> for UserFlagValue {
+    fn from(value: i64) -> Self {
+        Self::Integer { value }
+    }
+}
+
+impl From<&str> for UserFlagValue {
+    fn from(value: &str) -> Self {
+        Self::Text {
+            value: value.to_owned(),
+        }
+    }
+}
+
+impl Serialize for UserFlagValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Bool { value } => serializer.serialize_bool(*value),
+            Self::Integer { value } => serializer.serialize_i64(*value),
+            Self::Text { value } => serializer.serialize_str(value),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for UserFlagValue {
+    #[cfg(not(tarpaulin_include))] // false negative
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Wrapper {
+            Bool(bool),
+            Integer(i64),
+            Text(String),
+        }
+        let value = match Wrapper::deserialize(deserializer)? {
+            Wrapper::Bool(value) => Self::Bool { value },
+            Wrapper::Integer(value) => Self::Integer { value },
+            Wrapper::Text(value) => Self::Text { value },
+        };
+        value.validated().map_err(de::Error::custom)
+    }
+}
+
+impl HasPlaceholder for UserFlagValue {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        true.into()
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        "b".into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = UserFlagValue;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::placeholder(), SUT::placeholder());
+        assert_eq!(SUT::placeholder_other(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::placeholder(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn json_roundtrip_bool() {
+        assert_json_value_eq_after_roundtrip(&SUT::from(true), json!(true));
+    }
+
+    #[test]
+    fn json_roundtrip_integer() {
+        assert_json_value_eq_after_roundtrip(&SUT::from(-42i64), json!(-42));
+    }
+
+    #[test]
+    fn json_roundtrip_text() {
+        assert_json_value_eq_after_roundtrip(&SUT::from("b"), json!("b"));
+    }
+
+    #[test]
+    fn json_other_types_fail() {
+        assert_json_value_fails::<SUT>(json!(1.5));
+        assert_json_value_fails::<SUT>(json!(null));
+        assert_json_value_fails::<SUT>(json!([true]));
+        assert_json_value_fails::<SUT>(json!({ "value": true }));
+    }
+
+    #[test]
+    fn json_too_long_text_fails() {
+        assert_json_value_fails::<SUT>(
+            json!("a".repeat(SUT::MAX_TEXT_LEN + 1)),
+        );
+    }
+
+    #[test]
+    fn validated() {
+        let max = "a".repeat(SUT::MAX_TEXT_LEN);
+        assert!(SUT::from(max.as_str()).validated().is_ok());
+        assert_eq!(
+            SUT::from(format!("{max}a").as_str()).validated(),
+            Err(CommonError::UserFlagValueTooLong {
+                expected: SUT::MAX_TEXT_LEN,
+                found: SUT::MAX_TEXT_LEN + 1
+            })
+        );
+    }
+}
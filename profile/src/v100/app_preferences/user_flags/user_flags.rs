@@ -0,0 +1,210 @@
+use crate::prelude::*;
+
+/// A small, namespaced key-value store for host side feature flags and
+/// experiments which must roam with the Profile, in the order the flags
+/// were first set.
+///
+/// Limited to [`UserFlags::MAX_COUNT`] flags with small values, so that it
+/// cannot be abused as arbitrary storage.
+pub type UserFlags = IdentifiedVecOf<UserFlag>;
+
+// Trait: Default
+impl Default for UserFlags {
+    /// Instantiates a new empty collection.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl UserFlags {
+    /// The maximum number of flags.
+    pub const MAX_COUNT: usize = 32;
+
+    /// The value of the flag with `key`, if set.
+    pub fn value_of(&self, key: &UserFlagKey) -> Option<UserFlagValue> {
+        self.get_id(key).map(|f| f.value.clone())
+    }
+
+    /// Sets `flag`, replacing the value of any flag with the same key.
+    ///
+    /// Fails if the value is invalid or if the flag is new and there
+    /// already are [`UserFlags::MAX_COUNT`] flags.
+    pub fn try_set(&mut self, flag: UserFlag) -> Result<()> {
+        let value = flag.value.validated()?;
+        if self.update_with(&flag.key, |f| f.value = value.clone()) {
+            return Ok(());
+        }
+        if self.len() >= Self::MAX_COUNT {
+            return Err(CommonError::TooManyUserFlags {
+                max: Self::MAX_COUNT,
+            });
+        }
+        self.append(UserFlag::new(flag.key, value));
+        Ok(())
+    }
+}
+
+/// Deserializes [`UserFlags`], failing if there are more than
+/// [`UserFlags::MAX_COUNT`], duplicate keys or invalid values.
+pub(crate) fn deserialize_validated_user_flags<'de, D>(
+    deserializer: D,
+) -> Result<UserFlags, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let flags = UserFlags::deserialize(deserializer)?;
+    if flags.len() > UserFlags::MAX_COUNT {
+        return Err(de::Error::custom(CommonError::TooManyUserFlags {
+            max: UserFlags::MAX_COUNT,
+        }));
+    }
+    Ok(flags)
+}
+
+impl HasPlaceholder for UserFlags {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::from_iter([
+            UserFlag::placeholder(),
+            UserFlag::placeholder_other(),
+        ])
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::from_iter([UserFlag::placeholder_other()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = UserFlags;
+
+    #[derive(Deserialize, Debug)]
+    struct Container {
+        #[serde(deserialize_with = "deserialize_validated_user_flags")]
+        flags: SUT,
+    }
+
+    fn key(i: usize) -> UserFlagKey {
+        UserFlagKey::new("test", format!("flag{i}")).unwrap()
+    }
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::placeholder(), SUT::placeholder());
+        assert_eq!(SUT::placeholder_other(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::placeholder(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn default_is_empty() {
+        assert!(SUT::default().is_empty());
+    }
+
+    #[test]
+    fn value_of() {
+        let sut = SUT::placeholder();
+        assert_eq!(
+            sut.value_of(&UserFlagKey::placeholder()),
+            Some(UserFlagValue::placeholder())
+        );
+        assert_eq!(sut.value_of(&key(0)), None);
+    }
+
+    #[test]
+    fn try_set_replaces_value_in_place() {
+        let mut sut = SUT::placeholder();
+        sut.try_set(UserFlag::new(UserFlagKey::placeholder(), false))
+            .unwrap();
+        assert_eq!(
+            sut.value_of(&UserFlagKey::placeholder()),
+            Some(false.into())
+        );
+        assert_eq!(sut.first().unwrap().key, UserFlagKey::placeholder());
+        assert_eq!(sut.len(), 2);
+    }
+
+    #[test]
+    fn try_set_too_long_text_fails() {
+        let mut sut = SUT::new();
+        let text = "a".repeat(UserFlagValue::MAX_TEXT_LEN + 1);
+        assert!(sut.try_set(UserFlag::new(key(0), text.as_str())).is_err());
+        assert!(sut.is_empty());
+    }
+
+    #[test]
+    fn try_set_too_many_fails() {
+        let mut sut = SUT::new();
+        for i in 0..SUT::MAX_COUNT {
+            sut.try_set(UserFlag::new(key(i), true)).unwrap();
+        }
+        assert_eq!(
+            sut.try_set(UserFlag::new(key(SUT::MAX_COUNT), true)),
+            Err(CommonError::TooManyUserFlags {
+                max: SUT::MAX_COUNT
+            })
+        );
+        // Updating an existing flag is still possible.
+        assert_eq!(sut.try_set(UserFlag::new(key(0), false)), Ok(()));
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        assert_eq_after_json_roundtrip(
+            &SUT::placeholder(),
+            r#"
+            [
+                {
+                    "key": "home.show_new_card_layout",
+                    "value": true
+                },
+                {
+                    "key": "onboarding.variant",
+                    "value": "b"
+                }
+            ]
+            "#,
+        );
+    }
+
+    #[test]
+    fn deserialize_too_many_fails() {
+        let flags = (0..=SUT::MAX_COUNT)
+            .map(|i| json!({ "key": key(i).to_string(), "value": true }))
+            .collect_vec();
+        assert!(
+            serde_json::from_value::<Container>(json!({ "flags": flags }))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn deserialize_max_count() {
+        let flags = (0..SUT::MAX_COUNT)
+            .map(|i| json!({ "key": key(i).to_string(), "value": true }))
+            .collect_vec();
+        let container =
+            serde_json::from_value::<Container>(json!({ "flags": flags }))
+                .unwrap();
+        assert_eq!(container.flags.len(), SUT::MAX_COUNT);
+    }
+
+    #[test]
+    fn deserialize_duplicate_keys_fails() {
+        assert!(serde_json::from_value::<Container>(json!({
+            "flags": [
+                { "key": "a.b", "value": true },
+                { "key": "a.b", "value": false }
+            ]
+        }))
+        .is_err());
+    }
+}
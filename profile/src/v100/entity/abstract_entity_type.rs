@@ -25,6 +25,8 @@ pub enum AbstractEntityType {
     Identity,
     /// Resource address
     Resource,
+    /// Liquidity pool address, of any kind of pool.
+    Pool,
 }
 impl AbstractEntityType {
     /// Conversion of the Radix Engines type for EntityType to Self.
@@ -37,9 +39,13 @@ impl AbstractEntityType {
             EngineEntityType::GlobalVirtualEd25519Identity => {
                 Ok(Self::Identity)
             }
-            EngineEntityType::GlobalFungibleResourceManager => {
+            EngineEntityType::GlobalFungibleResourceManager
+            | EngineEntityType::GlobalNonFungibleResourceManager => {
                 Ok(Self::Resource)
             }
+            EngineEntityType::GlobalOneResourcePool
+            | EngineEntityType::GlobalTwoResourcePool
+            | EngineEntityType::GlobalMultiResourcePool => Ok(Self::Pool),
             _ => Err(CommonError::UnsupportedEntityType),
         }
     }
@@ -50,6 +56,48 @@ impl AbstractEntityType {
             Self::Account => "account".to_string(),
             Self::Identity => "identity".to_string(),
             Self::Resource => "resource".to_string(),
+            Self::Pool => "pool".to_string(),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use radix_engine_common::types::EntityType as EngineEntityType;
+
+    #[test]
+    fn resource_managers_are_resources() {
+        for engine in [
+            EngineEntityType::GlobalFungibleResourceManager,
+            EngineEntityType::GlobalNonFungibleResourceManager,
+        ] {
+            assert_eq!(
+                AbstractEntityType::try_from(engine),
+                Ok(AbstractEntityType::Resource)
+            );
+        }
+    }
+
+    #[test]
+    fn pools() {
+        for engine in [
+            EngineEntityType::GlobalOneResourcePool,
+            EngineEntityType::GlobalTwoResourcePool,
+            EngineEntityType::GlobalMultiResourcePool,
+        ] {
+            assert_eq!(
+                AbstractEntityType::try_from(engine),
+                Ok(AbstractEntityType::Pool)
+            );
+        }
+    }
+
+    #[test]
+    fn unsupported() {
+        assert_eq!(
+            AbstractEntityType::try_from(EngineEntityType::GlobalPackage),
+            Err(CommonError::UnsupportedEntityType)
+        );
+    }
+}
@@ -71,6 +71,11 @@ pub struct Account {
     /// The on ledger synced settings for this account, contains e.g.
     /// ThirdPartyDeposit settings, with deposit rules for assets.
     pub on_ledger_settings: OnLedgerSettings,
+
+    /// Fields unknown to this version, written by a newer version of the
+    /// Profile format, re-emitted as-is when encoded.
+    #[serde(flatten)]
+    pub extra: UnknownFields,
 }
 
 impl Account {
@@ -95,6 +100,92 @@ impl Account {
             appearance_id,
             flags: EntityFlags::default(),
             on_ledger_settings: OnLedgerSettings::default(),
+            extra: UnknownFields::default(),
+        }
+    }
+}
+
+impl Account {
+    /// Instantiates a watch-only Account, imported by `address` only, which
+    /// is not controlled by any factor instance of the user.
+    pub fn new_watch_only(
+        address: AccountAddress,
+        display_name: DisplayName,
+        appearance_id: AppearanceID,
+    ) -> Self {
+        Self {
+            network_id: address.network_id,
+            address,
+            display_name,
+            security_state: EntitySecurityState::Unowned,
+            appearance_id,
+            flags: EntityFlags::default(),
+            on_ledger_settings: OnLedgerSettings::default(),
+            extra: UnknownFields::default(),
+        }
+    }
+
+    /// `true` if this Account was imported by address only and cannot sign.
+    pub fn is_watch_only(&self) -> bool {
+        self.security_state.is_unowned()
+    }
+
+    /// The factor instance signing transactions of this Account, fails for
+    /// a watch-only Account, excluding it from signing.
+    pub fn transaction_signing_factor_instance(
+        &self,
+    ) -> Result<HierarchicalDeterministicFactorInstance> {
+        self.security_state
+            .transaction_signing_factor_instance()
+            .cloned()
+            .ok_or_else(|| {
+                CommonError::EntityIsWatchOnly(self.address.to_string())
+            })
+    }
+}
+
+impl Account {
+    /// Checks that `address` is the virtual address of the public key of the
+    /// transaction signing factor instance on `network_id`, which fails for
+    /// a corrupted or tampered snapshot.
+    /// Watch-only Accounts have no factor instance, so always pass.
+    pub fn validate_address_matches_factor_instance(&self) -> Result<()> {
+        let Some(instance) =
+            self.security_state.transaction_signing_factor_instance()
+        else {
+            return Ok(());
+        };
+        let public_key = instance.public_key.public_key.clone();
+        let expected = AccountAddress::new(public_key, self.network_id);
+        if self.address != expected {
+            return Err(
+                CommonError::AccountAddressDoesNotMatchFactorInstance {
+                    address: self.address.clone(),
+                    expected,
+                },
+            );
+        }
+        Ok(())
+    }
+}
+
+impl Account {
+    /// The index of this Account, i.e. the unhardened last component of the
+    /// CAP26 derivation path of its transaction signing factor instance.
+    ///
+    /// `None` if the Account was imported from Olympia - having a BIP44 like
+    /// derivation path - if it is watch-only or once securified.
+    ///
+    /// This is not the position of the Account, Accounts are ordered as
+    /// stored in the Profile, see [`Wallet::reorder_accounts`].
+    pub fn derivation_index(&self) -> Option<HDPathValue> {
+        let path = self
+            .security_state
+            .transaction_signing_factor_instance()?
+            .derivation_path();
+        match path {
+            DerivationPath::CAP26 { .. } => Some(path.last_component().index()),
+            DerivationPath::BIP44Like { .. } => None,
         }
     }
 }
@@ -107,25 +198,24 @@ impl Identifiable for Account {
     }
 }
 
-impl PartialOrd for Account {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
+#[uniffi::export]
+pub fn account_derivation_index(account: Account) -> Option<HDPathValue> {
+    account.derivation_index()
 }
 
-impl Ord for Account {
-    fn cmp(&self, other: &Self) -> Ordering {
-        match (&self.security_state, &other.security_state) {
-            (
-                EntitySecurityState::Unsecured { value: l },
-                EntitySecurityState::Unsecured { value: r },
-            ) => l
-                .transaction_signing
-                .derivation_path()
-                .last_component()
-                .cmp(r.transaction_signing.derivation_path().last_component()),
-        }
-    }
+/// A watch-only Account with `address`, see [`Account::new_watch_only`].
+#[uniffi::export]
+pub fn new_watch_only_account(
+    address: AccountAddress,
+    display_name: DisplayName,
+    appearance_id: AppearanceID,
+) -> Account {
+    Account::new_watch_only(address, display_name, appearance_id)
+}
+
+#[uniffi::export]
+pub fn account_is_watch_only(account: &Account) -> bool {
+    account.is_watch_only()
 }
 
 impl HasPlaceholder for Account {
@@ -155,6 +245,7 @@ impl Account {
             flags: EntityFlags::default(),
             on_ledger_settings: OnLedgerSettings::default(),
             security_state: EntitySecurityState::placeholder(),
+            extra: UnknownFields::default(),
         }
     }
 
@@ -313,6 +404,43 @@ mod tests {
 
     use super::Account;
 
+    #[test]
+    fn validate_address_matches_factor_instance() {
+        assert_eq!(
+            Account::placeholder_mainnet_alice()
+                .validate_address_matches_factor_instance(),
+            Ok(())
+        );
+        assert_eq!(
+            Account::placeholder_stokenet_carol()
+                .validate_address_matches_factor_instance(),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn validate_address_does_not_match_factor_instance() {
+        let mut sut = Account::placeholder_mainnet_alice();
+        let expected = sut.address.clone();
+        sut.address = Account::placeholder_mainnet_bob().address;
+        assert_eq!(
+            sut.validate_address_matches_factor_instance(),
+            Err(
+                crate::CommonError::AccountAddressDoesNotMatchFactorInstance {
+                    address: Account::placeholder_mainnet_bob().address,
+                    expected,
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn validate_address_on_other_network_does_not_match() {
+        let mut sut = Account::placeholder_mainnet_alice();
+        sut.network_id = crate::NetworkID::Stokenet;
+        assert!(sut.validate_address_matches_factor_instance().is_err());
+    }
+
     #[test]
     fn equality() {
         assert_eq!(Account::placeholder(), Account::placeholder());
@@ -348,8 +476,82 @@ mod tests {
     }
 
     #[test]
-    fn compare() {
-        assert!(Account::placeholder_alice() < Account::placeholder_bob());
+    fn derivation_index() {
+        assert_eq!(
+            Account::placeholder_mainnet_alice().derivation_index(),
+            Some(0)
+        );
+        assert_eq!(
+            Account::placeholder_mainnet_bob().derivation_index(),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn derivation_index_of_olympia_account_is_none() {
+        assert_eq!(olympia_account().derivation_index(), None);
+    }
+
+    fn watch_only_account() -> Account {
+        Account::new_watch_only(
+            AccountAddress::placeholder_mainnet(),
+            DisplayName::new("Watched").unwrap(),
+            AppearanceID::default(),
+        )
+    }
+
+    #[test]
+    fn watch_only() {
+        let sut = watch_only_account();
+        assert!(sut.is_watch_only());
+        assert!(!Account::placeholder().is_watch_only());
+        assert_eq!(sut.network_id, sut.address.network_id);
+        assert_eq!(sut.derivation_index(), None);
+        assert_eq!(sut.validate_address_matches_factor_instance(), Ok(()));
+    }
+
+    #[test]
+    fn watch_only_cannot_sign() {
+        let sut = watch_only_account();
+        assert_eq!(
+            sut.transaction_signing_factor_instance(),
+            Err(crate::CommonError::EntityIsWatchOnly(
+                sut.address.to_string()
+            ))
+        );
+        assert!(Account::placeholder()
+            .transaction_signing_factor_instance()
+            .is_ok());
+    }
+
+    #[test]
+    fn watch_only_json_roundtrip() {
+        let sut = watch_only_account();
+        let json = serde_json::to_value(&sut).unwrap();
+        assert_eq!(
+            json["securityState"],
+            serde_json::json!({ "discriminator": "unowned" })
+        );
+        assert_eq!(serde_json::from_value::<Account>(json).unwrap(), sut);
+    }
+
+    #[test]
+    fn account_derivation_index() {
+        assert_eq!(
+            super::account_derivation_index(Account::placeholder_mainnet_bob()),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn new_watch_only_account() {
+        let sut = super::new_watch_only_account(
+            AccountAddress::placeholder_mainnet(),
+            DisplayName::new("Watched").unwrap(),
+            AppearanceID::default(),
+        );
+        assert!(super::account_is_watch_only(&sut));
+        assert!(!super::account_is_watch_only(&Account::placeholder()));
     }
 
     #[test]
@@ -622,8 +824,8 @@ mod tests {
         );
     }
 
-    #[test]
-    fn json_deserialization_works_without_flags_as_version_1_0_0_of_app() {
+    /// An Account imported from Olympia, saved by version 1.0.0 of the app.
+    fn olympia_account() -> Account {
         let json = serde_json::Value::from_str(
             r#"
             {
@@ -686,7 +888,12 @@ mod tests {
                 }
             "#,
         ).unwrap();
-        let account = serde_json::from_value::<Account>(json).unwrap();
+        serde_json::from_value::<Account>(json).unwrap()
+    }
+
+    #[test]
+    fn json_deserialization_works_without_flags_as_version_1_0_0_of_app() {
+        let account = olympia_account();
         assert_eq!(account.display_name.value, "Olympia|Soft|0".to_string()); // soundness
         assert_eq!(account.flags.len(), 0); // assert Default value is empty flags.
     }
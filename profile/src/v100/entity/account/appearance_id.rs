@@ -26,6 +26,13 @@ pub fn new_appearance_id(validating: u8) -> Result<AppearanceID> {
     AppearanceID::new(validating)
 }
 
+#[uniffi::export]
+pub fn new_appearance_id_next_least_used(
+    existing: Vec<AppearanceID>,
+) -> AppearanceID {
+    AppearanceID::next_least_used(&existing)
+}
+
 #[uniffi::export]
 pub fn new_appearance_id_placeholder() -> AppearanceID {
     AppearanceID::placeholder()
@@ -52,6 +59,21 @@ impl AppearanceID {
         }
     }
 
+    /// The least used appearance among `existing`, to minimize the number
+    /// of accounts sharing the same gradient. Ties are broken by taking the
+    /// first candidate after the last of `existing`, so that consecutively
+    /// created accounts get different gradients.
+    pub fn next_least_used(existing: &[AppearanceID]) -> Self {
+        let usage =
+            |value: u8| existing.iter().filter(|a| a.value == value).count();
+        let start = existing.last().map(|a| a.value + 1).unwrap_or(0);
+        (0..=Self::MAX)
+            .map(|offset| (start + offset) % (Self::MAX + 1))
+            .min_by_key(|value| usage(*value))
+            .map(Self::declare)
+            .expect("Should always have at least one appearance.")
+    }
+
     // Probably want this as a macro... but it is just not worth it, why I boilerplate it.
     fn declare(value: u8) -> Self {
         Self::new(value).expect("Should have declared valid value.")
@@ -207,6 +229,61 @@ mod tests {
         .collect::<HashSet<_>>();
         assert_eq!(set.len(), (AppearanceID::MAX as usize) + 1);
     }
+
+    #[test]
+    fn next_least_used_when_empty() {
+        assert_eq!(
+            AppearanceID::next_least_used(&[]),
+            AppearanceID::gradient0()
+        );
+    }
+
+    #[test]
+    fn next_least_used_is_sequential_when_all_unused() {
+        assert_eq!(
+            AppearanceID::next_least_used(&[
+                AppearanceID::gradient0(),
+                AppearanceID::gradient1()
+            ]),
+            AppearanceID::gradient2()
+        );
+    }
+
+    #[test]
+    fn next_least_used_wraps_around() {
+        let all = (0..=AppearanceID::MAX)
+            .map(|v| AppearanceID::new(v).unwrap())
+            .collect_vec();
+        assert_eq!(
+            AppearanceID::next_least_used(&all),
+            AppearanceID::gradient0()
+        );
+    }
+
+    #[test]
+    fn next_least_used_fills_gaps() {
+        let mut existing = (0..=AppearanceID::MAX)
+            .map(|v| AppearanceID::new(v).unwrap())
+            .collect_vec();
+        existing.push(AppearanceID::gradient0());
+        existing.push(AppearanceID::gradient1());
+        // gradient 2 is used once, same as 3..=11, and directly follows the
+        // last one.
+        assert_eq!(
+            AppearanceID::next_least_used(&existing),
+            AppearanceID::gradient2()
+        );
+
+        // gradient 5 is the only unused one
+        let existing = (0..=AppearanceID::MAX)
+            .filter(|v| *v != 5)
+            .map(|v| AppearanceID::new(v).unwrap())
+            .collect_vec();
+        assert_eq!(
+            AppearanceID::next_least_used(&existing),
+            AppearanceID::gradient5()
+        );
+    }
 }
 
 #[cfg(test)]
@@ -225,4 +302,16 @@ mod uniffi_tests {
             new_appearance_id_placeholder_other()
         );
     }
+
+    #[test]
+    fn next_least_used() {
+        assert_eq!(
+            new_appearance_id_next_least_used(vec![
+                AppearanceID::gradient0(),
+                AppearanceID::gradient0(),
+                AppearanceID::gradient1(),
+            ]),
+            AppearanceID::gradient2()
+        );
+    }
 }
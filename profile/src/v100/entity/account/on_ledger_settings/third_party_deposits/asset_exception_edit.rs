@@ -0,0 +1,70 @@
+use crate::prelude::*;
+
+/// A single change to the `assets_exception_list` of a `ThirdPartyDeposits`,
+/// a batch of edits is applied with [`ThirdPartyDeposits::apply_edits`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Enum)]
+pub enum AssetExceptionEdit {
+    /// Adds `exception`, if an exception for the same resource is already
+    /// present it must have the same rule.
+    Add { exception: AssetException },
+
+    /// Removes the exception for the resource with `address`, which must be
+    /// present.
+    Remove { address: ResourceAddress },
+
+    /// Changes the rule of the exception for the resource with `address`,
+    /// which must be present.
+    ChangeRule {
+        address: ResourceAddress,
+        to: DepositAddressExceptionRule,
+    },
+}
+
+impl AssetExceptionEdit {
+    /// The address of the resource this edit is about.
+    pub fn address(&self) -> ResourceAddress {
+        match self {
+            Self::Add { exception } => exception.address.clone(),
+            Self::Remove { address } => address.clone(),
+            Self::ChangeRule { address, .. } => address.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn address() {
+        let address: ResourceAddress =
+            "resource_rdx1tkk83magp3gjyxrpskfsqwkg4g949rmcjee4tu2xmw93ltw2cz94sq"
+                .parse()
+                .unwrap();
+        assert_eq!(
+            AssetExceptionEdit::Add {
+                exception: AssetException::new(
+                    address.clone(),
+                    DepositAddressExceptionRule::Allow
+                )
+            }
+            .address(),
+            address
+        );
+        assert_eq!(
+            AssetExceptionEdit::Remove {
+                address: address.clone()
+            }
+            .address(),
+            address
+        );
+        assert_eq!(
+            AssetExceptionEdit::ChangeRule {
+                address: address.clone(),
+                to: DepositAddressExceptionRule::Deny
+            }
+            .address(),
+            address
+        );
+    }
+}
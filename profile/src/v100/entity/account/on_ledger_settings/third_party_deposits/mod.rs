@@ -1,11 +1,15 @@
 mod asset_exception;
+mod asset_exception_edit;
 mod deposit_address_exception_rule;
 mod deposit_rule;
 mod depositor_address;
+mod non_fungible_exception;
 mod third_party_deposits;
 
 pub use asset_exception::*;
+pub use asset_exception_edit::*;
 pub use deposit_address_exception_rule::*;
 pub use deposit_rule::*;
 pub use depositor_address::*;
+pub use non_fungible_exception::*;
 pub use third_party_deposits::*;
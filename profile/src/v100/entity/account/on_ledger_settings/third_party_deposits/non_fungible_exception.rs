@@ -0,0 +1,132 @@
+use crate::prelude::*;
+
+/// The exception rule for a single non-fungible, which overrides both the
+/// general `deposit_rule` of a `ThirdPartyDeposits` settings and any
+/// `AssetException` for the resource of the non-fungible.
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct NonFungibleException {
+    /// The non-fungible to either deny or allow, its resource must be
+    /// non-fungible.
+    #[serde(rename = "nonFungibleGlobalID")]
+    pub non_fungible_global_id: NonFungibleGlobalId,
+
+    /// Either deny or allow the `non_fungible_global_id`.
+    pub exception_rule: DepositAddressExceptionRule,
+}
+
+impl Identifiable for NonFungibleException {
+    type ID = NonFungibleGlobalId;
+
+    fn id(&self) -> Self::ID {
+        self.non_fungible_global_id.clone()
+    }
+}
+
+impl NonFungibleException {
+    /// Instantiates a new `NonFungibleException` with the specified
+    /// `NonFungibleGlobalId` and rule.
+    pub fn new(
+        non_fungible_global_id: NonFungibleGlobalId,
+        exception_rule: DepositAddressExceptionRule,
+    ) -> Self {
+        Self {
+            non_fungible_global_id,
+            exception_rule,
+        }
+    }
+
+    /// The address of the resource of the non-fungible.
+    pub fn resource_address(&self) -> ResourceAddress {
+        self.non_fungible_global_id.resource_address.clone()
+    }
+
+    /// `Err` if the resource of the non-fungible is fungible, in which case
+    /// local id granularity does not apply.
+    pub fn validated(self) -> Result<Self> {
+        if self.resource_address().is_fungible() {
+            return Err(CommonError::NonFungibleExceptionForFungibleResource(
+                self.resource_address(),
+            ));
+        }
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn global_id() -> NonFungibleGlobalId {
+        "resource_sim1ngktvyeenvvqetnqwysevcx5fyvl6hqe36y3rkhdfdn6uzvt5366ha:<foobar>"
+            .parse()
+            .unwrap()
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        let model = NonFungibleException::new(
+            global_id(),
+            DepositAddressExceptionRule::Deny,
+        );
+        assert_eq_after_json_roundtrip(
+            &model,
+            r#"
+            {
+                "nonFungibleGlobalID" : "resource_sim1ngktvyeenvvqetnqwysevcx5fyvl6hqe36y3rkhdfdn6uzvt5366ha:<foobar>",
+                "exceptionRule" : "deny"
+            }
+            "#,
+        )
+    }
+
+    #[test]
+    fn inequality_allow_ne_deny() {
+        assert_ne!(
+            NonFungibleException::new(
+                global_id(),
+                DepositAddressExceptionRule::Allow
+            ),
+            NonFungibleException::new(
+                global_id(),
+                DepositAddressExceptionRule::Deny
+            )
+        );
+    }
+
+    #[test]
+    fn validated() {
+        let sut = NonFungibleException::new(
+            global_id(),
+            DepositAddressExceptionRule::Allow,
+        );
+        assert_eq!(sut.clone().validated(), Ok(sut));
+    }
+
+    #[test]
+    fn validated_fungible_resource_fails() {
+        let xrd = ResourceAddress::xrd_on_network(NetworkID::Mainnet);
+        let sut = NonFungibleException::new(
+            NonFungibleGlobalId {
+                resource_address: xrd.clone(),
+                non_fungible_local_id: NonFungibleLocalId::integer(1),
+            },
+            DepositAddressExceptionRule::Allow,
+        );
+        assert_eq!(
+            sut.validated(),
+            Err(CommonError::NonFungibleExceptionForFungibleResource(xrd))
+        );
+    }
+}
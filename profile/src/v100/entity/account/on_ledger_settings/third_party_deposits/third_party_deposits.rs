@@ -1,5 +1,17 @@
 use crate::prelude::*;
 
+/// The asset exceptions of a `ThirdPartyDeposits`, in the order the user
+/// added them.
+pub type AssetsExceptionList = IdentifiedVecOf<AssetException>;
+
+/// The allowed depositors of a `ThirdPartyDeposits`, in the order the user
+/// added them.
+pub type DepositorsAllowList = IdentifiedVecOf<DepositorAddress>;
+
+/// The non-fungible exceptions of a `ThirdPartyDeposits`, in the order the
+/// user added them.
+pub type NonFungibleExceptionList = IdentifiedVecOf<NonFungibleException>;
+
 /// Controls the ability of third-parties to deposit into a certain account, this is
 /// useful for users who wish to not be able to receive airdrops.
 #[derive(
@@ -10,12 +22,57 @@ pub struct ThirdPartyDeposits {
     /// Controls the ability of third-parties to deposit into this account
     pub deposit_rule: DepositRule,
 
-    /// Denies or allows third-party deposits of specific assets by ignoring the `depositMode`
-    pub assets_exception_list: IdentifiedVecVia<AssetException>,
+    /// Denies or allows third-party deposits of specific assets by ignoring the `depositMode`,
+    /// in the order the user added them.
+    #[serde(deserialize_with = "deserialize_keeping_first_of_each_id")]
+    pub assets_exception_list: AssetsExceptionList,
 
-    /// Allows certain third-party depositors to deposit assets freely.
+    /// Allows certain third-party depositors to deposit assets freely,
+    /// in the order the user added them.
     /// Note: There is no `deny` counterpart for this.
-    pub depositors_allow_list: IdentifiedVecVia<DepositorAddress>,
+    #[serde(deserialize_with = "deserialize_keeping_first_of_each_id")]
+    pub depositors_allow_list: DepositorsAllowList,
+
+    /// Denies or allows third-party deposits of specific non-fungibles,
+    /// overriding both the `deposit_rule` and any exception in the
+    /// `assets_exception_list` for their resource, in the order the user
+    /// added them. Only non-fungible resources can have such exceptions.
+    #[serde(
+        default,
+        skip_serializing_if = "NonFungibleExceptionList::is_empty",
+        deserialize_with = "deserialize_validated_non_fungible_exceptions"
+    )]
+    pub non_fungible_exception_list: NonFungibleExceptionList,
+}
+
+/// Snapshots written when the lists were sets of elements - rather than of
+/// their IDs - might contain e.g. both an `allow` and a `deny` exception for
+/// the same resource, of which only the first is kept, preserving the order
+/// of the remaining elements.
+fn deserialize_keeping_first_of_each_id<'de, D, V>(
+    deserializer: D,
+) -> Result<IdentifiedVecOf<V>, D::Error>
+where
+    D: Deserializer<'de>,
+    V: Identifiable + Deserialize<'de>,
+    V::ID: Eq + std::hash::Hash + Clone + std::fmt::Debug,
+{
+    Vec::<V>::deserialize(deserializer).map(IdentifiedVecOf::from_iter)
+}
+
+/// Like [`deserialize_keeping_first_of_each_id`], failing if any of the
+/// exceptions is for a fungible resource.
+fn deserialize_validated_non_fungible_exceptions<'de, D>(
+    deserializer: D,
+) -> Result<NonFungibleExceptionList, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Vec::<NonFungibleException>::deserialize(deserializer)?
+        .into_iter()
+        .map(|e| e.validated().map_err(de::Error::custom))
+        .collect::<Result<Vec<_>, _>>()
+        .map(NonFungibleExceptionList::from_iter)
 }
 
 impl Default for ThirdPartyDeposits {
@@ -24,6 +81,14 @@ impl Default for ThirdPartyDeposits {
     }
 }
 
+// Trait: Default, used when `nonFungibleExceptionList` is absent in JSON.
+impl Default for NonFungibleExceptionList {
+    /// Instantiates a new empty collection.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Identifiable for DepositorAddress {
     type ID = Self;
 
@@ -39,8 +104,9 @@ impl ThirdPartyDeposits {
     pub fn new(deposit_rule: DepositRule) -> Self {
         Self {
             deposit_rule,
-            assets_exception_list: IdentifiedVecVia::new(),
-            depositors_allow_list: IdentifiedVecVia::new(),
+            assets_exception_list: AssetsExceptionList::new(),
+            depositors_allow_list: DepositorsAllowList::new(),
+            non_fungible_exception_list: NonFungibleExceptionList::new(),
         }
     }
 
@@ -57,12 +123,13 @@ impl ThirdPartyDeposits {
     {
         Self {
             deposit_rule,
-            assets_exception_list: IdentifiedVecVia::from_iter(
+            assets_exception_list: AssetsExceptionList::from_iter(
                 assets_exception_list,
             ),
-            depositors_allow_list: IdentifiedVecVia::from_iter(
+            depositors_allow_list: DepositorsAllowList::from_iter(
                 depositors_allow_list,
             ),
+            non_fungible_exception_list: NonFungibleExceptionList::new(),
         }
     }
 
@@ -73,7 +140,7 @@ impl ThirdPartyDeposits {
     /// If the set did not previously contain an equal value, true is returned.
     /// If the set already contained an equal value, false is returned, and the entry is not updated.
     pub fn add_asset_exception(&mut self, exception: AssetException) -> bool {
-        self.assets_exception_list.append(exception).0
+        self.assets_exception_list.append(exception)
     }
 
     // If the set contains an element equal to `exception`, removes it from the set and drops it. Returns whether such an element was present.
@@ -81,7 +148,9 @@ impl ThirdPartyDeposits {
         &mut self,
         exception: &AssetException,
     ) -> bool {
-        self.assets_exception_list.remove(exception).is_some()
+        self.assets_exception_list
+            .remove_by_id(&exception.address)
+            .is_some()
     }
 
     /// Adds a `DepositorAddress` to the `depositors_allow_list` (set).
@@ -91,7 +160,7 @@ impl ThirdPartyDeposits {
     /// If the set did not previously contain an equal value, true is returned.
     /// If the set already contained an equal value, false is returned, and the entry is not updated.
     pub fn allow_depositor(&mut self, depositor: DepositorAddress) -> bool {
-        self.depositors_allow_list.append(depositor).0
+        self.depositors_allow_list.append(depositor)
     }
 
     // If the set contains an element equal to `DepositorAddress`, removes it from the set and drops it. Returns whether such an element was present.
@@ -99,10 +168,151 @@ impl ThirdPartyDeposits {
         &mut self,
         depositor: &DepositorAddress,
     ) -> bool {
-        self.depositors_allow_list.remove(depositor).is_some()
+        self.depositors_allow_list.remove_by_id(depositor).is_some()
+    }
+
+    /// Adds a `NonFungibleException` to the `non_fungible_exception_list`,
+    /// replacing the rule of any exception for the same non-fungible.
+    ///
+    /// Returns whether the `exception` was newly inserted, fails if the
+    /// resource of the non-fungible is fungible.
+    pub fn add_non_fungible_exception(
+        &mut self,
+        exception: NonFungibleException,
+    ) -> Result<bool> {
+        let exception = exception.validated()?;
+        let rule = exception.exception_rule;
+        let is_updated = self
+            .non_fungible_exception_list
+            .update_with(&exception.id(), |e| e.exception_rule = rule);
+        if !is_updated {
+            self.non_fungible_exception_list.append(exception);
+        }
+        Ok(!is_updated)
+    }
+
+    /// Removes the exception for the non-fungible with `global_id`, returns
+    /// whether such an exception was present.
+    pub fn remove_non_fungible_exception(
+        &mut self,
+        global_id: &NonFungibleGlobalId,
+    ) -> bool {
+        self.non_fungible_exception_list
+            .remove_by_id(global_id)
+            .is_some()
+    }
+
+    /// The exception rule applying to third-party deposits of the
+    /// non-fungible with `global_id`, if any, an exception for the
+    /// non-fungible itself takes precedence over one for its resource.
+    pub fn exception_rule_for_non_fungible(
+        &self,
+        global_id: &NonFungibleGlobalId,
+    ) -> Option<DepositAddressExceptionRule> {
+        self.non_fungible_exception_list
+            .get_id(global_id)
+            .map(|e| e.exception_rule)
+            .or_else(|| {
+                self.assets_exception_list
+                    .get_id(&global_id.resource_address)
+                    .map(|e| e.exception_rule)
+            })
+    }
+
+    /// Applies all `edits` to the `assets_exception_list`, in order, either
+    /// all of them or - if any edit is invalid - none of them, leaving
+    /// `self` unchanged.
+    ///
+    /// Fails if an edit adds an exception for a resource already having an
+    /// exception with a different rule, if an edit removes or changes the
+    /// rule of an exception which is not present, or if the exceptions would
+    /// not all be on the same network.
+    pub fn apply_edits(
+        &mut self,
+        edits: Vec<AssetExceptionEdit>,
+    ) -> Result<()> {
+        let mut exceptions = self.assets_exception_list.items();
+        let position = |exceptions: &Vec<AssetException>,
+                        address: &ResourceAddress| {
+            exceptions.iter().position(|e| &e.address == address)
+        };
+        for edit in edits {
+            let address = edit.address();
+            match edit {
+                AssetExceptionEdit::Add { exception } => {
+                    match position(&exceptions, &address) {
+                        Some(i) if exceptions[i] != exception => {
+                            return Err(
+                                CommonError::ConflictingAssetExceptionRules(
+                                    address,
+                                ),
+                            );
+                        }
+                        Some(_) => {}
+                        None => exceptions.push(exception),
+                    }
+                }
+                AssetExceptionEdit::Remove { .. } => {
+                    let i =
+                        position(&exceptions, &address).ok_or_else(|| {
+                            CommonError::UnknownAssetException(address.clone())
+                        })?;
+                    exceptions.remove(i);
+                }
+                AssetExceptionEdit::ChangeRule { to, .. } => {
+                    let i =
+                        position(&exceptions, &address).ok_or_else(|| {
+                            CommonError::UnknownAssetException(address.clone())
+                        })?;
+                    exceptions[i].exception_rule = to;
+                }
+            }
+        }
+
+        if let Some(expected) = exceptions.first().map(|e| e.address.network_id)
+        {
+            if let Some(found) = exceptions
+                .iter()
+                .map(|e| e.address.network_id)
+                .find(|n| *n != expected)
+            {
+                return Err(CommonError::AssetExceptionOnWrongNetwork {
+                    expected,
+                    found,
+                });
+            }
+        }
+
+        self.assets_exception_list = AssetsExceptionList::from_iter(exceptions);
+        Ok(())
     }
 }
 
+/// Returns `deposits` with all `edits` applied to its `assets_exception_list`,
+/// see [`ThirdPartyDeposits::apply_edits`].
+#[uniffi::export]
+pub fn third_party_deposits_applying_asset_exception_edits(
+    deposits: &ThirdPartyDeposits,
+    edits: Vec<AssetExceptionEdit>,
+) -> Result<ThirdPartyDeposits> {
+    let mut deposits = deposits.clone();
+    deposits.apply_edits(edits)?;
+    Ok(deposits)
+}
+
+/// Returns `deposits` with `exception` added to its
+/// `non_fungible_exception_list`, see
+/// [`ThirdPartyDeposits::add_non_fungible_exception`].
+#[uniffi::export]
+pub fn third_party_deposits_adding_non_fungible_exception(
+    deposits: &ThirdPartyDeposits,
+    exception: NonFungibleException,
+) -> Result<ThirdPartyDeposits> {
+    let mut deposits = deposits.clone();
+    deposits.add_non_fungible_exception(exception)?;
+    Ok(deposits)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
@@ -123,10 +333,8 @@ mod tests {
         );
         let model = ThirdPartyDeposits::with_rule_and_lists(
             DepositRule::AcceptKnown,
-            BTreeSet::from_iter([excp1, excp2]),
-            BTreeSet::from_iter(
-                [DepositorAddress::NFGlobalID { value: "resource_sim1ngktvyeenvvqetnqwysevcx5fyvl6hqe36y3rkhdfdn6uzvt5366ha:<foobar>".parse().unwrap()}],
-            ),
+            [excp1, excp2],
+            [DepositorAddress::NFGlobalID { value: "resource_sim1ngktvyeenvvqetnqwysevcx5fyvl6hqe36y3rkhdfdn6uzvt5366ha:<foobar>".parse().unwrap()}],
         );
 
         assert_eq_after_json_roundtrip(
@@ -187,9 +395,8 @@ mod tests {
         assert_eq!(settings.assets_exception_list.len(), 2);
         assert!(settings.remove_asset_exception(&exception));
         assert_eq!(settings.assets_exception_list.len(), 1);
-        // settings.set_assets_exception_list(BTreeSet::from_iter([exception.clone()]));
         settings.assets_exception_list =
-            IdentifiedVecVia::from_iter([exception.clone()]);
+            AssetsExceptionList::from_iter([exception.clone()]);
 
         assert!(
             !settings.add_asset_exception(exception.clone()),
@@ -226,7 +433,7 @@ mod tests {
         assert_eq!(settings.depositors_allow_list.len(), 0);
 
         settings.depositors_allow_list =
-            IdentifiedVecVia::from_iter([depositor.clone()]);
+            DepositorsAllowList::from_iter([depositor.clone()]);
         assert!(
             !settings.allow_depositor(depositor.clone()),
             "Expected `false` since already present."
@@ -255,6 +462,359 @@ mod tests {
             .is_empty(),);
     }
 
+    fn resource(s: &str) -> ResourceAddress {
+        s.parse().unwrap()
+    }
+
+    fn xrd() -> ResourceAddress {
+        resource("resource_rdx1tknxxxxxxxxxradxrdxxxxxxxxx009923554798xxxxxxxxxradxrd")
+    }
+
+    fn other() -> ResourceAddress {
+        resource("resource_rdx1tkk83magp3gjyxrpskfsqwkg4g949rmcjee4tu2xmw93ltw2cz94sq")
+    }
+
+    fn stokenet() -> ResourceAddress {
+        resource("resource_tdx_2_1tkckx9fynl9f7756z8wxphq7wce6vk874nuq4f2nnxgh3nzrwhjdlp")
+    }
+
+    fn add(
+        address: ResourceAddress,
+        rule: DepositAddressExceptionRule,
+    ) -> AssetExceptionEdit {
+        AssetExceptionEdit::Add {
+            exception: AssetException::new(address, rule),
+        }
+    }
+
+    fn settings_with_xrd_allowed() -> ThirdPartyDeposits {
+        ThirdPartyDeposits::with_rule_and_lists(
+            DepositRule::AcceptKnown,
+            [AssetException::new(
+                xrd(),
+                DepositAddressExceptionRule::Allow,
+            )],
+            Vec::<DepositorAddress>::new(),
+        )
+    }
+
+    #[test]
+    fn apply_edits() {
+        let mut sut = settings_with_xrd_allowed();
+        assert_eq!(
+            sut.apply_edits(vec![
+                add(other(), DepositAddressExceptionRule::Allow),
+                AssetExceptionEdit::ChangeRule {
+                    address: other(),
+                    to: DepositAddressExceptionRule::Deny
+                },
+                AssetExceptionEdit::Remove { address: xrd() },
+            ]),
+            Ok(())
+        );
+        assert_eq!(
+            sut.assets_exception_list.items(),
+            vec![AssetException::new(
+                other(),
+                DepositAddressExceptionRule::Deny
+            )]
+        );
+    }
+
+    #[test]
+    fn apply_edits_adding_identical_exception_is_noop() {
+        let mut sut = settings_with_xrd_allowed();
+        assert_eq!(
+            sut.apply_edits(vec![add(
+                xrd(),
+                DepositAddressExceptionRule::Allow
+            )]),
+            Ok(())
+        );
+        assert_eq!(sut, settings_with_xrd_allowed());
+    }
+
+    #[test]
+    fn apply_edits_conflicting_rule_fails_and_changes_nothing() {
+        let mut sut = settings_with_xrd_allowed();
+        assert_eq!(
+            sut.apply_edits(vec![
+                add(other(), DepositAddressExceptionRule::Allow),
+                add(xrd(), DepositAddressExceptionRule::Deny),
+            ]),
+            Err(CommonError::ConflictingAssetExceptionRules(xrd()))
+        );
+        assert_eq!(sut, settings_with_xrd_allowed());
+    }
+
+    #[test]
+    fn apply_edits_conflicting_rules_within_edits_fails() {
+        let mut sut = ThirdPartyDeposits::default();
+        assert_eq!(
+            sut.apply_edits(vec![
+                add(other(), DepositAddressExceptionRule::Allow),
+                add(other(), DepositAddressExceptionRule::Deny),
+            ]),
+            Err(CommonError::ConflictingAssetExceptionRules(other()))
+        );
+        assert_eq!(sut, ThirdPartyDeposits::default());
+    }
+
+    #[test]
+    fn apply_edits_remove_unknown_fails() {
+        let mut sut = settings_with_xrd_allowed();
+        assert_eq!(
+            sut.apply_edits(vec![
+                AssetExceptionEdit::Remove { address: xrd() },
+                AssetExceptionEdit::Remove { address: xrd() },
+            ]),
+            Err(CommonError::UnknownAssetException(xrd()))
+        );
+        assert_eq!(sut, settings_with_xrd_allowed());
+    }
+
+    #[test]
+    fn apply_edits_change_rule_of_unknown_fails() {
+        let mut sut = settings_with_xrd_allowed();
+        assert_eq!(
+            sut.apply_edits(vec![AssetExceptionEdit::ChangeRule {
+                address: other(),
+                to: DepositAddressExceptionRule::Deny
+            }]),
+            Err(CommonError::UnknownAssetException(other()))
+        );
+        assert_eq!(sut, settings_with_xrd_allowed());
+    }
+
+    #[test]
+    fn apply_edits_different_networks_fails() {
+        let mut sut = settings_with_xrd_allowed();
+        assert_eq!(
+            sut.apply_edits(vec![add(
+                stokenet(),
+                DepositAddressExceptionRule::Deny
+            )]),
+            Err(CommonError::AssetExceptionOnWrongNetwork {
+                expected: NetworkID::Mainnet,
+                found: NetworkID::Stokenet
+            })
+        );
+        assert_eq!(sut, settings_with_xrd_allowed());
+    }
+
+    #[test]
+    fn apply_edits_replacing_all_exceptions_with_other_network() {
+        let mut sut = settings_with_xrd_allowed();
+        assert_eq!(
+            sut.apply_edits(vec![
+                AssetExceptionEdit::Remove { address: xrd() },
+                add(stokenet(), DepositAddressExceptionRule::Deny),
+            ]),
+            Ok(())
+        );
+        assert_eq!(sut.assets_exception_list.len(), 1);
+    }
+
+    #[test]
+    fn insertion_order_is_preserved() {
+        let mut sut = ThirdPartyDeposits::default();
+        sut.add_asset_exception(AssetException::new(
+            other(),
+            DepositAddressExceptionRule::Deny,
+        ));
+        sut.add_asset_exception(AssetException::new(
+            xrd(),
+            DepositAddressExceptionRule::Allow,
+        ));
+        assert_eq!(
+            sut.assets_exception_list
+                .iter()
+                .map(|e| e.address.clone())
+                .collect_vec(),
+            vec![other(), xrd()]
+        );
+        let json = serde_json::to_string(&sut).unwrap();
+        let decoded: ThirdPartyDeposits = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, sut);
+    }
+
+    #[test]
+    fn json_with_multiple_exceptions_for_same_resource_keeps_first() {
+        let sut: ThirdPartyDeposits = serde_json::from_str(
+            r#"
+            {
+                "depositRule" : "acceptKnown",
+                "assetsExceptionList" : [
+                    {
+                        "address" : "resource_rdx1tknxxxxxxxxxradxrdxxxxxxxxx009923554798xxxxxxxxxradxrd",
+                        "exceptionRule" : "deny"
+                    },
+                    {
+                        "address" : "resource_rdx1tkk83magp3gjyxrpskfsqwkg4g949rmcjee4tu2xmw93ltw2cz94sq",
+                        "exceptionRule" : "allow"
+                    },
+                    {
+                        "address" : "resource_rdx1tknxxxxxxxxxradxrdxxxxxxxxx009923554798xxxxxxxxxradxrd",
+                        "exceptionRule" : "allow"
+                    }
+                ],
+                "depositorsAllowList" : []
+            }
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            sut.assets_exception_list.items(),
+            vec![
+                AssetException::new(xrd(), DepositAddressExceptionRule::Deny),
+                AssetException::new(
+                    other(),
+                    DepositAddressExceptionRule::Allow
+                ),
+            ]
+        );
+    }
+
+    fn non_fungible() -> NonFungibleGlobalId {
+        "resource_sim1ngktvyeenvvqetnqwysevcx5fyvl6hqe36y3rkhdfdn6uzvt5366ha:<foobar>"
+            .parse()
+            .unwrap()
+    }
+
+    fn non_fungible_other() -> NonFungibleGlobalId {
+        "resource_sim1ngktvyeenvvqetnqwysevcx5fyvl6hqe36y3rkhdfdn6uzvt5366ha:#1#"
+            .parse()
+            .unwrap()
+    }
+
+    #[test]
+    fn add_and_remove_non_fungible_exception() {
+        let mut sut = ThirdPartyDeposits::default();
+        assert_eq!(
+            sut.add_non_fungible_exception(NonFungibleException::new(
+                non_fungible(),
+                DepositAddressExceptionRule::Allow
+            )),
+            Ok(true)
+        );
+        assert_eq!(
+            sut.add_non_fungible_exception(NonFungibleException::new(
+                non_fungible(),
+                DepositAddressExceptionRule::Deny
+            )),
+            Ok(false)
+        );
+        assert_eq!(
+            sut.non_fungible_exception_list.items(),
+            vec![NonFungibleException::new(
+                non_fungible(),
+                DepositAddressExceptionRule::Deny
+            )]
+        );
+        assert!(sut.remove_non_fungible_exception(&non_fungible()));
+        assert!(!sut.remove_non_fungible_exception(&non_fungible()));
+        assert!(sut.non_fungible_exception_list.is_empty());
+    }
+
+    #[test]
+    fn add_non_fungible_exception_for_fungible_resource_fails() {
+        let mut sut = ThirdPartyDeposits::default();
+        assert_eq!(
+            sut.add_non_fungible_exception(NonFungibleException::new(
+                NonFungibleGlobalId {
+                    resource_address: xrd(),
+                    non_fungible_local_id: NonFungibleLocalId::integer(1),
+                },
+                DepositAddressExceptionRule::Allow
+            )),
+            Err(CommonError::NonFungibleExceptionForFungibleResource(xrd()))
+        );
+        assert!(sut.non_fungible_exception_list.is_empty());
+    }
+
+    #[test]
+    fn exception_rule_for_non_fungible() {
+        let mut sut = ThirdPartyDeposits::with_rule_and_lists(
+            DepositRule::AcceptKnown,
+            [AssetException::new(
+                non_fungible().resource_address,
+                DepositAddressExceptionRule::Deny,
+            )],
+            Vec::<DepositorAddress>::new(),
+        );
+        sut.add_non_fungible_exception(NonFungibleException::new(
+            non_fungible(),
+            DepositAddressExceptionRule::Allow,
+        ))
+        .unwrap();
+        assert_eq!(
+            sut.exception_rule_for_non_fungible(&non_fungible()),
+            Some(DepositAddressExceptionRule::Allow)
+        );
+        assert_eq!(
+            sut.exception_rule_for_non_fungible(&non_fungible_other()),
+            Some(DepositAddressExceptionRule::Deny)
+        );
+        assert_eq!(
+            ThirdPartyDeposits::default()
+                .exception_rule_for_non_fungible(&non_fungible()),
+            None
+        );
+    }
+
+    #[test]
+    fn json_roundtrip_with_non_fungible_exceptions() {
+        let mut model = ThirdPartyDeposits::new(DepositRule::DenyAll);
+        model
+            .add_non_fungible_exception(NonFungibleException::new(
+                non_fungible(),
+                DepositAddressExceptionRule::Allow,
+            ))
+            .unwrap();
+        assert_eq_after_json_roundtrip(
+            &model,
+            r#"
+            {
+                "depositRule" : "denyAll",
+                "assetsExceptionList" : [],
+                "depositorsAllowList" : [],
+                "nonFungibleExceptionList" : [
+                    {
+                        "nonFungibleGlobalID" : "resource_sim1ngktvyeenvvqetnqwysevcx5fyvl6hqe36y3rkhdfdn6uzvt5366ha:<foobar>",
+                        "exceptionRule" : "allow"
+                    }
+                ]
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn json_without_non_fungible_exceptions_omits_list() {
+        let json = serde_json::to_value(ThirdPartyDeposits::default()).unwrap();
+        assert!(json.get("nonFungibleExceptionList").is_none());
+    }
+
+    #[test]
+    fn json_with_non_fungible_exception_for_fungible_resource_fails() {
+        let result = serde_json::from_str::<ThirdPartyDeposits>(
+            r#"
+            {
+                "depositRule" : "denyAll",
+                "assetsExceptionList" : [],
+                "depositorsAllowList" : [],
+                "nonFungibleExceptionList" : [
+                    {
+                        "nonFungibleGlobalID" : "resource_rdx1tknxxxxxxxxxradxrdxxxxxxxxx009923554798xxxxxxxxxradxrd:#1#",
+                        "exceptionRule" : "allow"
+                    }
+                ]
+            }
+            "#,
+        );
+        assert!(result.is_err());
+    }
+
     #[test]
     fn change_rule() {
         let mut settings = ThirdPartyDeposits::new(DepositRule::AcceptAll);
@@ -263,3 +823,46 @@ mod tests {
         assert_eq!(settings.deposit_rule, DepositRule::DenyAll);
     }
 }
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn applying_asset_exception_edits() {
+        let address: ResourceAddress =
+            "resource_rdx1tkk83magp3gjyxrpskfsqwkg4g949rmcjee4tu2xmw93ltw2cz94sq"
+                .parse()
+                .unwrap();
+        let exception =
+            AssetException::new(address, DepositAddressExceptionRule::Deny);
+        let sut = ThirdPartyDeposits::default();
+        let edited = third_party_deposits_applying_asset_exception_edits(
+            &sut,
+            vec![AssetExceptionEdit::Add {
+                exception: exception.clone(),
+            }],
+        )
+        .unwrap();
+        assert_eq!(edited.assets_exception_list.items(), vec![exception]);
+        assert!(sut.assets_exception_list.is_empty());
+    }
+
+    #[test]
+    fn adding_non_fungible_exception() {
+        let exception = NonFungibleException::new(
+            "resource_sim1ngktvyeenvvqetnqwysevcx5fyvl6hqe36y3rkhdfdn6uzvt5366ha:<foobar>"
+                .parse()
+                .unwrap(),
+            DepositAddressExceptionRule::Allow,
+        );
+        let sut = ThirdPartyDeposits::default();
+        let edited = third_party_deposits_adding_non_fungible_exception(
+            &sut,
+            exception.clone(),
+        )
+        .unwrap();
+        assert_eq!(edited.non_fungible_exception_list.items(), vec![exception]);
+        assert!(sut.non_fungible_exception_list.is_empty());
+    }
+}
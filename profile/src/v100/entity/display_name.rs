@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use unicode_segmentation::UnicodeSegmentation;
 
 #[derive(
     Clone,
@@ -23,7 +24,23 @@ pub fn new_display_name(name: String) -> Result<DisplayName> {
     DisplayName::new(name.as_str())
 }
 
+/// The max number of grapheme clusters of a `DisplayName`.
+#[uniffi::export]
+pub fn display_name_max_length() -> u64 {
+    DisplayName::MAX_LEN as u64
+}
+
+#[uniffi::export]
+pub fn display_name_truncated(
+    display_name: &DisplayName,
+    max_graphemes: u64,
+) -> DisplayName {
+    display_name.truncated(max_graphemes as usize)
+}
+
 impl DisplayName {
+    /// The max number of grapheme clusters - i.e. user-perceived characters,
+    /// an emoji or CJK character counting as one - not bytes.
     pub const MAX_LEN: usize = 30;
 
     pub fn new(value: &str) -> Result<Self> {
@@ -31,15 +48,35 @@ impl DisplayName {
         if value.is_empty() {
             return Err(CommonError::InvalidDisplayNameEmpty);
         }
-        if value.len() > Self::MAX_LEN {
+        let grapheme_count = value.graphemes(true).count();
+        if grapheme_count > Self::MAX_LEN {
             return Err(CommonError::InvalidDisplayNameTooLong {
                 expected: Self::MAX_LEN,
-                found: value.len(),
+                found: grapheme_count,
             });
         }
 
         Ok(Self { value })
     }
+
+    /// The number of grapheme clusters of the name.
+    pub fn grapheme_count(&self) -> usize {
+        self.value.graphemes(true).count()
+    }
+
+    /// The name cut to at most `max_graphemes` grapheme clusters - but never
+    /// empty - without trailing whitespace, e.g. for displays with limits of
+    /// their own such as a Ledger device. Never splits an emoji or a
+    /// character with combining marks.
+    pub fn truncated(&self, max_graphemes: usize) -> Self {
+        let truncated = self
+            .value
+            .graphemes(true)
+            .take(max_graphemes.max(1))
+            .collect::<String>();
+        Self::new(&truncated)
+            .expect("A truncated valid DisplayName is always valid.")
+    }
 }
 
 impl Default for DisplayName {
@@ -76,6 +113,54 @@ mod tests {
         assert!(DisplayName::new("0|RDX|Dev Nano S|Some very lon").is_ok());
     }
 
+    #[test]
+    fn emoji_counted_as_graphemes() {
+        let s = "👨‍👩‍👧‍👦".repeat(DisplayName::MAX_LEN);
+        assert!(s.len() > DisplayName::MAX_LEN);
+        let sut = DisplayName::new(&s).unwrap();
+        assert_eq!(sut.grapheme_count(), DisplayName::MAX_LEN);
+    }
+
+    #[test]
+    fn cjk_counted_as_graphemes() {
+        assert!(DisplayName::new(&"日".repeat(DisplayName::MAX_LEN)).is_ok());
+        assert_eq!(
+            DisplayName::new(&"日".repeat(DisplayName::MAX_LEN + 1)),
+            Err(CommonError::InvalidDisplayNameTooLong {
+                expected: DisplayName::MAX_LEN,
+                found: DisplayName::MAX_LEN + 1
+            })
+        );
+    }
+
+    #[test]
+    fn combining_marks_counted_as_one() {
+        let sut = DisplayName::new("e\u{301}e\u{301}").unwrap();
+        assert_eq!(sut.grapheme_count(), 2);
+    }
+
+    #[test]
+    fn truncated() {
+        let sut = DisplayName::new("Main account").unwrap();
+        assert_eq!(sut.truncated(4).value, "Main");
+        assert_eq!(sut.truncated(5).value, "Main");
+        assert_eq!(sut.truncated(100), sut);
+    }
+
+    #[test]
+    fn truncated_never_empty() {
+        let sut = DisplayName::new("Main").unwrap();
+        assert_eq!(sut.truncated(0).value, "M");
+    }
+
+    #[test]
+    fn truncated_does_not_split_graphemes() {
+        let sut = DisplayName::new("👨‍👩‍👧‍👦👍🏽e\u{301}").unwrap();
+        assert_eq!(sut.truncated(1).value, "👨‍👩‍👧‍👦");
+        assert_eq!(sut.truncated(2).value, "👨‍👩‍👧‍👦👍🏽");
+        assert_eq!(sut.truncated(3), sut);
+    }
+
     #[test]
     fn valid_try_from() {
         assert_eq!(
@@ -129,7 +214,10 @@ mod tests {
 
 #[cfg(test)]
 mod uniffi_tests {
-    use crate::{new_display_name, DisplayName};
+    use crate::{
+        display_name_max_length, display_name_truncated, new_display_name,
+        DisplayName,
+    };
 
     #[test]
     fn new() {
@@ -138,4 +226,15 @@ mod uniffi_tests {
             DisplayName::new("Main").unwrap(),
         );
     }
+
+    #[test]
+    fn max_length() {
+        assert_eq!(display_name_max_length(), DisplayName::MAX_LEN as u64);
+    }
+
+    #[test]
+    fn truncated() {
+        let sut = DisplayName::new("Main account").unwrap();
+        assert_eq!(display_name_truncated(&sut, 4), sut.truncated(4));
+    }
 }
@@ -61,6 +61,11 @@ pub struct Persona {
     /// email address(es) or phone number(s). This information is only ever stored in Profile and is never
     /// uploaded to the Radix Network.
     pub persona_data: PersonaData,
+
+    /// Fields unknown to this version, written by a newer version of the
+    /// Profile format, re-emitted as-is when encoded.
+    #[serde(flatten)]
+    pub extra: UnknownFields,
 }
 
 impl Persona {
@@ -69,6 +74,47 @@ impl Persona {
         persona_creating_factor_instance: HDFactorInstanceIdentityCreation,
         display_name: DisplayName,
         persona_data: impl Into<Option<PersonaData>>,
+    ) -> Self {
+        let security_state =
+            UnsecuredEntityControl::with_entity_creating_factor_instance(
+                persona_creating_factor_instance.clone(),
+            );
+        Self::with_security_state(
+            persona_creating_factor_instance,
+            security_state,
+            display_name,
+            persona_data,
+        )
+    }
+
+    /// Creates a new `Persona` controlled by both the transaction signing and
+    /// the authentication signing factor instances, if `persona_data` is
+    /// `None`, an empty object will be created.
+    pub fn new_with_auth(
+        persona_creating_factor_instances: HDFactorInstancesIdentityCreationWithAuth,
+        display_name: DisplayName,
+        persona_data: impl Into<Option<PersonaData>>,
+    ) -> Self {
+        let transaction_signing = persona_creating_factor_instances
+            .transaction_signing
+            .clone();
+        let security_state =
+            UnsecuredEntityControl::with_identity_creation_with_auth_factor_instances(
+                persona_creating_factor_instances,
+            );
+        Self::with_security_state(
+            transaction_signing,
+            security_state,
+            display_name,
+            persona_data,
+        )
+    }
+
+    fn with_security_state(
+        persona_creating_factor_instance: HDFactorInstanceIdentityCreation,
+        security_state: UnsecuredEntityControl,
+        display_name: DisplayName,
+        persona_data: impl Into<Option<PersonaData>>,
     ) -> Self {
         let address =
             IdentityAddress::from_hd_factor_instance_virtual_entity_creation(
@@ -78,13 +124,10 @@ impl Persona {
             network_id: persona_creating_factor_instance.network_id(),
             address,
             display_name,
-            security_state:
-                UnsecuredEntityControl::with_entity_creating_factor_instance(
-                    persona_creating_factor_instance,
-                )
-                .into(),
+            security_state: security_state.into(),
             flags: EntityFlags::default(),
             persona_data: persona_data.into().unwrap_or_default(),
+            extra: UnknownFields::default(),
         }
     }
 }
@@ -124,7 +167,8 @@ impl Persona {
                 .map(|v| unsafe {
                     PersonaDataIdentifiedPhoneNumber::with_id(id.next(), v)
                 }),
-        );
+        )
+        .unwrap();
 
         let email_addresses = CollectionOfEmailAddresses::entries(
             email_addresses
@@ -133,7 +177,8 @@ impl Persona {
                 .map(|v| unsafe {
                     PersonaDataIdentifiedEmailAddress::with_id(id.next(), v)
                 }),
-        );
+        )
+        .unwrap();
 
         let mut persona = Self::new(
             private_hd_factor_source
@@ -289,15 +334,16 @@ impl Persona {
 
 impl Ord for Persona {
     fn cmp(&self, other: &Self) -> Ordering {
-        match (&self.security_state, &other.security_state) {
-            (
-                EntitySecurityState::Unsecured { value: l },
-                EntitySecurityState::Unsecured { value: r },
-            ) => l
-                .transaction_signing
-                .derivation_path()
-                .last_component()
-                .cmp(r.transaction_signing.derivation_path().last_component()),
+        let index = |p: &Self| {
+            p.security_state
+                .transaction_signing_factor_instance()
+                .map(|i| i.derivation_path().last_component().clone())
+        };
+        match (index(self), index(other)) {
+            (Some(l), Some(r)) => l.cmp(&r),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
         }
     }
 }
@@ -356,6 +402,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn new_with_auth() {
+        let private =
+            PrivateHierarchicalDeterministicFactorSource::placeholder();
+        let instances = private
+            .derive_identity_creation_with_auth_factor_instances(
+                NetworkID::Mainnet,
+                0,
+            );
+        let display_name = DisplayName::new("Satoshi").unwrap();
+        let sut = Persona::new_with_auth(
+            instances.clone(),
+            display_name.clone(),
+            None,
+        );
+        let without_auth = Persona::new(
+            instances.transaction_signing.clone(),
+            display_name,
+            None,
+        );
+        assert_eq!(sut.address, without_auth.address);
+        assert_eq!(
+            sut.security_state
+                .as_unsecured()
+                .unwrap()
+                .authentication_signing,
+            Some(instances.authentication_signing)
+        );
+    }
+
     #[test]
     fn inequality() {
         assert_ne!(Persona::placeholder(), Persona::placeholder_other());
@@ -3,8 +3,10 @@ use crate::prelude::*;
 /// A persons email address they have chosen to associated with a Persona, e.g.
 /// `satoshi@btc.org`.
 ///
-/// Current implementation does not validate the email address other than it
-/// cannot be empty (in the future we might add some simple validation).
+/// The email address is trimmed and then validated with a simple syntax check:
+/// a single `@` separating a non-empty local part from a domain consisting of
+/// at least two dot separated labels, without any whitespace. We deliberately
+/// do not try to implement the full RFC 5322 grammar.
 #[derive(
     Serialize,
     Deserialize,
@@ -39,14 +41,75 @@ impl FromStr for PersonaDataEntryEmailAddress {
     }
 }
 
+impl TryFrom<&str> for PersonaDataEntryEmailAddress {
+    type Error = CommonError;
+
+    fn try_from(value: &str) -> Result<Self> {
+        Self::new(value)
+    }
+}
+
+#[uniffi::export]
+pub fn new_persona_data_entry_email_address(
+    email: String,
+) -> Result<PersonaDataEntryEmailAddress> {
+    PersonaDataEntryEmailAddress::new(email)
+}
+
 impl PersonaDataEntryEmailAddress {
+    /// Max length of an email address according to RFC 5321.
+    pub const MAX_LEN: usize = 254;
+
+    /// Max length of the local part (before `@`) according to RFC 5321.
+    pub const MAX_LEN_LOCAL_PART: usize = 64;
+
     pub fn new(email: impl AsRef<str>) -> Result<Self> {
-        let email = email.as_ref().to_owned();
+        let email = email.as_ref().trim().to_owned();
         if email.is_empty() {
             return Err(CommonError::PersonaDataInvalidEmailAddressEmpty);
         }
+        if !Self::is_valid(&email) {
+            return Err(CommonError::PersonaDataInvalidEmailAddress(email));
+        }
         Ok(Self { email })
     }
+
+    fn is_valid(email: &str) -> bool {
+        if email.chars().count() > Self::MAX_LEN
+            || email.chars().any(char::is_whitespace)
+        {
+            return false;
+        }
+        let Some((local, domain)) = email.split_once('@') else {
+            return false;
+        };
+        Self::is_valid_local_part(local) && Self::is_valid_domain(domain)
+    }
+
+    fn is_valid_local_part(local: &str) -> bool {
+        !local.is_empty()
+            && local.chars().count() <= Self::MAX_LEN_LOCAL_PART
+            && !local.starts_with('.')
+            && !local.ends_with('.')
+            && !local.contains("..")
+    }
+
+    fn is_valid_domain(domain: &str) -> bool {
+        let labels = domain.split('.').collect_vec();
+        if labels.len() < 2 {
+            return false;
+        }
+        let is_valid_label = |label: &&str| {
+            !label.is_empty()
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_alphanumeric() || c == '-')
+        };
+        let top_level = labels.last().expect("At least two labels");
+        labels.iter().all(is_valid_label)
+            && top_level.chars().count() >= 2
+            && !top_level.chars().all(|c| c.is_ascii_digit())
+    }
 }
 
 impl HasPlaceholder for PersonaDataEntryEmailAddress {
@@ -91,6 +154,71 @@ mod tests {
         );
     }
 
+    #[test]
+    fn valid_emails() {
+        for s in [
+            "alan@turing.hero",
+            "satoshi@nakamoto.btc",
+            "sat@os.hi",
+            "bat@m.an",
+            "granger.h@hogwarts.uk.co",
+            "first.last+tag@sub-domain.example.com",
+            "åsa@exempel.se",
+        ] {
+            assert!(PersonaDataEntryEmailAddress::new(s).is_ok(), "{s}");
+        }
+    }
+
+    #[test]
+    fn invalid_emails() {
+        for s in [
+            "alan",
+            "alan@",
+            "@turing.hero",
+            "alan@turing",
+            "alan@@turing.hero",
+            "alan@turing@hero.com",
+            "alan turing@hero.com",
+            ".alan@turing.hero",
+            "alan.@turing.hero",
+            "al..an@turing.hero",
+            "alan@turing..hero",
+            "alan@-turing.hero",
+            "alan@turing.h",
+            "alan@127.0.0.1",
+        ] {
+            assert_eq!(
+                PersonaDataEntryEmailAddress::new(s),
+                Err(CommonError::PersonaDataInvalidEmailAddress(s.to_owned())),
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_local_part_too_long() {
+        let s = format!(
+            "{}@turing.hero",
+            "a".repeat(PersonaDataEntryEmailAddress::MAX_LEN_LOCAL_PART + 1)
+        );
+        assert!(PersonaDataEntryEmailAddress::new(s).is_err());
+    }
+
+    #[test]
+    fn trims_whitespace() {
+        assert_eq!(
+            PersonaDataEntryEmailAddress::new("  alan@turing.hero\n").unwrap(),
+            PersonaDataEntryEmailAddress::placeholder()
+        );
+    }
+
+    #[test]
+    fn try_from_str() {
+        assert_eq!(
+            PersonaDataEntryEmailAddress::try_from("alan@turing.hero"),
+            Ok(PersonaDataEntryEmailAddress::placeholder())
+        );
+    }
+
     #[test]
     fn json_roundtrip_placeholder() {
         let model = PersonaDataEntryEmailAddress::placeholder();
@@ -129,3 +257,26 @@ mod tests {
         assert_eq!(email, PersonaDataEntryEmailAddress::placeholder());
     }
 }
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn new_email_address() {
+        assert_eq!(
+            new_persona_data_entry_email_address("alan@turing.hero".to_owned()),
+            Ok(PersonaDataEntryEmailAddress::placeholder())
+        );
+    }
+
+    #[test]
+    fn new_email_address_invalid() {
+        assert_eq!(
+            new_persona_data_entry_email_address("alan".to_owned()),
+            Err(CommonError::PersonaDataInvalidEmailAddress(
+                "alan".to_owned()
+            ))
+        );
+    }
+}
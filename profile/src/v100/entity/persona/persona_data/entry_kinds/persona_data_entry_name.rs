@@ -4,6 +4,7 @@ use crate::prelude::*;
 /// or `"Jun-fan 'Bruce' Lee"` using Eastern name variant (family name comes before given name(s)).
 ///
 /// Nickname is optional in the sense that it can be left blank. Family name and given names are never empty.
+/// All fields are trimmed and may not be longer than [`PersonaDataEntryName::MAX_LEN`] characters.
 ///
 /// If a name has multiple given names, they all go into the `given_names` String, e.g. Pippi Longstocking's real name -
 /// her Swedish name - is in full: "Pippilotta Viktualia Rullgardina Krusmynta Efraimsdotter Långstrump", where her
@@ -30,7 +31,19 @@ pub struct PersonaDataEntryName {
     pub nickname: String,
 }
 
+#[uniffi::export]
+pub fn new_persona_data_entry_name(
+    variant: Variant,
+    family_name: String,
+    given_names: String,
+    nickname: String,
+) -> Result<PersonaDataEntryName> {
+    PersonaDataEntryName::new(variant, family_name, given_names, nickname)
+}
+
 impl PersonaDataEntryName {
+    pub const MAX_LEN: usize = 100;
+
     pub fn new(
         variant: Variant,
         family_name: impl AsRef<str>,
@@ -46,6 +59,9 @@ impl PersonaDataEntryName {
         if given_names.is_empty() {
             return Err(CommonError::PersonaDataInvalidNameGivenNamesEmpty);
         }
+        Self::validate_length("family_name", &family_name)?;
+        Self::validate_length("given_names", &given_names)?;
+        Self::validate_length("nickname", &nickname)?;
         Ok(Self {
             variant,
             family_name,
@@ -54,6 +70,18 @@ impl PersonaDataEntryName {
         })
     }
 
+    fn validate_length(field: &str, value: &str) -> Result<()> {
+        let found = value.chars().count();
+        if found > Self::MAX_LEN {
+            return Err(CommonError::PersonaDataInvalidNameFieldTooLong {
+                field: field.to_owned(),
+                expected: Self::MAX_LEN,
+                found,
+            });
+        }
+        Ok(())
+    }
+
     fn full(&self) -> String {
         match self.variant {
             Variant::Western => format!(
@@ -196,6 +224,64 @@ mod tests {
         );
     }
 
+    #[test]
+    fn family_name_too_long_is_err() {
+        let too_long = "x".repeat(PersonaDataEntryName::MAX_LEN + 1);
+        assert_eq!(
+            PersonaDataEntryName::new(
+                Variant::Western,
+                too_long,
+                "Clark",
+                "Superman"
+            ),
+            Err(CommonError::PersonaDataInvalidNameFieldTooLong {
+                field: "family_name".to_owned(),
+                expected: PersonaDataEntryName::MAX_LEN,
+                found: PersonaDataEntryName::MAX_LEN + 1
+            })
+        );
+    }
+
+    #[test]
+    fn nickname_too_long_is_err() {
+        let too_long = "x".repeat(PersonaDataEntryName::MAX_LEN + 1);
+        assert_eq!(
+            PersonaDataEntryName::new(
+                Variant::Western,
+                "Kent",
+                "Clark",
+                too_long
+            )
+            .map_err(|e| e.to_string().contains("nickname")),
+            Err(true)
+        );
+    }
+
+    #[test]
+    fn max_len_counts_chars_not_bytes() {
+        let at_max = "å".repeat(PersonaDataEntryName::MAX_LEN);
+        assert!(PersonaDataEntryName::new(
+            Variant::Western,
+            "Kent",
+            at_max,
+            "Superman"
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn length_is_checked_after_trim() {
+        let padded =
+            format!("  {}  ", "x".repeat(PersonaDataEntryName::MAX_LEN));
+        assert!(PersonaDataEntryName::new(
+            Variant::Western,
+            padded,
+            "Clark",
+            "Superman"
+        )
+        .is_ok());
+    }
+
     #[test]
     fn display_western() {
         let placeholder = PersonaDataEntryName::placeholder();
@@ -240,3 +326,34 @@ mod tests {
         )
     }
 }
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn new_name() {
+        assert_eq!(
+            new_persona_data_entry_name(
+                Variant::Western,
+                "Wayne".to_owned(),
+                " Bruce ".to_owned(),
+                "Batman".to_owned()
+            ),
+            Ok(PersonaDataEntryName::placeholder())
+        );
+    }
+
+    #[test]
+    fn new_name_invalid() {
+        assert_eq!(
+            new_persona_data_entry_name(
+                Variant::Western,
+                "".to_owned(),
+                "Bruce".to_owned(),
+                "Batman".to_owned()
+            ),
+            Err(CommonError::PersonaDataInvalidNameFamilyNameEmpty)
+        );
+    }
+}
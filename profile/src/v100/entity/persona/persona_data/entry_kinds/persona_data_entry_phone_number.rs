@@ -3,8 +3,14 @@ use crate::prelude::*;
 /// A persons telephone number they have chosen to associated with a Persona, e.g.
 /// `+46 987 654 321` (don't try calling this number, it does not exist).
 ///
-/// Current implementation does not validate the phone number other than it
-/// cannot be empty, since telephone number validation is tricky.
+/// The number must be written in international format, E.164-ish: it starts
+/// with a country code prefixed by either `+` or `00`, and contains between
+/// [`PersonaDataEntryPhoneNumber::MIN_DIGITS`] and
+/// [`PersonaDataEntryPhoneNumber::MAX_DIGITS`] digits (including the country
+/// code). Spaces, dashes, dots and parentheses are allowed as separators and
+/// kept as entered, as is a national trunk prefix written as `(0)` - e.g.
+/// `+46 (0)70-123.45.67` - which is not dialed internationally and thus not
+/// counted. Use [`PersonaDataEntryPhoneNumber::e164`] for the normalized form.
 #[derive(
     Serialize,
     Deserialize,
@@ -39,13 +45,80 @@ impl FromStr for PersonaDataEntryPhoneNumber {
     }
 }
 
+impl TryFrom<&str> for PersonaDataEntryPhoneNumber {
+    type Error = CommonError;
+
+    fn try_from(value: &str) -> Result<Self> {
+        Self::new(value)
+    }
+}
+
+#[uniffi::export]
+pub fn new_persona_data_entry_phone_number(
+    number: String,
+) -> Result<PersonaDataEntryPhoneNumber> {
+    PersonaDataEntryPhoneNumber::new(number)
+}
+
 impl PersonaDataEntryPhoneNumber {
+    /// E.164 allows at most 15 digits, including the country code.
+    pub const MAX_DIGITS: usize = 15;
+
+    /// Shortest national numbers are a few digits, together with the
+    /// country code we require at least this many.
+    pub const MIN_DIGITS: usize = 7;
+
     pub fn new(number: impl AsRef<str>) -> Result<Self> {
-        let number = number.as_ref().to_owned();
+        let number = number.as_ref().trim().to_owned();
         if number.is_empty() {
             return Err(CommonError::PersonaDataInvalidPhoneNumberEmpty);
         }
-        Ok(Self { number })
+        Self::international_digits(&number)
+            .map(|_| Self { number })
+            .ok_or(CommonError::PersonaDataInvalidPhoneNumber(number.clone()))
+    }
+
+    /// The number in E.164 format, i.e. `+` followed by the country code and
+    /// subscriber number without any separators or trunk prefix, e.g.
+    /// `+46987654321`.
+    ///
+    /// Returns `Err` if `number` is not a valid international phone number,
+    /// which is possible since it is not validated when deserialized or
+    /// passed by hosts.
+    pub fn e164(&self) -> Result<String> {
+        Self::international_digits(&self.number)
+            .map(|digits| format!("+{}", digits))
+            .ok_or(CommonError::PersonaDataInvalidPhoneNumber(
+                self.number.clone(),
+            ))
+    }
+
+    /// Returns the digits after the international prefix (`+` or `00`),
+    /// without any `(0)` trunk prefix, or `None` if `number` is not a valid
+    /// international phone number.
+    fn international_digits(number: &str) -> Option<String> {
+        let rest = number
+            .strip_prefix('+')
+            .or_else(|| number.strip_prefix("00"))?
+            .replace("(0)", "");
+
+        let mut digits = String::new();
+        for c in rest.chars() {
+            match c {
+                '0'..='9' => digits.push(c),
+                ' ' | '-' | '.' | '(' | ')' => continue,
+                _ => return None,
+            }
+        }
+
+        if digits.starts_with('0') {
+            // Country codes never start with 0
+            return None;
+        }
+        if !(Self::MIN_DIGITS..=Self::MAX_DIGITS).contains(&digits.len()) {
+            return None;
+        }
+        Some(digits)
     }
 }
 
@@ -93,6 +166,124 @@ mod tests {
         );
     }
 
+    #[test]
+    fn invalid_only_whitespace_is_empty() {
+        assert_eq!(
+            PersonaDataEntryPhoneNumber::new("  "),
+            Err(CommonError::PersonaDataInvalidPhoneNumberEmpty)
+        );
+    }
+
+    #[test]
+    fn valid_with_separators() {
+        for s in [
+            "+1 13 371 337",
+            "+42 3 456 789",
+            "+44 123 456 77",
+            "+46 (0)70-123.45.67",
+            "0046701234567",
+        ] {
+            assert!(PersonaDataEntryPhoneNumber::new(s).is_ok(), "{s}");
+        }
+    }
+
+    #[test]
+    fn invalid_numbers() {
+        for s in [
+            "46701234567",       // missing international prefix
+            "+0701234567",       // country code starting with 0
+            "+46 70 abc 4567",   // letters
+            "+46+701234567",     // multiple plus signs
+            "+123456",           // too few digits
+            "+1234567890123456", // too many digits
+        ] {
+            assert_eq!(
+                PersonaDataEntryPhoneNumber::new(s),
+                Err(CommonError::PersonaDataInvalidPhoneNumber(s.to_owned())),
+            );
+        }
+    }
+
+    #[test]
+    fn trims_whitespace() {
+        assert_eq!(
+            PersonaDataEntryPhoneNumber::new(" +46123456789\n").unwrap(),
+            PersonaDataEntryPhoneNumber::placeholder()
+        );
+    }
+
+    #[test]
+    fn e164() {
+        assert_eq!(
+            PersonaDataEntryPhoneNumber::new("+1 13 371 337")
+                .unwrap()
+                .e164(),
+            Ok("+113371337".to_owned())
+        );
+        assert_eq!(
+            PersonaDataEntryPhoneNumber::new("0044 (987) 654-321")
+                .unwrap()
+                .e164(),
+            Ok("+44987654321".to_owned())
+        );
+    }
+
+    #[test]
+    fn e164_strips_trunk_prefix() {
+        assert_eq!(
+            PersonaDataEntryPhoneNumber::new("+46 (0)70-123.45.67")
+                .unwrap()
+                .e164(),
+            Ok("+46701234567".to_owned())
+        );
+        assert_eq!(
+            PersonaDataEntryPhoneNumber::new("+44 (0) 20 7946 0958")
+                .unwrap()
+                .e164(),
+            Ok("+442079460958".to_owned())
+        );
+    }
+
+    #[test]
+    fn trunk_prefix_is_not_counted() {
+        let s = "+46 (0)1234";
+        assert_eq!(
+            PersonaDataEntryPhoneNumber::new(s),
+            Err(CommonError::PersonaDataInvalidPhoneNumber(s.to_owned()))
+        );
+    }
+
+    #[test]
+    fn e164_of_unvalidated_number_fails() {
+        let sut = PersonaDataEntryPhoneNumber {
+            number: "112".to_owned(),
+        };
+        assert_eq!(
+            sut.e164(),
+            Err(CommonError::PersonaDataInvalidPhoneNumber("112".to_owned()))
+        );
+    }
+
+    #[test]
+    fn e164_of_invalid_deserialized_number_fails() {
+        let sut: PersonaDataEntryPhoneNumber =
+            serde_json::from_value(json!("not a number")).unwrap();
+        assert_eq!(
+            sut.e164(),
+            Err(CommonError::PersonaDataInvalidPhoneNumber(
+                "not a number".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    fn try_from_str() {
+        assert_eq!(
+            PersonaDataEntryPhoneNumber::try_from("+46123456789"),
+            Ok(PersonaDataEntryPhoneNumber::placeholder())
+        );
+    }
+
     #[test]
     fn json_roundtrip_placeholder() {
         let model = PersonaDataEntryPhoneNumber::placeholder();
@@ -131,3 +322,24 @@ mod tests {
         assert_eq!(phone, PersonaDataEntryPhoneNumber::placeholder());
     }
 }
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn new_phone_number() {
+        assert_eq!(
+            new_persona_data_entry_phone_number("+46123456789".to_owned()),
+            Ok(PersonaDataEntryPhoneNumber::placeholder())
+        );
+    }
+
+    #[test]
+    fn new_phone_number_invalid() {
+        assert_eq!(
+            new_persona_data_entry_phone_number("112".to_owned()),
+            Err(CommonError::PersonaDataInvalidPhoneNumber("112".to_owned()))
+        );
+    }
+}
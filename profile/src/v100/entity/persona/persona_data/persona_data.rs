@@ -45,13 +45,13 @@ pub struct PersonaData {
     pub name: Option<PersonaDataIdentifiedName>,
 
     /// A collection of [`PersonaDataIdentifiedPhoneNumber`]s, which is essentially a tuple of
-    /// `(Uuid, PersonaDataEntryPhoneNumber)`, each element is identifiable by its ID. Can be empty, can
-    /// contain elements with the same value, but under different IDs.
+    /// `(Uuid, PersonaDataEntryPhoneNumber)`, each element is identifiable by its ID. Can be empty, cannot
+    /// contain two elements with the same ID or value.
     pub phone_numbers: CollectionOfPhoneNumbers,
 
     /// A collection of [`PersonaDataEntryEmailAddress`]s, which is essentially a tuple of
-    /// `(Uuid, PersonaDataIdentifiedEmailAddress)`, each element is identifiable by its ID. Can be empty, can
-    /// contain elements with the same value, but under different IDs.
+    /// `(Uuid, PersonaDataIdentifiedEmailAddress)`, each element is identifiable by its ID. Can be empty, cannot
+    /// contain two elements with the same ID or value.
     pub email_addresses: CollectionOfEmailAddresses,
 }
 
@@ -4,11 +4,17 @@ use crate::prelude::*;
 // reason this macro exists, if/when UniFFI supports generics, this macro should
 // be replaced by `CollectionOfIdentifiedPersonaDataEntries<T>`.
 macro_rules! declare_collection_of_identified_entry {
-    ($id_ent_type:ty,$struct_name:ident) => {
+    ($id_ent_type:ty,$struct_name:ident,$max_entries:expr) => {
         /// A collection of identifiable PersonaData Entries.
+        ///
+        /// Entries are kept in insertion order, which is also the order they
+        /// are serialized in, and can be explicitly reordered using
+        /// `move_entry_up` and `move_entry_down`. The collection never holds
+        /// more than `MAX_ENTRIES` entries and never holds two entries with
+        /// the same ID or the same value, which is validated when created
+        /// using `entries` or `values`, when deserialized and by `try_append`.
         #[derive(
             Serialize,
-            Deserialize,
             Clone,
             PartialEq,
             Hash,
@@ -26,7 +32,9 @@ macro_rules! declare_collection_of_identified_entry {
 
         impl Default for $struct_name {
             fn default() -> Self {
-                Self::entries([])
+                Self {
+                    collection: IdentifiedVecVia::new(),
+                }
             }
         }
 
@@ -38,18 +46,36 @@ macro_rules! declare_collection_of_identified_entry {
             }
         }
 
+        impl<'de> Deserialize<'de> for $struct_name {
+            #[cfg(not(tarpaulin_include))] // false negative
+            fn deserialize<D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Self, D::Error> {
+                let entries = Vec::<$id_ent_type>::deserialize(deserializer)?;
+                Self::entries(entries).map_err(de::Error::custom)
+            }
+        }
+
         impl $struct_name {
-            pub fn entries<I>(values: I) -> Self
+            pub const MAX_ENTRIES: usize = $max_entries;
+
+            /// Creates a new collection from `entries`, keeping their order,
+            /// failing if there are too many entries or any duplicates.
+            pub fn entries<I>(entries: I) -> Result<Self>
             where
                 I: IntoIterator<Item = $id_ent_type>,
             {
-                Self {
-                    collection: IdentifiedVecVia::from_iter(values),
-                }
+                let entries = entries.into_iter().collect_vec();
+                Self::validate(&entries)?;
+                Ok(Self {
+                    collection: IdentifiedVecVia::from_iter(entries),
+                })
             }
 
+            /// Creates a new collection with the single entry `value`.
             pub fn new(value: $id_ent_type) -> Self {
                 Self::entries([value])
+                    .expect("A single entry is never too many nor duplicate")
             }
 
             /// Creates a new CollectionOf PersonaDataEntries using just the *value*, which will be given a
@@ -60,7 +86,9 @@ macro_rules! declare_collection_of_identified_entry {
                 Self::new(value.into())
             }
 
-            pub fn values<I>(values: I) -> Self
+            /// Creates a new collection of `values`, each given a generated
+            /// ID, see [`Self::entries`].
+            pub fn values<I>(values: I) -> Result<Self>
             where
                 I: IntoIterator<
                     Item = <$id_ent_type as PersonaDataEntryValue>::Value,
@@ -71,6 +99,79 @@ macro_rules! declare_collection_of_identified_entry {
         }
 
         impl $struct_name {
+            /// Appends `entry` last, failing if the collection is full or
+            /// already contains an entry with the same ID or value.
+            pub fn try_append(&mut self, entry: $id_ent_type) -> Result<()> {
+                let mut entries = self.collection.items();
+                entries.push(entry);
+                *self = Self::entries(entries)?;
+                Ok(())
+            }
+
+            /// Moves the entry with `id` one step towards the start of the
+            /// collection, moving the first entry up is a no-op.
+            pub fn move_entry_up(
+                &mut self,
+                id: &PersonaDataEntryID,
+            ) -> Result<()> {
+                self.move_entry(id, true)
+            }
+
+            /// Moves the entry with `id` one step towards the end of the
+            /// collection, moving the last entry down is a no-op.
+            pub fn move_entry_down(
+                &mut self,
+                id: &PersonaDataEntryID,
+            ) -> Result<()> {
+                self.move_entry(id, false)
+            }
+
+            fn move_entry(
+                &mut self,
+                id: &PersonaDataEntryID,
+                up: bool,
+            ) -> Result<()> {
+                let mut entries = self.collection.items();
+                let index = entries.iter().position(|e| &e.id == id).ok_or(
+                    CommonError::PersonaDataCollectionUnknownEntryID(
+                        id.to_string(),
+                    ),
+                )?;
+                let target = if up {
+                    index.checked_sub(1)
+                } else {
+                    Some(index + 1).filter(|t| *t < entries.len())
+                };
+                if let Some(target) = target {
+                    entries.swap(index, target);
+                    self.collection = IdentifiedVecVia::from_iter(entries);
+                }
+                Ok(())
+            }
+
+            fn validate(entries: &[$id_ent_type]) -> Result<()> {
+                if entries.len() > Self::MAX_ENTRIES {
+                    return Err(
+                        CommonError::PersonaDataCollectionTooManyEntries {
+                            max: Self::MAX_ENTRIES,
+                        },
+                    );
+                }
+                for (index, entry) in entries.iter().enumerate() {
+                    if entries[..index]
+                        .iter()
+                        .any(|e| e.id == entry.id || e.value == entry.value)
+                    {
+                        return Err(
+                            CommonError::PersonaDataCollectionDuplicateEntry(
+                                entry.to_string(),
+                            ),
+                        );
+                    }
+                }
+                Ok(())
+            }
+
             fn display_string(&self) -> String {
                 let items =
                     self.items().into_iter().map(|v| v.to_string()).join(", ");
@@ -84,6 +185,7 @@ macro_rules! declare_collection_of_identified_entry {
                     <$id_ent_type>::placeholder(),
                     <$id_ent_type>::placeholder_other(),
                 ])
+                .expect("Valid placeholder.")
             }
 
             fn placeholder_other() -> Self {
@@ -95,14 +197,106 @@ macro_rules! declare_collection_of_identified_entry {
 
 declare_collection_of_identified_entry!(
     PersonaDataIdentifiedPhoneNumber,
-    CollectionOfPhoneNumbers
+    CollectionOfPhoneNumbers,
+    10
 );
 
 declare_collection_of_identified_entry!(
     PersonaDataIdentifiedEmailAddress,
-    CollectionOfEmailAddresses
+    CollectionOfEmailAddresses,
+    10
 );
 
+/// Creates a new collection from `entries`, keeping their order, failing if
+/// there are too many entries or any duplicates.
+#[uniffi::export]
+pub fn new_collection_of_phone_numbers(
+    entries: Vec<PersonaDataIdentifiedPhoneNumber>,
+) -> Result<CollectionOfPhoneNumbers> {
+    CollectionOfPhoneNumbers::entries(entries)
+}
+
+/// Returns `collection` with `entry` appended last, failing if it is full or
+/// already contains an entry with the same ID or value.
+#[uniffi::export]
+pub fn collection_of_phone_numbers_try_append(
+    collection: CollectionOfPhoneNumbers,
+    entry: PersonaDataIdentifiedPhoneNumber,
+) -> Result<CollectionOfPhoneNumbers> {
+    let mut collection = collection;
+    collection.try_append(entry)?;
+    Ok(collection)
+}
+
+/// Returns `collection` with the entry with `id` moved one step towards the
+/// start.
+#[uniffi::export]
+pub fn collection_of_phone_numbers_move_entry_up(
+    collection: CollectionOfPhoneNumbers,
+    id: PersonaDataEntryID,
+) -> Result<CollectionOfPhoneNumbers> {
+    let mut collection = collection;
+    collection.move_entry_up(&id)?;
+    Ok(collection)
+}
+
+/// Returns `collection` with the entry with `id` moved one step towards the
+/// end.
+#[uniffi::export]
+pub fn collection_of_phone_numbers_move_entry_down(
+    collection: CollectionOfPhoneNumbers,
+    id: PersonaDataEntryID,
+) -> Result<CollectionOfPhoneNumbers> {
+    let mut collection = collection;
+    collection.move_entry_down(&id)?;
+    Ok(collection)
+}
+
+/// Creates a new collection from `entries`, keeping their order, failing if
+/// there are too many entries or any duplicates.
+#[uniffi::export]
+pub fn new_collection_of_email_addresses(
+    entries: Vec<PersonaDataIdentifiedEmailAddress>,
+) -> Result<CollectionOfEmailAddresses> {
+    CollectionOfEmailAddresses::entries(entries)
+}
+
+/// Returns `collection` with `entry` appended last, failing if it is full or
+/// already contains an entry with the same ID or value.
+#[uniffi::export]
+pub fn collection_of_email_addresses_try_append(
+    collection: CollectionOfEmailAddresses,
+    entry: PersonaDataIdentifiedEmailAddress,
+) -> Result<CollectionOfEmailAddresses> {
+    let mut collection = collection;
+    collection.try_append(entry)?;
+    Ok(collection)
+}
+
+/// Returns `collection` with the entry with `id` moved one step towards the
+/// start.
+#[uniffi::export]
+pub fn collection_of_email_addresses_move_entry_up(
+    collection: CollectionOfEmailAddresses,
+    id: PersonaDataEntryID,
+) -> Result<CollectionOfEmailAddresses> {
+    let mut collection = collection;
+    collection.move_entry_up(&id)?;
+    Ok(collection)
+}
+
+/// Returns `collection` with the entry with `id` moved one step towards the
+/// end.
+#[uniffi::export]
+pub fn collection_of_email_addresses_move_entry_down(
+    collection: CollectionOfEmailAddresses,
+    id: PersonaDataEntryID,
+) -> Result<CollectionOfEmailAddresses> {
+    let mut collection = collection;
+    collection.move_entry_down(&id)?;
+    Ok(collection)
+}
+
 #[cfg(test)]
 mod collection_of_phone_numbers_tests {
     use crate::prelude::*;
@@ -143,7 +337,7 @@ mod collection_of_phone_numbers_tests {
     fn new_with_values() {
         let value0 = PersonaDataEntryPhoneNumber::placeholder();
         let value1 = PersonaDataEntryPhoneNumber::placeholder_other();
-        let sut = SUT::values([value0.clone(), value1.clone()]);
+        let sut = SUT::values([value0.clone(), value1.clone()]).unwrap();
         assert_eq!(
             sut.collection.into_iter().map(|e| e.value).collect_vec(),
             vec![value0, value1]
@@ -308,7 +502,7 @@ mod collection_of_email_addresses_tests {
     fn new_with_values() {
         let value0 = PersonaDataEntryEmailAddress::placeholder();
         let value1 = PersonaDataEntryEmailAddress::placeholder_other();
-        let sut = SUT::values([value0.clone(), value1.clone()]);
+        let sut = SUT::values([value0.clone(), value1.clone()]).unwrap();
         assert_eq!(
             sut.collection.into_iter().map(|e| e.value).collect_vec(),
             vec![value0, value1]
@@ -351,3 +545,329 @@ mod collection_of_email_addresses_tests {
         )
     }
 }
+
+// Reordering and limits are implemented once in the macro, so we only test
+// them for one of the collection types.
+#[cfg(test)]
+mod collection_limits_and_ordering_tests {
+    use crate::prelude::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = CollectionOfPhoneNumbers;
+    type V = PersonaDataIdentifiedPhoneNumber;
+
+    fn entry(n: usize) -> V {
+        V::new(
+            PersonaDataEntryPhoneNumber::new(format!("+4670000{:04}", n))
+                .unwrap(),
+        )
+    }
+
+    fn numbers(sut: &SUT) -> Vec<String> {
+        sut.items()
+            .into_iter()
+            .map(|e| e.value.number)
+            .collect_vec()
+    }
+
+    #[test]
+    fn try_entries_keeps_order() {
+        let sut =
+            SUT::entries([V::placeholder_other(), V::placeholder()]).unwrap();
+        assert_eq!(numbers(&sut), vec!["+44987654321", "+46123456789"]);
+    }
+
+    #[test]
+    fn try_entries_too_many() {
+        assert_eq!(
+            SUT::entries((0..=SUT::MAX_ENTRIES).map(entry)),
+            Err(CommonError::PersonaDataCollectionTooManyEntries {
+                max: SUT::MAX_ENTRIES
+            })
+        );
+    }
+
+    #[test]
+    fn try_entries_max_is_ok() {
+        assert_eq!(
+            SUT::entries((0..SUT::MAX_ENTRIES).map(entry))
+                .unwrap()
+                .len(),
+            SUT::MAX_ENTRIES
+        );
+    }
+
+    #[test]
+    fn try_entries_duplicate_value() {
+        let value = PersonaDataEntryPhoneNumber::placeholder();
+        assert_eq!(
+            SUT::entries([V::new(value.clone()), V::new(value.clone())]),
+            Err(CommonError::PersonaDataCollectionDuplicateEntry(
+                value.to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn try_entries_duplicate_id() {
+        let other = V::with_id(
+            V::placeholder().id,
+            PersonaDataEntryPhoneNumber::placeholder_other(),
+        );
+        assert_eq!(
+            SUT::entries([V::placeholder(), other.clone()]),
+            Err(CommonError::PersonaDataCollectionDuplicateEntry(
+                other.to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn try_append() {
+        let mut sut = SUT::new(V::placeholder());
+        assert_eq!(sut.try_append(V::placeholder_other()), Ok(()));
+        assert_eq!(sut, SUT::placeholder());
+    }
+
+    #[test]
+    fn try_append_duplicate_is_err_and_unchanged() {
+        let mut sut = SUT::placeholder();
+        assert!(sut
+            .try_append(V::new(PersonaDataEntryPhoneNumber::placeholder()))
+            .is_err());
+        assert_eq!(sut, SUT::placeholder());
+    }
+
+    #[test]
+    fn try_append_full_is_err() {
+        let mut sut = SUT::entries((0..SUT::MAX_ENTRIES).map(entry)).unwrap();
+        assert_eq!(
+            sut.try_append(entry(SUT::MAX_ENTRIES)),
+            Err(CommonError::PersonaDataCollectionTooManyEntries {
+                max: SUT::MAX_ENTRIES
+            })
+        );
+    }
+
+    #[test]
+    fn move_entry_up() {
+        let mut sut = SUT::entries((0..3).map(entry)).unwrap();
+        let id = sut.items()[2].id.clone();
+        sut.move_entry_up(&id).unwrap();
+        assert_eq!(
+            numbers(&sut),
+            vec!["+46700000000", "+46700000002", "+46700000001"]
+        );
+        sut.move_entry_up(&id).unwrap();
+        assert_eq!(
+            numbers(&sut),
+            vec!["+46700000002", "+46700000000", "+46700000001"]
+        );
+        sut.move_entry_up(&id).unwrap(); // already first, no-op
+        assert_eq!(
+            numbers(&sut),
+            vec!["+46700000002", "+46700000000", "+46700000001"]
+        );
+    }
+
+    #[test]
+    fn move_entry_down() {
+        let mut sut = SUT::entries((0..3).map(entry)).unwrap();
+        let id = sut.items()[1].id.clone();
+        sut.move_entry_down(&id).unwrap();
+        assert_eq!(
+            numbers(&sut),
+            vec!["+46700000000", "+46700000002", "+46700000001"]
+        );
+        sut.move_entry_down(&id).unwrap(); // already last, no-op
+        assert_eq!(
+            numbers(&sut),
+            vec!["+46700000000", "+46700000002", "+46700000001"]
+        );
+    }
+
+    #[test]
+    fn move_unknown_entry_is_err() {
+        let mut sut = SUT::placeholder_other();
+        let id = V::placeholder().id;
+        assert_eq!(
+            sut.move_entry_up(&id),
+            Err(CommonError::PersonaDataCollectionUnknownEntryID(
+                id.to_string()
+            ))
+        );
+        assert_eq!(
+            sut.move_entry_down(&id),
+            Err(CommonError::PersonaDataCollectionUnknownEntryID(
+                id.to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn placeholder_is_valid() {
+        assert_eq!(
+            SUT::entries(SUT::placeholder().items()),
+            Ok(SUT::placeholder())
+        );
+    }
+
+    #[test]
+    fn values_duplicate() {
+        let value = PersonaDataEntryPhoneNumber::placeholder();
+        assert_eq!(
+            SUT::values([value.clone(), value.clone()]),
+            Err(CommonError::PersonaDataCollectionDuplicateEntry(
+                value.to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn json_with_duplicate_value_fails() {
+        assert!(serde_json::from_value::<SUT>(json!([
+            {
+                "id": "00000000-0000-0000-0000-000000000001",
+                "value": "+46123456789"
+            },
+            {
+                "id": "00000000-0000-0000-0000-000000000002",
+                "value": "+46123456789"
+            }
+        ]))
+        .is_err());
+    }
+
+    #[test]
+    fn json_with_duplicate_id_fails() {
+        assert!(serde_json::from_value::<SUT>(json!([
+            {
+                "id": "00000000-0000-0000-0000-000000000001",
+                "value": "+46123456789"
+            },
+            {
+                "id": "00000000-0000-0000-0000-000000000001",
+                "value": "+44987654321"
+            }
+        ]))
+        .is_err());
+    }
+
+    #[test]
+    fn json_with_too_many_entries_fails() {
+        let json = serde_json::to_value(
+            (0..=SUT::MAX_ENTRIES).map(entry).collect_vec(),
+        )
+        .unwrap();
+        assert!(serde_json::from_value::<SUT>(json).is_err());
+    }
+
+    #[test]
+    fn json_order_after_reorder() {
+        let mut sut = SUT::placeholder();
+        sut.move_entry_down(&V::placeholder().id).unwrap();
+        assert_eq_after_json_roundtrip(
+            &sut,
+            r#"
+            [
+                {
+                    "id": "00000000-0000-0000-0000-000000000002",
+                    "value": "+44987654321"
+                },
+                {
+                    "id": "00000000-0000-0000-0000-000000000001",
+                    "value": "+46123456789"
+                }
+            ]
+            "#,
+        )
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn new_collection_of_phone_numbers_duplicate() {
+        let entry = PersonaDataIdentifiedPhoneNumber::placeholder();
+        assert_eq!(
+            new_collection_of_phone_numbers(vec![entry.clone(), entry.clone()]),
+            Err(CommonError::PersonaDataCollectionDuplicateEntry(
+                entry.to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn phone_numbers_append_and_move() {
+        let sut = collection_of_phone_numbers_try_append(
+            CollectionOfPhoneNumbers::new(
+                PersonaDataIdentifiedPhoneNumber::placeholder(),
+            ),
+            PersonaDataIdentifiedPhoneNumber::placeholder_other(),
+        )
+        .unwrap();
+        assert_eq!(sut, CollectionOfPhoneNumbers::placeholder());
+        let id = PersonaDataIdentifiedPhoneNumber::placeholder_other().id;
+        let moved =
+            collection_of_phone_numbers_move_entry_up(sut.clone(), id.clone())
+                .unwrap();
+        assert_eq!(
+            moved.items(),
+            vec![
+                PersonaDataIdentifiedPhoneNumber::placeholder_other(),
+                PersonaDataIdentifiedPhoneNumber::placeholder()
+            ]
+        );
+        assert_eq!(
+            collection_of_phone_numbers_move_entry_down(moved, id),
+            Ok(sut)
+        );
+    }
+
+    #[test]
+    fn new_collection_of_email_addresses_keeps_order() {
+        let entries = vec![
+            PersonaDataIdentifiedEmailAddress::placeholder_other(),
+            PersonaDataIdentifiedEmailAddress::placeholder(),
+        ];
+        assert_eq!(
+            new_collection_of_email_addresses(entries.clone())
+                .unwrap()
+                .items(),
+            entries
+        );
+    }
+
+    #[test]
+    fn email_addresses_append_and_move() {
+        let sut = collection_of_email_addresses_try_append(
+            CollectionOfEmailAddresses::new(
+                PersonaDataIdentifiedEmailAddress::placeholder(),
+            ),
+            PersonaDataIdentifiedEmailAddress::placeholder_other(),
+        )
+        .unwrap();
+        assert_eq!(sut, CollectionOfEmailAddresses::placeholder());
+        let id = PersonaDataIdentifiedEmailAddress::placeholder().id;
+        let moved = collection_of_email_addresses_move_entry_down(
+            sut.clone(),
+            id.clone(),
+        )
+        .unwrap();
+        assert_eq!(
+            collection_of_email_addresses_move_entry_up(moved, id),
+            Ok(sut)
+        );
+    }
+
+    #[test]
+    fn email_addresses_append_duplicate_fails() {
+        assert!(collection_of_email_addresses_try_append(
+            CollectionOfEmailAddresses::placeholder(),
+            PersonaDataIdentifiedEmailAddress::placeholder()
+        )
+        .is_err());
+    }
+}
@@ -2,25 +2,29 @@ use crate::prelude::*;
 
 /// Describes the state an entity - Account or Persona - is in, in regards to how
 /// the user controls it, i.e. if it is controlled by a single factor (private key)
-///  or an `AccessController` with a potential Multi-Factor setup.
-#[derive(
-    Serialize,
-    Deserialize,
-    Clone,
-    Debug,
-    PartialEq,
-    Eq,
-    Hash,
-    EnumAsInner,
-    uniffi::Enum,
-)]
-#[serde(untagged, remote = "Self")]
+///  or an `AccessController` with a potential Multi-Factor setup, or not
+/// controlled by the user at all.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, EnumAsInner, uniffi::Enum)]
 pub enum EntitySecurityState {
     /// The account is controlled by a single factor (private key)
-    Unsecured {
-        #[serde(rename = "unsecuredEntityControl")]
-        value: UnsecuredEntityControl,
-    },
+    Unsecured { value: UnsecuredEntityControl },
+
+    /// The account is watch-only, imported by address only, no factor
+    /// instance of the user controls it, so it cannot sign anything.
+    Unowned,
+}
+
+impl EntitySecurityState {
+    /// The factor instance signing transactions of the entity, `None` if the
+    /// entity is watch-only.
+    pub fn transaction_signing_factor_instance(
+        &self,
+    ) -> Option<&HierarchicalDeterministicFactorInstance> {
+        match self {
+            Self::Unsecured { value } => Some(&value.transaction_signing),
+            Self::Unowned => None,
+        }
+    }
 }
 
 impl<'de> Deserialize<'de> for EntitySecurityState {
@@ -28,15 +32,26 @@ impl<'de> Deserialize<'de> for EntitySecurityState {
     fn deserialize<D: Deserializer<'de>>(
         deserializer: D,
     ) -> Result<Self, D::Error> {
-        // https://github.com/serde-rs/serde/issues/1343#issuecomment-409698470
-        #[derive(Deserialize, Serialize)]
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
         struct Wrapper {
-            #[serde(rename = "discriminator")]
-            _ignore: String,
-            #[serde(flatten, with = "EntitySecurityState")]
-            value: EntitySecurityState,
+            discriminator: String,
+            unsecured_entity_control: Option<UnsecuredEntityControl>,
+        }
+        let wrapper = Wrapper::deserialize(deserializer)?;
+        match wrapper.discriminator.as_str() {
+            "unsecured" => wrapper
+                .unsecured_entity_control
+                .map(|value| Self::Unsecured { value })
+                .ok_or_else(|| {
+                    de::Error::missing_field("unsecuredEntityControl")
+                }),
+            "unowned" => Ok(Self::Unowned),
+            other => Err(de::Error::unknown_variant(
+                other,
+                &["unsecured", "unowned"],
+            )),
         }
-        Wrapper::deserialize(deserializer).map(|w| w.value)
     }
 }
 
@@ -46,15 +61,21 @@ impl Serialize for EntitySecurityState {
     where
         S: Serializer,
     {
-        let mut state =
-            serializer.serialize_struct("EntitySecurityState", 2)?;
         match self {
             EntitySecurityState::Unsecured { value } => {
+                let mut state =
+                    serializer.serialize_struct("EntitySecurityState", 2)?;
                 state.serialize_field("discriminator", "unsecured")?;
                 state.serialize_field("unsecuredEntityControl", value)?;
+                state.end()
+            }
+            EntitySecurityState::Unowned => {
+                let mut state =
+                    serializer.serialize_struct("EntitySecurityState", 1)?;
+                state.serialize_field("discriminator", "unowned")?;
+                state.end()
             }
         }
-        state.end()
     }
 }
 
@@ -102,6 +123,51 @@ mod tests {
             EntitySecurityState::placeholder(),
             EntitySecurityState::placeholder_other()
         );
+        assert_ne!(
+            EntitySecurityState::placeholder(),
+            EntitySecurityState::Unowned
+        );
+    }
+
+    #[test]
+    fn transaction_signing_factor_instance() {
+        assert_eq!(
+            EntitySecurityState::placeholder()
+                .transaction_signing_factor_instance(),
+            Some(&UnsecuredEntityControl::placeholder().transaction_signing)
+        );
+        assert_eq!(
+            EntitySecurityState::Unowned.transaction_signing_factor_instance(),
+            None
+        );
+    }
+
+    #[test]
+    fn json_roundtrip_unowned() {
+        assert_eq_after_json_roundtrip(
+            &EntitySecurityState::Unowned,
+            r#"
+            {
+                "discriminator": "unowned"
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn json_unsecured_without_control_fails() {
+        assert!(serde_json::from_str::<EntitySecurityState>(
+            r#"{ "discriminator": "unsecured" }"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn json_unknown_discriminator_fails() {
+        assert!(serde_json::from_str::<EntitySecurityState>(
+            r#"{ "discriminator": "securified" }"#
+        )
+        .is_err());
     }
 
     #[test]
@@ -31,6 +31,19 @@ impl UnsecuredEntityControl {
         }
     }
 
+    /// Controls a new Persona by both its transaction signing and its
+    /// authentication signing factor instances, set together.
+    pub fn with_identity_creation_with_auth_factor_instances(
+        factor_instances: HDFactorInstancesIdentityCreationWithAuth,
+    ) -> Self {
+        Self {
+            transaction_signing: factor_instances.transaction_signing.into(),
+            authentication_signing: Some(
+                factor_instances.authentication_signing,
+            ),
+        }
+    }
+
     #[cfg(not(tarpaulin_include))] // false negative
     pub fn new(
         transaction_signing: HierarchicalDeterministicFactorInstance,
@@ -92,6 +105,27 @@ impl HasPlaceholder for UnsecuredEntityControl {
 mod tests {
     use crate::prelude::*;
 
+    #[test]
+    fn with_identity_creation_with_auth_factor_instances() {
+        let instances =
+            PrivateHierarchicalDeterministicFactorSource::placeholder()
+                .derive_identity_creation_with_auth_factor_instances(
+                    NetworkID::Mainnet,
+                    0,
+                );
+        let sut = UnsecuredEntityControl::with_identity_creation_with_auth_factor_instances(
+            instances.clone(),
+        );
+        assert_eq!(
+            sut,
+            UnsecuredEntityControl::new(
+                instances.transaction_signing.into(),
+                Some(instances.authentication_signing)
+            )
+            .unwrap()
+        );
+    }
+
     #[test]
     fn equality() {
         assert_eq!(
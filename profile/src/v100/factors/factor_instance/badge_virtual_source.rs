@@ -9,16 +9,40 @@ pub enum FactorInstanceBadgeVirtualSource {
         #[serde(rename = "hierarchicalDeterministicPublicKey")]
         value: HierarchicalDeterministicPublicKey,
     },
+
+    /// The address of an Account not controlled by this wallet, e.g. of a
+    /// trusted contact, whose signatures are produced by its owner rather
+    /// than by a key derived by this wallet.
+    Address {
+        #[serde(rename = "address")]
+        value: AccountAddress,
+    },
 }
 
 impl FactorInstanceBadgeVirtualSource {
+    /// The value of the `"discriminator"` JSON field of this variant.
+    pub fn discriminator(&self) -> &'static str {
+        match self {
+            Self::HierarchicalDeterministic { .. } => {
+                "hierarchicalDeterministicPublicKey"
+            }
+            Self::Address { .. } => "address",
+        }
+    }
+
     pub fn as_hierarchical_deterministic(
         &self,
-    ) -> &HierarchicalDeterministicPublicKey {
+    ) -> Option<&HierarchicalDeterministicPublicKey> {
         match self {
-            FactorInstanceBadgeVirtualSource::HierarchicalDeterministic {
-                value,
-            } => value,
+            Self::HierarchicalDeterministic { value } => Some(value),
+            Self::Address { .. } => None,
+        }
+    }
+
+    pub fn as_address(&self) -> Option<&AccountAddress> {
+        match self {
+            Self::Address { value } => Some(value),
+            Self::HierarchicalDeterministic { .. } => None,
         }
     }
 }
@@ -31,6 +55,12 @@ impl From<HierarchicalDeterministicPublicKey>
     }
 }
 
+impl From<AccountAddress> for FactorInstanceBadgeVirtualSource {
+    fn from(value: AccountAddress) -> Self {
+        Self::Address { value }
+    }
+}
+
 impl<'de> Deserialize<'de> for FactorInstanceBadgeVirtualSource {
     #[cfg(not(tarpaulin_include))] // false negative
     fn deserialize<D: Deserializer<'de>>(
@@ -39,12 +69,19 @@ impl<'de> Deserialize<'de> for FactorInstanceBadgeVirtualSource {
         // https://github.com/serde-rs/serde/issues/1343#issuecomment-409698470
         #[derive(Deserialize, Serialize)]
         struct Wrapper {
-            #[serde(rename = "discriminator")]
-            _ignore: String,
+            discriminator: String,
             #[serde(flatten, with = "FactorInstanceBadgeVirtualSource")]
             value: FactorInstanceBadgeVirtualSource,
         }
-        Wrapper::deserialize(deserializer).map(|w| w.value)
+        let wrapper = Wrapper::deserialize(deserializer)?;
+        if wrapper.discriminator != wrapper.value.discriminator() {
+            return Err(de::Error::custom(format!(
+                "Discriminator '{}' does not match virtual source '{}'",
+                wrapper.discriminator,
+                wrapper.value.discriminator()
+            )));
+        }
+        Ok(wrapper.value)
     }
 }
 
@@ -56,12 +93,15 @@ impl Serialize for FactorInstanceBadgeVirtualSource {
     {
         let mut state = serializer
             .serialize_struct("FactorInstanceBadgeVirtualSource", 2)?;
+        let discriminant = self.discriminator();
+        state.serialize_field("discriminator", discriminant)?;
         match self {
             FactorInstanceBadgeVirtualSource::HierarchicalDeterministic {
                 value,
             } => {
-                let discriminant = "hierarchicalDeterministicPublicKey";
-                state.serialize_field("discriminator", discriminant)?;
+                state.serialize_field(discriminant, value)?;
+            }
+            FactorInstanceBadgeVirtualSource::Address { value } => {
                 state.serialize_field(discriminant, value)?;
             }
         }
@@ -132,4 +172,55 @@ mod tests {
             "#,
         );
     }
+
+    #[test]
+    fn address_json_roundtrip() {
+        let model = FactorInstanceBadgeVirtualSource::from(
+            AccountAddress::placeholder_mainnet(),
+        );
+        assert_eq_after_json_roundtrip(
+            &model,
+            r#"
+            {
+                "address": "account_rdx16xlfcpp0vf7e3gqnswv8j9k58n6rjccu58vvspmdva22kf3aplease",
+                "discriminator": "address"
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn as_variants() {
+        let hd = FactorInstanceBadgeVirtualSource::placeholder();
+        assert_eq!(
+            hd.as_hierarchical_deterministic(),
+            Some(&HierarchicalDeterministicPublicKey::placeholder())
+        );
+        assert_eq!(hd.as_address(), None);
+
+        let address = FactorInstanceBadgeVirtualSource::from(
+            AccountAddress::placeholder_mainnet(),
+        );
+        assert_eq!(
+            address.as_address(),
+            Some(&AccountAddress::placeholder_mainnet())
+        );
+        assert_eq!(address.as_hierarchical_deterministic(), None);
+    }
+
+    #[test]
+    fn json_fails_for_mismatching_discriminator() {
+        assert_json_value_fails::<FactorInstanceBadgeVirtualSource>(json!({
+            "address": "account_rdx16xlfcpp0vf7e3gqnswv8j9k58n6rjccu58vvspmdva22kf3aplease",
+            "discriminator": "hierarchicalDeterministicPublicKey"
+        }));
+    }
+
+    #[test]
+    fn json_fails_for_unknown_discriminator() {
+        assert_json_value_fails::<FactorInstanceBadgeVirtualSource>(json!({
+            "address": "account_rdx16xlfcpp0vf7e3gqnswv8j9k58n6rjccu58vvspmdva22kf3aplease",
+            "discriminator": "physical"
+        }));
+    }
 }
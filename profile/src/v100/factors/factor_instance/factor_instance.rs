@@ -3,6 +3,7 @@ use crate::prelude::*;
 #[derive(
     Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
 )]
+#[serde(remote = "Self")]
 pub struct FactorInstance {
     /// The ID of the `FactorSource` that was used to produce this
     /// factor instance. We will lookup the `FactorSource` in the
@@ -28,6 +29,48 @@ impl FactorInstance {
         }
     }
 
+    /// Like `new` but validates that the kind of `badge` matches the kind of
+    /// `factor_source_id`: a hierarchical deterministic public key requires
+    /// an ID from hash, an address requires the ID from that very address.
+    pub fn try_new(
+        factor_source_id: FactorSourceID,
+        badge: FactorInstanceBadge,
+    ) -> Result<Self> {
+        let FactorInstanceBadge::Virtual {
+            value: virtual_source,
+        } = &badge;
+        let is_matching = match (&factor_source_id, virtual_source) {
+            (
+                FactorSourceID::Hash { .. },
+                FactorInstanceBadgeVirtualSource::HierarchicalDeterministic {
+                    ..
+                },
+            ) => true,
+            (
+                FactorSourceID::Address { value: id },
+                FactorInstanceBadgeVirtualSource::Address { value: address },
+            ) => id.body == *address,
+            _ => false,
+        };
+        if !is_matching {
+            return Err(
+                CommonError::FactorInstanceBadgeDoesNotMatchFactorSourceID(
+                    factor_source_id.to_string(),
+                ),
+            );
+        }
+        Ok(Self::new(factor_source_id, badge))
+    }
+
+    /// A factor instance of a trusted contact, or other factor source
+    /// identified by an address, its badge being that address.
+    pub fn with_address(factor_source_id: FactorSourceIDFromAddress) -> Self {
+        Self::new(
+            factor_source_id.clone().into(),
+            factor_source_id.body.into(),
+        )
+    }
+
     pub fn with_hierarchical_deterministic_public_key(
         factor_source_id: FactorSourceID,
         hierarchical_deterministic_public_key: HierarchicalDeterministicPublicKey,
@@ -43,6 +86,27 @@ impl FactorInstance {
     }
 }
 
+impl Serialize for FactorInstance {
+    #[cfg(not(tarpaulin_include))] // false negative
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        FactorInstance::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for FactorInstance {
+    #[cfg(not(tarpaulin_include))] // false negative
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let unchecked = FactorInstance::deserialize(deserializer)?;
+        Self::try_new(unchecked.factor_source_id, unchecked.badge)
+            .map_err(de::Error::custom)
+    }
+}
+
 impl HasPlaceholder for FactorInstance {
     /// A placeholder used to facilitate unit tests.
     fn placeholder() -> Self {
@@ -119,4 +183,98 @@ mod tests {
             "#,
         );
     }
+
+    #[test]
+    fn with_address() {
+        let id = FactorSourceIDFromAddress::placeholder();
+        let sut = FactorInstance::with_address(id.clone());
+        assert_eq!(
+            FactorInstance::try_new(
+                sut.factor_source_id.clone(),
+                sut.badge.clone()
+            ),
+            Ok(sut.clone())
+        );
+        assert_eq!(sut.badge, FactorInstanceBadge::from(id.body));
+    }
+
+    #[test]
+    fn address_json_roundtrip() {
+        let model = FactorInstance::with_address(
+            FactorSourceIDFromAddress::placeholder(),
+        );
+        assert_eq_after_json_roundtrip(
+            &model,
+            r#"
+            {
+                "badge": {
+                    "virtualSource": {
+                        "address": "account_rdx16xlfcpp0vf7e3gqnswv8j9k58n6rjccu58vvspmdva22kf3aplease",
+                        "discriminator": "address"
+                    },
+                    "discriminator": "virtualSource"
+                },
+                "factorSourceID": {
+                    "fromAddress": {
+                        "kind": "trustedContact",
+                        "body": "account_rdx16xlfcpp0vf7e3gqnswv8j9k58n6rjccu58vvspmdva22kf3aplease"
+                    },
+                    "discriminator": "fromAddress"
+                }
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn try_new_hd_badge_with_address_id_is_err() {
+        let id = FactorSourceID::from(FactorSourceIDFromAddress::placeholder());
+        assert_eq!(
+            FactorInstance::try_new(
+                id.clone(),
+                FactorInstanceBadge::placeholder()
+            ),
+            Err(CommonError::FactorInstanceBadgeDoesNotMatchFactorSourceID(
+                id.to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn try_new_address_badge_with_hash_id_is_err() {
+        assert!(FactorInstance::try_new(
+            FactorSourceID::placeholder(),
+            AccountAddress::placeholder_mainnet().into()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn try_new_address_badge_with_other_address_is_err() {
+        assert!(FactorInstance::try_new(
+            FactorSourceIDFromAddress::placeholder().into(),
+            AccountAddress::placeholder_other().into()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn json_fails_for_mismatching_badge() {
+        assert_json_value_fails::<FactorInstance>(json!({
+            "badge": {
+                "virtualSource": {
+                    "address": "account_rdx16xlfcpp0vf7e3gqnswv8j9k58n6rjccu58vvspmdva22kf3aplease",
+                    "discriminator": "address"
+                },
+                "discriminator": "virtualSource"
+            },
+            "factorSourceID": {
+                "fromHash": {
+                    "kind": "device",
+                    "body": "3c986ebf9dcd9167a97036d3b2c997433e85e6cc4e4422ad89269dac7bfea240"
+                },
+                "discriminator": "fromHash"
+            }
+        }));
+    }
 }
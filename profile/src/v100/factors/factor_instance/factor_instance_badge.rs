@@ -44,6 +44,14 @@ impl From<FactorInstanceBadgeVirtualSource> for FactorInstanceBadge {
     }
 }
 
+impl From<AccountAddress> for FactorInstanceBadge {
+    fn from(value: AccountAddress) -> Self {
+        Self::Virtual {
+            value: value.into(),
+        }
+    }
+}
+
 impl From<HierarchicalDeterministicPublicKey> for FactorInstanceBadge {
     fn from(value: HierarchicalDeterministicPublicKey) -> Self {
         Self::Virtual {
@@ -60,12 +68,18 @@ impl<'de> Deserialize<'de> for FactorInstanceBadge {
         // https://github.com/serde-rs/serde/issues/1343#issuecomment-409698470
         #[derive(Deserialize, Serialize)]
         struct Wrapper {
-            #[serde(rename = "discriminator")]
-            _ignore: String,
+            discriminator: String,
             #[serde(flatten, with = "FactorInstanceBadge")]
             value: FactorInstanceBadge,
         }
-        Wrapper::deserialize(deserializer).map(|w| w.value)
+        let wrapper = Wrapper::deserialize(deserializer)?;
+        if wrapper.discriminator != "virtualSource" {
+            return Err(de::Error::custom(format!(
+                "Unknown FactorInstanceBadge discriminator '{}'",
+                wrapper.discriminator
+            )));
+        }
+        Ok(wrapper.value)
     }
 }
 
@@ -138,6 +152,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn address_json_roundtrip() {
+        let model =
+            FactorInstanceBadge::from(AccountAddress::placeholder_mainnet());
+        assert_eq_after_json_roundtrip(
+            &model,
+            r#"
+            {
+                "virtualSource": {
+                    "address": "account_rdx16xlfcpp0vf7e3gqnswv8j9k58n6rjccu58vvspmdva22kf3aplease",
+                    "discriminator": "address"
+                },
+                "discriminator": "virtualSource"
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn json_fails_for_unknown_discriminator() {
+        assert_json_value_fails::<FactorInstanceBadge>(json!({
+            "virtualSource": {
+                "address": "account_rdx16xlfcpp0vf7e3gqnswv8j9k58n6rjccu58vvspmdva22kf3aplease",
+                "discriminator": "address"
+            },
+            "discriminator": "physical"
+        }));
+    }
+
     #[test]
     fn into_from_hd_pubkey() {
         let sut: FactorInstanceBadge =
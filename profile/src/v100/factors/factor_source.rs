@@ -43,6 +43,23 @@ impl BaseIsFactorSource for FactorSource {
     }
 }
 
+impl FactorSource {
+    /// The properties shared by factor sources of all kinds.
+    pub fn common(&self) -> &FactorSourceCommon {
+        match self {
+            FactorSource::Device { value } => &value.common,
+            FactorSource::Ledger { value } => &value.common,
+        }
+    }
+
+    pub fn common_mut(&mut self) -> &mut FactorSourceCommon {
+        match self {
+            FactorSource::Device { value } => &mut value.common,
+            FactorSource::Ledger { value } => &mut value.common,
+        }
+    }
+}
+
 impl From<DeviceFactorSource> for FactorSource {
     fn from(value: DeviceFactorSource) -> Self {
         FactorSource::Device { value }
@@ -127,6 +144,27 @@ impl FactorSource {
             value: LedgerHardwareWalletFactorSource::placeholder(),
         }
     }
+
+    pub fn placeholder_ledger_from_mnemonic() -> Self {
+        Self::Ledger {
+            value: LedgerHardwareWalletFactorSource::placeholder_from_mnemonic(
+            ),
+        }
+    }
+
+    pub fn placeholder_olympia() -> Self {
+        Self::Device {
+            value: PrivateHierarchicalDeterministicFactorSource::placeholder_olympia()
+                .factor_source,
+        }
+    }
+
+    pub fn placeholder_stokenet() -> Self {
+        Self::Device {
+            value: PrivateHierarchicalDeterministicFactorSource::placeholder_stokenet()
+                .factor_source,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -206,6 +244,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn common() {
+        let mut sut = FactorSource::placeholder_ledger();
+        assert_eq!(
+            sut.common(),
+            &LedgerHardwareWalletFactorSource::placeholder().common
+        );
+        sut.common_mut().next_derivation_indices_per_network =
+            NextDerivationIndicesPerNetwork::from_iter([
+                NextDerivationIndices::placeholder(),
+            ]);
+        assert_eq!(sut.common().next_derivation_indices_per_network.len(), 1);
+    }
+
     #[test]
     fn json_roundtrip_device() {
         let model = FactorSource::placeholder_device();
@@ -38,6 +38,13 @@ pub struct FactorSourceCommon {
 
     /// Flags which describe a certain state a FactorSource might be in, e.g. `Main` (BDFS).
     pub flags: FactorSourceFlags,
+
+    /// The derivation indices of the next Account and Persona created with
+    /// this factor source, per network. Absent for networks this factor
+    /// source has not created any entity on since these were introduced,
+    /// for which the indices are inferred from the entities in Profile.
+    #[serde(default, skip_serializing_if = "IdentifiedVecOf::is_empty")]
+    pub next_derivation_indices_per_network: NextDerivationIndicesPerNetwork,
 }
 
 impl FactorSourceCommon {
@@ -55,6 +62,8 @@ impl FactorSourceCommon {
             added_on,
             last_used_on,
             flags: FactorSourceFlags::from_iter(flags),
+            next_derivation_indices_per_network:
+                NextDerivationIndicesPerNetwork::new(),
         }
     }
 
@@ -65,7 +74,7 @@ impl FactorSourceCommon {
     where
         I: IntoIterator<Item = FactorSourceFlag>,
     {
-        let date = now();
+        let date = Timestamp::now();
         Self::with_values(crypto_parameters, date, date, flags)
     }
 
@@ -123,6 +132,18 @@ impl FactorSourceCommon {
         )
     }
 
+    /// A placeholder used to facilitate unit tests, Babylon crypto
+    /// parameters but not marked "main".
+    pub fn placeholder_babylon() -> Self {
+        let date = Timestamp::parse("2023-09-11T16:05:56.000Z").unwrap();
+        FactorSourceCommon::with_values(
+            FactorSourceCryptoParameters::babylon(),
+            date,
+            date,
+            [],
+        )
+    }
+
     /// A placeholder used to facilitate unit tests.
     pub fn placeholder_olympia() -> Self {
         let date = Timestamp::parse("2023-09-11T16:05:56.000Z").unwrap();
@@ -175,22 +196,12 @@ mod tests {
 
     #[test]
     fn new_uses_now_as_date() {
-        let date0 = now();
-        let model = FactorSourceCommon::new(
-            FactorSourceCryptoParameters::default(),
-            [],
-        );
-        let mut date1 = now();
-        for _ in 0..10 {
-            // rust is too fast... lol.
-            date1 = now();
-        }
-        let do_test = |d: Timestamp| {
-            assert!(d > date0);
-            assert!(d < date1);
-        };
-        do_test(model.added_on);
-        do_test(model.last_used_on);
+        let date = Timestamp::placeholder();
+        let model = with_time_provider(MockTimeProvider(date), || {
+            FactorSourceCommon::new(FactorSourceCryptoParameters::default(), [])
+        });
+        assert_eq!(model.added_on, date);
+        assert_eq!(model.last_used_on, date);
     }
 
     #[test]
@@ -46,6 +46,47 @@ impl From<FactorSourceIDFromAddress> for FactorSourceID {
     }
 }
 
+impl FactorSourceID {
+    /// The kind prefixed canonical string of the ID, e.g.
+    /// `"device:3c98...a240"` or `"trustedContact:account_rdx1...please"`.
+    pub fn to_canonical_string(&self) -> String {
+        match self {
+            FactorSourceID::Hash { value } => value.to_canonical_string(),
+            FactorSourceID::Address { value } => value.to_canonical_string(),
+        }
+    }
+}
+
+impl FromStr for FactorSourceID {
+    type Err = CommonError;
+
+    /// Parses the canonical string of either an ID from hash or, if the kind
+    /// is `trustedContact`, an ID from address.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (kind, _) = s.split_once(':').ok_or_else(|| {
+            CommonError::InvalidFactorSourceIDString(s.to_owned())
+        })?;
+        if kind == FactorSourceKind::TrustedContact.discriminant() {
+            FactorSourceIDFromAddress::from_str(s).map(Self::from)
+        } else {
+            FactorSourceIDFromHash::from_str(s).map(Self::from)
+        }
+    }
+}
+
+#[uniffi::export]
+pub fn new_factor_source_id_from_string(
+    string: String,
+) -> Result<FactorSourceID> {
+    FactorSourceID::from_str(string.as_str())
+}
+
+/// The kind prefixed canonical string of the ID, e.g. `"device:3c98...a240"`.
+#[uniffi::export]
+pub fn factor_source_id_to_string(factor_source_id: &FactorSourceID) -> String {
+    factor_source_id.to_canonical_string()
+}
+
 impl<'de> Deserialize<'de> for FactorSourceID {
     #[cfg(not(tarpaulin_include))] // false negative
     fn deserialize<D: Deserializer<'de>>(
@@ -162,6 +203,54 @@ mod tests {
         )
     }
 
+    #[test]
+    fn display_is_canonical_string() {
+        let sut = FactorSourceID::placeholder();
+        assert_eq!(sut.to_string(), sut.to_canonical_string());
+        assert_eq!(
+            sut.to_string(),
+            "device:3c986ebf9dcd9167a97036d3b2c997433e85e6cc4e4422ad89269dac7bfea240"
+        );
+    }
+
+    #[test]
+    fn from_str_hash() {
+        let sut: FactorSourceID =
+            FactorSourceIDFromHash::placeholder_ledger().into();
+        assert_eq!(sut.to_string().parse::<FactorSourceID>(), Ok(sut));
+    }
+
+    #[test]
+    fn from_str_address() {
+        let sut: FactorSourceID =
+            FactorSourceIDFromAddress::placeholder().into();
+        assert_eq!(
+            "trustedContact:account_rdx16xlfcpp0vf7e3gqnswv8j9k58n6rjccu58vvspmdva22kf3aplease"
+                .parse::<FactorSourceID>(),
+            Ok(sut)
+        );
+    }
+
+    #[test]
+    fn from_str_invalid() {
+        for bad in ["device", "trustedContact:device", "device:account_rdx1"] {
+            assert_eq!(
+                bad.parse::<FactorSourceID>(),
+                Err(CommonError::InvalidFactorSourceIDString(bad.to_owned()))
+            );
+        }
+    }
+
+    #[test]
+    fn string_roundtrip_uniffi() {
+        let sut: FactorSourceID =
+            FactorSourceIDFromAddress::placeholder().into();
+        assert_eq!(
+            new_factor_source_id_from_string(factor_source_id_to_string(&sut)),
+            Ok(sut)
+        );
+    }
+
     #[test]
     fn hash_into_as_roundtrip() {
         let from_hash = FactorSourceIDFromHash::placeholder();
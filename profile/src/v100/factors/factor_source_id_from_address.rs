@@ -35,6 +35,39 @@ impl FactorSourceIDFromAddress {
     }
 }
 
+impl FromStr for FactorSourceIDFromAddress {
+    type Err = CommonError;
+
+    /// Parses the canonical string of an ID, e.g.
+    /// `"trustedContact:account_rdx1...please"`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || CommonError::InvalidFactorSourceIDString(s.to_owned());
+        let (kind, body) = s.split_once(':').ok_or_else(invalid)?;
+        let kind = kind.parse::<FactorSourceKind>().map_err(|_| invalid())?;
+        if kind != FactorSourceKind::TrustedContact {
+            return Err(invalid());
+        }
+        let body = body.parse::<AccountAddress>().map_err(|_| invalid())?;
+        Ok(Self::new(kind, body))
+    }
+}
+
+#[uniffi::export]
+pub fn new_factor_source_id_from_address_from_string(
+    string: String,
+) -> Result<FactorSourceIDFromAddress> {
+    FactorSourceIDFromAddress::from_str(string.as_str())
+}
+
+/// The canonical string of the ID, e.g.
+/// `"trustedContact:account_rdx1...please"`.
+#[uniffi::export]
+pub fn factor_source_id_from_address_to_string(
+    factor_source_id: &FactorSourceIDFromAddress,
+) -> String {
+    factor_source_id.to_canonical_string()
+}
+
 impl HasPlaceholder for FactorSourceIDFromAddress {
     /// A placeholder used to facilitate unit tests.
     fn placeholder() -> Self {
@@ -93,6 +126,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn from_str() {
+        let sut = FactorSourceIDFromAddress::placeholder_other();
+        assert_eq!(
+            sut.to_string().parse::<FactorSourceIDFromAddress>(),
+            Ok(sut)
+        );
+    }
+
+    #[test]
+    fn from_str_invalid() {
+        for bad in [
+            "trustedContact",
+            "trustedContact:account_rdx1foo",
+            "device:account_rdx16xlfcpp0vf7e3gqnswv8j9k58n6rjccu58vvspmdva22kf3aplease",
+            "trustedContact:3c986ebf9dcd9167a97036d3b2c997433e85e6cc4e4422ad89269dac7bfea240",
+        ] {
+            assert_eq!(
+                bad.parse::<FactorSourceIDFromAddress>(),
+                Err(CommonError::InvalidFactorSourceIDString(bad.to_owned()))
+            );
+        }
+    }
+
+    #[test]
+    fn string_roundtrip_uniffi() {
+        let sut = FactorSourceIDFromAddress::placeholder();
+        assert_eq!(
+            new_factor_source_id_from_address_from_string(
+                factor_source_id_from_address_to_string(&sut)
+            ),
+            Ok(sut)
+        );
+    }
+
     #[test]
     fn json_roundtrip() {
         let model = FactorSourceIDFromAddress::placeholder();
@@ -1,5 +1,4 @@
 use crate::prelude::*;
-use radix_engine_common::crypto::{blake2b_256_hash, Hash};
 
 /// FactorSourceID from the blake2b hash of the special HD public key derived at `CAP26::GetID`,
 /// for a certain `FactorSourceKind`
@@ -38,10 +37,10 @@ impl FactorSourceIDFromHash {
     ) -> Self {
         let private_key =
             mnemonic_with_passphrase.derive_private_key(GetIDPath::default());
-        let public_key_bytes = private_key.public_key().to_bytes();
-        let hash: Hash = blake2b_256_hash(public_key_bytes);
-        let body = Hex32Bytes::from(hash);
-        Self::new(factor_source_kind, body)
+        let hash = PublicKeyHash::hash_of_public_key(
+            &private_key.public_key().public_key,
+        );
+        Self::new(factor_source_kind, hash.value)
     }
 
     pub fn new_for_device(
@@ -60,6 +59,50 @@ impl FactorSourceIDFromHash {
     }
 }
 
+impl FromStr for FactorSourceIDFromHash {
+    type Err = CommonError;
+
+    /// Parses the canonical string of an ID, e.g. `"device:3c98...a240"`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || CommonError::InvalidFactorSourceIDString(s.to_owned());
+        let (kind, body) = s.split_once(':').ok_or_else(invalid)?;
+        let kind = kind.parse::<FactorSourceKind>().map_err(|_| invalid())?;
+        let body = body.parse::<Hex32Bytes>().map_err(|_| invalid())?;
+        Ok(Self::new(kind, body))
+    }
+}
+
+#[uniffi::export]
+pub fn new_factor_source_id_from_hash_from_string(
+    string: String,
+) -> Result<FactorSourceIDFromHash> {
+    FactorSourceIDFromHash::from_str(string.as_str())
+}
+
+/// The canonical string of the ID, e.g. `"device:3c98...a240"`.
+#[uniffi::export]
+pub fn factor_source_id_from_hash_to_string(
+    factor_source_id: &FactorSourceIDFromHash,
+) -> String {
+    factor_source_id.to_canonical_string()
+}
+
+impl FactorSourceIDFromHash {
+    /// A short human-friendly identifier - two BIP39 words and four hex
+    /// characters - used to tell mnemonics apart without revealing any of
+    /// their words: the words are picked by the first bytes of `body`, the
+    /// hex characters are those of the next two bytes.
+    pub fn fingerprint(&self) -> String {
+        let bytes = self.body.bytes();
+        let word_list = bip39::Language::English.word_list();
+        let word_at = |i: usize| {
+            word_list
+                [(u16::from_be_bytes([bytes[i], bytes[i + 1]]) >> 5) as usize]
+        };
+        format!("{} {} {}", word_at(0), word_at(2), hex_encode(&bytes[4..6]))
+    }
+}
+
 impl HasPlaceholder for FactorSourceIDFromHash {
     /// A placeholder used to facilitate unit tests, just an alias
     /// for `placeholder_device`
@@ -99,6 +142,27 @@ impl FactorSourceIDFromHash {
 mod tests {
     use crate::prelude::*;
 
+    #[test]
+    fn fingerprint() {
+        let word_list = bip39::Language::English.word_list();
+        assert_eq!(
+            FactorSourceIDFromHash::placeholder_device().fingerprint(),
+            format!(
+                "{} {} 9dcd",
+                word_list[0x3c98 >> 5],
+                word_list[0x6ebf >> 5]
+            )
+        );
+    }
+
+    #[test]
+    fn fingerprint_differs() {
+        assert_ne!(
+            FactorSourceIDFromHash::placeholder_device().fingerprint(),
+            FactorSourceIDFromHash::placeholder_ledger_other().fingerprint()
+        );
+    }
+
     #[test]
     fn equality() {
         assert_eq!(
@@ -135,6 +199,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn from_str() {
+        let sut = FactorSourceIDFromHash::placeholder_ledger();
+        assert_eq!(sut.to_string().parse::<FactorSourceIDFromHash>(), Ok(sut));
+        assert_eq!(
+            "device:3c986ebf9dcd9167a97036d3b2c997433e85e6cc4e4422ad89269dac7bfea240"
+                .parse::<FactorSourceIDFromHash>(),
+            Ok(FactorSourceIDFromHash::placeholder())
+        );
+    }
+
+    #[test]
+    fn from_str_invalid() {
+        for bad in [
+            "",
+            "device",
+            "3c986ebf9dcd9167a97036d3b2c997433e85e6cc4e4422ad89269dac7bfea240",
+            "foo:3c986ebf9dcd9167a97036d3b2c997433e85e6cc4e4422ad89269dac7bfea240",
+            "device:3c98",
+            "device:3c986ebf9dcd9167a97036d3b2c997433e85e6cc4e4422ad89269dac7bfea24g",
+        ] {
+            assert_eq!(
+                bad.parse::<FactorSourceIDFromHash>(),
+                Err(CommonError::InvalidFactorSourceIDString(bad.to_owned()))
+            );
+        }
+    }
+
+    #[test]
+    fn string_roundtrip_uniffi() {
+        let sut = FactorSourceIDFromHash::placeholder();
+        assert_eq!(
+            new_factor_source_id_from_hash_from_string(
+                factor_source_id_from_hash_to_string(&sut)
+            ),
+            Ok(sut)
+        );
+    }
+
     #[test]
     fn json_roundtrip_placeholder() {
         let model = FactorSourceIDFromHash::placeholder();
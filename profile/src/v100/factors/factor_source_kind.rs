@@ -82,6 +82,16 @@ impl FactorSourceKind {
     }
 }
 
+impl FromStr for FactorSourceKind {
+    type Err = CommonError;
+
+    /// Parses the `discriminant` of a kind, e.g. `"device"`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        serde_json::from_value(json!(s))
+            .map_err(|_| CommonError::InvalidFactorSourceKind(s.to_owned()))
+    }
+}
+
 impl std::fmt::Display for FactorSourceKind {
     #[cfg(not(tarpaulin_include))] // false negative
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -199,4 +209,24 @@ mod tests {
         );
         assert_json_roundtrip(&FactorSourceKind::Device);
     }
+
+    #[test]
+    fn from_str() {
+        assert_eq!(
+            "ledgerHQHardwareWallet".parse::<FactorSourceKind>(),
+            Ok(FactorSourceKind::LedgerHQHardwareWallet)
+        );
+        assert_eq!(
+            "trustedContact".parse::<FactorSourceKind>(),
+            Ok(FactorSourceKind::TrustedContact)
+        );
+    }
+
+    #[test]
+    fn from_str_invalid() {
+        assert_eq!(
+            "Device".parse::<FactorSourceKind>(),
+            Err(CommonError::InvalidFactorSourceKind("Device".to_owned()))
+        );
+    }
 }
@@ -78,14 +78,24 @@ impl DeviceFactorSource {
             mnemonic_with_passphrase.clone(),
         );
 
-        Self::new(
-            id,
-            FactorSourceCommon::new_bdfs(is_main),
-            DeviceFactorSourceHint::unknown_model_of_client(
-                mnemonic_with_passphrase.mnemonic.word_count,
-                wallet_client_model,
-            ),
-        )
+        let mut hint = DeviceFactorSourceHint::unknown_model_of_client(
+            mnemonic_with_passphrase.mnemonic.word_count,
+            wallet_client_model,
+        );
+        hint.requires_passphrase =
+            !mnemonic_with_passphrase.passphrase.0.is_empty();
+        hint.mnemonic_fingerprint = Some(id.fingerprint());
+
+        Self::new(id, FactorSourceCommon::new_bdfs(is_main), hint)
+    }
+
+    /// A short human-friendly identifier of the mnemonic of this factor
+    /// source, the one in `hint` if any, else computed from `id`.
+    pub fn mnemonic_fingerprint(&self) -> String {
+        self.hint
+            .mnemonic_fingerprint
+            .clone()
+            .unwrap_or_else(|| self.id.fingerprint())
     }
 
     /// Checks if its Main Babylon Device Factor Source (BDFS).
@@ -130,6 +140,25 @@ impl DeviceFactorSource {
 mod tests {
     use crate::prelude::*;
 
+    #[test]
+    fn babylon_stores_mnemonic_fingerprint() {
+        let mwp = MnemonicWithPassphrase::placeholder();
+        let sut = DeviceFactorSource::babylon(
+            true,
+            mwp.clone(),
+            WalletClientModel::Iphone,
+        );
+        assert_eq!(sut.hint.mnemonic_fingerprint, Some(mwp.fingerprint()));
+        assert_eq!(sut.mnemonic_fingerprint(), mwp.fingerprint());
+    }
+
+    #[test]
+    fn mnemonic_fingerprint_computed_if_not_stored() {
+        let sut = DeviceFactorSource::placeholder();
+        assert_eq!(sut.hint.mnemonic_fingerprint, None);
+        assert_eq!(sut.mnemonic_fingerprint(), sut.id.fingerprint());
+    }
+
     #[test]
     fn equality() {
         assert_eq!(
@@ -160,6 +189,28 @@ mod tests {
         .is_main_bdfs());
     }
 
+    #[test]
+    fn babylon_with_passphrase_requires_passphrase() {
+        assert!(
+            DeviceFactorSource::babylon(
+                false,
+                MnemonicWithPassphrase::placeholder(),
+                WalletClientModel::placeholder()
+            )
+            .hint
+            .requires_passphrase
+        );
+        assert!(
+            !DeviceFactorSource::babylon(
+                false,
+                MnemonicWithPassphrase::placeholder_other(),
+                WalletClientModel::placeholder()
+            )
+            .hint
+            .requires_passphrase
+        );
+    }
+
     #[test]
     fn json() {
         let model = DeviceFactorSource::placeholder();
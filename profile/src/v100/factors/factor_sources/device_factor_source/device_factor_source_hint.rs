@@ -25,6 +25,19 @@ pub struct DeviceFactorSourceHint {
     /// The number of words in the mnemonic of a DeviceFactorSource, according to the BIP39
     /// standard, a multiple of 3, from 12 to 24 words.
     pub mnemonic_word_count: BIP39WordCount,
+
+    /// If the mnemonic of this DeviceFactorSource was used together with a
+    /// non-empty BIP39 passphrase, so that re-import flows know to prompt
+    /// for it.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub requires_passphrase: bool,
+
+    /// A short human-friendly identifier of the mnemonic, shown in lists of
+    /// factor sources to tell mnemonics apart without revealing them, see
+    /// [`MnemonicWithPassphrase::fingerprint`]. `None` for factor sources
+    /// created before it was introduced.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mnemonic_fingerprint: Option<String>,
 }
 
 impl DeviceFactorSourceHint {
@@ -38,6 +51,8 @@ impl DeviceFactorSourceHint {
             name,
             model,
             mnemonic_word_count: word_count,
+            requires_passphrase: false,
+            mnemonic_fingerprint: None,
         }
     }
 
@@ -127,6 +142,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn json_mnemonic_fingerprint() {
+        let mut model = DeviceFactorSourceHint::placeholder_iphone_unknown();
+        model.mnemonic_fingerprint = Some("zoo zone 0123".to_string());
+        assert_eq_after_json_roundtrip(
+            &model,
+            r#"
+        {
+            "name": "Unknown Name",
+            "model": "iPhone",
+            "mnemonicWordCount": 24,
+            "mnemonicFingerprint": "zoo zone 0123"
+        }
+        "#,
+        );
+    }
+
+    #[test]
+    fn requires_passphrase_is_false_by_default() {
+        assert!(!DeviceFactorSourceHint::placeholder().requires_passphrase);
+    }
+
+    #[test]
+    fn json_requires_passphrase() {
+        let mut model = DeviceFactorSourceHint::placeholder_iphone_unknown();
+        model.requires_passphrase = true;
+        assert_eq_after_json_roundtrip(
+            &model,
+            r#"
+        {
+            "name": "Unknown Name",
+            "model": "iPhone",
+            "mnemonicWordCount": 24,
+            "requiresPassphrase": true
+        }
+        "#,
+        )
+    }
+
     #[test]
     fn json() {
         let model = DeviceFactorSourceHint::placeholder_iphone_unknown();
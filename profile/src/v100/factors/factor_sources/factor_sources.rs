@@ -10,7 +10,7 @@ impl Identifiable for FactorSource {
 
 /// A collection of FactorSources generated by a wallet or manually added by user.
 /// MUST never be empty.
-pub type FactorSources = IdentifiedVecVia<FactorSource>;
+pub type FactorSources = IdentifiedVecOf<FactorSource>;
 
 #[uniffi::export]
 pub fn new_factor_sources_placeholder() -> FactorSources {
@@ -55,6 +55,32 @@ impl HasPlaceholder for FactorSources {
     }
 }
 
+impl FactorSources {
+    /// A placeholder used to facilitate unit tests, `placeholder` with
+    /// an additional Olympia device factor source.
+    pub fn placeholder_olympia() -> Self {
+        Self::placeholder_with(FactorSource::placeholder_olympia())
+    }
+
+    /// A placeholder used to facilitate unit tests, `placeholder` with
+    /// an additional Ledger factor source of a distinct mnemonic.
+    pub fn placeholder_ledger() -> Self {
+        Self::placeholder_with(FactorSource::placeholder_ledger_from_mnemonic())
+    }
+
+    /// A placeholder used to facilitate unit tests, `placeholder` with
+    /// an additional Babylon device factor source used on Stokenet.
+    pub fn placeholder_stokenet() -> Self {
+        Self::placeholder_with(FactorSource::placeholder_stokenet())
+    }
+
+    fn placeholder_with(factor_source: FactorSource) -> Self {
+        let mut factor_sources = Self::placeholder();
+        assert!(factor_sources.append(factor_source));
+        factor_sources
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
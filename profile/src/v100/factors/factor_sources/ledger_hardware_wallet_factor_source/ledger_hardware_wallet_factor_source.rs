@@ -59,6 +59,22 @@ impl HasPlaceholder for LedgerHardwareWalletFactorSource {
     }
 }
 
+impl LedgerHardwareWalletFactorSource {
+    /// A placeholder used to facilitate unit tests, the ID of which is
+    /// derived from `MnemonicWithPassphrase::placeholder_ledger`, unlike
+    /// `placeholder` which shares its mnemonic with the device placeholder.
+    pub fn placeholder_from_mnemonic() -> Self {
+        Self::new(
+            FactorSourceIDFromHash::from_mnemonic_with_passphrase(
+                FactorSourceKind::LedgerHQHardwareWallet,
+                MnemonicWithPassphrase::placeholder_ledger(),
+            ),
+            FactorSourceCommon::placeholder_babylon(),
+            LedgerHardwareWalletHint::placeholder(),
+        )
+    }
+}
+
 impl TryFrom<FactorSource> for LedgerHardwareWalletFactorSource {
     type Error = CommonError;
 
@@ -2,11 +2,13 @@ use crate::prelude::*;
 
 /// The model of a Ledger HQ hardware wallet NanoS, e.g.
 /// *Ledger Nano S+*.
+///
+/// Encoded as the exact string the Connector Extension uses, e.g. `"nanoS+"`,
+/// decoding is lenient: a model unknown to this version, e.g. a model Ledger
+/// HQ releases in the future, is kept as `Unknown` with the original string
+/// so that it survives being saved again.
 #[derive(
-    Serialize,
-    Deserialize,
     Clone,
-    Copy,
     Debug,
     PartialEq,
     Eq,
@@ -16,13 +18,81 @@ use crate::prelude::*;
     derive_more::Display,
     uniffi::Enum,
 )]
-#[serde(rename_all = "camelCase")]
 pub enum LedgerHardwareWalletModel {
     NanoS,
 
-    #[serde(rename = "nanoS+")]
     NanoSPlus,
+
     NanoX,
+
+    /// A model unknown to this version, `value` is the string as sent by the
+    /// Connector Extension.
+    #[display("{value}")]
+    Unknown {
+        value: String,
+    },
+}
+
+impl LedgerHardwareWalletModel {
+    /// Parses the model string sent by the Connector Extension, e.g.
+    /// `"nanoS+"`, any unknown string results in `Unknown`, never fails.
+    pub fn from_connector_string(value: impl AsRef<str>) -> Self {
+        match value.as_ref() {
+            "nanoS" => Self::NanoS,
+            "nanoS+" => Self::NanoSPlus,
+            "nanoX" => Self::NanoX,
+            unknown => Self::Unknown {
+                value: unknown.to_owned(),
+            },
+        }
+    }
+
+    /// The model string used by the Connector Extension, e.g. `"nanoS+"`.
+    pub fn connector_string(&self) -> String {
+        match self {
+            Self::NanoS => "nanoS".to_owned(),
+            Self::NanoSPlus => "nanoS+".to_owned(),
+            Self::NanoX => "nanoX".to_owned(),
+            Self::Unknown { value } => value.clone(),
+        }
+    }
+
+    /// `true` if this is a model unknown to this version.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Self::Unknown { .. })
+    }
+}
+
+impl Serialize for LedgerHardwareWalletModel {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.connector_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for LedgerHardwareWalletModel {
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Self::from_connector_string)
+    }
+}
+
+#[uniffi::export]
+pub fn new_ledger_hardware_wallet_model_from_string(
+    string: String,
+) -> LedgerHardwareWalletModel {
+    LedgerHardwareWalletModel::from_connector_string(string)
+}
+
+/// The model string used by the Connector Extension, e.g. `"nanoS+"`.
+#[uniffi::export]
+pub fn ledger_hardware_wallet_model_to_string(
+    model: &LedgerHardwareWalletModel,
+) -> String {
+    model.connector_string()
 }
 
 #[cfg(test)]
@@ -85,4 +155,69 @@ mod tests {
             json!("nanoX"),
         );
     }
+
+    #[test]
+    fn json_unknown_model_is_preserved() {
+        let sut = serde_json::from_value::<LedgerHardwareWalletModel>(json!(
+            "nanoGen5"
+        ))
+        .unwrap();
+        assert_eq!(
+            sut,
+            LedgerHardwareWalletModel::Unknown {
+                value: "nanoGen5".to_owned()
+            }
+        );
+        assert!(sut.is_unknown());
+        assert_json_value_eq_after_roundtrip(&sut, json!("nanoGen5"));
+    }
+
+    #[test]
+    fn json_fails_for_non_string() {
+        assert_json_value_fails::<LedgerHardwareWalletModel>(json!(1));
+    }
+
+    #[test]
+    fn connector_string_roundtrip() {
+        for model in [
+            LedgerHardwareWalletModel::NanoS,
+            LedgerHardwareWalletModel::NanoSPlus,
+            LedgerHardwareWalletModel::NanoX,
+        ] {
+            assert!(!model.is_unknown());
+            assert_eq!(
+                LedgerHardwareWalletModel::from_connector_string(
+                    model.connector_string()
+                ),
+                model
+            );
+        }
+    }
+
+    #[test]
+    fn connector_string_is_case_sensitive() {
+        assert!(LedgerHardwareWalletModel::from_connector_string("NanoS")
+            .is_unknown());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            LedgerHardwareWalletModel::NanoSPlus.to_string(),
+            "NanoSPlus"
+        );
+        assert_eq!(
+            LedgerHardwareWalletModel::from_connector_string("stax")
+                .to_string(),
+            "stax"
+        );
+    }
+
+    #[test]
+    fn string_roundtrip_uniffi() {
+        let sut =
+            new_ledger_hardware_wallet_model_from_string("nanoX".to_owned());
+        assert_eq!(sut, LedgerHardwareWalletModel::NanoX);
+        assert_eq!(ledger_hardware_wallet_model_to_string(&sut), "nanoX");
+    }
 }
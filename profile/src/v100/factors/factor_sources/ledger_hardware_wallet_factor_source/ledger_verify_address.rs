@@ -0,0 +1,386 @@
+use crate::prelude::*;
+
+/// A request to the Ledger connector to derive the address of an Account
+/// controlled by a Ledger hardware wallet and display it on the device, so
+/// that the user can verify it matches the address shown in the wallet.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct LedgerVerifyAddressRequest {
+    /// The Ledger factor source controlling the Account.
+    #[serde(rename = "factorSourceID")]
+    pub factor_source_id: FactorSourceIDFromHash,
+
+    /// Name and model of the Ledger device, to tell the user which device
+    /// to connect.
+    pub ledger_hint: LedgerHardwareWalletHint,
+
+    /// The derivation path of the transaction signing key of the Account.
+    pub derivation_path: DerivationPath,
+
+    /// The address the device is expected to display.
+    pub expected_address: AccountAddress,
+
+    /// The name of the Account, truncated to at most
+    /// [`Self::MAX_DISPLAY_NAME_GRAPHEMES`] for the screen of the device.
+    pub display_name: DisplayName,
+}
+
+/// The address derived and displayed by the Ledger device, in response to
+/// a [`LedgerVerifyAddressRequest`].
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct LedgerVerifyAddressResponse {
+    /// The derivation path the device used.
+    pub derivation_path: DerivationPath,
+
+    /// The Bech32 encoded address the device derived and displayed.
+    pub address: String,
+}
+
+impl LedgerVerifyAddressRequest {
+    /// The max number of grapheme clusters of the Account name shown on
+    /// the device, which is less than `DisplayName::MAX_LEN`.
+    pub const MAX_DISPLAY_NAME_GRAPHEMES: usize = 20;
+
+    /// Builds a request for `account`, returns `Err` if it is not controlled
+    /// by `ledger`.
+    pub fn new(
+        account: &Account,
+        ledger: &LedgerHardwareWalletFactorSource,
+    ) -> Result<Self> {
+        let not_controlled = || {
+            CommonError::AccountNotControlledByLedgerFactorSource(
+                account.address.to_string(),
+            )
+        };
+        let transaction_signing = account
+            .security_state
+            .transaction_signing_factor_instance()
+            .cloned()
+            .ok_or_else(not_controlled)?;
+        if transaction_signing.factor_source_id != ledger.id {
+            return Err(not_controlled());
+        }
+        Ok(Self {
+            factor_source_id: ledger.id.clone(),
+            ledger_hint: ledger.hint.clone(),
+            derivation_path: transaction_signing.derivation_path(),
+            expected_address: account.address.clone(),
+            display_name: account
+                .display_name
+                .truncated(Self::MAX_DISPLAY_NAME_GRAPHEMES),
+        })
+    }
+
+    /// Validates that the device used the requested derivation path and
+    /// derived the expected address.
+    pub fn validate_response(
+        &self,
+        response: &LedgerVerifyAddressResponse,
+    ) -> Result<()> {
+        if response.derivation_path != self.derivation_path {
+            return Err(
+                CommonError::LedgerVerifyAddressDerivationPathMismatch {
+                    expected: self.derivation_path.to_string(),
+                    found: response.derivation_path.to_string(),
+                },
+            );
+        }
+        let mismatch = || CommonError::LedgerVerifyAddressMismatch {
+            expected: self.expected_address.to_string(),
+            found: response.address.clone(),
+        };
+        let address = response
+            .address
+            .parse::<AccountAddress>()
+            .map_err(|_| mismatch())?;
+        if address != self.expected_address {
+            return Err(mismatch());
+        }
+        Ok(())
+    }
+}
+
+impl Profile {
+    /// Builds a [`LedgerVerifyAddressRequest`] for the Account with
+    /// `address`, returns `Err` if the Account is unknown or not controlled
+    /// by a Ledger factor source in this Profile.
+    pub fn ledger_verify_address_request(
+        &self,
+        address: &AccountAddress,
+    ) -> Result<LedgerVerifyAddressRequest> {
+        let account = self
+            .networks
+            .get_account(address)
+            .ok_or(CommonError::UnknownAccount)?;
+        let ledger = account
+            .security_state
+            .transaction_signing_factor_instance()
+            .and_then(|instance| {
+                self.factor_sources
+                    .get_id(&instance.factor_source_id.clone().into())
+            })
+            .and_then(|f| f.as_ledger().cloned())
+            .ok_or(CommonError::AccountNotControlledByLedgerFactorSource(
+                address.to_string(),
+            ))?;
+        LedgerVerifyAddressRequest::new(&account, &ledger)
+    }
+}
+
+#[uniffi::export]
+pub fn profile_ledger_verify_address_request(
+    profile: &Profile,
+    address: &AccountAddress,
+) -> Result<LedgerVerifyAddressRequest> {
+    profile.ledger_verify_address_request(address)
+}
+
+#[uniffi::export]
+pub fn ledger_verify_address_request_validate_response(
+    request: &LedgerVerifyAddressRequest,
+    response: &LedgerVerifyAddressResponse,
+) -> Result<()> {
+    request.validate_response(response)
+}
+
+#[cfg(test)]
+pub(crate) fn ledger_controlled_account(
+    network_id: NetworkID,
+    index: HDPathValue,
+) -> Account {
+    let ledger = LedgerHardwareWalletFactorSource::placeholder();
+    // The id of the placeholder Ledger is derived from the placeholder mnemonic.
+    let path =
+        AccountPath::new(network_id, CAP26KeyKind::TransactionSigning, index);
+    let public_key = MnemonicWithPassphrase::placeholder()
+        .derive_private_key(path)
+        .public_key();
+    Account::new(
+        HDFactorInstanceAccountCreation::new(
+            HierarchicalDeterministicFactorInstance::new(ledger.id, public_key),
+        )
+        .unwrap(),
+        DisplayName::new("Ledger").unwrap(),
+        AppearanceID::default(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ledger_controlled_account;
+    use crate::prelude::*;
+
+    fn profile_with_ledger_account() -> (Profile, Account) {
+        let account = ledger_controlled_account(NetworkID::Mainnet, 0);
+        let mut profile = Profile::placeholder();
+        profile.networks.update_with(&NetworkID::Mainnet, |n| {
+            n.accounts.append(account.clone());
+        });
+        (profile, account)
+    }
+
+    fn response_for(
+        request: &LedgerVerifyAddressRequest,
+    ) -> LedgerVerifyAddressResponse {
+        LedgerVerifyAddressResponse {
+            derivation_path: request.derivation_path.clone(),
+            address: request.expected_address.to_string(),
+        }
+    }
+
+    #[test]
+    fn request_from_profile() {
+        let (profile, account) = profile_with_ledger_account();
+        let sut = profile
+            .ledger_verify_address_request(&account.address)
+            .unwrap();
+        assert_eq!(
+            sut,
+            LedgerVerifyAddressRequest {
+                factor_source_id: FactorSourceIDFromHash::placeholder_ledger(),
+                ledger_hint: LedgerHardwareWalletHint::placeholder(),
+                derivation_path: AccountPath::new(
+                    NetworkID::Mainnet,
+                    CAP26KeyKind::TransactionSigning,
+                    0
+                )
+                .into(),
+                expected_address: account.address,
+                display_name: DisplayName::new("Ledger").unwrap(),
+            }
+        );
+    }
+
+    #[test]
+    fn display_name_is_truncated() {
+        let mut account = ledger_controlled_account(NetworkID::Mainnet, 0);
+        account.display_name =
+            DisplayName::new("My long Ledger account name").unwrap();
+        let sut = LedgerVerifyAddressRequest::new(
+            &account,
+            &LedgerHardwareWalletFactorSource::placeholder(),
+        )
+        .unwrap();
+        assert_eq!(sut.display_name.value, "My long Ledger accou");
+        assert_eq!(
+            sut.display_name.grapheme_count(),
+            LedgerVerifyAddressRequest::MAX_DISPLAY_NAME_GRAPHEMES
+        );
+    }
+
+    #[test]
+    fn request_for_unknown_account() {
+        let profile = Profile::placeholder();
+        let account = ledger_controlled_account(NetworkID::Mainnet, 0);
+        assert_eq!(
+            profile.ledger_verify_address_request(&account.address),
+            Err(CommonError::UnknownAccount)
+        );
+    }
+
+    #[test]
+    fn request_for_device_controlled_account() {
+        let profile = Profile::placeholder();
+        let address = profile.networks.items()[0].accounts.items()[0]
+            .address
+            .clone();
+        assert_eq!(
+            profile.ledger_verify_address_request(&address),
+            Err(CommonError::AccountNotControlledByLedgerFactorSource(
+                address.to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn new_for_watch_only_account() {
+        let account = Account::new_watch_only(
+            AccountAddress::placeholder_mainnet(),
+            DisplayName::new("Watched").unwrap(),
+            AppearanceID::default(),
+        );
+        assert_eq!(
+            LedgerVerifyAddressRequest::new(
+                &account,
+                &LedgerHardwareWalletFactorSource::placeholder()
+            ),
+            Err(CommonError::AccountNotControlledByLedgerFactorSource(
+                account.address.to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn new_with_other_ledger() {
+        let account = ledger_controlled_account(NetworkID::Mainnet, 0);
+        assert_eq!(
+            LedgerVerifyAddressRequest::new(
+                &account,
+                &LedgerHardwareWalletFactorSource::placeholder_other()
+            ),
+            Err(CommonError::AccountNotControlledByLedgerFactorSource(
+                account.address.to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn validate_response_success() {
+        let (profile, account) = profile_with_ledger_account();
+        let sut = profile
+            .ledger_verify_address_request(&account.address)
+            .unwrap();
+        assert_eq!(sut.validate_response(&response_for(&sut)), Ok(()));
+    }
+
+    #[test]
+    fn validate_response_wrong_derivation_path() {
+        let (profile, account) = profile_with_ledger_account();
+        let sut = profile
+            .ledger_verify_address_request(&account.address)
+            .unwrap();
+        let mut response = response_for(&sut);
+        response.derivation_path = AccountPath::new(
+            NetworkID::Mainnet,
+            CAP26KeyKind::TransactionSigning,
+            1,
+        )
+        .into();
+        assert_eq!(
+            sut.validate_response(&response),
+            Err(CommonError::LedgerVerifyAddressDerivationPathMismatch {
+                expected: sut.derivation_path.to_string(),
+                found: response.derivation_path.to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn validate_response_wrong_address() {
+        let (profile, account) = profile_with_ledger_account();
+        let sut = profile
+            .ledger_verify_address_request(&account.address)
+            .unwrap();
+        let mut response = response_for(&sut);
+        response.address = ledger_controlled_account(NetworkID::Mainnet, 1)
+            .address
+            .to_string();
+        assert_eq!(
+            sut.validate_response(&response),
+            Err(CommonError::LedgerVerifyAddressMismatch {
+                expected: account.address.to_string(),
+                found: response.address.clone(),
+            })
+        );
+    }
+
+    #[test]
+    fn validate_response_invalid_address() {
+        let (profile, account) = profile_with_ledger_account();
+        let sut = profile
+            .ledger_verify_address_request(&account.address)
+            .unwrap();
+        let mut response = response_for(&sut);
+        response.address = "not an address".to_owned();
+        assert_eq!(
+            sut.validate_response(&response),
+            Err(CommonError::LedgerVerifyAddressMismatch {
+                expected: account.address.to_string(),
+                found: "not an address".to_owned(),
+            })
+        );
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use super::ledger_controlled_account;
+    use crate::prelude::*;
+
+    #[test]
+    fn request_and_validate() {
+        let account = ledger_controlled_account(NetworkID::Mainnet, 0);
+        let mut profile = Profile::placeholder();
+        profile.networks.update_with(&NetworkID::Mainnet, |n| {
+            n.accounts.append(account.clone());
+        });
+        let request =
+            profile_ledger_verify_address_request(&profile, &account.address)
+                .unwrap();
+        let response = LedgerVerifyAddressResponse {
+            derivation_path: request.derivation_path.clone(),
+            address: account.address.to_string(),
+        };
+        assert_eq!(
+            ledger_verify_address_request_validate_response(
+                &request, &response
+            ),
+            Ok(())
+        );
+    }
+}
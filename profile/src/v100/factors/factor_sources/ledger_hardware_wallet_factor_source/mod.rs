@@ -1,7 +1,9 @@
 mod ledger_hardware_wallet_factor_source;
 mod ledger_hardware_wallet_hint;
 mod ledger_hardware_wallet_model;
+mod ledger_verify_address;
 
 pub use ledger_hardware_wallet_factor_source::*;
 pub use ledger_hardware_wallet_hint::*;
 pub use ledger_hardware_wallet_model::*;
+pub use ledger_verify_address::*;
@@ -39,6 +39,28 @@ impl PrivateHierarchicalDeterministicFactorSource {
         }
     }
 
+    /// Instantiates a new `PrivateHierarchicalDeterministicFactorSource`,
+    /// returns `Err` if the id of `factor_source` is not the id derived from
+    /// `mnemonic_with_passphrase`, e.g. if the wrong BIP39 passphrase was used.
+    pub fn try_new(
+        mnemonic_with_passphrase: MnemonicWithPassphrase,
+        factor_source: DeviceFactorSource,
+    ) -> Result<Self> {
+        let found = FactorSourceIDFromHash::from_mnemonic_with_passphrase(
+            factor_source.factor_source_kind(),
+            mnemonic_with_passphrase.clone(),
+        );
+        if found != factor_source.id {
+            return Err(
+                CommonError::MnemonicWithPassphraseDoesNotMatchFactorSourceID {
+                    expected: factor_source.id,
+                    found,
+                },
+            );
+        }
+        Ok(Self::new(mnemonic_with_passphrase, factor_source))
+    }
+
     fn new_with_mnemonic_with_passphrase(
         mnemonic_with_passphrase: MnemonicWithPassphrase,
         wallet_client_model: WalletClientModel,
@@ -75,6 +97,44 @@ impl PrivateHierarchicalDeterministicFactorSource {
 }
 
 impl PrivateHierarchicalDeterministicFactorSource {
+    /// Derives a private key for each of the `derivations`, computing the
+    /// seed of the mnemonic once for the whole batch.
+    pub fn derive_private_keys<D, I>(
+        &self,
+        derivations: I,
+    ) -> Vec<HierarchicalDeterministicPrivateKey>
+    where
+        D: Derivation,
+        I: IntoIterator<Item = D>,
+    {
+        self.derive_private_keys_using(&SeedCache::default(), derivations)
+    }
+
+    /// Derives a private key for each of the `derivations`, computing the
+    /// seed of the mnemonic only if `seed_cache` does not already hold it.
+    pub fn derive_private_keys_using<D, I>(
+        &self,
+        seed_cache: &SeedCache,
+        derivations: I,
+    ) -> Vec<HierarchicalDeterministicPrivateKey>
+    where
+        D: Derivation,
+        I: IntoIterator<Item = D>,
+    {
+        measure(MeasuredOperation::Derivation, "derive_private_keys", || {
+            seed_cache.with_seed(
+                &self.factor_source.id,
+                &self.mnemonic_with_passphrase,
+                |seed| {
+                    MnemonicWithPassphrase::derive_private_keys_from_seed(
+                        seed,
+                        derivations,
+                    )
+                },
+            )
+        })
+    }
+
     pub fn derive_entity_creation_factor_instance<T>(
         &self,
         network_id: NetworkID,
@@ -83,14 +143,111 @@ impl PrivateHierarchicalDeterministicFactorSource {
     where
         T: IsEntityPath + Clone,
     {
-        let path = T::new(network_id, CAP26KeyKind::TransactionSigning, index);
-        let hd_private_key =
-            self.mnemonic_with_passphrase.derive_private_key(path);
-        let hd_factor_instance = HierarchicalDeterministicFactorInstance::new(
-            self.factor_source.id.clone(),
-            hd_private_key.public_key(),
-        );
-        HDFactorInstanceTransactionSigning::new(hd_factor_instance).unwrap()
+        self.derive_entity_creation_factor_instance_using(
+            &SeedCache::default(),
+            network_id,
+            index,
+        )
+    }
+
+    /// Derives the factor instance at `index`, see
+    /// [`PrivateHierarchicalDeterministicFactorSource::derive_private_keys_using`].
+    pub fn derive_entity_creation_factor_instance_using<T>(
+        &self,
+        seed_cache: &SeedCache,
+        network_id: NetworkID,
+        index: HDPathValue,
+    ) -> HDFactorInstanceTransactionSigning<T>
+    where
+        T: IsEntityPath + Clone,
+    {
+        self.derive_entity_creation_factor_instances_using(
+            seed_cache,
+            network_id,
+            [index],
+        )
+        .into_iter()
+        .next()
+        .expect("Should have derived exactly one factor instance")
+    }
+
+    /// Derives a factor instance for each of the `indices`, see
+    /// [`PrivateHierarchicalDeterministicFactorSource::derive_private_keys`].
+    pub fn derive_entity_creation_factor_instances<T, I>(
+        &self,
+        network_id: NetworkID,
+        indices: I,
+    ) -> Vec<HDFactorInstanceTransactionSigning<T>>
+    where
+        T: IsEntityPath + Clone,
+        I: IntoIterator<Item = HDPathValue>,
+    {
+        self.derive_entity_creation_factor_instances_using(
+            &SeedCache::default(),
+            network_id,
+            indices,
+        )
+    }
+
+    /// Derives a factor instance for each of the `indices`, see
+    /// [`PrivateHierarchicalDeterministicFactorSource::derive_private_keys_using`].
+    pub fn derive_entity_creation_factor_instances_using<T, I>(
+        &self,
+        seed_cache: &SeedCache,
+        network_id: NetworkID,
+        indices: I,
+    ) -> Vec<HDFactorInstanceTransactionSigning<T>>
+    where
+        T: IsEntityPath + Clone,
+        I: IntoIterator<Item = HDPathValue>,
+    {
+        let paths = indices.into_iter().map(|index| {
+            T::new(network_id, CAP26KeyKind::TransactionSigning, index)
+        });
+        self.derive_private_keys_using(seed_cache, paths)
+            .into_iter()
+            .map(|hd_private_key| {
+                let hd_factor_instance =
+                    HierarchicalDeterministicFactorInstance::new(
+                        self.factor_source.id.clone(),
+                        hd_private_key.public_key(),
+                    );
+                HDFactorInstanceTransactionSigning::new(hd_factor_instance)
+                    .unwrap()
+            })
+            .collect_vec()
+    }
+
+    /// Derives both the transaction signing and the authentication signing
+    /// factor instances of a new Persona at `index` on `network_id`.
+    pub fn derive_identity_creation_with_auth_factor_instances(
+        &self,
+        network_id: NetworkID,
+        index: HDPathValue,
+    ) -> HDFactorInstancesIdentityCreationWithAuth {
+        let paths = [
+            CAP26KeyKind::TransactionSigning,
+            CAP26KeyKind::AuthenticationSigning,
+        ]
+        .map(|key_kind| IdentityPath::new(network_id, key_kind, index));
+        let (transaction_signing, authentication_signing) = self
+            .derive_private_keys(paths)
+            .into_iter()
+            .map(|hd_private_key| {
+                HierarchicalDeterministicFactorInstance::new(
+                    self.factor_source.id.clone(),
+                    hd_private_key.public_key(),
+                )
+            })
+            .collect_tuple()
+            .expect("Should have derived exactly two factor instances");
+
+        HDFactorInstancesIdentityCreationWithAuth::new(
+            HDFactorInstanceIdentityCreation::new(transaction_signing)
+                .expect("Transaction signing identity path"),
+            authentication_signing,
+        )
+        .expect("Same factor source, network and index")
     }
 }
 
@@ -115,6 +272,37 @@ impl HasPlaceholder for PrivateHierarchicalDeterministicFactorSource {
     }
 }
 
+impl PrivateHierarchicalDeterministicFactorSource {
+    /// A placeholder used to facilitate unit tests, an Olympia device
+    /// factor source of `MnemonicWithPassphrase::placeholder_olympia`.
+    pub fn placeholder_olympia() -> Self {
+        let mwp = MnemonicWithPassphrase::placeholder_olympia();
+        Self::new(
+            mwp.clone(),
+            DeviceFactorSource::new(
+                FactorSourceIDFromHash::new_for_device(mwp),
+                FactorSourceCommon::placeholder_olympia(),
+                DeviceFactorSourceHint::placeholder_other(),
+            ),
+        )
+    }
+
+    /// A placeholder used to facilitate unit tests, a Babylon device
+    /// factor source - not marked "main" - of
+    /// `MnemonicWithPassphrase::placeholder_stokenet`.
+    pub fn placeholder_stokenet() -> Self {
+        let mwp = MnemonicWithPassphrase::placeholder_stokenet();
+        Self::new(
+            mwp.clone(),
+            DeviceFactorSource::new(
+                FactorSourceIDFromHash::new_for_device(mwp),
+                FactorSourceCommon::placeholder_babylon(),
+                DeviceFactorSourceHint::placeholder(),
+            ),
+        )
+    }
+}
+
 impl SafeToLog for PrivateHierarchicalDeterministicFactorSource {
     /// Logs the word count and FactorSourceID.
     fn non_sensitive(&self) -> impl std::fmt::Debug {
@@ -129,6 +317,110 @@ impl SafeToLog for PrivateHierarchicalDeterministicFactorSource {
 mod tests {
     use crate::prelude::*;
 
+    #[test]
+    fn derive_entity_creation_factor_instances_eq_one_by_one() {
+        let sut = PrivateHierarchicalDeterministicFactorSource::placeholder();
+        let batch = sut
+            .derive_entity_creation_factor_instances::<AccountPath, _>(
+                NetworkID::Mainnet,
+                0..3,
+            );
+        let one_by_one = (0..3)
+            .map(|i| {
+                sut.derive_entity_creation_factor_instance::<AccountPath>(
+                    NetworkID::Mainnet,
+                    i,
+                )
+            })
+            .collect_vec();
+        assert_eq!(batch, one_by_one);
+    }
+
+    #[test]
+    fn seed_is_cached_in_given_cache() {
+        let sut = PrivateHierarchicalDeterministicFactorSource::placeholder();
+        let seed_cache = SeedCache::default();
+        assert!(!seed_cache.contains_seed_of(&sut.factor_source.id));
+        let first = sut
+            .derive_entity_creation_factor_instance_using::<AccountPath>(
+                &seed_cache,
+                NetworkID::Mainnet,
+                0,
+            );
+        assert!(seed_cache.contains_seed_of(&sut.factor_source.id));
+        assert_eq!(
+            first,
+            sut.derive_entity_creation_factor_instance::<AccountPath>(
+                NetworkID::Mainnet,
+                0
+            )
+        );
+    }
+
+    #[test]
+    fn derive_identity_creation_with_auth_factor_instances() {
+        let sut = PrivateHierarchicalDeterministicFactorSource::placeholder();
+        let instances = sut
+            .derive_identity_creation_with_auth_factor_instances(
+                NetworkID::Mainnet,
+                2,
+            );
+        assert_eq!(
+            instances.transaction_signing,
+            sut.derive_entity_creation_factor_instance::<IdentityPath>(
+                NetworkID::Mainnet,
+                2
+            )
+        );
+        assert_eq!(
+            instances.authentication_signing.key_kind(),
+            Some(CAP26KeyKind::AuthenticationSigning)
+        );
+        assert_eq!(
+            instances.authentication_signing.derivation_path(),
+            IdentityPath::new(
+                NetworkID::Mainnet,
+                CAP26KeyKind::AuthenticationSigning,
+                2
+            )
+            .into()
+        );
+        assert_ne!(
+            instances.authentication_signing.public_key.public_key,
+            instances.transaction_signing.public_key
+        );
+    }
+
+    #[test]
+    fn try_new_success() {
+        let sut = PrivateHierarchicalDeterministicFactorSource::placeholder();
+        assert_eq!(
+            PrivateHierarchicalDeterministicFactorSource::try_new(
+                sut.mnemonic_with_passphrase.clone(),
+                sut.factor_source.clone()
+            ),
+            Ok(sut)
+        );
+    }
+
+    #[test]
+    fn try_new_fails_for_wrong_passphrase() {
+        let sut = PrivateHierarchicalDeterministicFactorSource::placeholder();
+        let mwp = MnemonicWithPassphrase::new(Mnemonic::placeholder());
+        assert_eq!(
+            PrivateHierarchicalDeterministicFactorSource::try_new(
+                mwp.clone(),
+                sut.factor_source.clone()
+            ),
+            Err(
+                CommonError::MnemonicWithPassphraseDoesNotMatchFactorSourceID {
+                    expected: sut.factor_source.id,
+                    found: FactorSourceIDFromHash::new_for_device(mwp),
+                }
+            )
+        );
+    }
+
     #[test]
     fn hash() {
         let n = 100;
@@ -0,0 +1,170 @@
+use crate::prelude::*;
+
+/// The factor instances used to create a new Persona, derived at the same
+/// index by the same factor source: one for transaction signing, from which
+/// the address of the Persona is derived, and one for authentication
+/// signing, used for ROLA.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct HDFactorInstancesIdentityCreationWithAuth {
+    pub transaction_signing: HDFactorInstanceIdentityCreation,
+    pub authentication_signing: HierarchicalDeterministicFactorInstance,
+}
+
+impl HDFactorInstancesIdentityCreationWithAuth {
+    /// Returns `Err` if `authentication_signing` is not an identity
+    /// authentication signing factor instance of the same factor source,
+    /// network and index as `transaction_signing`.
+    pub fn new(
+        transaction_signing: HDFactorInstanceIdentityCreation,
+        authentication_signing: HierarchicalDeterministicFactorInstance,
+    ) -> Result<Self> {
+        let auth_path = match authentication_signing.derivation_path() {
+            DerivationPath::CAP26 {
+                value: CAP26Path::Identity { value },
+            } => Ok(value),
+            _ => Err(CommonError::WrongEntityKindOfInFactorInstancesPath),
+        }?;
+        if auth_path.key_kind() != CAP26KeyKind::AuthenticationSigning {
+            return Err(
+                CommonError::WrongKeyKindOfAuthenticationSigningFactorInstance,
+            );
+        }
+        let tx_path = &transaction_signing.path;
+        if authentication_signing.factor_source_id
+            != transaction_signing.factor_source_id
+            || auth_path.network_id() != tx_path.network_id()
+            || auth_path.index() != tx_path.index()
+        {
+            return Err(CommonError::IdentityCreationFactorInstancesMismatch);
+        }
+        Ok(Self {
+            transaction_signing,
+            authentication_signing,
+        })
+    }
+
+    pub fn network_id(&self) -> NetworkID {
+        self.transaction_signing.network_id()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn derive(
+        network_id: NetworkID,
+        key_kind: CAP26KeyKind,
+        index: HDPathValue,
+    ) -> HierarchicalDeterministicFactorInstance {
+        let private =
+            PrivateHierarchicalDeterministicFactorSource::placeholder();
+        let path = IdentityPath::new(network_id, key_kind, index);
+        HierarchicalDeterministicFactorInstance::new(
+            private.factor_source.id,
+            private
+                .mnemonic_with_passphrase
+                .derive_private_key(path)
+                .public_key(),
+        )
+    }
+
+    fn transaction_signing() -> HDFactorInstanceIdentityCreation {
+        HDFactorInstanceIdentityCreation::new(derive(
+            NetworkID::Mainnet,
+            CAP26KeyKind::TransactionSigning,
+            0,
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn valid() {
+        let sut = HDFactorInstancesIdentityCreationWithAuth::new(
+            transaction_signing(),
+            derive(NetworkID::Mainnet, CAP26KeyKind::AuthenticationSigning, 0),
+        )
+        .unwrap();
+        assert_eq!(sut.network_id(), NetworkID::Mainnet);
+    }
+
+    #[test]
+    fn wrong_key_kind() {
+        assert_eq!(
+            HDFactorInstancesIdentityCreationWithAuth::new(
+                transaction_signing(),
+                derive(NetworkID::Mainnet, CAP26KeyKind::TransactionSigning, 0),
+            ),
+            Err(CommonError::WrongKeyKindOfAuthenticationSigningFactorInstance)
+        );
+    }
+
+    #[test]
+    fn wrong_entity_kind() {
+        let private =
+            PrivateHierarchicalDeterministicFactorSource::placeholder();
+        let path = AccountPath::new(
+            NetworkID::Mainnet,
+            CAP26KeyKind::AuthenticationSigning,
+            0,
+        );
+        let account_auth = HierarchicalDeterministicFactorInstance::new(
+            private.factor_source.id,
+            private
+                .mnemonic_with_passphrase
+                .derive_private_key(path)
+                .public_key(),
+        );
+        assert_eq!(
+            HDFactorInstancesIdentityCreationWithAuth::new(
+                transaction_signing(),
+                account_auth
+            ),
+            Err(CommonError::WrongEntityKindOfInFactorInstancesPath)
+        );
+    }
+
+    #[test]
+    fn index_mismatch() {
+        assert_eq!(
+            HDFactorInstancesIdentityCreationWithAuth::new(
+                transaction_signing(),
+                derive(
+                    NetworkID::Mainnet,
+                    CAP26KeyKind::AuthenticationSigning,
+                    1
+                ),
+            ),
+            Err(CommonError::IdentityCreationFactorInstancesMismatch)
+        );
+    }
+
+    #[test]
+    fn network_mismatch() {
+        assert_eq!(
+            HDFactorInstancesIdentityCreationWithAuth::new(
+                transaction_signing(),
+                derive(
+                    NetworkID::Stokenet,
+                    CAP26KeyKind::AuthenticationSigning,
+                    0
+                ),
+            ),
+            Err(CommonError::IdentityCreationFactorInstancesMismatch)
+        );
+    }
+
+    #[test]
+    fn factor_source_mismatch() {
+        let mut auth =
+            derive(NetworkID::Mainnet, CAP26KeyKind::AuthenticationSigning, 0);
+        auth.factor_source_id = FactorSourceIDFromHash::placeholder_other();
+        assert_eq!(
+            HDFactorInstancesIdentityCreationWithAuth::new(
+                transaction_signing(),
+                auth
+            ),
+            Err(CommonError::IdentityCreationFactorInstancesMismatch)
+        );
+    }
+}
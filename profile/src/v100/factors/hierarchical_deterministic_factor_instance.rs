@@ -53,13 +53,12 @@ impl HierarchicalDeterministicFactorInstance {
     pub fn try_from_factor_instance(
         factor_instance: FactorInstance,
     ) -> Result<Self> {
-        let virtual_source = factor_instance
+        let badge = factor_instance
             .badge
             .as_virtual()
+            .and_then(|v| v.as_hierarchical_deterministic())
             .ok_or(CommonError::BadgeIsNotVirtualHierarchicalDeterministic)?;
 
-        let badge = virtual_source.as_hierarchical_deterministic();
-
         Self::try_from(
             factor_instance.factor_source_id.clone(),
             badge.public_key.clone(),
@@ -8,9 +8,12 @@ mod factor_source_id_from_address;
 mod factor_source_id_from_hash;
 mod factor_source_kind;
 mod factor_sources;
+mod hd_identity_creation_factor_instances;
 mod hd_transaction_signing_factor_instance;
 mod hierarchical_deterministic_factor_instance;
 mod is_factor_source;
+mod next_derivation_indices;
+mod off_device_signing;
 
 pub use factor_instance::*;
 pub use factor_source::*;
@@ -22,6 +25,9 @@ pub use factor_source_id_from_address::*;
 pub use factor_source_id_from_hash::*;
 pub use factor_source_kind::*;
 pub use factor_sources::*;
+pub use hd_identity_creation_factor_instances::*;
 pub use hd_transaction_signing_factor_instance::*;
 pub use hierarchical_deterministic_factor_instance::*;
 pub use is_factor_source::*;
+pub use next_derivation_indices::*;
+pub use off_device_signing::*;
@@ -0,0 +1,150 @@
+use crate::prelude::*;
+
+/// The derivation indices to use for the next Account and next Persona
+/// created on a network using a certain factor source.
+///
+/// Persisted rather than derived from the entities in Profile, since an
+/// entity which has been deleted must not have its index re-used.
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    derive_more::Display,
+    uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+#[display("{network_id}: account {for_account}, identity {for_identity}")]
+pub struct NextDerivationIndices {
+    /// The network these indices are used on.
+    #[serde(rename = "networkID")]
+    pub network_id: NetworkID,
+
+    /// The derivation index of the next Account.
+    pub for_account: HDPathValue,
+
+    /// The derivation index of the next Persona.
+    pub for_identity: HDPathValue,
+}
+
+/// The [`NextDerivationIndices`] of a factor source for every network it has
+/// been used on.
+pub type NextDerivationIndicesPerNetwork =
+    IdentifiedVecOf<NextDerivationIndices>;
+
+impl Default for NextDerivationIndicesPerNetwork {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Identifiable for NextDerivationIndices {
+    type ID = NetworkID;
+
+    fn id(&self) -> NetworkID {
+        self.network_id
+    }
+}
+
+impl NextDerivationIndices {
+    pub fn new(
+        network_id: NetworkID,
+        for_account: HDPathValue,
+        for_identity: HDPathValue,
+    ) -> Self {
+        Self {
+            network_id,
+            for_account,
+            for_identity,
+        }
+    }
+
+    /// Both indices `0`, for a network the factor source has not been used on.
+    pub fn unused(network_id: NetworkID) -> Self {
+        Self::new(network_id, 0, 0)
+    }
+
+    /// The derivation index of the next entity of `kind`.
+    pub fn index_for(&self, kind: EntityKind) -> HDPathValue {
+        match kind {
+            EntityKind::Accounts => self.for_account,
+            EntityKind::Persona => self.for_identity,
+        }
+    }
+
+    /// Raises the derivation index of the next entity of `kind` to `index`,
+    /// never lowering it, so that no index is ever handed out twice.
+    pub fn raise_index_for(&mut self, kind: EntityKind, index: HDPathValue) {
+        let next = match kind {
+            EntityKind::Accounts => &mut self.for_account,
+            EntityKind::Persona => &mut self.for_identity,
+        };
+        *next = (*next).max(index);
+    }
+}
+
+impl HasPlaceholder for NextDerivationIndices {
+    fn placeholder() -> Self {
+        Self::new(NetworkID::Mainnet, 2, 2)
+    }
+
+    fn placeholder_other() -> Self {
+        Self::new(NetworkID::Stokenet, 5, 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality() {
+        assert_eq!(
+            NextDerivationIndices::placeholder(),
+            NextDerivationIndices::placeholder()
+        );
+        assert_ne!(
+            NextDerivationIndices::placeholder(),
+            NextDerivationIndices::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn index_for() {
+        let sut = NextDerivationIndices::placeholder_other();
+        assert_eq!(sut.index_for(EntityKind::Accounts), 5);
+        assert_eq!(sut.index_for(EntityKind::Persona), 0);
+    }
+
+    #[test]
+    fn raise_index_for_never_lowers() {
+        let mut sut = NextDerivationIndices::unused(NetworkID::Mainnet);
+        sut.raise_index_for(EntityKind::Persona, 3);
+        sut.raise_index_for(EntityKind::Persona, 1);
+        assert_eq!(sut, NextDerivationIndices::new(NetworkID::Mainnet, 0, 3));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            NextDerivationIndices::placeholder_other().to_string(),
+            "stokenet: account 5, identity 0"
+        );
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        assert_json_value_eq_after_roundtrip(
+            &NextDerivationIndices::placeholder(),
+            json!({
+                "networkID": 1,
+                "forAccount": 2,
+                "forIdentity": 2
+            }),
+        );
+    }
+}
@@ -0,0 +1,5 @@
+mod off_device_signing_request;
+mod off_device_signing_response;
+
+pub use off_device_signing_request::*;
+pub use off_device_signing_response::*;
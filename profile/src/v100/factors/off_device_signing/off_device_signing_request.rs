@@ -0,0 +1,461 @@
+use crate::prelude::*;
+
+/// A hash to be signed by an external signer with the private key at the
+/// derivation path of `public_key`.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct OffDeviceSigningRequestEntry {
+    /// The hash to sign, e.g. a transaction intent hash.
+    pub hash: Hex32Bytes,
+
+    /// The derivation path of the key to sign with, and its expected
+    /// public key, used to validate the produced signature.
+    pub public_key: HierarchicalDeterministicPublicKey,
+}
+
+impl OffDeviceSigningRequestEntry {
+    pub fn new(
+        hash: Hex32Bytes,
+        public_key: HierarchicalDeterministicPublicKey,
+    ) -> Self {
+        Self { hash, public_key }
+    }
+}
+
+/// A request to sign hashes with keys of a factor source which is not
+/// available on this device, e.g. a cold key held by an admin tool of an
+/// enterprise setup. Exported as JSON, which can be shared as a file or
+/// QR code, the external signer replies with an [`OffDeviceSigningResponse`].
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct OffDeviceSigningRequest {
+    /// The network the hashes are to be submitted to.
+    #[serde(rename = "networkID")]
+    pub network_id: NetworkID,
+
+    /// The factor source controlling the keys to sign with.
+    #[serde(rename = "factorSourceID")]
+    pub factor_source_id: FactorSourceIDFromHash,
+
+    /// The hashes to sign, each with the key to sign it with.
+    pub entries: Vec<OffDeviceSigningRequestEntry>,
+}
+
+impl OffDeviceSigningRequest {
+    pub fn new<I>(
+        network_id: NetworkID,
+        factor_source_id: FactorSourceIDFromHash,
+        entries: I,
+    ) -> Self
+    where
+        I: IntoIterator<Item = OffDeviceSigningRequestEntry>,
+    {
+        Self {
+            network_id,
+            factor_source_id,
+            entries: entries.into_iter().collect_vec(),
+        }
+    }
+
+    /// Parses a request from its JSON representation, failing if it is
+    /// not valid, see [`OffDeviceSigningRequest::validate`].
+    pub fn from_json(json: impl AsRef<str>) -> Result<Self> {
+        let json = json.as_ref();
+        let request: Self = serde_json::from_str(json).map_err(|_| {
+            CommonError::FailedToDeserializeJSONToValue {
+                json_byte_count: json.len(),
+                type_name: "OffDeviceSigningRequest".to_string(),
+            }
+        })?;
+        request.validate()?;
+        Ok(request)
+    }
+
+    /// Validates that the derivation path of every entry bound to a network,
+    /// i.e. every Account and Identity path, is on the network of this
+    /// request.
+    pub fn validate(&self) -> Result<()> {
+        self.entries
+            .iter()
+            .filter_map(|e| e.public_key.derivation_path.network_id())
+            .find(|n| *n != self.network_id)
+            .map_or(Ok(()), |found| {
+                Err(CommonError::OffDeviceSigningNetworkMismatch {
+                    expected: self.network_id,
+                    found,
+                })
+            })
+    }
+
+    /// The JSON representation of this request, to be shared with the
+    /// external signer as a file or QR code.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self)
+            .expect("Should always be able to JSON serialize a request.")
+    }
+
+    /// Validates this request, see [`OffDeviceSigningRequest::validate`],
+    /// and that `response` was produced by the factor source of this request
+    /// and contains exactly one valid signature for each requested hash,
+    /// made by the requested public key at the requested derivation path.
+    ///
+    /// Since the derivation path of every signature must be a requested one,
+    /// the signatures are made by keys on the network of this request.
+    pub fn validate_response(
+        &self,
+        response: &OffDeviceSigningResponse,
+    ) -> Result<()> {
+        self.validate()?;
+
+        if response.factor_source_id != self.factor_source_id {
+            return Err(CommonError::OffDeviceSigningFactorSourceIDMismatch {
+                expected: self.factor_source_id.clone(),
+                found: response.factor_source_id.clone(),
+            });
+        }
+
+        for signature in response.signatures.iter() {
+            let requested = self
+                .entries
+                .iter()
+                .filter(|e| {
+                    e.hash == signature.hash
+                        && e.public_key.public_key
+                            == signature.public_key.public_key
+                })
+                .collect_vec();
+            let Some(first_requested) = requested.first() else {
+                return Err(CommonError::OffDeviceSigningUnrequestedSignature(
+                    signature.public_key.to_hex(),
+                ));
+            };
+            let derivation_path = &signature.public_key.derivation_path;
+            if !requested
+                .iter()
+                .any(|e| e.public_key.derivation_path == *derivation_path)
+            {
+                return Err(
+                    CommonError::OffDeviceSigningDerivationPathMismatch {
+                        expected: first_requested
+                            .public_key
+                            .derivation_path
+                            .bip32_string(),
+                        found: derivation_path.bip32_string(),
+                    },
+                );
+            }
+            if !signature.is_valid() {
+                return Err(CommonError::OffDeviceSigningInvalidSignature(
+                    signature.public_key.to_hex(),
+                ));
+            }
+        }
+
+        for entry in self.entries.iter() {
+            let signature_count = response
+                .signatures
+                .iter()
+                .filter(|s| {
+                    s.hash == entry.hash && s.public_key == entry.public_key
+                })
+                .count();
+            if signature_count != 1 {
+                return Err(CommonError::OffDeviceSigningMissingSignature(
+                    entry.public_key.to_hex(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl HasPlaceholder for OffDeviceSigningRequest {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::new(
+            NetworkID::Mainnet,
+            FactorSourceIDFromHash::placeholder(),
+            [OffDeviceSigningRequestEntry::new(
+                Hex32Bytes::placeholder(),
+                HierarchicalDeterministicPublicKey::placeholder(),
+            )],
+        )
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::new(
+            NetworkID::Mainnet,
+            FactorSourceIDFromHash::placeholder_other(),
+            [OffDeviceSigningRequestEntry::new(
+                Hex32Bytes::placeholder_other(),
+                HierarchicalDeterministicPublicKey::placeholder(),
+            )],
+        )
+    }
+}
+
+#[uniffi::export]
+pub fn new_off_device_signing_request_from_json(
+    json: String,
+) -> Result<OffDeviceSigningRequest> {
+    OffDeviceSigningRequest::from_json(json)
+}
+
+#[uniffi::export]
+pub fn off_device_signing_request_to_json(
+    request: &OffDeviceSigningRequest,
+) -> String {
+    request.to_json()
+}
+
+#[uniffi::export]
+pub fn off_device_signing_request_validate_response(
+    request: &OffDeviceSigningRequest,
+    response: &OffDeviceSigningResponse,
+) -> Result<()> {
+    request.validate_response(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality() {
+        assert_eq!(
+            OffDeviceSigningRequest::placeholder(),
+            OffDeviceSigningRequest::placeholder()
+        );
+        assert_eq!(
+            OffDeviceSigningRequest::placeholder_other(),
+            OffDeviceSigningRequest::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            OffDeviceSigningRequest::placeholder(),
+            OffDeviceSigningRequest::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        let sut = OffDeviceSigningRequest::placeholder();
+        assert_eq!(OffDeviceSigningRequest::from_json(sut.to_json()), Ok(sut));
+    }
+
+    #[test]
+    fn json_keys() {
+        let json = serde_json::to_value(OffDeviceSigningRequest::placeholder())
+            .unwrap();
+        assert_eq!(json["networkID"], json!(1));
+        assert_eq!(
+            json["entries"][0]["hash"],
+            json!(Hex32Bytes::placeholder().to_hex())
+        );
+        assert_eq!(
+            json["entries"][0]["publicKey"]["derivationPath"]["path"],
+            json!("m/44H/1022H/1H/525H/1460H/0H")
+        );
+    }
+
+    #[test]
+    fn from_invalid_json() {
+        assert_eq!(
+            OffDeviceSigningRequest::from_json("{}"),
+            Err(CommonError::FailedToDeserializeJSONToValue {
+                json_byte_count: 2,
+                type_name: "OffDeviceSigningRequest".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn validate_response_success() {
+        assert_eq!(
+            OffDeviceSigningRequest::placeholder()
+                .validate_response(&OffDeviceSigningResponse::placeholder()),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn validate_response_wrong_factor_source() {
+        let mut response = OffDeviceSigningResponse::placeholder();
+        response.factor_source_id = FactorSourceIDFromHash::placeholder_other();
+        assert_eq!(
+            OffDeviceSigningRequest::placeholder().validate_response(&response),
+            Err(CommonError::OffDeviceSigningFactorSourceIDMismatch {
+                expected: FactorSourceIDFromHash::placeholder(),
+                found: FactorSourceIDFromHash::placeholder_other(),
+            })
+        );
+    }
+
+    #[test]
+    fn validate_response_missing_signature() {
+        let mut response = OffDeviceSigningResponse::placeholder();
+        response.signatures.clear();
+        assert_eq!(
+            OffDeviceSigningRequest::placeholder().validate_response(&response),
+            Err(CommonError::OffDeviceSigningMissingSignature(
+                HierarchicalDeterministicPublicKey::placeholder().to_hex()
+            ))
+        );
+    }
+
+    #[test]
+    fn validate_response_duplicate_signature() {
+        let mut response = OffDeviceSigningResponse::placeholder();
+        response.signatures.push(response.signatures[0].clone());
+        assert_eq!(
+            OffDeviceSigningRequest::placeholder().validate_response(&response),
+            Err(CommonError::OffDeviceSigningMissingSignature(
+                HierarchicalDeterministicPublicKey::placeholder().to_hex()
+            ))
+        );
+    }
+
+    #[test]
+    fn validate_response_unrequested_signature() {
+        let mut response = OffDeviceSigningResponse::placeholder();
+        response.signatures[0].hash = Hex32Bytes::placeholder_other();
+        assert_eq!(
+            OffDeviceSigningRequest::placeholder().validate_response(&response),
+            Err(CommonError::OffDeviceSigningUnrequestedSignature(
+                HierarchicalDeterministicPublicKey::placeholder().to_hex()
+            ))
+        );
+    }
+
+    #[test]
+    fn validate_response_wrong_derivation_path() {
+        let mut response = OffDeviceSigningResponse::placeholder();
+        response.signatures[0].public_key.derivation_path =
+            AccountPath::new_mainnet_transaction_signing(1).into();
+        assert_eq!(
+            OffDeviceSigningRequest::placeholder().validate_response(&response),
+            Err(CommonError::OffDeviceSigningDerivationPathMismatch {
+                expected: "m/44H/1022H/1H/525H/1460H/0H".to_owned(),
+                found: "m/44H/1022H/1H/525H/1460H/1H".to_owned(),
+            })
+        );
+    }
+
+    fn stokenet_path() -> DerivationPath {
+        AccountPath::new(
+            NetworkID::Stokenet,
+            CAP26KeyKind::TransactionSigning,
+            0,
+        )
+        .into()
+    }
+
+    #[test]
+    fn validate_request_on_other_network() {
+        let mut sut = OffDeviceSigningRequest::placeholder();
+        sut.network_id = NetworkID::Stokenet;
+        assert_eq!(
+            sut.validate(),
+            Err(CommonError::OffDeviceSigningNetworkMismatch {
+                expected: NetworkID::Stokenet,
+                found: NetworkID::Mainnet,
+            })
+        );
+    }
+
+    #[test]
+    fn validate_request_ignores_paths_without_network() {
+        let mut sut = OffDeviceSigningRequest::placeholder();
+        sut.network_id = NetworkID::Stokenet;
+        sut.entries[0].public_key.derivation_path =
+            DerivationPath::from(BIP44LikePath::placeholder());
+        assert_eq!(sut.validate(), Ok(()));
+    }
+
+    #[test]
+    fn from_json_fails_for_request_on_other_network() {
+        let mut sut = OffDeviceSigningRequest::placeholder();
+        sut.entries[0].public_key.derivation_path = stokenet_path();
+        assert_eq!(
+            OffDeviceSigningRequest::from_json(sut.to_json()),
+            Err(CommonError::OffDeviceSigningNetworkMismatch {
+                expected: NetworkID::Mainnet,
+                found: NetworkID::Stokenet,
+            })
+        );
+    }
+
+    #[test]
+    fn validate_response_mismatched_request() {
+        let mut request = OffDeviceSigningRequest::placeholder();
+        request.entries[0].public_key.derivation_path = stokenet_path();
+        let mut response = OffDeviceSigningResponse::placeholder();
+        response.signatures[0].public_key.derivation_path = stokenet_path();
+        assert_eq!(
+            request.validate_response(&response),
+            Err(CommonError::OffDeviceSigningNetworkMismatch {
+                expected: NetworkID::Mainnet,
+                found: NetworkID::Stokenet,
+            })
+        );
+    }
+
+    #[test]
+    fn validate_response_signature_on_other_network() {
+        let mut response = OffDeviceSigningResponse::placeholder();
+        response.signatures[0].public_key.derivation_path = stokenet_path();
+        assert_eq!(
+            OffDeviceSigningRequest::placeholder().validate_response(&response),
+            Err(CommonError::OffDeviceSigningDerivationPathMismatch {
+                expected: "m/44H/1022H/1H/525H/1460H/0H".to_owned(),
+                found: "m/44H/1022H/2H/525H/1460H/0H".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn validate_response_invalid_signature() {
+        let mut response = OffDeviceSigningResponse::placeholder();
+        response.signatures[0].signature = BagOfBytes::from(vec![0xde; 64]);
+        assert_eq!(
+            OffDeviceSigningRequest::placeholder().validate_response(&response),
+            Err(CommonError::OffDeviceSigningInvalidSignature(
+                HierarchicalDeterministicPublicKey::placeholder().to_hex()
+            ))
+        );
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn json_roundtrip() {
+        let sut = OffDeviceSigningRequest::placeholder();
+        assert_eq!(
+            new_off_device_signing_request_from_json(
+                off_device_signing_request_to_json(&sut)
+            ),
+            Ok(sut)
+        );
+    }
+
+    #[test]
+    fn validate_response() {
+        assert!(off_device_signing_request_validate_response(
+            &OffDeviceSigningRequest::placeholder(),
+            &OffDeviceSigningResponse::placeholder()
+        )
+        .is_ok());
+    }
+}
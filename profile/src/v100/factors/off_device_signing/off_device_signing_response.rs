@@ -0,0 +1,233 @@
+use crate::prelude::*;
+use radix_engine_common::crypto::Hash;
+
+/// A signature of `hash` produced by an external signer.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct OffDeviceSignature {
+    /// The hash which was signed.
+    pub hash: Hex32Bytes,
+
+    /// The public key of the private key used to sign `hash`, and its
+    /// derivation path, validated against the requested entry.
+    pub public_key: HierarchicalDeterministicPublicKey,
+
+    /// The signature bytes, 64 bytes for Ed25519 and 65 bytes for
+    /// secp256k1.
+    pub signature: BagOfBytes,
+}
+
+impl OffDeviceSignature {
+    pub fn new(
+        hash: Hex32Bytes,
+        public_key: HierarchicalDeterministicPublicKey,
+        signature: BagOfBytes,
+    ) -> Self {
+        Self {
+            hash,
+            public_key,
+            signature,
+        }
+    }
+
+    /// Signs `hash` with `private_key`, as an external signer would.
+    pub fn produced_by(
+        private_key: &HierarchicalDeterministicPrivateKey,
+        hash: Hex32Bytes,
+    ) -> Self {
+        let signed = private_key.private_key.sign(&Hash(hash.bytes()));
+        Self::new(
+            hash,
+            private_key.public_key(),
+            signed.signature().to_bytes().into(),
+        )
+    }
+
+    /// The signature together with the public key, if the signature bytes
+    /// are valid for the curve of the public key.
+    pub fn signature_with_public_key(&self) -> Result<SignatureWithPublicKey> {
+        let public_key = self.public_key.public_key.clone();
+        let signature =
+            Signature::from_bytes(public_key.curve(), &self.signature)?;
+        SignatureWithPublicKey::new(public_key, signature)
+    }
+
+    /// Returns `true` if `signature` is a valid signature of `hash` by
+    /// `public_key`.
+    pub fn is_valid(&self) -> bool {
+        self.signature_with_public_key()
+            .map(|s| s.is_valid_for(&Hash(self.hash.bytes())))
+            .unwrap_or(false)
+    }
+}
+
+/// The signatures produced by an external signer for an
+/// [`OffDeviceSigningRequest`], imported from JSON and validated using
+/// [`OffDeviceSigningRequest::validate_response`].
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct OffDeviceSigningResponse {
+    /// The factor source which was used to sign.
+    #[serde(rename = "factorSourceID")]
+    pub factor_source_id: FactorSourceIDFromHash,
+
+    /// One signature per entry of the request.
+    pub signatures: Vec<OffDeviceSignature>,
+}
+
+impl OffDeviceSigningResponse {
+    pub fn new<I>(
+        factor_source_id: FactorSourceIDFromHash,
+        signatures: I,
+    ) -> Self
+    where
+        I: IntoIterator<Item = OffDeviceSignature>,
+    {
+        Self {
+            factor_source_id,
+            signatures: signatures.into_iter().collect_vec(),
+        }
+    }
+
+    /// Parses a response from its JSON representation.
+    pub fn from_json(json: impl AsRef<str>) -> Result<Self> {
+        let json = json.as_ref();
+        serde_json::from_str(json).map_err(|_| {
+            CommonError::FailedToDeserializeJSONToValue {
+                json_byte_count: json.len(),
+                type_name: "OffDeviceSigningResponse".to_string(),
+            }
+        })
+    }
+
+    /// The JSON representation of this response.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self)
+            .expect("Should always be able to JSON serialize a response.")
+    }
+}
+
+impl HasPlaceholder for OffDeviceSigningResponse {
+    /// A placeholder used to facilitate unit tests, a valid response to
+    /// `OffDeviceSigningRequest::placeholder()`.
+    fn placeholder() -> Self {
+        Self::new(
+            FactorSourceIDFromHash::placeholder(),
+            [OffDeviceSignature::produced_by(
+                &HierarchicalDeterministicPrivateKey::placeholder(),
+                Hex32Bytes::placeholder(),
+            )],
+        )
+    }
+
+    /// A placeholder used to facilitate unit tests, a valid response to
+    /// `OffDeviceSigningRequest::placeholder_other()`.
+    fn placeholder_other() -> Self {
+        Self::new(
+            FactorSourceIDFromHash::placeholder_other(),
+            [OffDeviceSignature::produced_by(
+                &HierarchicalDeterministicPrivateKey::placeholder(),
+                Hex32Bytes::placeholder_other(),
+            )],
+        )
+    }
+}
+
+#[uniffi::export]
+pub fn new_off_device_signing_response_from_json(
+    json: String,
+) -> Result<OffDeviceSigningResponse> {
+    OffDeviceSigningResponse::from_json(json)
+}
+
+#[uniffi::export]
+pub fn off_device_signing_response_to_json(
+    response: &OffDeviceSigningResponse,
+) -> String {
+    response.to_json()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality() {
+        assert_eq!(
+            OffDeviceSigningResponse::placeholder(),
+            OffDeviceSigningResponse::placeholder()
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            OffDeviceSigningResponse::placeholder(),
+            OffDeviceSigningResponse::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        let sut = OffDeviceSigningResponse::placeholder();
+        assert_eq!(OffDeviceSigningResponse::from_json(sut.to_json()), Ok(sut));
+    }
+
+    #[test]
+    fn from_invalid_json() {
+        assert!(OffDeviceSigningResponse::from_json("[]").is_err());
+    }
+
+    #[test]
+    fn ed25519_signature_is_valid() {
+        assert!(
+            OffDeviceSigningResponse::placeholder().signatures[0].is_valid()
+        );
+    }
+
+    #[test]
+    fn secp256k1_signature_is_valid() {
+        let sut = OffDeviceSignature::produced_by(
+            &HierarchicalDeterministicPrivateKey::placeholder_other(),
+            Hex32Bytes::placeholder(),
+        );
+        assert_eq!(sut.signature.len(), 65);
+        assert!(sut.is_valid());
+    }
+
+    #[test]
+    fn signature_of_other_hash_is_invalid() {
+        let mut sut =
+            OffDeviceSigningResponse::placeholder().signatures[0].clone();
+        sut.hash = Hex32Bytes::placeholder_other();
+        assert!(!sut.is_valid());
+    }
+
+    #[test]
+    fn signature_of_wrong_length_is_invalid() {
+        let mut sut =
+            OffDeviceSigningResponse::placeholder().signatures[0].clone();
+        sut.signature = BagOfBytes::from(vec![0xab; 10]);
+        assert!(!sut.is_valid());
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn json_roundtrip() {
+        let sut = OffDeviceSigningResponse::placeholder();
+        assert_eq!(
+            new_off_device_signing_response_from_json(
+                off_device_signing_response_to_json(&sut)
+            ),
+            Ok(sut)
+        );
+    }
+}
@@ -50,7 +50,7 @@ impl DeviceInfo {
     /// Instantiates a new `DeviceInfo` with `description`, and generates a new `id`
     /// and will use the current `date` for creation date.
     pub fn with_description(description: &str) -> Self {
-        Self::new(id(), now(), description.to_string())
+        Self::new(id(), Timestamp::now(), description.to_string())
     }
 
     /// Instantiates a new `DeviceInfo` with "iPhone" as description, and
@@ -36,6 +36,11 @@ pub struct Header {
 
     /// Hint about the contents of the profile, e.g. number of Accounts and Personas.
     pub content_hint: ContentHint,
+
+    /// Fields unknown to this version, written by a newer version of the
+    /// Profile format, re-emitted as-is when encoded.
+    #[serde(flatten)]
+    pub extra: UnknownFields,
 }
 
 #[uniffi::export]
@@ -63,6 +68,7 @@ impl Header {
             last_used_on_device: creating_device,
             last_modified,
             content_hint,
+            extra: UnknownFields::default(),
         }
     }
 
@@ -73,7 +79,7 @@ impl Header {
             profile_id(),
             creating_device,
             ContentHint::new(),
-            now(),
+            Timestamp::now(),
         )
     }
 }
@@ -105,7 +111,7 @@ impl HasPlaceholder for Header {
             ProfileID::from_str("12345678-bbbb-cccc-dddd-abcd12345678")
                 .unwrap(),
             device,
-            ContentHint::with_counters(4, 0, 2),
+            ContentHint::with_counters(4, 4, 2),
             date,
         )
     }
@@ -165,7 +171,7 @@ pub mod tests {
                 "lastModified": "2023-09-11T16:05:56.000Z",
                 "contentHint": {
                     "numberOfAccountsOnAllNetworksInTotal": 4,
-                    "numberOfPersonasOnAllNetworksInTotal": 0,
+                    "numberOfPersonasOnAllNetworksInTotal": 4,
                     "numberOfNetworks": 2
                 }
             }
@@ -175,8 +181,14 @@ pub mod tests {
 
     #[test]
     fn last_updated() {
-        let a = Header::default();
-        let b = Header::default();
+        let a = with_time_provider(
+            MockTimeProvider(Timestamp::placeholder()),
+            Header::default,
+        );
+        let b = with_time_provider(
+            MockTimeProvider(Timestamp::placeholder_other()),
+            Header::default,
+        );
         assert_ne!(a.last_modified, b.last_modified);
     }
 
@@ -3,7 +3,7 @@ use crate::prelude::*;
 /// An ordered set of [`Account`]s on a specific network, most commonly
 /// the set is non-empty, since wallets guide user to create a first
 /// Account.
-pub type Accounts = IdentifiedVecVia<Account>;
+pub type Accounts = IdentifiedVecOf<Account>;
 
 impl Accounts {
     /// Instantiates a new collection of accounts from
@@ -36,7 +36,7 @@ impl Accounts {
         &self,
         address: &AccountAddress,
     ) -> Option<&Account> {
-        self.get(address)
+        self.get_id(address)
     }
 
     /// Returns references to **all** accounts, including hidden ones.
@@ -27,6 +27,15 @@ pub struct AuthorizedDapp {
     /// necessary data by IDs.
     pub references_to_authorized_personas:
         IdentifiedVecVia<AuthorizedPersonaSimple>,
+
+    /// The Persona and Accounts the user chose the last time they logged in
+    /// to this Dapp, omitted if they have not logged in since preferences
+    /// were introduced.
+    #[serde(
+        default,
+        skip_serializing_if = "AuthorizedDappPreferences::is_empty"
+    )]
+    pub preferences: AuthorizedDappPreferences,
 }
 
 impl AuthorizedDapp {
@@ -45,8 +54,25 @@ impl AuthorizedDapp {
             dapp_definition_address,
             display_name: display_name.into(),
             references_to_authorized_personas,
+            preferences: AuthorizedDappPreferences::default(),
         }
     }
+
+    /// `true` if the Account with `address` has been shared with this Dapp,
+    /// either by any of the referenced Personas or in the last login stored
+    /// in `preferences`.
+    pub fn has_shared_account(&self, address: &AccountAddress) -> bool {
+        self.preferences.shared_accounts_order.contains(address)
+            || self
+                .references_to_authorized_personas
+                .items()
+                .iter()
+                .any(|r| {
+                    r.shared_accounts
+                        .as_ref()
+                        .is_some_and(|s| s.ids.contains_id(address))
+                })
+    }
 }
 
 impl Identifiable for AuthorizedDapp {
@@ -162,6 +188,36 @@ mod tests {
         assert_ne!(SUT::placeholder(), SUT::placeholder_other());
     }
 
+    #[test]
+    fn has_shared_account_via_persona_reference() {
+        let sut = SUT::placeholder_mainnet();
+        assert!(sut.has_shared_account(&AccountAddress::placeholder_mainnet()));
+        assert!(!sut
+            .has_shared_account(&Account::placeholder_mainnet_alice().address));
+    }
+
+    #[test]
+    fn has_shared_account_via_preferences() {
+        let alice = Account::placeholder_mainnet_alice().address;
+        let mut sut = SUT::placeholder_mainnet();
+        sut.preferences = AuthorizedDappPreferences::new(None, [alice.clone()]);
+        assert!(sut.has_shared_account(&alice));
+    }
+
+    #[test]
+    fn json_with_preferences_roundtrip() {
+        let mut model = AuthorizedDapp::placeholder_mainnet_other();
+        model.preferences = AuthorizedDappPreferences::placeholder_other();
+        let json = serde_json::to_value(&model).unwrap();
+        assert_eq!(
+            json["preferences"],
+            json!({
+                "lastUsedPersona": "identity_rdx12gcd4r799jpvztlffgw483pqcen98pjnay988n8rmscdswd872xy62"
+            })
+        );
+        assert_json_roundtrip(&model);
+    }
+
     #[test]
     fn json_mainnet_roundtrip() {
         let model = AuthorizedDapp::placeholder_mainnet();
@@ -0,0 +1,187 @@
+use crate::prelude::*;
+
+/// The choices the user made the last time they logged in to a Dapp, used
+/// to pre-select the same Persona and Accounts - in the same order - on the
+/// next login, and the Account the user prefers to deposit into when the
+/// Dapp asks for one.
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    Hash,
+    Eq,
+    uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthorizedDappPreferences {
+    /// The Persona the user last logged in with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_used_persona: Option<IdentityAddress>,
+
+    /// The Accounts the user last shared with the Dapp, in the order they
+    /// selected them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub shared_accounts_order: Vec<AccountAddress>,
+
+    /// The Account to pre-select when the Dapp requests an Account to
+    /// deposit into, must be one of the Accounts shared with the Dapp.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_deposit_account: Option<AccountAddress>,
+}
+
+impl AuthorizedDappPreferences {
+    pub fn new(
+        last_used_persona: impl Into<Option<IdentityAddress>>,
+        shared_accounts_order: impl IntoIterator<Item = AccountAddress>,
+    ) -> Self {
+        Self {
+            last_used_persona: last_used_persona.into(),
+            shared_accounts_order: shared_accounts_order
+                .into_iter()
+                .unique()
+                .collect_vec(),
+            default_deposit_account: None,
+        }
+    }
+
+    /// Returns a copy of these preferences with `default_deposit_account`
+    /// set, or cleared if `None`.
+    pub fn with_default_deposit_account(
+        &self,
+        default_deposit_account: impl Into<Option<AccountAddress>>,
+    ) -> Self {
+        Self {
+            default_deposit_account: default_deposit_account.into(),
+            ..self.clone()
+        }
+    }
+
+    /// `true` if the user has not logged in since preferences were
+    /// introduced, in which case they are omitted from JSON.
+    pub fn is_empty(&self) -> bool {
+        self.last_used_persona.is_none()
+            && self.shared_accounts_order.is_empty()
+            && self.default_deposit_account.is_none()
+    }
+}
+
+impl HasPlaceholder for AuthorizedDappPreferences {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::new(
+            IdentityAddress::placeholder_mainnet(),
+            [
+                AccountAddress::placeholder_mainnet_other(),
+                AccountAddress::placeholder_mainnet(),
+            ],
+        )
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::new(IdentityAddress::placeholder_mainnet_other(), Vec::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = AuthorizedDappPreferences;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::placeholder(), SUT::placeholder());
+        assert_eq!(SUT::placeholder_other(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::placeholder(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn default_is_empty() {
+        assert!(SUT::default().is_empty());
+        assert!(!SUT::placeholder().is_empty());
+    }
+
+    #[test]
+    fn duplicate_accounts_are_removed() {
+        let sut = SUT::new(
+            None,
+            [
+                AccountAddress::placeholder_mainnet(),
+                AccountAddress::placeholder_mainnet_other(),
+                AccountAddress::placeholder_mainnet(),
+            ],
+        );
+        assert_eq!(
+            sut.shared_accounts_order,
+            vec![
+                AccountAddress::placeholder_mainnet(),
+                AccountAddress::placeholder_mainnet_other()
+            ]
+        );
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        assert_eq_after_json_roundtrip(
+            &SUT::placeholder(),
+            r#"
+            {
+                "lastUsedPersona": "identity_rdx122kttqch0eehzj6f9nkkxcw7msfeg9udurq5u0ysa0e92c59w0mg6x",
+                "sharedAccountsOrder": [
+                    "account_rdx16yf8jxxpdtcf4afpj5ddeuazp2evep7quuhgtq28vjznee08master",
+                    "account_rdx16xlfcpp0vf7e3gqnswv8j9k58n6rjccu58vvspmdva22kf3aplease"
+                ]
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn with_default_deposit_account() {
+        let sut =
+            SUT::placeholder().with_default_deposit_account(
+                AccountAddress::placeholder_mainnet(),
+            );
+        assert_eq!(
+            sut.default_deposit_account,
+            Some(AccountAddress::placeholder_mainnet())
+        );
+        assert_eq!(sut.with_default_deposit_account(None), SUT::placeholder());
+    }
+
+    #[test]
+    fn only_default_deposit_account_is_not_empty() {
+        assert!(!SUT::default()
+            .with_default_deposit_account(AccountAddress::placeholder_mainnet())
+            .is_empty());
+    }
+
+    #[test]
+    fn json_roundtrip_with_default_deposit_account() {
+        assert_eq_after_json_roundtrip(
+            &SUT::placeholder_other().with_default_deposit_account(
+                AccountAddress::placeholder_mainnet(),
+            ),
+            r#"
+            {
+                "lastUsedPersona": "identity_rdx12gcd4r799jpvztlffgw483pqcen98pjnay988n8rmscdswd872xy62",
+                "defaultDepositAccount": "account_rdx16xlfcpp0vf7e3gqnswv8j9k58n6rjccu58vvspmdva22kf3aplease"
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn json_roundtrip_empty() {
+        assert_eq_after_json_roundtrip(&SUT::default(), "{}");
+    }
+}
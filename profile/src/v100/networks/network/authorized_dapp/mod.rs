@@ -1,4 +1,5 @@
 mod authorized_dapp;
+mod authorized_dapp_preferences;
 mod authorized_persona_simple;
 mod requested_number_quantifier;
 mod requested_quantity;
@@ -6,6 +7,7 @@ mod shared_persona_data;
 mod shared_with_dapp;
 
 pub use authorized_dapp::*;
+pub use authorized_dapp_preferences::*;
 pub use authorized_persona_simple::*;
 pub use requested_number_quantifier::*;
 pub use requested_quantity::*;
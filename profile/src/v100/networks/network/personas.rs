@@ -1,7 +1,7 @@
 use crate::prelude::*;
 
 /// An ordered set of Personas on a specific network.
-pub type Personas = IdentifiedVecVia<Persona>;
+pub type Personas = IdentifiedVecOf<Persona>;
 
 impl Personas {
     /// Instantiates a new collection of personas from
@@ -34,7 +34,7 @@ impl Personas {
         &self,
         address: &IdentityAddress,
     ) -> Option<&Persona> {
-        self.get(address)
+        self.get_id(address)
     }
 
     /// Returns references to **all** personas, including hidden ones.
@@ -87,7 +87,23 @@ impl ProfileNetwork {
         F: FnMut(&mut Account),
     {
         if self.accounts.update_with(address, mutate) {
-            self.accounts.get(address).cloned()
+            self.accounts.get_id(address).cloned()
+        } else {
+            None
+        }
+    }
+
+    /// Returns a clone of the updated persona if found, else None.
+    pub fn update_persona<F>(
+        &mut self,
+        address: &IdentityAddress,
+        mutate: F,
+    ) -> Option<Persona>
+    where
+        F: FnMut(&mut Persona),
+    {
+        if self.personas.update_with(address, mutate) {
+            self.personas.get_id(address).cloned()
         } else {
             None
         }
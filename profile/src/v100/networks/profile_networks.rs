@@ -3,7 +3,7 @@ use crate::prelude::*;
 /// An ordered mapping of NetworkID -> `Profile.Network`, containing
 /// all the users Accounts, Personas and AuthorizedDapps the user
 /// has created and interacted with on this network.
-pub type ProfileNetworks = IdentifiedVecVia<ProfileNetwork>;
+pub type ProfileNetworks = IdentifiedVecOf<ProfileNetwork>;
 
 // Constructors
 impl ProfileNetworks {
@@ -25,7 +25,7 @@ impl ProfileNetworks {
 
 impl ProfileNetworks {
     pub fn get_account(&self, address: &AccountAddress) -> Option<Account> {
-        self.get(&address.network_id)
+        self.get_id(&address.network_id)
             .and_then(|n| n.accounts.get_account_by_address(address))
             .cloned()
     }
@@ -44,13 +44,40 @@ impl ProfileNetworks {
         });
         self.get_account(address)
     }
+
+    pub fn get_persona(&self, address: &IdentityAddress) -> Option<Persona> {
+        self.get_id(&address.network_id)
+            .and_then(|n| n.personas.get_persona_by_address(address))
+            .cloned()
+    }
+
+    /// Returns a clone of the updated persona if found, else None.
+    pub fn update_persona<F>(
+        &mut self,
+        address: &IdentityAddress,
+        mut mutate: F,
+    ) -> Option<Persona>
+    where
+        F: FnMut(&mut Persona),
+    {
+        self.update_with(&address.network_id, |n| {
+            _ = n.update_persona(address, |p| mutate(p))
+        });
+        self.get_persona(address)
+    }
 }
 
 impl ProfileNetworks {
     pub fn content_hint(&self) -> ContentHint {
         let number_of_accounts =
             self.iter().fold(0, |acc, x| acc + x.accounts.len());
-        ContentHint::with_counters(number_of_accounts, 0, self.len())
+        let number_of_personas =
+            self.iter().fold(0, |acc, x| acc + x.personas.len());
+        ContentHint::with_counters(
+            number_of_accounts,
+            number_of_personas,
+            self.len(),
+        )
     }
 }
 
@@ -128,18 +155,16 @@ mod tests {
             Personas::default(),
             AuthorizedDapps::default(),
         )]);
-        assert!(
-            !sut.append(ProfileNetwork::new(
-                NetworkID::Mainnet,
-                Accounts::from_iter([Account::placeholder_mainnet_carol()]),
-                Personas::default(),
-                AuthorizedDapps::default(),
-            ))
-            .0
-        );
+        assert!(!sut.append(ProfileNetwork::new(
+            NetworkID::Mainnet,
+            Accounts::from_iter([Account::placeholder_mainnet_carol()]),
+            Personas::default(),
+            AuthorizedDapps::default(),
+        )));
 
+        assert_eq!(sut.len(), 1);
         assert_eq!(
-            sut.get(&NetworkID::Mainnet).unwrap().accounts.items(),
+            sut.get_id(&NetworkID::Mainnet).unwrap().accounts.items(),
             [
                 Account::placeholder_mainnet_alice(),
                 Account::placeholder_mainnet_bob()
@@ -147,16 +172,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn try_insert_unique_duplicate_network_fails() {
+        let mut sut = ProfileNetworks::placeholder();
+        assert_eq!(
+            sut.try_insert_unique(ProfileNetwork::new(
+                NetworkID::Mainnet,
+                Accounts::from_iter([Account::placeholder_mainnet_carol()]),
+                Personas::default(),
+                AuthorizedDapps::default(),
+            )),
+            Err(CommonError::IdentifiableItemAlreadyPresent(format!(
+                "{:?}",
+                NetworkID::Mainnet
+            )))
+        );
+        assert_eq!(sut, ProfileNetworks::placeholder());
+    }
+
     #[test]
     fn update_account() {
         let mut sut = ProfileNetworks::placeholder();
         let id = &NetworkID::Mainnet;
         let account_address = Account::placeholder().address;
         assert_eq!(
-            sut.get(id)
+            sut.get_id(id)
                 .unwrap()
                 .accounts
-                .get(&account_address)
+                .get_id(&account_address)
                 .unwrap()
                 .display_name
                 .value,
@@ -168,10 +211,10 @@ mod tests {
         });
 
         assert_eq!(
-            sut.get(id)
+            sut.get_id(id)
                 .unwrap()
                 .accounts
-                .get(&account_address)
+                .get_id(&account_address)
                 .unwrap()
                 .display_name
                 .value,
@@ -184,7 +227,10 @@ mod tests {
         let mut sut = ProfileNetworks::placeholder();
         let id = &NetworkID::Mainnet;
         let account_address = Account::placeholder_nebunet().address;
-        assert_eq!(sut.get(id).unwrap().accounts.get(&account_address), None);
+        assert_eq!(
+            sut.get_id(id).unwrap().accounts.get_id(&account_address),
+            None
+        );
 
         assert!(sut
             .update_account(&account_address, |a| {
@@ -201,7 +247,10 @@ mod tests {
         let mut sut = ProfileNetworks::placeholder();
         let id = &NetworkID::Mainnet;
         let account_address = Account::placeholder_mainnet_carol().address;
-        assert_eq!(sut.get(id).unwrap().accounts.get(&account_address), None);
+        assert_eq!(
+            sut.get_id(id).unwrap().accounts.get_id(&account_address),
+            None
+        );
 
         assert!(sut
             .update_account(&account_address, |a| {
@@ -213,6 +262,42 @@ mod tests {
         assert_eq!(sut, ProfileNetworks::placeholder());
     }
 
+    #[test]
+    fn update_persona() {
+        let mut sut = ProfileNetworks::placeholder();
+        let address = Persona::placeholder_mainnet_satoshi().address;
+        assert_eq!(
+            sut.update_persona(&address, |p| {
+                p.display_name = DisplayName::new("Hal").unwrap()
+            })
+            .unwrap()
+            .display_name
+            .value,
+            "Hal"
+        );
+        assert_eq!(
+            sut.get_persona(&address).unwrap().display_name.value,
+            "Hal"
+        );
+    }
+
+    #[test]
+    fn update_persona_unknown_persona() {
+        let mut sut = ProfileNetworks::placeholder();
+        let address: IdentityAddress =
+            "identity_rdx12tgzjrz9u0xz4l28vf04hz87eguclmfaq4d2p8f8lv7zg9ssnzku8j"
+                .parse()
+                .unwrap();
+        assert!(sut
+            .update_persona(&address, |p| {
+                p.display_name = DisplayName::new("will fail").unwrap()
+            })
+            .is_none());
+
+        // Assert unchanged
+        assert_eq!(sut, ProfileNetworks::placeholder());
+    }
+
     #[test]
     fn with_network() {
         let network = ProfileNetwork::new(
@@ -228,7 +313,7 @@ mod tests {
     fn content_hint() {
         assert_eq!(
             ProfileNetworks::placeholder().content_hint(),
-            ContentHint::with_counters(4, 0, 2)
+            ContentHint::with_counters(4, 4, 2)
         );
     }
 
@@ -26,6 +26,11 @@ pub struct Profile {
     /// all the users Accounts, Personas and AuthorizedDapps the user
     /// has created and interacted with on this network.
     pub networks: ProfileNetworks,
+
+    /// Fields unknown to this version, written by a newer version of the
+    /// Profile format, re-emitted as-is when encoded.
+    #[serde(flatten)]
+    pub extra: UnknownFields,
 }
 
 #[uniffi::export]
@@ -46,6 +51,11 @@ pub fn new_profile_placeholder_other() -> Profile {
     Profile::placeholder_other()
 }
 
+#[uniffi::export]
+pub fn profile_to_pretty_json_stable(profile: &Profile) -> String {
+    profile.to_pretty_json_stable()
+}
+
 impl Profile {
     /// Creates a new Profile from the `PrivateHierarchicalDeterministicFactorSource`, without any
     /// networks (thus no accounts), with creating device info as "unknown".
@@ -79,6 +89,7 @@ impl Profile {
             factor_sources,
             app_preferences,
             networks,
+            extra: UnknownFields::default(),
         }
     }
 }
@@ -89,6 +100,14 @@ impl Profile {
         self.header.id.clone()
     }
 
+    /// The JSON of this Profile with all keys sorted and indented by two
+    /// spaces, so that snapshots can be diffed line by line, e.g. in bug
+    /// reports, see [`to_pretty_json_stable`].
+    pub fn to_pretty_json_stable(&self) -> String {
+        to_pretty_json_stable(self)
+            .expect("Should always be able to JSON serialize a Profile.")
+    }
+
     /// Returns a clone of the updated account if found, else None.
     pub fn update_account<F>(
         &mut self,
@@ -101,6 +120,18 @@ impl Profile {
         self.networks.update_account(address, mutate)
     }
 
+    /// Returns a clone of the updated persona if found, else None.
+    pub fn update_persona<F>(
+        &mut self,
+        address: &IdentityAddress,
+        mutate: F,
+    ) -> Option<Persona>
+    where
+        F: FnMut(&mut Persona),
+    {
+        self.networks.update_persona(address, mutate)
+    }
+
     pub fn update_factor_source<S, M>(
         &mut self,
         factor_source_id: &FactorSourceID,
@@ -149,6 +180,51 @@ impl HasPlaceholder for Profile {
     }
 }
 
+impl Profile {
+    /// A placeholder used to facilitate unit tests, `placeholder` with
+    /// an additional Olympia device factor source.
+    pub fn placeholder_olympia() -> Self {
+        Self::placeholder_with(
+            FactorSources::placeholder_olympia(),
+            ProfileNetworks::placeholder(),
+        )
+    }
+
+    /// A placeholder used to facilitate unit tests, `placeholder` with
+    /// an additional Ledger factor source of a distinct mnemonic.
+    pub fn placeholder_ledger() -> Self {
+        Self::placeholder_with(
+            FactorSources::placeholder_ledger(),
+            ProfileNetworks::placeholder(),
+        )
+    }
+
+    /// A placeholder used to facilitate unit tests, with an additional
+    /// Babylon device factor source and only the Stokenet network.
+    pub fn placeholder_stokenet() -> Self {
+        Self::placeholder_with(
+            FactorSources::placeholder_stokenet(),
+            ProfileNetworks::with_network(
+                ProfileNetwork::placeholder_stokenet(),
+            ),
+        )
+    }
+
+    fn placeholder_with(
+        factor_sources: FactorSources,
+        networks: ProfileNetworks,
+    ) -> Self {
+        let mut header = Header::placeholder();
+        header.content_hint = networks.content_hint();
+        Self::with(
+            header,
+            factor_sources,
+            AppPreferences::placeholder(),
+            networks,
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
@@ -164,6 +240,58 @@ mod tests {
         assert_eq!(Profile::placeholder_other(), Profile::placeholder_other());
     }
 
+    #[test]
+    fn placeholder_variants_are_deterministic_and_distinct() {
+        let variants = [
+            Profile::placeholder_olympia,
+            Profile::placeholder_ledger,
+            Profile::placeholder_stokenet,
+        ];
+        for variant in variants {
+            assert_eq!(variant(), variant());
+            assert_ne!(variant(), Profile::placeholder());
+        }
+        assert_eq!(
+            HashSet::<FactorSourceID>::from_iter(variants.iter().flat_map(
+                |v| {
+                    v().factor_sources
+                        .iter()
+                        .map(|f| f.factor_source_id())
+                        .collect_vec()
+                }
+            ))
+            .len(),
+            5
+        );
+    }
+
+    #[test]
+    fn placeholder_olympia_has_olympia_device_factor_source() {
+        assert!(Profile::placeholder_olympia()
+            .factor_sources
+            .contains_id(&FactorSource::placeholder_olympia().id()));
+    }
+
+    #[test]
+    fn placeholder_ledger_has_two_ledgers() {
+        assert_eq!(
+            Profile::placeholder_ledger()
+                .factor_sources
+                .iter()
+                .filter(|f| f.factor_source_kind()
+                    == FactorSourceKind::LedgerHQHardwareWallet)
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn placeholder_stokenet_only_has_stokenet() {
+        let sut = Profile::placeholder_stokenet();
+        assert_eq!(sut.networks.len(), 1);
+        assert!(sut.networks.contains_id(&NetworkID::Stokenet));
+    }
+
     #[test]
     fn update_factor_source_not_update_when_factor_source_not_found() {
         let mut sut = Profile::placeholder();
@@ -191,7 +319,7 @@ mod tests {
 
         assert_eq!(
             sut.factor_sources
-                .get(id)
+                .get_id(id)
                 .unwrap()
                 .as_device()
                 .unwrap()
@@ -221,7 +349,7 @@ mod tests {
 
         assert_eq!(
             sut.factor_sources
-                .get(id)
+                .get_id(id)
                 .unwrap()
                 .as_device()
                 .unwrap()
@@ -245,7 +373,7 @@ mod tests {
 
         assert_eq!(
             sut.factor_sources
-                .get(id)
+                .get_id(id)
                 .unwrap()
                 .as_device()
                 .unwrap()
@@ -274,7 +402,7 @@ mod tests {
         // Remains unchanged
         assert_eq!(
             sut.factor_sources
-                .get(id)
+                .get_id(id)
                 .unwrap()
                 .as_device()
                 .unwrap()
@@ -291,7 +419,7 @@ mod tests {
         let mut sut = Profile::placeholder();
         let account = sut
             .networks
-            .get(&NetworkID::Mainnet)
+            .get_id(&NetworkID::Mainnet)
             .unwrap()
             .accounts
             .get_at_index(0)
@@ -306,7 +434,7 @@ mod tests {
 
         assert_eq!(
             sut.networks
-                .get(&NetworkID::Mainnet)
+                .get_id(&NetworkID::Mainnet)
                 .unwrap()
                 .accounts
                 .get_at_index(0)
@@ -344,6 +472,100 @@ mod tests {
         assert_eq!(set.len(), n);
     }
 
+    /// JSON of the placeholder Profile with fields added by a hypothetical
+    /// newer version of the Profile format.
+    fn json_with_unknown_fields() -> serde_json::Value {
+        let mut json = serde_json::to_value(Profile::placeholder()).unwrap();
+        json["futureRoot"] = json!({ "nested": [1, 2, 3] });
+        json["header"]["futureHeader"] = json!("header");
+        json["appPreferences"]["futurePreferences"] = json!(true);
+        json["networks"][0]["accounts"][0]["futureAccount"] = json!(42);
+        json["networks"][0]["personas"][0]["futurePersona"] = json!(null);
+        json
+    }
+
+    #[test]
+    fn unknown_fields_are_preserved() {
+        let sut = serde_json::from_value::<Profile>(json_with_unknown_fields())
+            .unwrap();
+        assert_eq!(sut.extra.keys(), vec!["futureRoot"]);
+        assert_eq!(
+            sut.header.extra.get("futureHeader"),
+            Some(&json!("header"))
+        );
+        assert_eq!(
+            sut.app_preferences.extra.get("futurePreferences"),
+            Some(&json!(true))
+        );
+        let network = sut.networks.get_id(&NetworkID::Mainnet).unwrap();
+        assert_eq!(
+            network.accounts.items()[0].extra.get("futureAccount"),
+            Some(&json!(42))
+        );
+        assert_eq!(
+            network.personas.items()[0].extra.get("futurePersona"),
+            Some(&json!(null))
+        );
+        assert_ne!(sut, Profile::placeholder());
+    }
+
+    #[test]
+    fn unknown_fields_are_reemitted() {
+        let json = json_with_unknown_fields();
+        let sut = serde_json::from_value::<Profile>(json.clone()).unwrap();
+        assert_eq!(serde_json::to_value(sut).unwrap(), json);
+    }
+
+    #[test]
+    fn unknown_fields_survive_update() {
+        let mut sut =
+            serde_json::from_value::<Profile>(json_with_unknown_fields())
+                .unwrap();
+        let address = Account::placeholder_mainnet_alice().address;
+        sut.update_account(&address, |a| {
+            a.display_name = DisplayName::new("Renamed").unwrap()
+        })
+        .unwrap();
+        let json = serde_json::to_value(sut).unwrap();
+        let account = &json["networks"][0]["accounts"][0];
+        assert_eq!(account["displayName"], json!("Renamed"));
+        assert_eq!(account["futureAccount"], json!(42));
+        assert_eq!(json["futureRoot"], json!({ "nested": [1, 2, 3] }));
+    }
+
+    #[test]
+    fn to_pretty_json_stable_roundtrip() {
+        let sut = Profile::placeholder();
+        assert_eq!(
+            serde_json::from_str::<Profile>(&sut.to_pretty_json_stable())
+                .unwrap(),
+            sut
+        );
+    }
+
+    #[test]
+    fn to_pretty_json_stable_sorts_keys() {
+        let json = Profile::placeholder().to_pretty_json_stable();
+        assert!(json.starts_with("{\n  \"appPreferences\": {\n"));
+        assert!(json.ends_with("}\n"));
+        let app_preferences = json.find("\n  \"appPreferences\"").unwrap();
+        let factor_sources = json.find("\n  \"factorSources\"").unwrap();
+        let header = json.find("\n  \"header\"").unwrap();
+        let networks = json.find("\n  \"networks\"").unwrap();
+        assert!(app_preferences < factor_sources);
+        assert!(factor_sources < header);
+        assert!(header < networks);
+    }
+
+    #[test]
+    fn to_pretty_json_stable_keeps_unknown_fields() {
+        let sut = serde_json::from_value::<Profile>(json_with_unknown_fields())
+            .unwrap();
+        let json: serde_json::Value =
+            serde_json::from_str(&sut.to_pretty_json_stable()).unwrap();
+        assert_eq!(json["futureRoot"], json!({ "nested": [1, 2, 3] }));
+    }
+
     #[test]
     fn json_roundtrip() {
         let sut = Profile::placeholder();
@@ -367,7 +589,7 @@ mod tests {
 					"lastModified": "2023-09-11T16:05:56.000Z",
 					"contentHint": {
 						"numberOfAccountsOnAllNetworksInTotal": 4,
-						"numberOfPersonasOnAllNetworksInTotal": 0,
+						"numberOfPersonasOnAllNetworksInTotal": 4,
 						"numberOfNetworks": 2
 					}
 				},
@@ -1182,6 +1404,15 @@ mod uniffi_tests {
         );
     }
 
+    #[test]
+    fn to_pretty_json_stable() {
+        let sut = Profile::placeholder();
+        assert_eq!(
+            super::profile_to_pretty_json_stable(&sut),
+            sut.to_pretty_json_stable()
+        );
+    }
+
     #[test]
     fn new_private_hd() {
         let private =
@@ -0,0 +1,320 @@
+use crate::prelude::*;
+
+/// Fluently assembles valid Profiles with particular contents, e.g. hidden
+/// accounts or Ledger factor sources, for use in tests and QA tools instead
+/// of hand editing JSON fixtures.
+///
+/// All entities are derived from the mnemonic of the `DeviceFactorSource`
+/// the builder was created with, using the next free derivation index, and
+/// are created on the network last set with [`ProfileBuilder::on_network`],
+/// which defaults to `Mainnet`.
+///
+/// ```
+/// extern crate profile;
+/// use profile::prelude::*;
+///
+/// let profile = ProfileBuilder::new()
+///     .with_account("Alice")
+///     .with_hidden_account("Bob")
+///     .on_network(NetworkID::Stokenet)
+///     .with_persona("Satoshi")
+///     .with_ledger_factor()
+///     .build();
+///
+/// assert_eq!(profile.networks.len(), 2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct ProfileBuilder {
+    private_device_factor_source: PrivateHierarchicalDeterministicFactorSource,
+    network_id: NetworkID,
+    profile: Profile,
+}
+
+impl ProfileBuilder {
+    /// A builder of a Profile with the placeholder "Babylon"
+    /// `DeviceFactorSource` and no networks.
+    pub fn new() -> Self {
+        Self::with_private_device_factor_source(
+            PrivateHierarchicalDeterministicFactorSource::placeholder(),
+        )
+    }
+
+    /// A builder of a Profile with `private_device_factor_source` as its
+    /// "Babylon" `DeviceFactorSource` and no networks.
+    pub fn with_private_device_factor_source(
+        private_device_factor_source: PrivateHierarchicalDeterministicFactorSource,
+    ) -> Self {
+        let profile = Profile::new(
+            private_device_factor_source.clone(),
+            "ProfileBuilder",
+        );
+        Self {
+            private_device_factor_source,
+            network_id: NetworkID::Mainnet,
+            profile,
+        }
+    }
+
+    /// Entities added after this call are created on `network_id`.
+    pub fn on_network(mut self, network_id: NetworkID) -> Self {
+        self.network_id = network_id;
+        self
+    }
+
+    /// Adds `factor_source`, unless already present.
+    pub fn with_factor_source(
+        mut self,
+        factor_source: impl Into<FactorSource>,
+    ) -> Self {
+        self.profile.factor_sources.append(factor_source.into());
+        self
+    }
+
+    /// Adds the placeholder `LedgerHardwareWalletFactorSource`.
+    pub fn with_ledger_factor(self) -> Self {
+        self.with_factor_source(LedgerHardwareWalletFactorSource::placeholder())
+    }
+
+    /// Adds an account named `name` on the current network.
+    pub fn with_account(self, name: &str) -> Self {
+        self.with_account_flagged(name, [])
+    }
+
+    /// Adds an account named `name`, marked as deleted by the user, on the
+    /// current network.
+    pub fn with_hidden_account(self, name: &str) -> Self {
+        self.with_account_flagged(name, [EntityFlag::DeletedByUser])
+    }
+
+    /// Adds a persona named `name` on the current network.
+    pub fn with_persona(self, name: &str) -> Self {
+        self.with_persona_flagged(name, [])
+    }
+
+    /// Adds a persona named `name`, marked as deleted by the user, on the
+    /// current network.
+    pub fn with_hidden_persona(self, name: &str) -> Self {
+        self.with_persona_flagged(name, [EntityFlag::DeletedByUser])
+    }
+
+    /// Adds a connection to the dApp with `dapp_definition_address`, which
+    /// decides the network of the dApp, without any authorized personas.
+    pub fn with_dapp_connection(
+        mut self,
+        dapp_definition_address: DappDefinitionAddress,
+        name: &str,
+    ) -> Self {
+        let network_id = dapp_definition_address.network_id;
+        let dapp = AuthorizedDapp::new(
+            network_id,
+            dapp_definition_address,
+            name.to_owned(),
+            IdentifiedVecVia::new(),
+        );
+        self.update_network(network_id, |n| {
+            n.authorized_dapps.append(dapp.clone());
+        });
+        self
+    }
+
+    /// The built Profile, with its content hint updated.
+    pub fn build(self) -> Profile {
+        let mut profile = self.profile;
+        profile.header.content_hint = profile.networks.content_hint();
+        profile
+    }
+}
+
+impl ProfileBuilder {
+    fn update_network<F>(&mut self, network_id: NetworkID, mut mutate: F)
+    where
+        F: FnMut(&mut ProfileNetwork),
+    {
+        let networks = &mut self.profile.networks;
+        if !networks.contains_id(&network_id) {
+            networks.append(ProfileNetwork::new(
+                network_id,
+                Accounts::default(),
+                Personas::default(),
+                AuthorizedDapps::default(),
+            ));
+        }
+        networks.update_with(&network_id, |n| mutate(n));
+    }
+
+    fn with_account_flagged<I>(mut self, name: &str, flags: I) -> Self
+    where
+        I: IntoIterator<Item = EntityFlag>,
+    {
+        let network_id = self.network_id;
+        let index = self
+            .profile
+            .next_derivation_index_for_entity(EntityKind::Accounts, network_id);
+        let number_of_accounts_on_network = self
+            .profile
+            .networks
+            .get_id(&network_id)
+            .map(|n| n.accounts.len())
+            .unwrap_or(0);
+        let mut account = Account::new(
+            self.private_device_factor_source
+                .derive_entity_creation_factor_instance(network_id, index),
+            DisplayName::new(name).expect("Valid display name"),
+            AppearanceID::from_number_of_accounts_on_network(
+                number_of_accounts_on_network,
+            ),
+        );
+        account.flags = EntityFlags::with_flags(flags);
+        self.update_network(network_id, |n| {
+            n.accounts.append(account.clone());
+        });
+        self.profile.record_used_derivation_index(
+            EntityKind::Accounts,
+            network_id,
+            &account.security_state,
+        );
+        self
+    }
+
+    fn with_persona_flagged<I>(mut self, name: &str, flags: I) -> Self
+    where
+        I: IntoIterator<Item = EntityFlag>,
+    {
+        let network_id = self.network_id;
+        let index = self
+            .profile
+            .next_derivation_index_for_entity(EntityKind::Persona, network_id);
+        let mut persona = Persona::new_with_auth(
+            self.private_device_factor_source
+                .derive_identity_creation_with_auth_factor_instances(
+                    network_id, index,
+                ),
+            DisplayName::new(name).expect("Valid display name"),
+            None,
+        );
+        persona.flags = EntityFlags::with_flags(flags);
+        self.update_network(network_id, |n| {
+            n.personas.append(persona.clone());
+        });
+        self.profile.record_used_derivation_index(
+            EntityKind::Persona,
+            network_id,
+            &persona.security_state,
+        );
+        self
+    }
+}
+
+impl Default for ProfileBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn empty() {
+        let profile = ProfileBuilder::new().build();
+        assert!(profile.networks.is_empty());
+        assert_eq!(
+            profile.bdfs().id,
+            PrivateHierarchicalDeterministicFactorSource::placeholder()
+                .factor_source
+                .id
+        );
+    }
+
+    #[test]
+    fn accounts_use_next_index() {
+        let profile = ProfileBuilder::new()
+            .with_account("Alice")
+            .with_account("Bob")
+            .build();
+        let accounts = &profile.networks[0].accounts;
+        assert_eq!(accounts[0].address, Account::placeholder_alice().address);
+        assert_eq!(accounts[1].address, Account::placeholder_bob().address);
+        assert_eq!(accounts[1].appearance_id, AppearanceID::gradient1());
+        assert_eq!(
+            profile
+                .header
+                .content_hint
+                .number_of_accounts_on_all_networks_in_total,
+            2
+        );
+    }
+
+    #[test]
+    fn hidden_account() {
+        let profile = ProfileBuilder::new().with_hidden_account("Bob").build();
+        assert!(profile.networks[0].accounts[0]
+            .flags
+            .contains_id(&EntityFlag::DeletedByUser));
+    }
+
+    #[test]
+    fn personas() {
+        let profile = ProfileBuilder::new()
+            .with_persona("Satoshi")
+            .with_hidden_persona("Batman")
+            .build();
+        let personas = &profile.networks[0].personas;
+        assert_eq!(
+            personas[0].address,
+            Persona::placeholder_mainnet_satoshi().address
+        );
+        assert!(personas[1].flags.contains_id(&EntityFlag::DeletedByUser));
+    }
+
+    #[test]
+    fn on_network() {
+        let profile = ProfileBuilder::new()
+            .with_account("Alice")
+            .on_network(NetworkID::Stokenet)
+            .with_account("Nadia")
+            .build();
+        assert_eq!(profile.networks.len(), 2);
+        let stokenet = profile.networks.get_id(&NetworkID::Stokenet).unwrap();
+        assert_eq!(stokenet.accounts[0].network_id, NetworkID::Stokenet);
+    }
+
+    #[test]
+    fn ledger_factor() {
+        let profile = ProfileBuilder::new().with_ledger_factor().build();
+        assert_eq!(profile.factor_sources.len(), 2);
+        assert!(profile.factor_sources.contains_id(
+            &LedgerHardwareWalletFactorSource::placeholder().id.into()
+        ));
+    }
+
+    #[test]
+    fn dapp_connection() {
+        let profile = ProfileBuilder::new()
+            .with_dapp_connection(AccountAddress::placeholder(), "Dashboard")
+            .build();
+        let dapp = &profile.networks[0].authorized_dapps[0];
+        assert_eq!(dapp.dapp_definition_address, AccountAddress::placeholder());
+        assert_eq!(dapp.display_name, Some("Dashboard".to_owned()));
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        let sut = ProfileBuilder::new()
+            .with_account("Alice")
+            .with_hidden_account("Bob")
+            .with_persona("Satoshi")
+            .with_ledger_factor()
+            .on_network(NetworkID::Stokenet)
+            .with_account("Nadia")
+            .build();
+        assert_eq!(
+            serde_json::from_str::<Profile>(
+                &serde_json::to_string(&sut).unwrap()
+            )
+            .unwrap(),
+            sut
+        );
+    }
+}
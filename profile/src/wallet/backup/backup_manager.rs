@@ -0,0 +1,494 @@
+use crate::prelude::*;
+
+/// Coordinates automatic backups of the Profile to the cloud: every change
+/// of the Profile schedules a backup, which is performed once the Profile
+/// has not changed for `debounce_seconds`, so that a burst of changes
+/// results in a single backup.
+#[derive(Debug, Default)]
+pub(crate) struct BackupManager {
+    driver: Option<Arc<dyn CloudBackupDriver>>,
+    debounce_seconds: u64,
+    pending_since: Option<Timestamp>,
+}
+
+impl BackupManager {
+    /// Starts using `driver` for backups, any pending backup is kept.
+    pub(crate) fn enable(
+        &mut self,
+        driver: Arc<dyn CloudBackupDriver>,
+        debounce_seconds: u64,
+    ) {
+        self.driver = Some(driver);
+        self.debounce_seconds = debounce_seconds;
+    }
+
+    /// Stops automatic backups, pending changes are still tracked so that a
+    /// backup is performed if automatic backups are enabled again.
+    pub(crate) fn disable(&mut self) {
+        self.driver = None;
+    }
+
+    /// The driver, if automatic backups are enabled.
+    pub(crate) fn driver(&self) -> Option<Arc<dyn CloudBackupDriver>> {
+        self.driver.clone()
+    }
+
+    /// Schedules a backup, postponing any already scheduled backup.
+    pub(crate) fn profile_changed(&mut self, at: Timestamp) {
+        self.pending_since = Some(at);
+    }
+
+    /// The driver to perform the pending backup with and the time of the
+    /// change it backs up, if automatic backups are enabled and the Profile
+    /// has not changed for `debounce_seconds` as of `now`.
+    pub(crate) fn due_backup(
+        &self,
+        now: &Timestamp,
+    ) -> Option<(Arc<dyn CloudBackupDriver>, Timestamp)> {
+        let driver = self.driver.clone()?;
+        let since = self.pending_since?;
+        if now.seconds_since(&since) < self.debounce_seconds as i64 {
+            return None;
+        }
+        Some((driver, since))
+    }
+
+    /// Clears the pending backup unless the Profile changed again after
+    /// `changed_at`, i.e. while the backup was performed.
+    pub(crate) fn did_backup(&mut self, changed_at: Option<Timestamp>) {
+        if self.pending_since == changed_at {
+            self.pending_since = None;
+        }
+    }
+}
+
+//========
+// Cloud Backup
+//========
+#[uniffi::export]
+impl Wallet {
+    /// Enables automatic backups of the Profile to the cloud using `driver`,
+    /// a backup is performed by [`Wallet::perform_pending_cloud_backup`] once
+    /// the Profile has not changed for `debounce_seconds`.
+    ///
+    /// If the Profile has changed since the most recent backup a backup is
+    /// scheduled immediately.
+    pub fn enable_automatic_cloud_backups(
+        &self,
+        driver: Arc<dyn CloudBackupDriver>,
+        debounce_seconds: u64,
+    ) -> Result<()> {
+        self.wallet_client_storage.ensure_not_deleted()?;
+        let is_stale = self.is_backup_stale()?;
+        self.update_backup_manager_with(|manager| {
+            manager.enable(driver.clone(), debounce_seconds);
+            if is_stale {
+                manager.profile_changed(Timestamp::now());
+            }
+        });
+        Ok(())
+    }
+
+    /// Disables automatic backups of the Profile to the cloud.
+    pub fn disable_automatic_cloud_backups(&self) {
+        self.update_backup_manager_with(|manager| manager.disable())
+    }
+
+    /// Performs the scheduled backup if automatic backups are enabled and the
+    /// Profile has not changed during the debounce window, should be called
+    /// periodically by the Wallet Client, e.g. every second.
+    ///
+    /// Returns `true` if a backup was successfully performed. Emits
+    /// [`WalletEvent::CloudBackupCompleted`] or
+    /// [`WalletEvent::CloudBackupFailed`], if the backup fails it is retried
+    /// on the next call.
+    pub fn perform_pending_cloud_backup(&self) -> bool {
+        let Some((driver, changed_at)) = self
+            .backup_manager
+            .read()
+            .expect("Should be able to acquire read lock for backup manager")
+            .due_backup(&Timestamp::now())
+        else {
+            return false;
+        };
+        self.backup_profile_to_cloud_with(driver, Some(changed_at))
+            .is_ok()
+    }
+
+    /// Immediately backs up the Profile to the cloud, regardless of any
+    /// debounce window, emitting the same events as
+    /// [`Wallet::perform_pending_cloud_backup`].
+    ///
+    /// Returns `Err` if automatic backups are not enabled or if the backup
+    /// fails.
+    pub fn backup_profile_to_cloud_now(&self) -> Result<()> {
+        let (driver, pending_since) = {
+            let manager = self.backup_manager.read().expect(
+                "Should be able to acquire read lock for backup manager",
+            );
+            (manager.driver(), manager.pending_since)
+        };
+        let driver = driver.ok_or(CommonError::CloudBackupsNotEnabled)?;
+        self.backup_profile_to_cloud_with(driver, pending_since)
+    }
+
+    /// All Profiles backed up in the cloud, e.g. to let the user choose one
+    /// to restore, snapshots which cannot be decoded are skipped.
+    ///
+    /// Returns `Err` if automatic backups are not enabled or if the snapshots
+    /// cannot be loaded.
+    pub fn cloud_backed_up_profiles(&self) -> Result<Vec<Profile>> {
+        let driver = self.cloud_backup_driver()?;
+        Ok(driver
+            .load_profile_snapshots()?
+            .into_iter()
+            .filter_map(|snapshot| {
+                serde_json::from_slice::<Profile>(snapshot.bytes())
+                    .inspect_err(|e| {
+                        log::warn!("Skipping undecodable cloud backup: {}", e)
+                    })
+                    .ok()
+            })
+            .collect())
+    }
+
+    /// Sets whether the user consents to backing up the Profile to the cloud
+    /// unencrypted while it is encrypted with a hardware key on this device.
+    ///
+    /// Cloud backups are always uploaded as plaintext JSON, since a hardware
+    /// key never leaves this device, so a snapshot encrypted with it could
+    /// not be restored on any other device. Without consent, backups of a
+    /// hardware key encrypted Profile fail with
+    /// [`CommonError::CloudBackupRequiresConsentToUploadUnencrypted`].
+    ///
+    /// The consent is persisted in the [`BackupState`].
+    pub fn set_consent_to_unencrypted_cloud_backup(
+        &self,
+        consent: bool,
+    ) -> Result<()> {
+        self.update_backup_state_with(|state, _| {
+            state.consented_to_upload_unencrypted = consent
+        })
+    }
+
+    /// Deletes the cloud backup of the Profile, if any.
+    ///
+    /// Returns `Err` if automatic backups are not enabled or if deletion
+    /// fails.
+    pub fn delete_cloud_backup(&self) -> Result<()> {
+        self.cloud_backup_driver()?
+            .delete_profile_snapshot(self.profile().id())
+    }
+}
+
+impl Wallet {
+    /// Schedules a backup, called whenever the Profile has been saved.
+    pub(crate) fn schedule_cloud_backup(&self) {
+        self.update_backup_manager_with(|manager| {
+            manager.profile_changed(Timestamp::now())
+        })
+    }
+
+    fn cloud_backup_driver(&self) -> Result<Arc<dyn CloudBackupDriver>> {
+        self.backup_manager
+            .read()
+            .expect("Should be able to acquire read lock for backup manager")
+            .driver()
+            .ok_or(CommonError::CloudBackupsNotEnabled)
+    }
+
+    fn update_backup_manager_with<F>(&self, mutate: F)
+    where
+        F: FnOnce(&mut BackupManager),
+    {
+        mutate(
+            &mut self.backup_manager.write().expect(
+                "Should be able to acquire write lock for backup manager",
+            ),
+        )
+    }
+
+    /// Fails if the Profile is encrypted with a hardware key on this device
+    /// and the user has not consented to uploading it unencrypted.
+    fn check_consent_to_upload_unencrypted(&self) -> Result<()> {
+        let consented = self.backup_state()?.consented_to_upload_unencrypted;
+        if self.is_profile_encrypted_with_hardware_key() && !consented {
+            return Err(
+                CommonError::CloudBackupRequiresConsentToUploadUnencrypted,
+            );
+        }
+        Ok(())
+    }
+
+    fn backup_profile_to_cloud_with(
+        &self,
+        driver: Arc<dyn CloudBackupDriver>,
+        changed_at: Option<Timestamp>,
+    ) -> Result<()> {
+        self.wallet_client_storage.ensure_not_deleted()?;
+        let profile = self.profile();
+        let profile_id = profile.id();
+
+        let result =
+            self.check_consent_to_upload_unencrypted().and_then(|_| {
+                let snapshot = serde_json::to_vec(&profile).expect(
+                    "Should always be able to JSON serialize a Profile.",
+                );
+                driver
+                    .save_profile_snapshot(profile_id.clone(), snapshot.into())
+                    .and_then(|_| {
+                        self.did_backup_profile_to_cloud(
+                            profile.canonical_hash(),
+                        )
+                    })
+            });
+
+        match result {
+            Ok(_) => {
+                self.update_backup_manager_with(|manager| {
+                    manager.did_backup(changed_at)
+                });
+                self.emit_event(WalletEvent::CloudBackupCompleted {
+                    profile_id,
+                });
+                Ok(())
+            }
+            Err(error) => {
+                log::error!("Failed to backup Profile to cloud: {}", error);
+                self.emit_event(WalletEvent::CloudBackupFailed {
+                    profile_id,
+                    error: error.to_string(),
+                });
+                Err(error)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn timestamp(seconds: u64) -> Timestamp {
+        Timestamp::parse(&format!(
+            "2023-09-11T16:{:02}:{:02}.000Z",
+            seconds / 60,
+            seconds % 60
+        ))
+        .unwrap()
+    }
+
+    fn make_wallet() -> (
+        Wallet,
+        Arc<MockCloudBackupDriver>,
+        Arc<RecordingEventObserver>,
+    ) {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let driver = Arc::new(MockCloudBackupDriver::default());
+        let observer = Arc::new(RecordingEventObserver::default());
+        wallet.set_event_observer(observer.clone());
+        with_time_provider(MockTimeProvider(timestamp(0)), || {
+            wallet
+                .enable_automatic_cloud_backups(driver.clone(), 5)
+                .unwrap()
+        });
+        (wallet, driver, observer)
+    }
+
+    fn perform_at(wallet: &Wallet, seconds: u64) -> bool {
+        with_time_provider(MockTimeProvider(timestamp(seconds)), || {
+            wallet.perform_pending_cloud_backup()
+        })
+    }
+
+    fn rename_first_account_at(wallet: &Wallet, seconds: u64, name: &str) {
+        let address = wallet.profile().networks.items()[0].accounts.items()[0]
+            .address
+            .clone();
+        with_time_provider(MockTimeProvider(timestamp(seconds)), || {
+            wallet
+                .rename_entity(address.into(), DisplayName::new(name).unwrap())
+                .unwrap()
+        });
+    }
+
+    #[test]
+    fn manager_without_driver_has_no_due_backup() {
+        let mut sut = BackupManager::default();
+        sut.profile_changed(timestamp(0));
+        assert!(sut.due_backup(&timestamp(100)).is_none());
+    }
+
+    #[test]
+    fn manager_due_after_debounce() {
+        let mut sut = BackupManager::default();
+        sut.enable(Arc::new(MockCloudBackupDriver::default()), 5);
+        assert!(sut.due_backup(&timestamp(100)).is_none());
+        sut.profile_changed(timestamp(10));
+        assert!(sut.due_backup(&timestamp(14)).is_none());
+        assert_eq!(sut.due_backup(&timestamp(15)).unwrap().1, timestamp(10));
+    }
+
+    #[test]
+    fn manager_did_backup_keeps_newer_change() {
+        let mut sut = BackupManager::default();
+        sut.profile_changed(timestamp(10));
+        sut.did_backup(Some(timestamp(5)));
+        assert_eq!(sut.pending_since, Some(timestamp(10)));
+        sut.did_backup(Some(timestamp(10)));
+        assert_eq!(sut.pending_since, None);
+    }
+
+    #[test]
+    fn stale_profile_is_backed_up_after_debounce() {
+        let (sut, driver, observer) = make_wallet();
+        assert!(!perform_at(&sut, 4));
+        assert!(driver.snapshots.read().unwrap().is_empty());
+
+        assert!(perform_at(&sut, 5));
+        let profile = sut.profile();
+        let snapshot = driver.snapshots.read().unwrap()[&profile.id()].clone();
+        assert_eq!(
+            serde_json::from_slice::<Profile>(snapshot.bytes()).unwrap(),
+            profile
+        );
+        assert_eq!(sut.is_backup_stale(), Ok(false));
+        assert_eq!(
+            observer.events.read().unwrap().clone(),
+            vec![WalletEvent::CloudBackupCompleted {
+                profile_id: profile.id()
+            }]
+        );
+
+        // Nothing pending anymore
+        assert!(!perform_at(&sut, 100));
+    }
+
+    #[test]
+    fn changes_postpone_backup() {
+        let (sut, driver, _) = make_wallet();
+        rename_first_account_at(&sut, 3, "Foo");
+        assert!(!perform_at(&sut, 5));
+        rename_first_account_at(&sut, 6, "Bar");
+        assert!(!perform_at(&sut, 10));
+        assert!(perform_at(&sut, 11));
+        assert_eq!(driver.snapshots.read().unwrap().len(), 1);
+        assert_eq!(sut.is_backup_stale(), Ok(false));
+    }
+
+    #[test]
+    fn failed_backup_emits_event_and_is_retried() {
+        let (sut, driver, observer) = make_wallet();
+        *driver.fail.write().unwrap() = true;
+        assert!(!perform_at(&sut, 5));
+        assert_eq!(
+            observer.events.read().unwrap().clone(),
+            vec![WalletEvent::CloudBackupFailed {
+                profile_id: sut.profile().id(),
+                error: CommonError::Unknown.to_string()
+            }]
+        );
+        assert_eq!(sut.is_backup_stale(), Ok(true));
+
+        *driver.fail.write().unwrap() = false;
+        assert!(perform_at(&sut, 6));
+        assert_eq!(sut.is_backup_stale(), Ok(false));
+    }
+
+    #[test]
+    fn disabled_backups_are_not_performed() {
+        let (sut, driver, _) = make_wallet();
+        sut.disable_automatic_cloud_backups();
+        assert!(!perform_at(&sut, 100));
+        assert!(driver.snapshots.read().unwrap().is_empty());
+        assert_eq!(
+            sut.backup_profile_to_cloud_now(),
+            Err(CommonError::CloudBackupsNotEnabled)
+        );
+        assert_eq!(
+            sut.cloud_backed_up_profiles(),
+            Err(CommonError::CloudBackupsNotEnabled)
+        );
+    }
+
+    #[test]
+    fn backup_now_ignores_debounce() {
+        let (sut, driver, _) = make_wallet();
+        with_time_provider(MockTimeProvider(timestamp(1)), || {
+            sut.backup_profile_to_cloud_now().unwrap()
+        });
+        assert_eq!(driver.snapshots.read().unwrap().len(), 1);
+        assert!(!perform_at(&sut, 100));
+    }
+
+    #[test]
+    fn hardware_key_encrypted_profile_requires_consent() {
+        let (sut, driver, observer) = make_wallet();
+        sut.wallet_client_storage
+            .set_hardware_key_alias(Some("alias".to_owned()));
+
+        assert!(!perform_at(&sut, 5));
+        assert!(driver.snapshots.read().unwrap().is_empty());
+        assert_eq!(
+            observer.events.read().unwrap().clone(),
+            vec![WalletEvent::CloudBackupFailed {
+                profile_id: sut.profile().id(),
+                error:
+                    CommonError::CloudBackupRequiresConsentToUploadUnencrypted
+                        .to_string()
+            }]
+        );
+        assert_eq!(sut.is_backup_stale(), Ok(true));
+
+        sut.set_consent_to_unencrypted_cloud_backup(true).unwrap();
+        assert!(perform_at(&sut, 6));
+        let profile = sut.profile();
+        let snapshot = driver.snapshots.read().unwrap()[&profile.id()].clone();
+        assert_eq!(
+            serde_json::from_slice::<Profile>(snapshot.bytes()).unwrap(),
+            profile
+        );
+    }
+
+    #[test]
+    fn withdrawn_consent_stops_backups_of_encrypted_profile() {
+        let (sut, driver, _) = make_wallet();
+        sut.wallet_client_storage
+            .set_hardware_key_alias(Some("alias".to_owned()));
+        sut.set_consent_to_unencrypted_cloud_backup(true).unwrap();
+        sut.set_consent_to_unencrypted_cloud_backup(false).unwrap();
+        assert_eq!(
+            sut.backup_profile_to_cloud_now(),
+            Err(CommonError::CloudBackupRequiresConsentToUploadUnencrypted)
+        );
+        assert!(driver.snapshots.read().unwrap().is_empty());
+    }
+
+    #[test]
+    fn consent_is_persisted() {
+        let (sut, storage) = Wallet::ephemeral(Profile::placeholder());
+        sut.set_consent_to_unencrypted_cloud_backup(true).unwrap();
+        let reloaded = Wallet::by_loading_profile(storage).unwrap();
+        assert!(
+            reloaded
+                .backup_state()
+                .unwrap()
+                .consented_to_upload_unencrypted
+        );
+    }
+
+    #[test]
+    fn load_and_delete_cloud_backups() {
+        let (sut, driver, _) = make_wallet();
+        driver
+            .snapshots
+            .write()
+            .unwrap()
+            .insert(ProfileID::placeholder_other(), vec![0xde, 0xad].into());
+        assert!(perform_at(&sut, 5));
+        assert_eq!(sut.cloud_backed_up_profiles(), Ok(vec![sut.profile()]));
+
+        sut.delete_cloud_backup().unwrap();
+        assert_eq!(sut.cloud_backed_up_profiles(), Ok(Vec::new()));
+    }
+}
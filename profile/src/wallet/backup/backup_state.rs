@@ -0,0 +1,218 @@
+use crate::prelude::*;
+
+/// Metadata about the most recent backups of the Profile, persisted in
+/// SecureStorage outside of the Profile, so that making a backup does not
+/// itself modify the Profile.
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupState {
+    /// When the Profile was last backed up to the cloud, if ever.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_cloud_backup: Option<Timestamp>,
+
+    /// When the Profile was last exported to a file by the user, if ever.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_manual_export: Option<Timestamp>,
+
+    /// The [`Profile::canonical_hash`] of the Profile at the most recent
+    /// backup, cloud or manual.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile_hash_at_backup: Option<Hex32Bytes>,
+
+    /// `true` if the user consents to backing up the Profile to the cloud
+    /// unencrypted while it is encrypted with a hardware key on this device.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub consented_to_upload_unencrypted: bool,
+}
+
+impl BackupState {
+    /// `true` if `profile` has never been backed up or has changed since the
+    /// most recent backup.
+    pub fn is_stale_for(&self, profile: &Profile) -> bool {
+        self.profile_hash_at_backup != Some(profile.canonical_hash())
+    }
+
+    /// Records a backup to the cloud of the Profile with the canonical hash
+    /// `profile_hash`, i.e. the Profile uploaded, which might differ from
+    /// the current one if it changed during the upload.
+    pub(crate) fn did_backup_to_cloud(
+        &mut self,
+        profile_hash: Hex32Bytes,
+        at: Timestamp,
+    ) {
+        self.last_cloud_backup = Some(at);
+        self.profile_hash_at_backup = Some(profile_hash);
+    }
+
+    pub(crate) fn did_export_manually(
+        &mut self,
+        profile: &Profile,
+        at: Timestamp,
+    ) {
+        self.last_manual_export = Some(at);
+        self.profile_hash_at_backup = Some(profile.canonical_hash());
+    }
+}
+
+impl Profile {
+    /// The hash of the JSON serialization of this Profile, which is stable
+    /// since it contains no unordered collections, used to detect if the
+    /// Profile has changed since it was backed up.
+    pub fn canonical_hash(&self) -> Hex32Bytes {
+        let json = serde_json::to_vec(self)
+            .expect("Should always be able to JSON serialize a Profile.");
+        hash(json).into()
+    }
+}
+
+impl HasPlaceholder for BackupState {
+    fn placeholder() -> Self {
+        let mut state = Self::default();
+        state.did_backup_to_cloud(
+            Profile::placeholder().canonical_hash(),
+            Timestamp::parse("2023-09-11T16:05:56.000Z").unwrap(),
+        );
+        state
+    }
+
+    fn placeholder_other() -> Self {
+        let mut state = Self::default();
+        state.did_export_manually(
+            &Profile::placeholder_other(),
+            Timestamp::parse("2023-12-24T17:13:56.123Z").unwrap(),
+        );
+        state
+    }
+}
+
+#[uniffi::export]
+pub fn profile_canonical_hash(profile: &Profile) -> Hex32Bytes {
+    profile.canonical_hash()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality() {
+        assert_eq!(BackupState::placeholder(), BackupState::placeholder());
+        assert_eq!(
+            BackupState::placeholder_other(),
+            BackupState::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            BackupState::placeholder(),
+            BackupState::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn canonical_hash_is_stable() {
+        assert_eq!(
+            Profile::placeholder().canonical_hash(),
+            Profile::placeholder().canonical_hash()
+        );
+        let json = serde_json::to_string(&Profile::placeholder()).unwrap();
+        let roundtripped: Profile = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            roundtripped.canonical_hash(),
+            Profile::placeholder().canonical_hash()
+        );
+    }
+
+    #[test]
+    fn canonical_hash_changes_with_profile() {
+        assert_ne!(
+            Profile::placeholder().canonical_hash(),
+            Profile::placeholder_other().canonical_hash()
+        );
+    }
+
+    #[test]
+    fn never_backed_up_is_stale() {
+        assert!(BackupState::default().is_stale_for(&Profile::placeholder()));
+    }
+
+    #[test]
+    fn backed_up_is_not_stale() {
+        assert!(
+            !BackupState::placeholder().is_stale_for(&Profile::placeholder())
+        );
+        assert!(!BackupState::placeholder_other()
+            .is_stale_for(&Profile::placeholder_other()));
+    }
+
+    #[test]
+    fn modified_after_backup_is_stale() {
+        let mut profile = Profile::placeholder();
+        let sut = BackupState::placeholder();
+        profile.app_preferences.security.is_developer_mode_enabled =
+            !profile.app_preferences.security.is_developer_mode_enabled;
+        assert!(sut.is_stale_for(&profile));
+    }
+
+    #[test]
+    fn manual_export_keeps_last_cloud_backup() {
+        let mut sut = BackupState::placeholder();
+        let at = Timestamp::parse("2024-01-01T00:00:00.000Z").unwrap();
+        sut.did_export_manually(&Profile::placeholder(), at);
+        assert_eq!(sut.last_manual_export, Some(at));
+        assert_eq!(
+            sut.last_cloud_backup,
+            BackupState::placeholder().last_cloud_backup
+        );
+    }
+
+    #[test]
+    fn json_roundtrip_consent() {
+        let sut = BackupState {
+            consented_to_upload_unencrypted: true,
+            ..Default::default()
+        };
+        assert_eq_after_json_roundtrip(
+            &sut,
+            r#"{"consentedToUploadUnencrypted":true}"#,
+        );
+    }
+
+    #[test]
+    fn json_roundtrip_default() {
+        assert_eq_after_json_roundtrip(&BackupState::default(), "{}");
+    }
+
+    #[test]
+    fn json_roundtrip_placeholder() {
+        let sut = BackupState::placeholder();
+        let json = serde_json::to_string(&sut).unwrap();
+        assert!(
+            json.contains("\"lastCloudBackup\":\"2023-09-11T16:05:56.000Z\"")
+        );
+        assert_eq!(serde_json::from_str::<BackupState>(&json).unwrap(), sut);
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn canonical_hash() {
+        let sut = Profile::placeholder();
+        assert_eq!(profile_canonical_hash(&sut), sut.canonical_hash());
+    }
+}
@@ -0,0 +1,71 @@
+use crate::prelude::*;
+
+/// Implemented by the Wallet Client to store Profile snapshots in the cloud,
+/// e.g. using iCloud or Google Drive, separately from `SecureStorage` since
+/// backups need not be kept in the keychain.
+///
+/// At most one snapshot per Profile is kept, saving a snapshot replaces any
+/// previous snapshot of the Profile with the same ID.
+///
+/// Snapshots are plaintext JSON, even if the Profile is encrypted with a
+/// hardware key on this device, in which case the user must have consented,
+/// see [`Wallet::set_consent_to_unencrypted_cloud_backup`].
+#[uniffi::export]
+pub trait CloudBackupDriver: Send + Sync + std::fmt::Debug {
+    /// Saves the JSON `snapshot` of the Profile with `profile_id`.
+    fn save_profile_snapshot(
+        &self,
+        profile_id: ProfileID,
+        snapshot: BagOfBytes,
+    ) -> Result<()>;
+
+    /// Loads the JSON snapshots of all Profiles backed up in the cloud.
+    fn load_profile_snapshots(&self) -> Result<Vec<BagOfBytes>>;
+
+    /// Deletes the snapshot of the Profile with `profile_id`, if any.
+    fn delete_profile_snapshot(&self, profile_id: ProfileID) -> Result<()>;
+}
+
+/// An in-memory [`CloudBackupDriver`] which can be made to fail, used to
+/// facilitate unit tests.
+#[cfg(test)]
+#[derive(Debug, Default)]
+pub(crate) struct MockCloudBackupDriver {
+    pub(crate) snapshots: std::sync::RwLock<HashMap<ProfileID, BagOfBytes>>,
+    pub(crate) fail: std::sync::RwLock<bool>,
+}
+
+#[cfg(test)]
+impl MockCloudBackupDriver {
+    fn fail_if_needed(&self) -> Result<()> {
+        if *self.fail.read().unwrap() {
+            Err(CommonError::Unknown)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+impl CloudBackupDriver for MockCloudBackupDriver {
+    fn save_profile_snapshot(
+        &self,
+        profile_id: ProfileID,
+        snapshot: BagOfBytes,
+    ) -> Result<()> {
+        self.fail_if_needed()?;
+        self.snapshots.write().unwrap().insert(profile_id, snapshot);
+        Ok(())
+    }
+
+    fn load_profile_snapshots(&self) -> Result<Vec<BagOfBytes>> {
+        self.fail_if_needed()?;
+        Ok(self.snapshots.read().unwrap().values().cloned().collect())
+    }
+
+    fn delete_profile_snapshot(&self, profile_id: ProfileID) -> Result<()> {
+        self.fail_if_needed()?;
+        self.snapshots.write().unwrap().remove(&profile_id);
+        Ok(())
+    }
+}
@@ -0,0 +1,9 @@
+mod backup_manager;
+mod backup_state;
+mod cloud_backup_driver;
+mod wallet_backup;
+
+pub use backup_manager::*;
+pub use backup_state::*;
+pub use cloud_backup_driver::*;
+pub use wallet_backup::*;
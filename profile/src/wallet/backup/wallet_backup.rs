@@ -0,0 +1,130 @@
+use crate::prelude::*;
+
+//========
+// Backup
+//========
+#[uniffi::export]
+impl Wallet {
+    /// The persisted metadata about the most recent backups of the Profile.
+    pub fn backup_state(&self) -> Result<BackupState> {
+        self.wallet_client_storage
+            .load::<BackupState>(SecureStorageKey::BackupState)
+            .map(|o| o.unwrap_or_default())
+    }
+
+    /// `true` if the Profile has never been backed up, or if it has changed
+    /// since the most recent backup, cloud or manual.
+    pub fn is_backup_stale(&self) -> Result<bool> {
+        let backup_state = self.backup_state()?;
+        Ok(self.access_profile_with(|p| backup_state.is_stale_for(&p)))
+    }
+
+    /// Saves that the Profile with the canonical hash `profile_hash` - see
+    /// [`profile_canonical_hash`] - was just backed up to the cloud.
+    ///
+    /// Takes the hash of the uploaded Profile rather than using the current
+    /// one, since the Profile might have changed during the upload, in which
+    /// case the backup must remain stale.
+    pub fn did_backup_profile_to_cloud(
+        &self,
+        profile_hash: Hex32Bytes,
+    ) -> Result<()> {
+        self.update_backup_state_with(|state, _| {
+            state.did_backup_to_cloud(profile_hash, Timestamp::now())
+        })
+    }
+
+    /// Saves that the current Profile was just exported to a file by the
+    /// user.
+    pub fn did_export_profile_manually(&self) -> Result<()> {
+        self.update_backup_state_with(|state, profile| {
+            state.did_export_manually(profile, Timestamp::now())
+        })
+    }
+}
+
+impl Wallet {
+    pub(crate) fn update_backup_state_with<F>(&self, mutate: F) -> Result<()>
+    where
+        F: FnOnce(&mut BackupState, &Profile),
+    {
+        let mut state = self.backup_state()?;
+        mutate(&mut state, &self.profile());
+        self.wallet_client_storage
+            .save(SecureStorageKey::BackupState, &state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn new_wallet_backup_is_stale() {
+        let sut = Wallet::placeholder();
+        assert_eq!(sut.backup_state(), Ok(BackupState::default()));
+        assert_eq!(sut.is_backup_stale(), Ok(true));
+    }
+
+    #[test]
+    fn backup_to_cloud_is_not_stale() {
+        let sut = Wallet::placeholder();
+        sut.did_backup_profile_to_cloud(sut.profile().canonical_hash())
+            .unwrap();
+        assert_eq!(sut.is_backup_stale(), Ok(false));
+        let state = sut.backup_state().unwrap();
+        assert!(state.last_cloud_backup.is_some());
+        assert!(state.last_manual_export.is_none());
+    }
+
+    #[test]
+    fn manual_export_is_not_stale() {
+        let sut = Wallet::placeholder();
+        sut.did_export_profile_manually().unwrap();
+        assert_eq!(sut.is_backup_stale(), Ok(false));
+        let state = sut.backup_state().unwrap();
+        assert!(state.last_cloud_backup.is_none());
+        assert!(state.last_manual_export.is_some());
+    }
+
+    #[test]
+    fn stale_after_profile_changed() {
+        let sut = Wallet::placeholder();
+        sut.did_backup_profile_to_cloud(sut.profile().canonical_hash())
+            .unwrap();
+        let account = sut.profile().networks.items()[0].accounts.items()[0]
+            .address
+            .clone();
+        sut.rename_entity(account.into(), DisplayName::new("Renamed").unwrap())
+            .unwrap();
+        assert_eq!(sut.is_backup_stale(), Ok(true));
+    }
+
+    #[test]
+    fn backup_of_outdated_profile_is_stale() {
+        let sut = Wallet::placeholder();
+        let uploaded = sut.profile().canonical_hash();
+        let account = sut.profile().networks.items()[0].accounts.items()[0]
+            .address
+            .clone();
+        sut.rename_entity(account.into(), DisplayName::new("Renamed").unwrap())
+            .unwrap();
+        sut.did_backup_profile_to_cloud(uploaded.clone()).unwrap();
+        assert_eq!(sut.is_backup_stale(), Ok(true));
+        assert_eq!(
+            sut.backup_state().unwrap().profile_hash_at_backup,
+            Some(uploaded)
+        );
+    }
+
+    #[test]
+    fn backup_state_is_persisted() {
+        let (sut, storage) = Wallet::ephemeral(Profile::placeholder());
+        sut.did_backup_profile_to_cloud(sut.profile().canonical_hash())
+            .unwrap();
+        assert!(storage
+            .load_data(SecureStorageKey::BackupState)
+            .unwrap()
+            .is_some());
+    }
+}
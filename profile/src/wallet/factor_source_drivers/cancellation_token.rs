@@ -0,0 +1,140 @@
+use crate::prelude::*;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// How long an interactive operation - e.g. signing with a Ledger - may run
+/// before it is aborted.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, uniffi::Enum)]
+pub enum OperationTimeoutPolicy {
+    /// The operation runs until it completes or is cancelled.
+    #[default]
+    NoTimeout,
+
+    /// The operation is aborted once `seconds` have elapsed since it started.
+    TimeoutAfter { seconds: u64 },
+}
+
+/// Passed into interactive operations - deriving keys or signing, which may
+/// involve the user and hardware - so that the Wallet Client can abort them
+/// by calling [`CancellationToken::cancel`], e.g. when the user dismisses a
+/// "Connect your Ledger" sheet.
+///
+/// Cancellation is cooperative, the token is checked between every step of
+/// an operation, e.g. before and after asking each factor source driver, any
+/// result produced after the token was cancelled is discarded.
+#[derive(Debug, uniffi::Object)]
+pub struct CancellationToken {
+    is_cancelled: AtomicBool,
+    started_at: Timestamp,
+    timeout_policy: OperationTimeoutPolicy,
+}
+
+#[uniffi::export]
+impl CancellationToken {
+    /// A token for an operation starting now, timing out according to
+    /// `timeout_policy`.
+    #[uniffi::constructor]
+    pub fn new(timeout_policy: OperationTimeoutPolicy) -> Self {
+        Self {
+            is_cancelled: AtomicBool::new(false),
+            started_at: Timestamp::now(),
+            timeout_policy,
+        }
+    }
+
+    /// Cancels the operation, which fails with `OperationCancelled` at its
+    /// next check of the token.
+    pub fn cancel(&self) {
+        self.is_cancelled.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.is_cancelled.load(Ordering::SeqCst)
+    }
+}
+
+impl CancellationToken {
+    /// `Err` if the operation was cancelled or has timed out, checked by
+    /// operations between each of their steps.
+    pub(crate) fn check(&self) -> Result<()> {
+        if self.is_cancelled() {
+            return Err(CommonError::OperationCancelled);
+        }
+        if let OperationTimeoutPolicy::TimeoutAfter { seconds } =
+            self.timeout_policy
+        {
+            if Timestamp::now().seconds_since(&self.started_at) > seconds as i64
+            {
+                return Err(CommonError::OperationTimedOut);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn timestamp(seconds: u64) -> Timestamp {
+        Timestamp::parse(format!(
+            "2023-09-11T16:{:02}:{:02}.000Z",
+            seconds / 60,
+            seconds % 60
+        ))
+        .unwrap()
+    }
+
+    fn token_started_at(
+        seconds: u64,
+        timeout_policy: OperationTimeoutPolicy,
+    ) -> CancellationToken {
+        with_time_provider(MockTimeProvider(timestamp(seconds)), || {
+            CancellationToken::new(timeout_policy)
+        })
+    }
+
+    fn check_at(sut: &CancellationToken, seconds: u64) -> Result<()> {
+        with_time_provider(MockTimeProvider(timestamp(seconds)), || sut.check())
+    }
+
+    #[test]
+    fn not_cancelled() {
+        let sut = CancellationToken::new(OperationTimeoutPolicy::default());
+        assert!(!sut.is_cancelled());
+        assert_eq!(sut.check(), Ok(()));
+    }
+
+    #[test]
+    fn cancelled() {
+        let sut = CancellationToken::new(OperationTimeoutPolicy::default());
+        sut.cancel();
+        assert!(sut.is_cancelled());
+        assert_eq!(sut.check(), Err(CommonError::OperationCancelled));
+    }
+
+    #[test]
+    fn no_timeout() {
+        let sut = token_started_at(0, OperationTimeoutPolicy::NoTimeout);
+        assert_eq!(check_at(&sut, 3000), Ok(()));
+    }
+
+    #[test]
+    fn timeout() {
+        let sut = token_started_at(
+            0,
+            OperationTimeoutPolicy::TimeoutAfter { seconds: 60 },
+        );
+        assert_eq!(check_at(&sut, 60), Ok(()));
+        assert_eq!(check_at(&sut, 61), Err(CommonError::OperationTimedOut));
+    }
+
+    #[test]
+    fn cancelled_takes_precedence_over_timeout() {
+        let sut = token_started_at(
+            0,
+            OperationTimeoutPolicy::TimeoutAfter { seconds: 60 },
+        );
+        sut.cancel();
+        assert_eq!(check_at(&sut, 61), Err(CommonError::OperationCancelled));
+    }
+}
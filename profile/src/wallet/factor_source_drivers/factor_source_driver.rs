@@ -0,0 +1,90 @@
+use crate::prelude::*;
+
+/// Implemented by the Wallet Client to derive public keys and sign with a
+/// certain [`FactorSourceKind`], e.g. a driver for `device` factor sources
+/// reading mnemonics from the keychain and one for `ledgerHQHardwareWallet`
+/// communicating with the Ledger through the Connector Extension.
+///
+/// Drivers are registered per kind with
+/// [`Wallet::register_factor_source_driver`], the Wallet dispatches every
+/// derivation and signing request to the driver of the kind of the factor
+/// source used, so that new kinds of factor sources can be supported without
+/// changing the Wallet.
+#[uniffi::export]
+pub trait FactorSourceDriver: Send + Sync + std::fmt::Debug {
+    /// Derives the public keys at `derivation_paths` using the factor source
+    /// with `factor_source_id`, for `purpose`, which the Wallet Client can
+    /// display to the user. The keys must be returned in the same order as
+    /// the paths.
+    fn derive_public_keys(
+        &self,
+        factor_source_id: FactorSourceIDFromHash,
+        derivation_paths: Vec<DerivationPath>,
+        purpose: DerivationPurpose,
+    ) -> Result<Vec<HierarchicalDeterministicPublicKey>>;
+
+    /// Signs `hash` with the keys at `derivation_paths` of the factor source
+    /// with `factor_source_id`, returning one signature per path, in the same
+    /// order as the paths.
+    fn sign(
+        &self,
+        factor_source_id: FactorSourceIDFromHash,
+        hash: Hex32Bytes,
+        derivation_paths: Vec<DerivationPath>,
+    ) -> Result<Vec<SignatureWithPublicKey>>;
+}
+
+/// A [`FactorSourceDriver`] deriving keys and signing with a mnemonic kept in
+/// memory, recording every purpose it derives for, used to facilitate unit
+/// tests.
+#[cfg(test)]
+#[derive(Debug)]
+pub(crate) struct MockFactorSourceDriver {
+    pub(crate) mnemonic_with_passphrase: MnemonicWithPassphrase,
+    pub(crate) purposes: std::sync::RwLock<Vec<DerivationPurpose>>,
+}
+
+#[cfg(test)]
+impl MockFactorSourceDriver {
+    pub(crate) fn new(
+        mnemonic_with_passphrase: MnemonicWithPassphrase,
+    ) -> Self {
+        Self {
+            mnemonic_with_passphrase,
+            purposes: std::sync::RwLock::new(Vec::new()),
+        }
+    }
+}
+
+#[cfg(test)]
+impl FactorSourceDriver for MockFactorSourceDriver {
+    fn derive_public_keys(
+        &self,
+        _factor_source_id: FactorSourceIDFromHash,
+        derivation_paths: Vec<DerivationPath>,
+        purpose: DerivationPurpose,
+    ) -> Result<Vec<HierarchicalDeterministicPublicKey>> {
+        self.purposes.write().unwrap().push(purpose);
+        Ok(self
+            .mnemonic_with_passphrase
+            .derive_private_keys(derivation_paths)
+            .into_iter()
+            .map(|k| k.public_key())
+            .collect_vec())
+    }
+
+    fn sign(
+        &self,
+        _factor_source_id: FactorSourceIDFromHash,
+        hash: Hex32Bytes,
+        derivation_paths: Vec<DerivationPath>,
+    ) -> Result<Vec<SignatureWithPublicKey>> {
+        let hash = radix_engine_common::crypto::Hash(hash.bytes());
+        Ok(self
+            .mnemonic_with_passphrase
+            .derive_private_keys(derivation_paths)
+            .into_iter()
+            .map(|k| k.private_key.sign(&hash))
+            .collect_vec())
+    }
+}
@@ -0,0 +1,7 @@
+mod cancellation_token;
+mod factor_source_driver;
+mod wallet_factor_source_drivers;
+
+pub use cancellation_token::*;
+pub use factor_source_driver::*;
+pub use wallet_factor_source_drivers::*;
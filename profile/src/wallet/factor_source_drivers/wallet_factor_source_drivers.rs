@@ -0,0 +1,454 @@
+use crate::prelude::*;
+
+//========
+// Factor Source Drivers
+//========
+#[uniffi::export]
+impl Wallet {
+    /// Registers `driver` to be used for all factor sources of `kind`,
+    /// replacing any driver previously registered for `kind`.
+    pub fn register_factor_source_driver(
+        &self,
+        kind: FactorSourceKind,
+        driver: Arc<dyn FactorSourceDriver>,
+    ) {
+        self.factor_source_drivers
+            .write()
+            .expect("Should be able to acquire write lock for drivers")
+            .insert(kind, driver);
+    }
+
+    /// Removes the driver registered for `kind`, if any.
+    pub fn unregister_factor_source_driver(&self, kind: FactorSourceKind) {
+        self.factor_source_drivers
+            .write()
+            .expect("Should be able to acquire write lock for drivers")
+            .remove(&kind);
+    }
+
+    /// `true` if a driver is registered for factor sources of `kind`.
+    pub fn has_factor_source_driver(&self, kind: FactorSourceKind) -> bool {
+        self.factor_source_drivers
+            .read()
+            .expect("Should be able to acquire read lock for drivers")
+            .contains_key(&kind)
+    }
+}
+
+impl Wallet {
+    /// The driver registered for factor sources of `kind`.
+    pub(crate) fn factor_source_driver(
+        &self,
+        kind: FactorSourceKind,
+    ) -> Result<Arc<dyn FactorSourceDriver>> {
+        self.factor_source_drivers
+            .read()
+            .expect("Should be able to acquire read lock for drivers")
+            .get(&kind)
+            .cloned()
+            .ok_or(CommonError::NoFactorSourceDriverRegistered(kind))
+    }
+
+    /// Derives the public keys at `derivation_paths` using the driver
+    /// registered for the kind of the factor source with `factor_source_id`,
+    /// verifying that the driver derived exactly the requested paths.
+    ///
+    /// Fails if `cancellation_token` is cancelled or times out before the
+    /// driver is asked or before it responds.
+    pub(crate) fn derive_public_keys_using_driver(
+        &self,
+        factor_source_id: &FactorSourceIDFromHash,
+        derivation_paths: Vec<DerivationPath>,
+        purpose: DerivationPurpose,
+        cancellation_token: &CancellationToken,
+    ) -> Result<Vec<HierarchicalDeterministicPublicKey>> {
+        let kind = factor_source_id.kind;
+        let driver = self.factor_source_driver(kind)?;
+        cancellation_token.check()?;
+        let keys = driver.derive_public_keys(
+            factor_source_id.clone(),
+            derivation_paths.clone(),
+            purpose,
+        )?;
+        cancellation_token.check()?;
+        if keys
+            .iter()
+            .map(|k| &k.derivation_path)
+            .ne(derivation_paths.iter())
+        {
+            return Err(CommonError::FactorSourceDriverInvalidResponse(kind));
+        }
+        Ok(keys)
+    }
+
+    /// Signs `hash` with the keys at `derivation_paths` using the driver
+    /// registered for the kind of the factor source with `factor_source_id`,
+    /// verifying that the driver returned one valid signature per path.
+    ///
+    /// Fails if `cancellation_token` is cancelled or times out before the
+    /// driver is asked or before it responds.
+    pub(crate) fn sign_using_driver(
+        &self,
+        factor_source_id: &FactorSourceIDFromHash,
+        hash: &Hex32Bytes,
+        derivation_paths: Vec<DerivationPath>,
+        cancellation_token: &CancellationToken,
+    ) -> Result<Vec<SignatureWithPublicKey>> {
+        let kind = factor_source_id.kind;
+        let driver = self.factor_source_driver(kind)?;
+        cancellation_token.check()?;
+        let signatures = driver.sign(
+            factor_source_id.clone(),
+            hash.clone(),
+            derivation_paths.clone(),
+        )?;
+        cancellation_token.check()?;
+        let engine_hash = radix_engine_common::crypto::Hash(hash.bytes());
+        if signatures.len() != derivation_paths.len()
+            || !signatures.iter().all(|s| s.is_valid_for(&engine_hash))
+        {
+            return Err(CommonError::FactorSourceDriverInvalidResponse(kind));
+        }
+        Ok(signatures)
+    }
+
+    /// Signs `hash` with the factor sources in `requests` one at a time,
+    /// each with the keys at its derivation paths, stopping at the first
+    /// factor source failing or once `cancellation_token` is cancelled or
+    /// times out.
+    pub(crate) fn sign_using_drivers(
+        &self,
+        requests: Vec<(FactorSourceIDFromHash, Vec<DerivationPath>)>,
+        hash: &Hex32Bytes,
+        cancellation_token: &CancellationToken,
+    ) -> Result<Vec<SignatureWithPublicKey>> {
+        let mut signatures = Vec::new();
+        for (factor_source_id, derivation_paths) in requests {
+            signatures.extend(self.sign_using_driver(
+                &factor_source_id,
+                hash,
+                derivation_paths,
+                cancellation_token,
+            )?);
+        }
+        Ok(signatures)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn paths() -> Vec<DerivationPath> {
+        vec![
+            AccountPath::placeholder().into(),
+            IdentityPath::placeholder().into(),
+        ]
+    }
+
+    fn token() -> CancellationToken {
+        CancellationToken::new(OperationTimeoutPolicy::NoTimeout)
+    }
+
+    fn driver() -> Arc<MockFactorSourceDriver> {
+        Arc::new(MockFactorSourceDriver::new(
+            MnemonicWithPassphrase::placeholder(),
+        ))
+    }
+
+    /// A driver deriving keys at other paths than requested and signing
+    /// other hashes than requested.
+    #[derive(Debug)]
+    struct WrongDriver;
+
+    impl FactorSourceDriver for WrongDriver {
+        fn derive_public_keys(
+            &self,
+            _factor_source_id: FactorSourceIDFromHash,
+            _derivation_paths: Vec<DerivationPath>,
+            _purpose: DerivationPurpose,
+        ) -> Result<Vec<HierarchicalDeterministicPublicKey>> {
+            Ok(vec![HierarchicalDeterministicPublicKey::placeholder()])
+        }
+
+        fn sign(
+            &self,
+            _factor_source_id: FactorSourceIDFromHash,
+            _hash: Hex32Bytes,
+            derivation_paths: Vec<DerivationPath>,
+        ) -> Result<Vec<SignatureWithPublicKey>> {
+            Ok(derivation_paths
+                .iter()
+                .map(|_| SignatureWithPublicKey::placeholder_other())
+                .collect_vec())
+        }
+    }
+
+    /// A driver cancelling `token` while it is signing, as if the user
+    /// dismissed the signing prompt.
+    #[derive(Debug)]
+    struct CancellingDriver {
+        token: Arc<CancellationToken>,
+        sign_count: std::sync::RwLock<usize>,
+    }
+
+    impl FactorSourceDriver for CancellingDriver {
+        fn derive_public_keys(
+            &self,
+            factor_source_id: FactorSourceIDFromHash,
+            derivation_paths: Vec<DerivationPath>,
+            purpose: DerivationPurpose,
+        ) -> Result<Vec<HierarchicalDeterministicPublicKey>> {
+            self.token.cancel();
+            driver().derive_public_keys(
+                factor_source_id,
+                derivation_paths,
+                purpose,
+            )
+        }
+
+        fn sign(
+            &self,
+            factor_source_id: FactorSourceIDFromHash,
+            hash: Hex32Bytes,
+            derivation_paths: Vec<DerivationPath>,
+        ) -> Result<Vec<SignatureWithPublicKey>> {
+            *self.sign_count.write().unwrap() += 1;
+            self.token.cancel();
+            driver().sign(factor_source_id, hash, derivation_paths)
+        }
+    }
+
+    #[test]
+    fn no_driver_registered() {
+        let sut = Wallet::placeholder();
+        assert!(!sut.has_factor_source_driver(FactorSourceKind::Device));
+        assert_eq!(
+            sut.derive_public_keys_using_driver(
+                &FactorSourceIDFromHash::placeholder(),
+                paths(),
+                DerivationPurpose::CreatingNewAccount,
+                &token()
+            ),
+            Err(CommonError::NoFactorSourceDriverRegistered(
+                FactorSourceKind::Device
+            ))
+        );
+    }
+
+    #[test]
+    fn register_and_unregister() {
+        let sut = Wallet::placeholder();
+        sut.register_factor_source_driver(FactorSourceKind::Device, driver());
+        assert!(sut.has_factor_source_driver(FactorSourceKind::Device));
+        assert!(!sut.has_factor_source_driver(
+            FactorSourceKind::LedgerHQHardwareWallet
+        ));
+        sut.unregister_factor_source_driver(FactorSourceKind::Device);
+        assert!(!sut.has_factor_source_driver(FactorSourceKind::Device));
+    }
+
+    #[test]
+    fn derive_dispatches_by_kind() {
+        let sut = Wallet::placeholder();
+        let device = driver();
+        let ledger = driver();
+        sut.register_factor_source_driver(
+            FactorSourceKind::Device,
+            device.clone(),
+        );
+        sut.register_factor_source_driver(
+            FactorSourceKind::LedgerHQHardwareWallet,
+            ledger.clone(),
+        );
+        let keys = sut
+            .derive_public_keys_using_driver(
+                &FactorSourceIDFromHash::placeholder(),
+                paths(),
+                DerivationPurpose::RecoveringAccounts,
+                &token(),
+            )
+            .unwrap();
+        assert_eq!(
+            keys.into_iter().map(|k| k.derivation_path).collect_vec(),
+            paths()
+        );
+        assert_eq!(
+            *device.purposes.read().unwrap(),
+            vec![DerivationPurpose::RecoveringAccounts]
+        );
+        assert!(ledger.purposes.read().unwrap().is_empty());
+    }
+
+    #[test]
+    fn sign_using_driver() {
+        let sut = Wallet::placeholder();
+        sut.register_factor_source_driver(FactorSourceKind::Device, driver());
+        let hash = Hex32Bytes::placeholder();
+        let signatures = sut
+            .sign_using_driver(
+                &FactorSourceIDFromHash::placeholder(),
+                &hash,
+                paths(),
+                &token(),
+            )
+            .unwrap();
+        assert_eq!(signatures.len(), 2);
+    }
+
+    #[test]
+    fn invalid_derivation_response() {
+        let sut = Wallet::placeholder();
+        sut.register_factor_source_driver(
+            FactorSourceKind::Device,
+            Arc::new(WrongDriver),
+        );
+        assert_eq!(
+            sut.derive_public_keys_using_driver(
+                &FactorSourceIDFromHash::placeholder(),
+                paths(),
+                DerivationPurpose::CreatingNewAccount,
+                &token()
+            ),
+            Err(CommonError::FactorSourceDriverInvalidResponse(
+                FactorSourceKind::Device
+            ))
+        );
+    }
+
+    #[test]
+    fn invalid_signing_response() {
+        let sut = Wallet::placeholder();
+        sut.register_factor_source_driver(
+            FactorSourceKind::Device,
+            Arc::new(WrongDriver),
+        );
+        assert_eq!(
+            sut.sign_using_driver(
+                &FactorSourceIDFromHash::placeholder(),
+                &Hex32Bytes::placeholder(),
+                paths(),
+                &token(),
+            ),
+            Err(CommonError::FactorSourceDriverInvalidResponse(
+                FactorSourceKind::Device
+            ))
+        );
+    }
+
+    #[test]
+    fn cancelled_before_driver_is_asked() {
+        let sut = Wallet::placeholder();
+        let device = driver();
+        sut.register_factor_source_driver(
+            FactorSourceKind::Device,
+            device.clone(),
+        );
+        let token = token();
+        token.cancel();
+        assert_eq!(
+            sut.derive_public_keys_using_driver(
+                &FactorSourceIDFromHash::placeholder(),
+                paths(),
+                DerivationPurpose::CreatingNewAccount,
+                &token
+            ),
+            Err(CommonError::OperationCancelled)
+        );
+        assert!(device.purposes.read().unwrap().is_empty());
+    }
+
+    #[test]
+    fn cancelled_while_deriving_discards_keys() {
+        let sut = Wallet::placeholder();
+        let token = Arc::new(token());
+        sut.register_factor_source_driver(
+            FactorSourceKind::Device,
+            Arc::new(CancellingDriver {
+                token: token.clone(),
+                sign_count: std::sync::RwLock::new(0),
+            }),
+        );
+        assert_eq!(
+            sut.derive_public_keys_using_driver(
+                &FactorSourceIDFromHash::placeholder(),
+                paths(),
+                DerivationPurpose::CreatingNewAccount,
+                &token
+            ),
+            Err(CommonError::OperationCancelled)
+        );
+    }
+
+    #[test]
+    fn sign_using_drivers() {
+        let sut = Wallet::placeholder();
+        sut.register_factor_source_driver(FactorSourceKind::Device, driver());
+        sut.register_factor_source_driver(
+            FactorSourceKind::LedgerHQHardwareWallet,
+            driver(),
+        );
+        let signatures = sut
+            .sign_using_drivers(
+                vec![
+                    (FactorSourceIDFromHash::placeholder_device(), paths()),
+                    (FactorSourceIDFromHash::placeholder_ledger(), paths()),
+                ],
+                &Hex32Bytes::placeholder(),
+                &token(),
+            )
+            .unwrap();
+        assert_eq!(signatures.len(), 4);
+    }
+
+    #[test]
+    fn sign_using_drivers_stops_once_cancelled() {
+        let sut = Wallet::placeholder();
+        let token = Arc::new(token());
+        let cancelling = Arc::new(CancellingDriver {
+            token: token.clone(),
+            sign_count: std::sync::RwLock::new(0),
+        });
+        sut.register_factor_source_driver(
+            FactorSourceKind::Device,
+            cancelling.clone(),
+        );
+        sut.register_factor_source_driver(
+            FactorSourceKind::LedgerHQHardwareWallet,
+            cancelling.clone(),
+        );
+        assert_eq!(
+            sut.sign_using_drivers(
+                vec![
+                    (FactorSourceIDFromHash::placeholder_device(), paths()),
+                    (FactorSourceIDFromHash::placeholder_ledger(), paths()),
+                ],
+                &Hex32Bytes::placeholder(),
+                &token,
+            ),
+            Err(CommonError::OperationCancelled)
+        );
+        assert_eq!(*cancelling.sign_count.read().unwrap(), 1);
+    }
+
+    #[test]
+    fn timed_out() {
+        let sut = Wallet::placeholder();
+        sut.register_factor_source_driver(FactorSourceKind::Device, driver());
+        let token =
+            with_time_provider(MockTimeProvider(Timestamp::UNIX_EPOCH), || {
+                CancellationToken::new(OperationTimeoutPolicy::TimeoutAfter {
+                    seconds: 60,
+                })
+            });
+        assert_eq!(
+            sut.sign_using_driver(
+                &FactorSourceIDFromHash::placeholder(),
+                &Hex32Bytes::placeholder(),
+                paths(),
+                &token,
+            ),
+            Err(CommonError::OperationTimedOut)
+        );
+    }
+}
@@ -0,0 +1,209 @@
+use crate::prelude::*;
+
+/// Data fetched from the Gateway during a session, cached by the [`Wallet`]
+/// so that Wallet Clients do not each need their own caching layer.
+///
+/// Each entry expires after the TTL it was cached with and is invalidated
+/// by [`WalletEvent`]s making it stale, e.g. a committed transaction
+/// changing the holdings of an Account.
+#[derive(Debug, Default)]
+pub(crate) struct GatewayCache {
+    entries: HashMap<GatewayCacheKey, GatewayCacheEntry>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct GatewayCacheEntry {
+    data: BagOfBytes,
+    cached_at: Timestamp,
+    ttl_seconds: u64,
+}
+
+impl GatewayCacheEntry {
+    fn is_expired(&self, now: &Timestamp) -> bool {
+        !(0..self.ttl_seconds as i64)
+            .contains(&now.seconds_since(&self.cached_at))
+    }
+}
+
+impl GatewayCache {
+    /// The data cached for `key`, if it has not expired as of `now`.
+    pub(crate) fn get(
+        &self,
+        key: &GatewayCacheKey,
+        now: &Timestamp,
+    ) -> Option<BagOfBytes> {
+        self.entries
+            .get(key)
+            .filter(|e| !e.is_expired(now))
+            .map(|e| e.data.clone())
+    }
+
+    /// Caches `data` for `key` for `ttl_seconds`, evicting all expired
+    /// entries.
+    pub(crate) fn insert(
+        &mut self,
+        key: GatewayCacheKey,
+        data: BagOfBytes,
+        ttl_seconds: u64,
+        now: Timestamp,
+    ) {
+        self.entries.retain(|_, e| !e.is_expired(&now));
+        self.entries.insert(
+            key,
+            GatewayCacheEntry {
+                data,
+                cached_at: now,
+                ttl_seconds,
+            },
+        );
+    }
+
+    /// Removes all entries within `scope`.
+    pub(crate) fn invalidate(&mut self, scope: &GatewayCacheScope) {
+        self.entries.retain(|key, _| !scope.contains(key));
+    }
+
+    /// Removes the entries made stale by `event`, if any.
+    pub(crate) fn invalidate_for_event(&mut self, event: &WalletEvent) {
+        match event {
+            WalletEvent::TransactionCommitted { affected_accounts } => {
+                affected_accounts.iter().for_each(|a| {
+                    self.invalidate(&GatewayCacheScope::account(a))
+                })
+            }
+            WalletEvent::NetworkRemoved { network_id } => {
+                self.invalidate(&GatewayCacheScope::Network {
+                    network_id: *network_id,
+                })
+            }
+            WalletEvent::CurrentGatewayChanged { .. }
+            | WalletEvent::ProfileRecoveredFromLastKnownGood { .. }
+            | WalletEvent::WalletDeleted { .. } => {
+                self.invalidate(&GatewayCacheScope::All)
+            }
+            WalletEvent::EntityRenamed { .. }
+            | WalletEvent::EntityFlagChanged { .. }
+            | WalletEvent::AccountsReordered { .. }
+            | WalletEvent::AppPreferencesChanged { .. }
+            | WalletEvent::NetworkAdded { .. }
+            | WalletEvent::CloudBackupCompleted { .. }
+            | WalletEvent::CloudBackupFailed { .. } => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn timestamp(seconds: u64) -> Timestamp {
+        Timestamp::parse(format!(
+            "2023-09-11T16:{:02}:{:02}.000Z",
+            seconds / 60,
+            seconds % 60
+        ))
+        .unwrap()
+    }
+
+    fn data() -> BagOfBytes {
+        BagOfBytes::from(vec![0xde, 0xad])
+    }
+
+    fn make_sut() -> GatewayCache {
+        let mut sut = GatewayCache::default();
+        sut.insert(GatewayCacheKey::placeholder(), data(), 60, timestamp(0));
+        sut.insert(
+            GatewayCacheKey::placeholder_other(),
+            data(),
+            60,
+            timestamp(0),
+        );
+        sut
+    }
+
+    #[test]
+    fn get_cached() {
+        let sut = make_sut();
+        assert_eq!(
+            sut.get(&GatewayCacheKey::placeholder(), &timestamp(59)),
+            Some(data())
+        );
+    }
+
+    #[test]
+    fn get_expired() {
+        let sut = make_sut();
+        assert_eq!(
+            sut.get(&GatewayCacheKey::placeholder(), &timestamp(60)),
+            None
+        );
+    }
+
+    #[test]
+    fn insert_evicts_expired() {
+        let mut sut = make_sut();
+        sut.insert(
+            GatewayCacheKey::new(
+                NetworkID::Mainnet,
+                "resource",
+                GatewayDataKind::ResourceDetails,
+            ),
+            data(),
+            60,
+            timestamp(60),
+        );
+        assert_eq!(sut.entries.len(), 1);
+    }
+
+    #[test]
+    fn invalidate_scope() {
+        let mut sut = make_sut();
+        sut.invalidate(&GatewayCacheScope::Network {
+            network_id: NetworkID::Mainnet,
+        });
+        assert_eq!(
+            sut.get(&GatewayCacheKey::placeholder(), &timestamp(0)),
+            None
+        );
+        assert_eq!(
+            sut.get(&GatewayCacheKey::placeholder_other(), &timestamp(0)),
+            Some(data())
+        );
+    }
+
+    #[test]
+    fn transaction_committed_invalidates_affected_accounts() {
+        let mut sut = make_sut();
+        sut.invalidate_for_event(&WalletEvent::TransactionCommitted {
+            affected_accounts: vec![AccountAddress::placeholder_mainnet()],
+        });
+        assert_eq!(
+            sut.get(&GatewayCacheKey::placeholder(), &timestamp(0)),
+            None
+        );
+        assert_eq!(
+            sut.get(&GatewayCacheKey::placeholder_other(), &timestamp(0)),
+            Some(data())
+        );
+    }
+
+    #[test]
+    fn gateway_changed_invalidates_all() {
+        let mut sut = make_sut();
+        sut.invalidate_for_event(&WalletEvent::CurrentGatewayChanged {
+            to: Gateway::stokenet(),
+        });
+        assert!(sut.entries.is_empty());
+    }
+
+    #[test]
+    fn rename_invalidates_nothing() {
+        let mut sut = make_sut();
+        sut.invalidate_for_event(&WalletEvent::EntityRenamed {
+            address: AddressOfAccountOrPersona::placeholder(),
+            old_name: DisplayName::new("Alice").unwrap(),
+            new_name: DisplayName::new("Stella").unwrap(),
+        });
+        assert_eq!(sut.entries.len(), 2);
+    }
+}
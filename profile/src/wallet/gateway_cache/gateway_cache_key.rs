@@ -0,0 +1,153 @@
+use crate::prelude::*;
+
+/// The kind of data fetched from the Gateway about an entity.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, uniffi::Enum)]
+pub enum GatewayDataKind {
+    /// The fungible and non-fungible resources an Account holds.
+    AccountHoldings,
+
+    /// The metadata of an entity, e.g. its name and icon.
+    EntityMetadata,
+
+    /// The details of a resource, e.g. its total supply.
+    ResourceDetails,
+
+    /// The committed transactions affecting an entity.
+    TransactionHistory,
+}
+
+/// Identifies data cached by the [`Wallet`]: the `kind` of data about the
+/// entity with the Bech32 address `entity_address` on `network_id`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct GatewayCacheKey {
+    pub network_id: NetworkID,
+    pub entity_address: String,
+    pub kind: GatewayDataKind,
+}
+
+impl GatewayCacheKey {
+    pub fn new(
+        network_id: NetworkID,
+        entity_address: impl AsRef<str>,
+        kind: GatewayDataKind,
+    ) -> Self {
+        Self {
+            network_id,
+            entity_address: entity_address.as_ref().to_owned(),
+            kind,
+        }
+    }
+}
+
+impl HasPlaceholder for GatewayCacheKey {
+    fn placeholder() -> Self {
+        let address = AccountAddress::placeholder_mainnet();
+        Self::new(
+            address.network_id,
+            address.address,
+            GatewayDataKind::AccountHoldings,
+        )
+    }
+
+    fn placeholder_other() -> Self {
+        let address = AccountAddress::placeholder_stokenet();
+        Self::new(
+            address.network_id,
+            address.address,
+            GatewayDataKind::TransactionHistory,
+        )
+    }
+}
+
+/// Which cached Gateway data to invalidate.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Enum)]
+pub enum GatewayCacheScope {
+    /// All cached data.
+    All,
+
+    /// All data about entities on `network_id`.
+    Network { network_id: NetworkID },
+
+    /// All data about the entity with `entity_address` on `network_id`.
+    Entity {
+        network_id: NetworkID,
+        entity_address: String,
+    },
+
+    /// All data of `kind`, on any network.
+    Kind { kind: GatewayDataKind },
+}
+
+impl GatewayCacheScope {
+    /// The scope of all data about the Account with `address`.
+    pub fn account(address: &AccountAddress) -> Self {
+        Self::Entity {
+            network_id: address.network_id,
+            entity_address: address.address.clone(),
+        }
+    }
+
+    /// `true` if the data with `key` is within this scope.
+    pub fn contains(&self, key: &GatewayCacheKey) -> bool {
+        match self {
+            Self::All => true,
+            Self::Network { network_id } => key.network_id == *network_id,
+            Self::Entity {
+                network_id,
+                entity_address,
+            } => {
+                key.network_id == *network_id
+                    && key.entity_address == *entity_address
+            }
+            Self::Kind { kind } => key.kind == *kind,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn all_contains_everything() {
+        assert!(
+            GatewayCacheScope::All.contains(&GatewayCacheKey::placeholder())
+        );
+        assert!(GatewayCacheScope::All
+            .contains(&GatewayCacheKey::placeholder_other()));
+    }
+
+    #[test]
+    fn network_contains_only_network() {
+        let sut = GatewayCacheScope::Network {
+            network_id: NetworkID::Mainnet,
+        };
+        assert!(sut.contains(&GatewayCacheKey::placeholder()));
+        assert!(!sut.contains(&GatewayCacheKey::placeholder_other()));
+    }
+
+    #[test]
+    fn account_contains_all_kinds_of_account() {
+        let sut = GatewayCacheScope::account(&AccountAddress::placeholder());
+        assert!(sut.contains(&GatewayCacheKey::placeholder()));
+        assert!(sut.contains(&GatewayCacheKey::new(
+            NetworkID::Mainnet,
+            AccountAddress::placeholder().address,
+            GatewayDataKind::EntityMetadata
+        )));
+        assert!(!sut.contains(&GatewayCacheKey::new(
+            NetworkID::Mainnet,
+            AccountAddress::placeholder_other().address,
+            GatewayDataKind::AccountHoldings
+        )));
+    }
+
+    #[test]
+    fn kind_contains_only_kind() {
+        let sut = GatewayCacheScope::Kind {
+            kind: GatewayDataKind::TransactionHistory,
+        };
+        assert!(!sut.contains(&GatewayCacheKey::placeholder()));
+        assert!(sut.contains(&GatewayCacheKey::placeholder_other()));
+    }
+}
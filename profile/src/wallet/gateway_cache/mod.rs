@@ -0,0 +1,7 @@
+mod gateway_cache;
+mod gateway_cache_key;
+mod wallet_gateway_cache;
+
+pub use gateway_cache::*;
+pub use gateway_cache_key::*;
+pub use wallet_gateway_cache::*;
@@ -0,0 +1,153 @@
+use crate::prelude::*;
+
+//========
+// Gateway data cache
+//========
+#[uniffi::export]
+impl Wallet {
+    /// The data cached for `key` during this session, if it has not expired
+    /// nor been invalidated.
+    pub fn cached_gateway_data(
+        &self,
+        key: GatewayCacheKey,
+    ) -> Option<BagOfBytes> {
+        let now = Timestamp::now();
+        self.access_gateway_cache_with(|c| c.get(&key, &now))
+    }
+
+    /// Caches `data` fetched from the Gateway for `key` for at most
+    /// `ttl_seconds`, replacing any data cached for `key`.
+    pub fn cache_gateway_data(
+        &self,
+        key: GatewayCacheKey,
+        data: BagOfBytes,
+        ttl_seconds: u64,
+    ) {
+        let now = Timestamp::now();
+        self.update_gateway_cache_with(|c| {
+            c.insert(key.clone(), data.clone(), ttl_seconds, now)
+        })
+    }
+
+    /// Removes all cached data within `scope`, e.g. when the user pulls to
+    /// refresh.
+    pub fn invalidate_cache(&self, scope: GatewayCacheScope) {
+        self.update_gateway_cache_with(|c| c.invalidate(&scope))
+    }
+
+    /// Notifies the Wallet that a transaction affecting `affected_accounts`
+    /// was committed, emitting [`WalletEvent::TransactionCommitted`] which
+    /// invalidates all cached data of those Accounts.
+    pub fn did_commit_transaction(
+        &self,
+        affected_accounts: Vec<AccountAddress>,
+    ) {
+        self.emit_event(WalletEvent::TransactionCommitted {
+            affected_accounts,
+        });
+    }
+}
+
+impl Wallet {
+    fn access_gateway_cache_with<T, F>(&self, access: F) -> T
+    where
+        F: Fn(&GatewayCache) -> T,
+    {
+        access(
+            &self
+                .gateway_cache
+                .read()
+                .expect("Gateway cache lock should not be poisoned"),
+        )
+    }
+
+    pub(crate) fn update_gateway_cache_with<F>(&self, mutate: F)
+    where
+        F: Fn(&mut GatewayCache),
+    {
+        mutate(
+            &mut self
+                .gateway_cache
+                .write()
+                .expect("Gateway cache lock should not be poisoned"),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn data() -> BagOfBytes {
+        BagOfBytes::from(vec![0xde, 0xad])
+    }
+
+    #[test]
+    fn cache_then_get() {
+        let sut = Wallet::placeholder();
+        assert_eq!(
+            sut.cached_gateway_data(GatewayCacheKey::placeholder()),
+            None
+        );
+        sut.cache_gateway_data(GatewayCacheKey::placeholder(), data(), 60);
+        assert_eq!(
+            sut.cached_gateway_data(GatewayCacheKey::placeholder()),
+            Some(data())
+        );
+    }
+
+    #[test]
+    fn expired_after_ttl() {
+        let sut = Wallet::placeholder();
+        with_time_provider(MockTimeProvider(Timestamp::placeholder()), || {
+            sut.cache_gateway_data(GatewayCacheKey::placeholder(), data(), 0)
+        });
+        assert_eq!(
+            sut.cached_gateway_data(GatewayCacheKey::placeholder()),
+            None
+        );
+    }
+
+    #[test]
+    fn invalidate_cache() {
+        let sut = Wallet::placeholder();
+        sut.cache_gateway_data(GatewayCacheKey::placeholder(), data(), 60);
+        sut.cache_gateway_data(
+            GatewayCacheKey::placeholder_other(),
+            data(),
+            60,
+        );
+        sut.invalidate_cache(GatewayCacheScope::Kind {
+            kind: GatewayDataKind::AccountHoldings,
+        });
+        assert_eq!(
+            sut.cached_gateway_data(GatewayCacheKey::placeholder()),
+            None
+        );
+        assert_eq!(
+            sut.cached_gateway_data(GatewayCacheKey::placeholder_other()),
+            Some(data())
+        );
+    }
+
+    #[test]
+    fn did_commit_transaction_invalidates_and_emits() {
+        let sut = Wallet::placeholder();
+        let observer = Arc::new(RecordingEventObserver::default());
+        sut.set_event_observer(observer.clone());
+        sut.cache_gateway_data(GatewayCacheKey::placeholder(), data(), 60);
+
+        sut.did_commit_transaction(vec![AccountAddress::placeholder_mainnet()]);
+
+        assert_eq!(
+            sut.cached_gateway_data(GatewayCacheKey::placeholder()),
+            None
+        );
+        assert_eq!(
+            *observer.events.read().unwrap(),
+            vec![WalletEvent::TransactionCommitted {
+                affected_accounts: vec![AccountAddress::placeholder_mainnet()]
+            }]
+        );
+    }
+}
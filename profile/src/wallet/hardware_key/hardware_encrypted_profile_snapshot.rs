@@ -0,0 +1,67 @@
+use crate::prelude::*;
+
+/// A Profile snapshot encrypted by a [`HardwareKeyDriver`], saved in
+/// SecureStorage in place of the plaintext snapshot when the Profile is
+/// protected by a hardware key.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct HardwareEncryptedProfileSnapshot {
+    /// The alias of the hardware key used to encrypt the snapshot.
+    pub key_alias: String,
+
+    /// The JSON of the Profile, encrypted.
+    pub ciphertext: BagOfBytes,
+}
+
+impl HardwareEncryptedProfileSnapshot {
+    pub fn new(key_alias: impl AsRef<str>, ciphertext: BagOfBytes) -> Self {
+        Self {
+            key_alias: key_alias.as_ref().to_owned(),
+            ciphertext,
+        }
+    }
+
+    /// The alias of the hardware key used to encrypt the snapshot of the
+    /// Profile with `profile_id`.
+    pub fn key_alias_for(profile_id: &ProfileID) -> String {
+        format!("profile_snapshot_encryption_key_{}", profile_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn key_alias_for() {
+        assert_eq!(
+            HardwareEncryptedProfileSnapshot::key_alias_for(
+                &ProfileID::placeholder()
+            ),
+            "profile_snapshot_encryption_key_ffffffff-ffff-ffff-ffff-ffffffffffff"
+        );
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        assert_json_value_eq_after_roundtrip(
+            &HardwareEncryptedProfileSnapshot::new(
+                "alias",
+                BagOfBytes::from(vec![0xde, 0xad]),
+            ),
+            json!({
+                "keyAlias": "alias",
+                "ciphertext": "dead"
+            }),
+        );
+    }
+
+    #[test]
+    fn profile_is_not_an_encrypted_snapshot() {
+        let json = serde_json::to_vec(&Profile::placeholder()).unwrap();
+        assert!(serde_json::from_slice::<HardwareEncryptedProfileSnapshot>(
+            &json
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,45 @@
+use crate::prelude::*;
+
+/// What the hardware backed key store of a device - e.g. the Secure Enclave
+/// on iOS or StrongBox/TEE on Android - supports, used to negotiate whether
+/// the Profile snapshot can be encrypted using it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct HardwareKeyCapabilities {
+    /// Keys can be used to encrypt and decrypt data.
+    pub supports_encryption: bool,
+
+    /// Keys can be used to sign data.
+    pub supports_signing: bool,
+}
+
+impl HardwareKeyCapabilities {
+    pub fn new(supports_encryption: bool, supports_signing: bool) -> Self {
+        Self {
+            supports_encryption,
+            supports_signing,
+        }
+    }
+}
+
+/// Callback interface implemented by Wallet Clients to use keys protected by
+/// hardware, which never leave it, identified by an alias chosen by the
+/// Wallet.
+#[uniffi::export]
+pub trait HardwareKeyDriver: Send + Sync + std::fmt::Debug {
+    /// What the hardware of this device supports.
+    fn capabilities(&self) -> HardwareKeyCapabilities;
+
+    /// Generates a new key with `alias`, replacing any existing key with it.
+    fn generate_key(&self, alias: String) -> Result<()>;
+
+    /// Encrypts `plaintext` using the key with `alias`, the returned
+    /// ciphertext must be authenticated.
+    fn encrypt(&self, alias: String, plaintext: Vec<u8>) -> Result<Vec<u8>>;
+
+    /// Decrypts `ciphertext` previously returned by `encrypt` for the key
+    /// with `alias`.
+    fn decrypt(&self, alias: String, ciphertext: Vec<u8>) -> Result<Vec<u8>>;
+
+    /// Signs `message` using the key with `alias`.
+    fn sign(&self, alias: String, message: Vec<u8>) -> Result<Vec<u8>>;
+}
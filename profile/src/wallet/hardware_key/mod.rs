@@ -0,0 +1,7 @@
+mod hardware_encrypted_profile_snapshot;
+mod hardware_key_driver;
+mod wallet_hardware_key;
+
+pub use hardware_encrypted_profile_snapshot::*;
+pub use hardware_key_driver::*;
+pub use wallet_hardware_key::*;
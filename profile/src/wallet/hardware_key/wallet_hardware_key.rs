@@ -0,0 +1,251 @@
+use crate::prelude::*;
+
+//========
+// CONSTRUCTOR
+//========
+#[uniffi::export]
+impl Wallet {
+    /// Creates wallet by loading the active Profile from storage, using
+    /// `driver` to decrypt it if it was saved encrypted by a hardware key.
+    #[uniffi::constructor]
+    pub fn by_loading_profile_with_hardware_key_driver(
+        secure_storage: Arc<dyn SecureStorage>,
+        driver: Arc<dyn HardwareKeyDriver>,
+    ) -> Result<Self> {
+        Wallet::init_logging();
+
+        log::info!(
+            "Instantiating Wallet by loading the active Profile from storage, with a HardwareKeyDriver"
+        );
+
+        let wallet_client_storage = WalletClientStorage::new(secure_storage);
+        wallet_client_storage.set_hardware_key_driver(Some(driver));
+        Self::new_load_active_profile(wallet_client_storage)
+    }
+}
+
+//========
+// Hardware key Profile encryption
+//========
+#[uniffi::export]
+impl Wallet {
+    /// Encrypts the saved Profile snapshot, now and on every later save,
+    /// using a newly generated key of `driver`.
+    ///
+    /// Returns `false` - leaving the Profile snapshot in plaintext - if the
+    /// hardware of this device does not support encryption.
+    pub fn enable_hardware_key_profile_encryption(
+        &self,
+        driver: Arc<dyn HardwareKeyDriver>,
+    ) -> Result<bool> {
+        if !driver.capabilities().supports_encryption {
+            log::info!("Hardware key encryption not supported, skipped");
+            return Ok(false);
+        }
+        let key_alias = HardwareEncryptedProfileSnapshot::key_alias_for(
+            &self.profile().id(),
+        );
+        driver.generate_key(key_alias.clone())?;
+
+        self.wallet_client_storage
+            .set_hardware_key_driver(Some(driver));
+        self.resave_profile_with_hardware_key_alias(Some(key_alias))
+            .map(|_| true)
+    }
+
+    /// Saves the Profile snapshot in plaintext, now and on every later save.
+    pub fn disable_hardware_key_profile_encryption(&self) -> Result<()> {
+        self.resave_profile_with_hardware_key_alias(None)
+    }
+
+    /// `true` if the saved Profile snapshot is encrypted by a hardware key.
+    pub fn is_profile_encrypted_with_hardware_key(&self) -> bool {
+        self.wallet_client_storage.hardware_key_alias().is_some()
+    }
+}
+
+impl Wallet {
+    /// Saves the Profile encrypted with the hardware key with `key_alias`,
+    /// or in plaintext if `None`, keeping the previous alias if the save
+    /// fails.
+    fn resave_profile_with_hardware_key_alias(
+        &self,
+        key_alias: Option<String>,
+    ) -> Result<()> {
+        let storage = &self.wallet_client_storage;
+        let previous = storage.hardware_key_alias();
+        storage.set_hardware_key_alias(key_alias);
+        self.save_existing_profile().map_err(|e| {
+            storage.set_hardware_key_alias(previous);
+            e
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use std::sync::RwLock;
+
+    /// "Encrypts" by reversing the bytes and xoring them with the length of
+    /// the alias, recording the aliases of generated keys.
+    #[derive(Debug, Default)]
+    struct MockHardwareKeyDriver {
+        supports_encryption: bool,
+        generated: RwLock<Vec<String>>,
+    }
+
+    impl MockHardwareKeyDriver {
+        fn new(supports_encryption: bool) -> Arc<Self> {
+            Arc::new(Self {
+                supports_encryption,
+                ..Default::default()
+            })
+        }
+
+        fn transform(alias: String, bytes: Vec<u8>) -> Vec<u8> {
+            let mask = alias.len() as u8;
+            bytes.into_iter().rev().map(|b| b ^ mask).collect()
+        }
+    }
+
+    impl HardwareKeyDriver for MockHardwareKeyDriver {
+        fn capabilities(&self) -> HardwareKeyCapabilities {
+            HardwareKeyCapabilities::new(self.supports_encryption, false)
+        }
+
+        fn generate_key(&self, alias: String) -> Result<()> {
+            self.generated.write().unwrap().push(alias);
+            Ok(())
+        }
+
+        fn encrypt(
+            &self,
+            alias: String,
+            plaintext: Vec<u8>,
+        ) -> Result<Vec<u8>> {
+            Ok(Self::transform(alias, plaintext))
+        }
+
+        fn decrypt(
+            &self,
+            alias: String,
+            ciphertext: Vec<u8>,
+        ) -> Result<Vec<u8>> {
+            Ok(Self::transform(alias, ciphertext))
+        }
+
+        fn sign(&self, _alias: String, _message: Vec<u8>) -> Result<Vec<u8>> {
+            Err(CommonError::Unknown)
+        }
+    }
+
+    fn snapshot(storage: &EphemeralSecureStorage) -> Vec<u8> {
+        storage
+            .load_data(SecureStorageKey::ProfileSnapshot {
+                profile_id: Profile::placeholder().id(),
+            })
+            .unwrap()
+            .unwrap()
+    }
+
+    #[test]
+    fn unsupported_encryption_is_skipped() {
+        let (sut, storage) = Wallet::ephemeral(Profile::placeholder());
+        let driver = MockHardwareKeyDriver::new(false);
+        assert_eq!(
+            sut.enable_hardware_key_profile_encryption(driver.clone()),
+            Ok(false)
+        );
+        assert!(!sut.is_profile_encrypted_with_hardware_key());
+        assert!(driver.generated.read().unwrap().is_empty());
+        assert_eq!(
+            serde_json::from_slice::<Profile>(&snapshot(&storage)).unwrap(),
+            Profile::placeholder()
+        );
+    }
+
+    #[test]
+    fn enable_encrypts_saved_profile() {
+        let (sut, storage) = Wallet::ephemeral(Profile::placeholder());
+        let driver = MockHardwareKeyDriver::new(true);
+        assert_eq!(
+            sut.enable_hardware_key_profile_encryption(driver.clone()),
+            Ok(true)
+        );
+        assert!(sut.is_profile_encrypted_with_hardware_key());
+
+        let key_alias = HardwareEncryptedProfileSnapshot::key_alias_for(
+            &Profile::placeholder().id(),
+        );
+        assert_eq!(*driver.generated.read().unwrap(), vec![key_alias.clone()]);
+        let encrypted = serde_json::from_slice::<
+            HardwareEncryptedProfileSnapshot,
+        >(&snapshot(&storage))
+        .unwrap();
+        assert_eq!(encrypted.key_alias, key_alias);
+        assert!(serde_json::from_slice::<Profile>(&snapshot(&storage)).is_err());
+    }
+
+    #[test]
+    fn load_encrypted_profile_with_driver() {
+        let (sut, storage) = Wallet::ephemeral(Profile::placeholder());
+        let driver = MockHardwareKeyDriver::new(true);
+        sut.enable_hardware_key_profile_encryption(driver.clone())
+            .unwrap();
+
+        let loaded = Wallet::by_loading_profile_with_hardware_key_driver(
+            storage.clone(),
+            driver,
+        )
+        .unwrap();
+        assert_eq!(loaded.profile(), Profile::placeholder());
+        assert!(loaded.is_profile_encrypted_with_hardware_key());
+
+        // later saves stay encrypted
+        loaded.save_existing_profile().unwrap();
+        assert!(serde_json::from_slice::<Profile>(&snapshot(&storage)).is_err());
+    }
+
+    #[test]
+    fn load_encrypted_profile_without_driver_fails() {
+        let (sut, storage) = Wallet::ephemeral(Profile::placeholder());
+        sut.enable_hardware_key_profile_encryption(MockHardwareKeyDriver::new(
+            true,
+        ))
+        .unwrap();
+
+        assert_eq!(
+            Wallet::by_loading_profile(storage).err(),
+            Some(CommonError::HardwareKeyDriverRequired)
+        );
+    }
+
+    #[test]
+    fn load_plaintext_profile_with_driver() {
+        let (_, storage) = Wallet::ephemeral(Profile::placeholder());
+        let loaded = Wallet::by_loading_profile_with_hardware_key_driver(
+            storage,
+            MockHardwareKeyDriver::new(true),
+        )
+        .unwrap();
+        assert_eq!(loaded.profile(), Profile::placeholder());
+        assert!(!loaded.is_profile_encrypted_with_hardware_key());
+    }
+
+    #[test]
+    fn disable_saves_plaintext_profile() {
+        let (sut, storage) = Wallet::ephemeral(Profile::placeholder());
+        sut.enable_hardware_key_profile_encryption(MockHardwareKeyDriver::new(
+            true,
+        ))
+        .unwrap();
+
+        assert_eq!(sut.disable_hardware_key_profile_encryption(), Ok(()));
+        assert!(!sut.is_profile_encrypted_with_hardware_key());
+        assert_eq!(
+            serde_json::from_slice::<Profile>(&snapshot(&storage)).unwrap(),
+            Profile::placeholder()
+        );
+    }
+}
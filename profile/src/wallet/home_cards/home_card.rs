@@ -0,0 +1,216 @@
+use crate::prelude::*;
+
+/// A card shown on the home screen of the wallet, e.g. a prompt to install
+/// the Radix Connector browser extension or a referral card of a dApp which
+/// brought the user to the wallet.
+///
+/// The order of the variants is the order in which the cards are presented.
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    uniffi::Enum,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum HomeCard {
+    /// The user arrived via RadQuest and has not finished it yet.
+    ContinueRadQuest,
+
+    /// The user has no accounts yet, RadQuest is a good place to start.
+    StartRadQuest,
+
+    /// The user was referred to the wallet by a dApp, `icon_url` is the
+    /// icon of that dApp, if known.
+    Dapp {
+        #[serde(rename = "iconURL")]
+        icon_url: Option<Url>,
+    },
+
+    /// The user has not linked any Radix Connector yet.
+    Connector,
+}
+
+impl Identifiable for HomeCard {
+    type ID = Self;
+
+    fn id(&self) -> Self::ID {
+        self.clone()
+    }
+}
+
+impl HomeCard {
+    /// The query parameter used by RadQuest deep links, e.g. `?special=rq`.
+    pub const DEEP_LINK_QUERY_SPECIAL: &'static str = "special";
+
+    /// The value of `DEEP_LINK_QUERY_SPECIAL` identifying RadQuest.
+    pub const DEEP_LINK_RADQUEST: &'static str = "rq";
+
+    /// The query parameter used by dApp referral deep links, the value being
+    /// the URL of the icon of the referring dApp, e.g. `?dapp_icon=https://..`.
+    pub const DEEP_LINK_QUERY_DAPP_ICON: &'static str = "dapp_icon";
+
+    /// The query parameter used by dApp referral deep links without an icon.
+    pub const DEEP_LINK_QUERY_DAPP: &'static str = "dapp";
+
+    /// Tries to create a `HomeCard` from a deep link, returns `Err` if the
+    /// deep link is not recognized.
+    pub fn from_deep_link(deep_link: &Url) -> Result<Self> {
+        let query: HashMap<String, String> =
+            deep_link.query_pairs().into_owned().collect();
+
+        if query
+            .get(Self::DEEP_LINK_QUERY_SPECIAL)
+            .is_some_and(|v| v == Self::DEEP_LINK_RADQUEST)
+        {
+            return Ok(Self::ContinueRadQuest);
+        }
+
+        if let Some(icon) = query.get(Self::DEEP_LINK_QUERY_DAPP_ICON) {
+            return Ok(Self::Dapp {
+                icon_url: Url::parse(icon).ok(),
+            });
+        }
+
+        if query.contains_key(Self::DEEP_LINK_QUERY_DAPP) {
+            return Ok(Self::Dapp { icon_url: None });
+        }
+
+        Err(CommonError::UnrecognizedHomeCardDeepLink(
+            deep_link.to_string(),
+        ))
+    }
+}
+
+impl HasPlaceholder for HomeCard {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::Connector
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::Dapp {
+            icon_url: Some(
+                Url::parse("https://radquest.io/icon.png")
+                    .expect("Valid placeholder URL"),
+            ),
+        }
+    }
+}
+
+#[uniffi::export]
+pub fn new_home_card_from_deep_link(deep_link: Url) -> Result<HomeCard> {
+    HomeCard::from_deep_link(&deep_link)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality() {
+        assert_eq!(HomeCard::placeholder(), HomeCard::placeholder());
+        assert_eq!(
+            HomeCard::placeholder_other(),
+            HomeCard::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(HomeCard::placeholder(), HomeCard::placeholder_other());
+    }
+
+    #[test]
+    fn ordering() {
+        assert!(HomeCard::ContinueRadQuest < HomeCard::StartRadQuest);
+        assert!(HomeCard::StartRadQuest < HomeCard::placeholder_other());
+        assert!(HomeCard::placeholder_other() < HomeCard::Connector);
+    }
+
+    #[test]
+    fn from_deep_link_radquest() {
+        let url =
+            Url::parse("https://wallet.radixdlt.com/?special=rq").unwrap();
+        assert_eq!(
+            HomeCard::from_deep_link(&url),
+            Ok(HomeCard::ContinueRadQuest)
+        );
+    }
+
+    #[test]
+    fn from_deep_link_dapp_with_icon() {
+        let url = Url::parse(
+            "https://wallet.radixdlt.com/?dapp_icon=https%3A%2F%2Fradquest.io%2Ficon.png",
+        )
+        .unwrap();
+        assert_eq!(
+            HomeCard::from_deep_link(&url),
+            Ok(HomeCard::placeholder_other())
+        );
+    }
+
+    #[test]
+    fn from_deep_link_dapp_with_invalid_icon() {
+        let url =
+            Url::parse("https://wallet.radixdlt.com/?dapp_icon=not_a_url")
+                .unwrap();
+        assert_eq!(
+            HomeCard::from_deep_link(&url),
+            Ok(HomeCard::Dapp { icon_url: None })
+        );
+    }
+
+    #[test]
+    fn from_deep_link_dapp_without_icon() {
+        let url = Url::parse("https://wallet.radixdlt.com/?dapp=1").unwrap();
+        assert_eq!(
+            HomeCard::from_deep_link(&url),
+            Ok(HomeCard::Dapp { icon_url: None })
+        );
+    }
+
+    #[test]
+    fn from_deep_link_unrecognized() {
+        let url = Url::parse("https://wallet.radixdlt.com/?foo=bar").unwrap();
+        assert_eq!(
+            HomeCard::from_deep_link(&url),
+            Err(CommonError::UnrecognizedHomeCardDeepLink(
+                "https://wallet.radixdlt.com/?foo=bar".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        assert_json_value_eq_after_roundtrip(
+            &HomeCard::Connector,
+            json!("connector"),
+        );
+        assert_json_value_eq_after_roundtrip(
+            &HomeCard::placeholder_other(),
+            json!({ "dapp": { "iconURL": "https://radquest.io/icon.png" } }),
+        );
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn from_deep_link() {
+        let url =
+            Url::parse("https://wallet.radixdlt.com/?special=rq").unwrap();
+        assert_eq!(
+            new_home_card_from_deep_link(url.clone()),
+            HomeCard::from_deep_link(&url)
+        );
+    }
+}
@@ -0,0 +1,95 @@
+use crate::prelude::*;
+
+/// An ordered set of [`HomeCard`]s, sorted in the order they should be
+/// presented to the user.
+pub type HomeCards = IdentifiedVecVia<HomeCard>;
+
+impl Default for HomeCards {
+    /// Instantiates a new empty collection.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HomeCards {
+    /// Inserts `card` and keeps the collection sorted, returns `true` if
+    /// the card was not already present.
+    pub fn insert_card(&mut self, card: HomeCard) -> bool {
+        let inserted = self.append(card).0;
+        if inserted {
+            *self = Self::from_iter(self.items().into_iter().sorted());
+        }
+        inserted
+    }
+
+    /// Removes `card` if present, returns `true` if it was removed.
+    pub fn remove_card(&mut self, card: &HomeCard) -> bool {
+        self.remove(card).is_some()
+    }
+}
+
+impl HasPlaceholder for HomeCards {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::from_iter([HomeCard::StartRadQuest, HomeCard::Connector])
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::from_iter([HomeCard::placeholder_other()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn default_is_empty() {
+        assert_eq!(HomeCards::default().len(), 0);
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(HomeCards::placeholder(), HomeCards::placeholder_other());
+    }
+
+    #[test]
+    fn insert_keeps_sorted() {
+        let mut sut = HomeCards::new();
+        assert!(sut.insert_card(HomeCard::Connector));
+        assert!(sut.insert_card(HomeCard::ContinueRadQuest));
+        assert!(sut.insert_card(HomeCard::placeholder_other()));
+        assert_eq!(
+            sut.items(),
+            [
+                HomeCard::ContinueRadQuest,
+                HomeCard::placeholder_other(),
+                HomeCard::Connector
+            ]
+        );
+    }
+
+    #[test]
+    fn insert_duplicate() {
+        let mut sut = HomeCards::placeholder();
+        assert!(!sut.insert_card(HomeCard::Connector));
+        assert_eq!(sut, HomeCards::placeholder());
+    }
+
+    #[test]
+    fn remove() {
+        let mut sut = HomeCards::placeholder();
+        assert!(sut.remove_card(&HomeCard::Connector));
+        assert!(!sut.remove_card(&HomeCard::Connector));
+        assert_eq!(sut.items(), [HomeCard::StartRadQuest]);
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        assert_json_value_eq_after_roundtrip(
+            &HomeCards::placeholder(),
+            json!(["startRadQuest", "connector"]),
+        );
+    }
+}
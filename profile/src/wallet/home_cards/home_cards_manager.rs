@@ -0,0 +1,325 @@
+use crate::prelude::*;
+use std::sync::RwLock;
+
+/// The persisted state of the home cards, the cards currently shown and
+/// the cards the user has dismissed, which must never be shown again.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct HomeCardsState {
+    pub(crate) cards: HomeCards,
+    pub(crate) dismissed: HomeCards,
+}
+
+impl HomeCardsState {
+    /// Inserts `card` unless the user has dismissed it before.
+    fn show(&mut self, card: HomeCard) {
+        if self.dismissed.contains(&card) {
+            return;
+        }
+        self.cards.insert_card(card);
+    }
+
+    /// Removes `card` without marking it as dismissed.
+    fn hide(&mut self, card: &HomeCard) {
+        self.cards.remove_card(card);
+    }
+
+    /// Removes `card` and remembers it was dismissed by the user.
+    fn dismiss(&mut self, card: HomeCard) {
+        self.hide(&card);
+        self.dismissed.insert_card(card);
+    }
+}
+
+/// Computes which [`HomeCard`]s to show on the home screen of the wallet,
+/// based on deep links and the state of the Profile. Dismissals of cards
+/// are persisted in `SecureStorage` and every change is reported to the
+/// `HomeCardsObserver`.
+#[derive(Debug, uniffi::Object)]
+pub struct HomeCardsManager {
+    wallet_client_storage: WalletClientStorage,
+    observer: Arc<dyn HomeCardsObserver>,
+    state: RwLock<HomeCardsState>,
+}
+
+#[uniffi::export]
+impl HomeCardsManager {
+    #[uniffi::constructor]
+    pub fn new(
+        secure_storage: Arc<dyn SecureStorage>,
+        observer: Arc<dyn HomeCardsObserver>,
+    ) -> Self {
+        Self {
+            wallet_client_storage: WalletClientStorage::new(secure_storage),
+            observer,
+            state: RwLock::new(HomeCardsState::default()),
+        }
+    }
+
+    /// Loads the persisted cards from `SecureStorage` and notifies the
+    /// observer with them.
+    pub fn bootstrap(&self) -> Result<HomeCards> {
+        let loaded = self
+            .wallet_client_storage
+            .load::<HomeCardsState>(SecureStorageKey::HomeCards)?
+            .unwrap_or_default();
+        self.update_state_with(|state| *state = loaded.clone())
+    }
+
+    /// Recomputes the cards which depend on the state of `profile`, e.g.
+    /// shows the `Connector` card if no P2PLinks have been established.
+    pub fn profile_changed(&self, profile: Profile) -> Result<HomeCards> {
+        let has_p2p_links = !profile.app_preferences.p2p_links.is_empty();
+        let has_accounts =
+            profile.networks.iter().any(|n| !n.accounts.is_empty());
+        self.update_state_with(|state| {
+            if has_p2p_links {
+                state.hide(&HomeCard::Connector);
+            } else {
+                state.show(HomeCard::Connector);
+            }
+            if has_accounts {
+                state.hide(&HomeCard::StartRadQuest);
+            } else {
+                state.show(HomeCard::StartRadQuest);
+            }
+        })
+    }
+
+    /// Shows the card the `deep_link` refers to, returns `Err` if the deep
+    /// link is not recognized.
+    pub fn deep_link_received(&self, deep_link: Url) -> Result<HomeCards> {
+        let card = HomeCard::from_deep_link(&deep_link)?;
+        self.update_state_with(|state| {
+            if card == HomeCard::ContinueRadQuest {
+                state.hide(&HomeCard::StartRadQuest);
+            }
+            state.show(card.clone())
+        })
+    }
+
+    /// Dismisses `card`, it will never be shown again.
+    pub fn card_dismissed(&self, card: HomeCard) -> Result<HomeCards> {
+        self.update_state_with(|state| state.dismiss(card.clone()))
+    }
+
+    /// The cards which currently should be shown.
+    pub fn cards(&self) -> HomeCards {
+        self.state
+            .try_read()
+            .map(|s| s.cards.clone())
+            .expect("Implementing Wallet clients should not read and write HomeCards from multiple threads.")
+    }
+}
+
+impl HomeCardsManager {
+    /// Mutates a copy of the state and persists it, the state in memory is
+    /// only replaced once saving succeeded, so that it never diverges from
+    /// what is in `SecureStorage`.
+    fn update_state_with<F>(&self, mutate: F) -> Result<HomeCards>
+    where
+        F: Fn(&mut HomeCardsState),
+    {
+        let cards = {
+            let mut state = self.state.try_write().map_err(|_| {
+                CommonError::UnableToAcquireWriteLockForHomeCards
+            })?;
+            let mut updated = state.clone();
+            mutate(&mut updated);
+            self.wallet_client_storage
+                .save(SecureStorageKey::HomeCards, &updated)?;
+            *state = updated;
+            state.cards.clone()
+        };
+        self.observer.handle_cards_update(cards.clone());
+        Ok(cards)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use std::sync::RwLock;
+
+    #[derive(Debug, Default)]
+    struct RecordingObserver {
+        updates: RwLock<Vec<HomeCards>>,
+    }
+
+    impl HomeCardsObserver for RecordingObserver {
+        fn handle_cards_update(&self, cards: HomeCards) {
+            self.updates.write().unwrap().push(cards)
+        }
+    }
+
+    fn make_sut() -> (
+        HomeCardsManager,
+        Arc<EphemeralSecureStorage>,
+        Arc<RecordingObserver>,
+    ) {
+        let storage = EphemeralSecureStorage::new();
+        let observer = Arc::new(RecordingObserver::default());
+        (
+            HomeCardsManager::new(storage.clone(), observer.clone()),
+            storage,
+            observer,
+        )
+    }
+
+    fn profile_without_links_and_accounts() -> Profile {
+        Profile::new(
+            PrivateHierarchicalDeterministicFactorSource::placeholder(),
+            "Test",
+        )
+    }
+
+    #[test]
+    fn failed_save_leaves_cards_unchanged() {
+        let storage = FlakyStorage::ephemeral();
+        let observer = Arc::new(RecordingObserver::default());
+        let sut = HomeCardsManager::new(storage.clone(), observer.clone());
+        _ = sut.profile_changed(profile_without_links_and_accounts());
+        storage.fail_nth_next_save(1);
+
+        assert_eq!(
+            sut.card_dismissed(HomeCard::Connector),
+            Err(CommonError::SecureStorageWriteError)
+        );
+
+        assert_eq!(
+            sut.cards().items(),
+            [HomeCard::StartRadQuest, HomeCard::Connector]
+        );
+        assert_eq!(observer.updates.read().unwrap().len(), 1);
+        assert_eq!(
+            storage
+                .load_data(SecureStorageKey::HomeCards)
+                .unwrap()
+                .map(|d| serde_json::from_slice::<HomeCardsState>(&d).unwrap())
+                .unwrap()
+                .cards,
+            sut.cards()
+        );
+    }
+
+    #[test]
+    fn bootstrap_empty() {
+        let (sut, _, observer) = make_sut();
+        assert_eq!(sut.bootstrap(), Ok(HomeCards::new()));
+        assert_eq!(observer.updates.read().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn profile_changed_new_profile() {
+        let (sut, _, _) = make_sut();
+        assert_eq!(
+            sut.profile_changed(profile_without_links_and_accounts())
+                .unwrap()
+                .items(),
+            [HomeCard::StartRadQuest, HomeCard::Connector]
+        );
+    }
+
+    #[test]
+    fn profile_changed_placeholder_profile() {
+        let (sut, _, _) = make_sut();
+        assert_eq!(
+            sut.profile_changed(Profile::placeholder()),
+            Ok(HomeCards::new())
+        );
+    }
+
+    #[test]
+    fn profile_changed_hides_cards_no_longer_relevant() {
+        let (sut, _, _) = make_sut();
+        _ = sut.profile_changed(profile_without_links_and_accounts());
+        _ = sut.profile_changed(Profile::placeholder());
+        assert_eq!(sut.cards(), HomeCards::new());
+    }
+
+    #[test]
+    fn deep_link_radquest_replaces_start() {
+        let (sut, _, _) = make_sut();
+        _ = sut.profile_changed(profile_without_links_and_accounts());
+        let url =
+            Url::parse("https://wallet.radixdlt.com/?special=rq").unwrap();
+        assert_eq!(
+            sut.deep_link_received(url).unwrap().items(),
+            [HomeCard::ContinueRadQuest, HomeCard::Connector]
+        );
+    }
+
+    #[test]
+    fn deep_link_unrecognized() {
+        let (sut, _, observer) = make_sut();
+        let url = Url::parse("https://wallet.radixdlt.com/?foo=bar").unwrap();
+        assert!(sut.deep_link_received(url).is_err());
+        assert!(observer.updates.read().unwrap().is_empty());
+    }
+
+    #[test]
+    fn dismissed_card_is_never_shown_again() {
+        let (sut, _, _) = make_sut();
+        _ = sut.profile_changed(profile_without_links_and_accounts());
+        assert_eq!(
+            sut.card_dismissed(HomeCard::Connector).unwrap().items(),
+            [HomeCard::StartRadQuest]
+        );
+        assert_eq!(
+            sut.profile_changed(profile_without_links_and_accounts())
+                .unwrap()
+                .items(),
+            [HomeCard::StartRadQuest]
+        );
+    }
+
+    #[test]
+    fn dismissals_are_persisted() {
+        let (sut, storage, _) = make_sut();
+        _ = sut.profile_changed(profile_without_links_and_accounts());
+        _ = sut.card_dismissed(HomeCard::Connector);
+
+        let observer = Arc::new(RecordingObserver::default());
+        let restored = HomeCardsManager::new(storage, observer.clone());
+        assert_eq!(
+            restored.bootstrap().unwrap().items(),
+            [HomeCard::StartRadQuest]
+        );
+        assert_eq!(
+            restored
+                .profile_changed(profile_without_links_and_accounts())
+                .unwrap()
+                .items(),
+            [HomeCard::StartRadQuest]
+        );
+        assert_eq!(observer.updates.read().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn observer_is_notified() {
+        let (sut, _, observer) = make_sut();
+        _ = sut.profile_changed(profile_without_links_and_accounts());
+        _ = sut.card_dismissed(HomeCard::StartRadQuest);
+        assert_eq!(
+            observer.updates.read().unwrap().clone(),
+            [
+                HomeCards::from_iter([
+                    HomeCard::StartRadQuest,
+                    HomeCard::Connector
+                ]),
+                HomeCards::from_iter([HomeCard::Connector])
+            ]
+        );
+    }
+
+    #[test]
+    fn save_failure_is_propagated() {
+        let observer = Arc::new(RecordingObserver::default());
+        let sut =
+            HomeCardsManager::new(Arc::new(AlwaysFailStorage {}), observer);
+        assert_eq!(
+            sut.card_dismissed(HomeCard::Connector),
+            Err(CommonError::Unknown)
+        );
+    }
+}
@@ -0,0 +1,8 @@
+use crate::prelude::*;
+
+/// Implemented by the Wallet Client to be notified whenever the set of
+/// [`HomeCard`]s to display changes.
+#[uniffi::export]
+pub trait HomeCardsObserver: Send + Sync + std::fmt::Debug {
+    fn handle_cards_update(&self, cards: HomeCards);
+}
@@ -0,0 +1,9 @@
+mod home_card;
+mod home_cards;
+mod home_cards_manager;
+mod home_cards_observer;
+
+pub use home_card::*;
+pub use home_cards::*;
+pub use home_cards_manager::*;
+pub use home_cards_observer::*;
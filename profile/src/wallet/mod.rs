@@ -1,9 +1,59 @@
+mod backup;
+#[cfg(feature = "signing")]
+mod factor_source_drivers;
+mod gateway_cache;
+mod hardware_key;
+mod home_cards;
+mod mutation_journal;
+mod offline_mode;
+#[cfg(feature = "radix-connect")]
+mod persona_auth;
 mod secure_storage;
+mod security_center;
 mod wallet;
 mod wallet_accounts;
+mod wallet_app_preferences;
+mod wallet_dapp_preferences;
+mod wallet_deletion;
+mod wallet_entities;
+mod wallet_events;
+mod wallet_networks;
+#[cfg(feature = "signing")]
+mod wallet_olympia_import;
+mod wallet_on_network;
+#[cfg(feature = "radix-connect")]
+mod wallet_pending_interactions;
+mod wallet_profile_export;
 mod wallet_profile_io;
+mod wallet_profile_recovery;
+mod wallet_transaction_review;
 
+pub use backup::*;
+#[cfg(feature = "signing")]
+pub use factor_source_drivers::*;
+pub use gateway_cache::*;
+pub use hardware_key::*;
+pub use home_cards::*;
+pub use mutation_journal::*;
+pub use offline_mode::*;
+#[cfg(feature = "radix-connect")]
+pub use persona_auth::*;
 pub use secure_storage::*;
+pub use security_center::*;
 pub use wallet::*;
 pub use wallet_accounts::*;
+pub use wallet_app_preferences::*;
+pub use wallet_dapp_preferences::*;
+pub use wallet_deletion::*;
+pub use wallet_entities::*;
+pub use wallet_events::*;
+pub use wallet_networks::*;
+#[cfg(feature = "signing")]
+pub use wallet_olympia_import::*;
+pub use wallet_on_network::*;
+#[cfg(feature = "radix-connect")]
+pub use wallet_pending_interactions::*;
+pub use wallet_profile_export::*;
 pub use wallet_profile_io::*;
+pub use wallet_profile_recovery::*;
+pub use wallet_transaction_review::*;
@@ -0,0 +1,7 @@
+mod mutation_journal;
+mod profile_mutation;
+mod wallet_undo;
+
+pub use mutation_journal::*;
+pub use profile_mutation::*;
+pub use wallet_undo::*;
@@ -0,0 +1,94 @@
+use crate::prelude::*;
+
+/// An in-memory journal of the [`ProfileMutation`]s made by the [`Wallet`]
+/// during a session, used to undo them, most recent first. Disabled by
+/// default, in which case nothing is recorded.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MutationJournal {
+    is_enabled: bool,
+    mutations: Vec<ProfileMutation>,
+}
+
+impl MutationJournal {
+    /// The maximum number of mutations kept, the oldest are dropped.
+    pub const MAX_LEN: usize = 50;
+
+    pub fn is_enabled(&self) -> bool {
+        self.is_enabled
+    }
+
+    /// Enables or disables recording, disabling clears the journal.
+    pub fn set_enabled(&mut self, is_enabled: bool) {
+        self.is_enabled = is_enabled;
+        if !is_enabled {
+            self.mutations.clear();
+        }
+    }
+
+    /// Records `mutation` if enabled, dropping the oldest mutation if the
+    /// journal is full.
+    pub fn record(&mut self, mutation: ProfileMutation) {
+        if !self.is_enabled {
+            return;
+        }
+        if self.mutations.len() == Self::MAX_LEN {
+            self.mutations.remove(0);
+        }
+        self.mutations.push(mutation);
+    }
+
+    /// Removes and returns the most recent mutation, if any.
+    pub fn pop(&mut self) -> Option<ProfileMutation> {
+        self.mutations.pop()
+    }
+
+    /// The recorded mutations, oldest first.
+    pub fn mutations(&self) -> Vec<ProfileMutation> {
+        self.mutations.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn disabled_by_default_records_nothing() {
+        let mut sut = MutationJournal::default();
+        assert!(!sut.is_enabled());
+        sut.record(ProfileMutation::placeholder());
+        assert_eq!(sut.pop(), None);
+    }
+
+    #[test]
+    fn pop_most_recent_first() {
+        let mut sut = MutationJournal::default();
+        sut.set_enabled(true);
+        sut.record(ProfileMutation::placeholder());
+        sut.record(ProfileMutation::placeholder_other());
+        assert_eq!(sut.pop(), Some(ProfileMutation::placeholder_other()));
+        assert_eq!(sut.pop(), Some(ProfileMutation::placeholder()));
+        assert_eq!(sut.pop(), None);
+    }
+
+    #[test]
+    fn disabling_clears() {
+        let mut sut = MutationJournal::default();
+        sut.set_enabled(true);
+        sut.record(ProfileMutation::placeholder());
+        sut.set_enabled(false);
+        assert!(sut.mutations().is_empty());
+    }
+
+    #[test]
+    fn oldest_dropped_when_full() {
+        let mut sut = MutationJournal::default();
+        sut.set_enabled(true);
+        sut.record(ProfileMutation::placeholder_other());
+        (0..MutationJournal::MAX_LEN)
+            .for_each(|_| sut.record(ProfileMutation::placeholder()));
+        let mutations = sut.mutations();
+        assert_eq!(mutations.len(), MutationJournal::MAX_LEN);
+        assert!(!mutations.contains(&ProfileMutation::placeholder_other()));
+    }
+}
@@ -0,0 +1,124 @@
+use crate::prelude::*;
+
+/// A reversible change of the Profile made by the [`Wallet`], recorded in
+/// its [`MutationJournal`] so that it can be undone.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Enum)]
+pub enum ProfileMutation {
+    /// An Account or Persona changed its display name from `old_name` to
+    /// `new_name`.
+    EntityRenamed {
+        address: AddressOfAccountOrPersona,
+        old_name: DisplayName,
+        new_name: DisplayName,
+    },
+
+    /// `flag` of an Account or Persona was set - if `is_set` - or cleared.
+    EntityFlagChanged {
+        address: AddressOfAccountOrPersona,
+        flag: EntityFlag,
+        is_set: bool,
+    },
+
+    /// The AppPreferences were changed from `old` to `new`.
+    AppPreferencesChanged {
+        old: AppPreferences,
+        new: AppPreferences,
+    },
+}
+
+impl ProfileMutation {
+    /// The mutation which undoes this mutation.
+    pub fn inverse(&self) -> Self {
+        match self.clone() {
+            Self::EntityRenamed {
+                address,
+                old_name,
+                new_name,
+            } => Self::EntityRenamed {
+                address,
+                old_name: new_name,
+                new_name: old_name,
+            },
+            Self::EntityFlagChanged {
+                address,
+                flag,
+                is_set,
+            } => Self::EntityFlagChanged {
+                address,
+                flag,
+                is_set: !is_set,
+            },
+            Self::AppPreferencesChanged { old, new } => {
+                Self::AppPreferencesChanged { old: new, new: old }
+            }
+        }
+    }
+}
+
+impl HasPlaceholder for ProfileMutation {
+    fn placeholder() -> Self {
+        Self::EntityRenamed {
+            address: AddressOfAccountOrPersona::placeholder(),
+            old_name: DisplayName::new("Alice").unwrap(),
+            new_name: DisplayName::new("Stella").unwrap(),
+        }
+    }
+
+    fn placeholder_other() -> Self {
+        Self::EntityFlagChanged {
+            address: AddressOfAccountOrPersona::placeholder(),
+            flag: EntityFlag::DeletedByUser,
+            is_set: true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn inverse_of_rename_swaps_names() {
+        assert_eq!(
+            ProfileMutation::placeholder().inverse(),
+            ProfileMutation::EntityRenamed {
+                address: AddressOfAccountOrPersona::placeholder(),
+                old_name: DisplayName::new("Stella").unwrap(),
+                new_name: DisplayName::new("Alice").unwrap(),
+            }
+        );
+    }
+
+    #[test]
+    fn inverse_of_flag_change_toggles() {
+        assert_eq!(
+            ProfileMutation::placeholder_other().inverse(),
+            ProfileMutation::EntityFlagChanged {
+                address: AddressOfAccountOrPersona::placeholder(),
+                flag: EntityFlag::DeletedByUser,
+                is_set: false,
+            }
+        );
+    }
+
+    #[test]
+    fn inverse_of_app_preferences_change_swaps() {
+        let sut = ProfileMutation::AppPreferencesChanged {
+            old: AppPreferences::placeholder(),
+            new: AppPreferences::placeholder_other(),
+        };
+        assert_eq!(
+            sut.inverse(),
+            ProfileMutation::AppPreferencesChanged {
+                old: AppPreferences::placeholder_other(),
+                new: AppPreferences::placeholder(),
+            }
+        );
+    }
+
+    #[test]
+    fn inverse_of_inverse_is_identity() {
+        let sut = ProfileMutation::placeholder_other();
+        assert_eq!(sut.inverse().inverse(), sut);
+    }
+}
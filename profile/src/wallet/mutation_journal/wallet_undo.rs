@@ -0,0 +1,253 @@
+use crate::prelude::*;
+
+#[uniffi::export]
+impl Wallet {
+    /// Enables or disables recording of [`ProfileMutation`]s in the mutation
+    /// journal of this session, so that they can be undone with
+    /// [`Wallet::undo_last`]. Disabling clears the journal.
+    pub fn set_mutation_journal_enabled(&self, is_enabled: bool) {
+        self.mutation_journal
+            .write()
+            .expect("Should be able to acquire write lock for mutation journal")
+            .set_enabled(is_enabled)
+    }
+
+    /// `true` if [`ProfileMutation`]s are recorded in the mutation journal.
+    pub fn is_mutation_journal_enabled(&self) -> bool {
+        self.mutation_journal
+            .read()
+            .expect("Should be able to acquire read lock for mutation journal")
+            .is_enabled()
+    }
+
+    /// The recorded [`ProfileMutation`]s which can be undone, oldest first.
+    pub fn recorded_mutations(&self) -> Vec<ProfileMutation> {
+        self.mutation_journal
+            .read()
+            .expect("Should be able to acquire read lock for mutation journal")
+            .mutations()
+    }
+
+    /// Undoes the most recently recorded [`ProfileMutation`], saving the
+    /// Profile and emitting the event of the reverting change, and returns
+    /// the undone mutation, or `None` if there was nothing to undo.
+    ///
+    /// If undoing fails the mutation is kept in the journal.
+    pub fn undo_last(&self) -> Result<Option<ProfileMutation>> {
+        let Some(mutation) = self
+            .mutation_journal
+            .write()
+            .expect("Should be able to acquire write lock for mutation journal")
+            .pop()
+        else {
+            return Ok(None);
+        };
+
+        match self.apply_mutation(mutation.inverse()) {
+            Ok(_) => Ok(Some(mutation)),
+            Err(e) => {
+                error!("Failed to undo {:?}, error: {}", mutation, e);
+                self.record_mutation(mutation);
+                Err(e)
+            }
+        }
+    }
+}
+
+impl Wallet {
+    /// Records `mutation` in the mutation journal, if enabled.
+    pub(crate) fn record_mutation(&self, mutation: ProfileMutation) {
+        self.mutation_journal
+            .write()
+            .expect("Should be able to acquire write lock for mutation journal")
+            .record(mutation)
+    }
+
+    /// Applies `mutation` without recording it.
+    fn apply_mutation(&self, mutation: ProfileMutation) -> Result<()> {
+        match mutation {
+            ProfileMutation::EntityRenamed {
+                address, new_name, ..
+            } => self.apply_rename_entity(address, new_name).map(|_| ()),
+            ProfileMutation::EntityFlagChanged {
+                address,
+                flag,
+                is_set,
+            } => self
+                .apply_set_entity_flag(address, flag, is_set)
+                .map(|_| ()),
+            ProfileMutation::AppPreferencesChanged { new, .. } => {
+                self.apply_update_app_preferences(new).map(|_| ())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn make_sut() -> (Wallet, Arc<RecordingEventObserver>) {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let observer = Arc::new(RecordingEventObserver::default());
+        wallet.set_event_observer(observer.clone());
+        wallet.set_mutation_journal_enabled(true);
+        (wallet, observer)
+    }
+
+    fn alice() -> AddressOfAccountOrPersona {
+        Account::placeholder_mainnet_alice().address.into()
+    }
+
+    fn is_alice_hidden(wallet: &Wallet) -> bool {
+        wallet.access_profile_with(|p| {
+            p.networks
+                .get_account(&Account::placeholder_mainnet_alice().address)
+                .unwrap()
+                .flags
+                .contains_id(&EntityFlag::DeletedByUser)
+        })
+    }
+
+    #[test]
+    fn nothing_recorded_when_disabled() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        assert!(!wallet.is_mutation_journal_enabled());
+        wallet
+            .rename_entity(alice(), DisplayName::new("Stella").unwrap())
+            .unwrap();
+        assert!(wallet.recorded_mutations().is_empty());
+        assert_eq!(wallet.undo_last(), Ok(None));
+    }
+
+    #[test]
+    fn undo_rename() {
+        let (wallet, observer) = make_sut();
+        wallet
+            .rename_entity(alice(), DisplayName::new("Stella").unwrap())
+            .unwrap();
+        let mutation = ProfileMutation::EntityRenamed {
+            address: alice(),
+            old_name: DisplayName::new("Alice").unwrap(),
+            new_name: DisplayName::new("Stella").unwrap(),
+        };
+        assert_eq!(wallet.recorded_mutations(), vec![mutation.clone()]);
+
+        assert_eq!(wallet.undo_last(), Ok(Some(mutation)));
+
+        assert_eq!(
+            wallet
+                .access_profile_with(|p| p
+                    .networks
+                    .get_account(&Account::placeholder_mainnet_alice().address))
+                .unwrap()
+                .display_name,
+            DisplayName::new("Alice").unwrap()
+        );
+        assert_eq!(
+            observer.events.read().unwrap().last(),
+            Some(&WalletEvent::EntityRenamed {
+                address: alice(),
+                old_name: DisplayName::new("Stella").unwrap(),
+                new_name: DisplayName::new("Alice").unwrap(),
+            })
+        );
+        assert!(wallet.recorded_mutations().is_empty());
+    }
+
+    #[test]
+    fn undo_hide_account() {
+        let (wallet, observer) = make_sut();
+        wallet
+            .set_entity_flag(alice(), EntityFlag::DeletedByUser, true)
+            .unwrap();
+        assert!(is_alice_hidden(&wallet));
+
+        assert!(wallet.undo_last().unwrap().is_some());
+
+        assert!(!is_alice_hidden(&wallet));
+        assert_eq!(
+            observer.events.read().unwrap().last(),
+            Some(&WalletEvent::EntityFlagChanged {
+                address: alice(),
+                flag: EntityFlag::DeletedByUser,
+                is_set: false,
+            })
+        );
+    }
+
+    #[test]
+    fn unchanged_flag_is_not_recorded() {
+        let (wallet, _) = make_sut();
+        wallet
+            .set_entity_flag(alice(), EntityFlag::DeletedByUser, false)
+            .unwrap();
+        assert!(wallet.recorded_mutations().is_empty());
+    }
+
+    #[test]
+    fn undo_app_preferences_change() {
+        let (wallet, _) = make_sut();
+        let old = wallet.profile().app_preferences;
+        let mut new = old.clone();
+        new.security.is_developer_mode_enabled =
+            !new.security.is_developer_mode_enabled;
+        wallet.update_app_preferences(new).unwrap();
+
+        assert!(wallet.undo_last().unwrap().is_some());
+
+        assert_eq!(wallet.profile().app_preferences, old);
+    }
+
+    #[test]
+    fn undo_most_recent_first() {
+        let (wallet, _) = make_sut();
+        wallet
+            .rename_entity(alice(), DisplayName::new("Stella").unwrap())
+            .unwrap();
+        wallet
+            .set_entity_flag(alice(), EntityFlag::DeletedByUser, true)
+            .unwrap();
+
+        assert!(matches!(
+            wallet.undo_last(),
+            Ok(Some(ProfileMutation::EntityFlagChanged { .. }))
+        ));
+        assert!(matches!(
+            wallet.undo_last(),
+            Ok(Some(ProfileMutation::EntityRenamed { .. }))
+        ));
+        assert_eq!(wallet.undo_last(), Ok(None));
+    }
+
+    #[test]
+    fn undo_is_not_recorded() {
+        let (wallet, _) = make_sut();
+        wallet
+            .set_entity_flag(alice(), EntityFlag::DeletedByUser, true)
+            .unwrap();
+        wallet.undo_last().unwrap();
+        assert!(wallet.recorded_mutations().is_empty());
+    }
+
+    #[test]
+    fn failed_undo_keeps_mutation() {
+        let storage = FlakyStorage::ephemeral();
+        let wallet = Wallet::by_importing_profile(
+            Profile::placeholder(),
+            storage.clone(),
+        );
+        wallet.set_mutation_journal_enabled(true);
+        wallet
+            .set_entity_flag(alice(), EntityFlag::DeletedByUser, true)
+            .unwrap();
+        let recorded = wallet.recorded_mutations();
+
+        storage.fail_nth_next_save(1);
+        assert_eq!(
+            wallet.undo_last(),
+            Err(CommonError::SecureStorageWriteError)
+        );
+        assert_eq!(wallet.recorded_mutations(), recorded);
+    }
+}
@@ -0,0 +1,7 @@
+mod offline_mode;
+mod wallet_capabilities;
+mod wallet_offline_mode;
+
+pub use offline_mode::*;
+pub use wallet_capabilities::*;
+pub use wallet_offline_mode::*;
@@ -0,0 +1,69 @@
+use crate::prelude::*;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Whether the Wallet Client has reported having no connectivity, shared
+/// between the [`Wallet`] and the Gateway clients created by it, so that
+/// APIs depending on the network fail fast with
+/// [`CommonError::FeatureUnavailableOffline`] instead of timing out.
+#[derive(Debug, Default)]
+pub struct OfflineMode(AtomicBool);
+
+impl OfflineMode {
+    pub fn is_enabled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+
+    /// Enables or disables offline mode, returning `true` if it changed.
+    pub fn set_enabled(&self, is_enabled: bool) -> bool {
+        self.0.swap(is_enabled, Ordering::SeqCst) != is_enabled
+    }
+
+    /// Throws [`CommonError::FeatureUnavailableOffline`] for `capability` if
+    /// offline mode is enabled.
+    pub fn ensure_available(&self, capability: WalletCapability) -> Result<()> {
+        if self.is_enabled() {
+            return Err(CommonError::FeatureUnavailableOffline(capability));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = OfflineMode;
+
+    #[test]
+    fn disabled_by_default() {
+        let sut = SUT::default();
+        assert!(!sut.is_enabled());
+        assert_eq!(
+            sut.ensure_available(WalletCapability::GatewayQueries),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn set_enabled_returns_if_changed() {
+        let sut = SUT::default();
+        assert!(sut.set_enabled(true));
+        assert!(!sut.set_enabled(true));
+        assert!(sut.is_enabled());
+        assert!(sut.set_enabled(false));
+        assert!(!sut.is_enabled());
+    }
+
+    #[test]
+    fn ensure_available_when_enabled() {
+        let sut = SUT::default();
+        sut.set_enabled(true);
+        assert_eq!(
+            sut.ensure_available(WalletCapability::DappVerification),
+            Err(CommonError::FeatureUnavailableOffline(
+                WalletCapability::DappVerification
+            ))
+        );
+    }
+}
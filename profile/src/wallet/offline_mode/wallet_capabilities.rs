@@ -0,0 +1,89 @@
+use crate::prelude::*;
+
+/// A feature of the wallet which depends on connectivity and is thus
+/// unavailable in offline mode.
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Hash, derive_more::Display, uniffi::Enum,
+)]
+pub enum WalletCapability {
+    /// Querying the Gateway, e.g. for account holdings, entity metadata or
+    /// the network status.
+    GatewayQueries,
+
+    /// Verifying a dApp against its website and dApp definition, previously
+    /// verified dApps are still available from cache.
+    DappVerification,
+}
+
+/// The features of the wallet currently available, see
+/// [`Wallet::capabilities`], so that Wallet Clients can grey out those which
+/// are not.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct WalletCapabilities {
+    pub is_offline: bool,
+    pub gateway_queries: bool,
+    pub dapp_verification: bool,
+}
+
+impl WalletCapabilities {
+    pub fn new(is_offline: bool) -> Self {
+        Self {
+            is_offline,
+            gateway_queries: !is_offline,
+            dapp_verification: !is_offline,
+        }
+    }
+
+    pub fn is_available(&self, capability: WalletCapability) -> bool {
+        match capability {
+            WalletCapability::GatewayQueries => self.gateway_queries,
+            WalletCapability::DappVerification => self.dapp_verification,
+        }
+    }
+}
+
+#[uniffi::export]
+pub fn wallet_capabilities_is_available(
+    capabilities: &WalletCapabilities,
+    capability: WalletCapability,
+) -> bool {
+    capabilities.is_available(capability)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = WalletCapabilities;
+
+    const ALL: [WalletCapability; 2] = [
+        WalletCapability::GatewayQueries,
+        WalletCapability::DappVerification,
+    ];
+
+    #[test]
+    fn online_has_all() {
+        let sut = SUT::new(false);
+        assert!(ALL.into_iter().all(|c| sut.is_available(c)));
+    }
+
+    #[test]
+    fn offline_has_none() {
+        let sut = SUT::new(true);
+        assert!(ALL.into_iter().all(|c| !sut.is_available(c)));
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn is_available() {
+        assert!(!wallet_capabilities_is_available(
+            &WalletCapabilities::new(true),
+            WalletCapability::GatewayQueries
+        ));
+    }
+}
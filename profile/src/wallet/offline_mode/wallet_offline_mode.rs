@@ -0,0 +1,132 @@
+use crate::prelude::*;
+
+//========
+// Offline mode
+//========
+#[uniffi::export]
+impl Wallet {
+    /// Enables - e.g. when the host reports no connectivity - or disables
+    /// offline mode, in which APIs depending on the network throw
+    /// [`CommonError::FeatureUnavailableOffline`] instead of timing out.
+    pub fn set_offline_mode(&self, is_offline: bool) {
+        if self.offline_mode.set_enabled(is_offline) {
+            log::info!("Offline mode changed, is offline: {}", is_offline);
+        }
+    }
+
+    pub fn is_offline(&self) -> bool {
+        self.offline_mode.is_enabled()
+    }
+
+    /// The features of the wallet currently available, so that Wallet
+    /// Clients can grey out those which are not.
+    pub fn capabilities(&self) -> WalletCapabilities {
+        WalletCapabilities::new(self.is_offline())
+    }
+}
+
+#[cfg(feature = "gateway")]
+#[uniffi::export]
+impl Wallet {
+    /// A [`DappVerifier`] using the current Gateway, which only uses cached
+    /// verifications while in offline mode.
+    pub fn dapp_verifier(
+        &self,
+        networking_driver: Arc<dyn NetworkingDriver>,
+        cache_ttl_seconds: u64,
+    ) -> Arc<DappVerifier> {
+        Arc::new(
+            DappVerifier::new(
+                networking_driver,
+                self.current_gateway(),
+                cache_ttl_seconds,
+            )
+            .with_offline_mode(self.offline_mode.clone()),
+        )
+    }
+}
+
+#[cfg(feature = "gateway")]
+impl Wallet {
+    /// A [`GatewayClient`] using the current Gateway, failing all requests
+    /// while in offline mode.
+    pub fn gateway_client(
+        &self,
+        networking_driver: Arc<dyn NetworkingDriver>,
+    ) -> GatewayClient {
+        GatewayClient::new(networking_driver, self.current_gateway())
+            .with_offline_mode(self.offline_mode.clone())
+    }
+
+    fn current_gateway(&self) -> Gateway {
+        self.access_profile_with(|p| p.app_preferences.gateways.current.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn online_by_default() {
+        let sut = Wallet::placeholder();
+        assert!(!sut.is_offline());
+        assert_eq!(sut.capabilities(), WalletCapabilities::new(false));
+    }
+
+    #[test]
+    fn set_offline_mode() {
+        let sut = Wallet::placeholder();
+        sut.set_offline_mode(true);
+        assert!(sut.is_offline());
+        assert_eq!(sut.capabilities(), WalletCapabilities::new(true));
+        sut.set_offline_mode(false);
+        assert!(!sut.is_offline());
+    }
+
+    #[cfg(feature = "gateway")]
+    #[test]
+    fn gateway_client_fails_fast_offline() {
+        let sut = Wallet::placeholder();
+        let driver = Arc::new(MockNetworkingDriver::default());
+        let client = sut.gateway_client(driver.clone());
+        sut.set_offline_mode(true);
+        assert_eq!(
+            client.network_status(),
+            Err(CommonError::FeatureUnavailableOffline(
+                WalletCapability::GatewayQueries
+            ))
+        );
+        assert!(driver.requests().is_empty());
+    }
+
+    #[cfg(feature = "gateway")]
+    #[test]
+    fn gateway_client_uses_current_gateway() {
+        let sut = Wallet::placeholder();
+        let driver = Arc::new(MockNetworkingDriver::default());
+        assert_eq!(
+            sut.gateway_client(driver).gateway(),
+            &sut.profile().app_preferences.gateways.current
+        );
+    }
+
+    #[cfg(feature = "gateway")]
+    #[test]
+    fn dapp_verifier_fails_fast_offline() {
+        let sut = Wallet::placeholder();
+        let driver = Arc::new(MockNetworkingDriver::default());
+        let verifier = sut.dapp_verifier(driver.clone(), 60);
+        sut.set_offline_mode(true);
+        assert_eq!(
+            verifier.verify(
+                Url::parse("https://dashboard.radixdlt.com").unwrap(),
+                AccountAddress::placeholder_mainnet()
+            ),
+            Err(CommonError::FeatureUnavailableOffline(
+                WalletCapability::DappVerification
+            ))
+        );
+        assert!(driver.requests().is_empty());
+    }
+}
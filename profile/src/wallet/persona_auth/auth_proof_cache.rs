@@ -0,0 +1,165 @@
+use crate::prelude::*;
+
+/// Identifies a login proof: the same persona signing the same challenge
+/// for the same dApp always produces an equivalent proof.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct AuthProofCacheKey {
+    pub(crate) identity_address: IdentityAddress,
+    pub(crate) metadata: DappAuthMetadata,
+    pub(crate) challenge: Hex32Bytes,
+}
+
+/// Recently produced login proofs, so that a dApp repeating a request during
+/// one interaction does not cause the user to be prompted - e.g. by their
+/// Ledger - to sign the same challenge again.
+///
+/// Proofs are only reused for [`AuthProofCache::MAX_AGE_SECONDS`], challenges
+/// are short lived anyway.
+#[derive(Debug, Default)]
+pub(crate) struct AuthProofCache {
+    is_disabled: bool,
+    proofs: HashMap<
+        AuthProofCacheKey,
+        (Timestamp, WalletToDappInteractionAuthProof),
+    >,
+}
+
+impl AuthProofCache {
+    pub(crate) const MAX_AGE_SECONDS: i64 = 300;
+
+    /// Enables or disables caching, disabling it clears the cache.
+    pub(crate) fn set_enabled(&mut self, is_enabled: bool) {
+        self.is_disabled = !is_enabled;
+        if !is_enabled {
+            self.clear();
+        }
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.proofs.clear();
+    }
+
+    /// The proof cached for `key`, if it is not older than
+    /// [`Self::MAX_AGE_SECONDS`] as of `now`.
+    pub(crate) fn get(
+        &self,
+        key: &AuthProofCacheKey,
+        now: &Timestamp,
+    ) -> Option<WalletToDappInteractionAuthProof> {
+        self.proofs
+            .get(key)
+            .filter(|(at, _)| now.seconds_since(at) <= Self::MAX_AGE_SECONDS)
+            .map(|(_, proof)| proof.clone())
+    }
+
+    /// Caches `proof` for `key` - unless caching is disabled - evicting all
+    /// expired proofs.
+    pub(crate) fn insert(
+        &mut self,
+        key: AuthProofCacheKey,
+        proof: WalletToDappInteractionAuthProof,
+        now: Timestamp,
+    ) {
+        if self.is_disabled {
+            return;
+        }
+        self.proofs.retain(|_, (at, _)| {
+            now.seconds_since(at) <= Self::MAX_AGE_SECONDS
+        });
+        self.proofs.insert(key, (now, proof));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn timestamp(seconds: u64) -> Timestamp {
+        Timestamp::parse(format!(
+            "2023-09-11T16:{:02}:{:02}.000Z",
+            seconds / 60,
+            seconds % 60
+        ))
+        .unwrap()
+    }
+
+    fn key() -> AuthProofCacheKey {
+        AuthProofCacheKey {
+            identity_address: IdentityAddress::placeholder(),
+            metadata: DappAuthMetadata::placeholder(),
+            challenge: Hex32Bytes::placeholder(),
+        }
+    }
+
+    #[test]
+    fn get_cached() {
+        let mut sut = AuthProofCache::default();
+        let proof = WalletToDappInteractionAuthProof::placeholder();
+        sut.insert(key(), proof.clone(), timestamp(0));
+        assert_eq!(sut.get(&key(), &timestamp(300)), Some(proof));
+    }
+
+    #[test]
+    fn get_expired() {
+        let mut sut = AuthProofCache::default();
+        sut.insert(
+            key(),
+            WalletToDappInteractionAuthProof::placeholder(),
+            timestamp(0),
+        );
+        assert_eq!(sut.get(&key(), &timestamp(301)), None);
+    }
+
+    #[test]
+    fn get_other_challenge() {
+        let mut sut = AuthProofCache::default();
+        sut.insert(
+            key(),
+            WalletToDappInteractionAuthProof::placeholder(),
+            timestamp(0),
+        );
+        let other = AuthProofCacheKey {
+            challenge: Hex32Bytes::placeholder_other(),
+            ..key()
+        };
+        assert_eq!(sut.get(&other, &timestamp(0)), None);
+    }
+
+    #[test]
+    fn insert_evicts_expired() {
+        let mut sut = AuthProofCache::default();
+        sut.insert(
+            key(),
+            WalletToDappInteractionAuthProof::placeholder(),
+            timestamp(0),
+        );
+        let other = AuthProofCacheKey {
+            challenge: Hex32Bytes::placeholder_other(),
+            ..key()
+        };
+        sut.insert(
+            other,
+            WalletToDappInteractionAuthProof::placeholder_other(),
+            timestamp(400),
+        );
+        assert_eq!(sut.proofs.len(), 1);
+    }
+
+    #[test]
+    fn disabled_does_not_cache() {
+        let mut sut = AuthProofCache::default();
+        sut.insert(
+            key(),
+            WalletToDappInteractionAuthProof::placeholder(),
+            timestamp(0),
+        );
+        sut.set_enabled(false);
+        assert_eq!(sut.get(&key(), &timestamp(0)), None);
+        sut.insert(
+            key(),
+            WalletToDappInteractionAuthProof::placeholder(),
+            timestamp(0),
+        );
+        assert_eq!(sut.get(&key(), &timestamp(0)), None);
+    }
+}
@@ -0,0 +1,5 @@
+mod auth_proof_cache;
+mod wallet_persona_auth;
+
+pub use auth_proof_cache::*;
+pub use wallet_persona_auth::*;
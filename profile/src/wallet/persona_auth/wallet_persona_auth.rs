@@ -0,0 +1,363 @@
+use crate::prelude::*;
+
+//========
+// Persona Login
+//========
+#[uniffi::export]
+impl Wallet {
+    /// Proves ownership of the Persona with `identity_address` to the dApp
+    /// described by `metadata` by signing its `challenge` as per ROLA, using
+    /// the authentication signing key of the Persona.
+    ///
+    /// If the Persona has no authentication signing key yet, one is derived
+    /// from the factor source which created the Persona - at the same index -
+    /// and saved into Profile.
+    ///
+    /// Proofs are cached for a few minutes per Persona, dApp and challenge,
+    /// so that a dApp repeating its request during one interaction gets the
+    /// same proof back without the user being prompted again.
+    pub fn sign_auth_for_persona(
+        &self,
+        identity_address: IdentityAddress,
+        challenge: Hex32Bytes,
+        metadata: DappAuthMetadata,
+    ) -> Result<WalletToDappInteractionAuthProof> {
+        let key = AuthProofCacheKey {
+            identity_address: identity_address.clone(),
+            metadata: metadata.clone(),
+            challenge: challenge.clone(),
+        };
+        let now = Timestamp::now();
+        if let Some(proof) =
+            self.access_auth_proof_cache_with(|c| c.get(&key, &now))
+        {
+            return Ok(proof);
+        }
+
+        let auth_signing_key =
+            self.load_or_create_auth_signing_private_key(&identity_address)?;
+        let proof: WalletToDappInteractionAuthProof = auth_signing_key
+            .sign(&metadata.rola_payload_hash(&challenge))
+            .into();
+
+        self.update_auth_proof_cache_with(|c| {
+            c.insert(key.clone(), proof.clone(), now)
+        });
+        Ok(proof)
+    }
+
+    /// Enables or disables caching of login proofs, disabling it also clears
+    /// all cached proofs. Caching is enabled by default.
+    pub fn set_auth_proof_caching_enabled(&self, is_enabled: bool) {
+        self.update_auth_proof_cache_with(|c| c.set_enabled(is_enabled))
+    }
+
+    /// Clears all cached login proofs, e.g. when a dApp interaction ends.
+    pub fn clear_auth_proof_cache(&self) {
+        self.update_auth_proof_cache_with(|c| c.clear())
+    }
+}
+
+impl Wallet {
+    /// Derives the private key of the authentication signing factor instance
+    /// of the Persona, creating and saving the factor instance if the
+    /// Persona does not have one yet.
+    fn load_or_create_auth_signing_private_key(
+        &self,
+        identity_address: &IdentityAddress,
+    ) -> Result<PrivateKey> {
+        let persona = self
+            .access_profile_with(|p| p.networks.get_persona(identity_address))
+            .ok_or(CommonError::UnknownPersona)?;
+        let EntitySecurityState::Unsecured { value: control } =
+            persona.security_state
+        else {
+            return Err(CommonError::EntityIsWatchOnly(
+                identity_address.to_string(),
+            ));
+        };
+
+        let private_hd_factor_source = self
+            .load_private_device_factor_source_by_id(
+                &control.transaction_signing.factor_source_id,
+            )?;
+        let mnemonic_with_passphrase =
+            &private_hd_factor_source.mnemonic_with_passphrase;
+
+        if let Some(authentication_signing) = control.authentication_signing {
+            return Ok(mnemonic_with_passphrase
+                .derive_private_key(authentication_signing.derivation_path())
+                .private_key);
+        }
+
+        let transaction_signing_path =
+            match control.transaction_signing.derivation_path() {
+                DerivationPath::CAP26 {
+                    value: CAP26Path::Identity { value },
+                } => Ok(value),
+                _ => Err(CommonError::WrongEntityKindOfInFactorInstancesPath),
+            }?;
+        let hd_private_key =
+            mnemonic_with_passphrase.derive_private_key(IdentityPath::new(
+                transaction_signing_path.network_id(),
+                CAP26KeyKind::AuthenticationSigning,
+                transaction_signing_path.index(),
+            ));
+        let authentication_signing =
+            HierarchicalDeterministicFactorInstance::new(
+                private_hd_factor_source.factor_source.id.clone(),
+                hd_private_key.public_key(),
+            );
+        let control = UnsecuredEntityControl::new(
+            control.transaction_signing,
+            Some(authentication_signing),
+        )?;
+
+        self.try_update_profile_with(|mut p| {
+            p.update_persona(identity_address, |persona| {
+                persona.security_state = EntitySecurityState::Unsecured {
+                    value: control.clone(),
+                }
+            })
+            .ok_or(CommonError::UnknownPersona)
+        })?;
+
+        Ok(hd_private_key.private_key)
+    }
+
+    fn access_auth_proof_cache_with<T, F>(&self, access: F) -> T
+    where
+        F: Fn(&AuthProofCache) -> T,
+    {
+        access(
+            &self
+                .auth_proof_cache
+                .read()
+                .expect("Auth proof cache lock should not be poisoned"),
+        )
+    }
+
+    fn update_auth_proof_cache_with<F>(&self, mutate: F)
+    where
+        F: Fn(&mut AuthProofCache),
+    {
+        mutate(
+            &mut self
+                .auth_proof_cache
+                .write()
+                .expect("Auth proof cache lock should not be poisoned"),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use radix_engine_common::crypto::Hash;
+
+    fn make_wallet() -> Wallet {
+        let (wallet, storage) = Wallet::ephemeral(Profile::placeholder());
+        let private =
+            PrivateHierarchicalDeterministicFactorSource::placeholder();
+        let data =
+            serde_json::to_vec(&private.mnemonic_with_passphrase).unwrap();
+        let key = SecureStorageKey::DeviceFactorSourceMnemonic {
+            factor_source_id: private.factor_source.id.clone(),
+        };
+        storage.save_data(key, data).unwrap();
+        wallet
+    }
+
+    fn auth_signing(
+        wallet: &Wallet,
+    ) -> Option<HierarchicalDeterministicFactorInstance> {
+        let persona = wallet
+            .access_profile_with(|p| {
+                p.networks.get_persona(
+                    &Persona::placeholder_mainnet_satoshi().address,
+                )
+            })
+            .unwrap();
+        persona
+            .security_state
+            .as_unsecured()
+            .and_then(|c| c.authentication_signing.clone())
+    }
+
+    fn is_signature_of(
+        proof: &WalletToDappInteractionAuthProof,
+        hash: &Hash,
+    ) -> bool {
+        Signature::from_bytes(proof.curve(), &proof.signature)
+            .and_then(|s| {
+                SignatureWithPublicKey::new(proof.public_key.clone(), s)
+            })
+            .map(|s| s.is_valid_for(hash))
+            .unwrap_or(false)
+    }
+
+    fn is_valid(
+        proof: &WalletToDappInteractionAuthProof,
+        challenge: &Hex32Bytes,
+        metadata: &DappAuthMetadata,
+    ) -> bool {
+        is_signature_of(proof, &metadata.rola_payload_hash(challenge))
+    }
+
+    #[test]
+    fn creates_auth_signing_key_and_signs() {
+        let wallet = make_wallet();
+        assert_eq!(auth_signing(&wallet), None);
+
+        let proof = wallet
+            .sign_auth_for_persona(
+                Persona::placeholder_mainnet_satoshi().address,
+                Hex32Bytes::placeholder(),
+                DappAuthMetadata::placeholder(),
+            )
+            .unwrap();
+
+        let auth_signing = auth_signing(&wallet).unwrap();
+        assert_eq!(
+            auth_signing.key_kind(),
+            Some(CAP26KeyKind::AuthenticationSigning)
+        );
+        assert_eq!(proof.public_key, auth_signing.public_key.public_key);
+        assert!(is_valid(
+            &proof,
+            &Hex32Bytes::placeholder(),
+            &DappAuthMetadata::placeholder()
+        ));
+    }
+
+    #[test]
+    fn reuses_auth_signing_key() {
+        let wallet = make_wallet();
+        let address = Persona::placeholder_mainnet_satoshi().address;
+        let first = wallet
+            .sign_auth_for_persona(
+                address.clone(),
+                Hex32Bytes::placeholder(),
+                DappAuthMetadata::placeholder(),
+            )
+            .unwrap();
+        let auth_signing_after_first = auth_signing(&wallet);
+        let second = wallet
+            .sign_auth_for_persona(
+                address,
+                Hex32Bytes::placeholder_other(),
+                DappAuthMetadata::placeholder(),
+            )
+            .unwrap();
+        assert_eq!(auth_signing(&wallet), auth_signing_after_first);
+        assert_eq!(first.public_key, second.public_key);
+        assert!(is_valid(
+            &second,
+            &Hex32Bytes::placeholder_other(),
+            &DappAuthMetadata::placeholder()
+        ));
+    }
+
+    #[test]
+    fn proof_is_cached() {
+        let wallet = make_wallet();
+        let address = Persona::placeholder_mainnet_satoshi().address;
+        let sign = || {
+            wallet
+                .sign_auth_for_persona(
+                    address.clone(),
+                    Hex32Bytes::placeholder(),
+                    DappAuthMetadata::placeholder(),
+                )
+                .unwrap()
+        };
+        let first = sign();
+        // Make sure a fresh signature would fail the comparison, by
+        // tampering with the cached proof.
+        let tampered = WalletToDappInteractionAuthProof::new(
+            first.public_key.clone(),
+            BagOfBytes::placeholder(),
+        );
+        wallet.update_auth_proof_cache_with(|c| {
+            c.insert(
+                AuthProofCacheKey {
+                    identity_address: address.clone(),
+                    metadata: DappAuthMetadata::placeholder(),
+                    challenge: Hex32Bytes::placeholder(),
+                },
+                tampered.clone(),
+                Timestamp::now(),
+            )
+        });
+        assert_eq!(sign(), tampered);
+
+        wallet.clear_auth_proof_cache();
+        assert_eq!(sign(), first);
+    }
+
+    #[test]
+    fn caching_disabled() {
+        let wallet = make_wallet();
+        wallet.set_auth_proof_caching_enabled(false);
+        let address = Persona::placeholder_mainnet_satoshi().address;
+        wallet
+            .sign_auth_for_persona(
+                address.clone(),
+                Hex32Bytes::placeholder(),
+                DappAuthMetadata::placeholder(),
+            )
+            .unwrap();
+        assert!(wallet.access_auth_proof_cache_with(|c| c
+            .get(
+                &AuthProofCacheKey {
+                    identity_address: address.clone(),
+                    metadata: DappAuthMetadata::placeholder(),
+                    challenge: Hex32Bytes::placeholder(),
+                },
+                &Timestamp::now()
+            )
+            .is_none()));
+    }
+
+    #[test]
+    fn unknown_persona() {
+        let wallet = make_wallet();
+        assert_eq!(
+            wallet.sign_auth_for_persona(
+                "identity_rdx12tgzjrz9u0xz4l28vf04hz87eguclmfaq4d2p8f8lv7zg9ssnzku8j"
+                    .parse()
+                    .unwrap(),
+                Hex32Bytes::placeholder(),
+                DappAuthMetadata::placeholder(),
+            ),
+            Err(CommonError::UnknownPersona)
+        );
+    }
+
+    #[test]
+    fn missing_mnemonic() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        assert!(wallet
+            .sign_auth_for_persona(
+                Persona::placeholder_mainnet_satoshi().address,
+                Hex32Bytes::placeholder(),
+                DappAuthMetadata::placeholder(),
+            )
+            .is_err());
+        assert_eq!(auth_signing(&wallet), None);
+    }
+
+    #[test]
+    fn signature_is_of_rola_payload_hash() {
+        let wallet = make_wallet();
+        let proof = wallet
+            .sign_auth_for_persona(
+                Persona::placeholder_mainnet_satoshi().address,
+                Hex32Bytes::placeholder(),
+                DappAuthMetadata::placeholder(),
+            )
+            .unwrap();
+        let other_hash: Hash = hash("not the rola payload");
+        assert!(!is_signature_of(&proof, &other_hash));
+    }
+}
@@ -0,0 +1,26 @@
+use crate::prelude::*;
+
+/// A key and the bytes to save for it, one of the entries of an atomic save.
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Record)]
+pub struct SecureStorageEntry {
+    pub key: SecureStorageKey,
+    pub data: Vec<u8>,
+}
+
+impl SecureStorageEntry {
+    pub fn new(key: SecureStorageKey, data: Vec<u8>) -> Self {
+        Self { key, data }
+    }
+}
+
+/// Optional capability of a Wallet Client's secure storage, registered with
+/// `Wallet::register_atomic_secure_storage`, able to save several entries
+/// all-or-nothing, e.g. using a keychain or database transaction.
+///
+/// If none is registered, `WalletClientStorage` falls back to a journal kept
+/// in `SecureStorage` under `SecureStorageKey::TransactionJournal`.
+#[uniffi::export]
+pub trait AtomicSecureStorage: Send + Sync + std::fmt::Debug {
+    /// Saves all `entries` or - if any save fails - none of them.
+    fn save_many_data(&self, entries: Vec<SecureStorageEntry>) -> Result<()>;
+}
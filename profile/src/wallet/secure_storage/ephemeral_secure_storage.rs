@@ -1,4 +1,4 @@
-#![cfg(test)]
+#![cfg(any(test, feature = "test-utils"))]
 use crate::prelude::*;
 
 use std::sync::RwLock;
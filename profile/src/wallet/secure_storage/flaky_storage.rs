@@ -0,0 +1,258 @@
+#![cfg(any(test, feature = "test-utils"))]
+
+use crate::prelude::*;
+
+use std::sync::RwLock;
+use std::time::Duration;
+
+/// The failures a [`FlakyStorage`] has been scripted to simulate.
+#[derive(Debug, Default)]
+struct FlakyStorageScript {
+    /// Numbers (one based) of the saves which fail.
+    failing_saves: HashSet<usize>,
+
+    /// Keys for which reads return bytes which cannot be decoded.
+    corrupted_reads: HashSet<SecureStorageKey>,
+
+    /// Keys whose deletes fail.
+    failing_deletes: HashSet<SecureStorageKey>,
+
+    /// Delay of every operation, simulating a slow storage.
+    delay: Option<Duration>,
+}
+
+/// Used for testing - a [`SecureStorage`] delegating to `inner` which can be
+/// scripted to fail specific saves, return corrupted data on reads or
+/// respond slowly, used to test how the wallet handles failures, e.g.
+/// rolling back partial changes.
+#[derive(Debug)]
+pub struct FlakyStorage {
+    inner: Arc<dyn SecureStorage>,
+    script: RwLock<FlakyStorageScript>,
+    save_count: RwLock<usize>,
+}
+
+impl FlakyStorage {
+    /// The bytes returned by reads of keys scripted with `corrupt_reads_of`.
+    pub const CORRUPTED_BYTES: [u8; 4] = [0xde, 0xad, 0xbe, 0xef];
+
+    pub fn new(inner: Arc<dyn SecureStorage>) -> Arc<Self> {
+        Arc::new(Self {
+            inner,
+            script: RwLock::new(FlakyStorageScript::default()),
+            save_count: RwLock::new(0),
+        })
+    }
+
+    /// A `FlakyStorage` saving into memory.
+    pub fn ephemeral() -> Arc<Self> {
+        Self::new(EphemeralSecureStorage::new())
+    }
+
+    /// The `n`th save from now on fails with
+    /// [`CommonError::SecureStorageWriteError`], `1` being the next save.
+    pub fn fail_nth_next_save(&self, n: usize) {
+        assert!(n > 0, "Save numbers are one based");
+        let nth = self.save_count() + n;
+        self.script.write().unwrap().failing_saves.insert(nth);
+    }
+
+    /// Reads of `key` return [`Self::CORRUPTED_BYTES`] - if data is saved
+    /// for `key` - instead of the saved data.
+    pub fn corrupt_reads_of(&self, key: SecureStorageKey) {
+        self.script.write().unwrap().corrupted_reads.insert(key);
+    }
+
+    /// Deletes of `key` fail with [`CommonError::SecureStorageWriteError`].
+    pub fn fail_deletes_of(&self, key: SecureStorageKey) {
+        self.script.write().unwrap().failing_deletes.insert(key);
+    }
+
+    /// Every operation blocks for `delay` before it is executed.
+    pub fn respond_slowly(&self, delay: Duration) {
+        self.script.write().unwrap().delay = Some(delay);
+    }
+
+    /// Removes all scripted failures, the count of saves is kept.
+    pub fn reset_script(&self) {
+        *self.script.write().unwrap() = FlakyStorageScript::default();
+    }
+
+    /// Number of saves attempted, including the failed ones.
+    pub fn save_count(&self) -> usize {
+        *self.save_count.read().unwrap()
+    }
+
+    fn wait(&self) {
+        if let Some(delay) = self.script.read().unwrap().delay {
+            std::thread::sleep(delay);
+        }
+    }
+}
+
+impl SecureStorage for FlakyStorage {
+    fn load_data(&self, key: SecureStorageKey) -> Result<Option<Vec<u8>>> {
+        self.wait();
+        let is_corrupted =
+            self.script.read().unwrap().corrupted_reads.contains(&key);
+        self.inner.load_data(key).map(|data| {
+            data.map(|d| {
+                if is_corrupted {
+                    Self::CORRUPTED_BYTES.to_vec()
+                } else {
+                    d
+                }
+            })
+        })
+    }
+
+    fn save_data(&self, key: SecureStorageKey, data: Vec<u8>) -> Result<()> {
+        self.wait();
+        let nth = {
+            let mut save_count = self.save_count.write().unwrap();
+            *save_count += 1;
+            *save_count
+        };
+        if self.script.read().unwrap().failing_saves.contains(&nth) {
+            return Err(CommonError::SecureStorageWriteError);
+        }
+        self.inner.save_data(key, data)
+    }
+
+    fn delete_data_for_key(&self, key: SecureStorageKey) -> Result<()> {
+        self.wait();
+        if self.script.read().unwrap().failing_deletes.contains(&key) {
+            return Err(CommonError::SecureStorageWriteError);
+        }
+        self.inner.delete_data_for_key(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use std::time::{Duration, Instant};
+
+    fn data() -> Vec<u8> {
+        vec![1, 2, 3]
+    }
+
+    #[test]
+    fn delegates_when_not_scripted() {
+        let sut = FlakyStorage::ephemeral();
+        let key = SecureStorageKey::HomeCards;
+        assert_eq!(sut.save_data(key.clone(), data()), Ok(()));
+        assert_eq!(sut.load_data(key.clone()), Ok(Some(data())));
+        assert_eq!(sut.delete_data_for_key(key.clone()), Ok(()));
+        assert_eq!(sut.load_data(key), Ok(None));
+    }
+
+    #[test]
+    fn fail_nth_next_save() {
+        let sut = FlakyStorage::ephemeral();
+        assert_eq!(sut.save_data(SecureStorageKey::HomeCards, data()), Ok(()));
+        sut.fail_nth_next_save(2);
+        assert_eq!(
+            sut.save_data(SecureStorageKey::BackupState, data()),
+            Ok(())
+        );
+        assert_eq!(
+            sut.save_data(SecureStorageKey::ActiveProfileID, data()),
+            Err(CommonError::SecureStorageWriteError)
+        );
+        assert_eq!(
+            sut.save_data(SecureStorageKey::ActiveProfileID, data()),
+            Ok(())
+        );
+        assert_eq!(sut.save_count(), 4);
+    }
+
+    #[test]
+    fn failed_save_does_not_write() {
+        let sut = FlakyStorage::ephemeral();
+        sut.fail_nth_next_save(1);
+        assert!(sut.save_data(SecureStorageKey::HomeCards, data()).is_err());
+        assert_eq!(sut.load_data(SecureStorageKey::HomeCards), Ok(None));
+    }
+
+    #[test]
+    #[should_panic(expected = "Save numbers are one based")]
+    fn fail_zeroth_save_panics() {
+        FlakyStorage::ephemeral().fail_nth_next_save(0);
+    }
+
+    #[test]
+    fn corrupt_reads_of() {
+        let sut = FlakyStorage::ephemeral();
+        sut.corrupt_reads_of(SecureStorageKey::HomeCards);
+        assert_eq!(sut.load_data(SecureStorageKey::HomeCards), Ok(None));
+        assert_eq!(sut.save_data(SecureStorageKey::HomeCards, data()), Ok(()));
+        assert_eq!(
+            sut.save_data(SecureStorageKey::BackupState, data()),
+            Ok(())
+        );
+        assert_eq!(
+            sut.load_data(SecureStorageKey::HomeCards),
+            Ok(Some(FlakyStorage::CORRUPTED_BYTES.to_vec()))
+        );
+        assert_eq!(
+            sut.load_data(SecureStorageKey::BackupState),
+            Ok(Some(data()))
+        );
+    }
+
+    #[test]
+    fn fail_deletes_of() {
+        let sut = FlakyStorage::ephemeral();
+        sut.fail_deletes_of(SecureStorageKey::HomeCards);
+        assert_eq!(sut.save_data(SecureStorageKey::HomeCards, data()), Ok(()));
+        assert_eq!(
+            sut.delete_data_for_key(SecureStorageKey::HomeCards),
+            Err(CommonError::SecureStorageWriteError)
+        );
+        assert_eq!(
+            sut.load_data(SecureStorageKey::HomeCards),
+            Ok(Some(data()))
+        );
+        assert_eq!(
+            sut.delete_data_for_key(SecureStorageKey::BackupState),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn respond_slowly() {
+        let sut = FlakyStorage::ephemeral();
+        sut.respond_slowly(Duration::from_millis(5));
+        let start = Instant::now();
+        _ = sut.load_data(SecureStorageKey::HomeCards);
+        assert!(start.elapsed() >= Duration::from_millis(5));
+    }
+
+    #[test]
+    fn reset_script() {
+        let sut = FlakyStorage::ephemeral();
+        sut.fail_nth_next_save(1);
+        sut.corrupt_reads_of(SecureStorageKey::HomeCards);
+        sut.reset_script();
+        assert_eq!(sut.save_data(SecureStorageKey::HomeCards, data()), Ok(()));
+        assert_eq!(
+            sut.load_data(SecureStorageKey::HomeCards),
+            Ok(Some(data()))
+        );
+    }
+
+    #[test]
+    fn corrupted_profile_fails_to_load() {
+        let storage = FlakyStorage::ephemeral();
+        let profile = Profile::placeholder();
+        drop(Wallet::by_importing_profile(
+            profile.clone(),
+            storage.clone(),
+        ));
+        storage.corrupt_reads_of(SecureStorageKey::ProfileSnapshot {
+            profile_id: profile.id(),
+        });
+        assert!(Wallet::by_loading_profile(storage).is_err());
+    }
+}
@@ -1,17 +1,27 @@
+mod atomic_secure_storage;
 mod secure_storage;
+mod secure_storage_journal;
 mod secure_storage_key;
 mod wallet_client_storage;
 
+pub use atomic_secure_storage::*;
 pub use secure_storage::*;
+pub use secure_storage_journal::*;
 pub use secure_storage_key::*;
 pub use wallet_client_storage::*;
 
-#[cfg(test)]
+#[cfg(any(test, feature = "test-utils"))]
 mod ephemeral_secure_storage;
 
-#[cfg(test)]
+#[cfg(any(test, feature = "test-utils"))]
 pub use ephemeral_secure_storage::*;
 
+#[cfg(any(test, feature = "test-utils"))]
+mod flaky_storage;
+
+#[cfg(any(test, feature = "test-utils"))]
+pub use flaky_storage::*;
+
 #[cfg(test)]
 mod always_fail_storage;
 
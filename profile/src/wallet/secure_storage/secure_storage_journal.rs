@@ -0,0 +1,58 @@
+use crate::prelude::*;
+
+/// The value a key had before a transaction of `WalletClientStorage`
+/// started, `None` if no value was saved for the key.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SecureStorageJournalEntry {
+    pub key: SecureStorageKey,
+    pub previous: Option<Vec<u8>>,
+}
+
+impl SecureStorageJournalEntry {
+    pub fn new(key: SecureStorageKey, previous: Option<Vec<u8>>) -> Self {
+        Self { key, previous }
+    }
+}
+
+/// Saved under `SecureStorageKey::TransactionJournal` before the entries
+/// of a transaction are saved and deleted once all of them are saved, so
+/// that a transaction interrupted half way - e.g. by the app being killed -
+/// can be rolled back when the Wallet is next loaded.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SecureStorageJournal {
+    pub entries: Vec<SecureStorageJournalEntry>,
+}
+
+impl SecureStorageJournal {
+    pub fn new(entries: Vec<SecureStorageJournalEntry>) -> Self {
+        Self { entries }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn json_roundtrip() {
+        let sut = SecureStorageJournal::new(vec![
+            SecureStorageJournalEntry::new(
+                SecureStorageKey::HomeCards,
+                Some(vec![0xde, 0xad]),
+            ),
+            SecureStorageJournalEntry::new(
+                SecureStorageKey::ProfileSnapshot {
+                    profile_id: ProfileID::placeholder(),
+                },
+                None,
+            ),
+        ]);
+        let json = serde_json::to_vec(&sut).unwrap();
+        assert_eq!(
+            serde_json::from_slice::<SecureStorageJournal>(&json).unwrap(),
+            sut
+        );
+    }
+}
@@ -1,6 +1,8 @@
 use crate::prelude::*;
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, uniffi::Enum)]
+#[derive(
+    Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, uniffi::Enum,
+)]
 pub enum SecureStorageKey {
     SnapshotHeadersList,
     ActiveProfileID,
@@ -10,6 +12,14 @@ pub enum SecureStorageKey {
     ProfileSnapshot {
         profile_id: ProfileID,
     },
+    LastKnownGoodProfileSnapshot {
+        profile_id: ProfileID,
+    },
+    HomeCards,
+    SecurityCenterState,
+    BackupState,
+    TransactionJournal,
+    PendingWalletInteractions,
 }
 impl SecureStorageKey {
     #[cfg(not(tarpaulin_include))] // false negative
@@ -25,6 +35,17 @@ impl SecureStorageKey {
                 } => format!("device_factor_source_{}", factor_source_id),
                 SecureStorageKey::ProfileSnapshot { profile_id } =>
                     format!("profile_snapshot_{}", profile_id),
+                SecureStorageKey::LastKnownGoodProfileSnapshot {
+                    profile_id,
+                } => format!("last_known_good_profile_snapshot_{}", profile_id),
+                SecureStorageKey::HomeCards => "home_cards".to_string(),
+                SecureStorageKey::SecurityCenterState =>
+                    "security_center_state".to_string(),
+                SecureStorageKey::BackupState => "backup_state".to_string(),
+                SecureStorageKey::TransactionJournal =>
+                    "transaction_journal".to_string(),
+                SecureStorageKey::PendingWalletInteractions =>
+                    "pending_wallet_interactions".to_string(),
             }
         )
     }
@@ -63,6 +84,33 @@ mod tests {
             .identifier(),
             "secure_storage_key_profile_snapshot_ffffffff-ffff-ffff-ffff-ffffffffffff"
         );
+        assert_eq!(
+            SecureStorageKey::LastKnownGoodProfileSnapshot {
+                profile_id: ProfileID::placeholder()
+            }
+            .identifier(),
+            "secure_storage_key_last_known_good_profile_snapshot_ffffffff-ffff-ffff-ffff-ffffffffffff"
+        );
+        assert_eq!(
+            SecureStorageKey::HomeCards.identifier(),
+            "secure_storage_key_home_cards"
+        );
+        assert_eq!(
+            SecureStorageKey::SecurityCenterState.identifier(),
+            "secure_storage_key_security_center_state"
+        );
+        assert_eq!(
+            SecureStorageKey::BackupState.identifier(),
+            "secure_storage_key_backup_state"
+        );
+        assert_eq!(
+            SecureStorageKey::TransactionJournal.identifier(),
+            "secure_storage_key_transaction_journal"
+        );
+        assert_eq!(
+            SecureStorageKey::PendingWalletInteractions.identifier(),
+            "secure_storage_key_pending_wallet_interactions"
+        );
     }
 }
 
@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use std::sync::RwLock;
 
 /// An abstraction of an implementing WalletClients's secure storage, used by `Wallet` to
 /// save and load models, most prominently `Profile` and `MnemonicWithPassphrase`.
@@ -14,13 +15,117 @@ use crate::prelude::*;
 pub struct WalletClientStorage {
     /// Low level CRUD traits injected from implementing Wallet Client, that works on bytes.
     interface: Arc<dyn SecureStorage>,
+
+    /// Used by `save_many` if registered, else a journal is used.
+    atomic_interface: RwLock<Option<Arc<dyn AtomicSecureStorage>>>,
+
+    /// Used to encrypt and decrypt Profile snapshots, if registered.
+    hardware_key_driver: RwLock<Option<Arc<dyn HardwareKeyDriver>>>,
+
+    /// The alias of the hardware key Profile snapshots are encrypted with,
+    /// `None` if they are saved in plaintext.
+    hardware_key_alias: RwLock<Option<String>>,
+
+    /// `true` once the wallet has been deleted, after which every save
+    /// fails with `WalletDeleted`.
+    is_deleted: RwLock<bool>,
 }
 
 impl WalletClientStorage {
     /// Creates a new WalletClientStorage using an implementation of
     /// `SecureStorage`.
     pub(crate) fn new(interface: Arc<dyn SecureStorage>) -> Self {
-        Self { interface }
+        Self {
+            interface,
+            atomic_interface: RwLock::new(None),
+            hardware_key_driver: RwLock::new(None),
+            hardware_key_alias: RwLock::new(None),
+            is_deleted: RwLock::new(false),
+        }
+    }
+
+    /// Makes `save_many` delegate to `atomic_interface`, or to fall back to
+    /// a journal if `None`.
+    pub(crate) fn set_atomic_interface(
+        &self,
+        atomic_interface: Option<Arc<dyn AtomicSecureStorage>>,
+    ) {
+        *self
+            .atomic_interface
+            .write()
+            .expect("Should be able to acquire write lock for storage") =
+            atomic_interface;
+    }
+
+    /// `true` if `save_many` delegates to an `AtomicSecureStorage`.
+    pub(crate) fn has_atomic_interface(&self) -> bool {
+        self.atomic_interface
+            .read()
+            .expect("Should be able to acquire read lock for storage")
+            .is_some()
+    }
+
+    /// Makes Profile snapshots be encrypted and decrypted by `driver`.
+    pub(crate) fn set_hardware_key_driver(
+        &self,
+        driver: Option<Arc<dyn HardwareKeyDriver>>,
+    ) {
+        *self
+            .hardware_key_driver
+            .write()
+            .expect("Should be able to acquire write lock for storage") =
+            driver;
+    }
+
+    /// The alias of the hardware key Profile snapshots are encrypted with,
+    /// `None` if they are saved in plaintext.
+    pub(crate) fn hardware_key_alias(&self) -> Option<String> {
+        self.hardware_key_alias
+            .read()
+            .expect("Should be able to acquire read lock for storage")
+            .clone()
+    }
+
+    /// Makes Profile snapshots be encrypted with the hardware key with
+    /// `alias`, or be saved in plaintext if `None`.
+    pub(crate) fn set_hardware_key_alias(&self, alias: Option<String>) {
+        *self
+            .hardware_key_alias
+            .write()
+            .expect("Should be able to acquire write lock for storage") = alias;
+    }
+
+    /// Makes every later save fail with `WalletDeleted`, called once the
+    /// wallet has been deleted so that nothing is written back.
+    pub(crate) fn mark_deleted(&self) {
+        *self
+            .is_deleted
+            .write()
+            .expect("Should be able to acquire write lock for storage") = true;
+    }
+
+    /// `true` once the wallet has been deleted.
+    pub(crate) fn is_deleted(&self) -> bool {
+        *self
+            .is_deleted
+            .read()
+            .expect("Should be able to acquire read lock for storage")
+    }
+
+    /// Fails with `WalletDeleted` once the wallet has been deleted.
+    pub(crate) fn ensure_not_deleted(&self) -> Result<()> {
+        if self.is_deleted() {
+            return Err(CommonError::WalletDeleted);
+        }
+        Ok(())
+    }
+
+    fn hardware_key_driver(&self) -> Result<Arc<dyn HardwareKeyDriver>> {
+        self.hardware_key_driver
+            .read()
+            .expect("Should be able to acquire read lock for storage")
+            .clone()
+            .ok_or(CommonError::HardwareKeyDriverRequired)
     }
 }
 
@@ -32,12 +137,297 @@ impl WalletClientStorage {
     where
         T: serde::Serialize,
     {
+        self.ensure_not_deleted()?;
         serde_json::to_vec(value)
             .map_err(|_| CommonError::FailedToSerializeToJSON)
             .and_then(|j| self.interface.save_data(key, j))
     }
 }
 
+//======
+// Save many
+//======
+impl WalletClientStorage {
+    /// Serializes `value` into the JSON bytes of an entry of `save_many`.
+    pub fn entry<T>(
+        key: SecureStorageKey,
+        value: &T,
+    ) -> Result<(SecureStorageKey, Vec<u8>)>
+    where
+        T: serde::Serialize,
+    {
+        serde_json::to_vec(value)
+            .map(|j| (key, j))
+            .map_err(|_| CommonError::FailedToSerializeToJSON)
+    }
+
+    /// Saves all `entries` or - if any save fails - none of them.
+    ///
+    /// Delegates to the registered `AtomicSecureStorage` if any, else saves
+    /// a `SecureStorageJournal` of the previous values before saving the
+    /// entries one by one, restoring the previous values if any save fails.
+    /// Returns the error of the failed save if the previous values were
+    /// restored, else `SecureStorageTransactionRollbackFailed`, in which case
+    /// the journal is kept and rolled back by `recover_interrupted_transaction`.
+    pub fn save_many(
+        &self,
+        entries: Vec<(SecureStorageKey, Vec<u8>)>,
+    ) -> Result<()> {
+        self.ensure_not_deleted()?;
+        let atomic_interface = self
+            .atomic_interface
+            .read()
+            .expect("Should be able to acquire read lock for storage")
+            .clone();
+        match atomic_interface {
+            Some(atomic_interface) => atomic_interface.save_many_data(
+                entries
+                    .into_iter()
+                    .map(|(key, data)| SecureStorageEntry::new(key, data))
+                    .collect(),
+            ),
+            None => self.save_many_journaled(entries),
+        }
+    }
+
+    fn save_many_journaled(
+        &self,
+        entries: Vec<(SecureStorageKey, Vec<u8>)>,
+    ) -> Result<()> {
+        let keys = entries.iter().map(|(key, _)| key.clone()).collect_vec();
+        self.journaled(keys, || {
+            entries
+                .into_iter()
+                .try_for_each(|(key, data)| self.interface.save_data(key, data))
+        })
+    }
+
+    /// Deletes all `keys` or - if any delete fails - none of them.
+    ///
+    /// Saves a `SecureStorageJournal` of the values of `keys` before
+    /// deleting them one by one, restoring the values if any delete fails,
+    /// like `save_many` does without an `AtomicSecureStorage`. A journal
+    /// left behind by an interrupted transaction is rolled back first, so
+    /// `TransactionJournal` must not be one of `keys`.
+    pub fn delete_many(&self, keys: Vec<SecureStorageKey>) -> Result<()> {
+        self.journaled(keys.clone(), || {
+            keys.into_iter()
+                .try_for_each(|key| self.interface.delete_data_for_key(key))
+        })
+    }
+
+    /// Performs `write` of the values of `keys` as a transaction, see
+    /// `save_many` and `delete_many`.
+    fn journaled<F>(&self, keys: Vec<SecureStorageKey>, write: F) -> Result<()>
+    where
+        F: FnOnce() -> Result<()>,
+    {
+        self.recover_interrupted_transaction()?;
+
+        let journal = SecureStorageJournal::new(
+            keys.iter()
+                .map(|key| {
+                    self.interface.load_data(key.clone()).map(|previous| {
+                        SecureStorageJournalEntry::new(key.clone(), previous)
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?,
+        );
+        self.save(SecureStorageKey::TransactionJournal, &journal)?;
+
+        // The transaction is committed once the journal is deleted.
+        let committed = write().and_then(|_| {
+            self.interface
+                .delete_data_for_key(SecureStorageKey::TransactionJournal)
+        });
+
+        committed.or_else(|e| {
+            error!("SecureStorage transaction failed, rolling back: {}", e);
+            self.roll_back(&journal)?;
+            Err(e)
+        })
+    }
+
+    /// Rolls back the transaction of a journal left behind by an interrupted
+    /// `save_many` or `delete_many`, if any, returning `true` if one was
+    /// rolled back.
+    pub fn recover_interrupted_transaction(&self) -> Result<bool> {
+        let Some(journal) = self.load::<SecureStorageJournal>(
+            SecureStorageKey::TransactionJournal,
+        )?
+        else {
+            return Ok(false);
+        };
+        warn!("Rolling back interrupted SecureStorage transaction");
+        self.roll_back(&journal).map(|_| true)
+    }
+
+    fn roll_back(&self, journal: &SecureStorageJournal) -> Result<()> {
+        journal
+            .entries
+            .iter()
+            .rev()
+            .try_for_each(|entry| match &entry.previous {
+                Some(data) => {
+                    self.interface.save_data(entry.key.clone(), data.clone())
+                }
+                None => self.interface.delete_data_for_key(entry.key.clone()),
+            })
+            .and_then(|_| {
+                self.interface
+                    .delete_data_for_key(SecureStorageKey::TransactionJournal)
+            })
+            .map_err(|_| CommonError::SecureStorageTransactionRollbackFailed)
+    }
+}
+
+//======
+// Profile snapshot
+//======
+impl WalletClientStorage {
+    /// Serializes `profile` into an entry of `save_many`, encrypted by the
+    /// `HardwareKeyDriver` if a hardware key alias is set.
+    pub fn profile_snapshot_entry(
+        &self,
+        profile: &Profile,
+    ) -> Result<(SecureStorageKey, Vec<u8>)> {
+        let (key, json) = Self::entry(
+            SecureStorageKey::ProfileSnapshot {
+                profile_id: profile.id(),
+            },
+            profile,
+        )?;
+        let Some(key_alias) = self.hardware_key_alias() else {
+            return Ok((key, json));
+        };
+        let ciphertext = self
+            .hardware_key_driver()?
+            .encrypt(key_alias.clone(), json)?;
+        Self::entry(
+            key,
+            &HardwareEncryptedProfileSnapshot::new(
+                key_alias,
+                ciphertext.into(),
+            ),
+        )
+    }
+
+    /// Saves `profile`, encrypted by the `HardwareKeyDriver` if a hardware
+    /// key alias is set, keeping the snapshot it replaces as the last known
+    /// good snapshot if it passes integrity checks.
+    pub fn save_profile_snapshot(&self, profile: &Profile) -> Result<()> {
+        self.ensure_not_deleted()?;
+        let (key, data) = self.profile_snapshot_entry(profile)?;
+        self.save_last_known_good_profile_snapshot(&profile.id(), &data);
+        self.interface.save_data(key, data)
+    }
+
+    /// Copies the currently saved snapshot of the Profile with `profile_id`
+    /// to the last known good key, unless it is unchanged compared to
+    /// `replacement` or fails integrity checks. This is best effort, failing
+    /// to keep a last known good snapshot must not fail the save.
+    fn save_last_known_good_profile_snapshot(
+        &self,
+        profile_id: &ProfileID,
+        replacement: &[u8],
+    ) {
+        let Ok(Some(previous)) =
+            self.interface.load_data(SecureStorageKey::ProfileSnapshot {
+                profile_id: profile_id.clone(),
+            })
+        else {
+            return;
+        };
+        if previous == replacement
+            || !Self::passes_profile_snapshot_integrity_check(&previous)
+        {
+            return;
+        }
+        if let Err(error) = self.interface.save_data(
+            SecureStorageKey::LastKnownGoodProfileSnapshot {
+                profile_id: profile_id.clone(),
+            },
+            previous,
+        ) {
+            warn!(
+                "Failed to save last known good snapshot of Profile with ID: {}, error: {}",
+                profile_id, error
+            );
+        }
+    }
+
+    /// `true` if `data` is a JSON Profile or a JSON hardware encrypted
+    /// Profile, the latter cannot be decrypted without user interaction.
+    fn passes_profile_snapshot_integrity_check(data: &[u8]) -> bool {
+        serde_json::from_slice::<HardwareEncryptedProfileSnapshot>(data).is_ok()
+            || serde_json::from_slice::<Profile>(data).is_ok()
+    }
+
+    /// Loads the Profile with `profile_id`, decrypting it using the
+    /// `HardwareKeyDriver` if it was saved encrypted, in which case later
+    /// saves are encrypted with the same hardware key.
+    pub fn load_profile_snapshot(
+        &self,
+        profile_id: &ProfileID,
+    ) -> Result<Profile> {
+        self.load_profile_snapshot_from(
+            SecureStorageKey::ProfileSnapshot {
+                profile_id: profile_id.clone(),
+            },
+            CommonError::ProfileSnapshotNotFound(profile_id.clone()),
+        )
+    }
+
+    /// Loads the last known good snapshot of the Profile with `profile_id`,
+    /// i.e. the snapshot saved before the latest one, decrypting it like
+    /// `load_profile_snapshot` does.
+    pub fn load_last_known_good_profile_snapshot(
+        &self,
+        profile_id: &ProfileID,
+    ) -> Result<Profile> {
+        self.load_profile_snapshot_from(
+            SecureStorageKey::LastKnownGoodProfileSnapshot {
+                profile_id: profile_id.clone(),
+            },
+            CommonError::NoLastKnownGoodProfileSnapshot(profile_id.clone()),
+        )
+    }
+
+    fn load_profile_snapshot_from(
+        &self,
+        key: SecureStorageKey,
+        not_found: CommonError,
+    ) -> Result<Profile> {
+        let data = self.interface.load_data(key)?.ok_or(not_found)?;
+
+        let Ok(encrypted) =
+            serde_json::from_slice::<HardwareEncryptedProfileSnapshot>(&data)
+        else {
+            let profile = Self::deserialize(data)?;
+            self.set_hardware_key_alias(None);
+            return Ok(profile);
+        };
+
+        let json = self.hardware_key_driver()?.decrypt(
+            encrypted.key_alias.clone(),
+            encrypted.ciphertext.to_vec(),
+        )?;
+        let profile = Self::deserialize(json)?;
+        self.set_hardware_key_alias(Some(encrypted.key_alias));
+        Ok(profile)
+    }
+}
+
+//======
+// Delete
+//======
+impl WalletClientStorage {
+    /// Deletes the value for `key`, if any.
+    pub fn delete(&self, key: SecureStorageKey) -> Result<()> {
+        self.interface.delete_data_for_key(key)
+    }
+}
+
 //======
 // Load T
 //======
@@ -53,18 +443,27 @@ impl WalletClientStorage {
     {
         self.interface.load_data(key).and_then(|o| match o {
             None => Ok(None),
-            Some(j) => serde_json::from_slice(j.as_slice()).map_err(|_| {
-                let type_name = std::any::type_name::<T>().to_string();
-                error!(
-                    "Deserialize json to type: {}\nJSON (utf8):\n{:?}",
-                    &type_name,
-                    String::from_utf8(j.clone())
-                );
-                CommonError::FailedToDeserializeJSONToValue {
-                    json_byte_count: j.len(),
-                    type_name,
-                }
-            }),
+            Some(j) => Self::deserialize(j).map(Some),
+        })
+    }
+
+    /// Deserializes the JSON bytes `j` into `T`.
+    #[cfg(not(tarpaulin_include))] // false negative
+    fn deserialize<T>(j: Vec<u8>) -> Result<T>
+    where
+        T: for<'a> serde::Deserialize<'a>,
+    {
+        serde_json::from_slice(j.as_slice()).map_err(|_| {
+            let type_name = std::any::type_name::<T>().to_string();
+            error!(
+                "Deserialize json to type: {}\nJSON (utf8):\n{:?}",
+                &type_name,
+                String::from_utf8(j.clone())
+            );
+            CommonError::FailedToDeserializeJSONToValue {
+                json_byte_count: j.len(),
+                type_name,
+            }
         })
     }
 
@@ -107,6 +506,7 @@ impl WalletClientStorage {
         mnemonic_with_passphrase: &MnemonicWithPassphrase,
         id: &FactorSourceIDFromHash,
     ) -> Result<()> {
+        self.ensure_not_deleted()?;
         self.save(
             SecureStorageKey::DeviceFactorSourceMnemonic {
                 factor_source_id: id.clone(),
@@ -118,6 +518,25 @@ impl WalletClientStorage {
         })
     }
 
+    /// Saves a MnemonicWithPassphrase under a given `FactorSourceIDFromHash`
+    /// and `profile`, both or neither of them.
+    pub fn save_mnemonic_with_passphrase_and_profile(
+        &self,
+        mnemonic_with_passphrase: &MnemonicWithPassphrase,
+        id: &FactorSourceIDFromHash,
+        profile: &Profile,
+    ) -> Result<()> {
+        self.save_many(vec![
+            Self::entry(
+                SecureStorageKey::DeviceFactorSourceMnemonic {
+                    factor_source_id: id.clone(),
+                },
+                mnemonic_with_passphrase,
+            )?,
+            self.profile_snapshot_entry(profile)?,
+        ])
+    }
+
     /// Loads a MnemonicWithPassphrase with a `FactorSourceIDFromHash`
     pub fn load_mnemonic_with_passphrase(
         &self,
@@ -131,6 +550,19 @@ impl WalletClientStorage {
         )
     }
 
+    /// Returns `true` if a MnemonicWithPassphrase is saved for the
+    /// `FactorSourceIDFromHash`, without deserializing it.
+    pub fn contains_mnemonic(
+        &self,
+        id: &FactorSourceIDFromHash,
+    ) -> Result<bool> {
+        self.interface
+            .load_data(SecureStorageKey::DeviceFactorSourceMnemonic {
+                factor_source_id: id.clone(),
+            })
+            .map(|o| o.is_some())
+    }
+
     /// Deletes a MnemonicWithPassphrase with a `FactorSourceIDFromHash`
     pub fn delete_mnemonic(&self, id: &FactorSourceIDFromHash) -> Result<()> {
         self.interface.delete_data_for_key(
@@ -293,6 +725,202 @@ mod tests {
         assert_eq!(storage.load_data(key), Ok(None));
     }
 
+    fn flaky_sut() -> (WalletClientStorage, Arc<FlakyStorage>) {
+        let storage = FlakyStorage::ephemeral();
+        (WalletClientStorage::new(storage.clone()), storage)
+    }
+
+    #[test]
+    fn save_many_saves_all() {
+        let (sut, storage) = flaky_sut();
+        assert_eq!(
+            sut.save_many(vec![
+                (SecureStorageKey::HomeCards, vec![1]),
+                (SecureStorageKey::BackupState, vec![2]),
+            ]),
+            Ok(())
+        );
+        assert_eq!(
+            storage.load_data(SecureStorageKey::HomeCards),
+            Ok(Some(vec![1]))
+        );
+        assert_eq!(
+            storage.load_data(SecureStorageKey::BackupState),
+            Ok(Some(vec![2]))
+        );
+        assert_eq!(
+            storage.load_data(SecureStorageKey::TransactionJournal),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn save_many_failure_restores_previous_values() {
+        let (sut, storage) = flaky_sut();
+        assert!(storage
+            .save_data(SecureStorageKey::HomeCards, vec![0xab])
+            .is_ok());
+
+        // Saves are of the journal, then `HomeCards` and `BackupState`.
+        storage.fail_nth_next_save(3);
+        assert_eq!(
+            sut.save_many(vec![
+                (SecureStorageKey::HomeCards, vec![1]),
+                (SecureStorageKey::BackupState, vec![2]),
+            ]),
+            Err(CommonError::SecureStorageWriteError)
+        );
+        assert_eq!(
+            storage.load_data(SecureStorageKey::HomeCards),
+            Ok(Some(vec![0xab]))
+        );
+        assert_eq!(storage.load_data(SecureStorageKey::BackupState), Ok(None));
+        assert_eq!(
+            storage.load_data(SecureStorageKey::TransactionJournal),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn save_many_failed_roll_back_is_recovered() {
+        let (sut, storage) = flaky_sut();
+
+        assert!(storage
+            .save_data(SecureStorageKey::BackupState, vec![0xab])
+            .is_ok());
+
+        // Saves are of the journal, `BackupState`, `HomeCards` - failing -
+        // and the restore of `BackupState` - failing - so the journal is kept.
+        storage.fail_nth_next_save(3);
+        storage.fail_nth_next_save(4);
+        assert_eq!(
+            sut.save_many(vec![
+                (SecureStorageKey::BackupState, vec![2]),
+                (SecureStorageKey::HomeCards, vec![1]),
+            ]),
+            Err(CommonError::SecureStorageTransactionRollbackFailed)
+        );
+        assert_eq!(
+            storage.load_data(SecureStorageKey::BackupState),
+            Ok(Some(vec![2]))
+        );
+
+        assert_eq!(sut.recover_interrupted_transaction(), Ok(true));
+        assert_eq!(
+            storage.load_data(SecureStorageKey::BackupState),
+            Ok(Some(vec![0xab]))
+        );
+        assert_eq!(storage.load_data(SecureStorageKey::HomeCards), Ok(None));
+        assert_eq!(sut.recover_interrupted_transaction(), Ok(false));
+    }
+
+    #[test]
+    fn delete_many_deletes_all() {
+        let (sut, storage) = flaky_sut();
+        assert!(storage
+            .save_data(SecureStorageKey::HomeCards, vec![1])
+            .is_ok());
+        assert_eq!(
+            sut.delete_many(vec![
+                SecureStorageKey::HomeCards,
+                SecureStorageKey::BackupState,
+            ]),
+            Ok(())
+        );
+        assert_eq!(storage.load_data(SecureStorageKey::HomeCards), Ok(None));
+        assert_eq!(
+            storage.load_data(SecureStorageKey::TransactionJournal),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn delete_many_failure_restores_deleted_values() {
+        let (sut, storage) = flaky_sut();
+        assert!(storage
+            .save_data(SecureStorageKey::HomeCards, vec![1])
+            .is_ok());
+        assert!(storage
+            .save_data(SecureStorageKey::BackupState, vec![2])
+            .is_ok());
+        storage.fail_deletes_of(SecureStorageKey::BackupState);
+
+        assert_eq!(
+            sut.delete_many(vec![
+                SecureStorageKey::HomeCards,
+                SecureStorageKey::BackupState,
+            ]),
+            Err(CommonError::SecureStorageWriteError)
+        );
+
+        assert_eq!(
+            storage.load_data(SecureStorageKey::HomeCards),
+            Ok(Some(vec![1]))
+        );
+        assert_eq!(
+            storage.load_data(SecureStorageKey::BackupState),
+            Ok(Some(vec![2]))
+        );
+        assert_eq!(
+            storage.load_data(SecureStorageKey::TransactionJournal),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn saves_fail_once_deleted() {
+        let (sut, storage) = flaky_sut();
+        sut.mark_deleted();
+        assert_eq!(
+            sut.save(SecureStorageKey::HomeCards, &vec![1]),
+            Err(CommonError::WalletDeleted)
+        );
+        assert_eq!(
+            sut.save_many(vec![(SecureStorageKey::HomeCards, vec![1])]),
+            Err(CommonError::WalletDeleted)
+        );
+        assert_eq!(
+            sut.save_profile_snapshot(&Profile::placeholder()),
+            Err(CommonError::WalletDeleted)
+        );
+        assert_eq!(
+            sut.save_mnemonic_with_passphrase(
+                &MnemonicWithPassphrase::placeholder(),
+                &FactorSourceIDFromHash::placeholder_device(),
+            ),
+            Err(CommonError::WalletDeleted)
+        );
+        assert_eq!(storage.save_count(), 0);
+    }
+
+    #[test]
+    fn save_many_delegates_to_atomic_interface() {
+        #[derive(Debug)]
+        struct FailingAtomicStorage {}
+        impl AtomicSecureStorage for FailingAtomicStorage {
+            fn save_many_data(
+                &self,
+                _entries: Vec<SecureStorageEntry>,
+            ) -> Result<()> {
+                Err(CommonError::Unknown)
+            }
+        }
+        let (sut, storage) = flaky_sut();
+        assert!(!sut.has_atomic_interface());
+        sut.set_atomic_interface(Some(Arc::new(FailingAtomicStorage {})));
+        assert!(sut.has_atomic_interface());
+        assert_eq!(
+            sut.save_many(vec![(SecureStorageKey::HomeCards, vec![1])]),
+            Err(CommonError::Unknown)
+        );
+        assert_eq!(storage.save_count(), 0);
+
+        sut.set_atomic_interface(None);
+        assert!(sut
+            .save_many(vec![(SecureStorageKey::HomeCards, vec![1])])
+            .is_ok());
+    }
+
     #[test]
     fn save_fail_to_serialize() {
         use serde::Serialize;
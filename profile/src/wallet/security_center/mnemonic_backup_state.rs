@@ -0,0 +1,80 @@
+use crate::prelude::*;
+
+/// Whether the user has confirmed having written down the mnemonic of the
+/// `DeviceFactorSource` with `factor_source_id`, as returned by
+/// [`Wallet::mnemonic_backup_states`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct MnemonicBackupState {
+    pub factor_source_id: FactorSourceIDFromHash,
+    pub is_backed_up: bool,
+}
+
+impl MnemonicBackupState {
+    pub fn new(
+        factor_source_id: FactorSourceIDFromHash,
+        is_backed_up: bool,
+    ) -> Self {
+        Self {
+            factor_source_id,
+            is_backed_up,
+        }
+    }
+}
+
+impl Profile {
+    /// The backup state of the mnemonic of each `DeviceFactorSource` not
+    /// flagged as deleted by the user, in the order of the factor sources.
+    pub(crate) fn mnemonic_backup_states(
+        &self,
+        state: &SecurityCenterState,
+    ) -> Vec<MnemonicBackupState> {
+        self.factor_sources
+            .items()
+            .into_iter()
+            .filter_map(|f| f.as_device().cloned())
+            .filter(|d| {
+                !d.common.flags.contains(&FactorSourceFlag::DeletedByUser)
+            })
+            .map(|d| {
+                let is_backed_up = state.is_mnemonic_backed_up(&d.id);
+                MnemonicBackupState::new(d.id, is_backed_up)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn not_backed_up_by_default() {
+        let profile = ProfileBuilder::new().build();
+        assert_eq!(
+            profile.mnemonic_backup_states(&SecurityCenterState::default()),
+            vec![MnemonicBackupState::new(profile.bdfs().id, false)]
+        );
+    }
+
+    #[test]
+    fn backed_up() {
+        let profile = ProfileBuilder::new().build();
+        let mut state = SecurityCenterState::default();
+        state.mnemonic_backed_up(profile.bdfs().id);
+        assert_eq!(
+            profile.mnemonic_backup_states(&state),
+            vec![MnemonicBackupState::new(profile.bdfs().id, true)]
+        );
+    }
+
+    #[test]
+    fn deleted_factor_source_is_excluded() {
+        let mut deleted = DeviceFactorSource::placeholder_olympia();
+        deleted.common.flags.append(FactorSourceFlag::DeletedByUser);
+        let profile = ProfileBuilder::new().with_factor_source(deleted).build();
+        assert_eq!(
+            profile.mnemonic_backup_states(&SecurityCenterState::default()),
+            vec![MnemonicBackupState::new(profile.bdfs().id, false)]
+        );
+    }
+}
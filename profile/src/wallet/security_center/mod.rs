@@ -0,0 +1,9 @@
+mod mnemonic_backup_state;
+mod security_center_state;
+mod security_problem;
+mod wallet_security_center;
+
+pub use mnemonic_backup_state::*;
+pub use security_center_state::*;
+pub use security_problem::*;
+pub use wallet_security_center::*;
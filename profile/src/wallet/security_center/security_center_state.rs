@@ -0,0 +1,246 @@
+use crate::prelude::*;
+
+/// The persisted state the Security Center needs apart from the Profile and
+/// the [`BackupState`], which the wallet apps report as it happens, e.g. the
+/// user confirming having written down a mnemonic.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SecurityCenterState {
+    /// IDs of the `DeviceFactorSource`s whose mnemonic the user has
+    /// confirmed having written down.
+    pub(crate) backed_up_mnemonics: Vec<FactorSourceIDFromHash>,
+}
+
+impl SecurityCenterState {
+    pub(crate) fn is_mnemonic_backed_up(
+        &self,
+        factor_source_id: &FactorSourceIDFromHash,
+    ) -> bool {
+        self.backed_up_mnemonics.contains(factor_source_id)
+    }
+
+    pub(crate) fn mnemonic_backed_up(
+        &mut self,
+        factor_source_id: FactorSourceIDFromHash,
+    ) {
+        if !self.is_mnemonic_backed_up(&factor_source_id) {
+            self.backed_up_mnemonics.push(factor_source_id);
+        }
+    }
+}
+
+impl Profile {
+    /// The security problems of this Profile given the persisted Security
+    /// Center `state`, the persisted `backup_state` and `is_mnemonic_present`,
+    /// which tells if the mnemonic of a `DeviceFactorSource` can be found in
+    /// secure storage.
+    ///
+    /// Profile backup problems come first, then mnemonic problems in the
+    /// order of the factor sources in the Profile. Factor sources which do
+    /// not control any entity, or are flagged as deleted by the user, never
+    /// cause any problem.
+    pub(crate) fn security_problems<F>(
+        &self,
+        state: &SecurityCenterState,
+        backup_state: &BackupState,
+        is_mnemonic_present: F,
+    ) -> Vec<SecurityProblem>
+    where
+        F: Fn(&FactorSourceIDFromHash) -> bool,
+    {
+        let mut problems = Vec::new();
+
+        if !self.app_preferences.security.is_cloud_profile_sync_enabled {
+            problems.push(SecurityProblem::ProfileBackupDisabled);
+        } else if backup_state.is_stale_for(self) {
+            problems.push(SecurityProblem::ProfileBackupNotUpToDate {
+                last_backed_up: backup_state.last_cloud_backup,
+            });
+        }
+
+        let device_factor_sources = self
+            .factor_sources
+            .items()
+            .into_iter()
+            .filter_map(|f| f.as_device().cloned())
+            .filter(|d| {
+                !d.common.flags.contains(&FactorSourceFlag::DeletedByUser)
+            })
+            .collect_vec();
+
+        for device_factor_source in device_factor_sources {
+            let factor_source_id = device_factor_source.id;
+            let affected =
+                AffectedEntities::controlled_by(&factor_source_id, self);
+            if affected.is_empty() {
+                continue;
+            }
+            if !is_mnemonic_present(&factor_source_id) {
+                problems.push(SecurityProblem::MnemonicMissing {
+                    factor_source_id,
+                    affected,
+                });
+            } else if !state.is_mnemonic_backed_up(&factor_source_id) {
+                problems.push(SecurityProblem::MnemonicNotBackedUp {
+                    factor_source_id,
+                    affected,
+                });
+            }
+        }
+
+        problems
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn profile() -> Profile {
+        ProfileBuilder::new().with_account("Alice").build()
+    }
+
+    fn mnemonic_backed_up(profile: &Profile) -> SecurityCenterState {
+        let mut state = SecurityCenterState::default();
+        state.mnemonic_backed_up(profile.bdfs().id);
+        state
+    }
+
+    fn profile_backed_up(profile: &Profile) -> BackupState {
+        let mut backup_state = BackupState::default();
+        backup_state.did_backup_to_cloud(
+            profile.canonical_hash(),
+            Timestamp::UNIX_EPOCH,
+        );
+        backup_state
+    }
+
+    #[test]
+    fn no_problems() {
+        let profile = profile();
+        assert_eq!(
+            profile.security_problems(
+                &mnemonic_backed_up(&profile),
+                &profile_backed_up(&profile),
+                |_| true
+            ),
+            Vec::new()
+        );
+    }
+
+    #[test]
+    fn profile_backup_disabled() {
+        let mut profile = profile();
+        profile
+            .app_preferences
+            .security
+            .is_cloud_profile_sync_enabled = false;
+        assert_eq!(
+            profile.security_problems(
+                &mnemonic_backed_up(&profile),
+                &BackupState::default(),
+                |_| true
+            ),
+            vec![SecurityProblem::ProfileBackupDisabled]
+        );
+    }
+
+    #[test]
+    fn profile_backup_never_done() {
+        let profile = profile();
+        assert_eq!(
+            profile.security_problems(
+                &mnemonic_backed_up(&profile),
+                &BackupState::default(),
+                |_| true
+            ),
+            vec![SecurityProblem::ProfileBackupNotUpToDate {
+                last_backed_up: None
+            }]
+        );
+    }
+
+    #[test]
+    fn profile_backup_outdated() {
+        let mut profile = profile();
+        let backup_state = profile_backed_up(&profile);
+        profile.app_preferences.security.is_developer_mode_enabled =
+            !profile.app_preferences.security.is_developer_mode_enabled;
+        assert_eq!(
+            profile.security_problems(
+                &mnemonic_backed_up(&profile),
+                &backup_state,
+                |_| true
+            ),
+            vec![SecurityProblem::ProfileBackupNotUpToDate {
+                last_backed_up: Some(Timestamp::UNIX_EPOCH)
+            }]
+        );
+    }
+
+    #[test]
+    fn mnemonic_not_backed_up() {
+        let profile = profile();
+        let bdfs_id = profile.bdfs().id;
+        assert_eq!(
+            profile.security_problems(
+                &SecurityCenterState::default(),
+                &profile_backed_up(&profile),
+                |_| true
+            ),
+            vec![SecurityProblem::MnemonicNotBackedUp {
+                factor_source_id: bdfs_id.clone(),
+                affected: AffectedEntities::controlled_by(&bdfs_id, &profile)
+            }]
+        );
+    }
+
+    #[test]
+    fn mnemonic_missing() {
+        let profile = profile();
+        let bdfs_id = profile.bdfs().id;
+        assert_eq!(
+            profile.security_problems(
+                &mnemonic_backed_up(&profile),
+                &profile_backed_up(&profile),
+                |_| false
+            ),
+            vec![SecurityProblem::MnemonicMissing {
+                factor_source_id: bdfs_id.clone(),
+                affected: AffectedEntities::controlled_by(&bdfs_id, &profile)
+            }]
+        );
+    }
+
+    #[test]
+    fn factor_source_without_entities_is_not_a_problem() {
+        let profile = ProfileBuilder::new().build();
+        assert_eq!(
+            profile.security_problems(
+                &SecurityCenterState::default(),
+                &profile_backed_up(&profile),
+                |_| false
+            ),
+            Vec::new()
+        );
+    }
+
+    #[test]
+    fn mnemonic_backed_up_is_idempotent() {
+        let mut state = SecurityCenterState::default();
+        state.mnemonic_backed_up(FactorSourceIDFromHash::placeholder());
+        state.mnemonic_backed_up(FactorSourceIDFromHash::placeholder());
+        assert_eq!(state.backed_up_mnemonics.len(), 1);
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        let mut state = SecurityCenterState::default();
+        state.mnemonic_backed_up(FactorSourceIDFromHash::placeholder());
+        let json = serde_json::to_string(&state).unwrap();
+        assert_eq!(
+            serde_json::from_str::<SecurityCenterState>(&json).unwrap(),
+            state
+        );
+    }
+}
@@ -0,0 +1,147 @@
+use crate::prelude::*;
+
+/// The Accounts and Personas, across all networks, controlled by some
+/// factor source, hidden ones (flagged as deleted by the user) are listed
+/// separately.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct AffectedEntities {
+    pub accounts: Vec<AccountAddress>,
+    pub hidden_accounts: Vec<AccountAddress>,
+    pub personas: Vec<IdentityAddress>,
+    pub hidden_personas: Vec<IdentityAddress>,
+}
+
+impl AffectedEntities {
+    /// The entities in `profile` linked to the factor source with id
+    /// `factor_source_id`, see [`Profile::entities_linked_to_factor_source`].
+    pub fn controlled_by(
+        factor_source_id: &FactorSourceIDFromHash,
+        profile: &Profile,
+    ) -> Self {
+        let is_hidden =
+            |flags: &EntityFlags| flags.contains_id(&EntityFlag::DeletedByUser);
+
+        let mut affected = Self::default();
+        for linked in profile.entities_linked_to_factor_source(
+            &FactorSourceID::from(factor_source_id.clone()),
+        ) {
+            for account in linked.accounts.items() {
+                if is_hidden(&account.flags) {
+                    affected.hidden_accounts.push(account.address);
+                } else {
+                    affected.accounts.push(account.address);
+                }
+            }
+            for persona in linked.personas.items() {
+                if is_hidden(&persona.flags) {
+                    affected.hidden_personas.push(persona.address);
+                } else {
+                    affected.personas.push(persona.address);
+                }
+            }
+        }
+        affected
+    }
+
+    /// `true` if no entity, not even a hidden one, is affected.
+    pub fn is_empty(&self) -> bool {
+        self.accounts.is_empty()
+            && self.hidden_accounts.is_empty()
+            && self.personas.is_empty()
+            && self.hidden_personas.is_empty()
+    }
+}
+
+/// A problem with the security of the wallet, shown in the Security Center
+/// of the wallet apps, as computed by [`Wallet::security_problems`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Enum)]
+pub enum SecurityProblem {
+    /// The user has disabled cloud backup of the Profile.
+    ProfileBackupDisabled,
+
+    /// The latest changes to the Profile have not been backed up, see
+    /// [`Wallet::is_backup_stale`], `last_backed_up` is when the Profile was
+    /// last backed up to the cloud, if ever.
+    ProfileBackupNotUpToDate { last_backed_up: Option<Timestamp> },
+
+    /// The user has never confirmed having written down the mnemonic of
+    /// the `DeviceFactorSource` controlling the `affected` entities.
+    MnemonicNotBackedUp {
+        factor_source_id: FactorSourceIDFromHash,
+        affected: AffectedEntities,
+    },
+
+    /// The mnemonic of the `DeviceFactorSource` controlling the `affected`
+    /// entities is missing in secure storage, so it must be recovered
+    /// before they can sign transactions.
+    MnemonicMissing {
+        factor_source_id: FactorSourceIDFromHash,
+        affected: AffectedEntities,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn affected_entities_placeholder() {
+        let profile = Profile::placeholder();
+        let sut = AffectedEntities::controlled_by(
+            &DeviceFactorSource::placeholder_babylon().id,
+            &profile,
+        );
+        assert!(!sut.accounts.is_empty());
+        assert!(!sut.personas.is_empty());
+    }
+
+    #[test]
+    fn affected_entities_hidden_are_listed_separately() {
+        let profile = ProfileBuilder::new()
+            .with_account("Alice")
+            .with_hidden_account("Bob")
+            .with_persona("Satoshi")
+            .with_hidden_persona("Hal")
+            .build();
+        let sut = AffectedEntities::controlled_by(&profile.bdfs().id, &profile);
+        assert_eq!(sut.accounts.len(), 1);
+        assert_eq!(sut.hidden_accounts.len(), 1);
+        assert_eq!(sut.personas.len(), 1);
+        assert_eq!(sut.hidden_personas.len(), 1);
+    }
+
+    #[test]
+    fn affected_entities_are_entities_linked_to_factor_source() {
+        let profile = Profile::placeholder();
+        let id = DeviceFactorSource::placeholder_babylon().id;
+        let sut = AffectedEntities::controlled_by(&id, &profile);
+        let linked =
+            profile.entities_linked_to_factor_source(&FactorSourceID::from(id));
+        assert_eq!(
+            sut.accounts,
+            linked
+                .iter()
+                .flat_map(|l| l.accounts.items())
+                .map(|a| a.address)
+                .collect_vec()
+        );
+        assert_eq!(
+            sut.personas,
+            linked
+                .iter()
+                .flat_map(|l| l.personas.items())
+                .map(|p| p.address)
+                .collect_vec()
+        );
+    }
+
+    #[test]
+    fn affected_entities_other_factor_source_is_empty() {
+        let profile = ProfileBuilder::new().with_account("Alice").build();
+        let sut = AffectedEntities::controlled_by(
+            &DeviceFactorSource::placeholder_olympia().id,
+            &profile,
+        );
+        assert!(sut.is_empty());
+    }
+}
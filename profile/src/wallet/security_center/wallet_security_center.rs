@@ -0,0 +1,186 @@
+use crate::prelude::*;
+
+//========
+// Security Center
+//========
+#[uniffi::export]
+impl Wallet {
+    /// The security problems of the wallet, computed from the Profile, the
+    /// presence of mnemonics in SecureStorage, the persisted Security Center
+    /// state and the [`BackupState`], see [`SecurityProblem`].
+    pub fn security_problems(&self) -> Result<Vec<SecurityProblem>> {
+        let state = self.load_security_center_state()?;
+        let backup_state = self.backup_state()?;
+        let profile = self.profile();
+        let present_mnemonics = profile
+            .factor_sources
+            .items()
+            .into_iter()
+            .filter_map(|f| f.as_device().map(|d| d.id.clone()))
+            .map(|id| {
+                self.wallet_client_storage
+                    .contains_mnemonic(&id)
+                    .map(|is_present| (id, is_present))
+            })
+            .collect::<Result<HashMap<_, _>>>()?;
+
+        Ok(profile.security_problems(&state, &backup_state, |id| {
+            present_mnemonics.get(id).copied().unwrap_or(false)
+        }))
+    }
+
+    /// Saves that the user has confirmed having written down the mnemonic of
+    /// the `DeviceFactorSource` with id `factor_source_id`, which resolves
+    /// [`SecurityProblem::MnemonicNotBackedUp`] for it. Throws if the Profile
+    /// has no such `DeviceFactorSource`.
+    pub fn mark_factor_source_backed_up(
+        &self,
+        factor_source_id: FactorSourceIDFromHash,
+    ) -> Result<()> {
+        self.access_profile_with(|p| {
+            p.device_factor_source_by_id(&factor_source_id)
+        })?;
+        self.update_security_center_state_with(|state| {
+            state.mnemonic_backed_up(factor_source_id.clone())
+        })
+    }
+
+    /// Same as [`Wallet::mark_factor_source_backed_up`].
+    pub fn mark_mnemonic_as_backed_up(
+        &self,
+        factor_source_id: FactorSourceIDFromHash,
+    ) -> Result<()> {
+        self.mark_factor_source_backed_up(factor_source_id)
+    }
+
+    /// `true` if the user has confirmed having written down the mnemonic of
+    /// the `DeviceFactorSource` with id `factor_source_id`.
+    pub fn is_factor_source_backed_up(
+        &self,
+        factor_source_id: FactorSourceIDFromHash,
+    ) -> Result<bool> {
+        self.load_security_center_state()
+            .map(|state| state.is_mnemonic_backed_up(&factor_source_id))
+    }
+
+    /// The backup state of the mnemonic of each `DeviceFactorSource` in the
+    /// Profile, except those flagged as deleted by the user.
+    pub fn mnemonic_backup_states(&self) -> Result<Vec<MnemonicBackupState>> {
+        let state = self.load_security_center_state()?;
+        Ok(self.access_profile_with(|p| p.mnemonic_backup_states(&state)))
+    }
+}
+
+impl Wallet {
+    fn load_security_center_state(&self) -> Result<SecurityCenterState> {
+        self.wallet_client_storage
+            .load::<SecurityCenterState>(SecureStorageKey::SecurityCenterState)
+            .map(|o| o.unwrap_or_default())
+    }
+
+    fn update_security_center_state_with<F>(&self, mutate: F) -> Result<()>
+    where
+        F: FnOnce(&mut SecurityCenterState),
+    {
+        let mut state = self.load_security_center_state()?;
+        mutate(&mut state);
+        self.wallet_client_storage
+            .save(SecureStorageKey::SecurityCenterState, &state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn make_wallet() -> (Wallet, Arc<EphemeralSecureStorage>) {
+        Wallet::ephemeral(ProfileBuilder::new().with_account("Alice").build())
+    }
+
+    #[test]
+    fn new_wallet_has_backup_and_mnemonic_problems() {
+        let (sut, _) = make_wallet();
+        let problems = sut.security_problems().unwrap();
+        assert_eq!(problems.len(), 2);
+        assert_eq!(
+            problems[0],
+            SecurityProblem::ProfileBackupNotUpToDate {
+                last_backed_up: None
+            }
+        );
+        assert!(matches!(
+            problems[1],
+            SecurityProblem::MnemonicMissing { .. }
+        ));
+    }
+
+    #[test]
+    fn no_problems_after_saving_mnemonic_and_marking_backed_up() {
+        let (sut, _) = make_wallet();
+        let private =
+            PrivateHierarchicalDeterministicFactorSource::placeholder();
+        let id = private.factor_source.id.clone();
+        assert_eq!(sut.profile().bdfs().id, id);
+        sut.wallet_client_storage
+            .save_mnemonic_with_passphrase(
+                &private.mnemonic_with_passphrase,
+                &id,
+            )
+            .unwrap();
+
+        assert!(matches!(
+            sut.security_problems().unwrap()[1],
+            SecurityProblem::MnemonicNotBackedUp { .. }
+        ));
+
+        sut.mark_mnemonic_as_backed_up(id).unwrap();
+        sut.did_backup_profile_to_cloud().unwrap();
+        assert_eq!(sut.security_problems(), Ok(Vec::new()));
+    }
+
+    #[test]
+    fn state_is_persisted() {
+        let (sut, storage) = make_wallet();
+        sut.mark_mnemonic_as_backed_up(sut.profile().bdfs().id)
+            .unwrap();
+        assert!(storage
+            .load_data(SecureStorageKey::SecurityCenterState)
+            .unwrap()
+            .is_some());
+    }
+
+    #[test]
+    fn mark_factor_source_backed_up() {
+        let (sut, _) = make_wallet();
+        let id = sut.profile().bdfs().id;
+        assert_eq!(sut.is_factor_source_backed_up(id.clone()), Ok(false));
+        assert_eq!(
+            sut.mnemonic_backup_states(),
+            Ok(vec![MnemonicBackupState::new(id.clone(), false)])
+        );
+
+        sut.mark_factor_source_backed_up(id.clone()).unwrap();
+
+        assert_eq!(sut.is_factor_source_backed_up(id.clone()), Ok(true));
+        assert_eq!(
+            sut.mnemonic_backup_states(),
+            Ok(vec![MnemonicBackupState::new(id, true)])
+        );
+    }
+
+    #[test]
+    fn mark_unknown_factor_source_backed_up_fails() {
+        let (sut, storage) = make_wallet();
+        let id = FactorSourceIDFromHash::placeholder_ledger();
+        assert_eq!(
+            sut.mark_factor_source_backed_up(id.clone()),
+            Err(CommonError::ProfileDoesNotContainFactorSourceWithID(
+                id.into()
+            ))
+        );
+        assert_eq!(
+            storage.load_data(SecureStorageKey::SecurityCenterState),
+            Ok(None)
+        );
+    }
+}
@@ -8,11 +8,24 @@ pub struct Wallet {
     // This is pub(crate) for testing purposes only, i.e. causing the RwLock to be poisoned.
     pub(crate) profile: RwLock<Profile>,
     pub(crate) wallet_client_storage: WalletClientStorage,
+    pub(crate) event_observer: RwLock<Option<Arc<dyn WalletEventObserver>>>,
+    pub(crate) pending_events: RwLock<Vec<WalletEvent>>,
+    pub(crate) pending_deletion: RwLock<Option<WalletDeletionSummary>>,
+    pub(crate) backup_manager: RwLock<BackupManager>,
+    #[cfg(feature = "radix-connect")]
+    pub(crate) auth_proof_cache: RwLock<AuthProofCache>,
+    #[cfg(feature = "signing")]
+    pub(crate) factor_source_drivers:
+        RwLock<HashMap<FactorSourceKind, Arc<dyn FactorSourceDriver>>>,
+    pub(crate) mutation_journal: RwLock<MutationJournal>,
+    pub(crate) gateway_cache: RwLock<GatewayCache>,
+    pub(crate) offline_mode: Arc<OfflineMode>,
+    pub(crate) seed_cache: SeedCache,
 }
 
 impl Wallet {
     /// Initializes logging
-    fn init_logging() {
+    pub(crate) fn init_logging() {
         static ONCE: Once = Once::new();
         ONCE.call_once(|| {
             pretty_env_logger::formatted_builder()
@@ -22,6 +35,29 @@ impl Wallet {
         });
     }
 
+    /// Creates a Wallet with `profile`, without saving anything.
+    pub(crate) fn with_profile(
+        profile: Profile,
+        wallet_client_storage: WalletClientStorage,
+    ) -> Self {
+        Self {
+            profile: RwLock::new(profile),
+            wallet_client_storage,
+            event_observer: RwLock::new(None),
+            pending_events: RwLock::new(Vec::new()),
+            pending_deletion: RwLock::new(None),
+            backup_manager: RwLock::new(BackupManager::default()),
+            #[cfg(feature = "radix-connect")]
+            auth_proof_cache: RwLock::new(AuthProofCache::default()),
+            #[cfg(feature = "signing")]
+            factor_source_drivers: RwLock::new(HashMap::new()),
+            mutation_journal: RwLock::new(MutationJournal::default()),
+            gateway_cache: RwLock::new(GatewayCache::default()),
+            offline_mode: Arc::new(OfflineMode::default()),
+            seed_cache: SeedCache::default(),
+        }
+    }
+
     fn with_imported_profile(
         profile: Profile,
         secure_storage: Arc<dyn SecureStorage>,
@@ -30,10 +66,7 @@ impl Wallet {
         let wallet_client_storage = WalletClientStorage::new(secure_storage);
 
         // Init wallet
-        let wallet = Self {
-            profile: RwLock::new(profile.clone()),
-            wallet_client_storage,
-        };
+        let wallet = Self::with_profile(profile.clone(), wallet_client_storage);
 
         // Save new profile (also sets activeProfileID)
         wallet.save_new_profile_or_panic(&profile);
@@ -41,26 +74,55 @@ impl Wallet {
         wallet
     }
 
+    /// Repairs what is safe to repair in an imported `profile`, i.e. its
+    /// `content_hint` and persisted next derivation indices.
+    pub(crate) fn repaired_for_import(profile: Profile) -> Profile {
+        let mut profile = profile;
+        profile.repair_content_hint();
+        for report in profile.repair_all_derivation_indices() {
+            log::warn!(
+                "Imported Profile has non-contiguous derivation indices: {:?}",
+                report
+            );
+        }
+        profile
+    }
+
+    pub(crate) fn new_load_active_profile(
+        wallet_client_storage: WalletClientStorage,
+    ) -> Result<Self> {
+        // Load active profile ID
+        let active_profile_id: ProfileID = wallet_client_storage.load_or(
+            SecureStorageKey::ActiveProfileID,
+            CommonError::NoActiveProfileIDSet,
+        )?;
+
+        Self::new_load_profile_with_id(active_profile_id, wallet_client_storage)
+    }
+
     fn new_load_profile_with_id(
         profile_id: ProfileID,
         wallet_client_storage: WalletClientStorage,
     ) -> Result<Self> {
-        // Form storage key
-        let profile_key = SecureStorageKey::ProfileSnapshot {
-            profile_id: profile_id.clone(),
+        // Roll back a transaction interrupted while this Profile was saved
+        wallet_client_storage.recover_interrupted_transaction()?;
+
+        // Load Profile from storage, decrypting it if needed
+        let profile = match wallet_client_storage
+            .load_profile_snapshot(&profile_id)
+        {
+            Err(error @ CommonError::FailedToDeserializeJSONToValue { .. }) => {
+                return Self::new_recover_last_known_good(
+                    profile_id,
+                    wallet_client_storage,
+                    error,
+                );
+            }
+            result => result?,
         };
 
-        // Load Profile from storage with key
-        let profile: Profile = wallet_client_storage.load_or(
-            profile_key,
-            CommonError::ProfileSnapshotNotFound(profile_id.clone()),
-        )?;
-
         // Create wallet
-        let wallet = Self {
-            profile: RwLock::new(profile),
-            wallet_client_storage,
-        };
+        let wallet = Self::with_profile(profile, wallet_client_storage);
 
         // Set active profile ID
         wallet.save_active_profile_id_or_panic(&profile_id);
@@ -99,16 +161,19 @@ impl Wallet {
             private_hd_factor_source.clone(),
             wallet_client_name.as_str(),
         );
-        let wallet = Self::with_imported_profile(profile, secure_storage);
-        wallet.wallet_client_storage.save(
-            SecureStorageKey::DeviceFactorSourceMnemonic {
-                factor_source_id: private_hd_factor_source
-                    .factor_source
-                    .id
-                    .clone(),
-            },
+
+        // Save Mnemonic and Profile, both or neither
+        let wallet_client_storage = WalletClientStorage::new(secure_storage);
+        wallet_client_storage.save_mnemonic_with_passphrase_and_profile(
             &private_hd_factor_source.mnemonic_with_passphrase,
+            &private_hd_factor_source.factor_source.id,
+            &profile,
         )?;
+
+        let profile_id = profile.id();
+        let wallet = Self::with_profile(profile, wallet_client_storage);
+        wallet.save_active_profile_id_or_panic(&profile_id);
+        wallet.schedule_cloud_backup();
         Ok(wallet)
     }
 
@@ -125,7 +190,21 @@ impl Wallet {
             profile.id()
         );
 
-        Self::with_imported_profile(profile, secure_storage)
+        Self::with_imported_profile(
+            Self::repaired_for_import(profile),
+            secure_storage,
+        )
+    }
+
+    /// Creates wallet by *importing* a Profile, after checking that it is
+    /// not corrupted, see [`Profile::validate_for_import`].
+    #[uniffi::constructor]
+    pub fn by_importing_validated_profile(
+        profile: Profile,
+        secure_storage: Arc<dyn SecureStorage>,
+    ) -> Result<Self> {
+        profile.validate_for_import()?;
+        Ok(Self::by_importing_profile(profile, secure_storage))
     }
 
     #[uniffi::constructor]
@@ -138,16 +217,7 @@ impl Wallet {
             "Instantiating Wallet by loading the active Profile from storage"
         );
 
-        // Init WalletClient's storage
-        let wallet_client_storage = WalletClientStorage::new(secure_storage);
-
-        // Load active profile ID
-        let active_profile_id: ProfileID = wallet_client_storage.load_or(
-            SecureStorageKey::ActiveProfileID,
-            CommonError::NoActiveProfileIDSet,
-        )?;
-
-        Self::new_load_profile_with_id(active_profile_id, wallet_client_storage)
+        Self::new_load_active_profile(WalletClientStorage::new(secure_storage))
     }
 
     #[uniffi::constructor]
@@ -209,6 +279,19 @@ impl Wallet {
     }
 }
 
+//========
+// LOCK
+//========
+#[uniffi::export]
+impl Wallet {
+    /// Forgets all secrets memoized in memory, i.e. the seeds of mnemonics
+    /// used for derivation, which are then recomputed from the mnemonics in
+    /// SecureStorage on next use. Call this when the app is locked.
+    pub fn lock(&self) {
+        self.seed_cache.clear();
+    }
+}
+
 impl Wallet {
     pub(crate) fn access_profile_with<T: Clone, F>(&self, access: F) -> T
     where
@@ -220,36 +303,37 @@ impl Wallet {
             .expect("Implementing Wallet clients should not read and write Profile from Wallet from multiple threads.")
     }
 
-    pub(crate) fn update_profile_with<F, R>(&self, mutate: F) -> R
-    where
-        F: Fn(RwLockWriteGuard<'_, Profile>) -> R,
-    {
-        let value = self.profile
-            .try_write()
-            .map(mutate)
-            .expect("Implementing Wallet clients should not read and write Profile from Wallet from multiple threads.");
-
-        self.save_existing_profile()
-            .expect("Failed to save Profile to secure storage.");
-
-        value
-    }
-
+    /// Mutates the Profile and saves it, fails without mutating it once the
+    /// wallet has been deleted.
     #[cfg(not(tarpaulin_include))] // false negative
     pub(crate) fn try_update_profile_with<F, R>(&self, mutate: F) -> Result<R>
     where
         F: Fn(RwLockWriteGuard<'_, Profile>) -> Result<R>,
     {
+        self.wallet_client_storage.ensure_not_deleted()?;
         let res = self
             .profile
             .try_write()
             .map_err(|_| CommonError::UnableToAcquireWriteLockForProfile)
             .and_then(mutate)?;
 
+        self.update_content_hint()?;
         self.save_existing_profile()?;
 
         Ok(res)
     }
+
+    /// Keeps the `content_hint` and `snapshot_version` of the Profile in
+    /// sync with its content after every mutation.
+    fn update_content_hint(&self) -> Result<()> {
+        self.profile
+            .try_write()
+            .map(|mut p| {
+                p.update_content_hint();
+                p.update_snapshot_version();
+            })
+            .map_err(|_| CommonError::UnableToAcquireWriteLockForProfile)
+    }
 }
 
 #[cfg(test)]
@@ -331,6 +415,34 @@ mod uniffi_tests {
         assert_eq!(wallet.profile(), profile);
     }
 
+    #[test]
+    fn by_importing_validated_profile() {
+        let storage = EphemeralSecureStorage::new();
+        let wallet = Wallet::by_importing_validated_profile(
+            Profile::placeholder(),
+            storage,
+        )
+        .unwrap();
+        assert_eq!(wallet.profile(), Profile::placeholder());
+    }
+
+    #[test]
+    fn by_importing_validated_profile_fails_for_tampered_profile() {
+        let mut profile = Profile::placeholder();
+        let mut network = profile.networks.first().unwrap().clone();
+        let mut accounts = network.accounts.items();
+        accounts[0].address = Account::placeholder_mainnet_carol().address;
+        network.accounts = Accounts::with_accounts(accounts);
+        profile.networks = ProfileNetworks::with_network(network);
+        let storage = EphemeralSecureStorage::new();
+        assert!(Wallet::by_importing_validated_profile(
+            profile,
+            storage.clone()
+        )
+        .is_err());
+        assert!(storage.storage.read().unwrap().is_empty());
+    }
+
     #[test]
     fn snapshot_json() {
         let profile = Profile::placeholder();
@@ -371,4 +483,46 @@ mod uniffi_tests {
             serde_json::from_slice::<ProfileID>(&active_id_data).unwrap();
         assert_eq!(active_id, wallet.profile().id());
     }
+
+    #[test]
+    fn content_hint_is_updated_on_mutation() {
+        let wallet = Wallet::placeholder();
+        wallet
+            .add_account(Account::placeholder_mainnet_carol())
+            .unwrap();
+        let profile = wallet.profile();
+        assert_eq!(
+            profile.header.content_hint,
+            ContentHint::with_counters(5, 4, 2)
+        );
+        assert!(profile.is_content_hint_consistent());
+    }
+
+    #[test]
+    fn importing_profile_repairs_derivation_indices() {
+        let mut profile = Profile::placeholder();
+        let bdfs_id = DeviceFactorSource::placeholder_babylon().id;
+        _ = profile
+            .factor_sources
+            .update_with(&bdfs_id.clone().into(), |f| {
+                f.common_mut().next_derivation_indices_per_network =
+                    NextDerivationIndicesPerNetwork::from_iter([
+                        NextDerivationIndices::new(NetworkID::Mainnet, 1, 2),
+                    ]);
+            });
+        let (wallet, _) = Wallet::ephemeral(profile);
+        assert_eq!(
+            wallet
+                .access_profile_with(|p| p.validate_next_derivation_indices()),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn importing_profile_repairs_content_hint() {
+        let mut profile = Profile::placeholder();
+        profile.header.content_hint = ContentHint::new();
+        let (wallet, _) = Wallet::ephemeral(profile);
+        assert_eq!(wallet.profile(), Profile::placeholder());
+    }
 }
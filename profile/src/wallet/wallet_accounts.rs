@@ -9,10 +9,9 @@ impl Wallet {
     /// Returns `Err` if it is already present in Profile. It is Wallet Client
     /// dependent if it throws if already present in SecureStorage.
     ///
-    /// If saving of `MnemonicWithPassphrase` to SecureStorage succeeds, but adding
-    /// `DeviceFactorSource` to Profile/saving of Profile to SecureStorage fails, then
-    /// this method will try to remove the newly saved `MnemonicWithPassphrase` from
-    /// `SecureStorage`.
+    /// The `MnemonicWithPassphrase` and Profile are saved to SecureStorage
+    /// together, all-or-nothing, see `WalletClientStorage::save_many`, and
+    /// Profile in memory is only updated if both were saved.
     ///
     /// Takes ownership of `PrivateHierarchicalDeterministicFactorSource`
     pub fn add_private_device_factor_source(
@@ -20,26 +19,47 @@ impl Wallet {
         private_device_factor_source: PrivateHierarchicalDeterministicFactorSource,
     ) -> Result<()> {
         let id = private_device_factor_source.factor_source.id.clone();
+        let factor_source: FactorSource =
+            private_device_factor_source.factor_source.into();
+        let err = || {
+            CommonError::UnableToSaveFactorSourceToProfile(
+                factor_source.factor_source_id(),
+            )
+        };
+
+        let mut profile_guard = self.profile.try_write().map_err(|_| err())?;
+        let mut profile = (*profile_guard).clone();
+        if !profile.factor_sources.append(factor_source.clone()) {
+            error!(
+                "FactorSource not added, already present: {}",
+                &factor_source
+            );
+            return Err(err());
+        }
+        profile.update_content_hint();
 
         info!(
-            "Save Private DeviceFactorSource to SecureStorage, factor source id: {}",
+            "Save Private DeviceFactorSource and Profile to SecureStorage, factor source id: {}",
             &id
         );
-
-        self.wallet_client_storage.save_mnemonic_with_passphrase(
-            &private_device_factor_source.mnemonic_with_passphrase,
-            &id,
-        )?;
-
-        self.add_factor_source(private_device_factor_source.factor_source.into())
+        self.wallet_client_storage
+            .save_mnemonic_with_passphrase_and_profile(
+                &private_device_factor_source.mnemonic_with_passphrase,
+                &id,
+                &profile,
+            )
             .map_err(|e| {
                 error!(
-                    "Failed to add Private DeviceFactorSource to SecureStorage, factor source id: {}",
-                    id
+                    "Failed to save Private DeviceFactorSource to SecureStorage, factor source id: {}, error: {}",
+                    id, e
                 );
-                _ = self.wallet_client_storage.delete_mnemonic(&id);
-                e
-            })
+                err()
+            })?;
+
+        *profile_guard = profile;
+        drop(profile_guard);
+        self.schedule_cloud_backup();
+        Ok(())
     }
 
     /// Adds `factor_source` to Profile and takes a snapshot of Profile and
@@ -57,7 +77,7 @@ impl Wallet {
                 &factor_source,
                 &p.factor_sources
             );
-            if p.factor_sources.append(factor_source.to_owned()).0 {
+            if p.factor_sources.append(factor_source.to_owned()) {
                 debug!("Added FactorSource: {}", &factor_source);
                 Ok(())
             } else {
@@ -83,7 +103,7 @@ impl Wallet {
     /// creation of new entities.
     ///
     /// Returns `Err` if loading or decoding of `MnemonicWithPassphrase` from
-    /// SecureStorage fails.
+    /// SecureStorage fails, or if it does not match `device_factor_source`.
     pub fn load_private_device_factor_source(
         &self,
         device_factor_source: &DeviceFactorSource,
@@ -94,8 +114,8 @@ impl Wallet {
         );
         self.wallet_client_storage
             .load_mnemonic_with_passphrase(&device_factor_source.id)
-            .map(|mwp| {
-                PrivateHierarchicalDeterministicFactorSource::new(mwp, device_factor_source.clone())
+            .and_then(|mwp| {
+                PrivateHierarchicalDeterministicFactorSource::try_new(mwp, device_factor_source.clone())
             })
             .log_info("Successfully loaded Private DeviceFactorSource from SecureStorage")
     }
@@ -119,6 +139,179 @@ impl Wallet {
     }
 }
 
+//========
+// SET - DeviceFactorSource
+//========
+#[uniffi::export]
+impl Wallet {
+    /// Creates a new (non main) Babylon `DeviceFactorSource` from `mnemonic`
+    /// and the BIP39 `passphrase` (the "25th word", may be empty), saves the
+    /// `MnemonicWithPassphrase` to SecureStorage and adds the factor source
+    /// to Profile, see `add_private_device_factor_source`.
+    ///
+    /// The hint of the returned factor source has `requires_passphrase` set
+    /// if `passphrase` is not empty.
+    pub fn add_device_factor_source_with_passphrase(
+        &self,
+        mnemonic: Mnemonic,
+        passphrase: BIP39Passphrase,
+        wallet_client_model: WalletClientModel,
+    ) -> Result<DeviceFactorSource> {
+        let mnemonic_with_passphrase =
+            MnemonicWithPassphrase::with_passphrase(mnemonic, passphrase);
+        let factor_source = DeviceFactorSource::babylon(
+            false,
+            mnemonic_with_passphrase.clone(),
+            wallet_client_model,
+        );
+        self.add_private_device_factor_source(
+            PrivateHierarchicalDeterministicFactorSource::new(
+                mnemonic_with_passphrase,
+                factor_source.clone(),
+            ),
+        )?;
+        Ok(factor_source)
+    }
+
+    /// Saves `mnemonic` and BIP39 `passphrase` to SecureStorage for the
+    /// `DeviceFactorSource` in Profile with id `factor_source_id`, e.g. when
+    /// the user re-imports a mnemonic.
+    ///
+    /// Returns `Err` if no such factor source exists in Profile, or if the
+    /// mnemonic and passphrase do not match `factor_source_id`, typically
+    /// because the wrong passphrase was entered.
+    pub fn import_mnemonic_with_passphrase_for_device_factor_source(
+        &self,
+        mnemonic: Mnemonic,
+        passphrase: BIP39Passphrase,
+        factor_source_id: FactorSourceIDFromHash,
+    ) -> Result<()> {
+        self.restore_mnemonic_for_factor_source(
+            factor_source_id,
+            MnemonicWithPassphrase::with_passphrase(mnemonic, passphrase),
+        )
+    }
+
+    /// Saves `mnemonic_with_passphrase` to SecureStorage for the
+    /// `DeviceFactorSource` in Profile with id `id`, e.g. when the user
+    /// re-enters the mnemonic of a factor source whose mnemonic is missing.
+    ///
+    /// The `FactorSourceIDFromHash` is recomputed from
+    /// `mnemonic_with_passphrase` and nothing is saved unless it equals `id`,
+    /// in which case `MnemonicWithPassphraseDoesNotMatchFactorSourceID` is
+    /// returned. Also returns `Err` if no such factor source exists in Profile.
+    pub fn restore_mnemonic_for_factor_source(
+        &self,
+        id: FactorSourceIDFromHash,
+        mnemonic_with_passphrase: MnemonicWithPassphrase,
+    ) -> Result<()> {
+        let device_factor_source =
+            self.profile().device_factor_source_by_id(&id)?;
+        let private = PrivateHierarchicalDeterministicFactorSource::try_new(
+            mnemonic_with_passphrase,
+            device_factor_source,
+        )?;
+        self.wallet_client_storage.save_mnemonic_with_passphrase(
+            &private.mnemonic_with_passphrase,
+            &id,
+        )
+    }
+}
+
+/// An [`Account`] together with the ID of the network it is on.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct AccountOnNetwork {
+    pub network_id: NetworkID,
+    pub account: Account,
+
+    /// `true` if the Account is watch-only, to be displayed distinctly
+    /// since it cannot be used to sign anything.
+    pub is_watch_only: bool,
+}
+
+impl AccountOnNetwork {
+    pub fn new(network_id: NetworkID, account: Account) -> Self {
+        Self {
+            network_id,
+            is_watch_only: account.is_watch_only(),
+            account,
+        }
+    }
+}
+
+/// A new display name for the Account with `address`, used to rename
+/// several Accounts at once.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct AccountRename {
+    pub address: AccountAddress,
+    pub new_name: DisplayName,
+}
+
+impl AccountRename {
+    pub fn new(address: AccountAddress, new_name: DisplayName) -> Self {
+        Self { address, new_name }
+    }
+}
+
+//========
+// GET - Account
+//========
+#[uniffi::export]
+impl Wallet {
+    /// Returns all Accounts on all networks of the Profile, paired with the
+    /// ID of their network, ordered by network and then by the order of the
+    /// Accounts on that network.
+    pub fn accounts_on_all_networks(&self) -> Vec<AccountOnNetwork> {
+        self.access_profile_with(|p| {
+            p.networks
+                .iter()
+                .flat_map(|n| {
+                    n.accounts
+                        .items()
+                        .into_iter()
+                        .map(|account| AccountOnNetwork::new(n.id, account))
+                })
+                .collect_vec()
+        })
+    }
+
+    /// Returns the address the next Account created on `network_id` with
+    /// `create_new_account` would get, without creating an Account or
+    /// consuming the derivation index.
+    pub fn preview_next_account_address(
+        &self,
+        network_id: NetworkID,
+    ) -> Result<AccountAddress> {
+        self.derive_next_account_creation_factor_instance(
+            &self.profile(),
+            network_id,
+        )
+        .map(AccountAddress::from_hd_factor_instance_virtual_entity_creation)
+    }
+}
+
+impl Wallet {
+    /// Derives the factor instance of the next Account on `network_id`,
+    /// using the *main* "Babylon" `DeviceFactorSource` of `profile` and the
+    /// "next" index for this FactorSource as derivation path.
+    fn derive_next_account_creation_factor_instance(
+        &self,
+        profile: &Profile,
+        network_id: NetworkID,
+    ) -> Result<HDFactorInstanceAccountCreation> {
+        let index = profile
+            .next_derivation_index_for_entity(EntityKind::Accounts, network_id);
+        self.load_private_device_factor_source(&profile.bdfs())
+            .map(|p| {
+                p.derive_entity_creation_factor_instance_using(
+                    &self.seed_cache,
+                    network_id,
+                    index,
+                )
+            })
+    }
+}
+
 //========
 // SET - Account
 //========
@@ -134,23 +327,25 @@ impl Wallet {
         name: DisplayName,
     ) -> Result<Account> {
         let profile = &self.profile();
-        let bdfs = profile.bdfs();
-        let index = profile
-            .next_derivation_index_for_entity(EntityKind::Accounts, network_id);
-        let number_of_accounts_on_network = profile
+        let appearance_ids_on_network = profile
             .networks
-            .get(&network_id)
-            .map(|n| n.accounts.len())
-            .unwrap_or(0);
+            .get_id(&network_id)
+            .map(|n| {
+                n.accounts
+                    .items()
+                    .into_iter()
+                    .map(|a| a.appearance_id)
+                    .collect_vec()
+            })
+            .unwrap_or_default();
 
-        let appearance_id = AppearanceID::from_number_of_accounts_on_network(
-            number_of_accounts_on_network,
-        );
+        let appearance_id =
+            AppearanceID::next_least_used(&appearance_ids_on_network);
 
-        let factor_instance =
-            self.load_private_device_factor_source(&bdfs).map(|p| {
-                p.derive_entity_creation_factor_instance(network_id, index)
-            })?;
+        let factor_instance = self
+            .derive_next_account_creation_factor_instance(
+                profile, network_id,
+            )?;
 
         let account = Account::new(factor_instance, name, appearance_id);
 
@@ -160,34 +355,34 @@ impl Wallet {
     /// Returns `Ok(())` if the `account` was new and successfully added. If saving failed or if the account was already present in Profile, an
     /// error is returned.
     pub fn add_account(&self, account: Account) -> Result<()> {
-        // TODO: clean this up, BAD code. messy, mostly because of (my) bad IdentifiedVec API.
         let network_id = account.network_id;
         let err_exists =
             CommonError::AccountAlreadyPresent(account.id().clone());
         self.try_update_profile_with(|mut p| {
             let networks = &mut p.networks;
-            if networks.contains_id(&network_id) {
-                networks
-                    .try_update_with(&network_id, |network| {
-                        if network.accounts.append(account.clone()).0 {
-                            Ok(network.clone())
-                        } else {
-                            Err(err_exists.clone())
-                        }
-                    })
-                    .and_then(
-                        |r| if r { Ok(()) } else { Err(err_exists.clone()) },
-                    )
-            } else {
-                let network = ProfileNetwork::new(
+            if !networks.contains_id(&network_id) {
+                networks.append(ProfileNetwork::new(
                     network_id,
-                    Accounts::from_iter([account.to_owned()]),
+                    Accounts::with_account(account.clone()),
                     Personas::default(),
                     AuthorizedDapps::default(),
-                );
-                networks.append(network);
-                Ok(())
+                ));
+            } else {
+                networks.try_update_with(&network_id, |network| {
+                    let mut network = network.clone();
+                    network
+                        .accounts
+                        .try_insert_unique(account.clone())
+                        .map_err(|_| err_exists.clone())?;
+                    Ok(network)
+                })?;
             }
+            p.record_used_derivation_index(
+                EntityKind::Accounts,
+                network_id,
+                &account.security_state,
+            );
+            Ok(())
         })
     }
 
@@ -204,10 +399,10 @@ impl Wallet {
 
     /// Updates `account` as a whole, if it exists, else an error is thrown.
     pub fn update_account(&self, to: Account) -> Result<Account> {
-        self.update_profile_with(|mut p| {
+        self.try_update_profile_with(|mut p| {
             p.update_account(&to.address, |a| *a = to.to_owned())
+                .ok_or(CommonError::UnknownAccount)
         })
-        .ok_or(CommonError::UnknownAccount)
     }
 
     /// Updates the display name of account with the provided address, throws an error if the account is unknown to the wallet.
@@ -216,10 +411,108 @@ impl Wallet {
         address: AccountAddress,
         to: DisplayName,
     ) -> Result<Account> {
-        self.update_profile_with(|mut p| {
-            p.update_account(&address, |a| a.display_name = to.to_owned())
-        })
-        .ok_or(CommonError::UnknownAccount)
+        self.rename_entity(address.clone().into(), to)?;
+        self.access_profile_with(|p| p.networks.get_account(&address))
+            .ok_or(CommonError::UnknownAccount)
+    }
+
+    /// Updates the display names of several Accounts, all or none of them,
+    /// saves the Profile once and emits a [`WalletEvent::EntityRenamed`] per
+    /// Account, throws an error if any Account is unknown to the wallet or
+    /// listed more than once.
+    ///
+    /// Each rename is recorded in the mutation journal, if enabled.
+    pub fn rename_accounts(
+        &self,
+        renames: Vec<AccountRename>,
+    ) -> Result<Vec<Account>> {
+        let mut listed = HashSet::new();
+        if let Some(duplicate) =
+            renames.iter().find(|r| !listed.insert(r.address.clone()))
+        {
+            return Err(CommonError::AccountListedMoreThanOnce(
+                duplicate.address.clone(),
+            ));
+        }
+        let old_names = self.access_profile_with(|p| {
+            renames
+                .iter()
+                .map(|r| {
+                    p.networks
+                        .get_account(&r.address)
+                        .map(|a| a.display_name)
+                        .ok_or(CommonError::UnknownAccount)
+                })
+                .collect::<Result<Vec<_>>>()
+        })?;
+
+        self.try_update_profile_with(|mut p| {
+            renames.iter().for_each(|r| {
+                p.update_account(&r.address, |a| {
+                    a.display_name = r.new_name.clone()
+                });
+            });
+            Ok(())
+        })?;
+
+        for (rename, old_name) in renames.iter().zip(old_names) {
+            let address: AddressOfAccountOrPersona =
+                rename.address.clone().into();
+            self.emit_event(WalletEvent::EntityRenamed {
+                address: address.clone(),
+                old_name: old_name.clone(),
+                new_name: rename.new_name.clone(),
+            });
+            self.record_mutation(ProfileMutation::EntityRenamed {
+                address,
+                old_name,
+                new_name: rename.new_name.clone(),
+            });
+        }
+
+        Ok(self.access_profile_with(|p| {
+            renames
+                .iter()
+                .filter_map(|r| p.networks.get_account(&r.address))
+                .collect_vec()
+        }))
+    }
+
+    /// Reorders the Accounts on `network_id` as `ordered_addresses`, e.g.
+    /// after the user dragged them into a new order, saves the Profile and
+    /// emits [`WalletEvent::AccountsReordered`] if the order changed.
+    ///
+    /// Accounts missing from `ordered_addresses` - e.g. hidden ones - are
+    /// kept after the listed ones, in their current relative order. Throws
+    /// an error if any Account is unknown to the wallet or listed more than
+    /// once.
+    pub fn reorder_accounts(
+        &self,
+        network_id: NetworkID,
+        ordered_addresses: Vec<AccountAddress>,
+    ) -> Result<()> {
+        let changed = self.try_update_profile_with(|mut p| {
+            p.reorder_accounts(network_id, &ordered_addresses)
+        })?;
+        if changed {
+            let ordered_addresses = self.access_profile_with(|p| {
+                p.networks
+                    .get_id(&network_id)
+                    .map(|n| {
+                        n.accounts
+                            .items()
+                            .into_iter()
+                            .map(|a| a.address)
+                            .collect_vec()
+                    })
+                    .unwrap_or_default()
+            });
+            self.emit_event(WalletEvent::AccountsReordered {
+                network_id,
+                ordered_addresses,
+            });
+        }
+        Ok(())
     }
 }
 
@@ -236,6 +529,81 @@ mod tests {
     pub use pretty_assertions::{assert_eq, assert_ne};
     use std::sync::RwLock;
 
+    #[test]
+    fn accounts_on_all_networks() {
+        let sut = Wallet::placeholder();
+        let expected = [
+            (NetworkID::Mainnet, Accounts::placeholder_mainnet()),
+            (NetworkID::Stokenet, Accounts::placeholder_stokenet()),
+        ]
+        .into_iter()
+        .flat_map(|(network_id, accounts)| {
+            accounts
+                .items()
+                .into_iter()
+                .map(move |account| AccountOnNetwork::new(network_id, account))
+        })
+        .collect_vec();
+        assert_eq!(sut.accounts_on_all_networks(), expected);
+    }
+
+    #[test]
+    fn accounts_on_all_networks_marks_watch_only() {
+        let (sut, _) = Wallet::ephemeral(Profile::placeholder());
+        let watched = Account::new_watch_only(
+            "account_rdx129qdd2yp9vs8jkkn2uwn6sw0ejwmcwr3r4c3usr2hp0nau67m2kzdm"
+                .parse()
+                .unwrap(),
+            DisplayName::new("Watched").unwrap(),
+            AppearanceID::default(),
+        );
+        sut.add_account(watched.clone()).unwrap();
+        let accounts = sut.accounts_on_all_networks();
+        assert_eq!(
+            accounts
+                .iter()
+                .filter(|a| a.is_watch_only)
+                .map(|a| a.account.clone())
+                .collect_vec(),
+            vec![watched]
+        );
+    }
+
+    #[test]
+    fn add_watch_only_account_bumps_snapshot_version() {
+        let (sut, storage) = Wallet::ephemeral(Profile::placeholder());
+        let watched = Account::new_watch_only(
+            "account_rdx129qdd2yp9vs8jkkn2uwn6sw0ejwmcwr3r4c3usr2hp0nau67m2kzdm"
+                .parse()
+                .unwrap(),
+            DisplayName::new("Watched").unwrap(),
+            AppearanceID::default(),
+        );
+        sut.add_account(watched).unwrap();
+        assert_eq!(
+            sut.profile().header.snapshot_version,
+            ProfileSnapshotVersion::V101
+        );
+        let saved = storage
+            .load_data(SecureStorageKey::ProfileSnapshot {
+                profile_id: sut.profile().id(),
+            })
+            .unwrap()
+            .unwrap();
+        let saved: Profile = serde_json::from_slice(&saved).unwrap();
+        assert_eq!(saved.header.snapshot_version, ProfileSnapshotVersion::V101);
+    }
+
+    #[test]
+    fn accounts_on_all_networks_empty() {
+        let sut = Wallet::ephemeral(Profile::new(
+            PrivateHierarchicalDeterministicFactorSource::placeholder(),
+            "Test",
+        ))
+        .0;
+        assert!(sut.accounts_on_all_networks().is_empty());
+    }
+
     #[test]
     fn change_display_name_of_accounts() {
         let profile = Profile::placeholder();
@@ -262,6 +630,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn add_account_persists_next_derivation_index() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let carol = Account::placeholder_mainnet_carol();
+        assert!(wallet.add_account(carol.clone()).is_ok());
+        wallet
+            .try_update_profile_with(|mut p| {
+                p.networks.update_with(&NetworkID::Mainnet, |n| {
+                    _ = n.accounts.remove_by_id(&carol.address);
+                });
+                Ok(())
+            })
+            .unwrap();
+        assert_eq!(
+            wallet.access_profile_with(|p| p.next_derivation_index_for_entity(
+                EntityKind::Accounts,
+                NetworkID::Mainnet
+            )),
+            3
+        );
+    }
+
     #[test]
     fn update_account() {
         let profile = Profile::placeholder();
@@ -337,79 +727,356 @@ mod tests {
     }
 
     #[test]
-    pub fn add_private_device_factor_source_ok_storage_when_save_to_profile_fails_then_deleted_from_storage(
+    fn add_private_device_factor_source_when_profile_locked_then_nothing_saved()
+    {
+        let new = PrivateHierarchicalDeterministicFactorSource::generate_new(
+            WalletClientModel::Unknown,
+        );
+        let storage = FlakyStorage::ephemeral();
+        let wallet = Wallet::by_importing_profile(
+            Profile::placeholder(),
+            storage.clone(),
+        );
+        let save_count = storage.save_count();
+
+        // Acquire write lock, in order to make `wallet.add_private_device_factor_source` fail (because cant have multiple writers).
+        let lock = wallet.profile.write().unwrap();
+
+        assert_eq!(
+            wallet.add_private_device_factor_source(new.clone()),
+            Err(CommonError::UnableToSaveFactorSourceToProfile(
+                new.factor_source.factor_source_id()
+            ))
+        );
+        drop(lock);
+
+        assert_eq!(storage.save_count(), save_count);
+        assert_eq!(wallet.profile(), Profile::placeholder());
+    }
+
+    fn assert_nothing_saved_after_failure(
+        storage: Arc<FlakyStorage>,
+        wallet: &Wallet,
+        new: &PrivateHierarchicalDeterministicFactorSource,
+    ) {
+        assert_eq!(
+            storage.load_data(SecureStorageKey::DeviceFactorSourceMnemonic {
+                factor_source_id: new.factor_source.id.clone()
+            }),
+            Ok(None)
+        );
+        assert_eq!(
+            storage.load_data(SecureStorageKey::TransactionJournal),
+            Ok(None)
+        );
+        assert_eq!(wallet.profile(), Profile::placeholder());
+        assert_eq!(
+            Wallet::by_loading_profile(storage).unwrap().profile(),
+            Profile::placeholder()
+        );
+    }
+
+    #[test]
+    fn add_private_device_factor_source_profile_save_fails_then_mnemonic_deleted(
     ) {
-        let profile = Profile::placeholder();
         let new = PrivateHierarchicalDeterministicFactorSource::generate_new(
             WalletClientModel::Unknown,
         );
+        let storage = FlakyStorage::ephemeral();
+        let wallet = Wallet::by_importing_profile(
+            Profile::placeholder(),
+            storage.clone(),
+        );
+
+        // Saves are of the journal, the mnemonic and then the Profile.
+        storage.fail_nth_next_save(3);
 
         assert_eq!(
-            profile
-                .factor_sources
-                .contains_id(&new.clone().factor_source.factor_source_id()),
-            false
+            wallet.add_private_device_factor_source(new.clone()),
+            Err(CommonError::UnableToSaveFactorSourceToProfile(
+                new.factor_source.factor_source_id()
+            ))
+        );
+        assert_nothing_saved_after_failure(storage, &wallet, &new);
+    }
+
+    #[test]
+    fn add_private_device_factor_source_mnemonic_save_fails_then_profile_unchanged(
+    ) {
+        let new = PrivateHierarchicalDeterministicFactorSource::generate_new(
+            WalletClientModel::Unknown,
+        );
+        let storage = FlakyStorage::ephemeral();
+        let wallet = Wallet::by_importing_profile(
+            Profile::placeholder(),
+            storage.clone(),
+        );
+        storage.fail_nth_next_save(2);
+
+        assert_eq!(
+            wallet.add_private_device_factor_source(new.clone()),
+            Err(CommonError::UnableToSaveFactorSourceToProfile(
+                new.factor_source.factor_source_id()
+            ))
         );
-        let delete_data_was_called =
-            Arc::new(RwLock::new(Option::<SecureStorageKey>::None));
+        assert_nothing_saved_after_failure(storage, &wallet, &new);
+    }
+
+    #[test]
+    fn add_private_device_factor_source_journal_save_fails_then_nothing_saved()
+    {
+        let new = PrivateHierarchicalDeterministicFactorSource::generate_new(
+            WalletClientModel::Unknown,
+        );
+        let storage = FlakyStorage::ephemeral();
+        let wallet = Wallet::by_importing_profile(
+            Profile::placeholder(),
+            storage.clone(),
+        );
+        storage.fail_nth_next_save(1);
+
+        assert!(wallet
+            .add_private_device_factor_source(new.clone())
+            .is_err());
+        assert_nothing_saved_after_failure(storage, &wallet, &new);
+    }
+
+    #[test]
+    fn add_private_device_factor_source_interrupted_then_rolled_back_on_load() {
+        let new = PrivateHierarchicalDeterministicFactorSource::generate_new(
+            WalletClientModel::Unknown,
+        );
+        let storage = FlakyStorage::ephemeral();
+        let wallet = Wallet::by_importing_profile(
+            Profile::placeholder(),
+            storage.clone(),
+        );
+
+        // Fail both the save of the Profile and its restore, leaving the
+        // journal behind as if the app had been killed.
+        storage.fail_nth_next_save(3);
+        storage.fail_nth_next_save(4);
+        assert!(wallet
+            .add_private_device_factor_source(new.clone())
+            .is_err());
+        assert!(storage
+            .load_data(SecureStorageKey::TransactionJournal)
+            .unwrap()
+            .is_some());
+
+        assert_eq!(
+            Wallet::by_loading_profile(storage.clone())
+                .unwrap()
+                .profile(),
+            Profile::placeholder()
+        );
+        assert_nothing_saved_after_failure(storage, &wallet, &new);
+    }
+
+    #[test]
+    fn add_private_device_factor_source_uses_atomic_secure_storage() {
         #[derive(Debug)]
-        struct TestStorage {
-            delete_data_was_called: Arc<RwLock<Option<SecureStorageKey>>>,
+        struct TestAtomicStorage {
+            inner: Arc<EphemeralSecureStorage>,
+            saved_keys: RwLock<Vec<SecureStorageKey>>,
         }
-        impl SecureStorage for TestStorage {
-            fn load_data(
+        impl AtomicSecureStorage for TestAtomicStorage {
+            fn save_many_data(
                 &self,
-                _key: SecureStorageKey,
-            ) -> Result<Option<Vec<u8>>> {
-                todo!()
-            }
-
-            fn save_data(
-                &self,
-                _key: SecureStorageKey,
-                _data: Vec<u8>,
+                entries: Vec<SecureStorageEntry>,
             ) -> Result<()> {
-                Ok(()) // mnemonic gets saved
-            }
-
-            fn delete_data_for_key(&self, key: SecureStorageKey) -> Result<()> {
-                let mut delete_data_was_called =
-                    self.delete_data_was_called.write().unwrap();
-                *delete_data_was_called = Some(key);
+                for entry in entries {
+                    self.saved_keys.write().unwrap().push(entry.key.clone());
+                    self.inner.save_data(entry.key, entry.data)?;
+                }
                 Ok(())
             }
         }
-        let storage = Arc::new(TestStorage {
-            delete_data_was_called: delete_data_was_called.clone(),
+
+        let new = PrivateHierarchicalDeterministicFactorSource::generate_new(
+            WalletClientModel::Unknown,
+        );
+        let (wallet, storage) = Wallet::ephemeral(Profile::placeholder());
+        let atomic = Arc::new(TestAtomicStorage {
+            inner: storage.clone(),
+            saved_keys: RwLock::new(Vec::new()),
         });
-        let wallet = Wallet::by_importing_profile(profile, storage.clone());
+        wallet.register_atomic_secure_storage(atomic.clone());
 
-        // Acquire write lock, in order to make `wallet.add_private_device_factor_source` fail (because cant have multiple writers).
-        let lock = wallet.profile.write().unwrap();
+        assert!(wallet.add_private_device_factor_source(new.clone()).is_ok());
+        assert_eq!(
+            atomic.saved_keys.read().unwrap().clone(),
+            vec![
+                SecureStorageKey::DeviceFactorSourceMnemonic {
+                    factor_source_id: new.factor_source.id.clone()
+                },
+                SecureStorageKey::ProfileSnapshot {
+                    profile_id: wallet.profile().id()
+                }
+            ]
+        );
+        assert!(wallet
+            .profile()
+            .factor_sources
+            .contains_id(&new.factor_source.factor_source_id()));
+    }
 
+    #[test]
+    fn add_device_factor_source_with_passphrase() {
+        let (wallet, storage) = Wallet::ephemeral(Profile::placeholder());
+        let factor_source = wallet
+            .add_device_factor_source_with_passphrase(
+                Mnemonic::placeholder_other(),
+                BIP39Passphrase::new("25th"),
+                WalletClientModel::Android,
+            )
+            .unwrap();
+        assert!(factor_source.hint.requires_passphrase);
+        assert!(!factor_source.is_main_bdfs());
+        assert!(wallet
+            .profile()
+            .factor_sources
+            .contains_id(&factor_source.factor_source_id()));
         assert_eq!(
-            wallet.add_private_device_factor_source(new.clone()),
-            Err(CommonError::UnableToSaveFactorSourceToProfile(
-                new.factor_source.factor_source_id()
-            ))
+            wallet
+                .load_private_device_factor_source(&factor_source)
+                .unwrap()
+                .mnemonic_with_passphrase
+                .passphrase,
+            BIP39Passphrase::new("25th")
         );
-        drop(lock);
+        assert!(storage.storage.read().unwrap().contains_key(
+            &SecureStorageKey::DeviceFactorSourceMnemonic {
+                factor_source_id: factor_source.id,
+            },
+        ));
+    }
+
+    #[test]
+    fn add_device_factor_source_without_passphrase() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let factor_source = wallet
+            .add_device_factor_source_with_passphrase(
+                Mnemonic::placeholder_other(),
+                BIP39Passphrase::default(),
+                WalletClientModel::Android,
+            )
+            .unwrap();
+        assert!(!factor_source.hint.requires_passphrase);
+    }
+
+    #[test]
+    fn import_mnemonic_with_passphrase_for_device_factor_source() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let private =
+            PrivateHierarchicalDeterministicFactorSource::placeholder();
+        assert!(wallet
+            .import_mnemonic_with_passphrase_for_device_factor_source(
+                private.mnemonic_with_passphrase.mnemonic.clone(),
+                private.mnemonic_with_passphrase.passphrase.clone(),
+                private.factor_source.id.clone(),
+            )
+            .is_ok());
+        assert_eq!(
+            wallet.load_private_device_factor_source_by_id(
+                &private.factor_source.id
+            ),
+            Ok(private)
+        );
+    }
 
+    #[test]
+    fn import_mnemonic_with_wrong_passphrase_fails() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let private =
+            PrivateHierarchicalDeterministicFactorSource::placeholder();
+        let wrong = MnemonicWithPassphrase::new(Mnemonic::placeholder());
+        assert_eq!(
+            wallet.import_mnemonic_with_passphrase_for_device_factor_source(
+                wrong.mnemonic.clone(),
+                wrong.passphrase.clone(),
+                private.factor_source.id.clone(),
+            ),
+            Err(
+                CommonError::MnemonicWithPassphraseDoesNotMatchFactorSourceID {
+                    expected: private.factor_source.id,
+                    found: FactorSourceIDFromHash::new_for_device(wrong),
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn restore_mnemonic_for_factor_source_resolves_missing_mnemonic() {
+        let (wallet, storage) = Wallet::ephemeral(Profile::placeholder());
+        let private =
+            PrivateHierarchicalDeterministicFactorSource::placeholder();
+        let id = private.factor_source.id.clone();
+        assert_eq!(
+            wallet.wallet_client_storage.contains_mnemonic(&id),
+            Ok(false)
+        );
+
+        wallet
+            .restore_mnemonic_for_factor_source(
+                id.clone(),
+                private.mnemonic_with_passphrase.clone(),
+            )
+            .unwrap();
+
+        assert!(storage
+            .load_data(SecureStorageKey::DeviceFactorSourceMnemonic {
+                factor_source_id: id.clone()
+            })
+            .unwrap()
+            .is_some());
+        assert_eq!(
+            wallet.load_private_device_factor_source_by_id(&id),
+            Ok(private)
+        );
+    }
+
+    #[test]
+    fn restore_mnemonic_for_factor_source_mismatch_saves_nothing() {
+        let (wallet, storage) = Wallet::ephemeral(Profile::placeholder());
+        let id = PrivateHierarchicalDeterministicFactorSource::placeholder()
+            .factor_source
+            .id;
+        let wrong = MnemonicWithPassphrase::with_passphrase(
+            Mnemonic::placeholder(),
+            BIP39Passphrase::new("wrong"),
+        );
         assert_eq!(
             wallet
-                .profile()
-                .factor_sources
-                .contains_id(&new.clone().factor_source.factor_source_id()),
-            false // should not have been saved.
+                .restore_mnemonic_for_factor_source(id.clone(), wrong.clone()),
+            Err(
+                CommonError::MnemonicWithPassphraseDoesNotMatchFactorSourceID {
+                    expected: id.clone(),
+                    found: FactorSourceIDFromHash::new_for_device(wrong),
+                }
+            )
         );
         assert_eq!(
-            delete_data_was_called.read().unwrap().clone().unwrap(),
-            SecureStorageKey::DeviceFactorSourceMnemonic {
-                factor_source_id: new.clone().factor_source.id
-            }
+            storage.load_data(SecureStorageKey::DeviceFactorSourceMnemonic {
+                factor_source_id: id
+            }),
+            Ok(None)
         );
     }
 
+    #[test]
+    fn restore_mnemonic_for_unknown_factor_source_fails() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let mnemonic_with_passphrase =
+            MnemonicWithPassphrase::placeholder_other();
+        let id = FactorSourceIDFromHash::new_for_device(
+            mnemonic_with_passphrase.clone(),
+        );
+        assert!(wallet
+            .restore_mnemonic_for_factor_source(id, mnemonic_with_passphrase)
+            .is_err());
+    }
+
     #[test]
     fn add_factor_source_fails_when_already_exists() {
         let profile = Profile::placeholder();
@@ -562,4 +1229,243 @@ mod tests {
             assert_eq!(q.networks[0].accounts[2], a);
         })
     }
+
+    fn wallet_with_placeholder_mnemonic() -> Wallet {
+        let private =
+            PrivateHierarchicalDeterministicFactorSource::placeholder();
+        let (wallet, storage) = Wallet::ephemeral(Profile::placeholder());
+        storage
+            .save_data(
+                SecureStorageKey::DeviceFactorSourceMnemonic {
+                    factor_source_id: private.factor_source.id.clone(),
+                },
+                serde_json::to_vec(&private.mnemonic_with_passphrase).unwrap(),
+            )
+            .unwrap();
+        wallet
+    }
+
+    #[test]
+    fn preview_next_account_address() {
+        let sut = wallet_with_placeholder_mnemonic();
+        let profile = sut.profile();
+
+        let preview = sut
+            .preview_next_account_address(NetworkID::Mainnet)
+            .unwrap();
+
+        assert_eq!(
+            preview.address,
+            "account_rdx12xvg2sssh0rpca6e8xyqv5vf4nqu928083yzf0fdrnvjdz2pvc000x"
+        );
+        // Nothing was persisted nor was the derivation index consumed
+        assert_eq!(sut.profile(), profile);
+        assert_eq!(
+            sut.preview_next_account_address(NetworkID::Mainnet),
+            Ok(preview.clone())
+        );
+    }
+
+    #[test]
+    fn preview_next_account_address_eq_created_account_address() {
+        let sut = wallet_with_placeholder_mnemonic();
+        let name = DisplayName::new("Test").unwrap();
+
+        let preview = sut
+            .preview_next_account_address(NetworkID::Mainnet)
+            .unwrap();
+        let account = sut
+            .create_and_save_new_account(NetworkID::Mainnet, name.clone())
+            .unwrap();
+        assert_eq!(account.address, preview);
+
+        let next_preview = sut
+            .preview_next_account_address(NetworkID::Mainnet)
+            .unwrap();
+        assert_ne!(next_preview, preview);
+        assert_eq!(
+            sut.create_new_account(NetworkID::Mainnet, name)
+                .unwrap()
+                .address,
+            next_preview
+        );
+    }
+
+    #[test]
+    fn seed_is_cached_until_locked() {
+        let sut = wallet_with_placeholder_mnemonic();
+        let id = sut.profile().bdfs().id;
+        assert!(!sut.seed_cache.contains_seed_of(&id));
+
+        let preview = sut
+            .preview_next_account_address(NetworkID::Mainnet)
+            .unwrap();
+        assert!(sut.seed_cache.contains_seed_of(&id));
+
+        sut.lock();
+        assert!(!sut.seed_cache.contains_seed_of(&id));
+        assert_eq!(
+            sut.preview_next_account_address(NetworkID::Mainnet),
+            Ok(preview)
+        );
+    }
+
+    #[test]
+    fn preview_next_account_address_without_mnemonic_fails() {
+        let sut = Wallet::placeholder();
+        assert!(sut
+            .preview_next_account_address(NetworkID::Mainnet)
+            .is_err());
+    }
+
+    #[test]
+    fn rename_accounts() {
+        let sut = Wallet::placeholder();
+        let observer = Arc::new(RecordingEventObserver::default());
+        sut.set_event_observer(observer.clone());
+        sut.set_mutation_journal_enabled(true);
+        let alice = Account::placeholder_mainnet_alice();
+        let bob = Account::placeholder_mainnet_bob();
+        let renames = vec![
+            AccountRename::new(
+                alice.address.clone(),
+                DisplayName::new("Satoshi").unwrap(),
+            ),
+            AccountRename::new(
+                bob.address.clone(),
+                DisplayName::new("Hal").unwrap(),
+            ),
+        ];
+
+        let renamed = sut.rename_accounts(renames.clone()).unwrap();
+
+        assert_eq!(
+            renamed.into_iter().map(|a| a.display_name).collect_vec(),
+            renames.iter().map(|r| r.new_name.clone()).collect_vec()
+        );
+        let events = renames
+            .iter()
+            .zip([alice.display_name, bob.display_name])
+            .map(|(r, old_name)| WalletEvent::EntityRenamed {
+                address: r.address.clone().into(),
+                old_name,
+                new_name: r.new_name.clone(),
+            })
+            .collect_vec();
+        assert_eq!(*observer.events.read().unwrap(), events);
+        assert_eq!(sut.recorded_mutations().len(), 2);
+
+        let saved = sut
+            .wallet_client_storage
+            .load_profile_snapshot(&sut.profile().id())
+            .unwrap();
+        assert_eq!(
+            saved
+                .networks
+                .get_account(&bob.address)
+                .unwrap()
+                .display_name,
+            DisplayName::new("Hal").unwrap()
+        );
+    }
+
+    #[test]
+    fn rename_accounts_unknown_account_renames_none() {
+        let sut = Wallet::placeholder();
+        let observer = Arc::new(RecordingEventObserver::default());
+        sut.set_event_observer(observer.clone());
+        let profile = sut.profile();
+
+        assert_eq!(
+            sut.rename_accounts(vec![
+                AccountRename::new(
+                    Account::placeholder_mainnet_alice().address,
+                    DisplayName::new("Satoshi").unwrap(),
+                ),
+                AccountRename::new(
+                    Account::placeholder_nebunet().address,
+                    DisplayName::new("Unknown").unwrap(),
+                ),
+            ]),
+            Err(CommonError::UnknownAccount)
+        );
+        assert_eq!(sut.profile(), profile);
+        assert!(observer.events.read().unwrap().is_empty());
+    }
+
+    #[test]
+    fn rename_accounts_duplicate_fails() {
+        let sut = Wallet::placeholder();
+        let address = Account::placeholder_mainnet_alice().address;
+        assert_eq!(
+            sut.rename_accounts(vec![
+                AccountRename::new(
+                    address.clone(),
+                    DisplayName::new("Satoshi").unwrap(),
+                ),
+                AccountRename::new(
+                    address.clone(),
+                    DisplayName::new("Hal").unwrap(),
+                ),
+            ]),
+            Err(CommonError::AccountListedMoreThanOnce(address))
+        );
+    }
+
+    #[test]
+    fn reorder_accounts() {
+        let sut = Wallet::placeholder();
+        let observer = Arc::new(RecordingEventObserver::default());
+        sut.set_event_observer(observer.clone());
+        let alice = Account::placeholder_mainnet_alice();
+        let bob = Account::placeholder_mainnet_bob();
+
+        assert_eq!(
+            sut.reorder_accounts(NetworkID::Mainnet, vec![bob.address.clone()]),
+            Ok(())
+        );
+
+        let ordered_addresses = vec![bob.address.clone(), alice.address];
+        assert_eq!(
+            sut.accounts_on_all_networks()
+                .into_iter()
+                .filter(|a| a.network_id == NetworkID::Mainnet)
+                .map(|a| a.account.address)
+                .collect_vec(),
+            ordered_addresses
+        );
+        assert_eq!(
+            sut.wallet_client_storage
+                .load_profile_snapshot(&sut.profile().id())
+                .unwrap(),
+            sut.profile()
+        );
+
+        // Unchanged order emits nothing
+        assert_eq!(
+            sut.reorder_accounts(NetworkID::Mainnet, vec![bob.address]),
+            Ok(())
+        );
+        assert_eq!(
+            *observer.events.read().unwrap(),
+            vec![WalletEvent::AccountsReordered {
+                network_id: NetworkID::Mainnet,
+                ordered_addresses,
+            }]
+        );
+    }
+
+    #[test]
+    fn reorder_accounts_unknown_account_fails() {
+        let sut = Wallet::placeholder();
+        let profile = sut.profile();
+        assert_eq!(
+            sut.reorder_accounts(
+                NetworkID::Mainnet,
+                vec![Account::placeholder_stokenet_carol().address]
+            ),
+            Err(CommonError::UnknownAccount)
+        );
+        assert_eq!(sut.profile(), profile);
+    }
 }
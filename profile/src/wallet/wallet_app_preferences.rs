@@ -0,0 +1,180 @@
+use crate::prelude::*;
+
+//========
+// SET - AppPreferences
+//========
+#[uniffi::export]
+impl Wallet {
+    /// Replaces the AppPreferences of the Profile with `to`, saves the
+    /// Profile and emits [`WalletEvent::AppPreferencesChanged`]. Does nothing
+    /// if they are unchanged.
+    ///
+    /// The change is recorded in the mutation journal, if enabled.
+    pub fn update_app_preferences(&self, to: AppPreferences) -> Result<()> {
+        if let Some(old) = self.apply_update_app_preferences(to.clone())? {
+            self.record_mutation(ProfileMutation::AppPreferencesChanged {
+                old,
+                new: to,
+            });
+        }
+        Ok(())
+    }
+}
+
+//========
+// GET/SET - User Flags
+//========
+#[uniffi::export]
+impl Wallet {
+    /// The value of the user flag with `key`, `None` if it is not set.
+    pub fn user_flag(&self, key: UserFlagKey) -> Option<UserFlagValue> {
+        self.access_profile_with(|p| {
+            p.app_preferences.user_flags.value_of(&key)
+        })
+    }
+
+    /// Sets the user flag with `key` to `value`, replacing any previous
+    /// value, see [`Wallet::update_app_preferences`].
+    ///
+    /// Throws an error if `value` is too long or if the flag is new and
+    /// the maximum number of flags is already set.
+    pub fn set_user_flag(
+        &self,
+        key: UserFlagKey,
+        value: UserFlagValue,
+    ) -> Result<()> {
+        let mut to = self.access_profile_with(|p| p.app_preferences.clone());
+        to.user_flags.try_set(UserFlag::new(key, value))?;
+        self.update_app_preferences(to)
+    }
+
+    /// Removes the user flag with `key`, returning `true` if it was set,
+    /// see [`Wallet::update_app_preferences`].
+    pub fn remove_user_flag(&self, key: UserFlagKey) -> Result<bool> {
+        let mut to = self.access_profile_with(|p| p.app_preferences.clone());
+        if to.user_flags.remove_by_id(&key).is_none() {
+            return Ok(false);
+        }
+        self.update_app_preferences(to).map(|_| true)
+    }
+}
+
+impl Wallet {
+    /// Replaces the AppPreferences with `to`, returning the old ones if they
+    /// changed, in which case the Profile is saved and
+    /// [`WalletEvent::AppPreferencesChanged`] is emitted.
+    pub(crate) fn apply_update_app_preferences(
+        &self,
+        to: AppPreferences,
+    ) -> Result<Option<AppPreferences>> {
+        let old = self.access_profile_with(|p| p.app_preferences.clone());
+        if old == to {
+            return Ok(None);
+        }
+        self.try_update_profile_with(|mut p| {
+            p.app_preferences = to.clone();
+            Ok(())
+        })?;
+        self.emit_event(WalletEvent::AppPreferencesChanged { to });
+        Ok(Some(old))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn update_app_preferences_saves_and_emits() {
+        let (wallet, storage) = Wallet::ephemeral(Profile::placeholder());
+        let observer = Arc::new(RecordingEventObserver::default());
+        wallet.set_event_observer(observer.clone());
+        let mut to = wallet.profile().app_preferences;
+        to.security.is_developer_mode_enabled =
+            !to.security.is_developer_mode_enabled;
+
+        assert_eq!(wallet.update_app_preferences(to.clone()), Ok(()));
+
+        assert_eq!(wallet.profile().app_preferences, to);
+        let reloaded = Wallet::by_loading_profile(storage).unwrap();
+        assert_eq!(reloaded.profile().app_preferences, to);
+        assert_eq!(
+            *observer.events.read().unwrap(),
+            vec![WalletEvent::AppPreferencesChanged { to }]
+        );
+    }
+
+    #[test]
+    fn update_app_preferences_unchanged_does_nothing() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let observer = Arc::new(RecordingEventObserver::default());
+        wallet.set_event_observer(observer.clone());
+
+        assert_eq!(
+            wallet.update_app_preferences(wallet.profile().app_preferences),
+            Ok(())
+        );
+        assert!(observer.events.read().unwrap().is_empty());
+    }
+
+    #[test]
+    fn set_then_get_user_flag() {
+        let (wallet, storage) = Wallet::ephemeral(Profile::placeholder());
+        let key = UserFlagKey::placeholder();
+        assert_eq!(wallet.user_flag(key.clone()), None);
+
+        wallet
+            .set_user_flag(key.clone(), UserFlagValue::placeholder())
+            .unwrap();
+
+        assert_eq!(
+            wallet.user_flag(key.clone()),
+            Some(UserFlagValue::placeholder())
+        );
+        let reloaded = Wallet::by_loading_profile(storage).unwrap();
+        assert_eq!(reloaded.user_flag(key), Some(UserFlagValue::placeholder()));
+    }
+
+    #[test]
+    fn set_user_flag_emits_event() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let observer = Arc::new(RecordingEventObserver::default());
+        wallet.set_event_observer(observer.clone());
+        wallet
+            .set_user_flag(UserFlagKey::placeholder(), true.into())
+            .unwrap();
+        assert_eq!(
+            *observer.events.read().unwrap(),
+            vec![WalletEvent::AppPreferencesChanged {
+                to: wallet.profile().app_preferences
+            }]
+        );
+    }
+
+    #[test]
+    fn set_invalid_user_flag_throws() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let text = "a".repeat(UserFlagValue::MAX_TEXT_LEN + 1);
+        assert_eq!(
+            wallet.set_user_flag(
+                UserFlagKey::placeholder(),
+                text.as_str().into()
+            ),
+            Err(CommonError::UserFlagValueTooLong {
+                expected: UserFlagValue::MAX_TEXT_LEN,
+                found: UserFlagValue::MAX_TEXT_LEN + 1
+            })
+        );
+        assert!(wallet.profile().app_preferences.user_flags.is_empty());
+    }
+
+    #[test]
+    fn remove_user_flag() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let key = UserFlagKey::placeholder();
+        assert_eq!(wallet.remove_user_flag(key.clone()), Ok(false));
+        wallet.set_user_flag(key.clone(), true.into()).unwrap();
+        assert_eq!(wallet.remove_user_flag(key.clone()), Ok(true));
+        assert_eq!(wallet.user_flag(key), None);
+    }
+}
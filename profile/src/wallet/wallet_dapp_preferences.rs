@@ -0,0 +1,491 @@
+use crate::prelude::*;
+
+/// The Persona and Accounts a Wallet Client should pre-select when the user
+/// logs in to a Dapp they have logged in to before.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct PreferredDappLogin {
+    /// The Persona to pre-select.
+    pub persona: IdentityAddress,
+
+    /// The Accounts to pre-select, in the order the user last shared them,
+    /// only containing Accounts which are still in the Profile.
+    pub accounts: Vec<AccountAddress>,
+}
+
+impl PreferredDappLogin {
+    pub fn new(
+        persona: IdentityAddress,
+        accounts: impl IntoIterator<Item = AccountAddress>,
+    ) -> Self {
+        Self {
+            persona,
+            accounts: accounts.into_iter().collect(),
+        }
+    }
+}
+
+impl Profile {
+    /// The [`PreferredDappLogin`] for the Dapp with `dapp_definition_address`,
+    /// `None` if the Dapp is unknown or no Persona has been used with it.
+    ///
+    /// Uses the last used Persona and Accounts if stored in the preferences
+    /// of the Dapp and the Persona is still authorized, else falls back to the
+    /// most recently logged in Persona and the Accounts shared with it.
+    pub fn preferred_login_for_dapp(
+        &self,
+        dapp_definition_address: &DappDefinitionAddress,
+    ) -> Option<PreferredDappLogin> {
+        let dapp = self
+            .networks
+            .get_id(&dapp_definition_address.network_id)?
+            .authorized_dapps
+            .get(dapp_definition_address)?
+            .clone();
+
+        let references = &dapp.references_to_authorized_personas;
+        let existing_accounts = |accounts: Vec<AccountAddress>| {
+            accounts
+                .into_iter()
+                .filter(|a| self.networks.get_account(a).is_some())
+                .collect_vec()
+        };
+
+        if let Some(persona) = dapp
+            .preferences
+            .last_used_persona
+            .clone()
+            .filter(|p| references.contains_id(p))
+        {
+            let accounts =
+                existing_accounts(dapp.preferences.shared_accounts_order);
+            return Some(PreferredDappLogin::new(persona, accounts));
+        }
+
+        // `max_by_key` returns the last of equal elements, iterate in reverse
+        // to prefer the first reference on ties.
+        let latest = references
+            .items()
+            .into_iter()
+            .rev()
+            .max_by_key(|r| r.last_login)?;
+        let accounts = existing_accounts(
+            latest
+                .shared_accounts
+                .map(|s| s.ids.items())
+                .unwrap_or_default(),
+        );
+        Some(PreferredDappLogin::new(latest.identity_address, accounts))
+    }
+
+    /// The Account the user prefers to deposit into when the Dapp with
+    /// `dapp_definition_address` requests an Account to deposit into, `None`
+    /// if the Dapp is unknown, no default has been set or the Account is no
+    /// longer in the Profile.
+    pub fn dapp_default_deposit_account(
+        &self,
+        dapp_definition_address: &DappDefinitionAddress,
+    ) -> Option<AccountAddress> {
+        self.networks
+            .get_id(&dapp_definition_address.network_id)?
+            .authorized_dapps
+            .get(dapp_definition_address)?
+            .preferences
+            .default_deposit_account
+            .clone()
+            .filter(|a| self.networks.get_account(a).is_some())
+    }
+}
+
+//========
+// GET - Dapp Preferences
+//========
+#[uniffi::export]
+impl Wallet {
+    /// The Persona and Accounts to pre-select when the user logs in to the
+    /// Dapp with `dapp_definition_address`, `None` if the user has never
+    /// logged in to it, see [`Profile::preferred_login_for_dapp`].
+    pub fn preferred_login_for_dapp(
+        &self,
+        dapp_definition_address: DappDefinitionAddress,
+    ) -> Option<PreferredDappLogin> {
+        self.access_profile_with(|p| {
+            p.preferred_login_for_dapp(&dapp_definition_address)
+        })
+    }
+
+    /// The Account to pre-select when the Dapp with `dapp_definition_address`
+    /// requests an Account to deposit into, see
+    /// [`Profile::dapp_default_deposit_account`].
+    pub fn dapp_default_deposit_account(
+        &self,
+        dapp_definition_address: DappDefinitionAddress,
+    ) -> Option<AccountAddress> {
+        self.access_profile_with(|p| {
+            p.dapp_default_deposit_account(&dapp_definition_address)
+        })
+    }
+}
+
+//========
+// SET - Dapp Preferences
+//========
+#[uniffi::export]
+impl Wallet {
+    /// Stores the Persona and Accounts - in the order given - the user chose
+    /// when logging in to the Dapp with `dapp_definition_address`, so that
+    /// they are pre-selected the next time, and saves the Profile.
+    ///
+    /// Throws an error if the Dapp is unknown, if the Persona is not
+    /// authorized by the Dapp or if any of the Accounts is unknown.
+    pub fn remember_dapp_login(
+        &self,
+        dapp_definition_address: DappDefinitionAddress,
+        persona_address: IdentityAddress,
+        account_addresses: Vec<AccountAddress>,
+    ) -> Result<()> {
+        self.try_update_profile_with(|mut p| {
+            if account_addresses
+                .iter()
+                .any(|a| p.networks.get_account(a).is_none())
+            {
+                return Err(CommonError::UnknownAccount);
+            }
+
+            let network = p
+                .networks
+                .get_id(&dapp_definition_address.network_id)
+                .ok_or_else(|| {
+                    CommonError::UnknownDapp(dapp_definition_address.clone())
+                })?;
+            let dapp = network
+                .authorized_dapps
+                .get(&dapp_definition_address)
+                .ok_or_else(|| {
+                CommonError::UnknownDapp(dapp_definition_address.clone())
+            })?;
+            if !dapp
+                .references_to_authorized_personas
+                .contains_id(&persona_address)
+            {
+                return Err(CommonError::PersonaNotAuthorizedByDapp(
+                    persona_address.clone(),
+                ));
+            }
+
+            let preferences = AuthorizedDappPreferences::new(
+                persona_address.clone(),
+                account_addresses.clone(),
+            )
+            .with_default_deposit_account(
+                dapp.preferences.default_deposit_account.clone(),
+            );
+            p.networks
+                .update_with(&dapp_definition_address.network_id, |n| {
+                    n.authorized_dapps
+                        .update_with(&dapp_definition_address, |d| {
+                            d.preferences = preferences.clone()
+                        });
+                });
+            Ok(())
+        })
+    }
+
+    /// Sets the Account to pre-select when the Dapp with
+    /// `dapp_definition_address` requests an Account to deposit into, or
+    /// clears it if `account_address` is `None`, and saves the Profile.
+    ///
+    /// Throws an error if the Dapp is unknown, if the Account is unknown or
+    /// if it has not been shared with the Dapp.
+    pub fn set_dapp_default_deposit_account(
+        &self,
+        dapp_definition_address: DappDefinitionAddress,
+        account_address: Option<AccountAddress>,
+    ) -> Result<()> {
+        self.try_update_profile_with(|mut p| {
+            let dapp = p
+                .networks
+                .get_id(&dapp_definition_address.network_id)
+                .and_then(|n| {
+                    n.authorized_dapps.get(&dapp_definition_address).cloned()
+                })
+                .ok_or_else(|| {
+                    CommonError::UnknownDapp(dapp_definition_address.clone())
+                })?;
+
+            if let Some(account_address) = &account_address {
+                if p.networks.get_account(account_address).is_none() {
+                    return Err(CommonError::UnknownAccount);
+                }
+                if !dapp.has_shared_account(account_address) {
+                    return Err(CommonError::AccountNotSharedWithDapp(
+                        account_address.clone(),
+                    ));
+                }
+            }
+
+            let preferences = dapp
+                .preferences
+                .with_default_deposit_account(account_address.clone());
+            p.networks
+                .update_with(&dapp_definition_address.network_id, |n| {
+                    n.authorized_dapps
+                        .update_with(&dapp_definition_address, |d| {
+                            d.preferences = preferences.clone()
+                        });
+                });
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn dapp_address() -> DappDefinitionAddress {
+        AuthorizedDapp::placeholder_mainnet_dashboard().dapp_definition_address
+    }
+
+    #[test]
+    fn unknown_dapp_has_no_preferred_login() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        assert_eq!(
+            wallet.preferred_login_for_dapp(AccountAddress::placeholder_mainnet()),
+            None
+        );
+    }
+
+    #[test]
+    fn preferred_login_falls_back_to_latest_reference() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        assert_eq!(
+            wallet.preferred_login_for_dapp(dapp_address()),
+            Some(PreferredDappLogin::new(
+                IdentityAddress::placeholder_mainnet(),
+                Vec::new()
+            ))
+        );
+    }
+
+    #[test]
+    fn remember_then_preferred_login() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let accounts = vec![
+            Account::placeholder_mainnet_bob().address,
+            Account::placeholder_mainnet_alice().address,
+        ];
+        wallet
+            .remember_dapp_login(
+                dapp_address(),
+                IdentityAddress::placeholder_mainnet_other(),
+                accounts.clone(),
+            )
+            .unwrap();
+        assert_eq!(
+            wallet.preferred_login_for_dapp(dapp_address()),
+            Some(PreferredDappLogin::new(
+                IdentityAddress::placeholder_mainnet_other(),
+                accounts
+            ))
+        );
+    }
+
+    #[test]
+    fn remember_is_saved_in_profile() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let alice = Account::placeholder_mainnet_alice().address;
+        wallet
+            .remember_dapp_login(
+                dapp_address(),
+                IdentityAddress::placeholder_mainnet(),
+                vec![alice.clone()],
+            )
+            .unwrap();
+        let preferences = wallet.access_profile_with(|p| {
+            p.networks
+                .get_id(&NetworkID::Mainnet)
+                .unwrap()
+                .authorized_dapps
+                .get(&dapp_address())
+                .unwrap()
+                .preferences
+                .clone()
+        });
+        assert_eq!(
+            preferences,
+            AuthorizedDappPreferences::new(
+                IdentityAddress::placeholder_mainnet(),
+                [alice]
+            )
+        );
+    }
+
+    #[test]
+    fn removed_account_is_not_preferred() {
+        let mut profile = Profile::placeholder();
+        profile.networks.update_with(&NetworkID::Mainnet, |n| {
+            n.authorized_dapps.update_with(&dapp_address(), |d| {
+                d.preferences = AuthorizedDappPreferences::new(
+                    IdentityAddress::placeholder_mainnet(),
+                    [
+                        AccountAddress::placeholder_mainnet(),
+                        Account::placeholder_mainnet_alice().address,
+                    ],
+                )
+            });
+        });
+        assert_eq!(
+            profile.preferred_login_for_dapp(&dapp_address()),
+            Some(PreferredDappLogin::new(
+                IdentityAddress::placeholder_mainnet(),
+                [Account::placeholder_mainnet_alice().address]
+            ))
+        );
+    }
+
+    #[test]
+    fn remember_unknown_account_throws() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        assert_eq!(
+            wallet.remember_dapp_login(
+                dapp_address(),
+                IdentityAddress::placeholder_mainnet(),
+                vec![AccountAddress::placeholder_mainnet()],
+            ),
+            Err(CommonError::UnknownAccount)
+        );
+    }
+
+    #[test]
+    fn remember_unknown_dapp_throws() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        assert_eq!(
+            wallet.remember_dapp_login(
+                AccountAddress::placeholder_mainnet(),
+                IdentityAddress::placeholder_mainnet(),
+                Vec::new(),
+            ),
+            Err(CommonError::UnknownDapp(
+                AccountAddress::placeholder_mainnet()
+            ))
+        );
+    }
+
+    #[test]
+    fn remember_unauthorized_persona_throws() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let persona = Persona::placeholder_mainnet_satoshi().address;
+        assert_eq!(
+            wallet.remember_dapp_login(
+                dapp_address(),
+                persona.clone(),
+                Vec::new(),
+            ),
+            Err(CommonError::PersonaNotAuthorizedByDapp(persona))
+        );
+    }
+
+    #[test]
+    fn default_deposit_account_is_none_by_default() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        assert_eq!(wallet.dapp_default_deposit_account(dapp_address()), None);
+    }
+
+    #[test]
+    fn set_then_get_default_deposit_account() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let alice = Account::placeholder_mainnet_alice().address;
+        wallet
+            .remember_dapp_login(
+                dapp_address(),
+                IdentityAddress::placeholder_mainnet(),
+                vec![alice.clone()],
+            )
+            .unwrap();
+        wallet
+            .set_dapp_default_deposit_account(
+                dapp_address(),
+                Some(alice.clone()),
+            )
+            .unwrap();
+        assert_eq!(
+            wallet.dapp_default_deposit_account(dapp_address()),
+            Some(alice)
+        );
+
+        wallet
+            .set_dapp_default_deposit_account(dapp_address(), None)
+            .unwrap();
+        assert_eq!(wallet.dapp_default_deposit_account(dapp_address()), None);
+    }
+
+    #[test]
+    fn remember_login_keeps_default_deposit_account() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let alice = Account::placeholder_mainnet_alice().address;
+        let bob = Account::placeholder_mainnet_bob().address;
+        wallet
+            .remember_dapp_login(
+                dapp_address(),
+                IdentityAddress::placeholder_mainnet(),
+                vec![alice.clone()],
+            )
+            .unwrap();
+        wallet
+            .set_dapp_default_deposit_account(
+                dapp_address(),
+                Some(alice.clone()),
+            )
+            .unwrap();
+        wallet
+            .remember_dapp_login(
+                dapp_address(),
+                IdentityAddress::placeholder_mainnet(),
+                vec![bob],
+            )
+            .unwrap();
+        assert_eq!(
+            wallet.dapp_default_deposit_account(dapp_address()),
+            Some(alice)
+        );
+    }
+
+    #[test]
+    fn set_default_deposit_account_not_shared_throws() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let alice = Account::placeholder_mainnet_alice().address;
+        assert_eq!(
+            wallet.set_dapp_default_deposit_account(
+                dapp_address(),
+                Some(alice.clone())
+            ),
+            Err(CommonError::AccountNotSharedWithDapp(alice))
+        );
+    }
+
+    #[test]
+    fn set_default_deposit_account_unknown_account_throws() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        assert_eq!(
+            wallet.set_dapp_default_deposit_account(
+                dapp_address(),
+                Some(AccountAddress::placeholder_mainnet())
+            ),
+            Err(CommonError::UnknownAccount)
+        );
+    }
+
+    #[test]
+    fn set_default_deposit_account_unknown_dapp_throws() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        assert_eq!(
+            wallet.set_dapp_default_deposit_account(
+                AccountAddress::placeholder_mainnet(),
+                None
+            ),
+            Err(CommonError::UnknownDapp(
+                AccountAddress::placeholder_mainnet()
+            ))
+        );
+    }
+}
@@ -0,0 +1,379 @@
+use crate::prelude::*;
+
+/// What [`Wallet::confirm_wallet_deletion`] will destroy, returned by
+/// [`Wallet::initiate_wallet_deletion`] so that the user can be shown the
+/// consequences before confirming.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct WalletDeletionSummary {
+    /// Must be passed to [`Wallet::confirm_wallet_deletion`].
+    pub token: Uuid,
+
+    /// The ID of the Profile which will be deleted.
+    pub profile_id: ProfileID,
+
+    /// The IDs of the `DeviceFactorSource`s whose mnemonics are stored on
+    /// this device and will be deleted, unless backed up these cannot be
+    /// recovered.
+    pub mnemonics_to_delete: Vec<FactorSourceIDFromHash>,
+}
+
+#[uniffi::export]
+impl Wallet {
+    /// First stage of deleting the wallet (factory reset), returns a
+    /// summary of what will be deleted, nothing is deleted until
+    /// [`Wallet::confirm_wallet_deletion`] is called with the `token` of the
+    /// summary. Calling this again invalidates any previous token.
+    pub fn initiate_wallet_deletion(&self) -> Result<WalletDeletionSummary> {
+        self.wallet_client_storage.ensure_not_deleted()?;
+        let summary = WalletDeletionSummary {
+            token: id(),
+            profile_id: self.profile().id(),
+            mnemonics_to_delete: self.mnemonics_to_delete()?,
+        };
+        *self.pending_deletion.write().expect(
+            "Should be able to acquire write lock for pending deletion",
+        ) = Some(summary.clone());
+        Ok(summary)
+    }
+
+    /// Second stage of deleting the wallet, deletes the Profile, the
+    /// mnemonics listed in the summary and all other state of the wallet
+    /// from SecureStorage, then emits [`WalletEvent::WalletDeleted`].
+    ///
+    /// Fails without deleting anything if `token` is not that of the most
+    /// recent summary, or if the summary is outdated since e.g. a factor
+    /// source has been added after it was created.
+    ///
+    /// Everything is deleted or - if any delete fails - nothing is, see
+    /// `WalletClientStorage::delete_many`. Once deleted this Wallet rejects
+    /// any mutation, save or backup with [`CommonError::WalletDeleted`].
+    pub fn confirm_wallet_deletion(&self, token: Uuid) -> Result<()> {
+        let summary = self
+            .pending_deletion
+            .write()
+            .expect("Should be able to acquire write lock for pending deletion")
+            .take()
+            .ok_or(CommonError::WalletDeletionNotInitiated)?;
+        if summary.token != token {
+            return Err(CommonError::WalletDeletionInvalidToken);
+        }
+        if summary.profile_id != self.profile().id()
+            || summary.mnemonics_to_delete != self.mnemonics_to_delete()?
+        {
+            return Err(CommonError::WalletDeletionSummaryOutdated);
+        }
+
+        // A journal left behind by an interrupted write is rolled back by
+        // `delete_many` before anything is deleted, so that it cannot bring
+        // back a deleted mnemonic or Profile.
+        let keys = summary
+            .mnemonics_to_delete
+            .iter()
+            .map(|id| SecureStorageKey::DeviceFactorSourceMnemonic {
+                factor_source_id: id.clone(),
+            })
+            .chain([
+                SecureStorageKey::HomeCards,
+                SecureStorageKey::SecurityCenterState,
+                SecureStorageKey::BackupState,
+                SecureStorageKey::PendingWalletInteractions,
+                SecureStorageKey::SnapshotHeadersList,
+                SecureStorageKey::ProfileSnapshot {
+                    profile_id: summary.profile_id.clone(),
+                },
+                SecureStorageKey::LastKnownGoodProfileSnapshot {
+                    profile_id: summary.profile_id.clone(),
+                },
+                SecureStorageKey::ActiveProfileID,
+            ])
+            .collect_vec();
+        self.wallet_client_storage.delete_many(keys)?;
+        self.wallet_client_storage.mark_deleted();
+        self.seed_cache.clear();
+
+        self.emit_event(WalletEvent::WalletDeleted {
+            profile_id: summary.profile_id,
+        });
+        Ok(())
+    }
+}
+
+#[uniffi::export]
+impl Wallet {
+    /// `true` once the wallet has been deleted by
+    /// [`Wallet::confirm_wallet_deletion`], after which it must not be used.
+    pub fn is_deleted(&self) -> bool {
+        self.wallet_client_storage.is_deleted()
+    }
+}
+
+impl Wallet {
+    /// The IDs of the `DeviceFactorSource`s of the Profile whose mnemonic is
+    /// present in SecureStorage.
+    fn mnemonics_to_delete(&self) -> Result<Vec<FactorSourceIDFromHash>> {
+        let mut ids = Vec::new();
+        for id in self
+            .profile()
+            .factor_sources
+            .items()
+            .into_iter()
+            .filter_map(|f| f.as_device().map(|d| d.id.clone()))
+        {
+            if self.wallet_client_storage.contains_mnemonic(&id)? {
+                ids.push(id);
+            }
+        }
+        Ok(ids)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn make_sut() -> (Wallet, Arc<EphemeralSecureStorage>) {
+        let (wallet, storage) = Wallet::ephemeral(Profile::placeholder());
+        let bdfs = PrivateHierarchicalDeterministicFactorSource::placeholder();
+        wallet
+            .wallet_client_storage
+            .save_mnemonic_with_passphrase(
+                &bdfs.mnemonic_with_passphrase,
+                &bdfs.factor_source.id,
+            )
+            .unwrap();
+        (wallet, storage)
+    }
+
+    #[test]
+    fn initiate_lists_present_mnemonics() {
+        let (sut, _) = make_sut();
+        let summary = sut.initiate_wallet_deletion().unwrap();
+        assert_eq!(summary.profile_id, sut.profile().id());
+        assert_eq!(
+            summary.mnemonics_to_delete,
+            vec![
+                PrivateHierarchicalDeterministicFactorSource::placeholder()
+                    .factor_source
+                    .id
+            ]
+        );
+    }
+
+    #[test]
+    fn initiate_does_not_delete_anything() {
+        let (sut, storage) = make_sut();
+        let summary = sut.initiate_wallet_deletion().unwrap();
+        assert!(sut
+            .wallet_client_storage
+            .contains_mnemonic(&summary.mnemonics_to_delete[0])
+            .unwrap());
+        assert!(Wallet::by_loading_profile_with_id(
+            summary.profile_id,
+            storage
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn confirm_deletes_everything() {
+        let (sut, storage) = make_sut();
+        let observer = Arc::new(RecordingEventObserver::default());
+        sut.set_event_observer(observer.clone());
+        sut.mark_mnemonic_as_backed_up(FactorSourceIDFromHash::placeholder())
+            .unwrap();
+        // A journal of an interrupted write of `HomeCards`.
+        storage
+            .save_data(
+                SecureStorageKey::TransactionJournal,
+                serde_json::to_vec(&SecureStorageJournal::new(vec![
+                    SecureStorageJournalEntry::new(
+                        SecureStorageKey::HomeCards,
+                        None,
+                    ),
+                ]))
+                .unwrap(),
+            )
+            .unwrap();
+        let summary = sut.initiate_wallet_deletion().unwrap();
+
+        assert_eq!(sut.confirm_wallet_deletion(summary.token), Ok(()));
+
+        assert!(!sut
+            .wallet_client_storage
+            .contains_mnemonic(&summary.mnemonics_to_delete[0])
+            .unwrap());
+        for key in [
+            SecureStorageKey::TransactionJournal,
+            SecureStorageKey::HomeCards,
+            SecureStorageKey::ActiveProfileID,
+            SecureStorageKey::SecurityCenterState,
+            SecureStorageKey::ProfileSnapshot {
+                profile_id: summary.profile_id.clone(),
+            },
+            SecureStorageKey::LastKnownGoodProfileSnapshot {
+                profile_id: summary.profile_id.clone(),
+            },
+        ] {
+            assert_eq!(storage.load_data(key), Ok(None));
+        }
+        assert_eq!(
+            *observer.events.read().unwrap(),
+            vec![WalletEvent::WalletDeleted {
+                profile_id: summary.profile_id
+            }]
+        );
+    }
+
+    #[test]
+    fn confirm_failure_deletes_nothing() {
+        let storage = FlakyStorage::ephemeral();
+        let sut = Wallet::by_importing_profile(
+            Profile::placeholder(),
+            storage.clone(),
+        );
+        let bdfs = PrivateHierarchicalDeterministicFactorSource::placeholder();
+        sut.wallet_client_storage
+            .save_mnemonic_with_passphrase(
+                &bdfs.mnemonic_with_passphrase,
+                &bdfs.factor_source.id,
+            )
+            .unwrap();
+        let summary = sut.initiate_wallet_deletion().unwrap();
+        storage.fail_deletes_of(SecureStorageKey::ActiveProfileID);
+
+        assert_eq!(
+            sut.confirm_wallet_deletion(summary.token),
+            Err(CommonError::SecureStorageWriteError)
+        );
+
+        assert!(sut
+            .wallet_client_storage
+            .contains_mnemonic(&bdfs.factor_source.id)
+            .unwrap());
+        assert!(!sut.is_deleted());
+        storage.reset_script();
+        assert!(Wallet::by_loading_profile(storage).is_ok());
+        assert!(sut.initiate_wallet_deletion().is_ok());
+    }
+
+    #[test]
+    fn deleted_wallet_rejects_mutations_and_backups() {
+        let (sut, storage) = make_sut();
+        let summary = sut.initiate_wallet_deletion().unwrap();
+        sut.confirm_wallet_deletion(summary.token).unwrap();
+        assert!(sut.is_deleted());
+        let profile = sut.profile();
+
+        assert_eq!(
+            sut.change_name_of_account(
+                Account::placeholder_mainnet_alice().address,
+                DisplayName::new("Deleted").unwrap()
+            ),
+            Err(CommonError::WalletDeleted)
+        );
+        assert_eq!(sut.profile(), profile);
+        assert_eq!(
+            sut.add_account(Account::placeholder_mainnet_carol()),
+            Err(CommonError::WalletDeleted)
+        );
+        assert_eq!(
+            sut.export_profile_snapshot(false).map(|_| ()),
+            Err(CommonError::WalletDeleted)
+        );
+        assert_eq!(
+            sut.enable_automatic_cloud_backups(
+                Arc::new(MockCloudBackupDriver::default()),
+                0
+            ),
+            Err(CommonError::WalletDeleted)
+        );
+        assert_eq!(
+            sut.initiate_wallet_deletion(),
+            Err(CommonError::WalletDeleted)
+        );
+        assert_eq!(
+            storage.load_data(SecureStorageKey::ActiveProfileID),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn confirm_clears_seed_cache() {
+        let (sut, _) = make_sut();
+        let id = FactorSourceIDFromHash::placeholder();
+        sut.preview_next_account_address(NetworkID::Mainnet)
+            .unwrap();
+        assert!(sut.seed_cache.contains_seed_of(&id));
+        let summary = sut.initiate_wallet_deletion().unwrap();
+
+        sut.confirm_wallet_deletion(summary.token).unwrap();
+
+        assert!(!sut.seed_cache.contains_seed_of(&id));
+    }
+
+    #[test]
+    fn confirm_without_initiate_fails() {
+        let (sut, _) = make_sut();
+        assert_eq!(
+            sut.confirm_wallet_deletion(Uuid::nil()),
+            Err(CommonError::WalletDeletionNotInitiated)
+        );
+    }
+
+    #[test]
+    fn confirm_with_wrong_token_fails() {
+        let (sut, storage) = make_sut();
+        let summary = sut.initiate_wallet_deletion().unwrap();
+        assert_eq!(
+            sut.confirm_wallet_deletion(Uuid::nil()),
+            Err(CommonError::WalletDeletionInvalidToken)
+        );
+        assert!(Wallet::by_loading_profile_with_id(
+            summary.profile_id,
+            storage
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn token_is_single_use() {
+        let (sut, _) = make_sut();
+        let summary = sut.initiate_wallet_deletion().unwrap();
+        _ = sut.confirm_wallet_deletion(Uuid::nil());
+        assert_eq!(
+            sut.confirm_wallet_deletion(summary.token),
+            Err(CommonError::WalletDeletionNotInitiated)
+        );
+    }
+
+    #[test]
+    fn reinitiate_invalidates_previous_token() {
+        let (sut, _) = make_sut();
+        let first = sut.initiate_wallet_deletion().unwrap();
+        let second = sut.initiate_wallet_deletion().unwrap();
+        assert_ne!(first.token, second.token);
+        assert_eq!(
+            sut.confirm_wallet_deletion(first.token),
+            Err(CommonError::WalletDeletionInvalidToken)
+        );
+    }
+
+    #[test]
+    fn outdated_summary_fails() {
+        let (sut, _) = make_sut();
+        let summary = sut.initiate_wallet_deletion().unwrap();
+        let other =
+            PrivateHierarchicalDeterministicFactorSource::placeholder_other();
+        sut.wallet_client_storage
+            .save_mnemonic_with_passphrase(
+                &other.mnemonic_with_passphrase,
+                &other.factor_source.id,
+            )
+            .unwrap();
+        sut.add_factor_source(other.factor_source.into()).unwrap();
+        assert_eq!(
+            sut.confirm_wallet_deletion(summary.token),
+            Err(CommonError::WalletDeletionSummaryOutdated)
+        );
+    }
+}
@@ -0,0 +1,363 @@
+use crate::prelude::*;
+
+//========
+// GET - Account or Persona
+//========
+#[uniffi::export]
+impl Wallet {
+    /// Returns - per network - all Accounts and Personas, including hidden
+    /// ones, linked to the factor source with id `factor_source_id`, i.e.
+    /// everything the user would lose access to if it was deleted.
+    pub fn entities_linked_to_factor_source(
+        &self,
+        factor_source_id: FactorSourceID,
+    ) -> Vec<EntitiesLinkedToFactorSource> {
+        self.access_profile_with(|p| {
+            p.entities_linked_to_factor_source(&factor_source_id)
+        })
+    }
+}
+
+//========
+// SET - Account or Persona
+//========
+#[uniffi::export]
+impl Wallet {
+    /// Raises the next derivation index of `entity_kind` on `network_id`
+    /// persisted in the factor source with id `factor_source_id` past the
+    /// highest index in use and saves the Profile, returning the gaps and
+    /// collisions found, see [`Profile::repair_derivation_indices`].
+    pub fn repair_derivation_indices(
+        &self,
+        factor_source_id: FactorSourceIDFromHash,
+        network_id: NetworkID,
+        entity_kind: EntityKind,
+    ) -> Result<DerivationIndicesReport> {
+        self.try_update_profile_with(|mut p| {
+            Ok(p.repair_derivation_indices(
+                &factor_source_id,
+                network_id,
+                entity_kind.clone(),
+            ))
+        })
+    }
+
+    /// Updates the display name of the Account or Persona with `address`,
+    /// saves the Profile and emits [`WalletEvent::EntityRenamed`], throws an
+    /// error if the entity is unknown to the wallet.
+    ///
+    /// The rename is recorded in the mutation journal, if enabled.
+    pub fn rename_entity(
+        &self,
+        address: AddressOfAccountOrPersona,
+        to: DisplayName,
+    ) -> Result<()> {
+        let old_name = self.apply_rename_entity(address.clone(), to.clone())?;
+        self.record_mutation(ProfileMutation::EntityRenamed {
+            address,
+            old_name,
+            new_name: to,
+        });
+        Ok(())
+    }
+
+    /// Updates the display name of persona with the provided address, throws an error if the persona is unknown to the wallet.
+    pub fn rename_persona(
+        &self,
+        address: IdentityAddress,
+        to: DisplayName,
+    ) -> Result<Persona> {
+        self.rename_entity(address.clone().into(), to)?;
+        self.access_profile_with(|p| p.networks.get_persona(&address))
+            .ok_or(CommonError::UnknownPersona)
+    }
+
+    /// Sets - if `is_set` - or clears `flag` of the Account or Persona with
+    /// `address`, e.g. [`EntityFlag::DeletedByUser`] to hide it, saves the
+    /// Profile and emits [`WalletEvent::EntityFlagChanged`], throws an error
+    /// if the entity is unknown to the wallet. Does nothing if the flag
+    /// already is set or cleared.
+    ///
+    /// The change is recorded in the mutation journal, if enabled.
+    pub fn set_entity_flag(
+        &self,
+        address: AddressOfAccountOrPersona,
+        flag: EntityFlag,
+        is_set: bool,
+    ) -> Result<()> {
+        if self.apply_set_entity_flag(address.clone(), flag, is_set)? {
+            self.record_mutation(ProfileMutation::EntityFlagChanged {
+                address,
+                flag,
+                is_set,
+            });
+        }
+        Ok(())
+    }
+}
+
+impl Wallet {
+    /// Updates the display name of the entity with `address`, saves the
+    /// Profile and emits [`WalletEvent::EntityRenamed`], returning the old
+    /// name.
+    pub(crate) fn apply_rename_entity(
+        &self,
+        address: AddressOfAccountOrPersona,
+        to: DisplayName,
+    ) -> Result<DisplayName> {
+        let old_name =
+            self.try_update_profile_with(|mut p| match &address {
+                AddressOfAccountOrPersona::Account { value } => {
+                    let old_name = p
+                        .networks
+                        .get_account(value)
+                        .ok_or(CommonError::UnknownAccount)?
+                        .display_name;
+                    p.update_account(value, |a| a.display_name = to.to_owned())
+                        .ok_or(CommonError::UnknownAccount)?;
+                    Ok(old_name)
+                }
+                AddressOfAccountOrPersona::Persona { value } => {
+                    let old_name = p
+                        .networks
+                        .get_persona(value)
+                        .ok_or(CommonError::UnknownPersona)?
+                        .display_name;
+                    p.update_persona(value, |persona| {
+                        persona.display_name = to.to_owned()
+                    })
+                    .ok_or(CommonError::UnknownPersona)?;
+                    Ok(old_name)
+                }
+            })?;
+
+        self.emit_event(WalletEvent::EntityRenamed {
+            address,
+            old_name: old_name.clone(),
+            new_name: to,
+        });
+
+        Ok(old_name)
+    }
+
+    /// Sets or clears `flag` of the entity with `address`, returning `true`
+    /// if it changed, in which case the Profile is saved and
+    /// [`WalletEvent::EntityFlagChanged`] is emitted.
+    pub(crate) fn apply_set_entity_flag(
+        &self,
+        address: AddressOfAccountOrPersona,
+        flag: EntityFlag,
+        is_set: bool,
+    ) -> Result<bool> {
+        let was_set = self.access_profile_with(|p| match &address {
+            AddressOfAccountOrPersona::Account { value } => p
+                .networks
+                .get_account(value)
+                .map(|a| a.flags.contains_id(&flag))
+                .ok_or(CommonError::UnknownAccount),
+            AddressOfAccountOrPersona::Persona { value } => p
+                .networks
+                .get_persona(value)
+                .map(|persona| persona.flags.contains_id(&flag))
+                .ok_or(CommonError::UnknownPersona),
+        })?;
+        if was_set == is_set {
+            return Ok(false);
+        }
+
+        let update_flags = |flags: &mut EntityFlags| {
+            if is_set {
+                flags.insert_flag(flag);
+            } else {
+                flags.remove_flag(&flag);
+            }
+        };
+        self.try_update_profile_with(|mut p| match &address {
+            AddressOfAccountOrPersona::Account { value } => p
+                .update_account(value, |a| update_flags(&mut a.flags))
+                .map(|_| ())
+                .ok_or(CommonError::UnknownAccount),
+            AddressOfAccountOrPersona::Persona { value } => p
+                .update_persona(value, |persona| {
+                    update_flags(&mut persona.flags)
+                })
+                .map(|_| ())
+                .ok_or(CommonError::UnknownPersona),
+        })?;
+
+        self.emit_event(WalletEvent::EntityFlagChanged {
+            address,
+            flag,
+            is_set,
+        });
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn entities_linked_to_factor_source() {
+        let profile = Profile::placeholder();
+        let id = FactorSource::placeholder_device().factor_source_id();
+        let (wallet, _) = Wallet::ephemeral(profile.clone());
+        assert_eq!(
+            wallet.entities_linked_to_factor_source(id.clone()),
+            profile.entities_linked_to_factor_source(&id)
+        );
+    }
+
+    #[test]
+    fn repair_derivation_indices_persists_missing_indices() {
+        let (wallet, storage) = Wallet::ephemeral(Profile::placeholder());
+        let bdfs_id = DeviceFactorSource::placeholder_babylon().id;
+        let report = wallet
+            .repair_derivation_indices(
+                bdfs_id.clone(),
+                NetworkID::Mainnet,
+                EntityKind::Accounts,
+            )
+            .unwrap();
+        assert!(report.is_contiguous());
+        let persisted = |p: &Profile| {
+            p.persisted_next_derivation_indices(&bdfs_id, NetworkID::Mainnet)
+                .map(|i| i.for_account)
+        };
+        assert_eq!(persisted(&wallet.profile()), Some(report.next));
+        let saved = Wallet::by_loading_profile(storage).unwrap();
+        assert_eq!(persisted(&saved.profile()), Some(report.next));
+    }
+
+    #[test]
+    fn rename_persona() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let address = Persona::placeholder_mainnet_satoshi().address;
+        let persona = wallet
+            .rename_persona(address.clone(), DisplayName::new("Hal").unwrap())
+            .unwrap();
+        assert_eq!(persona.display_name.value, "Hal");
+        assert_eq!(
+            wallet
+                .access_profile_with(|p| p.networks.get_persona(&address))
+                .unwrap()
+                .display_name
+                .value,
+            "Hal"
+        );
+    }
+
+    #[test]
+    fn rename_persona_unknown() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let address: IdentityAddress =
+            "identity_rdx12tgzjrz9u0xz4l28vf04hz87eguclmfaq4d2p8f8lv7zg9ssnzku8j"
+                .parse()
+                .unwrap();
+        assert_eq!(
+            wallet
+                .rename_persona(address, DisplayName::new("not used").unwrap()),
+            Err(CommonError::UnknownPersona)
+        );
+    }
+
+    #[test]
+    fn rename_entity_unknown_account_does_not_emit() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let observer = Arc::new(RecordingEventObserver::default());
+        wallet.set_event_observer(observer.clone());
+        assert_eq!(
+            wallet.rename_entity(
+                AccountAddress::placeholder_other().into(),
+                DisplayName::new("not used").unwrap()
+            ),
+            Err(CommonError::UnknownAccount)
+        );
+        assert!(observer.events.read().unwrap().is_empty());
+    }
+
+    #[test]
+    fn rename_entity_emits_event_with_old_and_new_name() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let observer = Arc::new(RecordingEventObserver::default());
+        wallet.set_event_observer(observer.clone());
+        let address: AddressOfAccountOrPersona =
+            Persona::placeholder_mainnet_satoshi().address.into();
+        wallet
+            .rename_entity(address.clone(), DisplayName::new("Hal").unwrap())
+            .unwrap();
+        assert_eq!(
+            *observer.events.read().unwrap(),
+            vec![WalletEvent::EntityRenamed {
+                address,
+                old_name: DisplayName::new("Satoshi").unwrap(),
+                new_name: DisplayName::new("Hal").unwrap(),
+            }]
+        );
+    }
+
+    #[test]
+    fn change_name_of_account_emits_event() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let observer = Arc::new(RecordingEventObserver::default());
+        wallet.set_event_observer(observer.clone());
+        let address = Account::placeholder_mainnet_alice().address;
+        wallet
+            .change_name_of_account(
+                address.clone(),
+                DisplayName::new("Stella").unwrap(),
+            )
+            .unwrap();
+        assert_eq!(
+            *observer.events.read().unwrap(),
+            vec![WalletEvent::EntityRenamed {
+                address: address.into(),
+                old_name: DisplayName::new("Alice").unwrap(),
+                new_name: DisplayName::new("Stella").unwrap(),
+            }]
+        );
+    }
+
+    #[test]
+    fn set_entity_flag_hides_persona_and_emits_event() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let observer = Arc::new(RecordingEventObserver::default());
+        wallet.set_event_observer(observer.clone());
+        let address = Persona::placeholder_mainnet_satoshi().address;
+        wallet
+            .set_entity_flag(
+                address.clone().into(),
+                EntityFlag::DeletedByUser,
+                true,
+            )
+            .unwrap();
+        assert!(wallet
+            .access_profile_with(|p| p.networks.get_persona(&address))
+            .unwrap()
+            .flags
+            .contains_id(&EntityFlag::DeletedByUser));
+        assert_eq!(
+            *observer.events.read().unwrap(),
+            vec![WalletEvent::EntityFlagChanged {
+                address: address.into(),
+                flag: EntityFlag::DeletedByUser,
+                is_set: true,
+            }]
+        );
+    }
+
+    #[test]
+    fn set_entity_flag_unknown_account() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        assert_eq!(
+            wallet.set_entity_flag(
+                AccountAddress::placeholder_other().into(),
+                EntityFlag::DeletedByUser,
+                true
+            ),
+            Err(CommonError::UnknownAccount)
+        );
+    }
+}
@@ -0,0 +1,155 @@
+use crate::prelude::*;
+
+/// An event emitted by the [`Wallet`] after it has changed and persisted
+/// the Profile, which Wallet Clients can observe by setting a
+/// [`WalletEventObserver`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Enum)]
+pub enum WalletEvent {
+    /// An Account or Persona changed its display name from `old_name` to
+    /// `new_name`.
+    EntityRenamed {
+        address: AddressOfAccountOrPersona,
+        old_name: DisplayName,
+        new_name: DisplayName,
+    },
+
+    /// `flag` of an Account or Persona was set - if `is_set` - or cleared.
+    EntityFlagChanged {
+        address: AddressOfAccountOrPersona,
+        flag: EntityFlag,
+        is_set: bool,
+    },
+
+    /// The Accounts on `network_id` were reordered by the user, now ordered
+    /// as `ordered_addresses`.
+    AccountsReordered {
+        network_id: NetworkID,
+        ordered_addresses: Vec<AccountAddress>,
+    },
+
+    /// The AppPreferences were changed to `to`.
+    AppPreferencesChanged { to: AppPreferences },
+
+    /// A transaction affecting `affected_accounts` was committed, as reported
+    /// by the Wallet Client.
+    TransactionCommitted {
+        affected_accounts: Vec<AccountAddress>,
+    },
+
+    /// An empty network with `network_id` was added to the Profile.
+    NetworkAdded { network_id: NetworkID },
+
+    /// The empty network with `network_id` was removed from the Profile.
+    NetworkRemoved { network_id: NetworkID },
+
+    /// The current Gateway was changed to `to`.
+    CurrentGatewayChanged { to: Gateway },
+
+    /// The Profile with `profile_id` was backed up to the cloud.
+    CloudBackupCompleted { profile_id: ProfileID },
+
+    /// Backing up the Profile with `profile_id` to the cloud failed with
+    /// `error`, the backup will be retried.
+    CloudBackupFailed {
+        profile_id: ProfileID,
+        error: String,
+    },
+
+    /// The Profile with `profile_id` was replaced by its last known good
+    /// snapshot, `is_automatic` if the Wallet did so itself since the saved
+    /// snapshot failed integrity checks when it was loaded.
+    ProfileRecoveredFromLastKnownGood {
+        profile_id: ProfileID,
+        is_automatic: bool,
+    },
+
+    /// The Profile with `profile_id` and all secrets of the wallet were
+    /// deleted, the last event emitted by the [`Wallet`].
+    WalletDeleted { profile_id: ProfileID },
+}
+
+/// Implemented by the Wallet Client to be notified of [`WalletEvent`]s.
+#[uniffi::export]
+pub trait WalletEventObserver: Send + Sync + std::fmt::Debug {
+    fn handle_event(&self, event: WalletEvent);
+}
+
+#[uniffi::export]
+impl Wallet {
+    /// Sets the observer to notify of all [`WalletEvent`]s, replacing any
+    /// previously set observer, which is notified of events emitted while
+    /// the Wallet was created, e.g. an automatic Profile recovery.
+    pub fn set_event_observer(&self, observer: Arc<dyn WalletEventObserver>) {
+        *self.event_observer.write().expect(
+            "Should be able to acquire write lock for event observer",
+        ) = Some(observer.clone());
+        let pending = std::mem::take(
+            &mut *self
+                .pending_events
+                .write()
+                .expect("Should be able to acquire write lock for events"),
+        );
+        pending
+            .into_iter()
+            .for_each(|event| observer.handle_event(event));
+    }
+}
+
+impl Wallet {
+    /// Invalidates cached Gateway data made stale by `event`, then notifies
+    /// the event observer, if any, of `event`.
+    pub(crate) fn emit_event(&self, event: WalletEvent) {
+        self.update_gateway_cache_with(|c| c.invalidate_for_event(&event));
+        let observer = self
+            .event_observer
+            .read()
+            .expect("Should be able to acquire read lock for event observer")
+            .clone();
+        if let Some(observer) = observer {
+            observer.handle_event(event);
+        }
+    }
+}
+
+/// Records all events it is notified of.
+#[cfg(test)]
+#[derive(Debug, Default)]
+pub(crate) struct RecordingEventObserver {
+    pub(crate) events: std::sync::RwLock<Vec<WalletEvent>>,
+}
+
+#[cfg(test)]
+impl WalletEventObserver for RecordingEventObserver {
+    fn handle_event(&self, event: WalletEvent) {
+        self.events.write().unwrap().push(event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn emit_without_observer_does_nothing() {
+        let sut = Wallet::placeholder();
+        sut.emit_event(WalletEvent::EntityRenamed {
+            address: AddressOfAccountOrPersona::placeholder(),
+            old_name: DisplayName::new("Alice").unwrap(),
+            new_name: DisplayName::new("Stella").unwrap(),
+        });
+    }
+
+    #[test]
+    fn emit_notifies_observer() {
+        let sut = Wallet::placeholder();
+        let observer = Arc::new(RecordingEventObserver::default());
+        sut.set_event_observer(observer.clone());
+        let event = WalletEvent::EntityRenamed {
+            address: AddressOfAccountOrPersona::placeholder(),
+            old_name: DisplayName::new("Alice").unwrap(),
+            new_name: DisplayName::new("Stella").unwrap(),
+        };
+        sut.emit_event(event.clone());
+        assert_eq!(*observer.events.read().unwrap(), vec![event]);
+    }
+}
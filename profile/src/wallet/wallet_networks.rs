@@ -0,0 +1,158 @@
+use crate::prelude::*;
+
+#[uniffi::export]
+impl Wallet {
+    /// Adds an empty network with `network_id` to the Profile unless it
+    /// already exists, saves the Profile and emits
+    /// [`WalletEvent::NetworkAdded`]. Returns `true` if it was added.
+    pub fn add_network_if_needed(&self, network_id: NetworkID) -> Result<bool> {
+        let added = self.try_update_profile_with(|mut p| {
+            Ok(p.add_network_if_needed(network_id))
+        })?;
+        if added {
+            self.emit_event(WalletEvent::NetworkAdded { network_id });
+        }
+        Ok(added)
+    }
+
+    /// Removes the network with `network_id` from the Profile, saves the
+    /// Profile and emits [`WalletEvent::NetworkRemoved`]. Only allowed if
+    /// the network has no Accounts or Personas and is not the network of
+    /// the current Gateway.
+    pub fn remove_network(&self, network_id: NetworkID) -> Result<()> {
+        self.try_update_profile_with(|mut p| p.remove_network(network_id))?;
+        self.emit_event(WalletEvent::NetworkRemoved { network_id });
+        Ok(())
+    }
+
+    /// Changes the current Gateway to `to`, adding its network to the
+    /// Profile if needed, saves the Profile and emits
+    /// [`WalletEvent::CurrentGatewayChanged`], and
+    /// [`WalletEvent::NetworkAdded`] if the network was added.
+    pub fn change_current_gateway(&self, to: Gateway) -> Result<()> {
+        let network_id = to.network.id;
+        let (changed, added) = self.try_update_profile_with(|mut p| {
+            if p.app_preferences.gateways.current == to {
+                return Ok((false, false));
+            }
+            p.change_current_gateway(to.clone())
+                .map(|added| (true, added))
+        })?;
+        if added {
+            self.emit_event(WalletEvent::NetworkAdded { network_id });
+        }
+        if changed {
+            self.emit_event(WalletEvent::CurrentGatewayChanged { to });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn make_sut() -> (Wallet, Arc<RecordingEventObserver>) {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let observer = Arc::new(RecordingEventObserver::default());
+        wallet.set_event_observer(observer.clone());
+        (wallet, observer)
+    }
+
+    #[test]
+    fn add_network_if_needed() {
+        let (sut, observer) = make_sut();
+        assert_eq!(sut.add_network_if_needed(NetworkID::Nebunet), Ok(true));
+        assert!(sut.access_profile_with(|p| p
+            .networks
+            .contains_id(&NetworkID::Nebunet)));
+        assert_eq!(
+            *observer.events.read().unwrap(),
+            vec![WalletEvent::NetworkAdded {
+                network_id: NetworkID::Nebunet
+            }]
+        );
+    }
+
+    #[test]
+    fn add_existing_network_emits_nothing() {
+        let (sut, observer) = make_sut();
+        assert_eq!(sut.add_network_if_needed(NetworkID::Mainnet), Ok(false));
+        assert!(observer.events.read().unwrap().is_empty());
+    }
+
+    #[test]
+    fn remove_network() {
+        let (sut, observer) = make_sut();
+        sut.add_network_if_needed(NetworkID::Nebunet).unwrap();
+        assert_eq!(sut.remove_network(NetworkID::Nebunet), Ok(()));
+        assert!(!sut.access_profile_with(|p| p
+            .networks
+            .contains_id(&NetworkID::Nebunet)));
+        assert_eq!(
+            observer.events.read().unwrap().last(),
+            Some(&WalletEvent::NetworkRemoved {
+                network_id: NetworkID::Nebunet
+            })
+        );
+    }
+
+    #[test]
+    fn remove_network_with_entities_fails() {
+        let (sut, observer) = make_sut();
+        assert_eq!(
+            sut.remove_network(NetworkID::Mainnet),
+            Err(CommonError::CannotRemoveNetworkWithEntities(
+                NetworkID::Mainnet
+            ))
+        );
+        assert!(sut.access_profile_with(|p| p
+            .networks
+            .contains_id(&NetworkID::Mainnet)));
+        assert!(observer.events.read().unwrap().is_empty());
+    }
+
+    #[test]
+    fn change_current_gateway_adds_network() {
+        let (sut, observer) = make_sut();
+        sut.change_current_gateway(Gateway::nebunet()).unwrap();
+        assert_eq!(
+            sut.access_profile_with(|p| p.current_network_id()),
+            NetworkID::Nebunet
+        );
+        assert_eq!(
+            *observer.events.read().unwrap(),
+            vec![
+                WalletEvent::NetworkAdded {
+                    network_id: NetworkID::Nebunet
+                },
+                WalletEvent::CurrentGatewayChanged {
+                    to: Gateway::nebunet()
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn change_current_gateway_to_current_emits_nothing() {
+        let (sut, observer) = make_sut();
+        let current = sut.access_profile_with(|p| {
+            p.app_preferences.gateways.current.clone()
+        });
+        sut.change_current_gateway(current).unwrap();
+        assert!(observer.events.read().unwrap().is_empty());
+    }
+
+    #[test]
+    fn change_current_gateway_is_persisted() {
+        let (sut, storage) = Wallet::ephemeral(Profile::placeholder());
+        sut.change_current_gateway(Gateway::mainnet()).unwrap();
+        let reloaded =
+            Wallet::by_loading_profile_with_id(sut.profile().id(), storage)
+                .unwrap();
+        assert_eq!(
+            reloaded.profile().app_preferences.gateways.current,
+            Gateway::mainnet()
+        );
+    }
+}
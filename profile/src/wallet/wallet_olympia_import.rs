@@ -0,0 +1,194 @@
+use crate::prelude::*;
+
+#[uniffi::export]
+impl Wallet {
+    /// Derives the secp256k1 keys at the Olympia BIP44 paths with address
+    /// indices `start_index..start_index + count` using the driver registered
+    /// for Ledger hardware wallets, returning the Olympia Accounts controlled
+    /// by the Ledger with `factor_source_id` together with the addresses of
+    /// their Babylon counterparts on `network_id`.
+    ///
+    /// Lets users re-discover Ledger controlled Olympia Accounts without the
+    /// Olympia wallet export, indices which cannot be hardened are skipped.
+    pub fn scan_olympia_ledger_accounts(
+        &self,
+        factor_source_id: FactorSourceIDFromHash,
+        network_id: NetworkID,
+        start_index: HDPathValue,
+        count: u32,
+        cancellation_token: Arc<CancellationToken>,
+    ) -> Result<Vec<DiscoveredOlympiaAccount>> {
+        let kind = factor_source_id.kind;
+        if kind != FactorSourceKind::LedgerHQHardwareWallet {
+            return Err(CommonError::CastFactorSourceWrongKind {
+                expected: FactorSourceKind::LedgerHQHardwareWallet,
+                found: kind,
+            });
+        }
+
+        let derivation_paths = (start_index..start_index.saturating_add(count))
+            .take_while(|i| !HDPathComponent::from(*i).is_hardened())
+            .map(|i| BIP44LikePath::new(i).into())
+            .collect_vec();
+
+        self.derive_public_keys_using_driver(
+            &factor_source_id,
+            derivation_paths,
+            DerivationPurpose::RecoveringAccounts,
+            &cancellation_token,
+        )?
+        .into_iter()
+        .map(|key| {
+            let (
+                PublicKey::Secp256k1 { value: public_key },
+                DerivationPath::BIP44Like { value: path },
+            ) = (key.public_key, key.derivation_path)
+            else {
+                return Err(CommonError::FactorSourceDriverInvalidResponse(
+                    kind,
+                ));
+            };
+            let account = OlympiaAccountToMigrate::new(
+                OlympiaAccountType::Hardware,
+                public_key,
+                path,
+                "",
+            );
+            Ok(DiscoveredOlympiaAccount::new(account, network_id))
+        })
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn token() -> Arc<CancellationToken> {
+        Arc::new(CancellationToken::new(OperationTimeoutPolicy::NoTimeout))
+    }
+
+    fn wallet_with_ledger_driver() -> (Wallet, Arc<MockFactorSourceDriver>) {
+        let (sut, _) = Wallet::ephemeral(Profile::placeholder());
+        let driver = Arc::new(MockFactorSourceDriver::new(
+            MnemonicWithPassphrase::placeholder(),
+        ));
+        sut.register_factor_source_driver(
+            FactorSourceKind::LedgerHQHardwareWallet,
+            driver.clone(),
+        );
+        (sut, driver)
+    }
+
+    #[test]
+    fn scan_derives_olympia_accounts_and_babylon_addresses() {
+        let (sut, driver) = wallet_with_ledger_driver();
+        let discovered = sut
+            .scan_olympia_ledger_accounts(
+                FactorSourceIDFromHash::placeholder_ledger(),
+                NetworkID::Mainnet,
+                2,
+                3,
+                token(),
+            )
+            .unwrap();
+
+        let mwp = MnemonicWithPassphrase::placeholder();
+        assert_eq!(
+            discovered
+                .iter()
+                .map(|d| d.account.derivation_path.clone())
+                .collect_vec(),
+            vec![
+                BIP44LikePath::new(2),
+                BIP44LikePath::new(3),
+                BIP44LikePath::new(4)
+            ]
+        );
+        for d in discovered {
+            let expected = mwp
+                .derive_private_keys(vec![d.account.derivation_path.clone()])
+                .remove(0)
+                .public_key()
+                .public_key;
+            assert_eq!(PublicKey::from(d.account.public_key), expected);
+            assert_eq!(d.account.account_type, OlympiaAccountType::Hardware);
+            assert_eq!(
+                d.babylon_address,
+                AccountAddress::new(expected, NetworkID::Mainnet)
+            );
+        }
+        assert_eq!(
+            *driver.purposes.read().unwrap(),
+            vec![DerivationPurpose::RecoveringAccounts]
+        );
+    }
+
+    #[test]
+    fn scan_skips_indices_which_cannot_be_hardened() {
+        let (sut, _) = wallet_with_ledger_driver();
+        let discovered = sut
+            .scan_olympia_ledger_accounts(
+                FactorSourceIDFromHash::placeholder_ledger(),
+                NetworkID::Mainnet,
+                2147483646,
+                5,
+                token(),
+            )
+            .unwrap();
+        assert_eq!(discovered.len(), 2);
+    }
+
+    #[test]
+    fn scan_requires_ledger_factor_source() {
+        let (sut, _) = wallet_with_ledger_driver();
+        assert_eq!(
+            sut.scan_olympia_ledger_accounts(
+                FactorSourceIDFromHash::placeholder_device(),
+                NetworkID::Mainnet,
+                0,
+                1,
+                token(),
+            ),
+            Err(CommonError::CastFactorSourceWrongKind {
+                expected: FactorSourceKind::LedgerHQHardwareWallet,
+                found: FactorSourceKind::Device,
+            })
+        );
+    }
+
+    #[test]
+    fn scan_requires_registered_driver() {
+        let (sut, _) = Wallet::ephemeral(Profile::placeholder());
+        assert_eq!(
+            sut.scan_olympia_ledger_accounts(
+                FactorSourceIDFromHash::placeholder_ledger(),
+                NetworkID::Mainnet,
+                0,
+                1,
+                token(),
+            ),
+            Err(CommonError::NoFactorSourceDriverRegistered(
+                FactorSourceKind::LedgerHQHardwareWallet
+            ))
+        );
+    }
+
+    #[test]
+    fn scan_cancelled() {
+        let (sut, driver) = wallet_with_ledger_driver();
+        let token = token();
+        token.cancel();
+        assert_eq!(
+            sut.scan_olympia_ledger_accounts(
+                FactorSourceIDFromHash::placeholder_ledger(),
+                NetworkID::Mainnet,
+                0,
+                1,
+                token,
+            ),
+            Err(CommonError::OperationCancelled)
+        );
+        assert!(driver.purposes.read().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,161 @@
+use crate::prelude::*;
+
+/// A view of a [`Wallet`] bound to a network, offering the operations of the
+/// Wallet which take a `NetworkID` without having to pass it, since Wallet
+/// Clients mostly work against the network of the current Gateway.
+///
+/// Holds a reference to the Wallet, so any change made through it is made to
+/// the Profile of the Wallet and vice versa.
+#[derive(Debug, uniffi::Object)]
+pub struct WalletOnNetwork {
+    wallet: Arc<Wallet>,
+    network_id: NetworkID,
+}
+
+#[uniffi::export]
+impl Wallet {
+    /// Returns a [`WalletOnNetwork`] bound to the network with `network_id`.
+    pub fn on_network(
+        self: Arc<Self>,
+        network_id: NetworkID,
+    ) -> Arc<WalletOnNetwork> {
+        Arc::new(WalletOnNetwork {
+            wallet: self,
+            network_id,
+        })
+    }
+
+    /// Returns a [`WalletOnNetwork`] bound to the network of the current
+    /// Gateway, it stays bound to that network if the current Gateway is
+    /// later changed.
+    pub fn on_current_network(self: Arc<Self>) -> Arc<WalletOnNetwork> {
+        let network_id = self.access_profile_with(|p| p.current_network_id());
+        self.on_network(network_id)
+    }
+}
+
+#[uniffi::export]
+impl WalletOnNetwork {
+    pub fn network_id(&self) -> NetworkID {
+        self.network_id
+    }
+
+    /// Returns the Accounts on the network, empty if the Profile does not
+    /// contain the network.
+    pub fn accounts(&self) -> Accounts {
+        self.wallet.access_profile_with(|p| {
+            p.networks
+                .get_id(&self.network_id)
+                .map(|n| n.accounts.clone())
+                .unwrap_or_default()
+        })
+    }
+
+    /// Creates a new Account on the network **WITHOUT** adding it to Profile,
+    /// see [`Wallet::create_new_account`].
+    pub fn create_new_account(&self, name: DisplayName) -> Result<Account> {
+        self.wallet.create_new_account(self.network_id, name)
+    }
+
+    /// Creates a new Account on the network and adds it to the Profile, see
+    /// [`Wallet::create_and_save_new_account`].
+    pub fn create_and_save_new_account(
+        &self,
+        name: DisplayName,
+    ) -> Result<Account> {
+        self.wallet
+            .create_and_save_new_account(self.network_id, name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    /// A Wallet with the placeholder Profile, which has Accounts on Mainnet
+    /// and Stokenet, and the mnemonic of its BDFS in secure storage.
+    fn make_wallet() -> Arc<Wallet> {
+        let private =
+            PrivateHierarchicalDeterministicFactorSource::placeholder();
+        let (wallet, storage) = Wallet::ephemeral(Profile::placeholder());
+        storage
+            .save_data(
+                SecureStorageKey::DeviceFactorSourceMnemonic {
+                    factor_source_id: private.factor_source.id.clone(),
+                },
+                serde_json::to_vec(&private.mnemonic_with_passphrase).unwrap(),
+            )
+            .unwrap();
+        Arc::new(wallet)
+    }
+
+    #[test]
+    fn network_id() {
+        let sut = make_wallet().on_network(NetworkID::Stokenet);
+        assert_eq!(sut.network_id(), NetworkID::Stokenet);
+    }
+
+    #[test]
+    fn on_current_network() {
+        let wallet = make_wallet();
+        let current = wallet.access_profile_with(|p| p.current_network_id());
+        assert_eq!(wallet.on_current_network().network_id(), current);
+    }
+
+    #[test]
+    fn accounts() {
+        let wallet = make_wallet();
+        assert_eq!(
+            wallet.clone().on_network(NetworkID::Mainnet).accounts(),
+            Accounts::placeholder_mainnet()
+        );
+        assert_eq!(
+            wallet.on_network(NetworkID::Stokenet).accounts(),
+            Accounts::placeholder_stokenet()
+        );
+    }
+
+    #[test]
+    fn accounts_of_unknown_network_is_empty() {
+        let sut = make_wallet().on_network(NetworkID::Nebunet);
+        assert!(sut.accounts().is_empty());
+    }
+
+    #[test]
+    fn create_new_account_is_not_saved() {
+        let sut = make_wallet().on_network(NetworkID::Stokenet);
+        let account = sut
+            .create_new_account(DisplayName::new("New").unwrap())
+            .unwrap();
+        assert_eq!(account.network_id, NetworkID::Stokenet);
+        assert_eq!(sut.accounts(), Accounts::placeholder_stokenet());
+    }
+
+    #[test]
+    fn create_and_save_new_account() {
+        let wallet = make_wallet();
+        let sut = wallet.clone().on_network(NetworkID::Stokenet);
+        let account = sut
+            .create_and_save_new_account(DisplayName::new("New").unwrap())
+            .unwrap();
+        assert_eq!(account.network_id, NetworkID::Stokenet);
+        assert_eq!(sut.accounts().last(), Some(&account));
+        assert_eq!(
+            wallet.accounts_on_all_networks().last(),
+            Some(&AccountOnNetwork::new(NetworkID::Stokenet, account))
+        );
+    }
+
+    #[test]
+    fn changes_through_wallet_are_visible() {
+        let wallet = make_wallet();
+        let sut = wallet.clone().on_network(NetworkID::Nebunet);
+        let account = wallet
+            .create_and_save_new_account(
+                NetworkID::Nebunet,
+                DisplayName::new("New").unwrap(),
+            )
+            .unwrap();
+        assert_eq!(sut.accounts().items(), vec![account]);
+    }
+}
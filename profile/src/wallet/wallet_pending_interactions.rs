@@ -0,0 +1,209 @@
+use crate::prelude::*;
+
+//========
+// GET - Pending Interactions
+//========
+#[uniffi::export]
+impl Wallet {
+    /// The dApp interactions received but not yet responded to - or whose
+    /// response has not yet been delivered - in the order received, so
+    /// that the host can resume them after the wallet app was killed.
+    ///
+    /// Expired interactions are dropped from SecureStorage.
+    pub fn pending_interactions(
+        &self,
+    ) -> Result<Vec<PendingWalletInteraction>> {
+        self.update_pending_interactions_with(|_| Ok(()))
+            .map(|i| i.items())
+    }
+}
+
+//========
+// SET - Pending Interactions
+//========
+#[uniffi::export]
+impl Wallet {
+    /// Persists `interaction` as pending, until it is removed with
+    /// [`Wallet::remove_pending_interaction`] or expires.
+    ///
+    /// Throws an error if an interaction with the same ID is pending.
+    pub fn enqueue_pending_interaction(
+        &self,
+        interaction: PendingWalletInteraction,
+    ) -> Result<()> {
+        self.update_pending_interactions_with(|pending| {
+            pending.try_insert_unique(interaction.clone())
+        })
+        .map(|_| ())
+    }
+
+    /// Stores `response` for the pending interaction with the same ID, so
+    /// that it can be delivered to the dApp even if the wallet app is
+    /// killed before doing so.
+    ///
+    /// Throws an error if no interaction with the ID is pending.
+    pub fn complete_pending_interaction(
+        &self,
+        response: WalletToDappInteractionResponse,
+    ) -> Result<()> {
+        let interaction_id = response.interaction_id().to_owned();
+        self.update_pending_interactions_with(|pending| {
+            if pending.update_with(&interaction_id, |i| {
+                i.response = Some(response.clone())
+            }) {
+                Ok(())
+            } else {
+                Err(CommonError::UnknownPendingWalletInteraction(
+                    interaction_id.clone(),
+                ))
+            }
+        })
+        .map(|_| ())
+    }
+
+    /// Removes the pending interaction with `interaction_id`, once its
+    /// response has been delivered or the user dismissed it, returning
+    /// `true` if it was pending.
+    pub fn remove_pending_interaction(
+        &self,
+        interaction_id: String,
+    ) -> Result<bool> {
+        let mut removed = false;
+        self.update_pending_interactions_with(|pending| {
+            removed = pending.remove_by_id(&interaction_id).is_some();
+            Ok(())
+        })?;
+        Ok(removed)
+    }
+}
+
+impl Wallet {
+    /// Loads the pending interactions, drops expired ones, applies `mutate`
+    /// and saves the result if anything changed.
+    fn update_pending_interactions_with<F>(
+        &self,
+        mutate: F,
+    ) -> Result<PendingWalletInteractions>
+    where
+        F: FnOnce(&mut PendingWalletInteractions) -> Result<()>,
+    {
+        let loaded = self
+            .wallet_client_storage
+            .load::<PendingWalletInteractions>(
+                SecureStorageKey::PendingWalletInteractions,
+            )?
+            .unwrap_or_else(PendingWalletInteractions::new);
+        let now = Timestamp::now();
+        let mut pending = PendingWalletInteractions::from_iter(
+            loaded.iter().filter(|i| !i.is_expired_at(&now)).cloned(),
+        );
+        mutate(&mut pending)?;
+        if pending != loaded {
+            self.wallet_client_storage
+                .save(SecureStorageKey::PendingWalletInteractions, &pending)?;
+        }
+        Ok(pending)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn interaction(id: &str, ttl_seconds: u64) -> PendingWalletInteraction {
+        PendingWalletInteraction::new(
+            id,
+            Url::parse("https://dashboard.radixdlt.com").unwrap(),
+            "{}",
+            ttl_seconds,
+        )
+    }
+
+    #[test]
+    fn empty_by_default() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        assert_eq!(wallet.pending_interactions(), Ok(Vec::new()));
+    }
+
+    #[test]
+    fn enqueue_survives_restart() {
+        let (wallet, storage) = Wallet::ephemeral(Profile::placeholder());
+        let first = interaction("a", 600);
+        let second = interaction("b", 600);
+        wallet.enqueue_pending_interaction(first.clone()).unwrap();
+        wallet.enqueue_pending_interaction(second.clone()).unwrap();
+
+        let reloaded = Wallet::by_loading_profile(storage).unwrap();
+        assert_eq!(reloaded.pending_interactions(), Ok(vec![first, second]));
+    }
+
+    #[test]
+    fn enqueue_duplicate_throws() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        wallet
+            .enqueue_pending_interaction(interaction("a", 600))
+            .unwrap();
+        assert!(wallet
+            .enqueue_pending_interaction(interaction("a", 600))
+            .is_err());
+        assert_eq!(wallet.pending_interactions().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn expired_are_dropped() {
+        let (wallet, storage) = Wallet::ephemeral(Profile::placeholder());
+        let expired = with_time_provider(
+            MockTimeProvider(Timestamp::placeholder()),
+            || interaction("a", 60),
+        );
+        wallet.enqueue_pending_interaction(expired).unwrap();
+        assert_eq!(wallet.pending_interactions(), Ok(Vec::new()));
+        assert_eq!(
+            storage.load_data(SecureStorageKey::PendingWalletInteractions),
+            Ok(Some(b"[]".to_vec()))
+        );
+    }
+
+    #[test]
+    fn complete_stores_response() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        wallet
+            .enqueue_pending_interaction(interaction("a", 600))
+            .unwrap();
+        let response = WalletToDappInteractionResponse::rejected_by_user("a");
+
+        wallet
+            .complete_pending_interaction(response.clone())
+            .unwrap();
+
+        assert_eq!(
+            wallet.pending_interactions().unwrap()[0].response,
+            Some(response)
+        );
+    }
+
+    #[test]
+    fn complete_unknown_throws() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        assert_eq!(
+            wallet.complete_pending_interaction(
+                WalletToDappInteractionResponse::rejected_by_user("a")
+            ),
+            Err(CommonError::UnknownPendingWalletInteraction("a".to_owned()))
+        );
+    }
+
+    #[test]
+    fn remove() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        wallet
+            .enqueue_pending_interaction(interaction("a", 600))
+            .unwrap();
+        assert_eq!(wallet.remove_pending_interaction("a".to_owned()), Ok(true));
+        assert_eq!(
+            wallet.remove_pending_interaction("a".to_owned()),
+            Ok(false)
+        );
+        assert_eq!(wallet.pending_interactions(), Ok(Vec::new()));
+    }
+}
@@ -0,0 +1,388 @@
+use crate::prelude::*;
+
+/// A Profile snapshot exported to plaintext JSON, ready to be written to a
+/// file by the host's file exporter.
+#[derive(Clone, Debug, PartialEq, Eq, uniffi::Record)]
+pub struct ProfileSnapshotExport {
+    /// The UTF-8 encoded JSON to write to the file.
+    pub json: BagOfBytes,
+
+    /// A suggested name of the file, containing the date of the last
+    /// modification of the Profile and the device it was last used on.
+    pub suggested_file_name: String,
+
+    /// `true` if the `json` contains secrets, i.e. the mnemonics of device
+    /// factor sources, in which case the host should warn the user that the
+    /// file MUST be kept safe.
+    pub contains_secrets: bool,
+}
+
+/// The mnemonic of a device factor source embedded in an export.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+struct ExportedMnemonic {
+    #[serde(rename = "factorSourceID")]
+    factor_source_id: FactorSourceIDFromHash,
+    mnemonic_with_passphrase: MnemonicWithPassphrase,
+}
+
+/// The JSON format of an export including sensitive data.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+struct ProfileSnapshotWithSecrets {
+    profile: Profile,
+    mnemonics: Vec<ExportedMnemonic>,
+}
+
+/// Either JSON format of an export, with or without sensitive data.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ExportedProfileSnapshot {
+    WithSecrets(ProfileSnapshotWithSecrets),
+    WithoutSecrets(Profile),
+}
+
+impl ProfileSnapshotWithSecrets {
+    /// Parses the JSON of an export, with or without sensitive data,
+    /// checking that every embedded mnemonic is the mnemonic of a device
+    /// factor source in the Profile.
+    fn from_export_json(json: &[u8]) -> Result<Self> {
+        let exported = serde_json::from_slice::<ExportedProfileSnapshot>(json)
+            .map_err(|_| CommonError::FailedToDeserializeJSONToValue {
+                json_byte_count: json.len(),
+                type_name: "ProfileSnapshotExport".to_string(),
+            })?;
+        let sut = match exported {
+            ExportedProfileSnapshot::WithSecrets(s) => s,
+            ExportedProfileSnapshot::WithoutSecrets(profile) => Self {
+                profile,
+                mnemonics: Vec::new(),
+            },
+        };
+        for mnemonic in sut.mnemonics.iter() {
+            let is_device_of_profile = sut
+                .profile
+                .factor_sources
+                .iter()
+                .filter_map(|f| f.as_device())
+                .any(|d| d.id == mnemonic.factor_source_id);
+            let id = FactorSourceIDFromHash::new_for_device(
+                mnemonic.mnemonic_with_passphrase.clone(),
+            );
+            if !is_device_of_profile || id != mnemonic.factor_source_id {
+                return Err(
+                    CommonError::ExportedMnemonicDoesNotMatchFactorSource(
+                        mnemonic.factor_source_id.clone(),
+                    ),
+                );
+            }
+        }
+        Ok(sut)
+    }
+}
+
+impl ProfileSnapshotExport {
+    /// The prefix of `suggested_file_name`.
+    pub const FILE_NAME_PREFIX: &'static str = "Radix-Wallet-Backup";
+
+    fn suggested_file_name_for(header: &Header) -> String {
+        let device = header
+            .last_used_on_device
+            .description
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+            .collect::<String>();
+        format!(
+            "{}_{}_{}.json",
+            Self::FILE_NAME_PREFIX,
+            date(&header.last_modified),
+            device
+        )
+    }
+}
+
+//========
+// EXPORT
+//========
+#[uniffi::export]
+impl Wallet {
+    /// Exports the Profile as plaintext JSON, if `include_sensitive` is `true`
+    /// the mnemonics of all device factor sources found in secure storage are
+    /// embedded as well. The JSON is encoded using [`to_pretty_json_stable`]
+    /// so that two exports can be diffed.
+    pub fn export_profile_snapshot(
+        &self,
+        include_sensitive: bool,
+    ) -> Result<ProfileSnapshotExport> {
+        self.wallet_client_storage.ensure_not_deleted()?;
+        let profile = self.profile();
+        let suggested_file_name =
+            ProfileSnapshotExport::suggested_file_name_for(&profile.header);
+
+        let mnemonics = if include_sensitive {
+            self.exported_mnemonics(&profile)?
+        } else {
+            Vec::new()
+        };
+        let contains_secrets = !mnemonics.is_empty();
+
+        let json = if include_sensitive {
+            to_pretty_json_stable(&ProfileSnapshotWithSecrets {
+                profile,
+                mnemonics,
+            })
+            .expect("Should always be able to JSON serialize a Profile.")
+        } else {
+            profile.to_pretty_json_stable()
+        };
+
+        Ok(ProfileSnapshotExport {
+            json: json.into_bytes().into(),
+            suggested_file_name,
+            contains_secrets,
+        })
+    }
+}
+
+//========
+// IMPORT
+//========
+#[uniffi::export]
+impl Wallet {
+    /// Creates a Wallet by importing the `json` of a file exported by
+    /// [`Wallet::export_profile_snapshot`], with or without sensitive data.
+    ///
+    /// The Profile is validated like [`Wallet::by_importing_validated_profile`]
+    /// does, and embedded mnemonics must belong to device factor sources of
+    /// the Profile. The mnemonics are saved together with the Profile, all or
+    /// none.
+    #[uniffi::constructor]
+    pub fn by_importing_profile_snapshot_export(
+        json: BagOfBytes,
+        secure_storage: Arc<dyn SecureStorage>,
+    ) -> Result<Self> {
+        Wallet::init_logging();
+
+        let imported = ProfileSnapshotWithSecrets::from_export_json(&json)?;
+        imported.profile.validate_for_import()?;
+
+        log::info!(
+            "Instantiating Wallet by importing an exported Profile with ID: {}, including {} mnemonics",
+            imported.profile.id(),
+            imported.mnemonics.len()
+        );
+
+        let profile = Self::repaired_for_import(imported.profile);
+        let wallet_client_storage = WalletClientStorage::new(secure_storage);
+        let mut entries = imported
+            .mnemonics
+            .iter()
+            .map(|m| {
+                WalletClientStorage::entry(
+                    SecureStorageKey::DeviceFactorSourceMnemonic {
+                        factor_source_id: m.factor_source_id.clone(),
+                    },
+                    &m.mnemonic_with_passphrase,
+                )
+            })
+            .collect::<Result<Vec<_>>>()?;
+        entries.push(wallet_client_storage.profile_snapshot_entry(&profile)?);
+        wallet_client_storage.save_many(entries)?;
+
+        let profile_id = profile.id();
+        let wallet = Self::with_profile(profile, wallet_client_storage);
+        wallet.save_active_profile_id_or_panic(&profile_id);
+        Ok(wallet)
+    }
+}
+
+impl Wallet {
+    fn exported_mnemonics(
+        &self,
+        profile: &Profile,
+    ) -> Result<Vec<ExportedMnemonic>> {
+        let mut mnemonics = Vec::new();
+        for device in profile
+            .factor_sources
+            .iter()
+            .filter_map(|f| f.as_device().cloned())
+        {
+            let mnemonic =
+                self.wallet_client_storage.load::<MnemonicWithPassphrase>(
+                    SecureStorageKey::DeviceFactorSourceMnemonic {
+                        factor_source_id: device.id.clone(),
+                    },
+                )?;
+            if let Some(mnemonic_with_passphrase) = mnemonic {
+                mnemonics.push(ExportedMnemonic {
+                    factor_source_id: device.id,
+                    mnemonic_with_passphrase,
+                });
+            }
+        }
+        Ok(mnemonics)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn wallet_with_secrets() -> Wallet {
+        Wallet::by_creating_new_profile_and_secrets_with_entropy(
+            Vec::from_iter([0xff; 32]),
+            WalletClientModel::Unknown,
+            "Test".to_string(),
+            EphemeralSecureStorage::new(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn suggested_file_name() {
+        let sut = Wallet::placeholder();
+        assert_eq!(
+            sut.export_profile_snapshot(false)
+                .unwrap()
+                .suggested_file_name,
+            "Radix-Wallet-Backup_2023-09-11_iPhone.json"
+        );
+    }
+
+    #[test]
+    fn suggested_file_name_replaces_special_characters() {
+        let mut header = Header::placeholder();
+        header.last_used_on_device.description = "My iPhone/Pro".to_owned();
+        assert_eq!(
+            ProfileSnapshotExport::suggested_file_name_for(&header),
+            "Radix-Wallet-Backup_2023-09-11_My-iPhone-Pro.json"
+        );
+    }
+
+    #[test]
+    fn without_sensitive_is_profile_json() {
+        let sut = Wallet::placeholder();
+        let export = sut.export_profile_snapshot(false).unwrap();
+        assert!(!export.contains_secrets);
+        assert_eq!(
+            serde_json::from_slice::<Profile>(&export.json).unwrap(),
+            Profile::placeholder()
+        );
+    }
+
+    #[test]
+    fn with_sensitive_but_no_mnemonics_in_storage() {
+        let sut = Wallet::placeholder();
+        let export = sut.export_profile_snapshot(true).unwrap();
+        assert!(!export.contains_secrets);
+    }
+
+    #[test]
+    fn with_sensitive_embeds_mnemonics() {
+        let sut = wallet_with_secrets();
+        let export = sut.export_profile_snapshot(true).unwrap();
+        assert!(export.contains_secrets);
+        let json: serde_json::Value =
+            serde_json::from_slice(&export.json).unwrap();
+        assert_eq!(
+            json["mnemonics"][0]["factorSourceID"],
+            serde_json::to_value(sut.profile().bdfs().id).unwrap()
+        );
+        assert_eq!(
+            serde_json::from_value::<Profile>(json["profile"].clone()).unwrap(),
+            sut.profile()
+        );
+    }
+
+    #[test]
+    fn without_sensitive_never_contains_secrets() {
+        let sut = wallet_with_secrets();
+        let export = sut.export_profile_snapshot(false).unwrap();
+        assert!(!export.contains_secrets);
+        assert_eq!(
+            export.json.to_vec(),
+            sut.profile().to_pretty_json_stable().into_bytes()
+        );
+    }
+
+    #[test]
+    fn roundtrip_without_sensitive() {
+        let sut = Wallet::placeholder();
+        let export = sut.export_profile_snapshot(false).unwrap();
+        let imported = Wallet::by_importing_profile_snapshot_export(
+            export.json,
+            EphemeralSecureStorage::new(),
+        )
+        .unwrap();
+        assert_eq!(imported.profile(), sut.profile());
+    }
+
+    #[test]
+    fn roundtrip_with_sensitive_restores_mnemonics() {
+        let sut = wallet_with_secrets();
+        let export = sut.export_profile_snapshot(true).unwrap();
+        let storage = EphemeralSecureStorage::new();
+        let imported = Wallet::by_importing_profile_snapshot_export(
+            export.json,
+            storage.clone(),
+        )
+        .unwrap();
+        assert_eq!(imported.profile(), sut.profile());
+        let bdfs_id = sut.profile().bdfs().id;
+        assert_eq!(
+            WalletClientStorage::new(storage)
+                .load_mnemonic_with_passphrase(&bdfs_id),
+            sut.wallet_client_storage
+                .load_mnemonic_with_passphrase(&bdfs_id)
+        );
+        assert_eq!(
+            imported.export_profile_snapshot(true).unwrap(),
+            sut.export_profile_snapshot(true).unwrap()
+        );
+    }
+
+    #[test]
+    fn import_fails_for_mnemonic_of_other_factor_source() {
+        let sut = wallet_with_secrets();
+        let export = sut.export_profile_snapshot(true).unwrap();
+        let mut json: serde_json::Value =
+            serde_json::from_slice(&export.json).unwrap();
+        json["mnemonics"][0]["mnemonicWithPassphrase"] =
+            serde_json::to_value(MnemonicWithPassphrase::placeholder())
+                .unwrap();
+        let storage = EphemeralSecureStorage::new();
+        assert_eq!(
+            Wallet::by_importing_profile_snapshot_export(
+                serde_json::to_vec(&json).unwrap().into(),
+                storage.clone(),
+            )
+            .map(|w| w.profile()),
+            Err(CommonError::ExportedMnemonicDoesNotMatchFactorSource(
+                sut.profile().bdfs().id
+            ))
+        );
+        assert_eq!(
+            WalletClientStorage::new(storage)
+                .load::<Profile>(SecureStorageKey::ProfileSnapshot {
+                    profile_id: sut.profile().id()
+                })
+                .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn import_fails_for_invalid_json() {
+        assert_eq!(
+            Wallet::by_importing_profile_snapshot_export(
+                BagOfBytes::from(b"[]".to_vec()),
+                EphemeralSecureStorage::new(),
+            )
+            .map(|w| w.profile()),
+            Err(CommonError::FailedToDeserializeJSONToValue {
+                json_byte_count: 2,
+                type_name: "ProfileSnapshotExport".to_string(),
+            })
+        );
+    }
+}
@@ -1,16 +1,42 @@
 use crate::prelude::*;
 
+//========
+// Atomic SecureStorage
+//========
+#[uniffi::export]
+impl Wallet {
+    /// Registers `driver` to be used to save several entries, e.g. a
+    /// Mnemonic and Profile, all-or-nothing, replacing any driver previously
+    /// registered. Without a driver the Wallet falls back to a journal.
+    pub fn register_atomic_secure_storage(
+        &self,
+        driver: Arc<dyn AtomicSecureStorage>,
+    ) {
+        self.wallet_client_storage
+            .set_atomic_interface(Some(driver));
+    }
+
+    /// Removes the registered `AtomicSecureStorage` driver, if any.
+    pub fn unregister_atomic_secure_storage(&self) {
+        self.wallet_client_storage.set_atomic_interface(None);
+    }
+
+    /// `true` if an `AtomicSecureStorage` driver is registered.
+    pub fn has_atomic_secure_storage(&self) -> bool {
+        self.wallet_client_storage.has_atomic_interface()
+    }
+}
+
 //========
 // Wallet + SecureStorage
 //========
 impl Wallet {
     pub(crate) fn save_profile(&self, profile: &Profile) -> Result<()> {
-        self.wallet_client_storage.save(
-            SecureStorageKey::ProfileSnapshot {
-                profile_id: profile.header.id.clone(),
-            },
-            profile,
-        )
+        measure(MeasuredOperation::ProfileSave, "save_profile", || {
+            self.wallet_client_storage.save_profile_snapshot(profile)
+        })?;
+        self.schedule_cloud_backup();
+        Ok(())
     }
     pub(crate) fn save_active_profile_id(
         &self,
@@ -82,7 +108,7 @@ mod tests {
                 &self,
                 _key: SecureStorageKey,
             ) -> Result<Option<Vec<u8>>> {
-                todo!()
+                Ok(None)
             }
 
             fn save_data(
@@ -123,7 +149,7 @@ mod tests {
                 &self,
                 _key: SecureStorageKey,
             ) -> Result<Option<Vec<u8>>> {
-                todo!()
+                Ok(None)
             }
 
             fn save_data(
@@ -202,4 +228,17 @@ mod tests {
         )
         .unwrap();
     }
+
+    #[test]
+    fn save_profile_is_measured() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let driver = Arc::new(RecordingMetricsDriver::default());
+        with_metrics_driver(driver.clone(), || {
+            wallet.save_existing_profile().unwrap()
+        });
+        assert_eq!(
+            driver.recorded(),
+            vec![(MeasuredOperation::ProfileSave, "save_profile".to_owned())]
+        );
+    }
 }
@@ -0,0 +1,204 @@
+use crate::prelude::*;
+
+//========
+// RECOVER
+//========
+#[uniffi::export]
+impl Wallet {
+    /// Replaces the Profile with its last known good snapshot - the snapshot
+    /// saved before the latest one - and saves it, e.g. when the Wallet Client
+    /// finds that the saved snapshot fails integrity checks.
+    ///
+    /// Emits [`WalletEvent::ProfileRecoveredFromLastKnownGood`].
+    pub fn recover_last_known_good(&self) -> Result<()> {
+        let profile_id = self.profile().id();
+        let profile = self
+            .wallet_client_storage
+            .load_last_known_good_profile_snapshot(&profile_id)?;
+
+        self.try_update_profile_with(|mut p| {
+            *p = profile.clone();
+            Ok(())
+        })?;
+
+        self.emit_event(WalletEvent::ProfileRecoveredFromLastKnownGood {
+            profile_id,
+            is_automatic: false,
+        });
+        Ok(())
+    }
+}
+
+impl Wallet {
+    /// Creates a Wallet with the last known good snapshot of the Profile with
+    /// `profile_id`, since its saved snapshot failed to load with `error`, and
+    /// saves it as the Profile snapshot.
+    ///
+    /// Returns `error` if no last known good snapshot can be loaded, and
+    /// otherwise notifies the first event observer set of the recovery.
+    pub(crate) fn new_recover_last_known_good(
+        profile_id: ProfileID,
+        wallet_client_storage: WalletClientStorage,
+        error: CommonError,
+    ) -> Result<Self> {
+        let profile = wallet_client_storage
+            .load_last_known_good_profile_snapshot(&profile_id)
+            .map_err(|recovery_error| {
+                log::error!(
+                    "Failed to recover Profile with ID: {} from last known good snapshot, error: {}",
+                    profile_id, recovery_error
+                );
+                error
+            })?;
+
+        log::warn!(
+            "Recovered Profile with ID: {} from last known good snapshot",
+            profile_id
+        );
+
+        let wallet = Self::with_profile(profile, wallet_client_storage);
+        wallet.save_existing_profile()?;
+        wallet.save_active_profile_id_or_panic(&profile_id);
+        wallet
+            .pending_events
+            .write()
+            .expect("Should be able to acquire write lock for events")
+            .push(WalletEvent::ProfileRecoveredFromLastKnownGood {
+                profile_id,
+                is_automatic: true,
+            });
+
+        Ok(wallet)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn hide_currency_amounts(wallet: &Wallet) {
+        wallet
+            .try_update_profile_with(|mut p| {
+                p.app_preferences.display.is_currency_amount_visible = false;
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn save_keeps_previous_snapshot_as_last_known_good() {
+        let (sut, _) = Wallet::ephemeral(Profile::placeholder());
+        let previous = sut.profile();
+
+        hide_currency_amounts(&sut);
+
+        assert_eq!(
+            sut.wallet_client_storage
+                .load_last_known_good_profile_snapshot(&previous.id()),
+            Ok(previous)
+        );
+    }
+
+    #[test]
+    fn recover_last_known_good() {
+        let (sut, _) = Wallet::ephemeral(Profile::placeholder());
+        let previous = sut.profile();
+        hide_currency_amounts(&sut);
+        let observer = Arc::new(RecordingEventObserver::default());
+        sut.set_event_observer(observer.clone());
+
+        assert_eq!(sut.recover_last_known_good(), Ok(()));
+
+        assert_eq!(sut.profile().app_preferences, previous.app_preferences);
+        assert_eq!(
+            sut.wallet_client_storage
+                .load_profile_snapshot(&previous.id())
+                .unwrap()
+                .app_preferences,
+            previous.app_preferences
+        );
+        assert_eq!(
+            *observer.events.read().unwrap(),
+            vec![WalletEvent::ProfileRecoveredFromLastKnownGood {
+                profile_id: previous.id(),
+                is_automatic: false,
+            }]
+        );
+    }
+
+    #[test]
+    fn recover_last_known_good_without_snapshot_fails() {
+        let sut = Wallet::placeholder();
+        assert_eq!(
+            sut.recover_last_known_good(),
+            Err(CommonError::NoLastKnownGoodProfileSnapshot(
+                sut.profile().id()
+            ))
+        );
+    }
+
+    #[test]
+    fn corrupted_snapshot_is_never_kept_as_last_known_good() {
+        let storage = FlakyStorage::ephemeral();
+        let sut = Wallet::by_importing_profile(
+            Profile::placeholder(),
+            storage.clone(),
+        );
+        let previous = sut.profile();
+        hide_currency_amounts(&sut);
+        storage.corrupt_reads_of(SecureStorageKey::ProfileSnapshot {
+            profile_id: previous.id(),
+        });
+
+        sut.save_existing_profile().unwrap();
+
+        assert_eq!(
+            sut.wallet_client_storage
+                .load_last_known_good_profile_snapshot(&previous.id()),
+            Ok(previous)
+        );
+    }
+
+    #[test]
+    fn loading_corrupted_snapshot_recovers_last_known_good() {
+        let storage = FlakyStorage::ephemeral();
+        let wallet = Wallet::by_importing_profile(
+            Profile::placeholder(),
+            storage.clone(),
+        );
+        let previous = wallet.profile();
+        hide_currency_amounts(&wallet);
+        drop(wallet);
+        storage.corrupt_reads_of(SecureStorageKey::ProfileSnapshot {
+            profile_id: previous.id(),
+        });
+
+        let sut = Wallet::by_loading_profile(storage.clone()).unwrap();
+        assert_eq!(sut.profile().app_preferences, previous.app_preferences);
+
+        let observer = Arc::new(RecordingEventObserver::default());
+        sut.set_event_observer(observer.clone());
+        assert_eq!(
+            *observer.events.read().unwrap(),
+            vec![WalletEvent::ProfileRecoveredFromLastKnownGood {
+                profile_id: previous.id(),
+                is_automatic: true,
+            }]
+        );
+
+        // Pending events are only delivered once
+        let other_observer = Arc::new(RecordingEventObserver::default());
+        sut.set_event_observer(other_observer.clone());
+        assert!(other_observer.events.read().unwrap().is_empty());
+
+        // The recovered Profile was saved as snapshot
+        storage.reset_script();
+        assert_eq!(
+            Wallet::by_loading_profile(storage)
+                .unwrap()
+                .profile()
+                .app_preferences,
+            previous.app_preferences
+        );
+    }
+}
@@ -0,0 +1,110 @@
+use crate::prelude::*;
+
+#[uniffi::export]
+impl Wallet {
+    /// Resolves which factor instances must sign a transaction with
+    /// `manifest` and whether the notary counts as a signatory, from the
+    /// static analysis of the manifest, see [`Profile::signing_policy`].
+    pub fn signing_policy(
+        &self,
+        manifest: TransactionManifest,
+    ) -> Result<SigningPolicy> {
+        let summary = ManifestSummary::from_manifest(&manifest)?;
+        self.access_profile_with(|p| p.signing_policy(&summary))
+    }
+
+    /// The warnings the transaction review should show prominently for a
+    /// transaction with `manifest`, e.g. that it changes control of one of
+    /// the Accounts of the user, see [`Profile::manifest_warnings`].
+    pub fn manifest_warnings(
+        &self,
+        manifest: TransactionManifest,
+    ) -> Result<Vec<ManifestWarning>> {
+        let summary = ManifestSummary::from_manifest(&manifest)?;
+        Ok(self.access_profile_with(|p| p.manifest_warnings(&summary)))
+    }
+
+    /// Describes per factor source which entities sign which transactions
+    /// of `plan`, so that the signing flow can be shown before it starts,
+    /// see [`SigningPlan::describe`].
+    pub fn describe_signing_plan(
+        &self,
+        plan: SigningPlan,
+    ) -> Result<Vec<FactorSourceSigningDescription>> {
+        self.access_profile_with(|p| plan.describe(p))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn signing_policy() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let alice = Account::placeholder_mainnet_alice();
+        let manifest = TransactionManifest::placeholder_transfer(
+            &alice.address,
+            &AccountAddress::placeholder_mainnet(),
+        );
+        assert_eq!(
+            wallet.signing_policy(manifest).unwrap().notary,
+            Some(alice.transaction_signing_factor_instance().unwrap())
+        );
+    }
+
+    #[test]
+    fn describe_signing_plan() {
+        let profile = Profile::placeholder();
+        let (wallet, _) = Wallet::ephemeral(profile.clone());
+        let alice = Account::placeholder_mainnet_alice();
+        let policy = wallet
+            .signing_policy(TransactionManifest::placeholder_transfer(
+                &alice.address,
+                &AccountAddress::placeholder_mainnet(),
+            ))
+            .unwrap();
+        let plan = SigningPlan::new([TransactionToSign::new(
+            TransactionIntentHash::placeholder(),
+            policy,
+        )]);
+        assert_eq!(
+            wallet.describe_signing_plan(plan.clone()),
+            plan.describe(&profile)
+        );
+    }
+
+    #[test]
+    fn signing_policy_unknown_account_fails() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        assert_eq!(
+            wallet.signing_policy(TransactionManifest::placeholder()),
+            Err(CommonError::UnknownAccount)
+        );
+    }
+
+    #[test]
+    fn manifest_warnings() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let alice = Account::placeholder_mainnet_alice().address;
+        let manifest = TransactionManifest::new(
+            format!(
+                r#"
+SET_OWNER_ROLE
+    Address("{alice}")
+    Enum<0u8>()
+;
+"#
+            ),
+            NetworkID::Mainnet,
+            [],
+        )
+        .unwrap();
+        assert_eq!(
+            wallet.manifest_warnings(manifest),
+            Ok(vec![ManifestWarning::ChangesControlOfEntity {
+                address: alice.into()
+            }])
+        );
+    }
+}
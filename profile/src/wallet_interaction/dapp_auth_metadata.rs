@@ -0,0 +1,137 @@
+use crate::prelude::*;
+
+use radix_engine_common::crypto::Hash;
+
+/// The identity of the dApp requesting a proof of ownership, which is signed
+/// together with the challenge so that a proof produced for one dApp cannot
+/// be replayed to another, as per ROLA (Radix Off-Ledger Authentication).
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct DappAuthMetadata {
+    pub dapp_definition_address: DappDefinitionAddress,
+
+    /// The origin of the website of the dApp, e.g.
+    /// `"https://dashboard.radixdlt.com"`.
+    pub origin: Url,
+}
+
+impl DappAuthMetadata {
+    /// The first byte of every ROLA payload, `'R'`.
+    pub const ROLA_PAYLOAD_PREFIX: u8 = 0x52;
+
+    pub fn new(
+        dapp_definition_address: DappDefinitionAddress,
+        origin: Url,
+    ) -> Self {
+        Self {
+            dapp_definition_address,
+            origin,
+        }
+    }
+
+    /// The payload which is signed to prove ownership of an entity:
+    /// the prefix, the `challenge`, the length of the bech32 encoded dApp
+    /// definition address followed by the address itself and finally the
+    /// origin, without trailing slash.
+    pub fn rola_payload(&self, challenge: &Hex32Bytes) -> Vec<u8> {
+        let address = self.dapp_definition_address.to_string();
+        let origin = self.origin.origin().ascii_serialization();
+        let mut payload = vec![Self::ROLA_PAYLOAD_PREFIX];
+        payload.extend(challenge.bytes());
+        payload.push(address.len() as u8);
+        payload.extend(address.as_bytes());
+        payload.extend(origin.as_bytes());
+        payload
+    }
+
+    /// The hash of [`Self::rola_payload`], which is what gets signed.
+    pub fn rola_payload_hash(&self, challenge: &Hex32Bytes) -> Hash {
+        hash(self.rola_payload(challenge))
+    }
+}
+
+impl HasPlaceholder for DappAuthMetadata {
+    fn placeholder() -> Self {
+        Self::new(
+            "account_rdx12xuhw6v30chdkhcu7qznz9vu926vxefr4h4tdvc0mdckg9rq4afx9t"
+                .parse()
+                .unwrap(),
+            Url::parse("https://dashboard.radixdlt.com").unwrap(),
+        )
+    }
+
+    fn placeholder_other() -> Self {
+        Self::new(
+            "account_tdx_2_12yf9gd53yfep7a669fv2t3wm7nz9zeezwd04n02a433ker8vza6rhe"
+                .parse()
+                .unwrap(),
+            Url::parse("https://stokenet-dashboard.radixdlt.com").unwrap(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality() {
+        assert_eq!(
+            DappAuthMetadata::placeholder(),
+            DappAuthMetadata::placeholder()
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            DappAuthMetadata::placeholder(),
+            DappAuthMetadata::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn rola_payload() {
+        let sut = DappAuthMetadata::placeholder();
+        let challenge = Hex32Bytes::placeholder();
+        let address = sut.dapp_definition_address.to_string();
+        let payload = sut.rola_payload(&challenge);
+
+        assert_eq!(payload[0], 0x52);
+        assert_eq!(&payload[1..33], &challenge.bytes());
+        assert_eq!(payload[33] as usize, address.len());
+        assert_eq!(
+            String::from_utf8(payload[34..].to_vec()).unwrap(),
+            format!("{}https://dashboard.radixdlt.com", address)
+        );
+    }
+
+    #[test]
+    fn rola_payload_origin_has_no_trailing_slash() {
+        let sut = DappAuthMetadata::new(
+            DappAuthMetadata::placeholder().dapp_definition_address,
+            Url::parse("https://dashboard.radixdlt.com/some/path").unwrap(),
+        );
+        assert_eq!(
+            sut.rola_payload(&Hex32Bytes::placeholder()),
+            DappAuthMetadata::placeholder()
+                .rola_payload(&Hex32Bytes::placeholder())
+        );
+    }
+
+    #[test]
+    fn rola_payload_hash_depends_on_challenge_and_dapp() {
+        let sut = DappAuthMetadata::placeholder();
+        let challenge = Hex32Bytes::placeholder();
+        assert_ne!(
+            sut.rola_payload_hash(&challenge),
+            sut.rola_payload_hash(&Hex32Bytes::placeholder_other())
+        );
+        assert_ne!(
+            sut.rola_payload_hash(&challenge),
+            DappAuthMetadata::placeholder_other().rola_payload_hash(&challenge)
+        );
+    }
+}
@@ -0,0 +1,71 @@
+use crate::prelude::*;
+
+/// The reason a wallet interaction failed, sent to the dApp in a
+/// [`WalletToDappInteractionResponse::Failure`].
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    enum_iterator::Sequence,
+    uniffi::Enum,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum DappWalletInteractionErrorType {
+    RejectedByUser,
+    WrongNetwork,
+    FailedToPrepareTransaction,
+    FailedToCompileTransaction,
+    FailedToSignTransaction,
+    FailedToSubmitTransaction,
+    FailedToPollSubmittedTransaction,
+    FailedToSignAuthChallenge,
+    SubmittedTransactionWasDuplicate,
+    SubmittedTransactionHasFailedTransactionStatus,
+    SubmittedTransactionHasRejectedTransactionStatus,
+    WrongAccountType,
+    UnknownWebsite,
+    InvalidOriginURL,
+    RadixJsonNotFound,
+    UnknownDappDefinitionAddress,
+    InvalidPersona,
+    InvalidRequest,
+    IncompatibleVersion,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn json_roundtrip() {
+        assert_json_value_eq_after_roundtrip(
+            &DappWalletInteractionErrorType::RejectedByUser,
+            json!("rejectedByUser"),
+        );
+        assert_json_value_eq_after_roundtrip(
+            &DappWalletInteractionErrorType::WrongNetwork,
+            json!("wrongNetwork"),
+        );
+        assert_json_value_eq_after_roundtrip(
+            &DappWalletInteractionErrorType::FailedToPrepareTransaction,
+            json!("failedToPrepareTransaction"),
+        );
+        assert_json_value_eq_after_roundtrip(
+            &DappWalletInteractionErrorType::InvalidOriginURL,
+            json!("invalidOriginURL"),
+        );
+    }
+
+    #[test]
+    fn all_distinct_json() {
+        let all = enum_iterator::all::<DappWalletInteractionErrorType>()
+            .map(|e| serde_json::to_string(&e).unwrap())
+            .collect::<HashSet<_>>();
+        assert_eq!(all.len(), 19);
+    }
+}
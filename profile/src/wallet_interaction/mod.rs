@@ -0,0 +1,17 @@
+mod dapp_auth_metadata;
+mod dapp_wallet_interaction_error_type;
+mod pending_wallet_interaction;
+mod rola_challenge;
+mod wallet_interaction_entities;
+mod wallet_to_dapp_interaction_auth_proof;
+mod wallet_to_dapp_interaction_response;
+mod wallet_to_dapp_interaction_response_items;
+
+pub use dapp_auth_metadata::*;
+pub use dapp_wallet_interaction_error_type::*;
+pub use pending_wallet_interaction::*;
+pub use rola_challenge::*;
+pub use wallet_interaction_entities::*;
+pub use wallet_to_dapp_interaction_auth_proof::*;
+pub use wallet_to_dapp_interaction_response::*;
+pub use wallet_to_dapp_interaction_response_items::*;
@@ -0,0 +1,197 @@
+use crate::prelude::*;
+
+/// A request of a dApp the wallet has received but not yet responded to,
+/// persisted so that the host can resume the flow if the wallet app is
+/// killed mid-interaction.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct PendingWalletInteraction {
+    pub interaction_id: String,
+
+    /// The origin of the website of the dApp which sent the request.
+    pub origin: Url,
+
+    /// The request as received, which the host parses again on resume.
+    pub request_json: String,
+
+    pub received_at: Timestamp,
+
+    /// The number of seconds after `received_at` after which the dApp no
+    /// longer awaits a response, and the interaction is dropped.
+    pub ttl_seconds: u64,
+
+    /// The response to send, once the user has completed the interaction,
+    /// kept until the host confirms it has been delivered.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response: Option<WalletToDappInteractionResponse>,
+}
+
+/// The persisted queue of [`PendingWalletInteraction`]s, in the order they
+/// were received.
+pub type PendingWalletInteractions = IdentifiedVecOf<PendingWalletInteraction>;
+
+impl PendingWalletInteraction {
+    /// The default for `ttl_seconds`, ten minutes.
+    pub const DEFAULT_TTL_SECONDS: u64 = 600;
+
+    /// A pending interaction received now, without a response.
+    pub fn new(
+        interaction_id: impl AsRef<str>,
+        origin: Url,
+        request_json: impl AsRef<str>,
+        ttl_seconds: u64,
+    ) -> Self {
+        Self {
+            interaction_id: interaction_id.as_ref().to_owned(),
+            origin,
+            request_json: request_json.as_ref().to_owned(),
+            received_at: Timestamp::now(),
+            ttl_seconds,
+            response: None,
+        }
+    }
+
+    /// `true` if the dApp no longer awaits a response at `now`.
+    pub fn is_expired_at(&self, now: &Timestamp) -> bool {
+        now.seconds_since(&self.received_at) >= self.ttl_seconds as i64
+    }
+
+    /// `true` if the user completed the interaction but the response has
+    /// not yet been delivered.
+    pub fn has_response(&self) -> bool {
+        self.response.is_some()
+    }
+}
+
+impl Identifiable for PendingWalletInteraction {
+    type ID = String;
+
+    fn id(&self) -> Self::ID {
+        self.interaction_id.clone()
+    }
+}
+
+impl HasPlaceholder for PendingWalletInteraction {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self {
+            interaction_id: "1d8f2f6b-0bd6-4ae2-9f0a-c1e0c0e4b0a1".to_owned(),
+            origin: Url::parse("https://dashboard.radixdlt.com").unwrap(),
+            request_json:
+                r#"{"interactionId":"1d8f2f6b-0bd6-4ae2-9f0a-c1e0c0e4b0a1"}"#
+                    .to_owned(),
+            received_at: Timestamp::placeholder(),
+            ttl_seconds: Self::DEFAULT_TTL_SECONDS,
+            response: None,
+        }
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self {
+            interaction_id: "b2f1d9c4-1e77-4c3f-8f6e-6a1f0b0c2d3e".to_owned(),
+            origin: Url::parse("https://gumball-club.radixdlt.com").unwrap(),
+            request_json:
+                r#"{"interactionId":"b2f1d9c4-1e77-4c3f-8f6e-6a1f0b0c2d3e"}"#
+                    .to_owned(),
+            received_at: Timestamp::placeholder_other(),
+            ttl_seconds: 60,
+            response: Some(WalletToDappInteractionResponse::rejected_by_user(
+                "b2f1d9c4-1e77-4c3f-8f6e-6a1f0b0c2d3e",
+            )),
+        }
+    }
+}
+
+#[uniffi::export]
+pub fn new_pending_wallet_interaction(
+    interaction_id: String,
+    origin: Url,
+    request_json: String,
+    ttl_seconds: u64,
+) -> PendingWalletInteraction {
+    PendingWalletInteraction::new(
+        interaction_id,
+        origin,
+        request_json,
+        ttl_seconds,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = PendingWalletInteraction;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::placeholder(), SUT::placeholder());
+        assert_eq!(SUT::placeholder_other(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::placeholder(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn new_is_received_now() {
+        let now = Timestamp::placeholder();
+        let sut = with_time_provider(MockTimeProvider(now), || {
+            SUT::new("abc", Url::parse("https://a.com").unwrap(), "{}", 60)
+        });
+        assert_eq!(sut.received_at, now);
+        assert!(!sut.has_response());
+    }
+
+    #[test]
+    fn is_expired_at() {
+        let sut = SUT::placeholder();
+        assert!(!sut.is_expired_at(&sut.received_at));
+        assert!(sut.is_expired_at(&Timestamp::placeholder_other()));
+    }
+
+    #[test]
+    fn has_response() {
+        assert!(!SUT::placeholder().has_response());
+        assert!(SUT::placeholder_other().has_response());
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        assert_json_roundtrip(&SUT::placeholder_other());
+        assert_eq_after_json_roundtrip(
+            &SUT::placeholder(),
+            r#"
+            {
+                "interactionId": "1d8f2f6b-0bd6-4ae2-9f0a-c1e0c0e4b0a1",
+                "origin": "https://dashboard.radixdlt.com/",
+                "requestJson": "{\"interactionId\":\"1d8f2f6b-0bd6-4ae2-9f0a-c1e0c0e4b0a1\"}",
+                "receivedAt": "2023-09-11T16:05:56.000Z",
+                "ttlSeconds": 600
+            }
+            "#,
+        );
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn new() {
+        let sut = new_pending_wallet_interaction(
+            "abc".to_owned(),
+            Url::parse("https://a.com").unwrap(),
+            "{}".to_owned(),
+            60,
+        );
+        assert_eq!(sut.interaction_id, "abc");
+        assert_eq!(sut.ttl_seconds, 60);
+    }
+}
@@ -0,0 +1,244 @@
+use crate::prelude::*;
+
+use radix_engine_common::crypto::Hash;
+
+/// A challenge of ROLA (Radix Off-Ledger Authentication), the `nonce` a
+/// dApp sends to the wallet together with its `metadata`. The wallet proves
+/// ownership of an entity by signing the hash of [`Self::payload`], which
+/// the dApp backend checks with [`Self::verify`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct RolaChallenge {
+    pub metadata: DappAuthMetadata,
+
+    /// 32 random bytes generated by the dApp backend, single use.
+    pub nonce: Hex32Bytes,
+}
+
+impl RolaChallenge {
+    pub fn new(
+        origin: Url,
+        dapp_definition_address: DappDefinitionAddress,
+        nonce: Hex32Bytes,
+    ) -> Self {
+        Self {
+            metadata: DappAuthMetadata::new(dapp_definition_address, origin),
+            nonce,
+        }
+    }
+
+    /// The exact bytes of the payload to sign, see
+    /// [`DappAuthMetadata::rola_payload`].
+    pub fn payload(&self) -> Vec<u8> {
+        self.metadata.rola_payload(&self.nonce)
+    }
+
+    /// The hash of [`Self::payload`], which is what gets signed.
+    pub fn payload_hash(&self) -> Hash {
+        self.metadata.rola_payload_hash(&self.nonce)
+    }
+
+    /// Returns `true` if `signature` is a valid signature of this challenge
+    /// by `public_key`, `false` if not or if they are on different curves.
+    pub fn verify(
+        &self,
+        signature: &Signature,
+        public_key: &PublicKey,
+    ) -> bool {
+        SignatureWithPublicKey::new(public_key.clone(), signature.clone())
+            .is_ok_and(|s| s.is_valid_for(&self.payload_hash()))
+    }
+
+    /// Returns `true` if `proof` - as sent by the wallet - is a valid
+    /// signature of this challenge.
+    pub fn verify_proof(
+        &self,
+        proof: &WalletToDappInteractionAuthProof,
+    ) -> bool {
+        Signature::from_bytes(proof.curve(), proof.signature.as_slice())
+            .is_ok_and(|signature| self.verify(&signature, &proof.public_key))
+    }
+}
+
+#[uniffi::export]
+pub fn new_rola_challenge(
+    origin: Url,
+    dapp_definition_address: DappDefinitionAddress,
+    nonce: Hex32Bytes,
+) -> RolaChallenge {
+    RolaChallenge::new(origin, dapp_definition_address, nonce)
+}
+
+#[uniffi::export]
+pub fn rola_challenge_payload(challenge: &RolaChallenge) -> BagOfBytes {
+    challenge.payload().into()
+}
+
+#[uniffi::export]
+pub fn rola_challenge_verify(
+    challenge: &RolaChallenge,
+    signature: Signature,
+    public_key: PublicKey,
+) -> bool {
+    challenge.verify(&signature, &public_key)
+}
+
+impl HasPlaceholder for RolaChallenge {
+    fn placeholder() -> Self {
+        Self::new(
+            Url::parse("https://dashboard.radixdlt.com").unwrap(),
+            "account_rdx12xuhw6v30chdkhcu7qznz9vu926vxefr4h4tdvc0mdckg9rq4afx9t"
+                .parse()
+                .unwrap(),
+            "ec5dcb3d1f75627be1021cb8890f0e8ce0c9fe7f2ff55cbdff096b38a32612c9"
+                .parse()
+                .unwrap(),
+        )
+    }
+
+    fn placeholder_other() -> Self {
+        Self {
+            metadata: DappAuthMetadata::placeholder_other(),
+            nonce: Hex32Bytes::placeholder_other(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = RolaChallenge;
+
+    /// Signature by `Ed25519PrivateKey::placeholder_alice` of the payload
+    /// hash of `RolaChallenge::placeholder`.
+    const SIGNATURE_ALICE: &str = "780acf3b3e67b1f4e1052fbd2b764ddc0cdca186eb6363f94d4f7a8a9e0dc53349fa68ffcc7a6d56d675b96aff27096c3dd468285e66b97693f09fb4c734d909";
+
+    fn signature_alice() -> Signature {
+        Signature::from_hex(SLIP10Curve::Curve25519, SIGNATURE_ALICE).unwrap()
+    }
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::placeholder(), SUT::placeholder());
+        assert_eq!(SUT::placeholder_other(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::placeholder(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn payload_vector() {
+        assert_eq!(
+            hex_encode(SUT::placeholder().payload()),
+            "52ec5dcb3d1f75627be1021cb8890f0e8ce0c9fe7f2ff55cbdff096b38a32612c9426163636f756e745f726478313278756877367633306368646b68637537717a6e7a397675393236767865667234683474647663306d64636b6739727134616678397468747470733a2f2f64617368626f6172642e7261646978646c742e636f6d"
+        );
+    }
+
+    #[test]
+    fn payload_hash_vector() {
+        assert_eq!(
+            hex_encode(SUT::placeholder().payload_hash().0),
+            "75a9a112eff1b1f9ea423ba31071bf836a96faa582a5ce672178a45c25f413d4"
+        );
+    }
+
+    #[test]
+    fn verify_vector() {
+        assert!(SUT::placeholder().verify(
+            &signature_alice(),
+            &PublicKey::placeholder_ed25519_alice()
+        ));
+    }
+
+    #[test]
+    fn verify_wrong_public_key() {
+        assert!(!SUT::placeholder()
+            .verify(&signature_alice(), &PublicKey::placeholder_ed25519_bob()));
+    }
+
+    #[test]
+    fn verify_wrong_challenge() {
+        assert!(!SUT::placeholder_other().verify(
+            &signature_alice(),
+            &PublicKey::placeholder_ed25519_alice()
+        ));
+    }
+
+    #[test]
+    fn verify_curve_mismatch() {
+        assert!(!SUT::placeholder().verify(
+            &signature_alice(),
+            &PublicKey::placeholder_secp256k1_alice()
+        ));
+    }
+
+    #[test]
+    fn verify_secp256k1() {
+        let sut = SUT::placeholder();
+        let private_key = PrivateKey::from(Secp256k1PrivateKey::placeholder());
+        let signed = private_key.sign(&sut.payload_hash());
+        assert!(sut.verify(&signed.signature(), &private_key.public_key()));
+        assert!(!SUT::placeholder_other()
+            .verify(&signed.signature(), &private_key.public_key()));
+    }
+
+    #[test]
+    fn verify_proof() {
+        let sut = SUT::placeholder();
+        let proof = WalletToDappInteractionAuthProof::new(
+            PublicKey::placeholder_ed25519_alice(),
+            signature_alice().to_bytes().into(),
+        );
+        assert!(sut.verify_proof(&proof));
+        assert!(!SUT::placeholder_other().verify_proof(&proof));
+    }
+
+    #[test]
+    fn verify_proof_malformed_signature() {
+        let proof = WalletToDappInteractionAuthProof::new(
+            PublicKey::placeholder_ed25519_alice(),
+            vec![0xde, 0xad].into(),
+        );
+        assert!(!SUT::placeholder().verify_proof(&proof));
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = RolaChallenge;
+
+    #[test]
+    fn new() {
+        let sut = SUT::placeholder();
+        assert_eq!(
+            new_rola_challenge(
+                sut.metadata.origin.clone(),
+                sut.metadata.dapp_definition_address.clone(),
+                sut.nonce.clone()
+            ),
+            sut
+        );
+    }
+
+    #[test]
+    fn payload() {
+        let sut = SUT::placeholder();
+        assert_eq!(rola_challenge_payload(&sut).to_vec(), sut.payload());
+    }
+
+    #[test]
+    fn verify() {
+        let sut = SUT::placeholder();
+        assert!(!rola_challenge_verify(
+            &sut,
+            Signature::placeholder(),
+            PublicKey::placeholder_ed25519_bob()
+        ));
+    }
+}
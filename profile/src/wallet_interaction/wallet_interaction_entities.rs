@@ -0,0 +1,117 @@
+use crate::prelude::*;
+
+/// An account shared with a dApp, as sent in wallet interaction responses.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletInteractionWalletAccount {
+    pub address: AccountAddress,
+    pub label: DisplayName,
+    pub appearance_id: AppearanceID,
+}
+
+impl From<&Account> for WalletInteractionWalletAccount {
+    fn from(value: &Account) -> Self {
+        Self {
+            address: value.address.clone(),
+            label: value.display_name.clone(),
+            appearance_id: value.appearance_id,
+        }
+    }
+}
+
+impl HasPlaceholder for WalletInteractionWalletAccount {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        (&Account::placeholder()).into()
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        (&Account::placeholder_other()).into()
+    }
+}
+
+/// The persona used to log in to a dApp, as sent in wallet interaction
+/// responses.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct DappWalletInteractionPersona {
+    pub identity_address: IdentityAddress,
+    pub label: DisplayName,
+}
+
+impl From<&Persona> for DappWalletInteractionPersona {
+    fn from(value: &Persona) -> Self {
+        Self {
+            identity_address: value.address.clone(),
+            label: value.display_name.clone(),
+        }
+    }
+}
+
+impl HasPlaceholder for DappWalletInteractionPersona {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        (&Persona::placeholder()).into()
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        (&Persona::placeholder_other()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            WalletInteractionWalletAccount::placeholder(),
+            WalletInteractionWalletAccount::placeholder_other()
+        );
+        assert_ne!(
+            DappWalletInteractionPersona::placeholder(),
+            DappWalletInteractionPersona::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn account_from_account() {
+        let account = Account::placeholder();
+        let sut = WalletInteractionWalletAccount::from(&account);
+        assert_eq!(sut.address, account.address);
+        assert_eq!(sut.label, account.display_name);
+        assert_eq!(sut.appearance_id, account.appearance_id);
+    }
+
+    #[test]
+    fn account_json() {
+        let sut = WalletInteractionWalletAccount::placeholder();
+        assert_json_value_eq_after_roundtrip(
+            &sut,
+            json!({
+                "address": sut.address.to_string(),
+                "label": sut.label.to_string(),
+                "appearanceId": sut.appearance_id.value
+            }),
+        );
+    }
+
+    #[test]
+    fn persona_json() {
+        let sut = DappWalletInteractionPersona::placeholder();
+        assert_json_value_eq_after_roundtrip(
+            &sut,
+            json!({
+                "identityAddress": sut.identity_address.to_string(),
+                "label": sut.label.to_string()
+            }),
+        );
+    }
+}
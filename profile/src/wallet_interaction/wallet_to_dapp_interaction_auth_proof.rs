@@ -0,0 +1,222 @@
+use crate::prelude::*;
+
+/// A proof of ownership of an entity, the signature by one of its public
+/// keys of a challenge sent by the dApp.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct WalletToDappInteractionAuthProof {
+    pub public_key: PublicKey,
+    pub signature: BagOfBytes,
+}
+
+impl WalletToDappInteractionAuthProof {
+    pub fn new(public_key: PublicKey, signature: BagOfBytes) -> Self {
+        Self {
+            public_key,
+            signature,
+        }
+    }
+
+    pub fn curve(&self) -> SLIP10Curve {
+        self.public_key.curve()
+    }
+}
+
+impl From<OffDeviceSignature> for WalletToDappInteractionAuthProof {
+    fn from(value: OffDeviceSignature) -> Self {
+        Self::new(value.public_key.public_key, value.signature)
+    }
+}
+
+impl From<SignatureWithPublicKey> for WalletToDappInteractionAuthProof {
+    fn from(value: SignatureWithPublicKey) -> Self {
+        Self::new(value.public_key(), value.signature().to_bytes().into())
+    }
+}
+
+/// The dApp expects the public key as a hex string with the curve as a
+/// sibling field, rather than the representation used in the Profile.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AuthProofWire {
+    public_key: String,
+    curve: SLIP10Curve,
+    signature: BagOfBytes,
+}
+
+impl Serialize for WalletToDappInteractionAuthProof {
+    #[cfg(not(tarpaulin_include))] // false negative
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        AuthProofWire {
+            public_key: self.public_key.to_hex(),
+            curve: self.curve(),
+            signature: self.signature.clone(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for WalletToDappInteractionAuthProof {
+    #[cfg(not(tarpaulin_include))] // false negative
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let wire = AuthProofWire::deserialize(deserializer)?;
+        let public_key = match wire.curve {
+            SLIP10Curve::Curve25519 => {
+                PublicKey::ed25519_from_str(&wire.public_key)
+            }
+            SLIP10Curve::Secp256k1 => {
+                PublicKey::secp256k1_from_str(&wire.public_key)
+            }
+        }
+        .map_err(de::Error::custom)?;
+        Ok(Self::new(public_key, wire.signature))
+    }
+}
+
+impl HasPlaceholder for WalletToDappInteractionAuthProof {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        OffDeviceSignature::produced_by(
+            &HierarchicalDeterministicPrivateKey::placeholder(),
+            Hex32Bytes::placeholder(),
+        )
+        .into()
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        OffDeviceSignature::produced_by(
+            &HierarchicalDeterministicPrivateKey::placeholder(),
+            Hex32Bytes::placeholder_other(),
+        )
+        .into()
+    }
+}
+
+/// A proof of ownership of the account with `account_address`.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletToDappInteractionAccountProof {
+    pub account_address: AccountAddress,
+    pub proof: WalletToDappInteractionAuthProof,
+}
+
+impl WalletToDappInteractionAccountProof {
+    pub fn new(
+        account_address: AccountAddress,
+        proof: impl Into<WalletToDappInteractionAuthProof>,
+    ) -> Self {
+        Self {
+            account_address,
+            proof: proof.into(),
+        }
+    }
+}
+
+impl HasPlaceholder for WalletToDappInteractionAccountProof {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::new(
+            AccountAddress::placeholder(),
+            WalletToDappInteractionAuthProof::placeholder(),
+        )
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::new(
+            AccountAddress::placeholder_other(),
+            WalletToDappInteractionAuthProof::placeholder_other(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality() {
+        assert_eq!(
+            WalletToDappInteractionAuthProof::placeholder(),
+            WalletToDappInteractionAuthProof::placeholder()
+        );
+        assert_eq!(
+            WalletToDappInteractionAccountProof::placeholder_other(),
+            WalletToDappInteractionAccountProof::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            WalletToDappInteractionAuthProof::placeholder(),
+            WalletToDappInteractionAuthProof::placeholder_other()
+        );
+        assert_ne!(
+            WalletToDappInteractionAccountProof::placeholder(),
+            WalletToDappInteractionAccountProof::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn from_off_device_signature() {
+        let signature = OffDeviceSignature::produced_by(
+            &HierarchicalDeterministicPrivateKey::placeholder(),
+            Hex32Bytes::placeholder(),
+        );
+        let sut = WalletToDappInteractionAuthProof::from(signature.clone());
+        assert_eq!(sut.public_key, signature.public_key.public_key);
+        assert_eq!(sut.signature, signature.signature);
+    }
+
+    #[test]
+    fn from_signature_with_public_key() {
+        let signature = SignatureWithPublicKey::placeholder_other();
+        let sut = WalletToDappInteractionAuthProof::from(signature.clone());
+        assert_eq!(sut.public_key, signature.public_key());
+        assert_eq!(sut.signature.to_vec(), signature.signature().to_bytes());
+        assert_eq!(
+            serde_json::to_value(&sut).unwrap(),
+            serde_json::to_value(&signature).unwrap()
+        );
+    }
+
+    #[test]
+    fn json_uses_hex_public_key_and_curve() {
+        let sut = WalletToDappInteractionAuthProof::placeholder();
+        let json = serde_json::to_value(&sut).unwrap();
+        assert_eq!(json["publicKey"], json!(sut.public_key.to_hex()));
+        assert_eq!(json["curve"], json!("curve25519"));
+        assert_eq!(json["signature"], json!(sut.signature.to_hex()));
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        let sut = WalletToDappInteractionAccountProof::placeholder();
+        let json = serde_json::to_string(&sut).unwrap();
+        assert_eq!(
+            serde_json::from_str::<WalletToDappInteractionAccountProof>(&json)
+                .unwrap(),
+            sut
+        );
+    }
+
+    #[test]
+    fn json_invalid_public_key_fails() {
+        assert!(serde_json::from_value::<WalletToDappInteractionAuthProof>(
+            json!({
+                "publicKey": "deadbeef",
+                "curve": "curve25519",
+                "signature": "deadbeef"
+            })
+        )
+        .is_err());
+    }
+}
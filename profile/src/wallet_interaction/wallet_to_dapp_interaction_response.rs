@@ -0,0 +1,468 @@
+use crate::prelude::*;
+
+/// The response sent by the wallet to a dApp, over Radix Connect or a
+/// deep link, once a wallet interaction has been handled.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Enum,
+)]
+#[serde(tag = "discriminator", rename_all = "camelCase")]
+pub enum WalletToDappInteractionResponse {
+    #[serde(rename_all = "camelCase")]
+    Success {
+        interaction_id: String,
+        items: WalletToDappInteractionResponseItems,
+    },
+
+    #[serde(rename_all = "camelCase")]
+    Failure {
+        interaction_id: String,
+        error: DappWalletInteractionErrorType,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        message: Option<String>,
+    },
+}
+
+impl WalletToDappInteractionResponse {
+    pub fn success(
+        interaction_id: impl AsRef<str>,
+        items: WalletToDappInteractionResponseItems,
+    ) -> Self {
+        Self::Success {
+            interaction_id: interaction_id.as_ref().to_owned(),
+            items,
+        }
+    }
+
+    pub fn failure(
+        interaction_id: impl AsRef<str>,
+        error: DappWalletInteractionErrorType,
+        message: Option<String>,
+    ) -> Self {
+        Self::Failure {
+            interaction_id: interaction_id.as_ref().to_owned(),
+            error,
+            message,
+        }
+    }
+
+    /// The response to a login request, with the accounts the user chose
+    /// to share on an ongoing basis, if any were requested.
+    pub fn authorized(
+        interaction_id: impl AsRef<str>,
+        auth: WalletToDappInteractionAuthRequestResponseItem,
+        ongoing_accounts: Option<
+            WalletToDappInteractionAccountsRequestResponseItem,
+        >,
+    ) -> Self {
+        Self::success(
+            interaction_id,
+            WalletToDappInteractionResponseItems::AuthorizedRequest {
+                auth,
+                ongoing_accounts,
+                one_time_accounts: None,
+            },
+        )
+    }
+
+    /// The response to a one-time accounts request.
+    pub fn one_time_accounts(
+        interaction_id: impl AsRef<str>,
+        accounts: WalletToDappInteractionAccountsRequestResponseItem,
+    ) -> Self {
+        Self::success(
+            interaction_id,
+            WalletToDappInteractionResponseItems::UnauthorizedRequest {
+                one_time_accounts: Some(accounts),
+            },
+        )
+    }
+
+    /// The response to a transaction request, once the transaction with
+    /// `transaction_intent_hash` has been submitted.
+    pub fn transaction_submitted(
+        interaction_id: impl AsRef<str>,
+        transaction_intent_hash: impl AsRef<str>,
+    ) -> Self {
+        Self::success(
+            interaction_id,
+            WalletToDappInteractionResponseItems::Transaction {
+                send: WalletToDappInteractionSendTransactionResponseItem {
+                    transaction_intent_hash: transaction_intent_hash
+                        .as_ref()
+                        .to_owned(),
+                },
+            },
+        )
+    }
+
+    pub fn rejected_by_user(interaction_id: impl AsRef<str>) -> Self {
+        Self::failure(
+            interaction_id,
+            DappWalletInteractionErrorType::RejectedByUser,
+            None,
+        )
+    }
+
+    /// The dApp sent a request for `requested` network, but the wallet is
+    /// currently on `current`.
+    pub fn wrong_network(
+        interaction_id: impl AsRef<str>,
+        current: NetworkID,
+        requested: NetworkID,
+    ) -> Self {
+        Self::failure(
+            interaction_id,
+            DappWalletInteractionErrorType::WrongNetwork,
+            Some(format!(
+                "Wallet is using network {}, dApp requested network {}",
+                current.logical_name(),
+                requested.logical_name()
+            )),
+        )
+    }
+
+    pub fn failed_to_prepare_transaction(
+        interaction_id: impl AsRef<str>,
+        message: impl AsRef<str>,
+    ) -> Self {
+        Self::failure(
+            interaction_id,
+            DappWalletInteractionErrorType::FailedToPrepareTransaction,
+            Some(message.as_ref().to_owned()),
+        )
+    }
+
+    pub fn interaction_id(&self) -> &str {
+        match self {
+            Self::Success { interaction_id, .. } => interaction_id,
+            Self::Failure { interaction_id, .. } => interaction_id,
+        }
+    }
+
+    /// The JSON representation of this response, as expected by the
+    /// connector.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self)
+            .expect("Should always be able to JSON serialize a response.")
+    }
+}
+
+impl HasPlaceholder for WalletToDappInteractionResponse {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::authorized(
+            "placeholder",
+            WalletToDappInteractionAuthRequestResponseItem::LoginWithoutChallenge {
+                persona: DappWalletInteractionPersona::placeholder(),
+            },
+            Some(
+                WalletToDappInteractionAccountsRequestResponseItem::without_proofs(
+                    [&Account::placeholder()],
+                ),
+            ),
+        )
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::rejected_by_user("placeholder_other")
+    }
+}
+
+#[uniffi::export]
+pub fn new_wallet_to_dapp_interaction_response_success(
+    interaction_id: String,
+    items: WalletToDappInteractionResponseItems,
+) -> WalletToDappInteractionResponse {
+    WalletToDappInteractionResponse::success(interaction_id, items)
+}
+
+#[uniffi::export]
+pub fn new_wallet_to_dapp_interaction_response_failure(
+    interaction_id: String,
+    error: DappWalletInteractionErrorType,
+    message: Option<String>,
+) -> WalletToDappInteractionResponse {
+    WalletToDappInteractionResponse::failure(interaction_id, error, message)
+}
+
+#[uniffi::export]
+pub fn new_wallet_to_dapp_interaction_response_transaction_submitted(
+    interaction_id: String,
+    transaction_intent_hash: String,
+) -> WalletToDappInteractionResponse {
+    WalletToDappInteractionResponse::transaction_submitted(
+        interaction_id,
+        transaction_intent_hash,
+    )
+}
+
+#[uniffi::export]
+pub fn new_wallet_to_dapp_interaction_response_wrong_network(
+    interaction_id: String,
+    current: NetworkID,
+    requested: NetworkID,
+) -> WalletToDappInteractionResponse {
+    WalletToDappInteractionResponse::wrong_network(
+        interaction_id,
+        current,
+        requested,
+    )
+}
+
+#[uniffi::export]
+pub fn new_wallet_to_dapp_interaction_accounts_response_item(
+    accounts: Vec<Account>,
+    challenge: Option<Hex32Bytes>,
+    proofs: Vec<WalletToDappInteractionAccountProof>,
+) -> Result<WalletToDappInteractionAccountsRequestResponseItem> {
+    match challenge {
+        Some(challenge) => {
+            WalletToDappInteractionAccountsRequestResponseItem::with_proofs(
+                &accounts, challenge, proofs,
+            )
+        }
+        None => Ok(
+            WalletToDappInteractionAccountsRequestResponseItem::without_proofs(
+                &accounts,
+            ),
+        ),
+    }
+}
+
+#[uniffi::export]
+pub fn wallet_to_dapp_interaction_response_to_json(
+    response: &WalletToDappInteractionResponse,
+) -> String {
+    response.to_json()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            WalletToDappInteractionResponse::placeholder(),
+            WalletToDappInteractionResponse::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn interaction_id() {
+        assert_eq!(
+            WalletToDappInteractionResponse::placeholder().interaction_id(),
+            "placeholder"
+        );
+        assert_eq!(
+            WalletToDappInteractionResponse::placeholder_other()
+                .interaction_id(),
+            "placeholder_other"
+        );
+    }
+
+    #[test]
+    fn rejected_by_user_json() {
+        assert_json_value_eq_after_roundtrip(
+            &WalletToDappInteractionResponse::rejected_by_user("abc"),
+            json!({
+                "discriminator": "failure",
+                "interactionId": "abc",
+                "error": "rejectedByUser"
+            }),
+        );
+    }
+
+    #[test]
+    fn wrong_network_json() {
+        assert_json_value_eq_after_roundtrip(
+            &WalletToDappInteractionResponse::wrong_network(
+                "abc",
+                NetworkID::Mainnet,
+                NetworkID::Stokenet,
+            ),
+            json!({
+                "discriminator": "failure",
+                "interactionId": "abc",
+                "error": "wrongNetwork",
+                "message": "Wallet is using network mainnet, dApp requested network stokenet"
+            }),
+        );
+    }
+
+    #[test]
+    fn failed_to_prepare_transaction() {
+        assert_eq!(
+            WalletToDappInteractionResponse::failed_to_prepare_transaction(
+                "abc", "oops"
+            ),
+            WalletToDappInteractionResponse::Failure {
+                interaction_id: "abc".to_owned(),
+                error:
+                    DappWalletInteractionErrorType::FailedToPrepareTransaction,
+                message: Some("oops".to_owned())
+            }
+        );
+    }
+
+    #[test]
+    fn transaction_submitted_json() {
+        assert_json_value_eq_after_roundtrip(
+            &WalletToDappInteractionResponse::transaction_submitted(
+                "abc",
+                "txid_rdx1",
+            ),
+            json!({
+                "discriminator": "success",
+                "interactionId": "abc",
+                "items": {
+                    "discriminator": "transaction",
+                    "send": { "transactionIntentHash": "txid_rdx1" }
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn authorized_json() {
+        let json = serde_json::to_value(
+            WalletToDappInteractionResponse::placeholder(),
+        )
+        .unwrap();
+        assert_eq!(json["discriminator"], json!("success"));
+        assert_eq!(json["items"]["discriminator"], json!("authorizedRequest"));
+        assert_eq!(
+            json["items"]["auth"]["discriminator"],
+            json!("loginWithoutChallenge")
+        );
+        assert!(json["items"]["ongoingAccounts"]["accounts"].is_array());
+        assert!(json["items"].get("oneTimeAccounts").is_none());
+    }
+
+    #[test]
+    fn one_time_accounts() {
+        let accounts =
+            WalletToDappInteractionAccountsRequestResponseItem::without_proofs(
+                [&Account::placeholder()],
+            );
+        assert_eq!(
+            WalletToDappInteractionResponse::one_time_accounts(
+                "abc",
+                accounts.clone()
+            ),
+            WalletToDappInteractionResponse::Success {
+                interaction_id: "abc".to_owned(),
+                items:
+                    WalletToDappInteractionResponseItems::UnauthorizedRequest {
+                        one_time_accounts: Some(accounts)
+                    }
+            }
+        );
+    }
+
+    #[test]
+    fn to_json_roundtrip() {
+        let sut = WalletToDappInteractionResponse::placeholder();
+        assert_eq!(
+            serde_json::from_str::<WalletToDappInteractionResponse>(
+                &sut.to_json()
+            )
+            .unwrap(),
+            sut
+        );
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn success() {
+        let items = WalletToDappInteractionResponseItems::UnauthorizedRequest {
+            one_time_accounts: None,
+        };
+        assert_eq!(
+            new_wallet_to_dapp_interaction_response_success(
+                "abc".to_owned(),
+                items.clone()
+            ),
+            WalletToDappInteractionResponse::success("abc", items)
+        );
+    }
+
+    #[test]
+    fn failure() {
+        assert_eq!(
+            new_wallet_to_dapp_interaction_response_failure(
+                "abc".to_owned(),
+                DappWalletInteractionErrorType::RejectedByUser,
+                None
+            ),
+            WalletToDappInteractionResponse::rejected_by_user("abc")
+        );
+    }
+
+    #[test]
+    fn transaction_submitted() {
+        assert_eq!(
+            new_wallet_to_dapp_interaction_response_transaction_submitted(
+                "abc".to_owned(),
+                "txid_rdx1".to_owned()
+            ),
+            WalletToDappInteractionResponse::transaction_submitted(
+                "abc",
+                "txid_rdx1"
+            )
+        );
+    }
+
+    #[test]
+    fn wrong_network() {
+        assert_eq!(
+            new_wallet_to_dapp_interaction_response_wrong_network(
+                "abc".to_owned(),
+                NetworkID::Mainnet,
+                NetworkID::Stokenet
+            ),
+            WalletToDappInteractionResponse::wrong_network(
+                "abc",
+                NetworkID::Mainnet,
+                NetworkID::Stokenet
+            )
+        );
+    }
+
+    #[test]
+    fn accounts_response_item() {
+        let account = Account::placeholder_other();
+        assert_eq!(
+            new_wallet_to_dapp_interaction_accounts_response_item(
+                vec![account.clone()],
+                None,
+                Vec::new()
+            ),
+            Ok(
+                WalletToDappInteractionAccountsRequestResponseItem::without_proofs(
+                    [&account]
+                )
+            )
+        );
+        assert!(new_wallet_to_dapp_interaction_accounts_response_item(
+            vec![account],
+            Some(Hex32Bytes::placeholder()),
+            Vec::new()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn to_json() {
+        let sut = WalletToDappInteractionResponse::placeholder_other();
+        assert_eq!(
+            wallet_to_dapp_interaction_response_to_json(&sut),
+            sut.to_json()
+        );
+    }
+}
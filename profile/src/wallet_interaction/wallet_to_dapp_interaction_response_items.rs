@@ -0,0 +1,267 @@
+use crate::prelude::*;
+
+/// The accounts shared with a dApp, ongoing or one-time, optionally with
+/// proofs of ownership if the dApp sent a challenge.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletToDappInteractionAccountsRequestResponseItem {
+    pub accounts: Vec<WalletInteractionWalletAccount>,
+
+    /// The challenge sent by the dApp, present iff `proofs` is.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub challenge: Option<Hex32Bytes>,
+
+    /// One proof of ownership per account in `accounts`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proofs: Option<Vec<WalletToDappInteractionAccountProof>>,
+}
+
+impl WalletToDappInteractionAccountsRequestResponseItem {
+    /// The selected `accounts`, for a request without a challenge.
+    pub fn without_proofs<'a, I>(accounts: I) -> Self
+    where
+        I: IntoIterator<Item = &'a Account>,
+    {
+        Self {
+            accounts: accounts.into_iter().map(|a| a.into()).collect_vec(),
+            challenge: None,
+            proofs: None,
+        }
+    }
+
+    /// The selected `accounts` together with the `proofs` of their
+    /// ownership, created by signing `challenge`. Fails if any of the
+    /// accounts is missing a proof.
+    pub fn with_proofs<'a, I>(
+        accounts: I,
+        challenge: Hex32Bytes,
+        proofs: Vec<WalletToDappInteractionAccountProof>,
+    ) -> Result<Self>
+    where
+        I: IntoIterator<Item = &'a Account>,
+    {
+        let sut = Self {
+            challenge: Some(challenge),
+            proofs: Some(proofs),
+            ..Self::without_proofs(accounts)
+        };
+        sut.validate_proofs()?;
+        Ok(sut)
+    }
+
+    fn validate_proofs(&self) -> Result<()> {
+        let Some(proofs) = self.proofs.as_ref() else {
+            return Ok(());
+        };
+        if let Some(missing) = self
+            .accounts
+            .iter()
+            .find(|a| !proofs.iter().any(|p| p.account_address == a.address))
+        {
+            return Err(CommonError::WalletInteractionMissingAccountProof(
+                missing.address.to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// The persona the user logged in with.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Enum,
+)]
+#[serde(tag = "discriminator", rename_all = "camelCase")]
+pub enum WalletToDappInteractionAuthRequestResponseItem {
+    /// The dApp asked to use a persona it is already authorized to use.
+    UsePersona {
+        persona: DappWalletInteractionPersona,
+    },
+
+    /// The user logged in without the dApp requesting a proof.
+    LoginWithoutChallenge {
+        persona: DappWalletInteractionPersona,
+    },
+
+    /// The user logged in and proved ownership of the persona by signing
+    /// `challenge`.
+    LoginWithChallenge {
+        persona: DappWalletInteractionPersona,
+        challenge: Hex32Bytes,
+        proof: WalletToDappInteractionAuthProof,
+    },
+}
+
+impl WalletToDappInteractionAuthRequestResponseItem {
+    pub fn persona(&self) -> &DappWalletInteractionPersona {
+        match self {
+            Self::UsePersona { persona } => persona,
+            Self::LoginWithoutChallenge { persona } => persona,
+            Self::LoginWithChallenge { persona, .. } => persona,
+        }
+    }
+}
+
+/// The result of a submitted transaction request.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletToDappInteractionSendTransactionResponseItem {
+    /// The bech32 encoded intent hash of the submitted transaction, e.g.
+    /// `"txid_rdx1..."`.
+    pub transaction_intent_hash: String,
+}
+
+/// The items of a successful [`WalletToDappInteractionResponse`], one
+/// variant per kind of request.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Enum,
+)]
+#[serde(tag = "discriminator", rename_all = "camelCase")]
+pub enum WalletToDappInteractionResponseItems {
+    #[serde(rename_all = "camelCase")]
+    AuthorizedRequest {
+        auth: WalletToDappInteractionAuthRequestResponseItem,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        ongoing_accounts:
+            Option<WalletToDappInteractionAccountsRequestResponseItem>,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        one_time_accounts:
+            Option<WalletToDappInteractionAccountsRequestResponseItem>,
+    },
+
+    #[serde(rename_all = "camelCase")]
+    UnauthorizedRequest {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        one_time_accounts:
+            Option<WalletToDappInteractionAccountsRequestResponseItem>,
+    },
+
+    Transaction {
+        send: WalletToDappInteractionSendTransactionResponseItem,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn without_proofs() {
+        let accounts = [Account::placeholder(), Account::placeholder_other()];
+        let sut =
+            WalletToDappInteractionAccountsRequestResponseItem::without_proofs(
+                &accounts,
+            );
+        assert_eq!(sut.accounts.len(), 2);
+        assert_eq!(sut.challenge, None);
+        assert_eq!(sut.proofs, None);
+    }
+
+    #[test]
+    fn with_proofs() {
+        let account = Account::placeholder();
+        let proof = WalletToDappInteractionAccountProof::new(
+            account.address.clone(),
+            WalletToDappInteractionAuthProof::placeholder(),
+        );
+        let sut =
+            WalletToDappInteractionAccountsRequestResponseItem::with_proofs(
+                [&account],
+                Hex32Bytes::placeholder(),
+                vec![proof.clone()],
+            )
+            .unwrap();
+        assert_eq!(sut.challenge, Some(Hex32Bytes::placeholder()));
+        assert_eq!(sut.proofs, Some(vec![proof]));
+    }
+
+    #[test]
+    fn with_proofs_missing_proof() {
+        let account = Account::placeholder_other();
+        assert_eq!(
+            WalletToDappInteractionAccountsRequestResponseItem::with_proofs(
+                [&account],
+                Hex32Bytes::placeholder(),
+                vec![WalletToDappInteractionAccountProof::placeholder()],
+            ),
+            Err(CommonError::WalletInteractionMissingAccountProof(
+                account.address.to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn accounts_json_omits_absent_proofs() {
+        let sut =
+            WalletToDappInteractionAccountsRequestResponseItem::without_proofs(
+                [&Account::placeholder()],
+            );
+        let json = serde_json::to_value(&sut).unwrap();
+        assert!(json.get("challenge").is_none());
+        assert!(json.get("proofs").is_none());
+    }
+
+    #[test]
+    fn auth_persona() {
+        let persona = DappWalletInteractionPersona::placeholder();
+        let sut = WalletToDappInteractionAuthRequestResponseItem::LoginWithChallenge {
+            persona: persona.clone(),
+            challenge: Hex32Bytes::placeholder(),
+            proof: WalletToDappInteractionAuthProof::placeholder(),
+        };
+        assert_eq!(sut.persona(), &persona);
+    }
+
+    #[test]
+    fn auth_json() {
+        let persona = DappWalletInteractionPersona::placeholder();
+        let sut = WalletToDappInteractionAuthRequestResponseItem::UsePersona {
+            persona: persona.clone(),
+        };
+        assert_json_value_eq_after_roundtrip(
+            &sut,
+            json!({
+                "discriminator": "usePersona",
+                "persona": {
+                    "identityAddress": persona.identity_address.to_string(),
+                    "label": persona.label.to_string()
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn transaction_items_json() {
+        let sut = WalletToDappInteractionResponseItems::Transaction {
+            send: WalletToDappInteractionSendTransactionResponseItem {
+                transaction_intent_hash: "txid_rdx1".to_owned(),
+            },
+        };
+        assert_json_value_eq_after_roundtrip(
+            &sut,
+            json!({
+                "discriminator": "transaction",
+                "send": { "transactionIntentHash": "txid_rdx1" }
+            }),
+        );
+    }
+
+    #[test]
+    fn unauthorized_items_json() {
+        let sut = WalletToDappInteractionResponseItems::UnauthorizedRequest {
+            one_time_accounts: Some(
+                WalletToDappInteractionAccountsRequestResponseItem::without_proofs(
+                    [&Account::placeholder()],
+                ),
+            ),
+        };
+        let json = serde_json::to_value(&sut).unwrap();
+        assert_eq!(json["discriminator"], json!("unauthorizedRequest"));
+        assert!(json["oneTimeAccounts"]["accounts"].is_array());
+    }
+}
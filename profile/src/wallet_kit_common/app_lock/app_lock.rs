@@ -0,0 +1,335 @@
+use crate::prelude::*;
+
+/// What a host stores to verify the PIN or password of the app lock,
+/// from which the secret cannot be recovered other than by brute force.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct AppLockVerifier {
+    /// Random salt of the key derivation.
+    pub salt: Hex32Bytes,
+
+    /// Parameters of the key derivation, stored so that they can be raised
+    /// for new verifiers without invalidating existing ones.
+    pub parameters: Argon2idParameters,
+
+    /// Hash of the verification half of the derived key material.
+    pub verification_hash: Hex32Bytes,
+}
+
+/// The key derived from the PIN or password of the app lock, zeroized
+/// when dropped.
+#[derive(Clone, PartialEq, Eq, derive_more::Debug)]
+#[debug("{}", self.redacted_debug())]
+pub struct AppLockEncryptionKey(Hex32Bytes);
+
+impl AppLockEncryptionKey {
+    pub fn bytes(&self) -> [u8; 32] {
+        self.0.bytes()
+    }
+}
+
+impl Drop for AppLockEncryptionKey {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.0);
+    }
+}
+
+impl SafeDebug for AppLockEncryptionKey {
+    fn redacted_debug(&self) -> String {
+        format!("<REDACTED {} bytes>", self.bytes().len())
+    }
+
+    #[cfg(feature = "danger-fully-reveal-debug")]
+    fn danger_fully_reveal_debug(&self) -> String {
+        self.0.to_hex()
+    }
+}
+
+uniffi::custom_type!(AppLockEncryptionKey, Hex32Bytes);
+/// Crosses the FFI boundary as its bytes, the host is responsible for
+/// wiping its copy.
+impl crate::UniffiCustomTypeConverter for AppLockEncryptionKey {
+    type Builtin = Hex32Bytes;
+
+    #[cfg(not(tarpaulin_include))] // false negative | tested in bindgen tests
+    fn into_custom(val: Self::Builtin) -> uniffi::Result<Self> {
+        Ok(Self(val))
+    }
+
+    #[cfg(not(tarpaulin_include))] // false negative | tested in bindgen tests
+    fn from_custom(obj: Self) -> Self::Builtin {
+        obj.0.clone()
+    }
+}
+
+/// The result of setting up the app lock: the `verifier` to store and the
+/// `encryption_key` derived from the secret, which must not be stored.
+#[derive(Clone, PartialEq, Eq, derive_more::Debug, uniffi::Record)]
+#[debug("{}", self.redacted_debug())]
+pub struct AppLockSetup {
+    pub verifier: AppLockVerifier,
+    pub encryption_key: AppLockEncryptionKey,
+}
+
+impl SafeDebug for AppLockSetup {
+    fn redacted_debug(&self) -> String {
+        format!(
+            "AppLockSetup {{ verifier: {:?}, encryption_key: {} }}",
+            self.verifier,
+            self.encryption_key.redacted_debug()
+        )
+    }
+
+    #[cfg(feature = "danger-fully-reveal-debug")]
+    fn danger_fully_reveal_debug(&self) -> String {
+        format!(
+            "AppLockSetup {{ verifier: {:?}, encryption_key: {} }}",
+            self.verifier,
+            self.encryption_key.danger_fully_reveal_debug()
+        )
+    }
+}
+
+/// Derives a verification hash and an encryption key from the PIN or
+/// password of the app lock, using Argon2id.
+///
+/// The 64 bytes derived from the secret are split in two: the first half is
+/// the encryption key, the hash of the second half is the verification hash,
+/// so that knowing the verifier reveals nothing about the encryption key.
+pub struct AppLock;
+
+impl AppLock {
+    /// The minimum number of characters of a PIN or password.
+    pub const MIN_SECRET_LENGTH: u32 = 4;
+
+    /// Sets up the app lock with `secret`, using a random salt and the
+    /// recommended [`Argon2idParameters`].
+    pub fn set_up(secret: &str) -> Result<AppLockSetup> {
+        Self::set_up_with(
+            secret,
+            Hex32Bytes::generate(),
+            Argon2idParameters::recommended(),
+        )
+    }
+
+    /// Sets up the app lock with `secret`, `salt` and `parameters`, which
+    /// must be at least [`Argon2idParameters::minimum`].
+    pub fn set_up_with(
+        secret: &str,
+        salt: Hex32Bytes,
+        parameters: Argon2idParameters,
+    ) -> Result<AppLockSetup> {
+        if (secret.chars().count() as u32) < Self::MIN_SECRET_LENGTH {
+            return Err(CommonError::AppLockSecretTooShort {
+                min_length: Self::MIN_SECRET_LENGTH,
+            });
+        }
+        parameters.validate_at_least_minimum()?;
+        let (encryption_key, verification_hash) =
+            Self::derive(secret, &salt, &parameters)?;
+        Ok(AppLockSetup {
+            verifier: AppLockVerifier {
+                salt,
+                parameters,
+                verification_hash,
+            },
+            encryption_key,
+        })
+    }
+
+    /// Returns the encryption key if `secret` is the one the `verifier` was
+    /// set up with, else `AppLockWrongSecret`.
+    ///
+    /// Fails with `Argon2idParametersBelowMinimum` - without deriving
+    /// anything - if the parameters of `verifier` are weaker than
+    /// [`Argon2idParameters::minimum`].
+    pub fn unlock(
+        verifier: &AppLockVerifier,
+        secret: &str,
+    ) -> Result<AppLockEncryptionKey> {
+        verifier.parameters.validate_at_least_minimum()?;
+        let (encryption_key, verification_hash) =
+            Self::derive(secret, &verifier.salt, &verifier.parameters)?;
+        if !constant_time_eq(
+            &verification_hash.bytes(),
+            &verifier.verification_hash.bytes(),
+        ) {
+            return Err(CommonError::AppLockWrongSecret);
+        }
+        Ok(encryption_key)
+    }
+
+    fn derive(
+        secret: &str,
+        salt: &Hex32Bytes,
+        parameters: &Argon2idParameters,
+    ) -> Result<(AppLockEncryptionKey, Hex32Bytes)> {
+        let mut derived = parameters.derive::<64>(secret, salt.bytes())?;
+        let (encryption_key, verification) = derived.split_at(32);
+        let encryption_key = AppLockEncryptionKey(Hex32Bytes::from_bytes(
+            encryption_key.try_into().expect("Split at 32"),
+        ));
+        let verification_hash = Hex32Bytes::from_bytes(&hash(verification).0);
+        zeroize::Zeroize::zeroize(&mut derived);
+        Ok((encryption_key, verification_hash))
+    }
+}
+
+/// Compares `a` and `b` in time independent of where they differ.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len()
+        && a.iter()
+            .zip(b.iter())
+            .fold(0u8, |acc, (x, y)| acc | (x ^ y))
+            == 0
+}
+
+/// Sets up the app lock with `secret`, see [`AppLock::set_up`].
+#[uniffi::export]
+pub fn new_app_lock_setup(secret: String) -> Result<AppLockSetup> {
+    AppLock::set_up(&secret)
+}
+
+/// Returns the encryption key if `secret` is the one `verifier` was set up
+/// with, see [`AppLock::unlock`].
+#[uniffi::export]
+pub fn app_lock_unlock(
+    verifier: &AppLockVerifier,
+    secret: String,
+) -> Result<AppLockEncryptionKey> {
+    AppLock::unlock(verifier, &secret)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn set_up(secret: &str) -> Result<AppLockSetup> {
+        AppLock::set_up_with(
+            secret,
+            Hex32Bytes::placeholder_aced(),
+            Argon2idParameters::minimum(),
+        )
+    }
+
+    #[test]
+    fn unlock_with_correct_secret_returns_encryption_key() {
+        let sut = set_up("1234").unwrap();
+        assert_eq!(
+            AppLock::unlock(&sut.verifier, "1234"),
+            Ok(sut.encryption_key)
+        );
+    }
+
+    #[test]
+    fn unlock_with_wrong_secret_fails() {
+        let sut = set_up("1234").unwrap();
+        assert_eq!(
+            AppLock::unlock(&sut.verifier, "4321"),
+            Err(CommonError::AppLockWrongSecret)
+        );
+    }
+
+    #[test]
+    fn unlock_with_parameters_below_minimum_fails() {
+        let mut verifier = set_up("1234").unwrap().verifier;
+        verifier.parameters = Argon2idParameters::placeholder();
+        assert_eq!(
+            AppLock::unlock(&verifier, "1234"),
+            Err(CommonError::Argon2idParametersBelowMinimum {
+                minimum: Argon2idParameters::minimum(),
+                found: Argon2idParameters::placeholder()
+            })
+        );
+    }
+
+    #[test]
+    fn set_up_with_parameters_below_minimum_fails() {
+        assert_eq!(
+            AppLock::set_up_with(
+                "1234",
+                Hex32Bytes::placeholder_aced(),
+                Argon2idParameters::new(19 * 1024, 1, 1),
+            ),
+            Err(CommonError::Argon2idParametersBelowMinimum {
+                minimum: Argon2idParameters::minimum(),
+                found: Argon2idParameters::new(19 * 1024, 1, 1)
+            })
+        );
+    }
+
+    #[test]
+    fn verifier_does_not_contain_encryption_key() {
+        let sut = set_up("1234").unwrap();
+        let key = Hex32Bytes::from_bytes(&sut.encryption_key.bytes());
+        assert_ne!(sut.verifier.verification_hash, key);
+        assert!(!serde_json::to_string(&sut.verifier)
+            .unwrap()
+            .contains(&key.to_hex()));
+    }
+
+    #[test]
+    fn debug_redacts_encryption_key() {
+        let sut = set_up("1234").unwrap();
+        let key = Hex32Bytes::from_bytes(&sut.encryption_key.bytes());
+        let debug = format!("{:?}", sut);
+        assert!(!debug.contains(&key.to_hex()));
+        assert!(debug.contains("<REDACTED 32 bytes>"));
+        assert!(debug.contains(&sut.verifier.salt.to_hex()));
+    }
+
+    #[test]
+    fn zeroize_hex32_bytes() {
+        let mut sut = Hex32Bytes::placeholder_aced();
+        zeroize::Zeroize::zeroize(&mut sut);
+        assert!(sut.to_vec().is_empty());
+    }
+
+    #[test]
+    fn salt_changes_keys() {
+        let a = set_up("1234").unwrap();
+        let b = AppLock::set_up_with(
+            "1234",
+            Hex32Bytes::placeholder_babe(),
+            Argon2idParameters::minimum(),
+        )
+        .unwrap();
+        assert_ne!(a.encryption_key, b.encryption_key);
+        assert_ne!(a.verifier.verification_hash, b.verifier.verification_hash);
+    }
+
+    #[test]
+    fn too_short_secret() {
+        assert_eq!(
+            set_up("123"),
+            Err(CommonError::AppLockSecretTooShort { min_length: 4 })
+        );
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        let sut = set_up("1234").unwrap().verifier;
+        let json = serde_json::to_string(&sut).unwrap();
+        assert_eq!(
+            serde_json::from_str::<AppLockVerifier>(&json).unwrap(),
+            sut
+        );
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn set_up_and_unlock() {
+        let sut = new_app_lock_setup("correct horse".to_string()).unwrap();
+        assert_eq!(sut.verifier.parameters, Argon2idParameters::recommended());
+        assert_eq!(
+            app_lock_unlock(&sut.verifier, "correct horse".to_string()),
+            Ok(sut.encryption_key)
+        );
+    }
+}
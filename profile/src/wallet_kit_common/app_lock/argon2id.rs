@@ -0,0 +1,204 @@
+use crate::prelude::*;
+use argon2::{Algorithm, Argon2, Params, Version};
+
+/// The cost parameters of the Argon2id key derivation function.
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    derive_more::Display,
+    uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+#[display("m={memory_kib}KiB, t={iterations}, p={parallelism}")]
+pub struct Argon2idParameters {
+    /// Memory used, in kibibytes.
+    pub memory_kib: u32,
+
+    /// Number of passes over the memory.
+    pub iterations: u32,
+
+    /// Number of lanes computed in parallel.
+    pub parallelism: u32,
+}
+
+impl Argon2idParameters {
+    pub fn new(memory_kib: u32, iterations: u32, parallelism: u32) -> Self {
+        Self {
+            memory_kib,
+            iterations,
+            parallelism,
+        }
+    }
+
+    /// The parameters recommended by OWASP for Argon2id - 19 MiB of memory,
+    /// 2 iterations and 1 lane - fast enough to unlock an app on a phone
+    /// yet making brute forcing a PIN costly.
+    pub fn recommended() -> Self {
+        Self::new(19 * 1024, 2, 1)
+    }
+
+    /// The weakest parameters the app lock accepts - those recommended by
+    /// OWASP - so that a tampered verifier cannot make brute forcing the
+    /// PIN cheap.
+    pub fn minimum() -> Self {
+        Self::new(19 * 1024, 2, 1)
+    }
+
+    /// Fails with `Argon2idParametersBelowMinimum` unless the memory,
+    /// iterations and parallelism are all at least those of
+    /// [`Argon2idParameters::minimum`].
+    pub fn validate_at_least_minimum(&self) -> Result<()> {
+        let minimum = Self::minimum();
+        if self.memory_kib < minimum.memory_kib
+            || self.iterations < minimum.iterations
+            || self.parallelism < minimum.parallelism
+        {
+            return Err(CommonError::Argon2idParametersBelowMinimum {
+                minimum,
+                found: *self,
+            });
+        }
+        Ok(())
+    }
+
+    /// Derives `N` bytes from `secret` and `salt`.
+    pub fn derive<const N: usize>(
+        &self,
+        secret: impl AsRef<[u8]>,
+        salt: impl AsRef<[u8]>,
+    ) -> Result<[u8; N]> {
+        let params = Params::new(
+            self.memory_kib,
+            self.iterations,
+            self.parallelism,
+            Some(N),
+        )
+        .map_err(|_| CommonError::InvalidArgon2idParameters(*self))?;
+        let mut output = [0u8; N];
+        Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+            .hash_password_into(secret.as_ref(), salt.as_ref(), &mut output)
+            .map_err(|_| CommonError::InvalidArgon2idParameters(*self))?;
+        Ok(output)
+    }
+}
+
+impl Default for Argon2idParameters {
+    fn default() -> Self {
+        Self::recommended()
+    }
+}
+
+impl HasPlaceholder for Argon2idParameters {
+    /// Cheap parameters, used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::new(8, 1, 1)
+    }
+
+    fn placeholder_other() -> Self {
+        Self::new(16, 2, 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn recommended_is_default() {
+        assert_eq!(
+            Argon2idParameters::default(),
+            Argon2idParameters::new(19456, 2, 1)
+        );
+    }
+
+    #[test]
+    fn recommended_is_at_least_minimum() {
+        assert_eq!(
+            Argon2idParameters::recommended().validate_at_least_minimum(),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn below_minimum() {
+        let minimum = Argon2idParameters::minimum();
+        for found in [
+            Argon2idParameters::new(19 * 1024 - 1, 2, 1),
+            Argon2idParameters::new(64 * 1024, 1, 4),
+            Argon2idParameters::new(64 * 1024, 4, 0),
+            Argon2idParameters::placeholder(),
+        ] {
+            assert_eq!(
+                found.validate_at_least_minimum(),
+                Err(CommonError::Argon2idParametersBelowMinimum {
+                    minimum,
+                    found
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            Argon2idParameters::recommended().to_string(),
+            "m=19456KiB, t=2, p=1"
+        );
+    }
+
+    #[test]
+    fn derive_is_deterministic() {
+        let sut = Argon2idParameters::placeholder();
+        assert_eq!(
+            sut.derive::<32>("1234", "saltsalt"),
+            sut.derive::<32>("1234", "saltsalt")
+        );
+    }
+
+    #[test]
+    fn derive_depends_on_all_inputs() {
+        let sut = Argon2idParameters::placeholder();
+        let key = sut.derive::<32>("1234", "saltsalt").unwrap();
+        assert_ne!(sut.derive::<32>("1235", "saltsalt").unwrap(), key);
+        assert_ne!(sut.derive::<32>("1234", "saltsalz").unwrap(), key);
+        assert_ne!(
+            Argon2idParameters::placeholder_other()
+                .derive::<32>("1234", "saltsalt")
+                .unwrap(),
+            key
+        );
+    }
+
+    #[test]
+    fn derive_invalid_parameters() {
+        let sut = Argon2idParameters::new(8, 0, 1);
+        assert_eq!(
+            sut.derive::<32>("1234", "saltsalt"),
+            Err(CommonError::InvalidArgon2idParameters(sut))
+        );
+    }
+
+    #[test]
+    fn derive_too_short_salt() {
+        let sut = Argon2idParameters::placeholder();
+        assert!(sut.derive::<32>("1234", "salt").is_err());
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        assert_json_value_eq_after_roundtrip(
+            &Argon2idParameters::recommended(),
+            json!({
+                "memoryKib": 19456,
+                "iterations": 2,
+                "parallelism": 1
+            }),
+        );
+    }
+}
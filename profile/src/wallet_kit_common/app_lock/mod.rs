@@ -0,0 +1,5 @@
+mod app_lock;
+mod argon2id;
+
+pub use app_lock::*;
+pub use argon2id::*;
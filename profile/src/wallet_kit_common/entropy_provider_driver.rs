@@ -0,0 +1,94 @@
+use crate::prelude::*;
+use std::cell::RefCell;
+
+/// Provides cryptographically secure random bytes, used e.g. by
+/// [`Nonce::random`]. Abstracted so that unit tests can control entropy.
+pub trait EntropyProviderDriver: Send + Sync + std::fmt::Debug {
+    fn generate_bytes(&self, count: usize) -> Vec<u8>;
+}
+
+/// An [`EntropyProviderDriver`] reading from the operating system CSPRNG.
+#[derive(Debug, Default)]
+pub struct SystemEntropyProvider;
+
+impl EntropyProviderDriver for SystemEntropyProvider {
+    fn generate_bytes(&self, count: usize) -> Vec<u8> {
+        use rand::{rngs::OsRng, RngCore};
+        let mut bytes = vec![0u8; count];
+        OsRng.fill_bytes(&mut bytes);
+        bytes
+    }
+}
+
+thread_local! {
+    static ENTROPY_PROVIDER: RefCell<Arc<dyn EntropyProviderDriver>> =
+        RefCell::new(Arc::new(SystemEntropyProvider));
+}
+
+/// `count` random bytes according to the [`EntropyProviderDriver`] of
+/// the current thread.
+pub(crate) fn entropy_provider_generate_bytes(count: usize) -> Vec<u8> {
+    ENTROPY_PROVIDER.with(|p| p.borrow().generate_bytes(count))
+}
+
+/// Runs `f` with `driver` as the [`EntropyProviderDriver`] of the current
+/// thread, restoring the previous driver afterwards.
+#[cfg(test)]
+pub(crate) fn with_entropy_provider<D, F, R>(driver: D, f: F) -> R
+where
+    D: EntropyProviderDriver + 'static,
+    F: FnOnce() -> R,
+{
+    let previous = ENTROPY_PROVIDER.with(|p| p.replace(Arc::new(driver)));
+    let result = f();
+    ENTROPY_PROVIDER.with(|p| *p.borrow_mut() = previous);
+    result
+}
+
+/// An [`EntropyProviderDriver`] always returning bytes with the same
+/// value, used to facilitate unit tests.
+#[cfg(test)]
+#[derive(Debug)]
+pub(crate) struct MockEntropyProvider(pub(crate) u8);
+
+#[cfg(test)]
+impl EntropyProviderDriver for MockEntropyProvider {
+    fn generate_bytes(&self, count: usize) -> Vec<u8> {
+        vec![self.0; count]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn mocked_bytes() {
+        assert_eq!(
+            with_entropy_provider(MockEntropyProvider(0xab), || {
+                entropy_provider_generate_bytes(3)
+            }),
+            vec![0xab, 0xab, 0xab]
+        );
+    }
+
+    #[test]
+    fn previous_provider_is_restored() {
+        with_entropy_provider(MockEntropyProvider(0), || {});
+        assert_ne!(entropy_provider_generate_bytes(32), vec![0u8; 32]);
+    }
+
+    #[test]
+    fn system_generates_requested_count() {
+        assert_eq!(SystemEntropyProvider.generate_bytes(16).len(), 16);
+    }
+
+    #[test]
+    fn system_is_random() {
+        let n = 100;
+        let set = (0..n)
+            .map(|_| SystemEntropyProvider.generate_bytes(32))
+            .collect::<HashSet<_>>();
+        assert_eq!(set.len(), n);
+    }
+}
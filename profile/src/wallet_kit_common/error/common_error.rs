@@ -304,4 +304,388 @@ pub enum CommonError {
 
     #[error("Invalid UUID (v4), got: {0}")]
     InvalidUUIDv4(String) = 10086,
+
+    #[error("Unrecognized HomeCard deep link: {0}")]
+    UnrecognizedHomeCardDeepLink(String) = 10087,
+
+    #[error("Unable to acquire write lock for HomeCards")]
+    UnableToAcquireWriteLockForHomeCards = 10088,
+
+    #[error("Invalid NonFungibleLocalID format: '{0}', expected one of: #123#, <string>, [hex], {{ruid}}")]
+    InvalidNonFungibleLocalIDFormat(String) = 10089,
+
+    #[error("MnemonicWithPassphrase does not match FactorSourceID, expected: {expected}, found: {found}")]
+    MnemonicWithPassphraseDoesNotMatchFactorSourceID {
+        expected: FactorSourceIDFromHash,
+        found: FactorSourceIDFromHash,
+    } = 10090,
+
+    #[error("Off-device signing response from wrong factor source, expected: {expected}, found: {found}")]
+    OffDeviceSigningFactorSourceIDMismatch {
+        expected: FactorSourceIDFromHash,
+        found: FactorSourceIDFromHash,
+    } = 10091,
+
+    #[error(
+        "Off-device signing response is missing signature by public key: {0}"
+    )]
+    OffDeviceSigningMissingSignature(String) = 10092,
+
+    #[error("Off-device signing response contains signature which was not requested, by public key: {0}")]
+    OffDeviceSigningUnrequestedSignature(String) = 10093,
+
+    #[error("Off-device signing response contains invalid signature by public key: {0}")]
+    OffDeviceSigningInvalidSignature(String) = 10094,
+
+    #[error("Gateway metadata item '{key}' has unexpected type, expected: {expected}, found: {found}")]
+    GatewayMetadataUnexpectedValueType {
+        key: String,
+        expected: String,
+        found: String,
+    } = 10095,
+
+    #[error("Gateway metadata item '{key}' has invalid URL: '{value}'")]
+    GatewayMetadataInvalidUrl { key: String, value: String } = 10096,
+
+    #[error("Entity is not a dApp definition, account_type: {found:?}")]
+    DappDefinitionAccountTypeMismatch { found: Option<String> } = 10097,
+
+    #[error("dApp definition does not claim website: {origin}")]
+    DappDefinitionDoesNotClaimWebsite { origin: String } = 10098,
+
+    #[error(
+        "Website does not claim dApp definition: {dapp_definition_address}"
+    )]
+    WebsiteDoesNotClaimDappDefinition { dapp_definition_address: String } =
+        10099,
+
+    #[error("Unknown persona.")]
+    UnknownPersona = 10100,
+
+    #[error("Invalid PersonaData - name field '{field}' too long, max: {expected}, found: {found}")]
+    PersonaDataInvalidNameFieldTooLong {
+        field: String,
+        expected: usize,
+        found: usize,
+    } = 10101,
+
+    #[error("Invalid PersonaData - phone number: '{0}'")]
+    PersonaDataInvalidPhoneNumber(String) = 10102,
+
+    #[error("Invalid PersonaData - email address: '{0}'")]
+    PersonaDataInvalidEmailAddress(String) = 10103,
+
+    #[error(
+        "Invalid PersonaData - collection cannot have more than {max} entries"
+    )]
+    PersonaDataCollectionTooManyEntries { max: usize } = 10104,
+
+    #[error("Invalid PersonaData - collection already contains entry: {0}")]
+    PersonaDataCollectionDuplicateEntry(String) = 10105,
+
+    #[error("Invalid PersonaData - collection has no entry with ID: {0}")]
+    PersonaDataCollectionUnknownEntryID(String) = 10106,
+
+    #[error("Transaction and authentication signing factor instances of identity creation must share factor source, network and index")]
+    IdentityCreationFactorInstancesMismatch = 10107,
+
+    #[error("Account is not controlled by a Ledger hardware wallet: {0}")]
+    AccountNotControlledByLedgerFactorSource(String) = 10108,
+
+    #[error("Ledger verify address response has wrong derivation path, expected: {expected}, found: {found}")]
+    LedgerVerifyAddressDerivationPathMismatch {
+        expected: String,
+        found: String,
+    } = 10109,
+
+    #[error(
+        "Ledger displayed wrong address, expected: {expected}, found: {found}"
+    )]
+    LedgerVerifyAddressMismatch { expected: String, found: String } = 10110,
+
+    #[error("No proof of ownership for shared account: {0}")]
+    WalletInteractionMissingAccountProof(String) = 10111,
+
+    #[error("Network response has bad status code: {code}")]
+    NetworkResponseBadCode { code: u16 } = 10112,
+
+    #[error("URL must use https: '{0}'")]
+    UrlNotHttps(String) = 10113,
+
+    #[error("Profile does not contain network: {0}")]
+    UnknownNetworkInProfile(NetworkID) = 10114,
+
+    #[error("Cannot remove network which has Accounts or Personas: {0}")]
+    CannotRemoveNetworkWithEntities(NetworkID) = 10115,
+
+    #[error("Cannot remove network of the current Gateway: {0}")]
+    CannotRemoveNetworkOfCurrentGateway(NetworkID) = 10116,
+
+    #[error("Wallet deletion must be initiated before it can be confirmed")]
+    WalletDeletionNotInitiated = 10117,
+
+    #[error("Wallet deletion token does not match the initiated deletion")]
+    WalletDeletionInvalidToken = 10118,
+
+    #[error("Wallet changed since deletion was initiated, initiate again")]
+    WalletDeletionSummaryOutdated = 10119,
+
+    #[error("Automatic cloud backups are not enabled")]
+    CloudBackupsNotEnabled = 10120,
+
+    #[error("Failed to create Secp256k1 Signature from bytes {0:?}.")]
+    InvalidSecp256k1SignatureFromBytes(Vec<u8>) = 10121,
+
+    #[error("Invalid Secp256k1 Signature recovery id {0}, expected 0..=3.")]
+    InvalidSecp256k1SignatureRecoveryID(u8) = 10122,
+
+    #[error("Unable to recover Secp256k1 Public key from signature and hash.")]
+    UnableToRecoverSecp256k1PublicKey = 10123,
+
+    #[error("Failed to create Ed25519 Signature from bytes {0:?}.")]
+    InvalidEd25519SignatureFromBytes(Vec<u8>) = 10124,
+
+    #[error("Public key on curve '{public_key_curve}' cannot verify a signature on curve '{signature_curve}'.")]
+    SignatureCurveMismatch {
+        public_key_curve: String,
+        signature_curve: String,
+    } = 10125,
+
+    #[error("Invalid compiled transaction payload, expected a compiled {0}.")]
+    InvalidCompiledTransactionPayload(String) = 10126,
+
+    #[error("Failed to decode transaction hash from bech32m string {0}.")]
+    InvalidTransactionHashBech32(String) = 10127,
+
+    #[error("No driver registered for factor sources of kind {0}.")]
+    NoFactorSourceDriverRegistered(FactorSourceKind) = 10128,
+
+    #[error(
+        "Driver for factor sources of kind {0} returned an invalid response."
+    )]
+    FactorSourceDriverInvalidResponse(FactorSourceKind) = 10129,
+
+    #[error("Operation was cancelled.")]
+    OperationCancelled = 10130,
+
+    #[error("Operation timed out.")]
+    OperationTimedOut = 10131,
+
+    #[error("An item with ID {0} is already present in the collection.")]
+    IdentifiableItemAlreadyPresent(String) = 10132,
+
+    #[error("Updating the item with ID {0} must not change its ID.")]
+    IdentifiableItemIDChanged(String) = 10133,
+
+    #[error("Address {input} is missing the '1' separating HRP and data.")]
+    AddressMissingSeparator { input: String } = 10134,
+
+    #[error("Unknown HRP '{hrp}' of address {input}.")]
+    UnknownAddressHRP { hrp: String, input: String } = 10135,
+
+    #[error(
+        "Invalid character '{character}' at position {position} of address {input}."
+    )]
+    InvalidAddressCharacter {
+        character: char,
+        position: usize,
+        input: String,
+    } = 10136,
+
+    #[error(
+        "Invalid checksum of address {input}, likely a typo at position {position}."
+    )]
+    InvalidAddressChecksumAtPosition { position: usize, input: String } = 10137,
+
+    #[error("Invalid checksum of address {input}.")]
+    InvalidAddressChecksum { input: String } = 10138,
+
+    #[error(
+        "Address {input} is truncated, expected {expected} bytes of data, found {found}."
+    )]
+    AddressDataTruncated {
+        expected: usize,
+        found: usize,
+        input: String,
+    } = 10139,
+
+    #[error(
+        "Entity type byte {byte:#04x} of address {input} does not match its HRP '{hrp}'."
+    )]
+    WrongAddressEntityTypeByte {
+        byte: u8,
+        hrp: String,
+        input: String,
+    } = 10140,
+
+    #[error("Conflicting rules for asset exception of resource {0}.")]
+    ConflictingAssetExceptionRules(ResourceAddress) = 10141,
+
+    #[error("No asset exception for resource {0}.")]
+    UnknownAssetException(ResourceAddress) = 10142,
+
+    #[error(
+        "Asset exceptions on different networks, expected: {expected}, found: {found}"
+    )]
+    AssetExceptionOnWrongNetwork {
+        expected: NetworkID,
+        found: NetworkID,
+    } = 10143,
+
+    #[error("Unknown Dapp with definition address {0}.")]
+    UnknownDapp(DappDefinitionAddress) = 10144,
+
+    #[error("Persona {0} is not authorized by the Dapp.")]
+    PersonaNotAuthorizedByDapp(IdentityAddress) = 10145,
+
+    #[error("Gateway collection has more than {max_page_count} pages")]
+    GatewayPageLimitExceeded { max_page_count: u32 } = 10146,
+
+    #[error("Invalid FactorSourceKind, bad value: '{0}'")]
+    InvalidFactorSourceKind(String) = 10147,
+
+    #[error("Failed to parse FactorSourceID from '{0}', expected format '<kind>:<body>'")]
+    InvalidFactorSourceIDString(String) = 10148,
+
+    #[error("Next derivation index {next} of FactorSource {factor_source_id} on {network_id} is not above the highest used index {highest_used}")]
+    NextDerivationIndexAlreadyUsed {
+        factor_source_id: FactorSourceIDFromHash,
+        network_id: NetworkID,
+        next: HDPathValue,
+        highest_used: HDPathValue,
+    } = 10149,
+
+    #[error("Failed to roll back interrupted SecureStorage transaction")]
+    SecureStorageTransactionRollbackFailed = 10150,
+
+    #[error("Address {address} does not match the address {expected} of its transaction signing key")]
+    AccountAddressDoesNotMatchFactorInstance {
+        address: AccountAddress,
+        expected: AccountAddress,
+    } = 10151,
+
+    #[error("Invalid Argon2id parameters: {0}")]
+    InvalidArgon2idParameters(Argon2idParameters) = 10152,
+
+    #[error(
+        "App lock PIN or password must be at least {min_length} characters"
+    )]
+    AppLockSecretTooShort { min_length: u32 } = 10153,
+
+    #[error("Wrong app lock PIN or password")]
+    AppLockWrongSecret = 10154,
+
+    #[error(
+        "A HardwareKeyDriver is required to encrypt or decrypt the Profile"
+    )]
+    HardwareKeyDriverRequired = 10155,
+
+    #[error("Invalid header of Olympia wallet export payload: '{0}'")]
+    InvalidOlympiaExportPayloadHeader(String) = 10156,
+
+    #[error("Invalid account in Olympia wallet export: '{0}'")]
+    InvalidOlympiaExportAccount(String) = 10157,
+
+    #[error("Olympia wallet export payloads are not parts of the same export")]
+    OlympiaExportPayloadsMismatch = 10158,
+
+    #[error("Olympia wallet export incomplete, expected {expected} payloads, found {found}")]
+    OlympiaExportPayloadsMissing { expected: u32, found: u32 } = 10159,
+
+    #[error("Olympia wallet export contains no accounts")]
+    OlympiaExportContainsNoAccounts = 10160,
+
+    #[error("Non-fungible exception for fungible resource {0}.")]
+    NonFungibleExceptionForFungibleResource(ResourceAddress) = 10161,
+
+    #[error("Entity {0} is watch-only, no factor instance controls it.")]
+    EntityIsWatchOnly(String) = 10162,
+
+    #[error("Account {0} has not been shared with the Dapp.")]
+    AccountNotSharedWithDapp(AccountAddress) = 10163,
+
+    #[error("Invalid UserFlagKey '{0}', expected 'namespace.name'.")]
+    InvalidUserFlagKey(String) = 10164,
+
+    #[error(
+        "UserFlagValue too long, expected max: {expected}, found: {found}"
+    )]
+    UserFlagValueTooLong { expected: usize, found: usize } = 10165,
+
+    #[error("Too many UserFlags, max: {max}")]
+    TooManyUserFlags { max: usize } = 10166,
+
+    #[error("No pending wallet interaction with ID {0}")]
+    UnknownPendingWalletInteraction(String) = 10167,
+
+    #[error("Invalid address QR payload: '{0}'")]
+    InvalidAddressQRPayload(String) = 10168,
+
+    #[error("{0} is unavailable in offline mode.")]
+    FeatureUnavailableOffline(WalletCapability) = 10169,
+
+    #[error("FactorInstance badge does not match its FactorSourceID {0}")]
+    FactorInstanceBadgeDoesNotMatchFactorSourceID(String) = 10170,
+
+    #[error("No last known good snapshot of Profile with ID {0}")]
+    NoLastKnownGoodProfileSnapshot(ProfileID) = 10171,
+
+    #[error("Account {0} is listed more than once")]
+    AccountListedMoreThanOnce(AccountAddress) = 10172,
+
+    #[error("Timestamp is not in UTC, expected 'Z' suffix: {0}")]
+    TimestampNotUTC(String) = 10173,
+
+    #[error("Timestamp has precision beyond milliseconds: {0}")]
+    TimestampPrecisionBeyondMilliseconds(String) = 10174,
+
+    #[error("Cloud backup of a hardware key encrypted Profile requires consent to upload it unencrypted.")]
+    CloudBackupRequiresConsentToUploadUnencrypted = 10175,
+
+    #[error("Off device signature is of derivation path {found}, expected {expected}")]
+    OffDeviceSigningDerivationPathMismatch { expected: String, found: String } =
+        10176,
+
+    #[error(
+        "Off device signature is of a key on {found}, expected {expected}"
+    )]
+    OffDeviceSigningNetworkMismatch {
+        expected: NetworkID,
+        found: NetworkID,
+    } = 10177,
+
+    #[error("Invalid derivation index range, start {start} is greater than end {end}")]
+    InvalidDerivationIndexRange {
+        start: HDPathValue,
+        end: HDPathValue,
+    } = 10178,
+
+    #[error("Exported mnemonic is not the mnemonic of a device factor source of the Profile, factor source id: {0}")]
+    ExportedMnemonicDoesNotMatchFactorSource(FactorSourceIDFromHash) = 10179,
+
+    #[error("Invalid transaction manifest, failed to compile: {0}")]
+    InvalidTransactionManifest(String) = 10180,
+
+    #[error("A pool of kind {kind} does not take {found} resources")]
+    InvalidNumberOfPoolContributions { kind: PoolKind, found: u64 } = 10181,
+
+    #[error("Pool is on {found}, expected {expected}")]
+    PoolOnWrongNetwork {
+        expected: NetworkID,
+        found: NetworkID,
+    } = 10182,
+
+    #[error("The wallet has been deleted, it cannot be used anymore.")]
+    WalletDeleted = 10183,
+
+    #[error(
+        "Argon2id parameters {found} are weaker than the minimum {minimum}"
+    )]
+    Argon2idParametersBelowMinimum {
+        minimum: Argon2idParameters,
+        found: Argon2idParameters,
+    } = 10184,
+
+    #[error("At least one factor source is needed to securify an entity")]
+    NoFactorSourcesToSecurifyWith = 10185,
 }
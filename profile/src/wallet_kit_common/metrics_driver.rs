@@ -0,0 +1,206 @@
+use crate::prelude::*;
+use std::cell::RefCell;
+use std::sync::RwLock;
+use std::time::Instant;
+
+/// The kinds of expensive operations the wallet kit reports the duration of
+/// to the [`MetricsDriver`].
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Hash, strum_macros::Display, uniffi::Enum,
+)]
+#[strum(serialize_all = "snake_case")]
+pub enum MeasuredOperation {
+    /// Deriving keys from a mnemonic.
+    Derivation,
+
+    /// Signing a hash with a private key.
+    Signing,
+
+    /// Saving the Profile to secure storage.
+    ProfileSave,
+
+    /// A call to the Gateway, including retries.
+    GatewayCall,
+}
+
+/// The duration of one expensive operation.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct MetricsSpan {
+    pub operation: MeasuredOperation,
+
+    /// The name of the specific operation, e.g. the function deriving keys
+    /// or the path of the Gateway endpoint called.
+    pub name: String,
+
+    pub duration_microseconds: u64,
+}
+
+/// Implemented by the Wallet Client to receive the duration of expensive
+/// operations, e.g. to feed them to its observability pipeline.
+#[uniffi::export]
+pub trait MetricsDriver: Send + Sync + std::fmt::Debug {
+    fn record_span(&self, span: MetricsSpan);
+}
+
+static METRICS_DRIVER: RwLock<Option<Arc<dyn MetricsDriver>>> =
+    RwLock::new(None);
+
+#[cfg(test)]
+thread_local! {
+    static METRICS_DRIVER_OVERRIDE: RefCell<Option<Arc<dyn MetricsDriver>>> =
+        RefCell::new(None);
+}
+
+/// Sets the [`MetricsDriver`] receiving the spans of all operations of all
+/// threads, replacing any previously installed driver.
+#[uniffi::export]
+pub fn install_metrics_driver(driver: Arc<dyn MetricsDriver>) {
+    *METRICS_DRIVER
+        .write()
+        .expect("Should be able to acquire write lock for metrics driver") =
+        Some(driver);
+}
+
+/// Removes the installed [`MetricsDriver`], if any.
+#[uniffi::export]
+pub fn uninstall_metrics_driver() {
+    *METRICS_DRIVER
+        .write()
+        .expect("Should be able to acquire write lock for metrics driver") =
+        None;
+}
+
+fn current_metrics_driver() -> Option<Arc<dyn MetricsDriver>> {
+    #[cfg(test)]
+    if let Some(driver) = METRICS_DRIVER_OVERRIDE.with(|d| d.borrow().clone()) {
+        return Some(driver);
+    }
+    METRICS_DRIVER
+        .read()
+        .expect("Should be able to acquire read lock for metrics driver")
+        .clone()
+}
+
+/// Runs `f`, reporting its duration as `operation` named `name` to the
+/// [`MetricsDriver`], if one is installed.
+pub(crate) fn measure<F, R>(
+    operation: MeasuredOperation,
+    name: impl Into<String>,
+    f: F,
+) -> R
+where
+    F: FnOnce() -> R,
+{
+    let Some(driver) = current_metrics_driver() else {
+        return f();
+    };
+    let start = Instant::now();
+    let result = f();
+    let duration_microseconds =
+        u64::try_from(start.elapsed().as_micros()).unwrap_or(u64::MAX);
+    driver.record_span(MetricsSpan {
+        operation,
+        name: name.into(),
+        duration_microseconds,
+    });
+    result
+}
+
+/// Runs `f` with `driver` as the [`MetricsDriver`] of the current thread,
+/// taking precedence over the installed driver, restoring the previous
+/// driver afterwards.
+#[cfg(test)]
+pub(crate) fn with_metrics_driver<D, F, R>(driver: Arc<D>, f: F) -> R
+where
+    D: MetricsDriver + 'static,
+    F: FnOnce() -> R,
+{
+    let previous =
+        METRICS_DRIVER_OVERRIDE.with(|d| d.replace(Some(driver as _)));
+    let result = f();
+    METRICS_DRIVER_OVERRIDE.with(|d| *d.borrow_mut() = previous);
+    result
+}
+
+/// Records all spans it receives.
+#[cfg(test)]
+#[derive(Debug, Default)]
+pub(crate) struct RecordingMetricsDriver {
+    spans: RwLock<Vec<MetricsSpan>>,
+}
+
+#[cfg(test)]
+impl RecordingMetricsDriver {
+    /// The operation and name of all spans received, in order.
+    pub(crate) fn recorded(&self) -> Vec<(MeasuredOperation, String)> {
+        self.spans
+            .read()
+            .unwrap()
+            .iter()
+            .map(|s| (s.operation, s.name.clone()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+impl MetricsDriver for RecordingMetricsDriver {
+    fn record_span(&self, span: MetricsSpan) {
+        self.spans.write().unwrap().push(span);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn operation_display() {
+        assert_eq!(MeasuredOperation::ProfileSave.to_string(), "profile_save");
+        assert_eq!(MeasuredOperation::GatewayCall.to_string(), "gateway_call");
+    }
+
+    #[test]
+    fn measure_returns_result() {
+        assert_eq!(measure(MeasuredOperation::Signing, "test", || 42), 42);
+    }
+
+    #[test]
+    fn measure_reports_span() {
+        let driver = Arc::new(RecordingMetricsDriver::default());
+        with_metrics_driver(driver.clone(), || {
+            measure(MeasuredOperation::Derivation, "a", || {});
+            measure(MeasuredOperation::Signing, "b", || {});
+        });
+        assert_eq!(
+            driver.recorded(),
+            vec![
+                (MeasuredOperation::Derivation, "a".to_owned()),
+                (MeasuredOperation::Signing, "b".to_owned())
+            ]
+        );
+    }
+
+    #[test]
+    fn measure_reports_duration() {
+        let driver = Arc::new(RecordingMetricsDriver::default());
+        with_metrics_driver(driver.clone(), || {
+            measure(MeasuredOperation::Signing, "sleep", || {
+                std::thread::sleep(std::time::Duration::from_millis(2))
+            })
+        });
+        assert!(driver.spans.read().unwrap()[0].duration_microseconds >= 2_000);
+    }
+
+    #[test]
+    fn installed_driver_receives_spans() {
+        let driver = Arc::new(RecordingMetricsDriver::default());
+        install_metrics_driver(driver.clone());
+        measure(MeasuredOperation::Signing, "installed", || {});
+        uninstall_metrics_driver();
+        measure(MeasuredOperation::Signing, "uninstalled", || {});
+        // Other tests running in parallel might report spans too.
+        let names = driver.recorded().into_iter().map(|r| r.1).collect_vec();
+        assert!(names.contains(&"installed".to_owned()));
+        assert!(!names.contains(&"uninstalled".to_owned()));
+    }
+}
@@ -1,17 +1,29 @@
+mod app_lock;
 mod assert_json;
+mod entropy_provider_driver;
 mod error;
 mod has_placeholder;
 mod hash;
+mod metrics_driver;
+mod ret_version_info;
 mod secure_random_bytes;
+mod self_test;
+mod time;
 mod types;
 mod unsafe_id_stepper;
 mod utils;
 
+pub use app_lock::*;
 pub use assert_json::*;
+pub use entropy_provider_driver::*;
 pub use error::*;
 pub use has_placeholder::*;
 pub use hash::*;
+pub use metrics_driver::*;
+pub use ret_version_info::*;
 pub use secure_random_bytes::*;
+pub use self_test::*;
+pub use time::*;
 pub use types::*;
 pub use unsafe_id_stepper::*;
 pub use utils::*;
@@ -0,0 +1,112 @@
+use crate::prelude::*;
+
+/// The git revision of the Radix Engine Toolkit (RET) this build is compiled
+/// with, MUST be kept in sync with the workspace `Cargo.toml`.
+pub const RADIX_ENGINE_TOOLKIT_REVISION: &str =
+    "e848b6abb9c5e72c695819e4d61a2d0c25f695f5";
+
+/// The git revision of Scrypto (the Radix Engine) this build is compiled
+/// with, MUST be kept in sync with the workspace `Cargo.toml`.
+pub const SCRYPTO_REVISION: &str = "038ddee8b0f57aa90e36375c69946c4eb634efeb";
+
+/// The versions of the Radix Engine Toolkit and Scrypto compiled into this
+/// build, which decide the semantics of e.g. address formats and manifest
+/// grammar, allowing host apps and bug reports to confirm which toolkit
+/// semantics this build understands.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct RetVersionInfo {
+    /// The version of this crate, e.g. `"0.1.0"`.
+    pub wallet_kit_version: String,
+
+    /// The git revision of the Radix Engine Toolkit.
+    pub radix_engine_toolkit_revision: String,
+
+    /// The git revision of Scrypto.
+    pub scrypto_revision: String,
+
+    /// The IDs of all networks addresses can be encoded and decoded for.
+    pub supported_network_ids: Vec<NetworkID>,
+}
+
+impl RetVersionInfo {
+    /// The versions compiled into this build.
+    pub fn current() -> Self {
+        Self {
+            wallet_kit_version: env!("CARGO_PKG_VERSION").to_owned(),
+            radix_engine_toolkit_revision: RADIX_ENGINE_TOOLKIT_REVISION
+                .to_owned(),
+            scrypto_revision: SCRYPTO_REVISION.to_owned(),
+            supported_network_ids: enum_iterator::all::<NetworkID>().collect(),
+        }
+    }
+}
+
+/// The versions of the Radix Engine Toolkit and Scrypto compiled into this
+/// build, see [`RetVersionInfo`].
+#[uniffi::export]
+pub fn ret_version_info() -> RetVersionInfo {
+    RetVersionInfo::current()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    /// The `rev` of every dependency in the workspace manifest with git
+    /// `repo`.
+    fn revisions_in_manifest(repo: &str) -> HashSet<String> {
+        include_str!("../../../Cargo.toml")
+            .lines()
+            .filter(|l| l.contains(&format!("git = \"{}\"", repo)))
+            .filter_map(|l| {
+                l.split("rev = \"")
+                    .nth(1)?
+                    .split('"')
+                    .next()
+                    .map(String::from)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn toolkit_revision_is_pinned_to_manifest() {
+        assert_eq!(
+            revisions_in_manifest(
+                "https://github.com/radixdlt/radix-engine-toolkit"
+            ),
+            HashSet::from([RADIX_ENGINE_TOOLKIT_REVISION.to_owned()])
+        );
+    }
+
+    #[test]
+    fn scrypto_revision_is_pinned_to_manifest() {
+        assert_eq!(
+            revisions_in_manifest(
+                "https://github.com/radixdlt/radixdlt-scrypto"
+            ),
+            HashSet::from([SCRYPTO_REVISION.to_owned()])
+        );
+    }
+
+    #[test]
+    fn current() {
+        let sut = RetVersionInfo::current();
+        assert_eq!(sut.wallet_kit_version, env!("CARGO_PKG_VERSION"));
+        assert!(sut.supported_network_ids.contains(&NetworkID::Mainnet));
+        assert!(sut.supported_network_ids.contains(&NetworkID::Stokenet));
+        assert_eq!(
+            sut.supported_network_ids.len(),
+            enum_iterator::cardinality::<NetworkID>()
+        );
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn ret_version_info_is_current() {
+        assert_eq!(ret_version_info(), RetVersionInfo::current());
+    }
+}
@@ -0,0 +1,7 @@
+mod self_test_check;
+mod self_test_checks;
+mod self_test_report;
+
+pub use self_test_check::*;
+pub(crate) use self_test_checks::*;
+pub use self_test_report::*;
@@ -0,0 +1,100 @@
+use crate::prelude::*;
+
+/// The area of functionality a check of the self-test verifies, see
+/// [`wallet_kit_self_test`].
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    enum_iterator::Sequence,
+    uniffi::Enum,
+)]
+pub enum SelfTestCheckKind {
+    /// Placeholder values survive a JSON roundtrip.
+    PlaceholderRoundtrip,
+
+    /// Keys and addresses derived from known inputs match test vectors.
+    AddressDerivation,
+
+    /// Hashes of known messages match test vectors.
+    Hashing,
+
+    /// Decimals parse and format as expected.
+    DecimalFormatting,
+}
+
+/// The outcome of a single check of the self-test.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct SelfTestCheck {
+    pub kind: SelfTestCheckKind,
+
+    /// A short name identifying the check, e.g. `"hash_of_hello_radix"`.
+    pub name: String,
+
+    /// Why the check failed - including a panic message if it panicked -
+    /// or `None` if it passed.
+    pub failure: Option<String>,
+}
+
+impl SelfTestCheck {
+    pub fn new(
+        kind: SelfTestCheckKind,
+        name: impl AsRef<str>,
+        failure: Option<String>,
+    ) -> Self {
+        Self {
+            kind,
+            name: name.as_ref().to_owned(),
+            failure,
+        }
+    }
+
+    pub fn passed(&self) -> bool {
+        self.failure.is_none()
+    }
+}
+
+impl HasPlaceholder for SelfTestCheck {
+    fn placeholder() -> Self {
+        Self::new(SelfTestCheckKind::Hashing, "hash_of_hello_radix", None)
+    }
+
+    fn placeholder_other() -> Self {
+        Self::new(
+            SelfTestCheckKind::DecimalFormatting,
+            "decimal_display_roundtrip",
+            Some("expected '1', found '2'".to_owned()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SelfTestCheck::placeholder(), SelfTestCheck::placeholder());
+        assert_eq!(
+            SelfTestCheck::placeholder_other(),
+            SelfTestCheck::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            SelfTestCheck::placeholder(),
+            SelfTestCheck::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn passed() {
+        assert!(SelfTestCheck::placeholder().passed());
+        assert!(!SelfTestCheck::placeholder_other().passed());
+    }
+}
@@ -0,0 +1,174 @@
+use crate::prelude::*;
+
+/// A check of the self-test, `Err` with the reason if it failed.
+pub(crate) type SelfTestCheckFn = fn() -> Result<(), String>;
+
+/// All checks run by the self-test, by kind and name.
+pub(crate) fn self_test_checks(
+) -> Vec<(SelfTestCheckKind, &'static str, SelfTestCheckFn)> {
+    use SelfTestCheckKind::*;
+    vec![
+        (
+            PlaceholderRoundtrip,
+            "profile_json_roundtrip",
+            json_roundtrip::<Profile>,
+        ),
+        (
+            PlaceholderRoundtrip,
+            "account_json_roundtrip",
+            json_roundtrip::<Account>,
+        ),
+        (
+            PlaceholderRoundtrip,
+            "persona_json_roundtrip",
+            json_roundtrip::<Persona>,
+        ),
+        (
+            PlaceholderRoundtrip,
+            "mnemonic_with_passphrase_json_roundtrip",
+            json_roundtrip::<MnemonicWithPassphrase>,
+        ),
+        (
+            AddressDerivation,
+            "curve25519_key_with_cap26",
+            derive_curve25519_key_with_cap26,
+        ),
+        (
+            AddressDerivation,
+            "secp256k1_key_with_bip44_olympia",
+            derive_secp256k1_key_with_bip44_olympia,
+        ),
+        (
+            AddressDerivation,
+            "account_address_from_public_key",
+            account_address_from_public_key,
+        ),
+        (
+            AddressDerivation,
+            "account_address_bech32_roundtrip",
+            account_address_bech32_roundtrip,
+        ),
+        (Hashing, "hash_of_hello_radix", hash_of_hello_radix),
+        (
+            DecimalFormatting,
+            "decimal_display_roundtrip",
+            decimal_display_roundtrip,
+        ),
+        (DecimalFormatting, "decimal_ordering", decimal_ordering),
+        (
+            DecimalFormatting,
+            "decimal_invalid_rejected",
+            decimal_invalid_rejected,
+        ),
+    ]
+}
+
+fn expect_eq<T>(expected: T, found: T) -> Result<(), String>
+where
+    T: PartialEq + std::fmt::Debug,
+{
+    if expected == found {
+        Ok(())
+    } else {
+        Err(format!("expected {:?}, found {:?}", expected, found))
+    }
+}
+
+fn json_roundtrip<T>() -> Result<(), String>
+where
+    T: HasPlaceholder
+        + Serialize
+        + for<'de> Deserialize<'de>
+        + PartialEq
+        + std::fmt::Debug,
+{
+    let placeholder = T::placeholder();
+    let json = serde_json::to_vec(&placeholder).map_err(|e| e.to_string())?;
+    let decoded =
+        serde_json::from_slice::<T>(&json).map_err(|e| e.to_string())?;
+    expect_eq(placeholder, decoded)
+}
+
+/// Test vector: `cap26_curve25519.json` of the iOS wallet.
+fn derive_curve25519_key_with_cap26() -> Result<(), String> {
+    let mwp = MnemonicWithPassphrase::from_phrase(
+        "equip will roof matter pink blind book anxiety banner elbow sun young",
+    )
+    .map_err(|e| e.to_string())?;
+    let path = AccountPath::from_str("m/44H/1022H/12H/525H/1460H/0H")
+        .map_err(|e| e.to_string())?;
+    expect_eq(
+        "451152a1cef7be603205086d4ebac0a0b78fda2ff4684b9dea5ca9ef003d4e7d"
+            .to_owned(),
+        mwp.derive_private_key(path).public_key().to_hex(),
+    )
+}
+
+/// Test vector: `bip44_secp256k1.json` of the iOS wallet.
+fn derive_secp256k1_key_with_bip44_olympia() -> Result<(), String> {
+    let mwp = MnemonicWithPassphrase::from_phrase(
+        "habit special recipe upon giraffe manual evil badge dwarf welcome inspire shrug post arrive van",
+    )
+    .map_err(|e| e.to_string())?;
+    let path = BIP44LikePath::from_str("m/44H/1022H/0H/0/5H")
+        .map_err(|e| e.to_string())?;
+    expect_eq(
+        "03e78cdb2e0b7ea6e55e121a58560ccf841a913d3a4a9b8349e0ef00c2102f48d8"
+            .to_owned(),
+        mwp.derive_private_key(path).public_key().to_hex(),
+    )
+}
+
+fn account_address_from_public_key() -> Result<(), String> {
+    let public_key = Ed25519PublicKey::from_str(
+        "3e9b96a2a863f1be4658ea66aa0584d2a8847d4c0f658b20e62e3594d994d73d",
+    )
+    .map_err(|e| e.to_string())?;
+    expect_eq(
+        "account_rdx129qdd2yp9vs8jkkn2uwn6sw0ejwmcwr3r4c3usr2hp0nau67m2kzdm"
+            .to_owned(),
+        AccountAddress::new(public_key.into(), NetworkID::Mainnet).address,
+    )
+}
+
+fn account_address_bech32_roundtrip() -> Result<(), String> {
+    let s =
+        "account_rdx16xlfcpp0vf7e3gqnswv8j9k58n6rjccu58vvspmdva22kf3aplease";
+    let address =
+        AccountAddress::try_from_bech32(s).map_err(|e| e.to_string())?;
+    expect_eq(NetworkID::Mainnet, address.network_id)?;
+    expect_eq(s.to_owned(), address.to_string())
+}
+
+fn hash_of_hello_radix() -> Result<(), String> {
+    expect_eq(
+        "48f1bd08444b5e713db9e14caac2faae71836786ac94d645b00679728202a935"
+            .to_owned(),
+        Hash::hash_of("Hello Radix").to_hex(),
+    )
+}
+
+fn decimal_display_roundtrip() -> Result<(), String> {
+    let s = "3138550867693340381917894711603833208051.177722232017256447";
+    let decimal = Decimal::try_from_str(s).map_err(|e| e.to_string())?;
+    expect_eq(s.to_owned(), decimal.to_string())
+}
+
+fn decimal_ordering() -> Result<(), String> {
+    let a = Decimal::try_from_str(
+        "3138550867693340381917894711603833208051.177722232017256447",
+    )
+    .map_err(|e| e.to_string())?;
+    let b = Decimal::try_from_str(
+        "3036550867693340381917894711603833208050.177722232017256447",
+    )
+    .map_err(|e| e.to_string())?;
+    expect_eq(Ordering::Greater, a.cmp(&b))
+}
+
+fn decimal_invalid_rejected() -> Result<(), String> {
+    expect_eq(
+        Err(CommonError::DecimalError),
+        Decimal::try_from_str("apabanan"),
+    )
+}
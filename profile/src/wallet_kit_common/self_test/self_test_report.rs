@@ -0,0 +1,166 @@
+use crate::prelude::*;
+
+/// The outcome of running all checks of the self-test on this device, see
+/// [`wallet_kit_self_test`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct SelfTestReport {
+    /// The version of this crate, e.g. `"0.1.0"`.
+    pub wallet_kit_version: String,
+
+    /// `true` if every check passed.
+    pub all_passed: bool,
+
+    /// The outcome of every check, in the order they were run.
+    pub checks: Vec<SelfTestCheck>,
+}
+
+impl SelfTestReport {
+    pub fn new(checks: Vec<SelfTestCheck>) -> Self {
+        Self {
+            wallet_kit_version: env!("CARGO_PKG_VERSION").to_owned(),
+            all_passed: checks.iter().all(|c| c.passed()),
+            checks,
+        }
+    }
+
+    /// Runs all checks of the self-test, a check panicking counts as it
+    /// failing rather than aborting the self-test.
+    pub fn run() -> Self {
+        Self::run_checks(self_test_checks())
+    }
+
+    pub(crate) fn run_checks(
+        checks: Vec<(SelfTestCheckKind, &'static str, SelfTestCheckFn)>,
+    ) -> Self {
+        Self::new(
+            checks
+                .into_iter()
+                .map(|(kind, name, check)| {
+                    let failure = match std::panic::catch_unwind(check) {
+                        Ok(outcome) => outcome.err(),
+                        Err(panic) => Some(format!(
+                            "panicked: {}",
+                            panic
+                                .downcast_ref::<String>()
+                                .cloned()
+                                .or_else(|| panic
+                                    .downcast_ref::<&str>()
+                                    .map(|s| s.to_string()))
+                                .unwrap_or_default()
+                        )),
+                    };
+                    if let Some(failure) = &failure {
+                        error!(
+                            "Self-test check '{}' failed: {}",
+                            name, failure
+                        );
+                    }
+                    SelfTestCheck::new(kind, name, failure)
+                })
+                .collect(),
+        )
+    }
+
+    /// The checks which failed.
+    pub fn failed_checks(&self) -> Vec<SelfTestCheck> {
+        self.checks
+            .iter()
+            .filter(|c| !c.passed())
+            .cloned()
+            .collect()
+    }
+}
+
+/// Runs a battery of internal checks - placeholder roundtrips, key and
+/// address derivation test vectors, hash test vectors and decimal
+/// formatting - on this device, allowing host apps to verify that a given
+/// build behaves correctly on the device and architecture it runs on.
+#[uniffi::export]
+pub fn wallet_kit_self_test() -> SelfTestReport {
+    SelfTestReport::run()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn all_checks_pass() {
+        let sut = SelfTestReport::run();
+        assert_eq!(sut.failed_checks(), Vec::new());
+        assert!(sut.all_passed);
+        assert_eq!(sut.wallet_kit_version, env!("CARGO_PKG_VERSION"));
+    }
+
+    #[test]
+    fn every_kind_is_checked() {
+        let kinds = SelfTestReport::run()
+            .checks
+            .into_iter()
+            .map(|c| c.kind)
+            .collect::<HashSet<_>>();
+        assert_eq!(
+            kinds,
+            HashSet::from_iter(enum_iterator::all::<SelfTestCheckKind>())
+        );
+    }
+
+    #[test]
+    fn check_names_are_unique() {
+        let checks = SelfTestReport::run().checks;
+        assert_eq!(
+            checks.iter().map(|c| &c.name).unique().count(),
+            checks.len()
+        );
+    }
+
+    fn passing_check() -> Result<(), String> {
+        Ok(())
+    }
+
+    fn failing_check() -> Result<(), String> {
+        Err("bad".to_owned())
+    }
+
+    fn panicking_check() -> Result<(), String> {
+        panic!("boom")
+    }
+
+    #[test]
+    fn failing_check_is_reported() {
+        let sut = SelfTestReport::run_checks(vec![
+            (SelfTestCheckKind::Hashing, "passes", passing_check),
+            (SelfTestCheckKind::Hashing, "fails", failing_check),
+        ]);
+        assert!(!sut.all_passed);
+        assert_eq!(
+            sut.failed_checks(),
+            vec![SelfTestCheck::new(
+                SelfTestCheckKind::Hashing,
+                "fails",
+                Some("bad".to_owned())
+            )]
+        );
+    }
+
+    #[test]
+    fn panicking_check_is_reported() {
+        let sut = SelfTestReport::run_checks(vec![(
+            SelfTestCheckKind::DecimalFormatting,
+            "panics",
+            panicking_check,
+        )]);
+        assert!(!sut.all_passed);
+        assert_eq!(sut.checks[0].failure, Some("panicked: boom".to_owned()));
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn self_test_passes() {
+        assert!(wallet_kit_self_test().all_passed);
+    }
+}
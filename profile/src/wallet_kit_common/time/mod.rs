@@ -0,0 +1,5 @@
+mod time_provider_driver;
+mod timestamp;
+
+pub use time_provider_driver::*;
+pub use timestamp::*;
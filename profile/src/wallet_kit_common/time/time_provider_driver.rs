@@ -0,0 +1,153 @@
+use crate::prelude::*;
+use std::sync::RwLock;
+
+/// Provides the current time, used by [`Timestamp::now`]. Implemented by
+/// Wallet Clients wanting to control time, e.g. in UI tests, and installed
+/// using [`set_time_provider_driver`].
+#[uniffi::export]
+pub trait TimeProviderDriver: Send + Sync + std::fmt::Debug {
+    fn now(&self) -> Timestamp;
+}
+
+/// A [`TimeProviderDriver`] reading the system clock, used unless another
+/// driver has been installed.
+#[derive(Debug, Default)]
+pub struct SystemTimeProvider;
+
+impl TimeProviderDriver for SystemTimeProvider {
+    fn now(&self) -> Timestamp {
+        Timestamp::from(iso8601_timestamp::Timestamp::now_utc())
+    }
+}
+
+/// The process wide [`TimeProviderDriver`], `None` meaning
+/// [`SystemTimeProvider`].
+static TIME_PROVIDER: RwLock<Option<Arc<dyn TimeProviderDriver>>> =
+    RwLock::new(None);
+
+/// Installs `driver` as the [`TimeProviderDriver`] used by
+/// [`Timestamp::now`] on all threads, replacing any previously installed.
+#[uniffi::export]
+pub fn set_time_provider_driver(driver: Arc<dyn TimeProviderDriver>) {
+    *TIME_PROVIDER.write().expect(
+        "Stop execution due to the time provider lock being poisoned",
+    ) = Some(driver);
+}
+
+/// Restores the [`SystemTimeProvider`] as the [`TimeProviderDriver`] used
+/// by [`Timestamp::now`] on all threads.
+#[uniffi::export]
+pub fn reset_time_provider_driver() {
+    *TIME_PROVIDER.write().expect(
+        "Stop execution due to the time provider lock being poisoned",
+    ) = None;
+}
+
+#[cfg(test)]
+thread_local! {
+    /// Overrides the process wide driver on the current thread only, so
+    /// that unit tests running in parallel do not affect each other.
+    static TEST_TIME_PROVIDER: std::cell::RefCell<Option<Arc<dyn TimeProviderDriver>>> =
+        std::cell::RefCell::new(None);
+}
+
+#[cfg(test)]
+fn test_time_provider() -> Option<Arc<dyn TimeProviderDriver>> {
+    TEST_TIME_PROVIDER.with(|p| p.borrow().clone())
+}
+
+#[cfg(not(test))]
+fn test_time_provider() -> Option<Arc<dyn TimeProviderDriver>> {
+    None
+}
+
+/// The current time according to the [`TimeProviderDriver`] in use.
+pub(crate) fn time_provider_now() -> Timestamp {
+    let driver = test_time_provider().or_else(|| {
+        TIME_PROVIDER
+            .read()
+            .expect(
+                "Stop execution due to the time provider lock being poisoned",
+            )
+            .clone()
+    });
+    match driver {
+        Some(driver) => driver.now(),
+        None => SystemTimeProvider.now(),
+    }
+}
+
+/// Runs `f` with `driver` as the [`TimeProviderDriver`] of the current
+/// thread, restoring the previous driver afterwards.
+#[cfg(test)]
+pub(crate) fn with_time_provider<D, F, R>(driver: D, f: F) -> R
+where
+    D: TimeProviderDriver + 'static,
+    F: FnOnce() -> R,
+{
+    let previous =
+        TEST_TIME_PROVIDER.with(|p| p.replace(Some(Arc::new(driver))));
+    let result = f();
+    TEST_TIME_PROVIDER.with(|p| *p.borrow_mut() = previous);
+    result
+}
+
+/// A [`TimeProviderDriver`] always returning the same [`Timestamp`], used
+/// to facilitate unit tests.
+#[cfg(test)]
+#[derive(Debug)]
+pub(crate) struct MockTimeProvider(pub(crate) Timestamp);
+
+#[cfg(test)]
+impl TimeProviderDriver for MockTimeProvider {
+    fn now(&self) -> Timestamp {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn mocked_now() {
+        let date = Timestamp::placeholder();
+        assert_eq!(
+            with_time_provider(MockTimeProvider(date), Timestamp::now),
+            date
+        );
+    }
+
+    #[test]
+    fn previous_provider_is_restored() {
+        with_time_provider(MockTimeProvider(Timestamp::UNIX_EPOCH), || {});
+        assert_ne!(Timestamp::now(), Timestamp::UNIX_EPOCH);
+    }
+
+    #[test]
+    fn system_now_has_millisecond_precision() {
+        let now = SystemTimeProvider.now();
+        assert_eq!(Timestamp::from_str(&now.to_string()), Ok(now));
+    }
+
+    /// Returns the system time, so that other tests running in parallel
+    /// are unaffected while it is installed, but counts its calls.
+    #[derive(Debug, Default)]
+    struct CountingTimeProvider(std::sync::atomic::AtomicUsize);
+
+    impl TimeProviderDriver for CountingTimeProvider {
+        fn now(&self) -> Timestamp {
+            self.0.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            SystemTimeProvider.now()
+        }
+    }
+
+    #[test]
+    fn installed_driver_applies_to_other_threads() {
+        let driver = Arc::new(CountingTimeProvider::default());
+        set_time_provider_driver(driver.clone());
+        std::thread::spawn(Timestamp::now).join().unwrap();
+        reset_time_provider_driver();
+        assert!(driver.0.load(std::sync::atomic::Ordering::SeqCst) >= 1);
+    }
+}
@@ -0,0 +1,252 @@
+use crate::prelude::*;
+
+/// A point in time in UTC with millisecond precision, always formatted as
+/// RFC3339 with exactly three fractional digits, e.g.
+/// `"2023-09-11T16:05:56.000Z"`, so that it serializes identically on all
+/// platforms.
+#[derive(
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    derive_more::Display,
+    derive_more::Debug,
+)]
+#[display("{}", self.to_rfc3339())]
+#[debug("{}", self.to_rfc3339())]
+pub struct Timestamp(iso8601_timestamp::Timestamp);
+
+impl Timestamp {
+    pub const UNIX_EPOCH: Self = Self(iso8601_timestamp::Timestamp::UNIX_EPOCH);
+
+    /// The current time according to the [`TimeProviderDriver`] in use.
+    pub fn now() -> Self {
+        time_provider_now()
+    }
+
+    /// Parses an ISO8601 string in UTC, i.e. with a `Z` suffix, with at
+    /// most millisecond precision, fractional digits beyond milliseconds
+    /// must be zero.
+    pub fn parse(s: impl AsRef<str>) -> Result<Self> {
+        let s = s.as_ref();
+        let parsed = iso8601_timestamp::Timestamp::parse(s)
+            .ok_or(CommonError::InvalidISO8601String(s.to_owned()))?;
+        if !s.ends_with('Z') {
+            return Err(CommonError::TimestampNotUTC(s.to_owned()));
+        }
+        if parsed.nanosecond() % 1_000_000 != 0 {
+            return Err(CommonError::TimestampPrecisionBeyondMilliseconds(
+                s.to_owned(),
+            ));
+        }
+        Ok(Self(parsed))
+    }
+
+    /// The number of whole seconds elapsed from `earlier` until `self`,
+    /// negative if `earlier` is in fact later.
+    pub fn seconds_since(&self, earlier: &Timestamp) -> i64 {
+        self.assume_utc().unix_timestamp()
+            - earlier.assume_utc().unix_timestamp()
+    }
+
+    fn to_rfc3339(&self) -> String {
+        format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+            self.year(),
+            u8::from(self.month()),
+            self.day(),
+            self.hour(),
+            self.minute(),
+            self.second(),
+            self.millisecond()
+        )
+    }
+}
+
+impl From<iso8601_timestamp::Timestamp> for Timestamp {
+    /// Truncates `value` to millisecond precision.
+    fn from(value: iso8601_timestamp::Timestamp) -> Self {
+        let truncated = Self(value).to_rfc3339();
+        Self(
+            iso8601_timestamp::Timestamp::parse(&truncated)
+                .expect("Should always be able to parse formatted Timestamp"),
+        )
+    }
+}
+
+impl std::ops::Deref for Timestamp {
+    type Target = iso8601_timestamp::Timestamp;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl FromStr for Timestamp {
+    type Err = CommonError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse(s)
+    }
+}
+
+impl Serialize for Timestamp {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(d)?;
+        Self::parse(&s).map_err(de::Error::custom)
+    }
+}
+
+impl HasPlaceholder for Timestamp {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::parse("2023-09-11T16:05:56.000Z").unwrap()
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::parse("2023-12-20T16:05:56.000Z").unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Timestamp::placeholder(), Timestamp::placeholder());
+        assert_eq!(
+            Timestamp::placeholder_other(),
+            Timestamp::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Timestamp::placeholder(), Timestamp::placeholder_other());
+    }
+
+    #[test]
+    fn ordering() {
+        assert!(Timestamp::placeholder() < Timestamp::placeholder_other());
+    }
+
+    #[test]
+    fn seconds_since() {
+        let earlier = Timestamp::parse("2023-09-11T16:05:56.000Z").unwrap();
+        let later = Timestamp::parse("2023-09-11T16:07:00.999Z").unwrap();
+        assert_eq!(later.seconds_since(&earlier), 64);
+        assert_eq!(earlier.seconds_since(&later), -64);
+    }
+
+    #[test]
+    fn display_always_has_milliseconds() {
+        assert_eq!(
+            Timestamp::parse("2023-09-11T16:05:56Z")
+                .unwrap()
+                .to_string(),
+            "2023-09-11T16:05:56.000Z"
+        );
+        assert_eq!(
+            Timestamp::UNIX_EPOCH.to_string(),
+            "1970-01-01T00:00:00.000Z"
+        );
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            format!("{:?}", Timestamp::placeholder()),
+            "2023-09-11T16:05:56.000Z"
+        );
+    }
+
+    #[test]
+    fn parse_rejects_precision_beyond_milliseconds() {
+        assert_eq!(
+            Timestamp::parse("2023-09-11T16:05:56.123456789Z"),
+            Err(CommonError::TimestampPrecisionBeyondMilliseconds(
+                "2023-09-11T16:05:56.123456789Z".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_accepts_trailing_zero_digits() {
+        assert_eq!(
+            Timestamp::parse("2023-09-11T16:05:56.123000Z").unwrap(),
+            Timestamp::parse("2023-09-11T16:05:56.123Z").unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_rejects_offset() {
+        assert_eq!(
+            Timestamp::parse("2023-09-11T18:05:56.000+02:00"),
+            Err(CommonError::TimestampNotUTC(
+                "2023-09-11T18:05:56.000+02:00".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    fn from_truncates_to_milliseconds() {
+        let precise =
+            iso8601_timestamp::Timestamp::parse("2023-09-11T16:05:56.123456Z")
+                .unwrap();
+        assert_eq!(
+            Timestamp::from(precise),
+            Timestamp::parse("2023-09-11T16:05:56.123Z").unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert_eq!(
+            Timestamp::parse("not a date"),
+            Err(CommonError::InvalidISO8601String("not a date".to_owned()))
+        );
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!(
+            "2023-09-11T16:05:56.000Z".parse::<Timestamp>(),
+            Ok(Timestamp::placeholder())
+        );
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        assert_json_value_eq_after_roundtrip(
+            &Timestamp::placeholder(),
+            json!("2023-09-11T16:05:56.000Z"),
+        );
+    }
+
+    #[test]
+    fn json_without_milliseconds() {
+        let sut: Timestamp =
+            serde_json::from_value(json!("2023-09-11T16:05:56Z")).unwrap();
+        assert_eq!(sut, Timestamp::placeholder());
+    }
+
+    #[test]
+    fn json_fails() {
+        assert_json_value_fails::<Timestamp>(json!("2023-13-45"));
+        assert_json_value_fails::<Timestamp>(json!(1694448356));
+    }
+}
@@ -28,6 +28,12 @@ pub struct BagOfBytes {
     bytes: Vec<u8>,
 }
 
+impl zeroize::Zeroize for BagOfBytes {
+    fn zeroize(&mut self) {
+        self.bytes.zeroize()
+    }
+}
+
 impl Deref for BagOfBytes {
     type Target = Vec<u8>;
 
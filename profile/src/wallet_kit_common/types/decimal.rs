@@ -3,7 +3,7 @@ use radix_engine_common::math::Decimal as NativeDecimal;
 use radix_engine_toolkit_json::models::common::SerializableDecimal;
 
 // FIXME: Use RET's type!
-#[derive(Clone, Debug, Eq, uniffi::Record, Default)]
+#[derive(Clone, Debug, Eq, Default)]
 pub struct Decimal {
     base10_string: String,
 }
@@ -38,7 +38,7 @@ impl Ord for Decimal {
     }
 }
 impl Decimal {
-    fn native(&self) -> NativeDecimal {
+    pub(crate) fn native(&self) -> NativeDecimal {
         NativeDecimal::from_str(&self.base10_string).unwrap()
     }
     fn from_native(decimal: NativeDecimal) -> Self {
@@ -78,6 +78,22 @@ impl Decimal {
     }
 }
 
+uniffi::custom_type!(Decimal, String);
+/// Crosses the FFI boundary as its base 10 string, validated when lifted.
+impl crate::UniffiCustomTypeConverter for Decimal {
+    type Builtin = String;
+
+    #[cfg(not(tarpaulin_include))] // false negative | tested in bindgen tests
+    fn into_custom(val: Self::Builtin) -> uniffi::Result<Self> {
+        Self::new(val).map_err(|e| e.into())
+    }
+
+    #[cfg(not(tarpaulin_include))] // false negative | tested in bindgen tests
+    fn from_custom(obj: Self) -> Self::Builtin {
+        obj.to_string()
+    }
+}
+
 impl std::fmt::Display for Decimal {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.native())
@@ -113,6 +129,21 @@ impl Decimal {
     }
 }
 
+impl std::ops::Add for Decimal {
+    type Output = Self;
+
+    /// Panics on overflow, which amounts of resources on ledger never reach.
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::from_native(self.native() + rhs.native())
+    }
+}
+
+impl std::iter::Sum for Decimal {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, d| acc + d)
+    }
+}
+
 impl TryInto<Decimal> for &str {
     type Error = crate::CommonError;
 
@@ -135,6 +166,15 @@ impl TryFrom<&[u8]> for Decimal {
 mod tests {
     use crate::prelude::*;
 
+    #[test]
+    fn custom_type_roundtrip() {
+        use crate::UniffiCustomTypeConverter;
+        let sut = Decimal::into_custom("1.5".to_owned()).unwrap();
+        assert_eq!(sut, Decimal::new("1.5".to_owned()).unwrap());
+        assert_eq!(Decimal::from_custom(sut), "1.5");
+        assert!(Decimal::into_custom("not a number".to_owned()).is_err());
+    }
+
     #[test]
     fn eq() {
         assert_eq!(Decimal::zero(), Decimal::zero());
@@ -148,6 +188,30 @@ mod tests {
         assert_ne!(Decimal::one(), Decimal::zero());
     }
 
+    #[test]
+    fn add() {
+        assert_eq!(
+            Decimal::try_from_str("1.5").unwrap()
+                + Decimal::try_from_str("2.25").unwrap(),
+            Decimal::try_from_str("3.75").unwrap()
+        );
+    }
+
+    #[test]
+    fn sum() {
+        assert_eq!(
+            ["1", "2", "3.5"]
+                .into_iter()
+                .map(|s| Decimal::try_from_str(s).unwrap())
+                .sum::<Decimal>(),
+            Decimal::try_from_str("6.5").unwrap()
+        );
+        assert_eq!(
+            Vec::<Decimal>::new().into_iter().sum::<Decimal>(),
+            Decimal::zero()
+        );
+    }
+
     #[test]
     fn is_zero() {
         assert!(Decimal::zero().is_zero());
@@ -0,0 +1,228 @@
+use crate::prelude::*;
+use radix_engine_common::crypto::{blake2b_256_hash, Hash as EngineHash};
+
+// We have to use macros since UniFFI does not support generics, that is the
+// only reason this macro exists.
+macro_rules! declare_hash_type {
+    (
+        $(#[$attributes:meta])*
+        $struct_name:ident
+    ) => {
+        $(#[$attributes])*
+        ///
+        /// Serializes as a hex string.
+        #[derive(
+            Clone,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Hash,
+            SerializeDisplay,
+            DeserializeFromStr,
+            derive_more::Display,
+            derive_more::Debug,
+            uniffi::Record,
+        )]
+        #[display("{value}")]
+        #[debug("{value}")]
+        pub struct $struct_name {
+            /// The 32 bytes Blake2b-256 digest.
+            pub value: Hex32Bytes,
+        }
+
+        impl $struct_name {
+            /// Instantiates a new hash from an already computed digest.
+            pub fn new(value: Hex32Bytes) -> Self {
+                Self { value }
+            }
+
+            /// Computes the Blake2b-256 digest of `bytes`.
+            pub fn hash_of(bytes: impl AsRef<[u8]>) -> Self {
+                Self::from(blake2b_256_hash(bytes))
+            }
+
+            pub fn bytes(&self) -> [u8; 32] {
+                self.value.bytes()
+            }
+
+            pub fn to_hex(&self) -> String {
+                self.value.to_hex()
+            }
+        }
+
+        impl FromStr for $struct_name {
+            type Err = CommonError;
+
+            fn from_str(s: &str) -> Result<Self> {
+                Hex32Bytes::from_str(s).map(Self::new)
+            }
+        }
+
+        impl From<EngineHash> for $struct_name {
+            fn from(value: EngineHash) -> Self {
+                Self::new(value.into())
+            }
+        }
+
+        impl From<$struct_name> for EngineHash {
+            fn from(value: $struct_name) -> Self {
+                EngineHash(value.bytes())
+            }
+        }
+    };
+}
+
+declare_hash_type!(
+    /// A Blake2b-256 digest of any data, e.g. the ROLA payload signed when
+    /// authenticating with a Dapp.
+    Hash
+);
+
+declare_hash_type!(
+    /// The Blake2b-256 digest of a transaction intent, which is what is
+    /// signed, for the bech32 encoded form see `TransactionIntentHash`.
+    IntentHash
+);
+
+declare_hash_type!(
+    /// The Blake2b-256 digest of the bytes of a public key, e.g. of the key
+    /// derived at `CAP26::GetID` forming the ID of a factor source. Note that
+    /// virtual entity addresses use the engine's `PublicKeyHash`, which is
+    /// only the last 29 bytes of this digest.
+    PublicKeyHash
+);
+
+impl PublicKeyHash {
+    /// Computes the Blake2b-256 digest of the bytes of `public_key`.
+    pub fn hash_of_public_key(public_key: &PublicKey) -> Self {
+        Self::hash_of(public_key.to_bytes())
+    }
+}
+
+impl HasPlaceholder for Hash {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::new(Hex32Bytes::placeholder())
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::new(Hex32Bytes::placeholder_other())
+    }
+}
+
+/// Computes the Blake2b-256 digest of `bytes`.
+#[uniffi::export]
+pub fn new_hash_of(bytes: Vec<u8>) -> Hash {
+    Hash::hash_of(bytes)
+}
+
+/// Computes the Blake2b-256 digest of the bytes of a transaction intent.
+#[uniffi::export]
+pub fn new_intent_hash_of(bytes: Vec<u8>) -> IntentHash {
+    IntentHash::hash_of(bytes)
+}
+
+/// Computes the Blake2b-256 digest of the bytes of `public_key`.
+#[uniffi::export]
+pub fn new_public_key_hash_of(public_key: PublicKey) -> PublicKeyHash {
+    PublicKeyHash::hash_of_public_key(&public_key)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn hash_of() {
+        assert_eq!(
+            Hash::hash_of("Hello Radix").to_hex(),
+            "48f1bd08444b5e713db9e14caac2faae71836786ac94d645b00679728202a935"
+        );
+    }
+
+    #[test]
+    fn hash_of_is_same_as_hash() {
+        assert_eq!(Hash::hash_of("Test").bytes(), hash("Test").0);
+    }
+
+    #[test]
+    fn types_hash_equally() {
+        assert_eq!(
+            Hash::hash_of("Test").value,
+            IntentHash::hash_of("Test").value
+        );
+        assert_eq!(
+            Hash::hash_of("Test").value,
+            PublicKeyHash::hash_of("Test").value
+        );
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        assert_json_value_eq_after_roundtrip(
+            &Hash::placeholder(),
+            json!("deadbeef".repeat(8)),
+        );
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!(
+            "deadbeef".repeat(8).parse::<Hash>().unwrap(),
+            Hash::placeholder()
+        );
+        assert!("dead".parse::<Hash>().is_err());
+    }
+
+    #[test]
+    fn display_and_debug() {
+        let sut = Hash::placeholder_other();
+        assert_eq!(format!("{}", sut), sut.to_hex());
+        assert_eq!(format!("{:?}", sut), sut.to_hex());
+    }
+
+    #[test]
+    fn engine_hash_roundtrip() {
+        let sut = IntentHash::hash_of("Test");
+        let engine: radix_engine_common::crypto::Hash = sut.clone().into();
+        assert_eq!(IntentHash::from(engine), sut);
+    }
+
+    #[test]
+    fn public_key_hash_is_factor_source_id_body() {
+        let mwp = MnemonicWithPassphrase::placeholder();
+        let public_key = mwp
+            .derive_private_key(GetIDPath::default())
+            .public_key()
+            .public_key;
+        assert_eq!(
+            PublicKeyHash::hash_of_public_key(&public_key).value,
+            FactorSourceIDFromHash::new_for_device(mwp).body
+        );
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn new_hash_of_bytes() {
+        assert_eq!(new_hash_of(b"Test".to_vec()), Hash::hash_of("Test"));
+        assert_eq!(
+            new_intent_hash_of(b"Test".to_vec()),
+            IntentHash::hash_of("Test")
+        );
+    }
+
+    #[test]
+    fn new_public_key_hash_of_public_key() {
+        let public_key = PublicKey::placeholder();
+        assert_eq!(
+            new_public_key_hash_of(public_key.clone()),
+            PublicKeyHash::hash_of_public_key(&public_key)
+        );
+    }
+}
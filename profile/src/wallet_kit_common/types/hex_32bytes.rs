@@ -61,6 +61,12 @@ impl Hex32Bytes {
     }
 }
 
+impl zeroize::Zeroize for Hex32Bytes {
+    fn zeroize(&mut self) {
+        self.bag_of_bytes.zeroize()
+    }
+}
+
 impl Hex32Bytes {
     /// Returns a references to the inner array slice.
     pub fn bytes(&self) -> [u8; 32] {
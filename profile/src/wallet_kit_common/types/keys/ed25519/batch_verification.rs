@@ -0,0 +1,145 @@
+use crate::prelude::*;
+
+use radix_engine_common::crypto::Hash;
+use transaction::signing::ed25519::Ed25519Signature;
+
+impl Ed25519PublicKey {
+    /// Verifies many EdDSA signatures over Curve25519 at once, e.g. when
+    /// validating ROLA proofs or imported signatures, returning the validity
+    /// of each `(public_key, hash, signature)` triple, in order.
+    ///
+    /// All signatures are first verified together using the batch verifier
+    /// of `ed25519-dalek`, which is considerably faster than verifying them
+    /// one by one. If the batch is rejected - i.e. at least one signature is
+    /// invalid - we fall back to verifying each signature individually, to
+    /// tell which ones are invalid.
+    pub fn verify_batch(
+        signatures: &[(Ed25519PublicKey, Hash, Ed25519Signature)],
+    ) -> Vec<bool> {
+        if signatures.is_empty() {
+            return Vec::new();
+        }
+        if Self::dalek_verify_batch(signatures) {
+            return vec![true; signatures.len()];
+        }
+        signatures
+            .iter()
+            .map(|(public_key, hash, signature)| {
+                public_key.is_valid(signature, hash)
+            })
+            .collect()
+    }
+
+    /// Returns `true` iff all signatures are valid, `false` if any signature
+    /// is invalid or if any key or signature could not be used by the
+    /// batch verifier.
+    fn dalek_verify_batch(
+        signatures: &[(Ed25519PublicKey, Hash, Ed25519Signature)],
+    ) -> bool {
+        let Ok(public_keys) = signatures
+            .iter()
+            .map(|(public_key, _, _)| {
+                ed25519_dalek::PublicKey::from_bytes(&public_key.to_bytes())
+            })
+            .collect::<Result<Vec<_>, _>>()
+        else {
+            return false;
+        };
+        let Ok(dalek_signatures) = signatures
+            .iter()
+            .map(|(_, _, signature)| {
+                ed25519_dalek::Signature::try_from(&signature.0[..])
+            })
+            .collect::<Result<Vec<_>, _>>()
+        else {
+            return false;
+        };
+        let messages = signatures
+            .iter()
+            .map(|(_, hash, _)| &hash.0[..])
+            .collect::<Vec<_>>();
+
+        ed25519_dalek::verify_batch(&messages, &dalek_signatures, &public_keys)
+            .is_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use radix_engine_common::crypto::Hash;
+    use transaction::signing::ed25519::Ed25519Signature;
+
+    fn signed(
+        private_key: Ed25519PrivateKey,
+        message: &str,
+    ) -> (Ed25519PublicKey, Hash, Ed25519Signature) {
+        let msg_hash = hash(message);
+        let signature = private_key.sign(&msg_hash);
+        (private_key.public_key(), msg_hash, signature)
+    }
+
+    fn valid() -> Vec<(Ed25519PublicKey, Hash, Ed25519Signature)> {
+        vec![
+            signed(Ed25519PrivateKey::placeholder_alice(), "Alice"),
+            signed(Ed25519PrivateKey::placeholder_bob(), "Bob"),
+            signed(Ed25519PrivateKey::generate(), "Random"),
+        ]
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(Ed25519PublicKey::verify_batch(&[]), Vec::<bool>::new());
+    }
+
+    #[test]
+    fn all_valid() {
+        assert_eq!(
+            Ed25519PublicKey::verify_batch(&valid()),
+            vec![true, true, true]
+        );
+    }
+
+    #[test]
+    fn one_wrong_hash() {
+        let mut signatures = valid();
+        signatures[1].1 = hash("Mallory");
+        assert_eq!(
+            Ed25519PublicKey::verify_batch(&signatures),
+            vec![true, false, true]
+        );
+    }
+
+    #[test]
+    fn one_wrong_public_key() {
+        let mut signatures = valid();
+        signatures[0].0 = Ed25519PrivateKey::placeholder_bob().public_key();
+        assert_eq!(
+            Ed25519PublicKey::verify_batch(&signatures),
+            vec![false, true, true]
+        );
+    }
+
+    #[test]
+    fn one_malformed_signature() {
+        let mut signatures = valid();
+        signatures[2].2 = Ed25519Signature([0xff; 64]);
+        assert_eq!(
+            Ed25519PublicKey::verify_batch(&signatures),
+            vec![true, true, false]
+        );
+    }
+
+    #[test]
+    fn same_result_as_individual_verification() {
+        let mut signatures = valid();
+        signatures[0].2 = signatures[1].2;
+        let individually = signatures
+            .iter()
+            .map(|(public_key, hash, signature)| {
+                public_key.is_valid(signature, hash)
+            })
+            .collect_vec();
+        assert_eq!(Ed25519PublicKey::verify_batch(&signatures), individually);
+    }
+}
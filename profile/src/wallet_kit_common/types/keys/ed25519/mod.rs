@@ -1,5 +1,7 @@
+mod batch_verification;
 mod private_key;
 mod public_key;
 
+pub use batch_verification::*;
 pub use private_key::*;
 pub use public_key::*;
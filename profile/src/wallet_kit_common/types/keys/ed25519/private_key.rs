@@ -8,7 +8,7 @@ use transaction::signing::ed25519::{
 /// An Ed25519 private key used to create cryptographic signatures, using
 /// EdDSA scheme.
 #[derive(derive_more::Debug)]
-#[debug("{}", self.to_hex())]
+#[debug("{}", self.redacted_debug())]
 pub struct Ed25519PrivateKey(EngineEd25519PrivateKey);
 
 impl Ed25519PrivateKey {
@@ -22,6 +22,17 @@ impl Ed25519PrivateKey {
     }
 }
 
+impl SafeDebug for Ed25519PrivateKey {
+    fn redacted_debug(&self) -> String {
+        format!("<REDACTED {} bytes>", self.to_bytes().len())
+    }
+
+    #[cfg(feature = "danger-fully-reveal-debug")]
+    fn danger_fully_reveal_debug(&self) -> String {
+        self.to_hex()
+    }
+}
+
 impl PartialEq for Ed25519PrivateKey {
     fn eq(&self, other: &Self) -> bool {
         self.to_bytes() == other.to_bytes()
@@ -242,6 +253,19 @@ mod tests {
             "0000000000000000000000000000000000000000000000000000000000000001";
         assert_eq!(
             format!("{:?}", Ed25519PrivateKey::from_str(hex).unwrap()),
+            "<REDACTED 32 bytes>"
+        );
+    }
+
+    #[cfg(feature = "danger-fully-reveal-debug")]
+    #[test]
+    fn danger_fully_reveal_debug() {
+        let hex =
+            "0000000000000000000000000000000000000000000000000000000000000001";
+        assert_eq!(
+            Ed25519PrivateKey::from_str(hex)
+                .unwrap()
+                .danger_fully_reveal_debug(),
             hex
         );
     }
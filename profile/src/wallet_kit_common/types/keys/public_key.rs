@@ -51,7 +51,67 @@ impl From<Secp256k1PublicKey> for PublicKey {
     }
 }
 
+#[uniffi::export]
+pub fn new_public_key_from_hex(
+    curve: SLIP10Curve,
+    hex: String,
+) -> Result<PublicKey> {
+    PublicKey::from_hex(curve, &hex)
+}
+
+#[uniffi::export]
+pub fn new_public_key_from_bytes(
+    curve: SLIP10Curve,
+    bytes: Vec<u8>,
+) -> Result<PublicKey> {
+    PublicKey::from_bytes(curve, &bytes)
+}
+
+#[uniffi::export]
+pub fn new_public_key_placeholder() -> PublicKey {
+    PublicKey::placeholder()
+}
+
+#[uniffi::export]
+pub fn new_public_key_placeholder_other() -> PublicKey {
+    PublicKey::placeholder_other()
+}
+
+/// Returns the `SLIP10Curve` of the `public_key`.
+#[uniffi::export]
+pub fn public_key_curve(public_key: &PublicKey) -> SLIP10Curve {
+    public_key.curve()
+}
+
+/// Encodes the inner public key to a hexadecimal string, lowercased, without
+/// any `0x` prefix.
+#[uniffi::export]
+pub fn public_key_to_hex(public_key: &PublicKey) -> String {
+    public_key.to_hex()
+}
+
+#[uniffi::export]
+pub fn public_key_to_bytes(public_key: &PublicKey) -> Vec<u8> {
+    public_key.to_bytes()
+}
+
 impl PublicKey {
+    /// Try to instantiate a `PublicKey` on `curve` from a hex string.
+    pub fn from_hex(curve: SLIP10Curve, hex: &str) -> Result<Self> {
+        match curve {
+            SLIP10Curve::Curve25519 => Self::ed25519_from_str(hex),
+            SLIP10Curve::Secp256k1 => Self::secp256k1_from_str(hex),
+        }
+    }
+
+    /// Try to instantiate a `PublicKey` on `curve` from bytes.
+    pub fn from_bytes(curve: SLIP10Curve, bytes: &[u8]) -> Result<Self> {
+        match curve {
+            SLIP10Curve::Curve25519 => Self::ed25519_from_bytes(bytes),
+            SLIP10Curve::Secp256k1 => Self::secp256k1_from_bytes(bytes),
+        }
+    }
+
     /// Try to instantiate a `PublicKey` from bytes as a `Secp256k1PublicKey`.
     pub fn secp256k1_from_bytes(slice: &[u8]) -> Result<Self> {
         Secp256k1PublicKey::try_from(slice).map(|k| k.into())
@@ -161,16 +221,8 @@ impl<'de> Deserialize<'de> for PublicKey {
             curve: SLIP10Curve,
         }
         let wrapper = Wrapper::deserialize(deserializer)?;
-        match wrapper.curve {
-            SLIP10Curve::Curve25519 => Ed25519PublicKey::from_str(&wrapper.hex)
-                .map(|pk| PublicKey::Ed25519 { value: pk })
-                .map_err(de::Error::custom),
-            SLIP10Curve::Secp256k1 => {
-                Secp256k1PublicKey::from_str(&wrapper.hex)
-                    .map(|pk| PublicKey::Secp256k1 { value: pk })
-                    .map_err(de::Error::custom)
-            }
-        }
+        PublicKey::from_hex(wrapper.curve, &wrapper.hex)
+            .map_err(de::Error::custom)
     }
 }
 
@@ -421,4 +473,102 @@ mod tests {
         let key: PublicKey = secp256k1.clone().into();
         assert_eq!(key.as_secp256k1().unwrap(), &secp256k1);
     }
+
+    #[test]
+    fn from_hex_ed25519() {
+        assert_eq!(
+            PublicKey::from_hex(
+                SLIP10Curve::Curve25519,
+                "ec172b93ad5e563bf4932c70e1245034c35467ef2efd4d64ebf819683467e2bf"
+            )
+            .unwrap(),
+            PublicKey::placeholder_ed25519_alice()
+        );
+    }
+
+    #[test]
+    fn from_hex_secp256k1() {
+        assert_eq!(
+            PublicKey::from_hex(
+                SLIP10Curve::Secp256k1,
+                "02517b88916e7f315bb682f9926b14bc67a0e4246f8a419b986269e1a7e61fffa7"
+            )
+            .unwrap(),
+            PublicKey::placeholder_secp256k1_alice()
+        );
+    }
+
+    #[test]
+    fn from_hex_wrong_curve() {
+        assert!(PublicKey::from_hex(
+            SLIP10Curve::Curve25519,
+            "02517b88916e7f315bb682f9926b14bc67a0e4246f8a419b986269e1a7e61fffa7"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn from_bytes_roundtrip() {
+        for key in [
+            PublicKey::placeholder_ed25519_bob(),
+            PublicKey::placeholder_secp256k1_bob(),
+        ] {
+            assert_eq!(
+                PublicKey::from_bytes(key.curve(), &key.to_bytes()).unwrap(),
+                key
+            );
+        }
+    }
+
+    #[test]
+    fn curve() {
+        assert_eq!(
+            PublicKey::placeholder_ed25519().curve(),
+            SLIP10Curve::Curve25519
+        );
+        assert_eq!(
+            PublicKey::placeholder_secp256k1().curve(),
+            SLIP10Curve::Secp256k1
+        );
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality_placeholders() {
+        assert_eq!(PublicKey::placeholder(), new_public_key_placeholder());
+        assert_eq!(
+            PublicKey::placeholder_other(),
+            new_public_key_placeholder_other()
+        );
+    }
+
+    #[test]
+    fn new_from_hex() {
+        let hex =
+            "02517b88916e7f315bb682f9926b14bc67a0e4246f8a419b986269e1a7e61fffa7";
+        let from_hex =
+            new_public_key_from_hex(SLIP10Curve::Secp256k1, hex.to_string())
+                .unwrap();
+        assert_eq!(from_hex, PublicKey::placeholder_secp256k1_alice());
+        assert_eq!(public_key_to_hex(&from_hex), hex);
+        assert_eq!(public_key_curve(&from_hex), SLIP10Curve::Secp256k1);
+    }
+
+    #[test]
+    fn new_from_bytes() {
+        let bytes = hex_decode(
+            "ec172b93ad5e563bf4932c70e1245034c35467ef2efd4d64ebf819683467e2bf",
+        )
+        .unwrap();
+        let from_bytes =
+            new_public_key_from_bytes(SLIP10Curve::Curve25519, bytes.clone())
+                .unwrap();
+        assert_eq!(from_bytes, PublicKey::placeholder_ed25519_alice());
+        assert_eq!(public_key_to_bytes(&from_bytes), bytes);
+        assert_eq!(public_key_curve(&from_bytes), SLIP10Curve::Curve25519);
+    }
 }
@@ -8,7 +8,7 @@ use transaction::signing::secp256k1::{
 /// A secp256k1 private key used to create cryptographic signatures, more specifically
 /// ECDSA signatures, that offer recovery of the public key.
 #[derive(derive_more::Debug)]
-#[debug("{}", self.to_hex())]
+#[debug("{}", self.redacted_debug())]
 pub struct Secp256k1PrivateKey(EngineSecp256k1PrivateKey);
 
 impl Secp256k1PrivateKey {
@@ -22,6 +22,17 @@ impl Secp256k1PrivateKey {
     }
 }
 
+impl SafeDebug for Secp256k1PrivateKey {
+    fn redacted_debug(&self) -> String {
+        format!("<REDACTED {} bytes>", self.to_bytes().len())
+    }
+
+    #[cfg(feature = "danger-fully-reveal-debug")]
+    fn danger_fully_reveal_debug(&self) -> String {
+        self.to_hex()
+    }
+}
+
 impl PartialEq for Secp256k1PrivateKey {
     fn eq(&self, other: &Self) -> bool {
         self.to_bytes() == other.to_bytes()
@@ -267,6 +278,19 @@ mod tests {
             "0000000000000000000000000000000000000000000000000000000000000001";
         assert_eq!(
             format!("{:?}", Secp256k1PrivateKey::from_str(hex).unwrap()),
+            "<REDACTED 32 bytes>"
+        );
+    }
+
+    #[cfg(feature = "danger-fully-reveal-debug")]
+    #[test]
+    fn danger_fully_reveal_debug() {
+        let hex =
+            "0000000000000000000000000000000000000000000000000000000000000001";
+        assert_eq!(
+            Secp256k1PrivateKey::from_str(hex)
+                .unwrap()
+                .danger_fully_reveal_debug(),
             hex
         );
     }
@@ -0,0 +1,197 @@
+use crate::prelude::*;
+
+use bip32::secp256k1::ecdsa::{
+    RecoveryId as BIP32RecoveryId, Signature as BIP32Secp256k1Signature,
+    VerifyingKey as BIP32VerifyingKey,
+};
+use radix_engine_common::crypto::IsHash;
+use transaction::signing::secp256k1::Secp256k1Signature;
+
+/// Conversions of, and public key recovery from, the recoverable ECDSA
+/// signatures over secp256k1 produced by [`Secp256k1PrivateKey`], which are
+/// 65 bytes: the recovery id `v` followed by the compact signature `r || s`.
+///
+/// Needed to verify Olympia accounts and to handle signatures from hardware
+/// wallets, which use the compact or DER encoding.
+pub trait Secp256k1SignatureExt: Sized {
+    /// Creates a recoverable signature from a 64 bytes compact signature
+    /// `r || s` and the `recovery_id` (`0..=3`).
+    fn from_compact(compact: &[u8], recovery_id: u8) -> Result<Self>;
+
+    /// Creates a recoverable signature from a DER encoded signature and the
+    /// `recovery_id` (`0..=3`), which DER does not encode.
+    fn from_der(der: &[u8], recovery_id: u8) -> Result<Self>;
+
+    /// The recovery id `v`.
+    fn recovery_id(&self) -> u8;
+
+    /// The compact signature `r || s`, i.e. without the recovery id.
+    fn to_compact(&self) -> [u8; 64];
+
+    /// The DER encoding of the signature, without the recovery id.
+    fn to_der(&self) -> Vec<u8>;
+
+    /// Recovers the public key which produced this signature of `msg_hash`,
+    /// the signature is valid for the returned key.
+    fn recover(&self, msg_hash: &impl IsHash) -> Result<Secp256k1PublicKey>;
+}
+
+impl Secp256k1SignatureExt for Secp256k1Signature {
+    fn from_compact(compact: &[u8], recovery_id: u8) -> Result<Self> {
+        BIP32RecoveryId::from_byte(recovery_id).ok_or(
+            CommonError::InvalidSecp256k1SignatureRecoveryID(recovery_id),
+        )?;
+        let compact = <[u8; 64]>::try_from(compact).map_err(|_| {
+            CommonError::InvalidSecp256k1SignatureFromBytes(compact.to_vec())
+        })?;
+        BIP32Secp256k1Signature::from_slice(&compact).map_err(|_| {
+            CommonError::InvalidSecp256k1SignatureFromBytes(compact.to_vec())
+        })?;
+        let mut bytes = [0u8; 65];
+        bytes[0] = recovery_id;
+        bytes[1..].copy_from_slice(&compact);
+        Ok(Self(bytes))
+    }
+
+    fn from_der(der: &[u8], recovery_id: u8) -> Result<Self> {
+        let signature =
+            BIP32Secp256k1Signature::from_der(der).map_err(|_| {
+                CommonError::InvalidSecp256k1SignatureFromBytes(der.to_vec())
+            })?;
+        Self::from_compact(&signature.to_bytes(), recovery_id)
+    }
+
+    fn recovery_id(&self) -> u8 {
+        self.0[0]
+    }
+
+    fn to_compact(&self) -> [u8; 64] {
+        let mut compact = [0u8; 64];
+        compact.copy_from_slice(&self.0[1..]);
+        compact
+    }
+
+    fn to_der(&self) -> Vec<u8> {
+        BIP32Secp256k1Signature::from_slice(&self.to_compact())
+            .expect("Should always be able to decode a valid signature.")
+            .to_der()
+            .as_bytes()
+            .to_vec()
+    }
+
+    fn recover(&self, msg_hash: &impl IsHash) -> Result<Secp256k1PublicKey> {
+        let recovery_id = BIP32RecoveryId::from_byte(self.recovery_id())
+            .ok_or(CommonError::InvalidSecp256k1SignatureRecoveryID(
+                self.recovery_id(),
+            ))?;
+        let signature = BIP32Secp256k1Signature::from_slice(&self.to_compact())
+            .map_err(|_| {
+                CommonError::InvalidSecp256k1SignatureFromBytes(self.0.to_vec())
+            })?;
+        let verifying_key = BIP32VerifyingKey::recover_from_prehash(
+            &msg_hash.as_hash().0,
+            &signature,
+            recovery_id,
+        )
+        .map_err(|_| CommonError::UnableToRecoverSecp256k1PublicKey)?;
+        Secp256k1PublicKey::try_from(
+            verifying_key.to_encoded_point(true).as_bytes(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use transaction::signing::secp256k1::Secp256k1Signature;
+
+    /// Signature of `hash("Test")` by the private key `1`.
+    fn signature() -> Secp256k1Signature {
+        Secp256k1Signature::from_str("00eb8dcd5bb841430dd0a6f45565a1b8bdb4a204eb868832cd006f963a89a662813ab844a542fcdbfda4086a83fbbde516214113051b9c8e42a206c98d564d7122").unwrap()
+    }
+
+    #[test]
+    fn recovery_id() {
+        assert_eq!(signature().recovery_id(), 0);
+    }
+
+    #[test]
+    fn compact_roundtrip() {
+        let sut = signature();
+        let compact = sut.to_compact();
+        assert_eq!(hex_encode(compact), "eb8dcd5bb841430dd0a6f45565a1b8bdb4a204eb868832cd006f963a89a662813ab844a542fcdbfda4086a83fbbde516214113051b9c8e42a206c98d564d7122");
+        assert_eq!(
+            Secp256k1Signature::from_compact(&compact, sut.recovery_id()),
+            Ok(sut)
+        );
+    }
+
+    #[test]
+    fn from_compact_invalid_length() {
+        assert_eq!(
+            Secp256k1Signature::from_compact(&[0xde, 0xad], 0),
+            Err(CommonError::InvalidSecp256k1SignatureFromBytes(vec![
+                0xde, 0xad
+            ]))
+        );
+    }
+
+    #[test]
+    fn from_compact_invalid_recovery_id() {
+        assert_eq!(
+            Secp256k1Signature::from_compact(&signature().to_compact(), 4),
+            Err(CommonError::InvalidSecp256k1SignatureRecoveryID(4))
+        );
+    }
+
+    #[test]
+    fn der() {
+        let sut = signature();
+        let der = sut.to_der();
+        assert_eq!(hex_encode(&der), "3045022100eb8dcd5bb841430dd0a6f45565a1b8bdb4a204eb868832cd006f963a89a6628102203ab844a542fcdbfda4086a83fbbde516214113051b9c8e42a206c98d564d7122");
+        assert_eq!(
+            Secp256k1Signature::from_der(&der, sut.recovery_id()),
+            Ok(sut)
+        );
+    }
+
+    #[test]
+    fn from_der_invalid() {
+        assert_eq!(
+            Secp256k1Signature::from_der(&[0xde, 0xad], 0),
+            Err(CommonError::InvalidSecp256k1SignatureFromBytes(vec![
+                0xde, 0xad
+            ]))
+        );
+    }
+
+    #[test]
+    fn recover() {
+        let private_key: Secp256k1PrivateKey =
+            "0000000000000000000000000000000000000000000000000000000000000001"
+                .parse()
+                .unwrap();
+        assert_eq!(
+            signature().recover(&hash("Test")),
+            Ok(private_key.public_key())
+        );
+    }
+
+    #[test]
+    fn recover_placeholder() {
+        let private_key = Secp256k1PrivateKey::placeholder_bob();
+        let msg_hash = hash("Olympia");
+        let sut = private_key.sign(&msg_hash);
+        let recovered = sut.recover(&msg_hash).unwrap();
+        assert_eq!(recovered, private_key.public_key());
+        assert!(recovered.is_valid(&sut, &msg_hash));
+    }
+
+    #[test]
+    fn recover_other_hash_yields_other_key() {
+        assert_ne!(
+            signature().recover(&hash("Other")),
+            signature().recover(&hash("Test"))
+        );
+    }
+}
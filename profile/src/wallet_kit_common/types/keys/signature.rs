@@ -0,0 +1,295 @@
+use crate::{prelude::*, UniffiCustomTypeConverter};
+
+use transaction::signing::{
+    ed25519::Ed25519Signature, secp256k1::Secp256k1Signature,
+};
+
+/// A tagged union of signatures on different curves, supported curves are
+/// `secp256k1` and `Curve25519`, mirroring [`PublicKey`].
+#[derive(Clone, Debug, PartialEq, EnumAsInner, Eq, Hash, uniffi::Enum)]
+pub enum Signature {
+    /// An EdDSA signature over Curve25519, 64 bytes.
+    Ed25519 { value: Ed25519Signature },
+
+    /// A recoverable ECDSA signature over secp256k1, 65 bytes.
+    Secp256k1 { value: Secp256k1Signature },
+}
+
+uniffi::custom_type!(Ed25519Signature, BagOfBytes);
+impl UniffiCustomTypeConverter for Ed25519Signature {
+    type Builtin = BagOfBytes;
+
+    #[cfg(not(tarpaulin_include))] // false negative | tested in bindgen tests
+    fn into_custom(val: Self::Builtin) -> uniffi::Result<Self> {
+        Signature::ed25519_from_bytes(val.as_slice())
+            .map(|s| *s.as_ed25519().unwrap())
+            .map_err(|e| e.into())
+    }
+
+    #[cfg(not(tarpaulin_include))] // false negative | tested in bindgen tests
+    fn from_custom(obj: Self) -> Self::Builtin {
+        obj.0.to_vec().into()
+    }
+}
+
+uniffi::custom_type!(Secp256k1Signature, BagOfBytes);
+impl UniffiCustomTypeConverter for Secp256k1Signature {
+    type Builtin = BagOfBytes;
+
+    #[cfg(not(tarpaulin_include))] // false negative | tested in bindgen tests
+    fn into_custom(val: Self::Builtin) -> uniffi::Result<Self> {
+        Signature::secp256k1_from_bytes(val.as_slice())
+            .map(|s| *s.as_secp256k1().unwrap())
+            .map_err(|e| e.into())
+    }
+
+    #[cfg(not(tarpaulin_include))] // false negative | tested in bindgen tests
+    fn from_custom(obj: Self) -> Self::Builtin {
+        obj.0.to_vec().into()
+    }
+}
+
+impl From<Ed25519Signature> for Signature {
+    fn from(value: Ed25519Signature) -> Self {
+        Self::Ed25519 { value }
+    }
+}
+
+impl From<Secp256k1Signature> for Signature {
+    fn from(value: Secp256k1Signature) -> Self {
+        Self::Secp256k1 { value }
+    }
+}
+
+#[uniffi::export]
+pub fn new_signature_from_hex(
+    curve: SLIP10Curve,
+    hex: String,
+) -> Result<Signature> {
+    Signature::from_hex(curve, &hex)
+}
+
+#[uniffi::export]
+pub fn new_signature_from_bytes(
+    curve: SLIP10Curve,
+    bytes: Vec<u8>,
+) -> Result<Signature> {
+    Signature::from_bytes(curve, &bytes)
+}
+
+#[uniffi::export]
+pub fn signature_to_hex(signature: &Signature) -> String {
+    signature.to_hex()
+}
+
+#[uniffi::export]
+pub fn signature_to_bytes(signature: &Signature) -> Vec<u8> {
+    signature.to_bytes()
+}
+
+impl Signature {
+    /// Try to instantiate a `Signature` on `curve` from a hex string.
+    pub fn from_hex(curve: SLIP10Curve, hex: &str) -> Result<Self> {
+        let bytes = hex_decode(hex)
+            .map_err(|_| CommonError::StringNotHex(hex.to_owned()))?;
+        Self::from_bytes(curve, &bytes)
+    }
+
+    /// Try to instantiate a `Signature` on `curve` from bytes.
+    pub fn from_bytes(curve: SLIP10Curve, bytes: &[u8]) -> Result<Self> {
+        match curve {
+            SLIP10Curve::Curve25519 => Self::ed25519_from_bytes(bytes),
+            SLIP10Curve::Secp256k1 => Self::secp256k1_from_bytes(bytes),
+        }
+    }
+
+    /// Try to instantiate a `Signature` from 64 bytes as an `Ed25519Signature`.
+    pub fn ed25519_from_bytes(bytes: &[u8]) -> Result<Self> {
+        <[u8; 64]>::try_from(bytes)
+            .map(|b| Ed25519Signature(b).into())
+            .map_err(|_| {
+                CommonError::InvalidEd25519SignatureFromBytes(bytes.to_vec())
+            })
+    }
+
+    /// Try to instantiate a `Signature` from 65 bytes as a `Secp256k1Signature`.
+    pub fn secp256k1_from_bytes(bytes: &[u8]) -> Result<Self> {
+        <[u8; 65]>::try_from(bytes)
+            .map(|b| Secp256k1Signature(b).into())
+            .map_err(|_| {
+                CommonError::InvalidSecp256k1SignatureFromBytes(bytes.to_vec())
+            })
+    }
+
+    /// Returns a `SLIP10Curve`, being the curve of the `Signature`.
+    pub fn curve(&self) -> SLIP10Curve {
+        match self {
+            Signature::Ed25519 { value: _ } => SLIP10Curve::Curve25519,
+            Signature::Secp256k1 { value: _ } => SLIP10Curve::Secp256k1,
+        }
+    }
+
+    /// Returns a clone of the bytes of the inner signature as a `Vec`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            Signature::Ed25519 { value } => value.0.to_vec(),
+            Signature::Secp256k1 { value } => value.0.to_vec(),
+        }
+    }
+
+    /// Returns a hex encoding of the inner signature.
+    pub fn to_hex(&self) -> String {
+        hex_encode(self.to_bytes())
+    }
+}
+
+impl HasPlaceholder for Signature {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        SignatureWithPublicKey::placeholder().signature()
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        SignatureWithPublicKey::placeholder_other().signature()
+    }
+}
+
+impl<'de> Deserialize<'de> for Signature {
+    #[cfg(not(tarpaulin_include))] // false negative
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct Wrapper {
+            signature: String,
+            curve: SLIP10Curve,
+        }
+        let wrapper = Wrapper::deserialize(deserializer)?;
+        Signature::from_hex(wrapper.curve, &wrapper.signature)
+            .map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for Signature {
+    #[cfg(not(tarpaulin_include))] // false negative
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Signature", 2)?;
+        state.serialize_field("curve", &self.curve())?;
+        state.serialize_field("signature", &self.to_hex())?;
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Signature::placeholder(), Signature::placeholder());
+        assert_eq!(
+            Signature::placeholder_other(),
+            Signature::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Signature::placeholder(), Signature::placeholder_other());
+    }
+
+    #[test]
+    fn curve() {
+        assert_eq!(Signature::placeholder().curve(), SLIP10Curve::Curve25519);
+        assert_eq!(
+            Signature::placeholder_other().curve(),
+            SLIP10Curve::Secp256k1
+        );
+    }
+
+    #[test]
+    fn hex_roundtrip() {
+        for sut in [Signature::placeholder(), Signature::placeholder_other()] {
+            assert_eq!(
+                Signature::from_hex(sut.curve(), &sut.to_hex()),
+                Ok(sut)
+            );
+        }
+    }
+
+    #[test]
+    fn from_bytes_wrong_length() {
+        assert_eq!(
+            Signature::from_bytes(SLIP10Curve::Curve25519, &[0xab; 65]),
+            Err(CommonError::InvalidEd25519SignatureFromBytes(vec![
+                0xab;
+                65
+            ]))
+        );
+        assert_eq!(
+            Signature::from_bytes(SLIP10Curve::Secp256k1, &[0xab; 64]),
+            Err(CommonError::InvalidSecp256k1SignatureFromBytes(vec![
+                0xab;
+                64
+            ]))
+        );
+    }
+
+    #[test]
+    fn from_hex_not_hex() {
+        assert_eq!(
+            Signature::from_hex(SLIP10Curve::Curve25519, "not hex"),
+            Err(CommonError::StringNotHex("not hex".to_owned()))
+        );
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        let sut = Signature::placeholder();
+        let json = serde_json::to_value(&sut).unwrap();
+        assert_eq!(
+            json,
+            json!({ "curve": "curve25519", "signature": sut.to_hex() })
+        );
+        assert_eq!(serde_json::from_value::<Signature>(json).unwrap(), sut);
+    }
+
+    #[test]
+    fn json_wrong_length_fails() {
+        assert_json_fails::<Signature>(
+            r#"
+            {
+                "curve": "secp256k1",
+                "signature": "deadbeef"
+            }
+            "#,
+        );
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn new_from_hex() {
+        let sut = Signature::placeholder_other();
+        let from_hex =
+            new_signature_from_hex(sut.curve(), sut.to_hex()).unwrap();
+        assert_eq!(from_hex, sut);
+        assert_eq!(signature_to_hex(&from_hex), sut.to_hex());
+    }
+
+    #[test]
+    fn new_from_bytes() {
+        let sut = Signature::placeholder();
+        let from_bytes =
+            new_signature_from_bytes(sut.curve(), sut.to_bytes()).unwrap();
+        assert_eq!(from_bytes, sut);
+        assert_eq!(signature_to_bytes(&from_bytes), sut.to_bytes());
+    }
+}
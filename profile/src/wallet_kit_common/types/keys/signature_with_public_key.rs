@@ -0,0 +1,348 @@
+use crate::prelude::*;
+
+use radix_engine_common::crypto::IsHash;
+use transaction::signing::{
+    ed25519::Ed25519Signature, secp256k1::Secp256k1Signature,
+};
+
+/// A signature together with the public key of the private key which
+/// produced it, guaranteed to be on the same curve.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Enum)]
+pub enum SignatureWithPublicKey {
+    /// An EdDSA signature over Curve25519 and the Ed25519 public key.
+    Ed25519 {
+        public_key: Ed25519PublicKey,
+        signature: Ed25519Signature,
+    },
+
+    /// An ECDSA signature over secp256k1 and the secp256k1 public key.
+    Secp256k1 {
+        public_key: Secp256k1PublicKey,
+        signature: Secp256k1Signature,
+    },
+}
+
+impl SignatureWithPublicKey {
+    /// Combines `public_key` and `signature`, failing if they are not on
+    /// the same curve.
+    pub fn new(public_key: PublicKey, signature: Signature) -> Result<Self> {
+        match (public_key, signature) {
+            (
+                PublicKey::Ed25519 { value: public_key },
+                Signature::Ed25519 { value: signature },
+            ) => Ok(Self::Ed25519 {
+                public_key,
+                signature,
+            }),
+            (
+                PublicKey::Secp256k1 { value: public_key },
+                Signature::Secp256k1 { value: signature },
+            ) => Ok(Self::Secp256k1 {
+                public_key,
+                signature,
+            }),
+            (public_key, signature) => {
+                Err(CommonError::SignatureCurveMismatch {
+                    public_key_curve: public_key.curve().id(),
+                    signature_curve: signature.curve().id(),
+                })
+            }
+        }
+    }
+
+    /// Returns a `SLIP10Curve`, being the curve of the public key and
+    /// signature.
+    pub fn curve(&self) -> SLIP10Curve {
+        match self {
+            Self::Ed25519 { .. } => SLIP10Curve::Curve25519,
+            Self::Secp256k1 { .. } => SLIP10Curve::Secp256k1,
+        }
+    }
+
+    /// The public key of the private key which produced the signature.
+    pub fn public_key(&self) -> PublicKey {
+        match self {
+            Self::Ed25519 { public_key, .. } => public_key.clone().into(),
+            Self::Secp256k1 { public_key, .. } => public_key.clone().into(),
+        }
+    }
+
+    /// The signature.
+    pub fn signature(&self) -> Signature {
+        match self {
+            Self::Ed25519 { signature, .. } => (*signature).into(),
+            Self::Secp256k1 { signature, .. } => (*signature).into(),
+        }
+    }
+
+    /// Returns `true` if the signature is a valid signature of `hash` by the
+    /// public key.
+    pub fn is_valid_for(&self, hash: &impl IsHash) -> bool {
+        match self {
+            Self::Ed25519 {
+                public_key,
+                signature,
+            } => public_key.is_valid(signature, hash),
+            Self::Secp256k1 {
+                public_key,
+                signature,
+            } => public_key.is_valid(signature, hash),
+        }
+    }
+}
+
+impl PrivateKey {
+    /// Signs `msg_hash` with the inner private key, returning the signature
+    /// together with the public key.
+    pub fn sign(&self, msg_hash: &impl IsHash) -> SignatureWithPublicKey {
+        measure(MeasuredOperation::Signing, "sign", || match self {
+            PrivateKey::Ed25519(key) => SignatureWithPublicKey::Ed25519 {
+                public_key: key.public_key(),
+                signature: key.sign(msg_hash),
+            },
+            PrivateKey::Secp256k1(key) => SignatureWithPublicKey::Secp256k1 {
+                public_key: key.public_key(),
+                signature: key.sign(msg_hash),
+            },
+        })
+    }
+}
+
+#[uniffi::export]
+pub fn new_signature_with_public_key(
+    public_key: PublicKey,
+    signature: Signature,
+) -> Result<SignatureWithPublicKey> {
+    SignatureWithPublicKey::new(public_key, signature)
+}
+
+#[uniffi::export]
+pub fn new_signature_with_public_key_placeholder() -> SignatureWithPublicKey {
+    SignatureWithPublicKey::placeholder()
+}
+
+#[uniffi::export]
+pub fn new_signature_with_public_key_placeholder_other(
+) -> SignatureWithPublicKey {
+    SignatureWithPublicKey::placeholder_other()
+}
+
+/// Returns `true` if the signature is a valid signature of `hash` by the
+/// public key.
+#[uniffi::export]
+pub fn signature_with_public_key_is_valid_for(
+    signature_with_public_key: &SignatureWithPublicKey,
+    hash: Hex32Bytes,
+) -> bool {
+    signature_with_public_key
+        .is_valid_for(&radix_engine_common::crypto::Hash(hash.bytes()))
+}
+
+impl HasPlaceholder for SignatureWithPublicKey {
+    /// A placeholder used to facilitate unit tests, an Ed25519 signature of
+    /// `Hex32Bytes::placeholder()`.
+    fn placeholder() -> Self {
+        PrivateKey::from(Ed25519PrivateKey::placeholder_alice()).sign(
+            &radix_engine_common::crypto::Hash(
+                Hex32Bytes::placeholder().bytes(),
+            ),
+        )
+    }
+
+    /// A placeholder used to facilitate unit tests, a secp256k1 signature of
+    /// `Hex32Bytes::placeholder_other()`.
+    fn placeholder_other() -> Self {
+        PrivateKey::from(Secp256k1PrivateKey::placeholder_alice()).sign(
+            &radix_engine_common::crypto::Hash(
+                Hex32Bytes::placeholder_other().bytes(),
+            ),
+        )
+    }
+}
+
+/// The public key as a hex string with the curve as a sibling field, which
+/// is the representation dApps expect.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SignatureWithPublicKeyWire {
+    curve: SLIP10Curve,
+    public_key: String,
+    signature: String,
+}
+
+impl Serialize for SignatureWithPublicKey {
+    #[cfg(not(tarpaulin_include))] // false negative
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        SignatureWithPublicKeyWire {
+            curve: self.curve(),
+            public_key: self.public_key().to_hex(),
+            signature: self.signature().to_hex(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for SignatureWithPublicKey {
+    #[cfg(not(tarpaulin_include))] // false negative
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let wire = SignatureWithPublicKeyWire::deserialize(deserializer)?;
+        let public_key =
+            PublicKey::from_hex(wire.curve.clone(), &wire.public_key)
+                .map_err(de::Error::custom)?;
+        let signature = Signature::from_hex(wire.curve, &wire.signature)
+            .map_err(de::Error::custom)?;
+        Self::new(public_key, signature).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use radix_engine_common::crypto::Hash;
+
+    #[test]
+    fn equality() {
+        assert_eq!(
+            SignatureWithPublicKey::placeholder(),
+            SignatureWithPublicKey::placeholder()
+        );
+        assert_eq!(
+            SignatureWithPublicKey::placeholder_other(),
+            SignatureWithPublicKey::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            SignatureWithPublicKey::placeholder(),
+            SignatureWithPublicKey::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn is_valid_for() {
+        assert!(SignatureWithPublicKey::placeholder()
+            .is_valid_for(&Hash(Hex32Bytes::placeholder().bytes())));
+        assert!(SignatureWithPublicKey::placeholder_other()
+            .is_valid_for(&Hash(Hex32Bytes::placeholder_other().bytes())));
+    }
+
+    #[test]
+    fn is_not_valid_for_other_hash() {
+        assert!(!SignatureWithPublicKey::placeholder()
+            .is_valid_for(&Hash(Hex32Bytes::placeholder_other().bytes())));
+        assert!(!SignatureWithPublicKey::placeholder_other()
+            .is_valid_for(&Hash(Hex32Bytes::placeholder().bytes())));
+    }
+
+    #[test]
+    fn new_roundtrip() {
+        for sut in [
+            SignatureWithPublicKey::placeholder(),
+            SignatureWithPublicKey::placeholder_other(),
+        ] {
+            assert_eq!(
+                SignatureWithPublicKey::new(sut.public_key(), sut.signature()),
+                Ok(sut)
+            );
+        }
+    }
+
+    #[test]
+    fn new_curve_mismatch() {
+        assert_eq!(
+            SignatureWithPublicKey::new(
+                PublicKey::placeholder_secp256k1(),
+                Signature::placeholder()
+            ),
+            Err(CommonError::SignatureCurveMismatch {
+                public_key_curve: "secp256k1".to_owned(),
+                signature_curve: "curve25519".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn curve() {
+        assert_eq!(
+            SignatureWithPublicKey::placeholder().curve(),
+            SignatureWithPublicKey::placeholder().public_key().curve()
+        );
+        assert_eq!(
+            SignatureWithPublicKey::placeholder_other().curve(),
+            SLIP10Curve::Secp256k1
+        );
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        let sut = SignatureWithPublicKey::placeholder_other();
+        let json = serde_json::to_value(&sut).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "curve": "secp256k1",
+                "publicKey": sut.public_key().to_hex(),
+                "signature": sut.signature().to_hex(),
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<SignatureWithPublicKey>(json).unwrap(),
+            sut
+        );
+    }
+
+    #[test]
+    fn json_invalid_public_key_fails() {
+        assert!(serde_json::from_value::<SignatureWithPublicKey>(json!({
+            "curve": "curve25519",
+            "publicKey": "deadbeef",
+            "signature": Signature::placeholder().to_hex(),
+        }))
+        .is_err());
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality_placeholders() {
+        assert_eq!(
+            SignatureWithPublicKey::placeholder(),
+            new_signature_with_public_key_placeholder()
+        );
+        assert_eq!(
+            SignatureWithPublicKey::placeholder_other(),
+            new_signature_with_public_key_placeholder_other()
+        );
+    }
+
+    #[test]
+    fn new() {
+        let sut = SignatureWithPublicKey::placeholder();
+        assert_eq!(
+            new_signature_with_public_key(sut.public_key(), sut.signature()),
+            Ok(sut)
+        );
+    }
+
+    #[test]
+    fn is_valid_for() {
+        assert!(signature_with_public_key_is_valid_for(
+            &SignatureWithPublicKey::placeholder(),
+            Hex32Bytes::placeholder()
+        ));
+        assert!(!signature_with_public_key_is_valid_for(
+            &SignatureWithPublicKey::placeholder(),
+            Hex32Bytes::placeholder_other()
+        ));
+    }
+}
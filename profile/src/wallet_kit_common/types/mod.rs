@@ -1,15 +1,21 @@
 mod bag_of_bytes;
 mod decimal;
 mod entity_kind;
+mod hashes;
 mod hex_32bytes;
 mod keys;
 mod logged_result;
+mod safe_debug;
 mod safe_to_log;
+mod unknown_fields;
 
 pub use bag_of_bytes::*;
 pub use decimal::*;
 pub use entity_kind::*;
+pub use hashes::*;
 pub use hex_32bytes::*;
 pub use keys::*;
 pub use logged_result::*;
+pub use safe_debug::*;
 pub use safe_to_log::*;
+pub use unknown_fields::*;
@@ -0,0 +1,15 @@
+/// A trait implemented by types holding secrets, e.g. Mnemonics, PrivateKeys
+/// and connection passwords, whose `Debug` output MUST use `redacted_debug`,
+/// a placeholder which preserves the structure of the secret but not its
+/// value, e.g. `<REDACTED 24 words>`, so that secrets never leak into the
+/// logs of the host.
+pub trait SafeDebug {
+    /// A representation of the secret safe to log, MUST NOT contain the
+    /// secret itself.
+    fn redacted_debug(&self) -> String;
+
+    /// The unredacted representation of the secret, only available with the
+    /// `danger-fully-reveal-debug` feature, use with extreme caution.
+    #[cfg(feature = "danger-fully-reveal-debug")]
+    fn danger_fully_reveal_debug(&self) -> String;
+}
@@ -0,0 +1,130 @@
+use crate::prelude::*;
+
+/// JSON fields of a Profile model which this version of the wallet kit does
+/// not know about, typically written by a newer version of the wallet kit.
+///
+/// Models which are flattened with these fields preserve them when decoded
+/// and re-emit them when encoded, so that an older app saving a Profile does
+/// not silently strip data written by a newer app.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(transparent)]
+pub struct UnknownFields(serde_json::Map<String, serde_json::Value>);
+
+impl UnknownFields {
+    pub fn new(fields: serde_json::Map<String, serde_json::Value>) -> Self {
+        Self(fields)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// The value of the unknown field `key`, if present.
+    pub fn get(&self, key: &str) -> Option<&serde_json::Value> {
+        self.0.get(key)
+    }
+
+    /// The names of all unknown fields, in the order they were decoded.
+    pub fn keys(&self) -> Vec<String> {
+        self.0.keys().cloned().collect()
+    }
+}
+
+impl std::hash::Hash for UnknownFields {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        // `serde_json::Value` is not `Hash`, but since the map preserves
+        // order its serialization is deterministic.
+        serde_json::to_string(&self.0)
+            .expect("JSON Map should always be serializable")
+            .hash(state)
+    }
+}
+
+uniffi::custom_type!(UnknownFields, String);
+/// Crosses the FFI boundary as its JSON object string.
+impl crate::UniffiCustomTypeConverter for UnknownFields {
+    type Builtin = String;
+
+    #[cfg(not(tarpaulin_include))] // false negative, tested in bindgen tests
+    fn into_custom(val: Self::Builtin) -> uniffi::Result<Self> {
+        serde_json::from_str(&val).map_err(|e| e.into())
+    }
+
+    #[cfg(not(tarpaulin_include))] // false negative, tested in bindgen tests
+    fn from_custom(obj: Self) -> Self::Builtin {
+        serde_json::to_string(&obj.0)
+            .expect("JSON Map should always be serializable")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+    struct Model {
+        known: u8,
+
+        #[serde(flatten)]
+        extra: UnknownFields,
+    }
+
+    #[test]
+    fn default_is_empty() {
+        assert!(UnknownFields::default().is_empty());
+        assert_eq!(UnknownFields::default().len(), 0);
+    }
+
+    #[test]
+    fn unknown_fields_are_collected() {
+        let sut = serde_json::from_value::<Model>(json!({
+            "known": 1,
+            "future": "hello",
+            "nested": { "a": [1, 2] }
+        }))
+        .unwrap();
+        assert_eq!(sut.known, 1);
+        assert_eq!(sut.extra.keys(), vec!["future", "nested"]);
+        assert_eq!(sut.extra.get("future"), Some(&json!("hello")));
+        assert_eq!(sut.extra.get("known"), None);
+    }
+
+    #[test]
+    fn unknown_fields_are_reemitted() {
+        let json = json!({
+            "known": 1,
+            "future": "hello",
+            "nested": { "a": [1, 2] }
+        });
+        assert_json_value_eq_after_roundtrip(
+            &serde_json::from_value::<Model>(json.clone()).unwrap(),
+            json,
+        );
+    }
+
+    #[test]
+    fn no_unknown_fields() {
+        assert_json_value_eq_after_roundtrip(
+            &Model {
+                known: 1,
+                extra: UnknownFields::default(),
+            },
+            json!({ "known": 1 }),
+        );
+    }
+
+    #[test]
+    fn hash_depends_on_content() {
+        let set = HashSet::<UnknownFields>::from_iter([
+            UnknownFields::default(),
+            serde_json::from_value(json!({ "a": 1 })).unwrap(),
+            serde_json::from_value(json!({ "a": 2 })).unwrap(),
+            serde_json::from_value(json!({ "a": 1 })).unwrap(),
+        ]);
+        assert_eq!(set.len(), 3);
+    }
+}
@@ -1,11 +1,9 @@
 use crate::prelude::*;
 
-pub fn now() -> Timestamp {
-    Timestamp::now_utc()
-}
-
+/// A new id, generated by the [`IdGenerator`] of the current thread, which
+/// is random unless in test builds.
 pub fn id() -> Uuid {
-    Uuid::new_v4()
+    id_generator_next()
 }
 
 pub fn profile_id() -> ProfileID {
@@ -25,16 +23,6 @@ pub fn date(dt: &Timestamp) -> String {
 mod tests {
     use crate::prelude::*;
 
-    #[test]
-    fn date_now() {
-        let d0 = now();
-        let mut d1 = now();
-        for _ in 0..10 {
-            d1 = now();
-        }
-        assert!(d1 > d0);
-    }
-
     #[test]
     fn id_unique() {
         let n = 100;
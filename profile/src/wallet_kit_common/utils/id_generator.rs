@@ -0,0 +1,127 @@
+use crate::prelude::*;
+use std::cell::RefCell;
+
+/// Generates the UUIDs used as ids, e.g. of Profiles and persona data
+/// entries, used by [`id`]. Abstracted so that tests and placeholders can
+/// use stable ids.
+pub trait IdGenerator: Send + Sync + std::fmt::Debug {
+    fn next_id(&self) -> Uuid;
+}
+
+/// An [`IdGenerator`] generating random (v4) UUIDs.
+#[derive(Debug, Default)]
+pub struct RandomIdGenerator;
+
+impl IdGenerator for RandomIdGenerator {
+    fn next_id(&self) -> Uuid {
+        Uuid::new_v4()
+    }
+}
+
+/// An [`IdGenerator`] generating the sequence of UUIDs
+/// `00000000-0000-4000-8000-000000000001`, `...-000000000002` and so on,
+/// the default in test builds so that fixtures have stable ids.
+#[cfg(any(test, feature = "test-utils"))]
+#[derive(Debug, Default)]
+pub struct SequentialIdGenerator {
+    last: std::sync::atomic::AtomicU64,
+}
+
+#[cfg(any(test, feature = "test-utils"))]
+impl SequentialIdGenerator {
+    /// The `n`th id generated, `1` being the first.
+    pub fn nth(n: u64) -> Uuid {
+        Uuid::from_u64_pair(0x4000, 0x8000_0000_0000_0000 | n)
+    }
+}
+
+#[cfg(any(test, feature = "test-utils"))]
+impl IdGenerator for SequentialIdGenerator {
+    fn next_id(&self) -> Uuid {
+        Self::nth(
+            self.last.fetch_add(1, std::sync::atomic::Ordering::Relaxed) + 1,
+        )
+    }
+}
+
+#[cfg(not(any(test, feature = "test-utils")))]
+fn default_id_generator() -> Arc<dyn IdGenerator> {
+    Arc::new(RandomIdGenerator)
+}
+
+#[cfg(any(test, feature = "test-utils"))]
+fn default_id_generator() -> Arc<dyn IdGenerator> {
+    Arc::new(SequentialIdGenerator::default())
+}
+
+thread_local! {
+    static ID_GENERATOR: RefCell<Arc<dyn IdGenerator>> =
+        RefCell::new(default_id_generator());
+}
+
+/// The next id according to the [`IdGenerator`] of the current thread.
+pub(crate) fn id_generator_next() -> Uuid {
+    ID_GENERATOR.with(|g| g.borrow().next_id())
+}
+
+/// Runs `f` with `generator` as the [`IdGenerator`] of the current thread,
+/// restoring the previous generator afterwards.
+#[cfg(any(test, feature = "test-utils"))]
+pub fn with_id_generator<G, F, R>(generator: G, f: F) -> R
+where
+    G: IdGenerator + 'static,
+    F: FnOnce() -> R,
+{
+    let previous = ID_GENERATOR.with(|g| g.replace(Arc::new(generator)));
+    let result = f();
+    ID_GENERATOR.with(|g| *g.borrow_mut() = previous);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn sequential() {
+        with_id_generator(SequentialIdGenerator::default(), || {
+            assert_eq!(
+                id().to_string(),
+                "00000000-0000-4000-8000-000000000001"
+            );
+            assert_eq!(id(), SequentialIdGenerator::nth(2));
+        });
+    }
+
+    #[test]
+    fn sequential_is_the_default_in_tests() {
+        let first = std::thread::spawn(id).join().unwrap();
+        assert_eq!(first, SequentialIdGenerator::nth(1));
+    }
+
+    #[test]
+    fn sequential_does_not_collide_with_placeholders() {
+        with_id_generator(SequentialIdGenerator::default(), || {
+            assert_ne!(
+                PersonaDataEntryID::generate(),
+                PersonaDataEntryID::placeholder_one()
+            );
+        });
+    }
+
+    #[test]
+    fn random() {
+        let (a, b) = with_id_generator(RandomIdGenerator, || (id(), id()));
+        assert_ne!(a, b);
+        assert_eq!(a.get_version_num(), 4);
+    }
+
+    #[test]
+    fn previous_generator_is_restored() {
+        with_id_generator(SequentialIdGenerator::default(), || {
+            assert_eq!(id(), SequentialIdGenerator::nth(1));
+            with_id_generator(RandomIdGenerator, id);
+            assert_eq!(id(), SequentialIdGenerator::nth(2));
+        });
+    }
+}
@@ -1,7 +1,13 @@
 mod factory;
+mod id_generator;
 mod logged_panic;
+mod stable_json;
 mod string_utils;
+mod url_utils;
 
 pub use factory::*;
+pub use id_generator::*;
 pub use logged_panic::*;
+pub use stable_json::*;
 pub use string_utils::*;
+pub use url_utils::*;
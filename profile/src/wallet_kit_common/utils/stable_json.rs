@@ -0,0 +1,73 @@
+use crate::prelude::*;
+
+/// Returns `value` with the keys of all - also nested - objects sorted
+/// lexicographically, the order of array elements is kept.
+fn with_sorted_keys(value: serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(map) => serde_json::Value::Object(
+            map.into_iter()
+                .sorted_by(|(lhs, _), (rhs, _)| lhs.cmp(rhs))
+                .map(|(key, value)| (key, with_sorted_keys(value)))
+                .collect(),
+        ),
+        serde_json::Value::Array(items) => serde_json::Value::Array(
+            items.into_iter().map(with_sorted_keys).collect(),
+        ),
+        other => other,
+    }
+}
+
+/// Encodes `value` as JSON with the keys of all objects sorted, indented by
+/// two spaces and ending with a newline, so that the output is identical on
+/// all platforms and diffs between two encodings only show actual changes.
+pub fn to_pretty_json_stable<T: Serialize>(
+    value: &T,
+) -> serde_json::Result<String> {
+    let value = with_sorted_keys(serde_json::to_value(value)?);
+    serde_json::to_string_pretty(&value).map(|json| json + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn keys_are_sorted_recursively() {
+        let value = json!({
+            "b": 1,
+            "a": { "d": [ { "f": 1, "e": 2 } ], "c": null }
+        });
+        assert_eq!(
+            to_pretty_json_stable(&value).unwrap(),
+            r#"{
+  "a": {
+    "c": null,
+    "d": [
+      {
+        "e": 2,
+        "f": 1
+      }
+    ]
+  },
+  "b": 1
+}
+"#
+        );
+    }
+
+    #[test]
+    fn array_order_is_kept() {
+        assert_eq!(
+            to_pretty_json_stable(&json!([3, 1, 2])).unwrap(),
+            "[\n  3,\n  1,\n  2\n]\n"
+        );
+    }
+
+    #[test]
+    fn independent_of_insertion_order() {
+        assert_eq!(
+            to_pretty_json_stable(&json!({ "x": 1, "y": 2 })).unwrap(),
+            to_pretty_json_stable(&json!({ "y": 2, "x": 1 })).unwrap()
+        );
+    }
+}
@@ -0,0 +1,124 @@
+use crate::prelude::*;
+
+/// Parses `s` as an absolute `http` or `https` URL with a host, rejecting
+/// e.g. `javascript:` or `file:` URLs.
+pub fn parse_web_url(s: impl AsRef<str>) -> Result<Url> {
+    let s = s.as_ref();
+    let url = Url::parse(s.trim())
+        .map_err(|_| CommonError::InvalidURL(s.to_owned()))?;
+    match url.scheme() {
+        "http" | "https" if url.has_host() => Ok(url),
+        _ => Err(CommonError::InvalidURL(s.to_owned())),
+    }
+}
+
+/// Parses `s` as an absolute `https` URL with a host.
+pub fn parse_https_url(s: impl AsRef<str>) -> Result<Url> {
+    let url = parse_web_url(s)?;
+    ensure_https(&url)?;
+    Ok(url)
+}
+
+/// Parses `s` as an origin, i.e. an `https` URL without path (other than
+/// `/`), query or fragment, e.g. `"https://dashboard.radixdlt.com"`.
+pub fn parse_origin(s: impl AsRef<str>) -> Result<Url> {
+    let s = s.as_ref();
+    let url = parse_https_url(s)?;
+    if url.path() == "/" && url.query().is_none() && url.fragment().is_none() {
+        Ok(url)
+    } else {
+        Err(CommonError::InvalidURL(s.to_owned()))
+    }
+}
+
+/// Returns `Err` unless `url` uses the `https` scheme.
+pub fn ensure_https(url: &Url) -> Result<()> {
+    if url.scheme() == "https" {
+        Ok(())
+    } else {
+        Err(CommonError::UrlNotHttps(url.to_string()))
+    }
+}
+
+/// Returns `url` with a path ending in `/`, so that relative paths are
+/// joined onto it rather than replacing its last path segment, e.g. a
+/// Gateway at `https://example.com/api` becomes `https://example.com/api/`.
+pub fn url_with_trailing_slash(mut url: Url) -> Url {
+    if !url.path().ends_with('/') {
+        let path = format!("{}/", url.path());
+        url.set_path(&path);
+    }
+    url
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn web_url() {
+        assert_eq!(
+            parse_web_url("http://localhost:8080").unwrap().as_str(),
+            "http://localhost:8080/"
+        );
+        assert_eq!(
+            parse_web_url(" https://example.com/a ").unwrap().as_str(),
+            "https://example.com/a"
+        );
+    }
+
+    #[test]
+    fn web_url_rejects_other_schemes() {
+        assert_eq!(
+            parse_web_url("javascript:alert(1)"),
+            Err(CommonError::InvalidURL("javascript:alert(1)".to_owned()))
+        );
+        assert!(parse_web_url("file:///etc/passwd").is_err());
+        assert!(parse_web_url("not a url").is_err());
+    }
+
+    #[test]
+    fn https_url() {
+        assert!(parse_https_url("https://example.com").is_ok());
+        assert_eq!(
+            parse_https_url("http://example.com"),
+            Err(CommonError::UrlNotHttps("http://example.com/".to_owned()))
+        );
+    }
+
+    #[test]
+    fn origin() {
+        assert_eq!(
+            parse_origin("https://dashboard.radixdlt.com")
+                .unwrap()
+                .as_str(),
+            "https://dashboard.radixdlt.com/"
+        );
+        assert!(parse_origin("https://dashboard.radixdlt.com/").is_ok());
+    }
+
+    #[test]
+    fn origin_rejects_path_query_and_fragment() {
+        assert!(parse_origin("https://example.com/a").is_err());
+        assert!(parse_origin("https://example.com?a=b").is_err());
+        assert!(parse_origin("https://example.com#a").is_err());
+        assert!(parse_origin("http://example.com").is_err());
+    }
+
+    #[test]
+    fn trailing_slash() {
+        let url = Url::parse("https://example.com/api").unwrap();
+        assert_eq!(
+            url_with_trailing_slash(url).as_str(),
+            "https://example.com/api/"
+        );
+    }
+
+    #[test]
+    fn trailing_slash_is_idempotent() {
+        let url = Url::parse("https://example.com/api/").unwrap();
+        assert_eq!(url_with_trailing_slash(url.clone()), url);
+        let root = Url::parse("https://example.com").unwrap();
+        assert_eq!(url_with_trailing_slash(root.clone()), root);
+    }
+}
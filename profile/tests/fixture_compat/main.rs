@@ -0,0 +1,153 @@
+//! Cross-platform compatibility suite, asserting that the canonical fixture
+//! files shared with the Swift and Kotlin wallets round-trip exactly through
+//! the Rust models.
+//!
+//! Run with `cargo test --features fixture-compat --test fixture_compat`.
+//! Fixtures are read from `tests/vectors/fixtures` unless the environment
+//! variable `FIXTURE_COMPAT_DIR` points to another vendored directory.
+
+use profile::prelude::*;
+use serde::{de::DeserializeOwned, Serialize};
+use std::{env, fs, path::PathBuf, str::FromStr};
+
+fn fixtures_dir() -> PathBuf {
+    env::var("FIXTURE_COMPAT_DIR").map(PathBuf::from).unwrap_or_else(|_| {
+        PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap())
+            .join("tests/vectors/fixtures")
+    })
+}
+
+/// The kind of a fixture, determined by its file name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FixtureKind {
+    ProfileSnapshot,
+    DerivationVectors,
+}
+
+impl FixtureKind {
+    fn of(file_name: &str) -> Option<Self> {
+        if file_name.contains("profile_snapshot") {
+            Some(Self::ProfileSnapshot)
+        } else if file_name.starts_with("cap26_")
+            || file_name.starts_with("bip44_")
+            || file_name.starts_with("slip10_")
+        {
+            Some(Self::DerivationVectors)
+        } else {
+            None
+        }
+    }
+}
+
+/// All JSON fixtures in the fixtures directory, recursively, with their kind.
+fn fixtures() -> Vec<(PathBuf, FixtureKind)> {
+    fn visit(dir: PathBuf, found: &mut Vec<(PathBuf, FixtureKind)>) {
+        let entries = fs::read_dir(&dir)
+            .unwrap_or_else(|_| panic!("Failed to read dir {:?}", dir));
+        for entry in entries.map(|e| e.unwrap().path()) {
+            if entry.is_dir() {
+                visit(entry, found);
+            } else if entry.extension().is_some_and(|e| e == "json") {
+                let name = entry.file_name().unwrap().to_string_lossy();
+                if let Some(kind) = FixtureKind::of(&name) {
+                    found.push((entry.clone(), kind));
+                }
+            }
+        }
+    }
+    let mut found = Vec::new();
+    visit(fixtures_dir(), &mut found);
+    found.sort();
+    found
+}
+
+fn read_json(path: &PathBuf) -> serde_json::Value {
+    let contents = fs::read_to_string(path)
+        .unwrap_or_else(|_| panic!("Failed to read {:?}", path));
+    serde_json::Value::from_str(&contents)
+        .unwrap_or_else(|_| panic!("Invalid JSON in {:?}", path))
+}
+
+/// Compact JSON with sorted keys, so that two semantically equal documents
+/// produced on different platforms have identical bytes.
+fn canonical_bytes(value: &serde_json::Value) -> Vec<u8> {
+    serde_json::to_vec(value).unwrap()
+}
+
+/// Asserts that decoding `json` as `T` and encoding it again produces
+/// exactly the same canonical bytes.
+fn assert_byte_exact_roundtrip<T>(path: &PathBuf, json: serde_json::Value)
+where
+    T: Serialize + DeserializeOwned,
+{
+    let model = serde_json::from_value::<T>(json.clone()).unwrap_or_else(|e| {
+        panic!("Failed to decode {:?} as model: {}", path, e)
+    });
+    let encoded = serde_json::to_value(&model).unwrap();
+    assert_eq!(
+        String::from_utf8(canonical_bytes(&encoded)).unwrap(),
+        String::from_utf8(canonical_bytes(&json)).unwrap(),
+        "Fixture {:?} did not round-trip byte-exact",
+        path
+    );
+}
+
+/// Collects the values of all `"path"` fields, at any depth.
+fn derivation_paths(value: &serde_json::Value, found: &mut Vec<String>) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map {
+                match (key.as_str(), value) {
+                    ("path", serde_json::Value::String(path)) => {
+                        found.push(path.clone())
+                    }
+                    _ => derivation_paths(value, found),
+                }
+            }
+        }
+        serde_json::Value::Array(values) => {
+            values.iter().for_each(|v| derivation_paths(v, found))
+        }
+        _ => {}
+    }
+}
+
+#[test]
+fn fixtures_are_found() {
+    let fixtures = fixtures();
+    assert!(fixtures
+        .iter()
+        .any(|(_, k)| *k == FixtureKind::ProfileSnapshot));
+    assert!(fixtures
+        .iter()
+        .any(|(_, k)| *k == FixtureKind::DerivationVectors));
+}
+
+#[test]
+fn profile_snapshots_roundtrip_byte_exact() {
+    fixtures()
+        .into_iter()
+        .filter(|(_, k)| *k == FixtureKind::ProfileSnapshot)
+        .for_each(|(path, _)| {
+            let json = read_json(&path);
+            assert_byte_exact_roundtrip::<Profile>(&path, json)
+        });
+}
+
+#[test]
+fn derivation_paths_roundtrip_byte_exact() {
+    fixtures()
+        .into_iter()
+        .filter(|(_, k)| *k == FixtureKind::DerivationVectors)
+        .for_each(|(path, _)| {
+            let mut paths = Vec::new();
+            derivation_paths(&read_json(&path), &mut paths);
+            assert!(!paths.is_empty(), "No paths found in {:?}", path);
+            for p in paths {
+                let parsed = HDPath::from_str(&p).unwrap_or_else(|_| {
+                    panic!("Invalid path '{}' in {:?}", p, path)
+                });
+                assert_eq!(parsed.to_string(), p, "in {:?}", path);
+            }
+        });
+}
@@ -10,12 +10,12 @@ fun testAddress() {
         publicKey = PublicKey.Ed25519(value = key),
         networkId = NetworkId.MAINNET
     )
-    assert(address0.address == bech32)
+    assert(address0 == bech32)
 
     val address1 = newAccountAddress(bech32 = bech32)
-    assert(address1.address == bech32)
+    assert(address1 == bech32)
     assert(accountAddressToShort(address = address1) == "acco...m2kzdm")
-    assert(address1.networkId == NetworkId.MAINNET)
+    assert(accountAddressNetworkId(address = address1) == NetworkId.MAINNET)
 }
 
 fun test() {
@@ -6,14 +6,14 @@ func testAddress() throws {
 		hex: "3e9b96a2a863f1be4658ea66aa0584d2a8847d4c0f658b20e62e3594d994d73d")
 	let address0 = newAccountAddressFrom(
 		publicKey: PublicKey.ed25519(value: key), networkId: .mainnet)
-	assert(address0.address == bech32)
+	assert(address0 == bech32)
 	let address1 = try newAccountAddress(bech32: bech32)
-	assert(address1.address == bech32)
+	assert(address1 == bech32)
 	assert(
 		accountAddressToShort(
 			address: address1
 		) == "acco...m2kzdm")
-	assert(address1.networkId == .mainnet)
+	assert(accountAddressNetworkId(address: address1) == .mainnet)
 }
 
 func test() throws {